@@ -3,6 +3,28 @@ use veryl_parser::miette::{self, Diagnostic, NamedSource, SourceSpan};
 use veryl_parser::resource_table;
 use veryl_parser::veryl_token::VerylToken;
 
+/// Text edit which fixes an error
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fix {
+    pub title: String,
+    /// Byte offset of the replaced text
+    pub offset: usize,
+    /// Byte length of the replaced text
+    pub length: usize,
+    pub text: String,
+}
+
+impl Fix {
+    pub fn new(title: &str, offset: usize, length: usize, text: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            offset,
+            length,
+            text: text.to_string(),
+        }
+    }
+}
+
 #[derive(Error, Diagnostic, Debug)]
 pub enum AnalyzeError {
     #[diagnostic(code(AnalyzeError::InvalidNumberCharacter), help(""))]
@@ -10,6 +32,7 @@ pub enum AnalyzeError {
     InvalidNumberCharacter {
         cause: char,
         kind: String,
+        fixes: Vec<Fix>,
         #[source_code]
         input: NamedSource,
         #[label("Error location")]
@@ -29,6 +52,7 @@ pub enum AnalyzeError {
     #[diagnostic(code(AnalyzeError::IfResetRequired), help("add if_reset statement"))]
     #[error("if_reset statement is required for always_ff with reset signal")]
     IfResetRequired {
+        fixes: Vec<Fix>,
         #[source_code]
         input: NamedSource,
         #[label("Error location")]
@@ -54,6 +78,19 @@ pub enum AnalyzeError {
         error_location: SourceSpan,
     },
 
+    #[diagnostic(
+        code(AnalyzeError::TooManyAttributeArguments),
+        help("SystemVerilog attribute takes a single value")
+    )]
+    #[error("attribute \"{name}\" has too many arguments")]
+    TooManyAttributeArguments {
+        name: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
     #[diagnostic(code(AnalyzeError::InvalidDirection), help("remove {kind} direction"))]
     #[error("{kind} direction can't be placed at here")]
     InvalidDirection {
@@ -77,6 +114,46 @@ pub enum AnalyzeError {
         error_location: SourceSpan,
     },
 
+    #[diagnostic(
+        code(AnalyzeError::InvalidFormatSpecifier),
+        help("fix format specifier")
+    )]
+    #[error("format specifier \"{specifier}\" is invalid")]
+    InvalidFormatSpecifier {
+        specifier: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        code(AnalyzeError::InvalidSignedModifier),
+        help("use logic or bit as the base type")
+    )]
+    #[error("\"signed\" can't be applied to \"{r#type}\"")]
+    InvalidSignedModifier {
+        r#type: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(AnalyzeError::MixedSignedness),
+        help("cast operands by \"as\" to make their signedness the same")
+    )]
+    #[error("operands of \"{operator}\" mix signed and unsigned, so the operation is unsigned")]
+    MixedSignedness {
+        operator: String,
+        #[source_code]
+        input: NamedSource,
+        #[label("Warning location")]
+        error_location: SourceSpan,
+    },
+
     #[diagnostic(code(AnalyzeError::MismatchArity), help("fix function arguments"))]
     #[error("function \"{name}\" has {arity} arguments, but {args} arguments are supplied")]
     MismatchArity {
@@ -89,6 +166,21 @@ pub enum AnalyzeError {
         error_location: SourceSpan,
     },
 
+    #[diagnostic(
+        code(AnalyzeError::MismatchFormatArgs),
+        help("fix format specifiers or arguments")
+    )]
+    #[error("\"{name}\" has {specifiers} format specifiers, but {args} arguments are supplied")]
+    MismatchFormatArgs {
+        name: String,
+        specifiers: usize,
+        args: usize,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
     #[diagnostic(code(AnalyzeError::MitmatchType), help(""))]
     #[error("\"{name}\" is expected to \"{expected}\", but it is \"{actual}\"")]
     MismatchType {
@@ -106,6 +198,7 @@ pub enum AnalyzeError {
     MissingPort {
         name: String,
         port: String,
+        fixes: Vec<Fix>,
         #[source_code]
         input: NamedSource,
         #[label("Error location")]
@@ -117,6 +210,17 @@ pub enum AnalyzeError {
     UnknownPort {
         name: String,
         port: String,
+        fixes: Vec<Fix>,
+        #[source_code]
+        input: NamedSource,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(code(AnalyzeError::UndefinedIdentifier), help(""))]
+    #[error("{identifier} is undefined")]
+    UndefinedIdentifier {
+        identifier: String,
         #[source_code]
         input: NamedSource,
         #[label("Error location")]
@@ -147,12 +251,14 @@ impl AnalyzeError {
     pub fn invalid_number_character(
         cause: char,
         kind: &str,
+        fixes: Vec<Fix>,
         source: &str,
         token: &VerylToken,
     ) -> Self {
         AnalyzeError::InvalidNumberCharacter {
             cause,
             kind: kind.to_string(),
+            fixes,
             input: AnalyzeError::named_source(source, token),
             error_location: token.token.into(),
         }
@@ -166,8 +272,9 @@ impl AnalyzeError {
         }
     }
 
-    pub fn if_reset_required(source: &str, token: &VerylToken) -> Self {
+    pub fn if_reset_required(fixes: Vec<Fix>, source: &str, token: &VerylToken) -> Self {
         AnalyzeError::IfResetRequired {
+            fixes,
             input: AnalyzeError::named_source(source, token),
             error_location: token.token.into(),
         }
@@ -188,6 +295,14 @@ impl AnalyzeError {
         }
     }
 
+    pub fn too_many_attribute_arguments(name: &str, source: &str, token: &VerylToken) -> Self {
+        AnalyzeError::TooManyAttributeArguments {
+            name: name.to_string(),
+            input: AnalyzeError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
     pub fn invalid_direction(kind: &str, source: &str, token: &VerylToken) -> Self {
         AnalyzeError::InvalidDirection {
             kind: kind.to_string(),
@@ -204,6 +319,30 @@ impl AnalyzeError {
         }
     }
 
+    pub fn invalid_format_specifier(specifier: &str, source: &str, token: &VerylToken) -> Self {
+        AnalyzeError::InvalidFormatSpecifier {
+            specifier: specifier.to_string(),
+            input: AnalyzeError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    pub fn invalid_signed_modifier(r#type: &str, source: &str, token: &VerylToken) -> Self {
+        AnalyzeError::InvalidSignedModifier {
+            r#type: r#type.to_string(),
+            input: AnalyzeError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    pub fn mixed_signedness(operator: &str, source: &str, token: &VerylToken) -> Self {
+        AnalyzeError::MixedSignedness {
+            operator: operator.to_string(),
+            input: AnalyzeError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
     pub fn mismatch_arity(
         name: &str,
         arity: usize,
@@ -220,6 +359,22 @@ impl AnalyzeError {
         }
     }
 
+    pub fn mismatch_format_args(
+        name: &str,
+        specifiers: usize,
+        args: usize,
+        source: &str,
+        token: &VerylToken,
+    ) -> Self {
+        AnalyzeError::MismatchFormatArgs {
+            name: name.to_string(),
+            specifiers,
+            args,
+            input: AnalyzeError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
     pub fn mismatch_type(
         name: &str,
         expected: &str,
@@ -236,19 +391,33 @@ impl AnalyzeError {
         }
     }
 
-    pub fn missing_port(name: &str, port: &str, source: &str, token: &VerylToken) -> Self {
+    pub fn missing_port(
+        name: &str,
+        port: &str,
+        fixes: Vec<Fix>,
+        source: &str,
+        token: &VerylToken,
+    ) -> Self {
         AnalyzeError::MissingPort {
             name: name.to_string(),
             port: port.to_string(),
+            fixes,
             input: AnalyzeError::named_source(source, token),
             error_location: token.token.into(),
         }
     }
 
-    pub fn unknown_port(name: &str, port: &str, source: &str, token: &VerylToken) -> Self {
+    pub fn unknown_port(
+        name: &str,
+        port: &str,
+        fixes: Vec<Fix>,
+        source: &str,
+        token: &VerylToken,
+    ) -> Self {
         AnalyzeError::UnknownPort {
             name: name.to_string(),
             port: port.to_string(),
+            fixes,
             input: AnalyzeError::named_source(source, token),
             error_location: token.token.into(),
         }
@@ -261,4 +430,28 @@ impl AnalyzeError {
             error_location: token.token.into(),
         }
     }
+
+    pub fn undefined_identifier(identifier: &str, source: &str, token: &VerylToken) -> Self {
+        AnalyzeError::UndefinedIdentifier {
+            identifier: identifier.to_string(),
+            input: AnalyzeError::named_source(source, token),
+            error_location: token.token.into(),
+        }
+    }
+
+    /// Warnings don't fail the check
+    pub fn is_warning(&self) -> bool {
+        matches!(self, AnalyzeError::MixedSignedness { .. })
+    }
+
+    /// Text edits which fix the error
+    pub fn fixes(&self) -> &[Fix] {
+        match self {
+            AnalyzeError::InvalidNumberCharacter { fixes, .. }
+            | AnalyzeError::IfResetRequired { fixes, .. }
+            | AnalyzeError::MissingPort { fixes, .. }
+            | AnalyzeError::UnknownPort { fixes, .. } => fixes,
+            _ => &[],
+        }
+    }
 }
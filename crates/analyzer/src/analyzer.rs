@@ -41,14 +41,18 @@ impl<'a> VerylWalker for AnalyzerPass2<'a> {
 
 pub struct Analyzer<'a> {
     text: &'a str,
+    warnings: Vec<AnalyzeError>,
 }
 
 impl<'a> Analyzer<'a> {
     pub fn new(text: &'a str) -> Self {
-        Analyzer { text }
+        Analyzer {
+            text,
+            warnings: Vec::new(),
+        }
     }
 
-    pub fn analyze(&'a mut self, input: &Veryl) -> Vec<AnalyzeError> {
+    pub fn analyze(&mut self, input: &Veryl) -> Vec<AnalyzeError> {
         let mut ret = Vec::new();
 
         let mut pass1 = AnalyzerPass1::new(self.text);
@@ -58,7 +62,13 @@ impl<'a> Analyzer<'a> {
         let mut pass2 = AnalyzerPass2::new(self.text);
         pass2.veryl(input);
         ret.append(&mut pass2.handlers.get_errors());
+        self.warnings.append(&mut pass2.handlers.get_warnings());
 
         ret
     }
+
+    /// Take warnings found by analysis, which don't prevent the build
+    pub fn take_warnings(&mut self) -> Vec<AnalyzeError> {
+        std::mem::take(&mut self.warnings)
+    }
 }
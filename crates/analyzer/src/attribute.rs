@@ -0,0 +1,66 @@
+use std::fmt;
+use veryl_parser::resource_table::{self, StrId};
+use veryl_parser::veryl_grammar_trait as syntax_tree;
+use veryl_parser::veryl_walker::VerylWalker;
+use veryl_parser::Stringifier;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Attribute {
+    /// The item is not emitted
+    Skip,
+    /// The variable is a flip-flop of synchronizer
+    Sync,
+    /// Attributes which are emitted to SystemVerilog as is
+    Sv { name: StrId, args: Vec<String> },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttributeError {
+    /// SystemVerilog attribute can have only one value like `(* name = value *)`
+    TooManyArguments,
+}
+
+impl TryFrom<&syntax_tree::Attribute> for Attribute {
+    type Error = AttributeError;
+
+    fn try_from(value: &syntax_tree::Attribute) -> Result<Self, Self::Error> {
+        let name = value.identifier.identifier_token.token.text;
+        let mut args = Vec::new();
+        if let Some(ref x) = value.attribute_opt {
+            let x = &x.attribute_list;
+            args.push(attribute_item_text(&x.attribute_item));
+            for x in &x.attribute_list_list {
+                args.push(attribute_item_text(&x.attribute_item));
+            }
+        }
+        match resource_table::get_str_value(name).unwrap().as_str() {
+            "skip" => Ok(Attribute::Skip),
+            "sync" => Ok(Attribute::Sync),
+            _ if args.len() > 1 => Err(AttributeError::TooManyArguments),
+            _ => Ok(Attribute::Sv { name, args }),
+        }
+    }
+}
+
+fn attribute_item_text(arg: &syntax_tree::AttributeItem) -> String {
+    let mut stringifier = Stringifier::new();
+    stringifier.attribute_item(arg);
+    stringifier.as_str().to_string()
+}
+
+impl fmt::Display for Attribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            Attribute::Skip => "skip".to_string(),
+            Attribute::Sync => "sync".to_string(),
+            Attribute::Sv { name, args } => {
+                let mut text = resource_table::get_str_value(*name).unwrap();
+                if !args.is_empty() {
+                    text.push_str(&format!("({})", args.join(", ")));
+                }
+                text
+            }
+        };
+        text.fmt(f)
+    }
+}
@@ -0,0 +1,346 @@
+use veryl_parser::veryl_grammar_trait::*;
+
+/// Evaluator of constant integer expressions like `WIDTH * 2`
+///
+/// Identifiers are resolved by the given function,
+/// so each user can take values of parameters from its own context.
+pub struct Evaluator<F>
+where
+    F: FnMut(&ScopedOrHierIdentifier) -> Option<i64>,
+{
+    resolve: F,
+}
+
+impl<F> Evaluator<F>
+where
+    F: FnMut(&ScopedOrHierIdentifier) -> Option<i64>,
+{
+    pub fn new(resolve: F) -> Self {
+        Self { resolve }
+    }
+
+    /// Value of the expression, `None` if it is not constant or overflows
+    pub fn expression(&mut self, arg: &Expression) -> Option<i64> {
+        match arg {
+            Expression::IfExpression(x) => self.if_expression(&x.if_expression),
+            Expression::Expression01(x) => self.expression01(&x.expression01),
+        }
+    }
+
+    fn if_expression(&mut self, arg: &IfExpression) -> Option<i64> {
+        let mut branches = vec![(&arg.expression, &arg.expression0)];
+        for x in &arg.if_expression_list {
+            branches.push((&x.expression, &x.expression0));
+        }
+        for (cond, value) in branches {
+            if self.expression(cond)? != 0 {
+                return self.expression(value);
+            }
+        }
+        self.expression(&arg.expression1)
+    }
+
+    fn expression01(&mut self, arg: &Expression01) -> Option<i64> {
+        let mut ret = self.expression02(&arg.expression02)?;
+        for x in &arg.expression01_list {
+            let y = self.expression02(&x.expression02)?;
+            ret = binary(&x.operator01.operator01_token.text(), ret, y)?;
+        }
+        Some(ret)
+    }
+
+    fn expression02(&mut self, arg: &Expression02) -> Option<i64> {
+        let mut ret = self.expression03(&arg.expression03)?;
+        for x in &arg.expression02_list {
+            let y = self.expression03(&x.expression03)?;
+            ret = binary(&x.operator02.operator02_token.text(), ret, y)?;
+        }
+        Some(ret)
+    }
+
+    fn expression03(&mut self, arg: &Expression03) -> Option<i64> {
+        let mut ret = self.expression04(&arg.expression04)?;
+        for x in &arg.expression03_list {
+            let y = self.expression04(&x.expression04)?;
+            ret = binary(&x.operator03.operator03_token.text(), ret, y)?;
+        }
+        Some(ret)
+    }
+
+    fn expression04(&mut self, arg: &Expression04) -> Option<i64> {
+        let mut ret = self.expression05(&arg.expression05)?;
+        for x in &arg.expression04_list {
+            let y = self.expression05(&x.expression05)?;
+            ret = binary(&x.operator04.operator04_token.text(), ret, y)?;
+        }
+        Some(ret)
+    }
+
+    fn expression05(&mut self, arg: &Expression05) -> Option<i64> {
+        let mut ret = self.expression06(&arg.expression06)?;
+        for x in &arg.expression05_list {
+            let y = self.expression06(&x.expression06)?;
+            ret = binary(&x.operator05.operator05_token.text(), ret, y)?;
+        }
+        Some(ret)
+    }
+
+    fn expression06(&mut self, arg: &Expression06) -> Option<i64> {
+        let mut ret = self.expression07(&arg.expression07)?;
+        for x in &arg.expression06_list {
+            let y = self.expression07(&x.expression07)?;
+            ret = binary(&x.operator06.operator06_token.text(), ret, y)?;
+        }
+        Some(ret)
+    }
+
+    fn expression07(&mut self, arg: &Expression07) -> Option<i64> {
+        let mut ret = self.expression08(&arg.expression08)?;
+        for x in &arg.expression07_list {
+            let y = self.expression08(&x.expression08)?;
+            ret = binary(&x.operator07.operator07_token.text(), ret, y)?;
+        }
+        Some(ret)
+    }
+
+    fn expression08(&mut self, arg: &Expression08) -> Option<i64> {
+        let mut ret = self.expression09(&arg.expression09)?;
+        for x in &arg.expression08_list {
+            let y = self.expression09(&x.expression09)?;
+            ret = binary(&x.operator08.operator08_token.text(), ret, y)?;
+        }
+        Some(ret)
+    }
+
+    fn expression09(&mut self, arg: &Expression09) -> Option<i64> {
+        let mut ret = self.expression10(&arg.expression10)?;
+        for x in &arg.expression09_list {
+            let y = self.expression10(&x.expression10)?;
+            ret = binary(&x.operator09.operator09_token.text(), ret, y)?;
+        }
+        Some(ret)
+    }
+
+    fn expression10(&mut self, arg: &Expression10) -> Option<i64> {
+        let mut ret = self.expression11(&arg.expression11)?;
+        for x in &arg.expression10_list {
+            let y = self.expression11(&x.expression11)?;
+            let op = match &*x.expression10_list_group {
+                Expression10ListGroup::Operator10(x) => x.operator10.operator10_token.text(),
+                Expression10ListGroup::Star(x) => x.star.star_token.text(),
+            };
+            ret = binary(&op, ret, y)?;
+        }
+        Some(ret)
+    }
+
+    fn expression11(&mut self, arg: &Expression11) -> Option<i64> {
+        let mut ret = self.expression12(&arg.expression12)?;
+        for x in &arg.expression11_list {
+            let y = self.expression12(&x.expression12)?;
+            ret = binary(&x.operator11.operator11_token.text(), ret, y)?;
+        }
+        Some(ret)
+    }
+
+    fn expression12(&mut self, arg: &Expression12) -> Option<i64> {
+        // the value of constant is kept as is through cast
+        let mut ret = self.factor(&arg.factor)?;
+        for x in arg.expression12_list.iter().rev() {
+            let op = match &*x.expression12_list_group {
+                Expression12ListGroup::UnaryOperator(x) => {
+                    x.unary_operator.unary_operator_token.text()
+                }
+                Expression12ListGroup::Operator09(x) => x.operator09.operator09_token.text(),
+                Expression12ListGroup::Operator05(x) => x.operator05.operator05_token.text(),
+                Expression12ListGroup::Operator03(x) => x.operator03.operator03_token.text(),
+                Expression12ListGroup::Operator04(x) => x.operator04.operator04_token.text(),
+            };
+            ret = unary(&op, ret)?;
+        }
+        Some(ret)
+    }
+
+    fn factor(&mut self, arg: &Factor) -> Option<i64> {
+        match arg {
+            Factor::Number(x) => number(&x.number),
+            Factor::ScopedOrHierIdentifierFactorOpt(x) => {
+                // function call, select and member access are not constant
+                let identifier = &x.scoped_or_hier_identifier;
+                let plain = match &*identifier.scoped_or_hier_identifier_group {
+                    ScopedOrHierIdentifierGroup::ColonColonIdentifierScopedOrHierIdentifierGroupList(_) => true,
+                    ScopedOrHierIdentifierGroup::ScopedOrHierIdentifierGroupList0ScopedOrHierIdentifierGroupList1(x) => {
+                        x.scoped_or_hier_identifier_group_list0.is_empty()
+                            && x.scoped_or_hier_identifier_group_list1.is_empty()
+                    }
+                };
+                if x.factor_opt.is_none() && plain {
+                    (self.resolve)(identifier)
+                } else {
+                    None
+                }
+            }
+            Factor::SystemFunctionCall(x) => self.system_function_call(&x.system_function_call),
+            Factor::LParenExpressionRParen(x) => self.expression(&x.expression),
+            Factor::StringLiteral(_) | Factor::LBraceConcatenationListRBrace(_) => None,
+        }
+    }
+
+    fn system_function_call(&mut self, arg: &SystemFunctionCall) -> Option<i64> {
+        let name = arg.dollar_identifier.dollar_identifier_token.text();
+        let args = arg.system_function_call_opt.as_ref()?;
+        let args = &args.system_function_call_opt0.as_ref()?.function_call_arg;
+        if name != "$clog2" || !args.function_call_arg_list.is_empty() {
+            return None;
+        }
+        let x = self.expression(&args.expression)?;
+        if x < 0 {
+            None
+        } else if x <= 1 {
+            Some(0)
+        } else {
+            Some(64 - (x - 1).leading_zeros() as i64)
+        }
+    }
+}
+
+fn binary(op: &str, x: i64, y: i64) -> Option<i64> {
+    let ret = match op {
+        "||" => (x != 0 || y != 0) as i64,
+        "&&" => (x != 0 && y != 0) as i64,
+        "|" => x | y,
+        "^" => x ^ y,
+        "~^" | "^~" => !(x ^ y),
+        "&" => x & y,
+        "==" | "===" => (x == y) as i64,
+        "!=" | "!==" => (x != y) as i64,
+        "<=" => (x <= y) as i64,
+        ">=" => (x >= y) as i64,
+        "<" => (x < y) as i64,
+        ">" => (x > y) as i64,
+        "<<" | "<<<" => x.checked_shl(u32::try_from(y).ok()?)?,
+        ">>" | ">>>" => x.checked_shr(u32::try_from(y).ok()?)?,
+        "+" => x.checked_add(y)?,
+        "-" => x.checked_sub(y)?,
+        "*" => x.checked_mul(y)?,
+        "/" => x.checked_div(y)?,
+        "%" => x.checked_rem(y)?,
+        "**" => x.checked_pow(u32::try_from(y).ok()?)?,
+        // wildcard equality depends on x/z bits which are not constant integers
+        _ => return None,
+    };
+    Some(ret)
+}
+
+fn unary(op: &str, x: i64) -> Option<i64> {
+    let ret = match op {
+        "-" => x.checked_neg()?,
+        "+" => x,
+        "~" => !x,
+        "!" => (x == 0) as i64,
+        // reduction operators depend on the width which is unknown
+        _ => return None,
+    };
+    Some(ret)
+}
+
+/// Value of a number literal like `10`, `1_000` or `8'hff`
+fn number(arg: &Number) -> Option<i64> {
+    let text = match arg {
+        Number::IntegralNumber(x) => match &*x.integral_number {
+            IntegralNumber::Based(x) => x.based.based_token.text(),
+            IntegralNumber::BaseLess(x) => x.base_less.base_less_token.text(),
+            IntegralNumber::AllBit(_) => return None,
+        },
+        Number::RealNumber(_) => return None,
+    };
+    let text = text.replace('_', "");
+    if let Some((width, tail)) = text.split_once('\'') {
+        let width: u32 = width.parse().ok()?;
+        let radix = match tail.get(0..1)? {
+            "b" => 2,
+            "o" => 8,
+            "d" => 10,
+            "h" => 16,
+            _ => return None,
+        };
+        // x and z can't be parsed
+        let value = i64::from_str_radix(&tail[1..], radix).ok()?;
+        if width < 63 {
+            Some(value & ((1 << width) - 1))
+        } else {
+            Some(value)
+        }
+    } else {
+        text.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use veryl_parser::veryl_walker::VerylWalker;
+    use veryl_parser::Parser;
+
+    #[derive(Default)]
+    struct Expressions {
+        values: Vec<Expression>,
+    }
+
+    impl VerylWalker for Expressions {
+        fn var_declaration(&mut self, arg: &VarDeclaration) {
+            if let Some(x) = arg.r#type.type_list0.first() {
+                self.values.push(*x.width.expression.clone());
+            }
+        }
+    }
+
+    fn eval(code: &str) -> Option<i64> {
+        let code = format!("module A {{ var a: logic [{}]; }}", code);
+        let parser = Parser::parse(&code, &"").unwrap();
+        let mut expressions = Expressions::default();
+        expressions.veryl(&parser.veryl);
+
+        let mut evaluator = Evaluator::new(|x: &ScopedOrHierIdentifier| {
+            match x.identifier.identifier_token.text().as_str() {
+                "W" => Some(8),
+                _ => None,
+            }
+        });
+        evaluator.expression(&expressions.values[0])
+    }
+
+    #[test]
+    fn operator() {
+        assert_eq!(eval("W * 2 + 1"), Some(17));
+        assert_eq!(eval("(W + 2) * 3"), Some(30));
+        assert_eq!(eval("2 ** 3 ** 2"), Some(64));
+        assert_eq!(eval("-W + 1"), Some(-7));
+        assert_eq!(eval("1 << W >> 2"), Some(64));
+        assert_eq!(eval("W == 8 && W != 7"), Some(1));
+        assert_eq!(eval("if W > 4 { 1 } else { 2 }"), Some(1));
+        assert_eq!(eval("$clog2(W + 1)"), Some(4));
+    }
+
+    #[test]
+    fn number() {
+        assert_eq!(eval("1_000"), Some(1000));
+        assert_eq!(eval("8'hff"), Some(255));
+        assert_eq!(eval("4'hff"), Some(15));
+        assert_eq!(eval("8'b1010_0101"), Some(165));
+        assert_eq!(eval("8'hxx"), None);
+        assert_eq!(eval("'1"), None);
+    }
+
+    #[test]
+    fn not_constant() {
+        assert_eq!(eval("X + 1"), None);
+        assert_eq!(eval("f(W)"), None);
+        assert_eq!(eval("W[0]"), None);
+        assert_eq!(eval("W / 0"), None);
+        assert_eq!(eval("1 << 64"), None);
+        assert_eq!(eval("2 ** 63"), None);
+        assert_eq!(eval("&W"), None);
+        assert_eq!(eval("W ==? 8"), None);
+    }
+}
@@ -1,3 +1,4 @@
+pub mod check_assertion;
 pub mod check_function_arity;
 pub mod check_invalid_direction;
 pub mod check_invalid_number_character;
@@ -5,8 +6,12 @@ pub mod check_invalid_reset;
 pub mod check_invalid_statement;
 pub mod check_module_instance;
 pub mod check_number_overflow;
+pub mod check_signedness;
 pub mod check_system_function;
+pub mod check_type_parameter;
+pub mod create_reference_table;
 pub mod create_symbol_table;
+use check_assertion::*;
 use check_function_arity::*;
 use check_invalid_direction::*;
 use check_invalid_number_character::*;
@@ -14,7 +19,10 @@ use check_invalid_reset::*;
 use check_invalid_statement::*;
 use check_module_instance::*;
 use check_number_overflow::*;
+use check_signedness::*;
 use check_system_function::*;
+use check_type_parameter::*;
+use create_reference_table::*;
 use create_symbol_table::*;
 
 use crate::analyze_error::AnalyzeError;
@@ -69,29 +77,50 @@ impl<'a> Pass1Handlers<'a> {
 }
 
 pub struct Pass2Handlers<'a> {
+    check_assertion: CheckAssertion<'a>,
     check_function_arity: CheckFunctionArity<'a>,
     check_module_instance: CheckModuleInstance<'a>,
+    check_signedness: CheckSignedness<'a>,
+    check_type_parameter: CheckTypeParameter<'a>,
+    create_reference_table: CreateReferenceTable,
 }
 
 impl<'a> Pass2Handlers<'a> {
     pub fn new(text: &'a str) -> Self {
         Self {
+            check_assertion: CheckAssertion::new(text),
             check_function_arity: CheckFunctionArity::new(text),
             check_module_instance: CheckModuleInstance::new(text),
+            check_signedness: CheckSignedness::new(text),
+            check_type_parameter: CheckTypeParameter::new(text),
+            create_reference_table: CreateReferenceTable::new(),
         }
     }
 
     pub fn get_handlers(&mut self) -> Vec<&mut dyn Handler> {
         vec![
+            &mut self.check_assertion as &mut dyn Handler,
             &mut self.check_function_arity as &mut dyn Handler,
             &mut self.check_module_instance as &mut dyn Handler,
+            &mut self.check_signedness as &mut dyn Handler,
+            &mut self.check_type_parameter as &mut dyn Handler,
+            &mut self.create_reference_table as &mut dyn Handler,
         ]
     }
 
     pub fn get_errors(&mut self) -> Vec<AnalyzeError> {
         let mut ret = Vec::new();
+        ret.append(&mut self.check_assertion.errors);
         ret.append(&mut self.check_function_arity.errors);
         ret.append(&mut self.check_module_instance.errors);
+        ret.append(&mut self.check_signedness.errors);
+        ret.append(&mut self.check_type_parameter.errors);
+        ret
+    }
+
+    pub fn get_warnings(&mut self) -> Vec<AnalyzeError> {
+        let mut ret = Vec::new();
+        ret.append(&mut self.check_signedness.warnings);
         ret
     }
 }
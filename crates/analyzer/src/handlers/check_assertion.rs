@@ -0,0 +1,165 @@
+use crate::analyze_error::AnalyzeError;
+use crate::namespace_table;
+use crate::symbol_table::{self, Name};
+use veryl_parser::miette::Result;
+use veryl_parser::resource_table::{self, StrId};
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::VerylToken;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+
+pub struct CheckAssertion<'a> {
+    pub errors: Vec<AnalyzeError>,
+    text: &'a str,
+    point: HandlerPoint,
+    in_assertion: bool,
+    loop_indices: Vec<StrId>,
+}
+
+impl<'a> CheckAssertion<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            errors: Vec::new(),
+            text,
+            point: HandlerPoint::Before,
+            in_assertion: false,
+            loop_indices: Vec::new(),
+        }
+    }
+
+    /// Check the head of the hierarchical path because members of struct and interface have no symbol
+    fn check(&mut self, token: &VerylToken) {
+        let text = token.token.text;
+        if self.loop_indices.contains(&text) {
+            return;
+        }
+        if let Some(namespace) = namespace_table::get(token.token.id) {
+            let name = Name::Hierarchical(vec![text]);
+            if symbol_table::get(&name, &namespace).is_none() {
+                let identifier = resource_table::get_str_value(text).unwrap();
+                self.errors.push(AnalyzeError::undefined_identifier(
+                    &identifier,
+                    self.text,
+                    token,
+                ));
+            }
+        }
+    }
+}
+
+impl<'a> Handler for CheckAssertion<'a> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl<'a> VerylGrammarTrait for CheckAssertion<'a> {
+    fn factor(&mut self, arg: &Factor) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            if !self.in_assertion {
+                return Ok(());
+            }
+            if let Factor::ScopedOrHierIdentifierFactorOpt(x) = arg {
+                // skip function call
+                if x.factor_opt.is_some() {
+                    return Ok(());
+                }
+                // scoped identifiers may refer enum members which have no symbol
+                let x = &x.scoped_or_hier_identifier;
+                if let ScopedOrHierIdentifierGroup::ScopedOrHierIdentifierGroupList0ScopedOrHierIdentifierGroupList1(_) = &*x.scoped_or_hier_identifier_group {
+                    self.check(&x.identifier.identifier_token);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn hierarchical_identifier(&mut self, arg: &HierarchicalIdentifier) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            if self.in_assertion {
+                self.check(&arg.identifier.identifier_token);
+            }
+        }
+        Ok(())
+    }
+
+    fn assertion_statement(&mut self, _arg: &AssertionStatement) -> Result<()> {
+        match self.point {
+            HandlerPoint::Before => self.in_assertion = true,
+            HandlerPoint::After => self.in_assertion = false,
+        }
+        Ok(())
+    }
+
+    fn assertion_declaration(&mut self, _arg: &AssertionDeclaration) -> Result<()> {
+        match self.point {
+            HandlerPoint::Before => self.in_assertion = true,
+            HandlerPoint::After => self.in_assertion = false,
+        }
+        Ok(())
+    }
+
+    fn for_statement(&mut self, arg: &ForStatement) -> Result<()> {
+        match self.point {
+            HandlerPoint::Before => self
+                .loop_indices
+                .push(arg.identifier.identifier_token.token.text),
+            HandlerPoint::After => {
+                self.loop_indices.pop();
+            }
+        }
+        Ok(())
+    }
+
+    fn module_for_declaration(&mut self, arg: &ModuleForDeclaration) -> Result<()> {
+        match self.point {
+            HandlerPoint::Before => self
+                .loop_indices
+                .push(arg.identifier.identifier_token.token.text),
+            HandlerPoint::After => {
+                self.loop_indices.pop();
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AnalyzeError, Analyzer};
+    use veryl_parser::Parser;
+
+    const CODE: &str = r#"
+module ModuleA (
+    i_clk: input logic,
+    i_rst: input logic,
+) {
+    var a: logic;
+    var b: logic [4];
+
+    assert (i_clk, i_rst) a == b[0];
+    cover (i_clk) c;
+    assume (i_clk, x_rst) a;
+
+    always_comb {
+        for i: u32 in 0..4 {
+            assert b[i] != d;
+        }
+    }
+}
+"#;
+
+    #[test]
+    fn undefined() {
+        let parser = Parser::parse(CODE, &"").unwrap();
+        let mut analyzer = Analyzer::new(CODE);
+        let errors = analyzer.analyze(&parser.veryl);
+        let errors: Vec<_> = errors
+            .iter()
+            .map(|x| match x {
+                AnalyzeError::UndefinedIdentifier { identifier, .. } => identifier.clone(),
+                _ => format!("{}", x),
+            })
+            .collect();
+        assert_eq!(errors, vec!["c", "x_rst", "d"]);
+    }
+}
@@ -32,12 +32,7 @@ impl<'a> Handler for CheckFunctionArity<'a> {
 impl<'a> VerylGrammarTrait for CheckFunctionArity<'a> {
     fn factor(&mut self, arg: &Factor) -> Result<()> {
         if let HandlerPoint::Before = self.point {
-            if let Factor::FactorOptScopedOrHierIdentifierFactorOpt0(x) = arg {
-                // skip system function
-                if x.factor_opt.is_some() {
-                    return Ok(());
-                }
-
+            if let Factor::ScopedOrHierIdentifierFactorOpt(x) = arg {
                 let name: Name = (&*x.scoped_or_hier_identifier).into();
                 let namespace = namespace_table::get(
                     x.scoped_or_hier_identifier
@@ -60,8 +55,8 @@ impl<'a> VerylGrammarTrait for CheckFunctionArity<'a> {
                 };
 
                 let mut args = 0;
-                if let Some(ref x) = x.factor_opt0 {
-                    if let Some(ref x) = x.factor_opt1 {
+                if let Some(ref x) = x.factor_opt {
+                    if let Some(ref x) = x.factor_opt0 {
                         args += 1;
                         args += x.function_call_arg.function_call_arg_list.len();
                     }
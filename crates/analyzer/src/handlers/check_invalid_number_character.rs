@@ -1,11 +1,16 @@
-use crate::analyze_error::AnalyzeError;
+use crate::analyze_error::{AnalyzeError, Fix};
 use veryl_parser::miette::Result;
 use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::VerylToken;
 use veryl_parser::veryl_walker::{Handler, HandlerPoint};
 
 const BINARY_CHARS: [char; 6] = ['0', '1', 'x', 'z', 'X', 'Z'];
 const OCTAL_CHARS: [char; 12] = ['0', '1', '2', '3', '4', '5', '6', '7', 'x', 'z', 'X', 'Z'];
 const DECIMAL_CHARS: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+const HEX_CHARS: [char; 26] = [
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f', 'A', 'B', 'C',
+    'D', 'E', 'F', 'x', 'z', 'X', 'Z',
+];
 
 #[derive(Default)]
 pub struct CheckInvalidNumberCharacter<'a> {
@@ -23,6 +28,26 @@ impl<'a> CheckInvalidNumberCharacter<'a> {
     }
 }
 
+/// Change the base to the smallest one which accepts all digits
+fn fixes(token: &VerylToken, number: &str) -> Vec<Fix> {
+    let text = token.text();
+    let (width, tail) = text.split_once('\'').unwrap();
+    let bases: [(&str, &str, &[char]); 4] = [
+        ("b", "binary", &BINARY_CHARS),
+        ("o", "octal", &OCTAL_CHARS),
+        ("d", "decimal", &DECIMAL_CHARS),
+        ("h", "hexadecimal", &HEX_CHARS),
+    ];
+    for (base, kind, chars) in bases {
+        if number.chars().all(|x| chars.contains(&x)) {
+            let fixed = format!("{}'{}{}", width, base, &tail[1..]);
+            let title = format!("Change to {} number", kind);
+            return vec![Fix::new(&title, token.token.pos, text.len(), &fixed)];
+        }
+    }
+    Vec::new()
+}
+
 impl<'a> Handler for CheckInvalidNumberCharacter<'a> {
     fn set_point(&mut self, p: HandlerPoint) {
         self.point = p;
@@ -45,21 +70,33 @@ impl<'a> VerylGrammarTrait for CheckInvalidNumberCharacter<'a> {
                 "b" => {
                     if let Some(x) = number.chars().find(|x| !BINARY_CHARS.contains(x)) {
                         self.errors.push(AnalyzeError::invalid_number_character(
-                            x, "binary", self.text, token,
+                            x,
+                            "binary",
+                            fixes(token, number),
+                            self.text,
+                            token,
                         ));
                     }
                 }
                 "o" => {
                     if let Some(x) = number.chars().find(|x| !OCTAL_CHARS.contains(x)) {
                         self.errors.push(AnalyzeError::invalid_number_character(
-                            x, "octal", self.text, token,
+                            x,
+                            "octal",
+                            fixes(token, number),
+                            self.text,
+                            token,
                         ));
                     }
                 }
                 "d" => {
                     if let Some(x) = number.chars().find(|x| !DECIMAL_CHARS.contains(x)) {
                         self.errors.push(AnalyzeError::invalid_number_character(
-                            x, "decimal", self.text, token,
+                            x,
+                            "decimal",
+                            fixes(token, number),
+                            self.text,
+                            token,
                         ));
                     }
                 }
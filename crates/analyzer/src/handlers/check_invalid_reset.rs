@@ -1,4 +1,4 @@
-use crate::analyze_error::AnalyzeError;
+use crate::analyze_error::{AnalyzeError, Fix};
 use veryl_parser::miette::Result;
 use veryl_parser::veryl_grammar_trait::*;
 use veryl_parser::veryl_walker::{Handler, HandlerPoint};
@@ -19,6 +19,37 @@ impl<'a> CheckInvalidReset<'a> {
     }
 }
 
+impl<'a> CheckInvalidReset<'a> {
+    /// Wrap the existing statements by the else clause of if_reset skeleton
+    fn if_reset_fixes(&self, arg: &AlwaysFfDeclaration) -> Vec<Fix> {
+        let indent = " ".repeat(arg.always_ff.always_ff_token.token.column - 1);
+        let begin = arg.l_brace.l_brace_token.token.pos + 1;
+        let end = arg.r_brace.r_brace_token.token.pos;
+
+        let mut text = format!("\n{indent}    if_reset {{\n{indent}    }} else {{\n");
+        let lines: Vec<_> = self.text[begin..end].lines().collect();
+        let first = lines.iter().position(|x| !x.trim().is_empty());
+        let last = lines.iter().rposition(|x| !x.trim().is_empty());
+        if let (Some(first), Some(last)) = (first, last) {
+            for line in &lines[first..=last] {
+                if line.trim().is_empty() {
+                    text.push('\n');
+                } else {
+                    text.push_str(&format!("    {}\n", line.trim_end()));
+                }
+            }
+        }
+        text.push_str(&format!("{indent}    }}\n{indent}"));
+
+        vec![Fix::new(
+            "Add if_reset statement",
+            begin,
+            end - begin,
+            &text,
+        )]
+    }
+}
+
 impl<'a> Handler for CheckInvalidReset<'a> {
     fn set_point(&mut self, p: HandlerPoint) {
         self.point = p;
@@ -40,6 +71,7 @@ impl<'a> VerylGrammarTrait for CheckInvalidReset<'a> {
             };
             if if_reset_required {
                 self.errors.push(AnalyzeError::if_reset_required(
+                    self.if_reset_fixes(arg),
                     self.text,
                     &arg.always_ff.always_ff_token,
                 ));
@@ -11,6 +11,8 @@ pub struct CheckInvalidStatement<'a> {
     in_always_ff: bool,
     in_always_comb: bool,
     in_function: bool,
+    in_initial: bool,
+    in_final: bool,
     statement_depth_in_always_ff: usize,
 }
 
@@ -39,7 +41,7 @@ impl<'a> VerylGrammarTrait for CheckInvalidStatement<'a> {
 
     fn if_reset_statement(&mut self, arg: &IfResetStatement) -> Result<()> {
         if let HandlerPoint::Before = self.point {
-            if self.in_always_comb || self.in_function {
+            if self.in_always_comb || self.in_function || self.in_initial || self.in_final {
                 self.errors.push(AnalyzeError::invalid_statement(
                     "if_reset",
                     self.text,
@@ -60,7 +62,7 @@ impl<'a> VerylGrammarTrait for CheckInvalidStatement<'a> {
 
     fn return_statement(&mut self, arg: &ReturnStatement) -> Result<()> {
         if let HandlerPoint::Before = self.point {
-            if self.in_always_ff || self.in_always_comb {
+            if self.in_always_ff || self.in_always_comb || self.in_initial || self.in_final {
                 self.errors.push(AnalyzeError::invalid_statement(
                     "return",
                     self.text,
@@ -90,6 +92,22 @@ impl<'a> VerylGrammarTrait for CheckInvalidStatement<'a> {
         Ok(())
     }
 
+    fn initial_declaration(&mut self, _arg: &InitialDeclaration) -> Result<()> {
+        match self.point {
+            HandlerPoint::Before => self.in_initial = true,
+            HandlerPoint::After => self.in_initial = false,
+        }
+        Ok(())
+    }
+
+    fn final_declaration(&mut self, _arg: &FinalDeclaration) -> Result<()> {
+        match self.point {
+            HandlerPoint::Before => self.in_final = true,
+            HandlerPoint::After => self.in_final = false,
+        }
+        Ok(())
+    }
+
     fn function_declaration(&mut self, _arg: &FunctionDeclaration) -> Result<()> {
         match self.point {
             HandlerPoint::Before => self.in_function = true,
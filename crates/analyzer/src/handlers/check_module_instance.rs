@@ -1,16 +1,18 @@
-use crate::analyze_error::AnalyzeError;
+use crate::analyze_error::{AnalyzeError, Fix};
 use crate::namespace_table;
 use crate::symbol::SymbolKind;
 use crate::symbol_table::{self, Name};
 use veryl_parser::miette::Result;
 use veryl_parser::resource_table;
 use veryl_parser::veryl_grammar_trait::*;
-use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+use veryl_parser::veryl_token::VerylToken;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
 
 pub struct CheckModuleInstance<'a> {
     pub errors: Vec<AnalyzeError>,
     text: &'a str,
     point: HandlerPoint,
+    in_test: bool,
 }
 
 impl<'a> CheckModuleInstance<'a> {
@@ -19,10 +21,112 @@ impl<'a> CheckModuleInstance<'a> {
             errors: Vec::new(),
             text,
             point: HandlerPoint::Before,
+            in_test: false,
         }
     }
 }
 
+#[derive(Default)]
+struct LastToken {
+    token: Option<VerylToken>,
+}
+
+impl VerylWalker for LastToken {
+    fn veryl_token(&mut self, arg: &VerylToken) {
+        self.token = Some(arg.clone());
+    }
+}
+
+fn begin(token: &VerylToken) -> usize {
+    token.token.pos
+}
+
+fn end(token: &VerylToken) -> usize {
+    token.token.pos + token.text().len()
+}
+
+fn end_of_item(arg: &InstPortItem) -> usize {
+    let mut last = LastToken::default();
+    last.inst_port_item(arg);
+    end(&last.token.unwrap())
+}
+
+/// Port list of the instance like `(a, b: x)`
+struct PortList<'a> {
+    l_paren: &'a VerylToken,
+    r_paren: &'a VerylToken,
+    /// Items with the preceding comma
+    items: Vec<(Option<&'a VerylToken>, &'a InstPortItem)>,
+    trailing_comma: Option<&'a VerylToken>,
+}
+
+impl<'a> PortList<'a> {
+    fn new(arg: &'a InstDeclarationOpt1) -> Self {
+        let mut items = Vec::new();
+        let mut trailing_comma = None;
+        if let Some(ref x) = arg.inst_declaration_opt2 {
+            let x = &x.inst_port_list;
+            items.push((None, &*x.inst_port_item));
+            for x in &x.inst_port_list_list {
+                items.push((Some(&x.comma.comma_token), &*x.inst_port_item));
+            }
+            trailing_comma = x.inst_port_list_opt.as_ref().map(|x| &x.comma.comma_token);
+        }
+        Self {
+            l_paren: &arg.l_paren.l_paren_token,
+            r_paren: &arg.r_paren.r_paren_token,
+            items,
+            trailing_comma,
+        }
+    }
+
+    /// Insert the implicit connection of `port` after the last item
+    fn add(&self, port: &str) -> Fix {
+        let title = format!("Add \"{}\" port", port);
+        let (first, last) = match (self.items.first(), self.items.last()) {
+            (Some((_, first)), Some((_, last))) => (first, last),
+            _ => return Fix::new(&title, end(self.l_paren), 0, port),
+        };
+
+        let first = &first.identifier.identifier_token;
+        if first.token.line != self.l_paren.token.line {
+            let indent = " ".repeat(first.token.column - 1);
+            if let Some(comma) = self.trailing_comma {
+                Fix::new(&title, end(comma), 0, &format!("\n{indent}{port},"))
+            } else {
+                let text = format!(",\n{indent}{port},");
+                Fix::new(&title, end_of_item(last), 0, &text)
+            }
+        } else {
+            Fix::new(&title, end_of_item(last), 0, &format!(", {port}"))
+        }
+    }
+
+    /// Remove the item and the adjacent comma
+    fn remove(&self, index: usize, port: &str) -> Fix {
+        let title = format!("Remove \"{}\" port", port);
+        let (begin, end) = if self.items.len() == 1 {
+            (end(self.l_paren), begin(self.r_paren))
+        } else if index == 0 {
+            let next = &self.items[1].1.identifier.identifier_token;
+            (
+                begin(&self.items[0].1.identifier.identifier_token),
+                begin(next),
+            )
+        } else {
+            let end = if let Some((Some(comma), _)) = self.items.get(index + 1) {
+                begin(comma)
+            } else if let Some(comma) = self.trailing_comma {
+                begin(comma)
+            } else {
+                begin(self.r_paren)
+            };
+            (begin(self.items[index].0.unwrap()), end)
+        };
+        Fix::new(&title, begin, end - begin, "")
+    }
+}
+
 impl<'a> Handler for CheckModuleInstance<'a> {
     fn set_point(&mut self, p: HandlerPoint) {
         self.point = p;
@@ -34,45 +138,60 @@ impl<'a> VerylGrammarTrait for CheckModuleInstance<'a> {
         if let HandlerPoint::Before = self.point {
             let name = &Name::Hierarchical(vec![arg.identifier0.identifier_token.token.text]);
 
-            let mut connected_ports = Vec::new();
-            if let Some(ref x) = arg.inst_declaration_opt1 {
-                if let Some(ref x) = x.inst_declaration_opt2 {
-                    let x = &x.inst_port_list;
-                    connected_ports.push(x.inst_port_item.identifier.identifier_token.token.text);
-                    for x in &x.inst_port_list_list {
-                        connected_ports
-                            .push(x.inst_port_item.identifier.identifier_token.token.text);
-                    }
-                }
-            }
+            let port_list = arg.inst_declaration_opt1.as_ref().map(|x| PortList::new(x));
+            let connected_ports: Vec<_> = port_list
+                .as_ref()
+                .map(|x| {
+                    x.items
+                        .iter()
+                        .map(|(_, x)| x.identifier.identifier_token.token.text)
+                        .collect()
+                })
+                .unwrap_or_default();
 
             let namespace = namespace_table::get(arg.identifier.identifier_token.token.id).unwrap();
             let symbol = symbol_table::get(name, &namespace);
             if let Some(symbol) = symbol {
                 if let SymbolKind::Module(x) = symbol.kind {
+                    // ports of the instance in test are driven by the test
                     for port in &x.ports {
-                        if !connected_ports.contains(&port.name) {
+                        if !self.in_test && !connected_ports.contains(&port.name) {
                             let name =
                                 resource_table::get_str_value(*name.as_slice().last().unwrap())
                                     .unwrap();
                             let port = resource_table::get_str_value(port.name).unwrap();
+                            let fix = if let Some(ref x) = port_list {
+                                x.add(&port)
+                            } else {
+                                let semicolon = &arg.semicolon.semicolon_token;
+                                let text = format!(" ({})", port);
+                                Fix::new(
+                                    &format!("Add \"{}\" port", port),
+                                    begin(semicolon),
+                                    0,
+                                    &text,
+                                )
+                            };
                             self.errors.push(AnalyzeError::missing_port(
                                 &name,
                                 &port,
+                                vec![fix],
                                 self.text,
                                 &arg.identifier.identifier_token,
                             ));
                         }
                     }
-                    for port in &connected_ports {
+                    for (i, port) in connected_ports.iter().enumerate() {
                         if !x.ports.iter().any(|x| &x.name == port) {
                             let name =
                                 resource_table::get_str_value(*name.as_slice().last().unwrap())
                                     .unwrap();
                             let port = resource_table::get_str_value(*port).unwrap();
+                            let fix = port_list.as_ref().unwrap().remove(i, &port);
                             self.errors.push(AnalyzeError::unknown_port(
                                 &name,
                                 &port,
+                                vec![fix],
                                 self.text,
                                 &arg.identifier.identifier_token,
                             ));
@@ -93,4 +212,12 @@ impl<'a> VerylGrammarTrait for CheckModuleInstance<'a> {
         }
         Ok(())
     }
+
+    fn test_declaration(&mut self, _arg: &TestDeclaration) -> Result<()> {
+        match self.point {
+            HandlerPoint::Before => self.in_test = true,
+            HandlerPoint::After => self.in_test = false,
+        }
+        Ok(())
+    }
 }
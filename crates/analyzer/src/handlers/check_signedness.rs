@@ -0,0 +1,356 @@
+use crate::analyze_error::AnalyzeError;
+use crate::namespace::Namespace;
+use crate::namespace_table;
+use crate::symbol::Type as SymType;
+use crate::symbol::{SymbolKind, TypeKind};
+use crate::symbol_table::{self, Name};
+use veryl_parser::miette::Result;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::VerylToken;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
+
+pub struct CheckSignedness<'a> {
+    pub errors: Vec<AnalyzeError>,
+    pub warnings: Vec<AnalyzeError>,
+    text: &'a str,
+    point: HandlerPoint,
+}
+
+impl<'a> CheckSignedness<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            text,
+            point: HandlerPoint::Before,
+        }
+    }
+
+    /// Warn if signed and unsigned operands are mixed at the same level of expression
+    fn check(&mut self, signs: &[Option<bool>], operator: &VerylToken) {
+        if signs.contains(&Some(true)) && signs.contains(&Some(false)) {
+            self.warnings.push(AnalyzeError::mixed_signedness(
+                &operator.text(),
+                self.text,
+                operator,
+            ));
+        }
+    }
+}
+
+impl<'a> Handler for CheckSignedness<'a> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl<'a> VerylGrammarTrait for CheckSignedness<'a> {
+    fn expression06(&mut self, arg: &Expression06) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            if let Some(x) = arg.expression06_list.first() {
+                let mut signs = vec![Operand::sign(|y| y.expression07(&arg.expression07))];
+                for x in &arg.expression06_list {
+                    signs.push(Operand::sign(|y| y.expression07(&x.expression07)));
+                }
+                self.check(&signs, &x.operator06.operator06_token);
+            }
+        }
+        Ok(())
+    }
+
+    fn expression07(&mut self, arg: &Expression07) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            if let Some(x) = arg.expression07_list.first() {
+                let mut signs = vec![Operand::sign(|y| y.expression08(&arg.expression08))];
+                for x in &arg.expression07_list {
+                    signs.push(Operand::sign(|y| y.expression08(&x.expression08)));
+                }
+                self.check(&signs, &x.operator07.operator07_token);
+            }
+        }
+        Ok(())
+    }
+
+    fn expression09(&mut self, arg: &Expression09) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            if let Some(x) = arg.expression09_list.first() {
+                let mut signs = vec![Operand::sign(|y| y.expression10(&arg.expression10))];
+                for x in &arg.expression09_list {
+                    signs.push(Operand::sign(|y| y.expression10(&x.expression10)));
+                }
+                self.check(&signs, &x.operator09.operator09_token);
+            }
+        }
+        Ok(())
+    }
+
+    fn expression10(&mut self, arg: &Expression10) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            if let Some(x) = arg.expression10_list.first() {
+                let mut signs = vec![Operand::sign(|y| y.expression11(&arg.expression11))];
+                for x in &arg.expression10_list {
+                    signs.push(Operand::sign(|y| y.expression11(&x.expression11)));
+                }
+                let operator = match &*x.expression10_list_group {
+                    Expression10ListGroup::Operator10(x) => &x.operator10.operator10_token,
+                    Expression10ListGroup::Star(x) => &x.star.star_token,
+                };
+                self.check(&signs, operator);
+            }
+        }
+        Ok(())
+    }
+
+    fn r#type(&mut self, arg: &Type) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            for x in &arg.type_list {
+                if let TypeModifier::Signed(x) = &*x.type_modifier {
+                    let r#type: SymType = arg.into();
+                    if !matches!(r#type.kind, TypeKind::Logic | TypeKind::Bit) {
+                        let name = format!("{}", r#type).replacen("signed ", "", 1);
+                        self.errors.push(AnalyzeError::invalid_signed_modifier(
+                            &name,
+                            self.text,
+                            &x.signed.signed_token,
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Signedness of an operand gathered from its leaf factors
+#[derive(Default)]
+struct Operand {
+    signed: bool,
+    unsigned: bool,
+    unknown: bool,
+}
+
+impl Operand {
+    /// `Some(true)` if signed, `Some(false)` if unsigned, and `None` if it can't be decided.
+    /// Mixed operand is `None` because it is reported at the inner expression.
+    fn sign<F: FnOnce(&mut Operand)>(walk: F) -> Option<bool> {
+        let mut operand = Operand::default();
+        walk(&mut operand);
+        match (operand.signed, operand.unsigned, operand.unknown) {
+            (true, false, false) => Some(true),
+            (false, true, false) => Some(false),
+            _ => None,
+        }
+    }
+
+    fn push(&mut self, sign: Option<bool>) {
+        match sign {
+            Some(true) => self.signed = true,
+            Some(false) => self.unsigned = true,
+            None => self.unknown = true,
+        }
+    }
+
+    fn identifier_sign(arg: &FactorScopedOrHierIdentifierFactorOpt) -> Option<bool> {
+        // function call
+        if arg.factor_opt.is_some() {
+            return None;
+        }
+        let x = &*arg.scoped_or_hier_identifier;
+        let selects = match &*x.scoped_or_hier_identifier_group {
+            ScopedOrHierIdentifierGroup::ScopedOrHierIdentifierGroupList0ScopedOrHierIdentifierGroupList1(x) => {
+                // members of struct and interface have no symbol
+                if !x.scoped_or_hier_identifier_group_list1.is_empty() {
+                    return None;
+                }
+                x.scoped_or_hier_identifier_group_list0.len()
+            }
+            ScopedOrHierIdentifierGroup::ColonColonIdentifierScopedOrHierIdentifierGroupList(_) => 0,
+        };
+        let namespace = namespace_table::get(x.identifier.identifier_token.token.id)?;
+        let symbol = symbol_table::get(&x.into(), &namespace)?;
+        let r#type = match symbol.kind {
+            SymbolKind::Variable(x) => x.r#type,
+            SymbolKind::Port(x) => x.r#type?,
+            SymbolKind::Parameter(x) => x.r#type,
+            _ => return None,
+        };
+        // bit-select and part-select are unsigned
+        if selects > r#type.array.len() {
+            Some(false)
+        } else {
+            type_sign(&r#type, &symbol.namespace)
+        }
+    }
+}
+
+/// Signedness of the type through type aliases
+fn type_sign(r#type: &SymType, namespace: &Namespace) -> Option<bool> {
+    let mut r#type = r#type.clone();
+    let mut namespace = namespace.clone();
+    // the depth is limited to stop recursive type aliases
+    for _ in 0..16 {
+        if let TypeKind::UserDefined(ref paths) = r#type.kind {
+            let symbol = symbol_table::get(&Name::Scoped(paths.clone()), &namespace)?;
+            if let SymbolKind::Type(x) = symbol.kind {
+                r#type = x.r#type;
+                namespace = symbol.namespace;
+            } else {
+                return None;
+            }
+        } else {
+            return r#type.is_signed();
+        }
+    }
+    None
+}
+
+impl VerylWalker for Operand {
+    fn expression(&mut self, arg: &Expression) {
+        match arg {
+            Expression::IfExpression(_) => self.push(None),
+            Expression::Expression01(x) => self.expression01(&x.expression01),
+        }
+    }
+
+    /// Logical operation is unsigned
+    fn expression01(&mut self, arg: &Expression01) {
+        if arg.expression01_list.is_empty() {
+            self.expression02(&arg.expression02);
+        } else {
+            self.push(Some(false));
+        }
+    }
+
+    /// Logical operation is unsigned
+    fn expression02(&mut self, arg: &Expression02) {
+        if arg.expression02_list.is_empty() {
+            self.expression03(&arg.expression03);
+        } else {
+            self.push(Some(false));
+        }
+    }
+
+    /// Equality is unsigned
+    fn expression06(&mut self, arg: &Expression06) {
+        if arg.expression06_list.is_empty() {
+            self.expression07(&arg.expression07);
+        } else {
+            self.push(Some(false));
+        }
+    }
+
+    /// Relation is unsigned
+    fn expression07(&mut self, arg: &Expression07) {
+        if arg.expression07_list.is_empty() {
+            self.expression08(&arg.expression08);
+        } else {
+            self.push(Some(false));
+        }
+    }
+
+    /// Shift amount doesn't affect the signedness
+    fn expression08(&mut self, arg: &Expression08) {
+        self.expression09(&arg.expression09);
+    }
+
+    fn expression12(&mut self, arg: &Expression12) {
+        if let Some(ref x) = arg.expression12_opt {
+            let namespace = match &*x.r#type.type_group {
+                TypeGroup::ScopedIdentifier(x) => {
+                    namespace_table::get(x.scoped_identifier.identifier.identifier_token.token.id)
+                }
+                _ => Some(Namespace::default()),
+            };
+            let r#type: SymType = (&*x.r#type).into();
+            self.push(namespace.and_then(|x| type_sign(&r#type, &x)));
+            return;
+        }
+        for x in &arg.expression12_list {
+            let reduction = match &*x.expression12_list_group {
+                Expression12ListGroup::UnaryOperator(x) => {
+                    x.unary_operator.unary_operator_token.text() != "~"
+                }
+                Expression12ListGroup::Operator09(_) => false,
+                Expression12ListGroup::Operator03(_)
+                | Expression12ListGroup::Operator04(_)
+                | Expression12ListGroup::Operator05(_) => true,
+            };
+            // reduction and logical negation are unsigned
+            if reduction {
+                self.push(Some(false));
+                return;
+            }
+        }
+        match &*arg.factor {
+            // numbers follow the other operands
+            Factor::Number(_) => (),
+            Factor::ScopedOrHierIdentifierFactorOpt(x) => self.push(Operand::identifier_sign(x)),
+            Factor::SystemFunctionCall(x) => {
+                let sign = match x
+                    .system_function_call
+                    .dollar_identifier
+                    .dollar_identifier_token
+                    .text()
+                    .as_str()
+                {
+                    "$signed" => Some(true),
+                    "$unsigned" => Some(false),
+                    _ => None,
+                };
+                self.push(sign);
+            }
+            Factor::LParenExpressionRParen(x) => self.expression(&x.expression),
+            Factor::LBraceConcatenationListRBrace(_) => self.push(Some(false)),
+            Factor::StringLiteral(_) => self.push(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analyze_error::AnalyzeError;
+    use crate::analyzer::Analyzer;
+    use veryl_parser::Parser;
+
+    const CODE: &str = r#"
+module ModuleA (
+    i_a: input signed logic [8],
+    i_b: input logic [8],
+    i_c: input i32,
+) {
+    type sword = signed logic [16];
+
+    var a: sword;
+    var b: logic;
+    var c: logic;
+    var d: logic;
+    var e: signed u32;
+
+    assign a = i_a + i_b;
+    assign b = i_a < i_c;
+    assign c = i_a < (i_b as signed logic [8]);
+    assign d = a * i_b[3:0] == 1;
+}
+"#;
+
+    #[test]
+    fn signedness() {
+        let parser = Parser::parse(CODE, &"").unwrap();
+        let mut analyzer = Analyzer::new(CODE);
+        let errors: Vec<_> = analyzer
+            .analyze(&parser.veryl)
+            .iter()
+            .map(|x| format!("{}", x))
+            .collect();
+        assert_eq!(errors, vec!["\"signed\" can't be applied to \"u32\""]);
+        let warnings: Vec<_> = analyzer
+            .take_warnings()
+            .iter()
+            .map(|x| match x {
+                AnalyzeError::MixedSignedness { operator, .. } => operator.clone(),
+                _ => format!("{}", x),
+            })
+            .collect();
+        assert_eq!(warnings, vec!["+", "*"]);
+    }
+}
@@ -1,8 +1,8 @@
 use crate::analyze_error::AnalyzeError;
 use veryl_parser::miette::Result;
 use veryl_parser::veryl_grammar_trait::*;
-use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
-use veryl_parser::Stringifier;
+use veryl_parser::veryl_token::VerylToken;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
 
 #[derive(Default)]
 pub struct CheckSystemFunction<'a> {
@@ -18,6 +18,98 @@ impl<'a> CheckSystemFunction<'a> {
             ..Default::default()
         }
     }
+
+    fn check(&mut self, name: &str, token: &VerylToken, args: Option<&FunctionCallArg>) {
+        if !DEFINED_SYSTEM_FUNCTIONS.contains(&name) {
+            self.errors.push(AnalyzeError::invalid_system_function(
+                name, self.text, token,
+            ));
+            return;
+        }
+
+        if FORMAT_SYSTEM_FUNCTIONS.contains(&name) {
+            if let Some(args) = args {
+                self.check_format(name, token, args);
+            }
+        }
+    }
+
+    /// The first string literal argument is the format, and the rest are its arguments
+    fn check_format(&mut self, name: &str, token: &VerylToken, args: &FunctionCallArg) {
+        let mut exprs = vec![&*args.expression];
+        for x in &args.function_call_arg_list {
+            exprs.push(&*x.expression);
+        }
+
+        let strings: Vec<_> = exprs.iter().map(|x| string_literal(x)).collect();
+        let format = strings.iter().position(|x| x.is_some());
+        if let Some(format) = format {
+            // multiple format strings are not checked
+            if strings[format + 1..].iter().any(|x| x.is_some()) {
+                return;
+            }
+
+            let literal = strings[format].unwrap();
+            let text = literal.text();
+            match format_specifiers(&text[1..text.len() - 1]) {
+                Ok(specifiers) => {
+                    let args = exprs.len() - format - 1;
+                    // arguments without specifier are allowed for default format
+                    if specifiers != 0 && specifiers != args {
+                        self.errors.push(AnalyzeError::mismatch_format_args(
+                            name, specifiers, args, self.text, token,
+                        ));
+                    }
+                }
+                Err(specifier) => {
+                    self.errors.push(AnalyzeError::invalid_format_specifier(
+                        &specifier, self.text, literal,
+                    ));
+                }
+            }
+        }
+    }
+}
+
+fn string_literal(arg: &Expression) -> Option<&VerylToken> {
+    if let Some(Factor::StringLiteral(x)) = arg.single_factor() {
+        Some(&x.string_literal.string_literal_token)
+    } else {
+        None
+    }
+}
+
+/// Number of format specifiers which consume an argument
+fn format_specifiers(text: &str) -> std::result::Result<usize, String> {
+    let mut ret = 0;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '%' => {
+                let mut specifier = "%".to_string();
+                for c in chars.by_ref() {
+                    specifier.push(c);
+                    if !(c.is_ascii_digit() || c == '.' || c == '-') {
+                        break;
+                    }
+                }
+                match specifier.chars().last().map(|x| x.to_ascii_lowercase()) {
+                    Some('%') if specifier == "%%" => (),
+                    Some('l' | 'm') => (),
+                    Some(
+                        'b' | 'c' | 'd' | 'e' | 'f' | 'g' | 'h' | 'o' | 'p' | 's' | 't' | 'u' | 'v'
+                        | 'x' | 'z',
+                    ) => ret += 1,
+                    _ => return Err(specifier),
+                }
+            }
+            _ => (),
+        }
+    }
+    Ok(ret)
 }
 
 impl<'a> Handler for CheckSystemFunction<'a> {
@@ -27,27 +119,66 @@ impl<'a> Handler for CheckSystemFunction<'a> {
 }
 
 impl<'a> VerylGrammarTrait for CheckSystemFunction<'a> {
-    fn factor(&mut self, arg: &Factor) -> Result<()> {
-        if let Factor::FactorOptScopedOrHierIdentifierFactorOpt0(x) = arg {
-            if x.factor_opt.is_some() {
-                let mut stringifier = Stringifier::new();
-                stringifier.scoped_or_hier_identifier(&x.scoped_or_hier_identifier);
-                match stringifier.as_str() {
-                    name if DEFINED_SYSTEM_FUNCTIONS.contains(&name) => (),
-                    name => {
-                        self.errors.push(AnalyzeError::invalid_system_function(
-                            name,
-                            self.text,
-                            &x.scoped_or_hier_identifier.identifier.identifier_token,
-                        ));
-                    }
-                }
-            }
+    fn system_function_call(&mut self, arg: &SystemFunctionCall) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            let token = &arg.dollar_identifier.dollar_identifier_token;
+            let args = arg
+                .system_function_call_opt
+                .as_ref()
+                .and_then(|x| x.system_function_call_opt0.as_ref())
+                .map(|x| &*x.function_call_arg);
+            self.check(&token.text()[1..], token, args);
         }
         Ok(())
     }
 }
 
+// system functions which take a format string
+const FORMAT_SYSTEM_FUNCTIONS: [&str; 42] = [
+    "display",
+    "displayb",
+    "displayh",
+    "displayo",
+    "error",
+    "fatal",
+    "fdisplay",
+    "fdisplayb",
+    "fdisplayh",
+    "fdisplayo",
+    "fmonitor",
+    "fmonitorb",
+    "fmonitorh",
+    "fmonitoro",
+    "fstrobe",
+    "fstrobeb",
+    "fstrobeh",
+    "fstrobeo",
+    "fwrite",
+    "fwriteb",
+    "fwriteh",
+    "fwriteo",
+    "info",
+    "monitor",
+    "monitorb",
+    "monitorh",
+    "monitoro",
+    "sformat",
+    "sformatf",
+    "strobe",
+    "strobeb",
+    "strobeh",
+    "strobeo",
+    "swrite",
+    "swriteb",
+    "swriteh",
+    "swriteo",
+    "warning",
+    "write",
+    "writeb",
+    "writeh",
+    "writeo",
+];
+
 // Refer IEEE Std 1800-2012  Clause 20 and 21
 const DEFINED_SYSTEM_FUNCTIONS: [&str; 196] = [
     "acos",
@@ -247,3 +378,61 @@ const DEFINED_SYSTEM_FUNCTIONS: [&str; 196] = [
     "writememh",
     "writeo",
 ];
+
+#[cfg(test)]
+mod tests {
+    use crate::{AnalyzeError, Analyzer};
+    use veryl_parser::Parser;
+
+    const CODE: &str = r#"
+module ModuleA {
+    var a: logic [8];
+    var b: logic [8];
+
+    initial {
+        $display("a = %d, b = %0h", a, b);
+        $display("a = %d, b = %h", a);
+        $display("a = ", a);
+        $display("%m: 100%% done");
+        $fatal(1, "a = %-4d", a, b);
+        $write("a = %q", a);
+        $fdisplay(b, "%s");
+        $finishes;
+    }
+
+    assign b = $sformatf("%d %d", a);
+}
+"#;
+
+    #[test]
+    fn format() {
+        let parser = Parser::parse(CODE, &"").unwrap();
+        let mut analyzer = Analyzer::new(CODE);
+        let errors = analyzer.analyze(&parser.veryl);
+        let errors: Vec<_> = errors
+            .iter()
+            .filter_map(|x| match x {
+                AnalyzeError::MismatchFormatArgs {
+                    name,
+                    specifiers,
+                    args,
+                    ..
+                } => Some(format!("{} {} {}", name, specifiers, args)),
+                AnalyzeError::InvalidFormatSpecifier { specifier, .. } => Some(specifier.clone()),
+                AnalyzeError::InvalidSystemFunction { name, .. } => Some(name.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                "display 2 1",
+                "fatal 1 2",
+                "%q",
+                "fdisplay 1 0",
+                "finishes",
+                "sformatf 2 1"
+            ]
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use crate::analyze_error::AnalyzeError;
+use crate::namespace_table;
+use crate::symbol::{SymbolKind, TypeKind};
+use crate::symbol_table::{self, Name};
+use veryl_parser::miette::Result;
+use veryl_parser::resource_table;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::{Token, VerylToken};
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+
+pub struct CheckTypeParameter<'a> {
+    pub errors: Vec<AnalyzeError>,
+    text: &'a str,
+    point: HandlerPoint,
+}
+
+impl<'a> CheckTypeParameter<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            errors: Vec::new(),
+            text,
+            point: HandlerPoint::Before,
+        }
+    }
+
+    fn check(&mut self, expected: ValueKind, actual: ValueKind, token: &VerylToken) {
+        if actual != ValueKind::Unknown && actual != expected {
+            let name = resource_table::get_str_value(token.token.text).unwrap();
+            self.errors.push(AnalyzeError::mismatch_type(
+                &name,
+                expected.to_str(),
+                actual.to_str(),
+                self.text,
+                token,
+            ));
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ValueKind {
+    Type,
+    Expression,
+    /// Identifiers which can't be resolved like struct and enum
+    Unknown,
+}
+
+impl ValueKind {
+    fn to_str(self) -> &'static str {
+        match self {
+            ValueKind::Type => "type",
+            ValueKind::Expression => "expression",
+            ValueKind::Unknown => "unknown",
+        }
+    }
+
+    fn from_symbol(tokens: &[Token], scoped: bool) -> Self {
+        let namespace = match namespace_table::get(tokens[0].id) {
+            Some(x) => x,
+            None => return ValueKind::Unknown,
+        };
+        let paths = tokens.iter().map(|x| x.text).collect();
+        let name = if scoped {
+            Name::Scoped(paths)
+        } else {
+            Name::Hierarchical(paths)
+        };
+        match symbol_table::get(&name, &namespace).map(|x| x.kind) {
+            Some(SymbolKind::Type(_)) => ValueKind::Type,
+            Some(SymbolKind::Parameter(x)) if matches!(x.r#type.kind, TypeKind::Type) => {
+                ValueKind::Type
+            }
+            Some(SymbolKind::Parameter(_))
+            | Some(SymbolKind::Variable(_))
+            | Some(SymbolKind::Port(_)) => ValueKind::Expression,
+            _ => ValueKind::Unknown,
+        }
+    }
+
+    fn from_parameter_value(arg: &ParameterValue) -> Self {
+        let arg = match arg {
+            ParameterValue::Expression(x) => &x.expression,
+            ParameterValue::TypeValue(_) => return ValueKind::Type,
+        };
+        match arg.single_factor() {
+            Some(Factor::ScopedOrHierIdentifierFactorOpt(x)) if x.factor_opt.is_none() => {
+                let x = &x.scoped_or_hier_identifier;
+                let mut tokens = vec![x.identifier.identifier_token.token];
+                match &*x.scoped_or_hier_identifier_group {
+                    ScopedOrHierIdentifierGroup::ColonColonIdentifierScopedOrHierIdentifierGroupList(x) => {
+                        tokens.push(x.identifier.identifier_token.token);
+                        for x in &x.scoped_or_hier_identifier_group_list {
+                            tokens.push(x.identifier.identifier_token.token);
+                        }
+                        ValueKind::from_symbol(&tokens, true)
+                    }
+                    ScopedOrHierIdentifierGroup::ScopedOrHierIdentifierGroupList0ScopedOrHierIdentifierGroupList1(x) => {
+                        // selected or member access can't be a type
+                        if !x.scoped_or_hier_identifier_group_list0.is_empty()
+                            || !x.scoped_or_hier_identifier_group_list1.is_empty()
+                        {
+                            return ValueKind::Expression;
+                        }
+                        ValueKind::from_symbol(&tokens, false)
+                    }
+                }
+            }
+            _ => ValueKind::Expression,
+        }
+    }
+}
+
+impl<'a> Handler for CheckTypeParameter<'a> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl<'a> VerylGrammarTrait for CheckTypeParameter<'a> {
+    fn with_parameter_item(&mut self, arg: &WithParameterItem) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            let expected = match &*arg.with_parameter_item_group0 {
+                WithParameterItemGroup0::Typ(_) => ValueKind::Type,
+                WithParameterItemGroup0::Type(_) => ValueKind::Expression,
+            };
+            let actual = ValueKind::from_parameter_value(&arg.parameter_value);
+            self.check(expected, actual, &arg.identifier.identifier_token);
+        }
+        Ok(())
+    }
+
+    fn inst_declaration(&mut self, arg: &InstDeclaration) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            let x = match arg.inst_declaration_opt0 {
+                Some(ref x) => x,
+                None => return Ok(()),
+            };
+            let x = match x.inst_parameter.inst_parameter_opt {
+                Some(ref x) => &x.inst_parameter_list,
+                None => return Ok(()),
+            };
+
+            let name = &Name::Hierarchical(vec![arg.identifier0.identifier_token.token.text]);
+            let namespace = namespace_table::get(arg.identifier.identifier_token.token.id).unwrap();
+            let parameters = match symbol_table::get(name, &namespace).map(|x| x.kind) {
+                Some(SymbolKind::Module(x)) => x.parameters,
+                Some(SymbolKind::Interface(x)) => x.parameters,
+                _ => return Ok(()),
+            };
+
+            let mut items = vec![&*x.inst_parameter_item];
+            for x in &x.inst_parameter_list_list {
+                items.push(&*x.inst_parameter_item);
+            }
+            for item in items {
+                let token = &item.identifier.identifier_token;
+                let parameter = parameters.iter().find(|x| x.name == token.token.text);
+                if let Some(parameter) = parameter {
+                    let expected = if let TypeKind::Type = parameter.property.r#type.kind {
+                        ValueKind::Type
+                    } else {
+                        ValueKind::Expression
+                    };
+                    // implicit override refers the identifier which has the same name
+                    let actual = if let Some(ref x) = item.inst_parameter_item_opt {
+                        ValueKind::from_parameter_value(&x.parameter_value)
+                    } else {
+                        ValueKind::from_symbol(&[token.token], false)
+                    };
+                    self.check(expected, actual, token);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AnalyzeError, Analyzer};
+    use veryl_parser::Parser;
+
+    const CODE: &str = r#"
+module ModuleA #(
+    parameter T: type = logic,
+    parameter W: u32  = 1,
+    parameter X: type = W,
+) {}
+
+module ModuleB {
+    type word_t = logic [32];
+
+    inst u0: ModuleA #(
+        T: word_t,
+        W: 8,
+    );
+
+    inst u1: ModuleA #(
+        T: 8,
+        W: logic [8],
+    );
+}
+"#;
+
+    #[test]
+    fn mismatch() {
+        let parser = Parser::parse(CODE, &"").unwrap();
+        let mut analyzer = Analyzer::new(CODE);
+        let errors = analyzer.analyze(&parser.veryl);
+        let errors: Vec<_> = errors
+            .iter()
+            .map(|x| match x {
+                AnalyzeError::MismatchType {
+                    name,
+                    expected,
+                    actual,
+                    ..
+                } => format!("{}: {} {}", name, expected, actual),
+                _ => format!("{}", x),
+            })
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                "X: type expression",
+                "T: type expression",
+                "W: expression type"
+            ]
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use crate::namespace::Namespace;
+use crate::namespace_table;
+use crate::reference_table::{self, Reference, ReferenceKind};
+use crate::symbol_table::Name;
+use veryl_parser::miette::Result;
+use veryl_parser::resource_table::StrId;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::Token;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+
+#[derive(Default)]
+pub struct CreateReferenceTable {
+    point: HandlerPoint,
+    inst_type: Option<StrId>,
+}
+
+impl CreateReferenceTable {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Insert references to each element of the path
+    fn insert_path(&mut self, tokens: &[Token], scoped: bool) {
+        if let Some(namespace) = namespace_table::get(tokens[0].id) {
+            for i in 0..tokens.len() {
+                let paths = tokens[0..=i].iter().map(|x| x.text).collect();
+                let name = if scoped {
+                    Name::Scoped(paths)
+                } else {
+                    Name::Hierarchical(paths)
+                };
+                let reference =
+                    Reference::new(&tokens[i], ReferenceKind::Explicit, name, &namespace);
+                reference_table::insert(reference);
+            }
+        }
+    }
+
+    /// Insert references of the port or parameter name of instance
+    fn insert_inst_item(&mut self, token: &Token, implicit: bool) {
+        if let Some(inst_type) = self.inst_type {
+            let mut namespace = Namespace::default();
+            namespace.push(inst_type);
+            let name = Name::Hierarchical(vec![token.text]);
+            let kind = if implicit {
+                ReferenceKind::ImplicitPort
+            } else {
+                ReferenceKind::Explicit
+            };
+            reference_table::insert(Reference::new(token, kind, name, &namespace));
+        }
+
+        // implicit connection refers the variable which has the same name
+        if implicit {
+            if let Some(namespace) = namespace_table::get(token.id) {
+                let name = Name::Hierarchical(vec![token.text]);
+                let kind = ReferenceKind::ImplicitConnection;
+                reference_table::insert(Reference::new(token, kind, name, &namespace));
+            }
+        }
+    }
+}
+
+impl Handler for CreateReferenceTable {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl VerylGrammarTrait for CreateReferenceTable {
+    fn hierarchical_identifier(&mut self, arg: &HierarchicalIdentifier) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            let mut tokens = vec![arg.identifier.identifier_token.token];
+            for x in &arg.hierarchical_identifier_list0 {
+                tokens.push(x.identifier.identifier_token.token);
+            }
+            self.insert_path(&tokens, false);
+        }
+        Ok(())
+    }
+
+    fn scoped_identifier(&mut self, arg: &ScopedIdentifier) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            let mut tokens = vec![arg.identifier.identifier_token.token];
+            for x in &arg.scoped_identifier_list {
+                tokens.push(x.identifier.identifier_token.token);
+            }
+            self.insert_path(&tokens, true);
+        }
+        Ok(())
+    }
+
+    fn scoped_or_hier_identifier(&mut self, arg: &ScopedOrHierIdentifier) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            let mut tokens = vec![arg.identifier.identifier_token.token];
+            let scoped = match &*arg.scoped_or_hier_identifier_group {
+                ScopedOrHierIdentifierGroup::ColonColonIdentifierScopedOrHierIdentifierGroupList(x) => {
+                    tokens.push(x.identifier.identifier_token.token);
+                    for x in &x.scoped_or_hier_identifier_group_list {
+                        tokens.push(x.identifier.identifier_token.token);
+                    }
+                    true
+                }
+                ScopedOrHierIdentifierGroup::ScopedOrHierIdentifierGroupList0ScopedOrHierIdentifierGroupList1(x) => {
+                    for x in &x.scoped_or_hier_identifier_group_list1 {
+                        tokens.push(x.identifier.identifier_token.token);
+                    }
+                    false
+                }
+            };
+            self.insert_path(&tokens, scoped);
+        }
+        Ok(())
+    }
+
+    fn inst_declaration(&mut self, arg: &InstDeclaration) -> Result<()> {
+        match self.point {
+            HandlerPoint::Before => {
+                let token = arg.identifier0.identifier_token.token;
+                self.insert_path(&[token], false);
+                self.inst_type = Some(token.text);
+            }
+            HandlerPoint::After => self.inst_type = None,
+        }
+        Ok(())
+    }
+
+    fn inst_parameter_item(&mut self, arg: &InstParameterItem) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            let implicit = arg.inst_parameter_item_opt.is_none();
+            self.insert_inst_item(&arg.identifier.identifier_token.token, implicit);
+        }
+        Ok(())
+    }
+
+    fn inst_port_item(&mut self, arg: &InstPortItem) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            let implicit = arg.inst_port_item_opt.is_none();
+            self.insert_inst_item(&arg.identifier.identifier_token.token, implicit);
+        }
+        Ok(())
+    }
+}
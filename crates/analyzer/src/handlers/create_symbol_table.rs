@@ -1,18 +1,21 @@
 use crate::analyze_error::AnalyzeError;
+use crate::attribute::{Attribute, AttributeError};
 use crate::namespace::Namespace;
 use crate::namespace_table;
 use crate::symbol::Direction as SymDirection;
 use crate::symbol::Type as SymType;
 use crate::symbol::{
-    FunctionProperty, InstanceProperty, InterfaceProperty, ModuleProperty, ParameterProperty,
-    ParameterScope, PortProperty, Symbol, SymbolKind, VariableProperty,
+    DocComment, FunctionProperty, InstanceProperty, InterfaceProperty, ModuleProperty,
+    ParameterProperty, ParameterScope, PortProperty, Symbol, SymbolKind, TypeProperty,
+    VariableProperty,
 };
 use crate::symbol_table;
+use std::collections::HashMap;
 use veryl_parser::miette::Result;
-use veryl_parser::resource_table::{self, StrId};
+use veryl_parser::resource_table::{self, StrId, TokenId};
 use veryl_parser::veryl_grammar_trait::*;
 use veryl_parser::veryl_token::VerylToken;
-use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
 
 #[derive(Default)]
 pub struct CreateSymbolTable<'a> {
@@ -21,6 +24,40 @@ pub struct CreateSymbolTable<'a> {
     point: HandlerPoint,
     namespace: Namespace,
     default_block: Option<StrId>,
+    attributes: Vec<Attribute>,
+    doc_comments: HashMap<TokenId, Vec<String>>,
+    doc_comment: Vec<String>,
+}
+
+/// Doc comments keyed by the first token after them
+#[derive(Default)]
+struct DocComments {
+    table: HashMap<TokenId, Vec<String>>,
+    lines: Vec<String>,
+}
+
+impl VerylWalker for DocComments {
+    fn veryl_token(&mut self, arg: &VerylToken) {
+        if !self.lines.is_empty() {
+            self.table
+                .insert(arg.token.id, std::mem::take(&mut self.lines));
+        }
+        for comment in &arg.comments {
+            let text = resource_table::get_str_value(comment.text).unwrap();
+            // trailing comment of the token or ordinary comment breaks the block
+            // (start token has no text, so its comments are never trailing)
+            if (arg.token.length != 0 && comment.line == arg.token.line)
+                || !text.starts_with("///")
+                || text.starts_with("////")
+            {
+                self.lines.clear();
+                continue;
+            }
+            let text = text[3..].trim_end();
+            let text = text.strip_prefix(' ').unwrap_or(text);
+            self.lines.push(text.to_string());
+        }
+    }
 }
 
 impl<'a> CreateSymbolTable<'a> {
@@ -32,13 +69,22 @@ impl<'a> CreateSymbolTable<'a> {
     }
 
     fn insert_symbol(&mut self, token: &VerylToken, kind: SymbolKind) {
-        let symbol = Symbol::new(&token.token, kind, &self.namespace);
+        // attributes belong to the first symbol of the annotated item
+        let attributes = std::mem::take(&mut self.attributes);
+        let doc_comment = DocComment(std::mem::take(&mut self.doc_comment));
+        let symbol = Symbol::new(&token.token, kind, &self.namespace, attributes, doc_comment);
         if !symbol_table::insert(&token.token, symbol) {
             let text = resource_table::get_str_value(token.token.text).unwrap();
             self.errors
                 .push(AnalyzeError::duplicated_identifier(&text, self.text, token));
         }
     }
+
+    fn push_doc_comment(&mut self, token: &VerylToken) {
+        if let Some(mut x) = self.doc_comments.remove(&token.token.id) {
+            self.doc_comment.append(&mut x);
+        }
+    }
 }
 
 impl<'a> Handler for CreateSymbolTable<'a> {
@@ -48,6 +94,15 @@ impl<'a> Handler for CreateSymbolTable<'a> {
 }
 
 impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
+    fn veryl(&mut self, arg: &Veryl) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            let mut doc_comments = DocComments::default();
+            doc_comments.veryl(arg);
+            self.doc_comments = doc_comments.table;
+        }
+        Ok(())
+    }
+
     fn identifier(&mut self, arg: &Identifier) -> Result<()> {
         if let HandlerPoint::Before = self.point {
             let id = arg.identifier_token.token.id;
@@ -57,8 +112,44 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
         Ok(())
     }
 
+    fn attribute(&mut self, arg: &veryl_parser::veryl_grammar_trait::Attribute) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            match arg.try_into() {
+                Ok(x) => self.attributes.push(x),
+                Err(AttributeError::TooManyArguments) => {
+                    let token = &arg.identifier.identifier_token;
+                    self.errors.push(AnalyzeError::too_many_attribute_arguments(
+                        &token.text(),
+                        self.text,
+                        token,
+                    ));
+                }
+            }
+            self.push_doc_comment(&arg.hash.hash_token);
+        }
+        Ok(())
+    }
+
+    fn module_item(&mut self, _arg: &ModuleItem) -> Result<()> {
+        // attributes of the item which has no symbol
+        if let HandlerPoint::After = self.point {
+            self.attributes.clear();
+            self.doc_comment.clear();
+        }
+        Ok(())
+    }
+
+    fn description(&mut self, _arg: &Description) -> Result<()> {
+        if let HandlerPoint::After = self.point {
+            self.attributes.clear();
+            self.doc_comment.clear();
+        }
+        Ok(())
+    }
+
     fn var_declaration(&mut self, arg: &VarDeclaration) -> Result<()> {
         if let HandlerPoint::Before = self.point {
+            self.push_doc_comment(&arg.var.var_token);
             let r#type: SymType = (&*arg.r#type).into();
             let property = VariableProperty { r#type };
             let kind = SymbolKind::Variable(property);
@@ -69,8 +160,11 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
 
     fn localparam_declaration(&mut self, arg: &LocalparamDeclaration) -> Result<()> {
         if let HandlerPoint::Before = self.point {
+            self.push_doc_comment(&arg.localparam.localparam_token);
             let r#type: SymType = (&*arg.r#type).into();
-            let value = *arg.expression.clone();
+            let value = ParameterValue::Expression(ParameterValueExpression {
+                expression: arg.expression.clone(),
+            });
             let kind = SymbolKind::Parameter(ParameterProperty {
                 r#type,
                 scope: ParameterScope::Local,
@@ -81,6 +175,41 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
         Ok(())
     }
 
+    fn type_declaration(&mut self, arg: &TypeDeclaration) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            self.push_doc_comment(&arg.typ.type_token);
+            let r#type: SymType = (&*arg.r#type).into();
+            let kind = SymbolKind::Type(TypeProperty { r#type });
+            self.insert_symbol(&arg.identifier.identifier_token, kind);
+        }
+        Ok(())
+    }
+
+    fn enum_declaration(&mut self, arg: &EnumDeclaration) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            self.push_doc_comment(&arg.r#enum.enum_token);
+            self.insert_symbol(&arg.identifier.identifier_token, SymbolKind::Enum);
+        }
+        Ok(())
+    }
+
+    fn struct_declaration(&mut self, arg: &StructDeclaration) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            let kind = match &*arg.struct_union {
+                StructUnion::Struct(x) => {
+                    self.push_doc_comment(&x.r#struct.struct_token);
+                    SymbolKind::Struct
+                }
+                StructUnion::Union(x) => {
+                    self.push_doc_comment(&x.r#union.union_token);
+                    SymbolKind::Union
+                }
+            };
+            self.insert_symbol(&arg.identifier.identifier_token, kind);
+        }
+        Ok(())
+    }
+
     fn inst_declaration(&mut self, arg: &InstDeclaration) -> Result<()> {
         if let HandlerPoint::Before = self.point {
             let type_name = arg.identifier0.identifier_token.token.text;
@@ -94,11 +223,17 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
     fn with_parameter_item(&mut self, arg: &WithParameterItem) -> Result<()> {
         if let HandlerPoint::Before = self.point {
             let scope = match &*arg.with_parameter_item_group {
-                WithParameterItemGroup::Parameter(_) => ParameterScope::Global,
-                WithParameterItemGroup::Localparam(_) => ParameterScope::Local,
+                WithParameterItemGroup::Parameter(x) => {
+                    self.push_doc_comment(&x.parameter.parameter_token);
+                    ParameterScope::Global
+                }
+                WithParameterItemGroup::Localparam(x) => {
+                    self.push_doc_comment(&x.localparam.localparam_token);
+                    ParameterScope::Local
+                }
             };
-            let r#type: SymType = (&*arg.r#type).into();
-            let value = *arg.expression.clone();
+            let r#type: SymType = (&*arg.with_parameter_item_group0).into();
+            let value = *arg.parameter_value.clone();
             let property = ParameterProperty {
                 r#type,
                 scope,
@@ -111,7 +246,22 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
     }
 
     fn port_declaration_item(&mut self, arg: &PortDeclarationItem) -> Result<()> {
+        if let HandlerPoint::After = self.point {
+            self.attributes.clear();
+            self.doc_comment.clear();
+        }
         if let HandlerPoint::Before = self.point {
+            // attributes of port are walked after the port itself
+            self.attributes = arg
+                .port_declaration_item_list
+                .iter()
+                .filter_map(|x| (&*x.attribute).try_into().ok())
+                .collect();
+            if let Some(x) = arg.port_declaration_item_list.first() {
+                self.push_doc_comment(&x.attribute.hash.hash_token);
+            } else {
+                self.push_doc_comment(&arg.identifier.identifier_token);
+            }
             let property = match &*arg.port_declaration_item_group {
                 PortDeclarationItemGroup::DirectionType(x) => {
                     let r#type: SymType = (&*x.r#type).into();
@@ -135,6 +285,7 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
     fn function_declaration(&mut self, arg: &FunctionDeclaration) -> Result<()> {
         match self.point {
             HandlerPoint::Before => {
+                self.push_doc_comment(&arg.function.function_token);
                 let mut parameters = Vec::new();
                 if let Some(ref x) = arg.function_declaration_opt {
                     if let Some(ref x) = x.with_parameter.with_parameter_opt {
@@ -172,6 +323,7 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
     fn module_declaration(&mut self, arg: &ModuleDeclaration) -> Result<()> {
         match self.point {
             HandlerPoint::Before => {
+                self.push_doc_comment(&arg.module.module_token);
                 let mut parameters = Vec::new();
                 if let Some(ref x) = arg.module_declaration_opt {
                     if let Some(ref x) = x.with_parameter.with_parameter_opt {
@@ -240,6 +392,7 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
     fn interface_declaration(&mut self, arg: &InterfaceDeclaration) -> Result<()> {
         match self.point {
             HandlerPoint::Before => {
+                self.push_doc_comment(&arg.interface.interface_token);
                 let mut parameters = Vec::new();
                 if let Some(ref x) = arg.interface_declaration_opt {
                     if let Some(ref x) = x.with_parameter.with_parameter_opt {
@@ -298,6 +451,7 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
     fn package_declaration(&mut self, arg: &PackageDeclaration) -> Result<()> {
         match self.point {
             HandlerPoint::Before => {
+                self.push_doc_comment(&arg.package.package_token);
                 self.insert_symbol(&arg.identifier.identifier_token, SymbolKind::Package);
 
                 let name = arg.identifier.identifier_token.token.text;
@@ -307,4 +461,92 @@ impl<'a> VerylGrammarTrait for CreateSymbolTable<'a> {
         }
         Ok(())
     }
+
+    fn test_declaration(&mut self, arg: &TestDeclaration) -> Result<()> {
+        match self.point {
+            HandlerPoint::Before => {
+                self.insert_symbol(&arg.identifier.identifier_token, SymbolKind::Test);
+
+                let name = arg.identifier.identifier_token.token.text;
+                self.namespace.push(name)
+            }
+            HandlerPoint::After => self.namespace.pop(),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::namespace::Namespace;
+    use crate::symbol_table::{self, Name};
+    use crate::Analyzer;
+    use veryl_parser::resource_table;
+    use veryl_parser::Parser;
+
+    const CODE: &str = r#"/// Adder
+///
+/// Adds two values.
+#[sv(keep)]
+module ModuleA #(
+    /// Data width
+    parameter WIDTH: u32 = 8,
+) (
+    // not a doc comment
+    i_a: input logic [WIDTH],
+    /// Second operand
+    #[sv(keep)]
+    i_b: input logic [WIDTH], /// Sum
+    o_c: output logic [WIDTH],
+) {
+    assign o_c = i_a + i_b;
+}
+"#;
+
+    fn doc_comment(name: &str, namespace: &[&str]) -> Vec<String> {
+        let name = Name::Hierarchical(vec![resource_table::insert_str(name)]);
+        let mut ns = Namespace::default();
+        for x in namespace {
+            ns.push(resource_table::insert_str(x));
+        }
+        symbol_table::get(&name, &ns).unwrap().doc_comment.0
+    }
+
+    #[test]
+    fn doc_comment_lines() {
+        let parser = Parser::parse(CODE, &"").unwrap();
+        let mut analyzer = Analyzer::new(CODE);
+        analyzer.analyze(&parser.veryl);
+
+        assert_eq!(
+            doc_comment("ModuleA", &[]),
+            vec!["Adder", "", "Adds two values."]
+        );
+        assert_eq!(doc_comment("WIDTH", &["ModuleA"]), vec!["Data width"]);
+        assert!(doc_comment("i_a", &["ModuleA"]).is_empty());
+        assert_eq!(doc_comment("i_b", &["ModuleA"]), vec!["Second operand"]);
+        assert!(doc_comment("o_c", &["ModuleA"]).is_empty());
+    }
+
+    #[test]
+    fn too_many_attribute_arguments() {
+        let code = r#"module ModuleB {
+    #[ram_style(block, 1)]
+    var a: logic;
+    #[max_fanout(16)]
+    var b: logic;
+}
+"#;
+        let parser = Parser::parse(code, &"").unwrap();
+        let mut analyzer = Analyzer::new(code);
+        let errors: Vec<_> = analyzer
+            .analyze(&parser.veryl)
+            .iter()
+            .map(|x| format!("{}", x))
+            .collect();
+        assert_eq!(
+            errors,
+            vec!["attribute \"ram_style\" has too many arguments"]
+        );
+    }
 }
@@ -1,8 +1,11 @@
 pub mod analyze_error;
 pub mod analyzer;
+pub mod attribute;
+pub mod evaluator;
 pub mod handlers;
 pub mod namespace;
 pub mod namespace_table;
+pub mod reference_table;
 pub mod symbol;
 pub mod symbol_table;
 pub use analyze_error::AnalyzeError;
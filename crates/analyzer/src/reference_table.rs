@@ -0,0 +1,230 @@
+use crate::namespace::Namespace;
+use crate::symbol::Symbol;
+use crate::symbol_table::{self, Name};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use veryl_parser::resource_table::{PathId, StrId};
+use veryl_parser::veryl_token::Token;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReferenceKind {
+    /// Identifier which refers a symbol
+    Explicit,
+    /// Port name of the implicit connection like `inst u: M (a)`
+    ImplicitPort,
+    /// Connected signal of the implicit connection like `inst u: M (a)`
+    ImplicitConnection,
+}
+
+/// Identifier which refers a symbol
+///
+/// The symbol is resolved by `name` and `namespace` at the time of lookup,
+/// because the symbol may be declared in a file which is analyzed later.
+#[derive(Clone, Debug)]
+pub struct Reference {
+    pub token: Token,
+    pub kind: ReferenceKind,
+    pub name: Name,
+    pub namespace: Namespace,
+}
+
+impl Reference {
+    pub fn new(token: &Token, kind: ReferenceKind, name: Name, namespace: &Namespace) -> Self {
+        Self {
+            token: *token,
+            kind,
+            name,
+            namespace: namespace.to_owned(),
+        }
+    }
+
+    pub fn resolve(&self) -> Option<Symbol> {
+        symbol_table::get(&self.name, &self.namespace)
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ReferenceTable {
+    table: HashMap<StrId, Vec<Reference>>,
+}
+
+impl ReferenceTable {
+    pub fn insert(&mut self, reference: Reference) {
+        self.table
+            .entry(reference.token.text)
+            .or_default()
+            .push(reference);
+    }
+
+    /// References which are resolved to `symbol`
+    pub fn get(&self, symbol: &Symbol) -> Vec<Reference> {
+        let mut ret = Vec::new();
+        if let Some(references) = self.table.get(&symbol.token.text) {
+            for reference in references {
+                if let Some(x) = reference.resolve() {
+                    if x.token.id == symbol.token.id {
+                        ret.push(reference.clone());
+                    }
+                }
+            }
+        }
+        ret
+    }
+
+    /// Symbol which is declared or referred by the token
+    pub fn resolve(&self, token: &Token) -> Option<Symbol> {
+        if let Some(symbol) = symbol_table::get_by_token(token) {
+            return Some(symbol);
+        }
+        self.table
+            .get(&token.text)?
+            .iter()
+            .filter(|x| x.token.id == token.id)
+            .find_map(|x| x.resolve())
+    }
+
+    pub fn dump(&self) -> String {
+        format!("{}", self)
+    }
+
+    pub fn drop(&mut self, file_path: PathId) {
+        for (_, references) in self.table.iter_mut() {
+            references.retain(|x| x.token.file_path != file_path);
+        }
+    }
+}
+
+impl fmt::Display for ReferenceTable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "ReferenceTable [")?;
+        let mut references: Vec<_> = self.table.values().flatten().collect();
+        references.sort_by_key(|x| (x.token.file_path, x.token.pos));
+        for x in references {
+            let separator = match x.name {
+                Name::Hierarchical(_) => ".",
+                Name::Scoped(_) => "::",
+            };
+            let name: Vec<_> = x.name.as_slice().iter().map(|x| x.to_string()).collect();
+            writeln!(
+                f,
+                "    {}:{}:{} {} @ {} ({:?}),",
+                x.token.file_path,
+                x.token.line,
+                x.token.column,
+                name.join(separator),
+                x.namespace,
+                x.kind,
+            )?;
+        }
+        writeln!(f, "]")?;
+        Ok(())
+    }
+}
+
+thread_local!(static REFERENCE_TABLE: RefCell<ReferenceTable> = RefCell::new(ReferenceTable::default()));
+
+pub fn insert(reference: Reference) {
+    REFERENCE_TABLE.with(|f| f.borrow_mut().insert(reference))
+}
+
+pub fn get(symbol: &Symbol) -> Vec<Reference> {
+    REFERENCE_TABLE.with(|f| f.borrow().get(symbol))
+}
+
+pub fn resolve(token: &Token) -> Option<Symbol> {
+    REFERENCE_TABLE.with(|f| f.borrow().resolve(token))
+}
+
+pub fn dump() -> String {
+    REFERENCE_TABLE.with(|f| f.borrow().dump())
+}
+
+pub fn drop(file_path: PathId) {
+    REFERENCE_TABLE.with(|f| f.borrow_mut().drop(file_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Analyzer;
+    use veryl_parser::resource_table;
+    use veryl_parser::Parser;
+
+    const CODE: &str = r#"
+package PkgA {
+    localparam W: u32 = 8;
+}
+
+module ModuleA (
+    i_a: input  logic,
+    o_b: output logic,
+) {
+    assign o_b = i_a;
+}
+
+module ModuleB (
+    i_a: input  logic,
+    o_b: output logic,
+) {
+    var x: logic [PkgA::W];
+
+    inst u: ModuleA (
+        i_a     ,
+        o_b: x  ,
+    );
+
+    assign o_b = u.o_b;
+}
+"#;
+
+    fn references(name: &[&str], namespace: &[&str]) -> Vec<(usize, usize, ReferenceKind)> {
+        let name = name.iter().map(|x| resource_table::insert_str(x)).collect();
+        let mut ns = Namespace::default();
+        for x in namespace {
+            ns.push(resource_table::insert_str(x));
+        }
+        let symbol = symbol_table::get(&Name::Hierarchical(name), &ns).unwrap();
+        let mut ret: Vec<_> = get(&symbol)
+            .iter()
+            .map(|x| (x.token.line, x.token.column, x.kind))
+            .collect();
+        ret.sort_by_key(|x| (x.0, x.1));
+        ret
+    }
+
+    #[test]
+    fn reference() {
+        let parser = Parser::parse(CODE, &"").unwrap();
+        let mut analyzer = Analyzer::new(CODE);
+        analyzer.analyze(&parser.veryl);
+
+        assert_eq!(
+            references(&["i_a"], &["ModuleA"]),
+            vec![
+                (10, 18, ReferenceKind::Explicit),
+                (20, 9, ReferenceKind::ImplicitPort)
+            ]
+        );
+        assert_eq!(
+            references(&["i_a"], &["ModuleB"]),
+            vec![(20, 9, ReferenceKind::ImplicitConnection)]
+        );
+        assert_eq!(
+            references(&["o_b"], &["ModuleA"]),
+            vec![
+                (10, 12, ReferenceKind::Explicit),
+                (21, 9, ReferenceKind::Explicit),
+                (24, 20, ReferenceKind::Explicit)
+            ]
+        );
+        assert_eq!(
+            references(&["W"], &["PkgA"]),
+            vec![(17, 25, ReferenceKind::Explicit)]
+        );
+        assert_eq!(
+            references(&["ModuleA"], &[]),
+            vec![(19, 13, ReferenceKind::Explicit)]
+        );
+    }
+}
@@ -1,7 +1,8 @@
+use crate::attribute::Attribute;
 use crate::namespace::Namespace;
 use std::fmt;
 use veryl_parser::resource_table::StrId;
-use veryl_parser::veryl_grammar_trait::{BuiltinType, Expression, TypeGroup};
+use veryl_parser::veryl_grammar_trait::{BuiltinType, Expression, ParameterValue, TypeGroup};
 use veryl_parser::veryl_token::Token;
 use veryl_parser::veryl_walker::VerylWalker;
 use veryl_parser::Stringifier;
@@ -11,18 +12,54 @@ pub struct Symbol {
     pub token: Token,
     pub kind: SymbolKind,
     pub namespace: Namespace,
+    pub attributes: Vec<Attribute>,
+    pub doc_comment: DocComment,
 }
 
 impl Symbol {
-    pub fn new(token: &Token, kind: SymbolKind, namespace: &Namespace) -> Self {
+    pub fn new(
+        token: &Token,
+        kind: SymbolKind,
+        namespace: &Namespace,
+        attributes: Vec<Attribute>,
+        doc_comment: DocComment,
+    ) -> Self {
         Self {
             token: *token,
             kind,
             namespace: namespace.to_owned(),
+            attributes,
+            doc_comment,
         }
     }
 }
 
+/// `///` comment lines which precede a declaration
+#[derive(Debug, Clone, Default)]
+pub struct DocComment(pub Vec<String>);
+
+impl DocComment {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// First paragraph of the documentation
+    pub fn summary(&self) -> String {
+        let mut ret = Vec::new();
+        for line in &self.0 {
+            if line.trim().is_empty() {
+                break;
+            }
+            ret.push(line.trim());
+        }
+        ret.join(" ")
+    }
+
+    pub fn text(&self) -> String {
+        self.0.join("\n")
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum SymbolKind {
     Port(PortProperty),
@@ -32,8 +69,13 @@ pub enum SymbolKind {
     Function(FunctionProperty),
     Parameter(ParameterProperty),
     Instance(InstanceProperty),
+    Type(TypeProperty),
+    Struct,
+    Union,
+    Enum,
     Block,
     Package,
+    Test,
 }
 
 impl SymbolKind {
@@ -46,8 +88,13 @@ impl SymbolKind {
             SymbolKind::Function(_) => "function".to_string(),
             SymbolKind::Parameter(_) => "parameter".to_string(),
             SymbolKind::Instance(_) => "instance".to_string(),
+            SymbolKind::Type(_) => "type".to_string(),
+            SymbolKind::Struct => "struct".to_string(),
+            SymbolKind::Union => "union".to_string(),
+            SymbolKind::Enum => "enum".to_string(),
             SymbolKind::Block => "block".to_string(),
             SymbolKind::Package => "package".to_string(),
+            SymbolKind::Test => "test".to_string(),
         }
     }
 }
@@ -99,7 +146,7 @@ impl fmt::Display for SymbolKind {
             }
             SymbolKind::Parameter(x) => {
                 let mut stringifier = Stringifier::new();
-                stringifier.expression(&x.value);
+                stringifier.parameter_value(&x.value);
                 match x.scope {
                     ParameterScope::Global => {
                         format!("parameter [{}] ({})", x.r#type, stringifier.as_str())
@@ -112,8 +159,15 @@ impl fmt::Display for SymbolKind {
             SymbolKind::Instance(x) => {
                 format!("instance [{}]", x.type_name)
             }
+            SymbolKind::Type(x) => {
+                format!("type [{}]", x.r#type)
+            }
+            SymbolKind::Struct => "struct".to_string(),
+            SymbolKind::Union => "union".to_string(),
+            SymbolKind::Enum => "enum".to_string(),
             SymbolKind::Block => "block".to_string(),
             SymbolKind::Package => "package".to_string(),
+            SymbolKind::Test => "test".to_string(),
         };
         text.fmt(f)
     }
@@ -157,8 +211,11 @@ impl From<&veryl_parser::veryl_grammar_trait::Direction> for Direction {
 
 #[derive(Debug, Clone)]
 pub struct Type {
-    kind: TypeKind,
-    modifier: Option<TypeModifier>,
+    pub kind: TypeKind,
+    pub modifier: Vec<TypeModifier>,
+    pub width: Vec<Expression>,
+    /// Unpacked array dimensions
+    pub array: Vec<Expression>,
 }
 
 #[derive(Debug, Clone)]
@@ -173,19 +230,39 @@ pub enum TypeKind {
     F64,
     UserDefined(Vec<StrId>),
     Modport(StrId, StrId),
+    /// Type of type parameters
+    Type,
 }
 
 #[derive(Debug, Clone)]
 pub enum TypeModifier {
     Tri,
+    Signed,
+}
+
+impl Type {
+    /// Signedness of integral types, `None` if it is not known from the type itself
+    pub fn is_signed(&self) -> Option<bool> {
+        match self.kind {
+            TypeKind::I32 | TypeKind::I64 => Some(true),
+            TypeKind::U32 | TypeKind::U64 => Some(false),
+            TypeKind::Bit | TypeKind::Logic => Some(
+                self.modifier
+                    .iter()
+                    .any(|x| matches!(x, TypeModifier::Signed)),
+            ),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for Type {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut text = String::new();
-        if let Some(x) = &self.modifier {
+        for x in &self.modifier {
             match x {
                 TypeModifier::Tri => text.push_str("tri "),
+                TypeModifier::Signed => text.push_str("signed "),
             }
         }
         match &self.kind {
@@ -206,6 +283,7 @@ impl fmt::Display for Type {
             TypeKind::Modport(interface, modport) => {
                 text.push_str(&format!("{}.{}", interface, modport));
             }
+            TypeKind::Type => text.push_str("type"),
         }
         text.fmt(f)
     }
@@ -213,11 +291,14 @@ impl fmt::Display for Type {
 
 impl From<&veryl_parser::veryl_grammar_trait::Type> for Type {
     fn from(value: &veryl_parser::veryl_grammar_trait::Type) -> Self {
-        let modifier = if value.type_opt.is_some() {
-            Some(TypeModifier::Tri)
-        } else {
-            None
-        };
+        let modifier = value
+            .type_list
+            .iter()
+            .map(|x| match &*x.type_modifier {
+                veryl_parser::veryl_grammar_trait::TypeModifier::Tri(_) => TypeModifier::Tri,
+                veryl_parser::veryl_grammar_trait::TypeModifier::Signed(_) => TypeModifier::Signed,
+            })
+            .collect();
         let kind = match &*value.type_group {
             TypeGroup::BuiltinType(x) => match &*x.builtin_type {
                 BuiltinType::Logic(_) => TypeKind::Logic,
@@ -245,7 +326,64 @@ impl From<&veryl_parser::veryl_grammar_trait::Type> for Type {
                 TypeKind::Modport(interface, modport)
             }
         };
-        Type { kind, modifier }
+        let mut width = Vec::new();
+        for x in &value.type_list0 {
+            width.push(*x.width.expression.clone());
+        }
+        let mut array = Vec::new();
+        if let Some(ref x) = value.type_opt {
+            array.push(*x.array.width.expression.clone());
+            for x in &x.array.array_list {
+                array.push(*x.width.expression.clone());
+            }
+        }
+        Type {
+            kind,
+            modifier,
+            width,
+            array,
+        }
+    }
+}
+
+impl From<&veryl_parser::veryl_grammar_trait::TypeValue> for Type {
+    fn from(value: &veryl_parser::veryl_grammar_trait::TypeValue) -> Self {
+        let kind = match &*value.builtin_type {
+            BuiltinType::Logic(_) => TypeKind::Logic,
+            BuiltinType::Bit(_) => TypeKind::Bit,
+            BuiltinType::U32(_) => TypeKind::U32,
+            BuiltinType::U64(_) => TypeKind::U64,
+            BuiltinType::I32(_) => TypeKind::I32,
+            BuiltinType::I64(_) => TypeKind::I64,
+            BuiltinType::F32(_) => TypeKind::F32,
+            BuiltinType::F64(_) => TypeKind::F64,
+        };
+        let mut width = Vec::new();
+        for x in &value.type_value_list {
+            width.push(*x.width.expression.clone());
+        }
+        Type {
+            kind,
+            modifier: Vec::new(),
+            width,
+            array: Vec::new(),
+        }
+    }
+}
+
+impl From<&veryl_parser::veryl_grammar_trait::WithParameterItemGroup0> for Type {
+    fn from(value: &veryl_parser::veryl_grammar_trait::WithParameterItemGroup0) -> Self {
+        match value {
+            veryl_parser::veryl_grammar_trait::WithParameterItemGroup0::Typ(_) => Type {
+                kind: TypeKind::Type,
+                modifier: Vec::new(),
+                width: Vec::new(),
+                array: Vec::new(),
+            },
+            veryl_parser::veryl_grammar_trait::WithParameterItemGroup0::Type(x) => {
+                (&*x.r#type).into()
+            }
+        }
     }
 }
 
@@ -254,6 +392,11 @@ pub struct VariableProperty {
     pub r#type: Type,
 }
 
+#[derive(Debug, Clone)]
+pub struct TypeProperty {
+    pub r#type: Type,
+}
+
 #[derive(Debug, Clone)]
 pub struct PortProperty {
     pub r#type: Option<Type>,
@@ -308,7 +451,7 @@ pub enum ParameterScope {
 pub struct ParameterProperty {
     pub r#type: Type,
     pub scope: ParameterScope,
-    pub value: Expression,
+    pub value: ParameterValue,
 }
 
 #[derive(Debug, Clone)]
@@ -334,11 +477,11 @@ impl From<&veryl_parser::veryl_grammar_trait::WithParameterItem> for Parameter {
                 ParameterScope::Local
             }
         };
-        let r#type: Type = (&*value.r#type).into();
+        let r#type: Type = (&*value.with_parameter_item_group0).into();
         let property = ParameterProperty {
             r#type,
             scope,
-            value: *value.expression.clone(),
+            value: *value.parameter_value.clone(),
         };
         Parameter {
             name: value.identifier.identifier_token.token.text,
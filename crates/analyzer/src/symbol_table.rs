@@ -91,10 +91,18 @@ impl SymbolTable {
     pub fn get(&self, name: &Name, namespace: &Namespace) -> Option<&Symbol> {
         match name {
             Name::Hierarchical(x) => self.get_hierarchical(x, namespace),
-            Name::Scoped(_) => todo!(),
+            Name::Scoped(x) => self.get_scoped(x, namespace),
         }
     }
 
+    /// Symbol which is declared by the token
+    pub fn get_by_token(&self, token: &Token) -> Option<&Symbol> {
+        self.table
+            .get(&token.text)?
+            .iter()
+            .find(|x| x.token.id == token.id)
+    }
+
     pub fn get_all(&self) -> Vec<Symbol> {
         let mut ret = Vec::new();
         for value in self.table.values() {
@@ -135,6 +143,22 @@ impl SymbolTable {
         ret
     }
 
+    fn get_scoped(&self, paths: &[StrId], namespace: &Namespace) -> Option<&Symbol> {
+        let (first, rest) = paths.split_first()?;
+        let mut ret = self.get_hierarchical(&[*first], namespace)?;
+        for name in rest {
+            // members of package are placed at the namespace of the package
+            let mut namespace = ret.namespace.clone();
+            namespace.push(ret.token.text);
+            ret = self
+                .table
+                .get(name)?
+                .iter()
+                .find(|x| x.namespace == namespace)?;
+        }
+        Some(ret)
+    }
+
     pub fn dump(&self) -> String {
         format!("{}", self)
     }
@@ -161,12 +185,16 @@ impl fmt::Display for SymbolTable {
         }
         for (k, v) in &vec {
             for symbol in *v {
+                let mut kind = symbol.kind.to_string();
+                for x in &symbol.attributes {
+                    kind.push_str(&format!(" #[{}]", x));
+                }
                 writeln!(
                     f,
                     "    {:symbol_width$} @ {:namespace_width$}: {},",
                     k,
                     symbol.namespace,
-                    symbol.kind,
+                    kind,
                     symbol_width = symbol_width,
                     namespace_width = namespace_width
                 )?;
@@ -187,6 +215,10 @@ pub fn get(name: &Name, namespace: &Namespace) -> Option<Symbol> {
     SYMBOL_TABLE.with(|f| f.borrow().get(name, namespace).cloned())
 }
 
+pub fn get_by_token(token: &Token) -> Option<Symbol> {
+    SYMBOL_TABLE.with(|f| f.borrow().get_by_token(token).cloned())
+}
+
 pub fn get_all() -> Vec<Symbol> {
     SYMBOL_TABLE.with(|f| f.borrow().get_all())
 }
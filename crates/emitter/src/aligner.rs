@@ -1,3 +1,4 @@
+use crate::emitter::{CastPart, Emitter};
 use std::collections::HashMap;
 use veryl_parser::veryl_grammar_trait::*;
 use veryl_parser::veryl_token::{Token, VerylToken};
@@ -135,6 +136,17 @@ impl Aligner {
         }
     }
 
+    /// Condition of conditional operator, which needs parentheses if it is also conditional operator
+    fn if_expression_condition(&mut self, arg: &Expression) {
+        if let Expression::IfExpression(_) = arg {
+            self.space(1);
+            self.expression(arg);
+            self.space(1);
+        } else {
+            self.expression(arg);
+        }
+    }
+
     fn space(&mut self, repeat: usize) {
         for i in 0..self.aligns.len() {
             self.aligns[i].space(repeat);
@@ -180,23 +192,12 @@ impl VerylWalker for Aligner {
         self.veryl_token(&arg.u64_token.replace("unsigned longint"));
     }
 
-    /// Semantic action for non-terminal 'Expression'
-    fn expression(&mut self, arg: &Expression) {
-        self.expression01(&arg.expression01);
-        for x in &arg.expression_list {
-            self.space(1);
-            self.operator01(&x.operator01);
-            self.space(1);
-            self.expression01(&x.expression01);
-        }
-    }
-
     /// Semantic action for non-terminal 'Expression01'
     fn expression01(&mut self, arg: &Expression01) {
         self.expression02(&arg.expression02);
         for x in &arg.expression01_list {
             self.space(1);
-            self.operator02(&x.operator02);
+            self.operator01(&x.operator01);
             self.space(1);
             self.expression02(&x.expression02);
         }
@@ -207,7 +208,7 @@ impl VerylWalker for Aligner {
         self.expression03(&arg.expression03);
         for x in &arg.expression02_list {
             self.space(1);
-            self.operator03(&x.operator03);
+            self.operator02(&x.operator02);
             self.space(1);
             self.expression03(&x.expression03);
         }
@@ -218,7 +219,7 @@ impl VerylWalker for Aligner {
         self.expression04(&arg.expression04);
         for x in &arg.expression03_list {
             self.space(1);
-            self.operator04(&x.operator04);
+            self.operator03(&x.operator03);
             self.space(1);
             self.expression04(&x.expression04);
         }
@@ -229,7 +230,7 @@ impl VerylWalker for Aligner {
         self.expression05(&arg.expression05);
         for x in &arg.expression04_list {
             self.space(1);
-            self.operator05(&x.operator05);
+            self.operator04(&x.operator04);
             self.space(1);
             self.expression05(&x.expression05);
         }
@@ -240,7 +241,7 @@ impl VerylWalker for Aligner {
         self.expression06(&arg.expression06);
         for x in &arg.expression05_list {
             self.space(1);
-            self.operator06(&x.operator06);
+            self.operator05(&x.operator05);
             self.space(1);
             self.expression06(&x.expression06);
         }
@@ -251,7 +252,7 @@ impl VerylWalker for Aligner {
         self.expression07(&arg.expression07);
         for x in &arg.expression06_list {
             self.space(1);
-            self.operator07(&x.operator07);
+            self.operator06(&x.operator06);
             self.space(1);
             self.expression07(&x.expression07);
         }
@@ -262,7 +263,7 @@ impl VerylWalker for Aligner {
         self.expression08(&arg.expression08);
         for x in &arg.expression07_list {
             self.space(1);
-            self.operator08(&x.operator08);
+            self.operator07(&x.operator07);
             self.space(1);
             self.expression08(&x.expression08);
         }
@@ -273,7 +274,7 @@ impl VerylWalker for Aligner {
         self.expression09(&arg.expression09);
         for x in &arg.expression08_list {
             self.space(1);
-            self.operator09(&x.operator09);
+            self.operator08(&x.operator08);
             self.space(1);
             self.expression09(&x.expression09);
         }
@@ -284,10 +285,7 @@ impl VerylWalker for Aligner {
         self.expression10(&arg.expression10);
         for x in &arg.expression09_list {
             self.space(1);
-            match &*x.expression09_list_group {
-                Expression09ListGroup::Operator10(x) => self.operator10(&x.operator10),
-                Expression09ListGroup::Star(x) => self.star(&x.star),
-            }
+            self.operator09(&x.operator09);
             self.space(1);
             self.expression10(&x.expression10);
         }
@@ -298,12 +296,94 @@ impl VerylWalker for Aligner {
         self.expression11(&arg.expression11);
         for x in &arg.expression10_list {
             self.space(1);
-            self.operator11(&x.operator11);
+            match &*x.expression10_list_group {
+                Expression10ListGroup::Operator10(x) => self.operator10(&x.operator10),
+                Expression10ListGroup::Star(x) => self.star(&x.star),
+            }
             self.space(1);
             self.expression11(&x.expression11);
         }
     }
 
+    /// Semantic action for non-terminal 'Expression11'
+    fn expression11(&mut self, arg: &Expression11) {
+        self.expression12(&arg.expression12);
+        for x in &arg.expression11_list {
+            self.space(1);
+            self.operator11(&x.operator11);
+            self.space(1);
+            self.expression12(&x.expression12);
+        }
+    }
+
+    /// Semantic action for non-terminal 'Expression12'
+    fn expression12(&mut self, arg: &Expression12) {
+        let mut depth = 0;
+        if let Some(ref x) = arg.expression12_opt {
+            for part in Emitter::cast_parts(&x.r#type) {
+                depth += part.depth();
+                match part {
+                    CastPart::Text(x) => self.space(x.len()),
+                    CastPart::Width(x) => self.expression(x),
+                    CastPart::Type(x) => self.scoped_identifier(x),
+                }
+            }
+        }
+        for x in &arg.expression12_list {
+            match &*x.expression12_list_group {
+                Expression12ListGroup::UnaryOperator(x) => self.unary_operator(&x.unary_operator),
+                Expression12ListGroup::Operator03(x) => self.operator03(&x.operator03),
+                Expression12ListGroup::Operator04(x) => self.operator04(&x.operator04),
+                Expression12ListGroup::Operator05(x) => self.operator05(&x.operator05),
+                Expression12ListGroup::Operator09(x) => self.operator09(&x.operator09),
+            }
+        }
+        self.factor(&arg.factor);
+        if arg.expression12_opt.is_some() {
+            self.space(depth);
+        }
+    }
+
+    /// Semantic action for non-terminal 'IfExpression'
+    fn if_expression(&mut self, arg: &IfExpression) {
+        self.veryl_token(&arg.r#if.if_token.replace(""));
+        self.if_expression_condition(&arg.expression);
+        self.space(1);
+        self.veryl_token(&arg.l_brace.l_brace_token.replace("?"));
+        self.space(1);
+        self.expression(&arg.expression0);
+        self.space(1);
+        self.veryl_token(&arg.r_brace.r_brace_token.replace(":"));
+        self.space(1);
+        for x in &arg.if_expression_list {
+            self.veryl_token(&x.r#else.else_token.replace(""));
+            self.veryl_token(&x.r#if.if_token.replace(""));
+            self.if_expression_condition(&x.expression);
+            self.space(1);
+            self.veryl_token(&x.l_brace.l_brace_token.replace("?"));
+            self.space(1);
+            self.expression(&x.expression0);
+            self.space(1);
+            self.veryl_token(&x.r_brace.r_brace_token.replace(":"));
+            self.space(1);
+        }
+        self.veryl_token(&arg.r#else.else_token.replace(""));
+        self.veryl_token(&arg.l_brace0.l_brace_token.replace(""));
+        self.expression(&arg.expression1);
+        self.veryl_token(&arg.r_brace0.r_brace_token.replace(""));
+    }
+
+    /// Semantic action for non-terminal 'TypeValue'
+    fn type_value(&mut self, arg: &TypeValue) {
+        self.builtin_type(&arg.builtin_type);
+        if !arg.type_value_list.is_empty() {
+            self.space(1);
+            for x in &arg.type_value_list {
+                self.width(&x.width);
+            }
+        }
+    }
+
     /// Semantic action for non-terminal 'FunctionCallArg'
     fn function_call_arg(&mut self, arg: &FunctionCallArg) {
         self.expression(&arg.expression);
@@ -328,23 +408,33 @@ impl VerylWalker for Aligner {
     /// Semantic action for non-terminal 'Type'
     fn r#type(&mut self, arg: &Type) {
         self.aligns[align_kind::TYPE].start_item();
-        if let Some(ref x) = arg.type_opt {
-            self.type_modifier(&x.type_modifier);
-            self.space(1);
+        let mut signed = None;
+        for x in &arg.type_list {
+            match &*x.type_modifier {
+                TypeModifier::Tri(_) => {
+                    self.type_modifier(&x.type_modifier);
+                    self.space(1);
+                }
+                TypeModifier::Signed(x) => signed = Some(&x.signed),
+            }
         }
         match &*arg.type_group {
             TypeGroup::BuiltinType(x) => self.builtin_type(&x.builtin_type),
             TypeGroup::ScopedIdentifier(x) => self.scoped_identifier(&x.scoped_identifier),
             TypeGroup::ModportIdentifier(x) => self.modport_identifier(&x.modport_identifier),
         };
+        if let Some(x) = signed {
+            self.space(1);
+            self.signed(x);
+        }
         let loc = self.aligns[align_kind::TYPE].last_location;
         self.aligns[align_kind::TYPE].finish_item();
         self.aligns[align_kind::WIDTH].start_item();
-        if arg.type_list.is_empty() {
+        if arg.type_list0.is_empty() {
             let loc = loc.unwrap();
             self.aligns[align_kind::WIDTH].dummy_location(loc);
         } else {
-            for x in &arg.type_list {
+            for x in &arg.type_list0 {
                 self.width(&x.width);
             }
         }
@@ -483,7 +573,7 @@ impl VerylWalker for Aligner {
             self.colon(&x.colon);
             self.space(1);
             self.aligns[align_kind::EXPRESSION].start_item();
-            self.expression(&x.expression);
+            self.parameter_value(&x.parameter_value);
             self.aligns[align_kind::EXPRESSION].finish_item();
         } else {
             self.aligns[align_kind::EXPRESSION].start_item();
@@ -524,10 +614,21 @@ impl VerylWalker for Aligner {
         self.identifier(&arg.identifier);
         self.aligns[align_kind::IDENTIFIER].finish_item();
         self.colon(&arg.colon);
-        self.r#type(&arg.r#type);
+        match &*arg.with_parameter_item_group0 {
+            WithParameterItemGroup0::Typ(x) => {
+                self.aligns[align_kind::TYPE].start_item();
+                self.typ(&x.typ);
+                let loc = self.aligns[align_kind::TYPE].last_location;
+                self.aligns[align_kind::TYPE].finish_item();
+                self.aligns[align_kind::WIDTH].start_item();
+                self.aligns[align_kind::WIDTH].dummy_location(loc.unwrap());
+                self.aligns[align_kind::WIDTH].finish_item();
+            }
+            WithParameterItemGroup0::Type(x) => self.r#type(&x.r#type),
+        }
         self.equ(&arg.equ);
         self.aligns[align_kind::EXPRESSION].start_item();
-        self.expression(&arg.expression);
+        self.parameter_value(&arg.parameter_value);
         self.aligns[align_kind::EXPRESSION].finish_item();
     }
 
@@ -1,4 +1,6 @@
 use crate::aligner::{Aligner, Location};
+use crate::header::Header;
+use std::path::Path;
 use veryl_metadata::{ClockType, Metadata, ResetType};
 use veryl_parser::resource_table;
 use veryl_parser::veryl_grammar_trait::*;
@@ -10,6 +12,9 @@ pub struct Emitter {
     pub indent_width: usize,
     pub clock_type: ClockType,
     pub reset_type: ResetType,
+    pub header: Option<Header>,
+    pub strip_initial_final: bool,
+    pub strip_assertion: bool,
     string: String,
     indent: usize,
     line: usize,
@@ -32,6 +37,9 @@ impl Default for Emitter {
             indent_width: 4,
             clock_type: ClockType::PosEdge,
             reset_type: ResetType::AsyncLow,
+            header: None,
+            strip_initial_final: false,
+            strip_assertion: false,
             string: String::new(),
             indent: 0,
             line: 1,
@@ -56,6 +64,9 @@ impl Emitter {
             indent_width: metadata.format.indent_width,
             clock_type: metadata.build.clock_type,
             reset_type: metadata.build.reset_type,
+            header: metadata.build.header.then(|| Header::new(metadata)),
+            strip_initial_final: metadata.build.strip_initial_final,
+            strip_assertion: metadata.build.strip_assertion,
             ..Default::default()
         }
     }
@@ -65,6 +76,16 @@ impl Emitter {
         self.veryl(input);
     }
 
+    /// Emit with the header which records the source of the emitted code
+    pub fn emit_with_header<T: AsRef<Path>>(&mut self, input: &Veryl, path: T, source: &str) {
+        self.emit(input);
+        if let Some(mut header) = self.header.take() {
+            let path = path.as_ref().to_string_lossy();
+            header.set_source(&path, source, &self.string);
+            self.string.insert_str(0, &header.to_string());
+        }
+    }
+
     pub fn as_str(&self) -> &str {
         &self.string
     }
@@ -196,32 +217,281 @@ impl Emitter {
         self.process_token(x, false, Some(i))
     }
 
+    /// SystemVerilog keyword of the builtin type and whether it can have packed dimensions
+    fn builtin_type_token(arg: &BuiltinType) -> (bool, VerylToken) {
+        match arg {
+            BuiltinType::Logic(x) => (true, x.logic.logic_token.clone()),
+            BuiltinType::Bit(x) => (true, x.bit.bit_token.clone()),
+            BuiltinType::U32(x) => (false, x.u32.u32_token.replace("int unsigned")),
+            BuiltinType::U64(x) => (false, x.u64.u64_token.replace("longint unsigned")),
+            BuiltinType::I32(x) => (false, x.i32.i32_token.replace("int signed")),
+            BuiltinType::I64(x) => (false, x.i64.i64_token.replace("longint signed")),
+            BuiltinType::F32(x) => (false, x.f32.f32_token.replace("shortreal")),
+            BuiltinType::F64(x) => (false, x.f64.f64_token.replace("real")),
+        }
+    }
+
+    /// SystemVerilog casts to the type which are nested around the casted expression
+    pub(crate) fn cast_parts(arg: &Type) -> Vec<CastPart<'_>> {
+        let signed = arg
+            .type_list
+            .iter()
+            .any(|x| matches!(&*x.type_modifier, TypeModifier::Signed(_)));
+        let mut ret = Vec::new();
+        match &*arg.type_group {
+            TypeGroup::BuiltinType(x) => match &*x.builtin_type {
+                BuiltinType::Logic(_) | BuiltinType::Bit(_) if !arg.type_list0.is_empty() => {
+                    ret.push(CastPart::Text(if signed {
+                        "signed'("
+                    } else {
+                        "unsigned'("
+                    }));
+                    let widths: Vec<_> = arg
+                        .type_list0
+                        .iter()
+                        .map(|x| &*x.width.expression)
+                        .collect();
+                    if widths.len() == 1 && widths[0].single_factor().is_some() {
+                        ret.push(CastPart::Width(widths[0]));
+                        ret.push(CastPart::Text("'("));
+                    } else {
+                        ret.push(CastPart::Text("(("));
+                        for (i, x) in widths.iter().enumerate() {
+                            if i != 0 {
+                                ret.push(CastPart::Text(")*("));
+                            }
+                            ret.push(CastPart::Width(x));
+                        }
+                        ret.push(CastPart::Text("))'("));
+                    }
+                }
+                BuiltinType::Logic(_) | BuiltinType::Bit(_) => {
+                    if signed {
+                        ret.push(CastPart::Text("signed'("));
+                    }
+                    if matches!(&*x.builtin_type, BuiltinType::Logic(_)) {
+                        ret.push(CastPart::Text("logic'("));
+                    } else {
+                        ret.push(CastPart::Text("bit'("));
+                    }
+                }
+                BuiltinType::U32(_) => {
+                    ret.push(CastPart::Text("unsigned'("));
+                    ret.push(CastPart::Text("int'("));
+                }
+                BuiltinType::U64(_) => {
+                    ret.push(CastPart::Text("unsigned'("));
+                    ret.push(CastPart::Text("longint'("));
+                }
+                BuiltinType::I32(_) => ret.push(CastPart::Text("int'(")),
+                BuiltinType::I64(_) => ret.push(CastPart::Text("longint'(")),
+                BuiltinType::F32(_) => ret.push(CastPart::Text("shortreal'(")),
+                BuiltinType::F64(_) => ret.push(CastPart::Text("real'(")),
+            },
+            TypeGroup::ScopedIdentifier(x) => {
+                if signed {
+                    ret.push(CastPart::Text("signed'("));
+                }
+                ret.push(CastPart::Type(&x.scoped_identifier));
+                ret.push(CastPart::Text("'("));
+            }
+            // modport can't be a target of cast
+            TypeGroup::ModportIdentifier(_) => (),
+        }
+        ret
+    }
+
+    /// Condition to disable concurrent assertions while reset is active
+    fn disable_condition(&mut self, arg: &AlwaysFfReset) {
+        let low_active = if let Some(ref x) = arg.always_ff_reset_opt {
+            match &*x.always_ff_reset_opt_group {
+                AlwaysFfResetOptGroup::AsyncLow(x) => {
+                    self.token(&x.async_low.async_low_token.replace(""));
+                    true
+                }
+                AlwaysFfResetOptGroup::AsyncHigh(x) => {
+                    self.token(&x.async_high.async_high_token.replace(""));
+                    false
+                }
+                AlwaysFfResetOptGroup::SyncLow(x) => {
+                    self.token(&x.sync_low.sync_low_token.replace(""));
+                    true
+                }
+                AlwaysFfResetOptGroup::SyncHigh(x) => {
+                    self.token(&x.sync_high.sync_high_token.replace(""));
+                    false
+                }
+            }
+        } else {
+            matches!(self.reset_type, ResetType::AsyncLow | ResetType::SyncLow)
+        };
+        if low_active {
+            self.str("!");
+        }
+        self.hierarchical_identifier(&arg.hierarchical_identifier);
+    }
+
+    fn module_items(&mut self, items: &[(Vec<&Attribute>, &ModuleItem)]) {
+        let mut first = true;
+        for (attributes, x) in items {
+            // initial/final blocks and assertions are removed for synthesis
+            let stripped = match x {
+                ModuleItem::InitialDeclaration(_) | ModuleItem::FinalDeclaration(_) => {
+                    self.strip_initial_final
+                }
+                ModuleItem::AssertionDeclaration(_) => self.strip_assertion,
+                _ => false,
+            };
+            if stripped || attributes.iter().any(|x| x.is_skip()) {
+                let mut removed = RemovedTokens::default();
+                for x in attributes {
+                    removed.attribute(x);
+                }
+                removed.module_item(x);
+                if !removed.comments.is_empty() {
+                    if !first {
+                        self.newline();
+                    }
+                    first = false;
+                    self.comments(&removed.comments);
+                }
+                // skip lines of the removed item to keep the other blank lines
+                self.line = self.line.max(removed.line);
+                // avoid a blank line at the beginning of the block
+                if first {
+                    self.adjust_line = false;
+                }
+                continue;
+            }
+            if !first {
+                self.newline();
+            }
+            first = false;
+            self.attributes(attributes);
+            self.module_item(x);
+        }
+    }
+
+    /// Emit attributes except Veryl directives
+    fn attributes(&mut self, attributes: &[&Attribute]) {
+        // blank line before attributes is kept even if all of them are directives
+        if let Some(x) = attributes.first() {
+            if self.adjust_line && x.hash.hash_token.token.line > self.line + 1 {
+                self.newline();
+            }
+            self.adjust_line = false;
+        }
+        for x in attributes {
+            if x.is_directive() {
+                let mut removed = RemovedTokens::default();
+                removed.attribute(x);
+                if !removed.comments.is_empty() {
+                    self.comments(&removed.comments);
+                    self.newline();
+                }
+                self.line = self.line.max(removed.line);
+            } else {
+                self.attribute(x);
+                self.newline();
+            }
+        }
+    }
+
+    /// Emit comments of the removed tokens on their own lines
+    fn comments(&mut self, comments: &[Token]) {
+        for (i, x) in comments.iter().enumerate() {
+            if i != 0 {
+                self.newline();
+            }
+            self.push_token(x);
+            // newline after line comment is emitted by the caller
+            self.consumed_next_newline = false;
+        }
+    }
+
+    /// Condition of conditional operator, which needs parentheses if it is also conditional operator
+    fn if_expression_condition(&mut self, arg: &Expression) {
+        if let Expression::IfExpression(_) = arg {
+            self.str("(");
+            self.expression(arg);
+            self.str(")");
+        } else {
+            self.expression(arg);
+        }
+    }
+
+    fn statements(&mut self, items: &[&Statement]) {
+        let mut first = true;
+        for x in items {
+            if let Statement::AssertionStatement(x) = x {
+                if self.strip_assertion {
+                    let mut removed = RemovedTokens::default();
+                    removed.assertion_statement(&x.assertion_statement);
+                    if !removed.comments.is_empty() {
+                        if !first {
+                            self.newline();
+                        }
+                        first = false;
+                        self.comments(&removed.comments);
+                    }
+                    // skip lines of the removed item to keep the other blank lines
+                    self.line = self.line.max(removed.line);
+                    // avoid a blank line at the beginning of the block
+                    if first {
+                        self.adjust_line = false;
+                    }
+                    continue;
+                }
+            }
+            if !first {
+                self.newline();
+            }
+            first = false;
+            self.statement(x);
+        }
+    }
+
     fn type_left(&mut self, input: &Type) {
-        if let Some(ref x) = input.type_opt {
-            self.type_modifier(&x.type_modifier);
-            self.space(1);
+        // `signed` follows the base type in SystemVerilog
+        let mut signed = None;
+        for x in &input.type_list {
+            match &*x.type_modifier {
+                TypeModifier::Tri(_) => {
+                    self.type_modifier(&x.type_modifier);
+                    self.space(1);
+                }
+                TypeModifier::Signed(x) => signed = Some(&x.signed),
+            }
         }
         match &*input.type_group {
             TypeGroup::BuiltinType(x) => {
-                let (width, token) = match &*x.builtin_type {
-                    BuiltinType::Logic(x) => (true, x.logic.logic_token.clone()),
-                    BuiltinType::Bit(x) => (true, x.bit.bit_token.clone()),
-                    BuiltinType::U32(x) => (false, x.u32.u32_token.replace("int unsigned")),
-                    BuiltinType::U64(x) => (false, x.u64.u64_token.replace("longint unsigned")),
-                    BuiltinType::I32(x) => (false, x.i32.i32_token.replace("int signed")),
-                    BuiltinType::I64(x) => (false, x.i64.i64_token.replace("longint signed")),
-                    BuiltinType::F32(x) => (false, x.f32.f32_token.replace("shortreal")),
-                    BuiltinType::F64(x) => (false, x.f64.f64_token.replace("real")),
-                };
+                let (width, token) = Emitter::builtin_type_token(&x.builtin_type);
                 self.token(&token);
+                if let Some(x) = signed {
+                    self.space(1);
+                    self.signed(x);
+                }
                 if width {
                     self.space(1);
-                    for x in &input.type_list {
+                    for x in &input.type_list0 {
+                        self.width(&x.width);
+                    }
+                }
+            }
+            TypeGroup::ScopedIdentifier(x) => {
+                // user-defined types like struct and type alias can have packed dimensions
+                self.scoped_identifier(&x.scoped_identifier);
+                if let Some(x) = signed {
+                    self.space(1);
+                    self.signed(x);
+                }
+                if !input.type_list0.is_empty() {
+                    self.space(1);
+                    for x in &input.type_list0 {
                         self.width(&x.width);
                     }
                 }
             }
-            TypeGroup::ScopedIdentifier(x) => self.scoped_identifier(&x.scoped_identifier),
             TypeGroup::ModportIdentifier(x) => self.modport_identifier(&x.modport_identifier),
         }
     }
@@ -238,15 +508,19 @@ impl Emitter {
                 BuiltinType::F32(_) => true,
                 BuiltinType::F64(_) => true,
             },
-            TypeGroup::ScopedIdentifier(_) => true,
+            TypeGroup::ScopedIdentifier(_) => false,
             TypeGroup::ModportIdentifier(_) => true,
         };
         if width {
             self.space(1);
-            for x in &input.type_list {
+            for x in &input.type_list0 {
                 self.width(&x.width);
             }
         }
+        if let Some(ref x) = input.type_opt {
+            self.space(1);
+            self.array(&x.array);
+        }
     }
 
     fn always_ff_reset_exist_in_sensitivity_list(&mut self, arg: &AlwaysFfReset) -> bool {
@@ -268,29 +542,35 @@ impl Emitter {
     }
 }
 
+/// Piece of SystemVerilog cast
+pub(crate) enum CastPart<'a> {
+    Text(&'static str),
+    Width(&'a Expression),
+    Type(&'a ScopedIdentifier),
+}
+
+impl<'a> CastPart<'a> {
+    /// Number of parentheses opened by the piece
+    pub(crate) fn depth(&self) -> usize {
+        match self {
+            CastPart::Text(x) if x.ends_with("'(") => 1,
+            _ => 0,
+        }
+    }
+}
+
 impl VerylWalker for Emitter {
     /// Semantic action for non-terminal 'VerylToken'
     fn veryl_token(&mut self, arg: &VerylToken) {
         self.token(arg);
     }
 
-    /// Semantic action for non-terminal 'Expression'
-    fn expression(&mut self, arg: &Expression) {
-        self.expression01(&arg.expression01);
-        for x in &arg.expression_list {
-            self.space(1);
-            self.operator01(&x.operator01);
-            self.space(1);
-            self.expression01(&x.expression01);
-        }
-    }
-
     /// Semantic action for non-terminal 'Expression01'
     fn expression01(&mut self, arg: &Expression01) {
         self.expression02(&arg.expression02);
         for x in &arg.expression01_list {
             self.space(1);
-            self.operator02(&x.operator02);
+            self.operator01(&x.operator01);
             self.space(1);
             self.expression02(&x.expression02);
         }
@@ -301,7 +581,7 @@ impl VerylWalker for Emitter {
         self.expression03(&arg.expression03);
         for x in &arg.expression02_list {
             self.space(1);
-            self.operator03(&x.operator03);
+            self.operator02(&x.operator02);
             self.space(1);
             self.expression03(&x.expression03);
         }
@@ -312,7 +592,7 @@ impl VerylWalker for Emitter {
         self.expression04(&arg.expression04);
         for x in &arg.expression03_list {
             self.space(1);
-            self.operator04(&x.operator04);
+            self.operator03(&x.operator03);
             self.space(1);
             self.expression04(&x.expression04);
         }
@@ -323,7 +603,7 @@ impl VerylWalker for Emitter {
         self.expression05(&arg.expression05);
         for x in &arg.expression04_list {
             self.space(1);
-            self.operator05(&x.operator05);
+            self.operator04(&x.operator04);
             self.space(1);
             self.expression05(&x.expression05);
         }
@@ -334,7 +614,7 @@ impl VerylWalker for Emitter {
         self.expression06(&arg.expression06);
         for x in &arg.expression05_list {
             self.space(1);
-            self.operator06(&x.operator06);
+            self.operator05(&x.operator05);
             self.space(1);
             self.expression06(&x.expression06);
         }
@@ -345,7 +625,7 @@ impl VerylWalker for Emitter {
         self.expression07(&arg.expression07);
         for x in &arg.expression06_list {
             self.space(1);
-            self.operator07(&x.operator07);
+            self.operator06(&x.operator06);
             self.space(1);
             self.expression07(&x.expression07);
         }
@@ -356,7 +636,7 @@ impl VerylWalker for Emitter {
         self.expression08(&arg.expression08);
         for x in &arg.expression07_list {
             self.space(1);
-            self.operator08(&x.operator08);
+            self.operator07(&x.operator07);
             self.space(1);
             self.expression08(&x.expression08);
         }
@@ -367,7 +647,7 @@ impl VerylWalker for Emitter {
         self.expression09(&arg.expression09);
         for x in &arg.expression08_list {
             self.space(1);
-            self.operator09(&x.operator09);
+            self.operator08(&x.operator08);
             self.space(1);
             self.expression09(&x.expression09);
         }
@@ -378,10 +658,7 @@ impl VerylWalker for Emitter {
         self.expression10(&arg.expression10);
         for x in &arg.expression09_list {
             self.space(1);
-            match &*x.expression09_list_group {
-                Expression09ListGroup::Operator10(x) => self.operator10(&x.operator10),
-                Expression09ListGroup::Star(x) => self.star(&x.star),
-            }
+            self.operator09(&x.operator09);
             self.space(1);
             self.expression10(&x.expression10);
         }
@@ -392,12 +669,97 @@ impl VerylWalker for Emitter {
         self.expression11(&arg.expression11);
         for x in &arg.expression10_list {
             self.space(1);
-            self.operator11(&x.operator11);
+            match &*x.expression10_list_group {
+                Expression10ListGroup::Operator10(x) => self.operator10(&x.operator10),
+                Expression10ListGroup::Star(x) => self.star(&x.star),
+            }
             self.space(1);
             self.expression11(&x.expression11);
         }
     }
 
+    /// Semantic action for non-terminal 'Expression11'
+    fn expression11(&mut self, arg: &Expression11) {
+        self.expression12(&arg.expression12);
+        for x in &arg.expression11_list {
+            self.space(1);
+            self.operator11(&x.operator11);
+            self.space(1);
+            self.expression12(&x.expression12);
+        }
+    }
+
+    /// Semantic action for non-terminal 'Expression12'
+    fn expression12(&mut self, arg: &Expression12) {
+        let mut depth = 0;
+        if let Some(ref x) = arg.expression12_opt {
+            for part in Emitter::cast_parts(&x.r#type) {
+                depth += part.depth();
+                match part {
+                    CastPart::Text(x) => self.str(x),
+                    CastPart::Width(x) => self.expression(x),
+                    CastPart::Type(x) => self.scoped_identifier(x),
+                }
+            }
+        }
+        for x in &arg.expression12_list {
+            match &*x.expression12_list_group {
+                Expression12ListGroup::UnaryOperator(x) => self.unary_operator(&x.unary_operator),
+                Expression12ListGroup::Operator03(x) => self.operator03(&x.operator03),
+                Expression12ListGroup::Operator04(x) => self.operator04(&x.operator04),
+                Expression12ListGroup::Operator05(x) => self.operator05(&x.operator05),
+                Expression12ListGroup::Operator09(x) => self.operator09(&x.operator09),
+            }
+        }
+        self.factor(&arg.factor);
+        if let Some(ref x) = arg.expression12_opt {
+            self.str(&")".repeat(depth));
+            // keep comments around the type
+            self.token(&x.r#as.as_token.replace(""));
+        }
+    }
+
+    /// Semantic action for non-terminal 'IfExpression'
+    fn if_expression(&mut self, arg: &IfExpression) {
+        self.token(&arg.r#if.if_token.replace(""));
+        self.if_expression_condition(&arg.expression);
+        self.space(1);
+        self.token(&arg.l_brace.l_brace_token.replace("?"));
+        self.space(1);
+        self.expression(&arg.expression0);
+        self.space(1);
+        self.token(&arg.r_brace.r_brace_token.replace(":"));
+        self.space(1);
+        for x in &arg.if_expression_list {
+            self.token(&x.r#else.else_token.replace(""));
+            self.token(&x.r#if.if_token.replace(""));
+            self.if_expression_condition(&x.expression);
+            self.space(1);
+            self.token(&x.l_brace.l_brace_token.replace("?"));
+            self.space(1);
+            self.expression(&x.expression0);
+            self.space(1);
+            self.token(&x.r_brace.r_brace_token.replace(":"));
+            self.space(1);
+        }
+        self.token(&arg.r#else.else_token.replace(""));
+        self.token(&arg.l_brace0.l_brace_token.replace(""));
+        self.expression(&arg.expression1);
+        self.token(&arg.r_brace0.r_brace_token.replace(""));
+    }
+
+    /// Semantic action for non-terminal 'TypeValue'
+    fn type_value(&mut self, arg: &TypeValue) {
+        let (_, token) = Emitter::builtin_type_token(&arg.builtin_type);
+        self.token(&token);
+        if !arg.type_value_list.is_empty() {
+            self.space(1);
+            for x in &arg.type_value_list {
+                self.width(&x.width);
+            }
+        }
+    }
+
     /// Semantic action for non-terminal 'FunctionCallArg'
     fn function_call_arg(&mut self, arg: &FunctionCallArg) {
         self.expression(&arg.expression);
@@ -468,6 +830,18 @@ impl VerylWalker for Emitter {
         self.r_bracket(&arg.r_bracket);
     }
 
+    /// Semantic action for non-terminal 'Array'
+    fn array(&mut self, arg: &Array) {
+        self.token(&arg.unpacked.unpacked_token.replace(""));
+        let mut widths = vec![&*arg.width];
+        widths.extend(arg.array_list.iter().map(|x| &*x.width));
+        for x in widths {
+            self.l_bracket(&x.l_bracket);
+            self.expression(&x.expression);
+            self.r_bracket(&x.r_bracket);
+        }
+    }
+
     /// Semantic action for non-terminal 'Type'
     fn r#type(&mut self, _arg: &Type) {}
 
@@ -499,12 +873,12 @@ impl VerylWalker for Emitter {
         self.space(1);
         self.token_will_push(&arg.l_brace.l_brace_token.replace("begin"));
         self.newline_push();
-        for (i, x) in arg.if_statement_list.iter().enumerate() {
-            if i != 0 {
-                self.newline();
-            }
-            self.statement(&x.statement);
-        }
+        let items: Vec<_> = arg
+            .if_statement_list
+            .iter()
+            .map(|x| &*x.statement)
+            .collect();
+        self.statements(&items);
         self.newline_pop();
         self.token(&arg.r_brace.r_brace_token.replace("end"));
         for x in &arg.if_statement_list0 {
@@ -519,12 +893,12 @@ impl VerylWalker for Emitter {
             self.space(1);
             self.token_will_push(&x.l_brace.l_brace_token.replace("begin"));
             self.newline_push();
-            for (i, x) in x.if_statement_list0_list.iter().enumerate() {
-                if i != 0 {
-                    self.newline();
-                }
-                self.statement(&x.statement);
-            }
+            let items: Vec<_> = x
+                .if_statement_list0_list
+                .iter()
+                .map(|x| &*x.statement)
+                .collect();
+            self.statements(&items);
             self.newline_pop();
             self.token(&x.r_brace.r_brace_token.replace("end"));
         }
@@ -534,12 +908,12 @@ impl VerylWalker for Emitter {
             self.space(1);
             self.token_will_push(&x.l_brace.l_brace_token.replace("begin"));
             self.newline_push();
-            for (i, x) in x.if_statement_opt_list.iter().enumerate() {
-                if i != 0 {
-                    self.newline();
-                }
-                self.statement(&x.statement);
-            }
+            let items: Vec<_> = x
+                .if_statement_opt_list
+                .iter()
+                .map(|x| &*x.statement)
+                .collect();
+            self.statements(&items);
             self.newline_pop();
             self.token(&x.r_brace.r_brace_token.replace("end"));
         }
@@ -556,12 +930,12 @@ impl VerylWalker for Emitter {
         self.space(1);
         self.token_will_push(&arg.l_brace.l_brace_token.replace("begin"));
         self.newline_push();
-        for (i, x) in arg.if_reset_statement_list.iter().enumerate() {
-            if i != 0 {
-                self.newline();
-            }
-            self.statement(&x.statement);
-        }
+        let items: Vec<_> = arg
+            .if_reset_statement_list
+            .iter()
+            .map(|x| &*x.statement)
+            .collect();
+        self.statements(&items);
         self.newline_pop();
         self.token(&arg.r_brace.r_brace_token.replace("end"));
         for x in &arg.if_reset_statement_list0 {
@@ -576,12 +950,12 @@ impl VerylWalker for Emitter {
             self.space(1);
             self.token_will_push(&x.l_brace.l_brace_token.replace("begin"));
             self.newline_push();
-            for (i, x) in x.if_reset_statement_list0_list.iter().enumerate() {
-                if i != 0 {
-                    self.newline();
-                }
-                self.statement(&x.statement);
-            }
+            let items: Vec<_> = x
+                .if_reset_statement_list0_list
+                .iter()
+                .map(|x| &*x.statement)
+                .collect();
+            self.statements(&items);
             self.newline_pop();
             self.token(&x.r_brace.r_brace_token.replace("end"));
         }
@@ -591,12 +965,12 @@ impl VerylWalker for Emitter {
             self.space(1);
             self.token_will_push(&x.l_brace.l_brace_token.replace("begin"));
             self.newline_push();
-            for (i, x) in x.if_reset_statement_opt_list.iter().enumerate() {
-                if i != 0 {
-                    self.newline();
-                }
-                self.statement(&x.statement);
-            }
+            let items: Vec<_> = x
+                .if_reset_statement_opt_list
+                .iter()
+                .map(|x| &*x.statement)
+                .collect();
+            self.statements(&items);
             self.newline_pop();
             self.token(&x.r_brace.r_brace_token.replace("end"));
         }
@@ -646,12 +1020,12 @@ impl VerylWalker for Emitter {
         self.space(1);
         self.token_will_push(&arg.l_brace.l_brace_token.replace("begin"));
         self.newline_push();
-        for (i, x) in arg.for_statement_list.iter().enumerate() {
-            if i != 0 {
-                self.newline();
-            }
-            self.statement(&x.statement);
-        }
+        let items: Vec<_> = arg
+            .for_statement_list
+            .iter()
+            .map(|x| &*x.statement)
+            .collect();
+        self.statements(&items);
         self.newline_pop();
         self.token(&arg.r_brace.r_brace_token.replace("end"));
     }
@@ -674,6 +1048,21 @@ impl VerylWalker for Emitter {
         self.token(&arg.r_brace.r_brace_token.replace("endcase"));
     }
 
+    /// Semantic action for non-terminal 'AssertionStatement'
+    fn assertion_statement(&mut self, arg: &AssertionStatement) {
+        // null statement is required if the assertion is the only statement of case item
+        if self.strip_assertion {
+            self.semicolon(&arg.semicolon);
+            return;
+        }
+        self.assertion_kind(&arg.assertion_kind);
+        self.space(1);
+        self.str("(");
+        self.expression(&arg.expression);
+        self.str(")");
+        self.semicolon(&arg.semicolon);
+    }
+
     /// Semantic action for non-terminal 'CaseItem'
     fn case_item(&mut self, arg: &CaseItem) {
         match &*arg.case_item_group {
@@ -687,18 +1076,60 @@ impl VerylWalker for Emitter {
             CaseItemGroup0::LBraceCaseItemGroup0ListRBrace(x) => {
                 self.token_will_push(&x.l_brace.l_brace_token.replace("begin"));
                 self.newline_push();
-                for (i, x) in x.case_item_group0_list.iter().enumerate() {
-                    if i != 0 {
-                        self.newline();
-                    }
-                    self.statement(&x.statement);
-                }
+                let items: Vec<_> = x
+                    .case_item_group0_list
+                    .iter()
+                    .map(|x| &*x.statement)
+                    .collect();
+                self.statements(&items);
                 self.newline_pop();
                 self.token(&x.r_brace.r_brace_token.replace("end"));
             }
         }
     }
 
+    /// Semantic action for non-terminal 'Attribute'
+    fn attribute(&mut self, arg: &Attribute) {
+        self.token(&arg.hash.hash_token.replace("(*"));
+        self.space(1);
+        self.token(&arg.l_bracket.l_bracket_token.replace(""));
+        self.identifier(&arg.identifier);
+        if let Some(ref x) = arg.attribute_opt {
+            self.space(1);
+            self.token(&x.l_paren.l_paren_token.replace("="));
+            self.space(1);
+            self.attribute_list(&x.attribute_list);
+            self.token(&x.r_paren.r_paren_token.replace(""));
+        }
+        self.space(1);
+        self.token(&arg.r_bracket.r_bracket_token.replace("*)"));
+    }
+
+    /// Semantic action for non-terminal 'AttributeList'
+    fn attribute_list(&mut self, arg: &AttributeList) {
+        self.attribute_item(&arg.attribute_item);
+        for x in &arg.attribute_list_list {
+            self.comma(&x.comma);
+            self.space(1);
+            self.attribute_item(&x.attribute_item);
+        }
+        if let Some(ref x) = arg.attribute_list_opt {
+            self.token(&x.comma.comma_token.replace(""));
+        }
+    }
+
+    /// Semantic action for non-terminal 'AttributeItem'
+    fn attribute_item(&mut self, arg: &AttributeItem) {
+        match arg {
+            // identifier arguments are emitted as string
+            AttributeItem::Identifier(x) => {
+                let token = &x.identifier.identifier_token;
+                self.token(&token.replace(&format!("\"{}\"", token.text())));
+            }
+            AttributeItem::Number(x) => self.number(&x.number),
+        }
+    }
+
     /// Semantic action for non-terminal 'VarDeclaration'
     fn var_declaration(&mut self, arg: &VarDeclaration) {
         self.type_left(&arg.r#type);
@@ -736,6 +1167,18 @@ impl VerylWalker for Emitter {
         self.semicolon(&arg.semicolon);
     }
 
+    /// Semantic action for non-terminal 'TypeDeclaration'
+    fn type_declaration(&mut self, arg: &TypeDeclaration) {
+        self.token(&arg.typ.type_token.replace("typedef"));
+        self.space(1);
+        self.type_left(&arg.r#type);
+        self.space(1);
+        self.identifier(&arg.identifier);
+        self.type_right(&arg.r#type);
+        self.token(&arg.equ.equ_token.replace(""));
+        self.semicolon(&arg.semicolon);
+    }
+
     /// Semantic action for non-terminal 'AlwaysFfDeclaration'
     fn always_ff_declaration(&mut self, arg: &AlwaysFfDeclaration) {
         self.in_always_ff = true;
@@ -756,12 +1199,12 @@ impl VerylWalker for Emitter {
         self.space(1);
         self.token_will_push(&arg.l_brace.l_brace_token.replace("begin"));
         self.newline_push();
-        for (i, x) in arg.always_ff_declaration_list.iter().enumerate() {
-            if i != 0 {
-                self.newline();
-            }
-            self.statement(&x.statement);
-        }
+        let items: Vec<_> = arg
+            .always_ff_declaration_list
+            .iter()
+            .map(|x| &*x.statement)
+            .collect();
+        self.statements(&items);
         self.newline_pop();
         self.token(&arg.r_brace.r_brace_token.replace("end"));
         self.in_always_ff = false;
@@ -835,12 +1278,70 @@ impl VerylWalker for Emitter {
         self.space(1);
         self.token_will_push(&arg.l_brace.l_brace_token.replace("begin"));
         self.newline_push();
-        for (i, x) in arg.always_comb_declaration_list.iter().enumerate() {
-            if i != 0 {
-                self.newline();
-            }
-            self.statement(&x.statement);
+        let items: Vec<_> = arg
+            .always_comb_declaration_list
+            .iter()
+            .map(|x| &*x.statement)
+            .collect();
+        self.statements(&items);
+        self.newline_pop();
+        self.token(&arg.r_brace.r_brace_token.replace("end"));
+    }
+
+    /// Semantic action for non-terminal 'AssertionDeclaration'
+    fn assertion_declaration(&mut self, arg: &AssertionDeclaration) {
+        self.assertion_kind(&arg.assertion_kind);
+        self.space(1);
+        self.str("property");
+        self.space(1);
+        self.l_paren(&arg.l_paren);
+        self.str("@(");
+        self.always_ff_clock(&arg.always_ff_clock);
+        self.str(")");
+        if let Some(ref x) = arg.assertion_declaration_opt {
+            self.token(&x.comma.comma_token.replace(""));
+            self.space(1);
+            self.str("disable iff");
+            self.space(1);
+            self.str("(");
+            self.disable_condition(&x.always_ff_reset);
+            self.str(")");
         }
+        self.token(&arg.r_paren.r_paren_token.replace(""));
+        self.space(1);
+        self.expression(&arg.expression);
+        self.str(")");
+        self.semicolon(&arg.semicolon);
+    }
+
+    /// Semantic action for non-terminal 'InitialDeclaration'
+    fn initial_declaration(&mut self, arg: &InitialDeclaration) {
+        self.initial(&arg.initial);
+        self.space(1);
+        self.token_will_push(&arg.l_brace.l_brace_token.replace("begin"));
+        self.newline_push();
+        let items: Vec<_> = arg
+            .initial_declaration_list
+            .iter()
+            .map(|x| &*x.statement)
+            .collect();
+        self.statements(&items);
+        self.newline_pop();
+        self.token(&arg.r_brace.r_brace_token.replace("end"));
+    }
+
+    /// Semantic action for non-terminal 'FinalDeclaration'
+    fn final_declaration(&mut self, arg: &FinalDeclaration) {
+        self.r#final(&arg.r#final);
+        self.space(1);
+        self.token_will_push(&arg.l_brace.l_brace_token.replace("begin"));
+        self.newline_push();
+        let items: Vec<_> = arg
+            .final_declaration_list
+            .iter()
+            .map(|x| &*x.statement)
+            .collect();
+        self.statements(&items);
         self.newline_pop();
         self.token(&arg.r_brace.r_brace_token.replace("end"));
     }
@@ -939,8 +1440,14 @@ impl VerylWalker for Emitter {
     fn struct_declaration(&mut self, arg: &StructDeclaration) {
         self.str("typedef");
         self.space(1);
-        self.r#struct(&arg.r#struct);
+        self.struct_union(&arg.struct_union);
         self.space(1);
+        if let Some(ref x) = arg.struct_declaration_opt {
+            self.token(&x.unpacked.unpacked_token.replace(""));
+        } else {
+            self.str("packed");
+            self.space(1);
+        }
         self.token_will_push(&arg.l_brace.l_brace_token);
         self.newline_push();
         self.struct_list(&arg.struct_list);
@@ -1051,7 +1558,7 @@ impl VerylWalker for Emitter {
         self.str("(");
         if let Some(ref x) = arg.inst_parameter_item_opt {
             self.token(&x.colon.colon_token.replace(""));
-            self.expression(&x.expression);
+            self.parameter_value(&x.parameter_value);
         } else {
             self.duplicated_token(&arg.identifier.identifier_token, 0);
         }
@@ -1122,14 +1629,23 @@ impl VerylWalker for Emitter {
             WithParameterItemGroup::Localparam(x) => self.localparam(&x.localparam),
         };
         self.space(1);
-        self.type_left(&arg.r#type);
-        self.space(1);
-        self.identifier(&arg.identifier);
-        self.type_right(&arg.r#type);
+        match &*arg.with_parameter_item_group0 {
+            WithParameterItemGroup0::Typ(x) => {
+                self.typ(&x.typ);
+                self.space(1);
+                self.identifier(&arg.identifier);
+            }
+            WithParameterItemGroup0::Type(x) => {
+                self.type_left(&x.r#type);
+                self.space(1);
+                self.identifier(&arg.identifier);
+                self.type_right(&x.r#type);
+            }
+        }
         self.space(1);
         self.equ(&arg.equ);
         self.space(1);
-        self.expression(&arg.expression);
+        self.parameter_value(&arg.parameter_value);
     }
 
     /// Semantic action for non-terminal 'PortDeclaration'
@@ -1161,6 +1677,12 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'PortDeclarationItem'
     fn port_declaration_item(&mut self, arg: &PortDeclarationItem) {
+        let attributes: Vec<_> = arg
+            .port_declaration_item_list
+            .iter()
+            .map(|x| &*x.attribute)
+            .collect();
+        self.attributes(&attributes);
         match &*arg.port_declaration_item_group {
             PortDeclarationItemGroup::DirectionType(x) => {
                 self.direction(&x.direction);
@@ -1282,12 +1804,19 @@ impl VerylWalker for Emitter {
         }
         self.token_will_push(&arg.l_brace.l_brace_token.replace(";"));
         self.newline_push();
-        for (i, x) in arg.module_declaration_list.iter().enumerate() {
-            if i != 0 {
-                self.newline();
-            }
-            self.module_item(&x.module_item);
-        }
+        let items: Vec<_> = arg
+            .module_declaration_list
+            .iter()
+            .map(|x| {
+                let attributes = x
+                    .module_declaration_list_list
+                    .iter()
+                    .map(|x| &*x.attribute)
+                    .collect();
+                (attributes, &*x.module_item)
+            })
+            .collect();
+        self.module_items(&items);
         self.newline_pop();
         self.token(&arg.r_brace.r_brace_token.replace("endmodule"));
     }
@@ -1376,12 +1905,19 @@ impl VerylWalker for Emitter {
         self.default_block = Some(arg.identifier.identifier_token.text());
         self.token_will_push(&arg.l_brace.l_brace_token.replace(""));
         self.newline_push();
-        for (i, x) in arg.module_named_block_list.iter().enumerate() {
-            if i != 0 {
-                self.newline();
-            }
-            self.module_item(&x.module_item);
-        }
+        let items: Vec<_> = arg
+            .module_named_block_list
+            .iter()
+            .map(|x| {
+                let attributes = x
+                    .module_named_block_list_list
+                    .iter()
+                    .map(|x| &*x.attribute)
+                    .collect();
+                (attributes, &*x.module_item)
+            })
+            .collect();
+        self.module_items(&items);
         self.newline_pop();
         self.token(&arg.r_brace.r_brace_token.replace("end"));
     }
@@ -1401,12 +1937,19 @@ impl VerylWalker for Emitter {
         }
         self.token_will_push(&arg.l_brace.l_brace_token.replace(""));
         self.newline_push();
-        for (i, x) in arg.module_optional_named_block_list.iter().enumerate() {
-            if i != 0 {
-                self.newline();
-            }
-            self.module_item(&x.module_item);
-        }
+        let items: Vec<_> = arg
+            .module_optional_named_block_list
+            .iter()
+            .map(|x| {
+                let attributes = x
+                    .module_optional_named_block_list_list
+                    .iter()
+                    .map(|x| &*x.attribute)
+                    .collect();
+                (attributes, &*x.module_item)
+            })
+            .collect();
+        self.module_items(&items);
         self.newline_pop();
         self.token(&arg.r_brace.r_brace_token.replace("end"));
     }
@@ -1577,12 +2120,98 @@ impl VerylWalker for Emitter {
         if !arg.start.start_token.comments.is_empty() {
             self.newline();
         }
-        for (i, x) in arg.veryl_list.iter().enumerate() {
+        // tests are run by `veryl test`, and not emitted
+        let descriptions = arg.veryl_list.iter().filter(|x| {
+            !matches!(*x.description, Description::TestDeclaration(_))
+                && !x.veryl_list_list.iter().any(|x| x.attribute.is_skip())
+        });
+        for (i, x) in descriptions.enumerate() {
             if i != 0 {
                 self.newline();
             }
+            let attributes: Vec<_> = x.veryl_list_list.iter().map(|x| &*x.attribute).collect();
+            self.attributes(&attributes);
             self.description(&x.description);
         }
         self.newline();
     }
 }
+
+/// Last line and comments of the syntax tree to skip the removed item
+#[derive(Default)]
+struct RemovedTokens {
+    line: usize,
+    comments: Vec<Token>,
+}
+
+impl VerylWalker for RemovedTokens {
+    /// Semantic action for non-terminal 'VerylToken'
+    fn veryl_token(&mut self, arg: &VerylToken) {
+        self.line = self.line.max(arg.token.line);
+        self.comments.extend(arg.comments.iter().copied());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use veryl_parser::Parser;
+
+    const CODE: &str = r#"module ModuleA {
+    initial {
+        a = 1;
+    }
+
+    final {
+        a = 0;
+    }
+
+    assign a = 0;
+}
+"#;
+
+    const STRIPPED: &str = r#"module ModuleA ;
+    assign a = 0;
+endmodule
+"#;
+
+    #[test]
+    fn strip_initial_final() {
+        let parser = Parser::parse(CODE, &"").unwrap();
+        let mut emitter = Emitter {
+            strip_initial_final: true,
+            ..Default::default()
+        };
+        emitter.emit(&parser.veryl);
+        assert_eq!(emitter.as_str(), STRIPPED);
+    }
+    const ASSERTION: &str = r#"module ModuleA {
+    assert (i_clk) a;
+
+    always_comb {
+        a = 1;
+        assert a == 1;
+        b = 1;
+    }
+}
+"#;
+
+    const ASSERTION_STRIPPED: &str = r#"module ModuleA ;
+    always_comb begin
+        a = 1;
+        b = 1;
+    end
+endmodule
+"#;
+
+    #[test]
+    fn strip_assertion() {
+        let parser = Parser::parse(ASSERTION, &"").unwrap();
+        let mut emitter = Emitter {
+            strip_assertion: true,
+            ..Default::default()
+        };
+        emitter.emit(&parser.veryl);
+        assert_eq!(emitter.as_str(), ASSERTION_STRIPPED);
+    }
+}
@@ -0,0 +1,212 @@
+use sha2::{Digest, Sha256};
+use std::fmt;
+use veryl_metadata::Metadata;
+
+const RULE: &str =
+    "// -----------------------------------------------------------------------------";
+const DO_NOT_EDIT: &str = "// DO NOT EDIT: this file is generated by Veryl";
+const SOURCE: &str = "source      :";
+const SOURCE_HASH: &str = "source hash :";
+const CONTENT_HASH: &str = "content hash:";
+const PACKAGE: &str = "package     :";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub version: String,
+    pub source: String,
+    pub source_hash: String,
+    pub content_hash: String,
+    pub package: String,
+    pub comment: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeaderStatus {
+    /// The output is consistent with both the source and the recorded content
+    UpToDate,
+    /// The source was changed after the output was generated
+    Stale,
+    /// The output was edited after generation
+    Modified,
+    /// The output doesn't have any header
+    NotGenerated,
+}
+
+impl Header {
+    pub fn new(metadata: &Metadata) -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            source: String::new(),
+            source_hash: String::new(),
+            content_hash: String::new(),
+            package: format!("{} {}", metadata.package.name, metadata.package.version),
+            comment: metadata.build.header_comment.clone(),
+        }
+    }
+
+    /// Record the source and the content which the header is attached to
+    pub fn set_source(&mut self, source: &str, source_text: &str, content: &str) {
+        self.source = source.to_string();
+        self.source_hash = hash(source_text);
+        self.content_hash = hash(content);
+    }
+
+    /// Split the text into the header and the remaining content
+    pub fn parse(text: &str) -> Option<(Self, &str)> {
+        let mut lines = text.split_inclusive('\n');
+
+        let rule = lines.next()?;
+        if rule.trim_end() != RULE {
+            return None;
+        }
+        let title = lines.next()?;
+        let version = title
+            .trim_end()
+            .strip_prefix(DO_NOT_EDIT)?
+            .trim()
+            .to_string();
+
+        let mut header = Header {
+            version,
+            source: String::new(),
+            source_hash: String::new(),
+            content_hash: String::new(),
+            package: String::new(),
+            comment: None,
+        };
+        let mut comment = Vec::new();
+        let mut pos = rule.len() + title.len();
+
+        for line in lines {
+            pos += line.len();
+            let line = line.trim_end();
+            if line == RULE {
+                if !comment.is_empty() {
+                    header.comment = Some(comment.join("\n"));
+                }
+                return Some((header, &text[pos..]));
+            }
+
+            let line = line.strip_prefix("//")?;
+            let item = line.trim_start();
+            if let Some(x) = item.strip_prefix(SOURCE) {
+                header.source = x.trim().to_string();
+            } else if let Some(x) = item.strip_prefix(SOURCE_HASH) {
+                header.source_hash = x.trim().to_string();
+            } else if let Some(x) = item.strip_prefix(CONTENT_HASH) {
+                header.content_hash = x.trim().to_string();
+            } else if let Some(x) = item.strip_prefix(PACKAGE) {
+                header.package = x.trim().to_string();
+            } else if !item.is_empty() {
+                comment.push(line.strip_prefix(' ').unwrap_or(line));
+            }
+        }
+
+        None
+    }
+
+    /// Check the generated text against the current source
+    pub fn check(generated: &str, source_text: &str) -> HeaderStatus {
+        // remove CR on Windows environment
+        let generated = generated.replace('\r', "");
+        if let Some((header, content)) = Header::parse(&generated) {
+            if header.content_hash != hash(content) {
+                HeaderStatus::Modified
+            } else if header.source_hash != hash(source_text) {
+                HeaderStatus::Stale
+            } else {
+                HeaderStatus::UpToDate
+            }
+        } else {
+            HeaderStatus::NotGenerated
+        }
+    }
+}
+
+impl fmt::Display for Header {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", RULE)?;
+        writeln!(f, "{} {}", DO_NOT_EDIT, self.version)?;
+        writeln!(f, "//")?;
+        writeln!(f, "//   {} {}", SOURCE, self.source)?;
+        writeln!(f, "//   {} {}", SOURCE_HASH, self.source_hash)?;
+        writeln!(f, "//   {} {}", CONTENT_HASH, self.content_hash)?;
+        writeln!(f, "//   {} {}", PACKAGE, self.package)?;
+        if let Some(ref x) = self.comment {
+            writeln!(f, "//")?;
+            for line in x.lines() {
+                writeln!(f, "// {}", line)?;
+            }
+        }
+        writeln!(f, "{}", RULE)
+    }
+}
+
+fn hash(text: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(text.as_bytes());
+    hasher
+        .finalize()
+        .iter()
+        .map(|x| format!("{:02x}", x))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use veryl_metadata::semver::Version;
+    use veryl_metadata::{Build, Doc, Format, Package, Test};
+
+    const SOURCE: &str = "module A {}\n";
+    const CONTENT: &str = "module A;\nendmodule\n";
+
+    fn generated() -> String {
+        let metadata = Metadata {
+            package: Package {
+                name: "test".to_string(),
+                version: Version::new(0, 1, 0),
+                authors: Vec::new(),
+                description: None,
+                license: None,
+                repository: None,
+            },
+            build: Build {
+                header: true,
+                header_comment: Some("Copyright (c) test".to_string()),
+                ..Default::default()
+            },
+            format: Format::default(),
+            test: Test::default(),
+            doc: Doc::default(),
+            metadata_path: PathBuf::new(),
+        };
+        let mut header = Header::new(&metadata);
+        header.set_source("src/a.vl", SOURCE, CONTENT);
+        format!("{}{}", header, CONTENT)
+    }
+
+    #[test]
+    fn parse() {
+        let generated = generated();
+        let (header, content) = Header::parse(&generated).unwrap();
+        assert_eq!(header.source, "src/a.vl");
+        assert_eq!(header.package, "test 0.1.0");
+        assert_eq!(header.comment.as_deref(), Some("Copyright (c) test"));
+        assert_eq!(content, CONTENT);
+    }
+
+    #[test]
+    fn check() {
+        let generated = generated();
+        assert_eq!(Header::check(&generated, SOURCE), HeaderStatus::UpToDate);
+        assert_eq!(
+            Header::check(&generated, "module B {}\n"),
+            HeaderStatus::Stale
+        );
+        let edited = generated.replace("module A;", "module B;");
+        assert_eq!(Header::check(&edited, SOURCE), HeaderStatus::Modified);
+        assert_eq!(Header::check(CONTENT, SOURCE), HeaderStatus::NotGenerated);
+    }
+}
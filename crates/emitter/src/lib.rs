@@ -1,3 +1,7 @@
 pub mod aligner;
 pub mod emitter;
+pub mod header;
+pub mod netlist;
 pub use emitter::Emitter;
+pub use header::{Header, HeaderStatus};
+pub use netlist::Netlist;
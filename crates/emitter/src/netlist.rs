@@ -0,0 +1,1950 @@
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::Write;
+use veryl_analyzer::evaluator::Evaluator;
+use veryl_metadata::{ClockType, Metadata, ResetType};
+use veryl_parser::veryl_grammar_trait::{self, *};
+use veryl_parser::veryl_token::VerylToken;
+use veryl_parser::veryl_walker::VerylWalker;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Bit {
+    Net(usize),
+    Zero,
+    One,
+    X,
+    Z,
+}
+
+impl Bit {
+    fn to_char(self) -> char {
+        match self {
+            Bit::Net(_) => unreachable!(),
+            Bit::Zero => '0',
+            Bit::One => '1',
+            Bit::X => 'x',
+            Bit::Z => 'z',
+        }
+    }
+
+    fn is_const(self) -> bool {
+        !matches!(self, Bit::Net(_))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PortDirection {
+    Input,
+    Output,
+    Inout,
+}
+
+impl PortDirection {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PortDirection::Input => "input",
+            PortDirection::Output => "output",
+            PortDirection::Inout => "inout",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum ParameterValue {
+    Integer(i64),
+    Bits(Vec<Bit>),
+}
+
+#[derive(Clone, Debug)]
+pub struct Wire {
+    pub name: String,
+    pub bits: Vec<Bit>,
+    pub direction: Option<PortDirection>,
+}
+
+#[derive(Clone, Debug)]
+pub struct CellPort {
+    pub name: String,
+    pub direction: Option<PortDirection>,
+    pub bits: Vec<Bit>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Cell {
+    pub name: String,
+    pub r#type: String,
+    pub parameters: Vec<(String, ParameterValue)>,
+    pub ports: Vec<CellPort>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct NetlistModule {
+    pub name: String,
+    pub wires: Vec<Wire>,
+    pub cells: Vec<Cell>,
+    pub connections: Vec<(Vec<Bit>, Vec<Bit>)>,
+}
+
+type State = BTreeMap<String, Vec<Bit>>;
+
+/// Expression tree flattened from the precedence levels of `Expression`
+enum Expr<'a> {
+    Binary(&'a VerylToken, Box<Expr<'a>>, Box<Expr<'a>>),
+    Unary(&'a VerylToken, Box<Expr<'a>>),
+    Cast(&'a Type, Box<Expr<'a>>),
+    Factor(&'a Factor),
+    If(&'a IfExpression),
+}
+
+/// Lower a synthesizable subset of Veryl into a structural netlist,
+/// which can be written as Yosys JSON or RTLIL.
+pub struct Netlist {
+    pub clock_type: ClockType,
+    pub reset_type: ResetType,
+    pub modules: Vec<NetlistModule>,
+    pub warnings: Vec<String>,
+    module_ports: HashMap<String, Vec<(String, PortDirection)>>,
+    module: NetlistModule,
+    signals: HashMap<String, Vec<Bit>>,
+    parameters: HashMap<String, (Expression, Option<usize>)>,
+    types: HashMap<String, Type>,
+    type_parameters: HashMap<String, veryl_grammar_trait::ParameterValue>,
+    evaluating: HashSet<String>,
+    hold: bool,
+    reset_state: Option<State>,
+    net: usize,
+    index: usize,
+}
+
+impl Default for Netlist {
+    fn default() -> Self {
+        Self {
+            clock_type: ClockType::PosEdge,
+            reset_type: ResetType::AsyncLow,
+            modules: Vec::new(),
+            warnings: Vec::new(),
+            module_ports: HashMap::new(),
+            module: NetlistModule::default(),
+            signals: HashMap::new(),
+            parameters: HashMap::new(),
+            types: HashMap::new(),
+            type_parameters: HashMap::new(),
+            evaluating: HashSet::new(),
+            hold: false,
+            reset_state: None,
+            net: 0,
+            index: 0,
+        }
+    }
+}
+
+fn expression_tree(arg: &Expression) -> Expr<'_> {
+    match arg {
+        Expression::IfExpression(x) => Expr::If(&x.if_expression),
+        Expression::Expression01(x) => expression01_tree(&x.expression01),
+    }
+}
+
+fn expression01_tree(arg: &Expression01) -> Expr<'_> {
+    let mut ret = expression02_tree(&arg.expression02);
+    for x in &arg.expression01_list {
+        let rhs = expression02_tree(&x.expression02);
+        let op = &x.operator01.operator01_token;
+        ret = Expr::Binary(op, Box::new(ret), Box::new(rhs));
+    }
+    ret
+}
+
+fn expression02_tree(arg: &Expression02) -> Expr<'_> {
+    let mut ret = expression03_tree(&arg.expression03);
+    for x in &arg.expression02_list {
+        let rhs = expression03_tree(&x.expression03);
+        let op = &x.operator02.operator02_token;
+        ret = Expr::Binary(op, Box::new(ret), Box::new(rhs));
+    }
+    ret
+}
+
+fn expression03_tree(arg: &Expression03) -> Expr<'_> {
+    let mut ret = expression04_tree(&arg.expression04);
+    for x in &arg.expression03_list {
+        let rhs = expression04_tree(&x.expression04);
+        let op = &x.operator03.operator03_token;
+        ret = Expr::Binary(op, Box::new(ret), Box::new(rhs));
+    }
+    ret
+}
+
+fn expression04_tree(arg: &Expression04) -> Expr<'_> {
+    let mut ret = expression05_tree(&arg.expression05);
+    for x in &arg.expression04_list {
+        let rhs = expression05_tree(&x.expression05);
+        let op = &x.operator04.operator04_token;
+        ret = Expr::Binary(op, Box::new(ret), Box::new(rhs));
+    }
+    ret
+}
+
+fn expression05_tree(arg: &Expression05) -> Expr<'_> {
+    let mut ret = expression06_tree(&arg.expression06);
+    for x in &arg.expression05_list {
+        let rhs = expression06_tree(&x.expression06);
+        let op = &x.operator05.operator05_token;
+        ret = Expr::Binary(op, Box::new(ret), Box::new(rhs));
+    }
+    ret
+}
+
+fn expression06_tree(arg: &Expression06) -> Expr<'_> {
+    let mut ret = expression07_tree(&arg.expression07);
+    for x in &arg.expression06_list {
+        let rhs = expression07_tree(&x.expression07);
+        let op = &x.operator06.operator06_token;
+        ret = Expr::Binary(op, Box::new(ret), Box::new(rhs));
+    }
+    ret
+}
+
+fn expression07_tree(arg: &Expression07) -> Expr<'_> {
+    let mut ret = expression08_tree(&arg.expression08);
+    for x in &arg.expression07_list {
+        let rhs = expression08_tree(&x.expression08);
+        let op = &x.operator07.operator07_token;
+        ret = Expr::Binary(op, Box::new(ret), Box::new(rhs));
+    }
+    ret
+}
+
+fn expression08_tree(arg: &Expression08) -> Expr<'_> {
+    let mut ret = expression09_tree(&arg.expression09);
+    for x in &arg.expression08_list {
+        let rhs = expression09_tree(&x.expression09);
+        let op = &x.operator08.operator08_token;
+        ret = Expr::Binary(op, Box::new(ret), Box::new(rhs));
+    }
+    ret
+}
+
+fn expression09_tree(arg: &Expression09) -> Expr<'_> {
+    let mut ret = expression10_tree(&arg.expression10);
+    for x in &arg.expression09_list {
+        let rhs = expression10_tree(&x.expression10);
+        let op = &x.operator09.operator09_token;
+        ret = Expr::Binary(op, Box::new(ret), Box::new(rhs));
+    }
+    ret
+}
+
+fn expression10_tree(arg: &Expression10) -> Expr<'_> {
+    let mut ret = expression11_tree(&arg.expression11);
+    for x in &arg.expression10_list {
+        let rhs = expression11_tree(&x.expression11);
+        let op = match &*x.expression10_list_group {
+            Expression10ListGroup::Operator10(x) => &x.operator10.operator10_token,
+            Expression10ListGroup::Star(x) => &x.star.star_token,
+        };
+        ret = Expr::Binary(op, Box::new(ret), Box::new(rhs));
+    }
+    ret
+}
+
+fn expression11_tree(arg: &Expression11) -> Expr<'_> {
+    let mut ret = expression12_tree(&arg.expression12);
+    for x in &arg.expression11_list {
+        let rhs = expression12_tree(&x.expression12);
+        let op = &x.operator11.operator11_token;
+        ret = Expr::Binary(op, Box::new(ret), Box::new(rhs));
+    }
+    ret
+}
+
+fn expression12_tree(arg: &Expression12) -> Expr<'_> {
+    let mut ret = Expr::Factor(&arg.factor);
+    for x in arg.expression12_list.iter().rev() {
+        let op = match &*x.expression12_list_group {
+            Expression12ListGroup::UnaryOperator(x) => &x.unary_operator.unary_operator_token,
+            Expression12ListGroup::Operator09(x) => &x.operator09.operator09_token,
+            Expression12ListGroup::Operator05(x) => &x.operator05.operator05_token,
+            Expression12ListGroup::Operator03(x) => &x.operator03.operator03_token,
+            Expression12ListGroup::Operator04(x) => &x.operator04.operator04_token,
+        };
+        ret = Expr::Unary(op, Box::new(ret));
+    }
+    if let Some(ref x) = arg.expression12_opt {
+        ret = Expr::Cast(&x.r#type, Box::new(ret));
+    }
+    ret
+}
+
+fn resize(mut bits: Vec<Bit>, width: usize) -> Vec<Bit> {
+    bits.resize(width, Bit::Zero);
+    bits
+}
+
+fn integer_bits(value: i64, width: usize) -> Vec<Bit> {
+    (0..width)
+        .map(|i| {
+            if i < 64 && (value >> i) & 1 == 1 {
+                Bit::One
+            } else {
+                Bit::Zero
+            }
+        })
+        .collect()
+}
+
+fn based_bits(text: &str) -> Option<Vec<Bit>> {
+    let (width, tail) = text.split_once('\'')?;
+    let width: usize = width.replace('_', "").parse().ok()?;
+    let base = &tail[0..1];
+    let number = tail[1..].replace('_', "");
+
+    let mut bits = Vec::new();
+    if base == "d" {
+        if let Ok(x) = number.parse::<i64>() {
+            return Some(integer_bits(x, width));
+        }
+        let bit = match number.to_ascii_lowercase().as_str() {
+            "x" => Bit::X,
+            "z" => Bit::Z,
+            _ => return None,
+        };
+        return Some(vec![bit; width]);
+    }
+
+    let digit_width = match base {
+        "b" => 1,
+        "o" => 3,
+        "h" => 4,
+        _ => return None,
+    };
+    for c in number.chars().rev() {
+        match c.to_ascii_lowercase() {
+            'x' => bits.extend(vec![Bit::X; digit_width]),
+            'z' => bits.extend(vec![Bit::Z; digit_width]),
+            c => {
+                let x = c.to_digit(16)? as i64;
+                bits.extend(integer_bits(x, digit_width));
+            }
+        }
+    }
+    let fill = match bits.last() {
+        Some(Bit::X) => Bit::X,
+        Some(Bit::Z) => Bit::Z,
+        _ => Bit::Zero,
+    };
+    bits.resize(width, fill);
+    Some(bits)
+}
+
+fn hierarchical_name(arg: &HierarchicalIdentifier) -> String {
+    arg.identifier.identifier_token.text()
+}
+
+fn builtin_width(arg: &BuiltinType) -> usize {
+    match arg {
+        BuiltinType::Logic(_) | BuiltinType::Bit(_) => 1,
+        BuiltinType::U32(_) | BuiltinType::I32(_) | BuiltinType::F32(_) => 32,
+        BuiltinType::U64(_) | BuiltinType::I64(_) | BuiltinType::F64(_) => 64,
+    }
+}
+
+fn builtin_token(arg: &BuiltinType) -> &VerylToken {
+    match arg {
+        BuiltinType::Logic(x) => &x.logic.logic_token,
+        BuiltinType::Bit(x) => &x.bit.bit_token,
+        BuiltinType::U32(x) => &x.u32.u32_token,
+        BuiltinType::U64(x) => &x.u64.u64_token,
+        BuiltinType::I32(x) => &x.i32.i32_token,
+        BuiltinType::I64(x) => &x.i64.i64_token,
+        BuiltinType::F32(x) => &x.f32.f32_token,
+        BuiltinType::F64(x) => &x.f64.f64_token,
+    }
+}
+
+/// Name of the factor which is a plain identifier without select and call
+fn factor_name(arg: &Factor) -> Option<String> {
+    if let Factor::ScopedOrHierIdentifierFactorOpt(x) = arg {
+        if x.factor_opt.is_some() {
+            return None;
+        }
+        let x = &x.scoped_or_hier_identifier;
+        if let ScopedOrHierIdentifierGroup::ScopedOrHierIdentifierGroupList0ScopedOrHierIdentifierGroupList1(y) = &*x.scoped_or_hier_identifier_group {
+            if y.scoped_or_hier_identifier_group_list0.is_empty()
+                && y.scoped_or_hier_identifier_group_list1.is_empty()
+            {
+                return Some(x.identifier.identifier_token.text());
+            }
+        }
+    }
+    None
+}
+
+impl Netlist {
+    pub fn new(metadata: &Metadata) -> Self {
+        Self {
+            clock_type: metadata.build.clock_type,
+            reset_type: metadata.build.reset_type,
+            ..Default::default()
+        }
+    }
+
+    pub fn build(&mut self, input: &Veryl) {
+        for x in &input.veryl_list {
+            if x.veryl_list_list.iter().any(|x| x.attribute.is_skip()) {
+                continue;
+            }
+            if let Description::ModuleDeclaration(x) = &*x.description {
+                let x = &x.module_declaration;
+                let name = x.identifier.identifier_token.text();
+                let ports = self
+                    .ports(x)
+                    .iter()
+                    .filter_map(|x| {
+                        if let PortDeclarationItemGroup::DirectionType(y) =
+                            &*x.port_declaration_item_group
+                        {
+                            let direction = match &*y.direction {
+                                Direction::Input(_) => PortDirection::Input,
+                                Direction::Output(_) => PortDirection::Output,
+                                Direction::Inout(_) => PortDirection::Inout,
+                                _ => return None,
+                            };
+                            Some((x.identifier.identifier_token.text(), direction))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+                self.module_ports.insert(name, ports);
+            }
+        }
+        self.veryl(input);
+    }
+
+    fn ports<'a>(&self, arg: &'a ModuleDeclaration) -> Vec<&'a PortDeclarationItem> {
+        let mut ret = Vec::new();
+        if let Some(ref x) = arg.module_declaration_opt0 {
+            if let Some(ref x) = x.port_declaration.port_declaration_opt {
+                let x = &x.port_declaration_list;
+                ret.push(&*x.port_declaration_item);
+                for x in &x.port_declaration_list_list {
+                    ret.push(&*x.port_declaration_item);
+                }
+            }
+        }
+        ret
+    }
+
+    fn warning(&mut self, token: &VerylToken, msg: &str) {
+        self.warnings.push(format!(
+            "{}:{}: {}",
+            token.token.line, token.token.column, msg
+        ));
+    }
+
+    fn new_wire(&mut self, name: &str, width: usize, direction: Option<PortDirection>) -> Vec<Bit> {
+        let bits: Vec<_> = (0..width).map(|i| Bit::Net(self.net + i)).collect();
+        self.net += width;
+        self.module.wires.push(Wire {
+            name: name.to_string(),
+            bits: bits.clone(),
+            direction,
+        });
+        bits
+    }
+
+    fn cell(
+        &mut self,
+        r#type: &str,
+        parameters: Vec<(&str, ParameterValue)>,
+        inputs: Vec<(&str, Vec<Bit>)>,
+        output: (&str, usize),
+    ) -> Vec<Bit> {
+        self.index += 1;
+        let name = format!("{}${}", r#type, self.index);
+        let y = self.new_wire(&format!("{}_{}", name, output.0), output.1, None);
+
+        let parameters = parameters
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect();
+        let mut ports: Vec<_> = inputs
+            .into_iter()
+            .map(|(k, v)| CellPort {
+                name: k.to_string(),
+                direction: Some(PortDirection::Input),
+                bits: v,
+            })
+            .collect();
+        ports.push(CellPort {
+            name: output.0.to_string(),
+            direction: Some(PortDirection::Output),
+            bits: y.clone(),
+        });
+
+        self.module.cells.push(Cell {
+            name,
+            r#type: r#type.to_string(),
+            parameters,
+            ports,
+        });
+        y
+    }
+
+    fn unary_cell(&mut self, r#type: &str, a: Vec<Bit>, width: usize) -> Vec<Bit> {
+        let parameters = vec![
+            ("A_SIGNED", ParameterValue::Integer(0)),
+            ("A_WIDTH", ParameterValue::Integer(a.len() as i64)),
+            ("Y_WIDTH", ParameterValue::Integer(width as i64)),
+        ];
+        self.cell(r#type, parameters, vec![("A", a)], ("Y", width))
+    }
+
+    fn binary_cell(&mut self, r#type: &str, a: Vec<Bit>, b: Vec<Bit>, width: usize) -> Vec<Bit> {
+        let parameters = vec![
+            ("A_SIGNED", ParameterValue::Integer(0)),
+            ("A_WIDTH", ParameterValue::Integer(a.len() as i64)),
+            ("B_SIGNED", ParameterValue::Integer(0)),
+            ("B_WIDTH", ParameterValue::Integer(b.len() as i64)),
+            ("Y_WIDTH", ParameterValue::Integer(width as i64)),
+        ];
+        self.cell(r#type, parameters, vec![("A", a), ("B", b)], ("Y", width))
+    }
+
+    fn mux(&mut self, a: Vec<Bit>, b: Vec<Bit>, s: Vec<Bit>) -> Vec<Bit> {
+        let width = a.len();
+        let parameters = vec![("WIDTH", ParameterValue::Integer(width as i64))];
+        self.cell(
+            "$mux",
+            parameters,
+            vec![("A", a), ("B", b), ("S", s)],
+            ("Y", width),
+        )
+    }
+
+    fn condition(&mut self, bits: Vec<Bit>) -> Vec<Bit> {
+        if bits.len() == 1 {
+            bits
+        } else {
+            self.unary_cell("$reduce_bool", bits, 1)
+        }
+    }
+
+    fn undefined(&self, width: Option<usize>) -> Vec<Bit> {
+        vec![Bit::X; width.unwrap_or(1)]
+    }
+
+    fn type_width(&mut self, arg: &Type) -> Option<usize> {
+        if arg.type_opt.is_some() {
+            return None;
+        }
+        let mut width = match &*arg.type_group {
+            TypeGroup::BuiltinType(x) => builtin_width(&x.builtin_type),
+            TypeGroup::ScopedIdentifier(x)
+                if x.scoped_identifier.scoped_identifier_list.is_empty() =>
+            {
+                let name = x.scoped_identifier.identifier.identifier_token.text();
+                self.named_type_width(&name)?
+            }
+            _ => return None,
+        };
+        for x in &arg.type_list0 {
+            width *= self.eval(&x.width.expression)? as usize;
+        }
+        Some(width)
+    }
+
+    /// Width of type alias or type parameter
+    fn named_type_width(&mut self, name: &str) -> Option<usize> {
+        // the definition is taken out while evaluating to stop recursive definition
+        if let Some(alias) = self.types.remove(name) {
+            let width = self.type_width(&alias);
+            self.types.insert(name.to_string(), alias);
+            width
+        } else {
+            let value = self.type_parameters.remove(name)?;
+            let width = self.type_value_width(&value);
+            self.type_parameters.insert(name.to_string(), value);
+            width
+        }
+    }
+
+    /// Width of the type given as the value of type parameter
+    fn type_value_width(&mut self, arg: &veryl_grammar_trait::ParameterValue) -> Option<usize> {
+        match arg {
+            veryl_grammar_trait::ParameterValue::TypeValue(x) => {
+                let x = &x.type_value;
+                let mut width = builtin_width(&x.builtin_type);
+                for x in &x.type_value_list {
+                    width *= self.eval(&x.width.expression)? as usize;
+                }
+                Some(width)
+            }
+            veryl_grammar_trait::ParameterValue::Expression(x) => {
+                match expression_tree(&x.expression) {
+                    Expr::Factor(x) => {
+                        let name = factor_name(x)?;
+                        self.named_type_width(&name)
+                    }
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    /// Value of the constant expression whose identifiers are resolved to parameters
+    fn eval(&mut self, arg: &Expression) -> Option<i64> {
+        let mut evaluator = Evaluator::new(|x: &ScopedOrHierIdentifier| {
+            match &*x.scoped_or_hier_identifier_group {
+                ScopedOrHierIdentifierGroup::ColonColonIdentifierScopedOrHierIdentifierGroupList(_) => None,
+                ScopedOrHierIdentifierGroup::ScopedOrHierIdentifierGroupList0ScopedOrHierIdentifierGroupList1(_) => {
+                    self.eval_parameter(&x.identifier.identifier_token.text())
+                }
+            }
+        });
+        evaluator.expression(arg)
+    }
+
+    fn eval_parameter(&mut self, name: &str) -> Option<i64> {
+        if self.evaluating.contains(name) {
+            return None;
+        }
+        let (value, _) = self.parameters.get(name)?.clone();
+        self.evaluating.insert(name.to_string());
+        let ret = self.eval(&value);
+        self.evaluating.remove(name);
+        ret
+    }
+
+    fn expression(&mut self, arg: &Expression, width: Option<usize>) -> Vec<Bit> {
+        let tree = expression_tree(arg);
+        self.expr(&tree, width)
+    }
+
+    fn if_expression(&mut self, arg: &IfExpression, width: Option<usize>) -> Vec<Bit> {
+        let mut branches = vec![(&arg.expression, &arg.expression0)];
+        for x in &arg.if_expression_list {
+            branches.push((&x.expression, &x.expression0));
+        }
+
+        let mut conds = Vec::new();
+        let mut values = Vec::new();
+        for (cond, value) in branches {
+            let cond = self.expression(cond, None);
+            conds.push(self.condition(cond));
+            values.push(self.expression(value, width));
+        }
+        let mut ret = self.expression(&arg.expression1, width);
+
+        // the result width is the widest branch
+        let width = values
+            .iter()
+            .map(|x| x.len())
+            .fold(width.unwrap_or(0).max(ret.len()), usize::max);
+        ret = resize(ret, width);
+        for (cond, value) in conds.into_iter().zip(values).rev() {
+            ret = self.mux(ret, resize(value, width), cond);
+        }
+        ret
+    }
+
+    fn expr(&mut self, arg: &Expr, width: Option<usize>) -> Vec<Bit> {
+        match arg {
+            Expr::Binary(token, x, y) => {
+                let text = token.text();
+                let op = text.as_str();
+                match op {
+                    "||" | "&&" => {
+                        let x = self.expr(x, None);
+                        let y = self.expr(y, None);
+                        let r#type = if op == "||" {
+                            "$logic_or"
+                        } else {
+                            "$logic_and"
+                        };
+                        self.binary_cell(r#type, x, y, 1)
+                    }
+                    "==?" | "!=?" => {
+                        self.warning(token, "wildcard equality is not supported");
+                        self.undefined(Some(1))
+                    }
+                    "==" | "!=" | "===" | "!==" | "<=" | ">=" | "<" | ">" => {
+                        let x = self.expr(x, None);
+                        let y = self.expr(y, Some(x.len()));
+                        let r#type = match op {
+                            "==" => "$eq",
+                            "!=" => "$ne",
+                            "===" => "$eqx",
+                            "!==" => "$nex",
+                            "<=" => "$le",
+                            ">=" => "$ge",
+                            "<" => "$lt",
+                            _ => "$gt",
+                        };
+                        self.binary_cell(r#type, x, y, 1)
+                    }
+                    "<<" | ">>" | "<<<" | ">>>" => {
+                        let x = self.expr(x, width);
+                        let y = self.expr(y, None);
+                        let r#type = match op {
+                            "<<" => "$shl",
+                            ">>" => "$shr",
+                            "<<<" => "$sshl",
+                            _ => "$sshr",
+                        };
+                        let width = width.unwrap_or(0).max(x.len());
+                        self.binary_cell(r#type, x, y, width)
+                    }
+                    _ => {
+                        let r#type = match op {
+                            "|" => "$or",
+                            "^" => "$xor",
+                            "~^" | "^~" => "$xnor",
+                            "&" => "$and",
+                            "+" => "$add",
+                            "-" => "$sub",
+                            "*" => "$mul",
+                            "/" => "$div",
+                            "%" => "$mod",
+                            "**" => "$pow",
+                            _ => {
+                                self.warning(
+                                    token,
+                                    &format!("operator \"{}\" is not supported", op),
+                                );
+                                return self.undefined(width);
+                            }
+                        };
+                        let x = self.expr(x, width);
+                        let y = self.expr(y, width);
+                        let width = width.unwrap_or(0).max(x.len()).max(y.len());
+                        self.binary_cell(r#type, x, y, width)
+                    }
+                }
+            }
+            Expr::Unary(token, x) => {
+                let text = token.text();
+                let op = text.as_str();
+                match op {
+                    "~" | "-" | "+" => {
+                        let x = self.expr(x, width);
+                        let r#type = match op {
+                            "~" => "$not",
+                            "-" => "$neg",
+                            _ => "$pos",
+                        };
+                        let width = width.unwrap_or(0).max(x.len());
+                        self.unary_cell(r#type, x, width)
+                    }
+                    _ => {
+                        let (r#type, invert) = match op {
+                            "!" => ("$logic_not", false),
+                            "&" => ("$reduce_and", false),
+                            "|" => ("$reduce_or", false),
+                            "^" => ("$reduce_xor", false),
+                            "~&" => ("$reduce_and", true),
+                            "~|" => ("$reduce_or", true),
+                            "~^" | "^~" => ("$reduce_xnor", false),
+                            _ => {
+                                self.warning(
+                                    token,
+                                    &format!("operator \"{}\" is not supported", op),
+                                );
+                                return self.undefined(width);
+                            }
+                        };
+                        let x = self.expr(x, None);
+                        let y = self.unary_cell(r#type, x, 1);
+                        if invert {
+                            self.unary_cell("$not", y, 1)
+                        } else {
+                            y
+                        }
+                    }
+                }
+            }
+            Expr::Cast(r#type, x) => {
+                let x = self.expr(x, None);
+                if let Some(width) = self.type_width(r#type) {
+                    resize(x, width)
+                } else {
+                    x
+                }
+            }
+            Expr::Factor(x) => self.factor(x, width),
+            Expr::If(x) => self.if_expression(x, width),
+        }
+    }
+
+    fn factor(&mut self, arg: &Factor, width: Option<usize>) -> Vec<Bit> {
+        match arg {
+            Factor::Number(x) => match &*x.number {
+                Number::IntegralNumber(x) => match &*x.integral_number {
+                    IntegralNumber::Based(x) => {
+                        let token = &x.based.based_token;
+                        if let Some(bits) = based_bits(&token.text()) {
+                            bits
+                        } else {
+                            self.warning(token, "unsupported number");
+                            self.undefined(width)
+                        }
+                    }
+                    IntegralNumber::BaseLess(x) => {
+                        let token = &x.base_less.base_less_token;
+                        if let Ok(value) = token.text().replace('_', "").parse() {
+                            integer_bits(value, 32)
+                        } else {
+                            self.warning(token, "number is too large");
+                            self.undefined(width)
+                        }
+                    }
+                    IntegralNumber::AllBit(x) => {
+                        let bit = if x.all_bit.all_bit_token.text() == "'1" {
+                            Bit::One
+                        } else {
+                            Bit::Zero
+                        };
+                        vec![bit; width.unwrap_or(1)]
+                    }
+                },
+                Number::RealNumber(x) => {
+                    let token = match &*x.real_number {
+                        RealNumber::FixedPoint(x) => &x.fixed_point.fixed_point_token,
+                        RealNumber::Exponent(x) => &x.exponent.exponent_token,
+                    };
+                    self.warning(token, "real number is not supported");
+                    self.undefined(width)
+                }
+            },
+            Factor::ScopedOrHierIdentifierFactorOpt(x) => {
+                let identifier = &x.scoped_or_hier_identifier;
+                let token = &identifier.identifier.identifier_token;
+                if x.factor_opt.is_some() {
+                    self.warning(token, "function call is not supported");
+                    return self.undefined(width);
+                }
+                match &*identifier.scoped_or_hier_identifier_group {
+                    ScopedOrHierIdentifierGroup::ScopedOrHierIdentifierGroupList0ScopedOrHierIdentifierGroupList1(y) => {
+                        if !y.scoped_or_hier_identifier_group_list1.is_empty() {
+                            self.warning(token, "hierarchical reference is not supported");
+                            return self.undefined(width);
+                        }
+                        let ranges: Vec<_> = y
+                            .scoped_or_hier_identifier_group_list0
+                            .iter()
+                            .map(|x| &*x.range)
+                            .collect();
+                        let bits = self.identifier(token);
+                        self.select(token, bits, &ranges)
+                    }
+                    _ => {
+                        self.warning(token, "scoped reference is not supported");
+                        self.undefined(width)
+                    }
+                }
+            }
+            Factor::LParenExpressionRParen(x) => self.expression(&x.expression, width),
+            Factor::SystemFunctionCall(x) => {
+                let token = &x
+                    .system_function_call
+                    .dollar_identifier
+                    .dollar_identifier_token;
+                self.warning(token, "system function call is not supported");
+                self.undefined(width)
+            }
+            Factor::StringLiteral(x) => {
+                let token = &x.string_literal.string_literal_token;
+                self.warning(token, "string literal is not supported");
+                self.undefined(width)
+            }
+            Factor::LBraceConcatenationListRBrace(x) => {
+                let x = &x.concatenation_list;
+                let mut items = vec![&*x.concatenation_item];
+                for x in &x.concatenation_list_list {
+                    items.push(&*x.concatenation_item);
+                }
+
+                let mut ret = Vec::new();
+                for item in items.iter().rev() {
+                    let bits = self.expression(&item.expression, None);
+                    if let Some(ref x) = item.concatenation_item_opt {
+                        if let Some(repeat) = self.eval(&x.expression) {
+                            for _ in 0..repeat {
+                                ret.extend(bits.iter().copied());
+                            }
+                        } else {
+                            self.warning(&x.repeat.repeat_token, "repeat count must be constant");
+                        }
+                    } else {
+                        ret.extend(bits);
+                    }
+                }
+                ret
+            }
+        }
+    }
+
+    fn identifier(&mut self, token: &VerylToken) -> Vec<Bit> {
+        let name = token.text();
+        if let Some(bits) = self.signals.get(&name) {
+            bits.clone()
+        } else if let Some((_, width)) = self.parameters.get(&name).cloned() {
+            if let Some(value) = self.eval_parameter(&name) {
+                integer_bits(value, width.unwrap_or(32))
+            } else {
+                self.warning(token, "parameter value must be constant");
+                self.undefined(width)
+            }
+        } else {
+            self.warning(token, &format!("\"{}\" is not defined", name));
+            self.undefined(None)
+        }
+    }
+
+    /// Resolve a constant range into (lsb, width)
+    fn const_range(&mut self, arg: &Range) -> Option<(usize, usize)> {
+        let x = self.eval(&arg.expression)?;
+        if let Some(ref y) = arg.range_opt {
+            let y = self.eval(&y.expression)?;
+            let (lsb, width) = match &*arg.range_opt.as_ref().unwrap().range_operator {
+                RangeOperator::Colon(_) => (y, x - y + 1),
+                RangeOperator::PlusColon(_) => (x, y),
+                RangeOperator::MinusColon(_) => (x - y + 1, y),
+                RangeOperator::Step(_) => (x * y, y),
+            };
+            if lsb < 0 || width <= 0 {
+                return None;
+            }
+            Some((lsb as usize, width as usize))
+        } else if x >= 0 {
+            Some((x as usize, 1))
+        } else {
+            None
+        }
+    }
+
+    fn select(&mut self, token: &VerylToken, bits: Vec<Bit>, ranges: &[&Range]) -> Vec<Bit> {
+        let range = match ranges {
+            [] => return bits,
+            [x] => *x,
+            _ => {
+                self.warning(token, "multi-dimensional select is not supported");
+                return self.undefined(None);
+            }
+        };
+
+        if let Some((lsb, width)) = self.const_range(range) {
+            return (lsb..lsb + width)
+                .map(|i| bits.get(i).copied().unwrap_or(Bit::X))
+                .collect();
+        }
+
+        // dynamic select is lowered to $shiftx
+        let (offset, width) = if let Some(ref x) = range.range_opt {
+            let width = self.eval(&x.expression);
+            match (&*x.range_operator, width) {
+                (RangeOperator::PlusColon(_), Some(width)) => {
+                    (self.expression(&range.expression, None), width as usize)
+                }
+                (RangeOperator::Step(_), Some(width)) => {
+                    let base = self.expression(&range.expression, None);
+                    let step = integer_bits(width, 32);
+                    let width_base = base.len() + 32;
+                    (
+                        self.binary_cell("$mul", base, step, width_base),
+                        width as usize,
+                    )
+                }
+                _ => {
+                    self.warning(token, "dynamic range select is not supported");
+                    return self.undefined(None);
+                }
+            }
+        } else {
+            (self.expression(&range.expression, None), 1)
+        };
+        self.binary_cell("$shiftx", bits, offset, width)
+    }
+
+    /// Resolve the target of assignment into (name, lsb, width)
+    fn target(&mut self, arg: &HierarchicalIdentifier) -> Option<(String, usize, usize)> {
+        let token = &arg.identifier.identifier_token;
+        let name = hierarchical_name(arg);
+        let full = if let Some(x) = self.signals.get(&name) {
+            x.len()
+        } else {
+            self.warning(token, &format!("\"{}\" is not defined", name));
+            return None;
+        };
+        if !arg.hierarchical_identifier_list0.is_empty() {
+            self.warning(token, "hierarchical reference is not supported");
+            return None;
+        }
+        match arg.hierarchical_identifier_list.as_slice() {
+            [] => Some((name, 0, full)),
+            [x] => {
+                if let Some((lsb, width)) = self.const_range(&x.range) {
+                    if lsb + width <= full {
+                        return Some((name, lsb, width));
+                    }
+                }
+                self.warning(token, "range of assignment target must be constant");
+                None
+            }
+            _ => {
+                self.warning(token, "multi-dimensional select is not supported");
+                None
+            }
+        }
+    }
+
+    fn current(&self, state: &State, name: &str) -> Vec<Bit> {
+        if let Some(x) = state.get(name) {
+            x.clone()
+        } else if self.hold {
+            self.signals[name].clone()
+        } else {
+            vec![Bit::X; self.signals[name].len()]
+        }
+    }
+
+    fn statements(&mut self, arg: &[&Statement], state: &mut State) {
+        for x in arg {
+            self.statement(x, state);
+        }
+    }
+
+    fn statement(&mut self, arg: &Statement, state: &mut State) {
+        match arg {
+            Statement::AssignmentStatement(x) => {
+                let x = &x.assignment_statement;
+                if let Some((name, lsb, width)) = self.target(&x.hierarchical_identifier) {
+                    let mut value = self.current(state, &name);
+                    let rhs = match &*x.assignment_statement_group {
+                        AssignmentStatementGroup::Equ(_) => {
+                            self.expression(&x.expression, Some(width))
+                        }
+                        AssignmentStatementGroup::AssignmentOperator(y) => {
+                            let token = &y.assignment_operator.assignment_operator_token;
+                            let op = token.text();
+                            let op = op.trim_end_matches('=');
+                            let lhs = value[lsb..lsb + width].to_vec();
+                            let rhs = self.expression(&x.expression, Some(width));
+                            self.compound(op, lhs, rhs, width)
+                        }
+                    };
+                    let rhs = resize(rhs, width);
+                    value.splice(lsb..lsb + width, rhs);
+                    state.insert(name, value);
+                }
+            }
+            Statement::IfStatement(x) => {
+                let x = &x.if_statement;
+                let mut conds = vec![(
+                    &*x.expression,
+                    x.if_statement_list.iter().map(|x| &*x.statement).collect(),
+                )];
+                for y in &x.if_statement_list0 {
+                    conds.push((
+                        &*y.expression,
+                        y.if_statement_list0_list
+                            .iter()
+                            .map(|x| &*x.statement)
+                            .collect(),
+                    ));
+                }
+                let else_ = x.if_statement_opt.as_ref().map(|x| {
+                    x.if_statement_opt_list
+                        .iter()
+                        .map(|x| &*x.statement)
+                        .collect()
+                });
+                self.if_chain(&conds, else_.as_ref(), state);
+            }
+            Statement::IfResetStatement(x) => {
+                let x = &x.if_reset_statement;
+                let mut reset_state = state.clone();
+                let list: Vec<_> = x
+                    .if_reset_statement_list
+                    .iter()
+                    .map(|x| &*x.statement)
+                    .collect();
+                self.statements(&list, &mut reset_state);
+                if self.reset_state.is_some() {
+                    self.warning(&x.if_reset.if_reset_token, "duplicated if_reset");
+                }
+                self.reset_state = Some(reset_state);
+
+                let mut conds = Vec::new();
+                for y in &x.if_reset_statement_list0 {
+                    conds.push((
+                        &*y.expression,
+                        y.if_reset_statement_list0_list
+                            .iter()
+                            .map(|x| &*x.statement)
+                            .collect(),
+                    ));
+                }
+                let else_ = x.if_reset_statement_opt.as_ref().map(|x| {
+                    x.if_reset_statement_opt_list
+                        .iter()
+                        .map(|x| &*x.statement)
+                        .collect()
+                });
+                self.if_chain(&conds, else_.as_ref(), state);
+            }
+            Statement::CaseStatement(x) => {
+                let x = &x.case_statement;
+                let sel = self.expression(&x.expression, None);
+
+                let mut items = Vec::new();
+                let mut default = None;
+                for y in &x.case_statement_list {
+                    let y = &y.case_item;
+                    let list: Vec<&Statement> = match &*y.case_item_group0 {
+                        CaseItemGroup0::Statement(x) => vec![&*x.statement],
+                        CaseItemGroup0::LBraceCaseItemGroup0ListRBrace(x) => x
+                            .case_item_group0_list
+                            .iter()
+                            .map(|x| &*x.statement)
+                            .collect(),
+                    };
+                    match &*y.case_item_group {
+                        CaseItemGroup::Expression(x) => items.push((&*x.expression, list)),
+                        CaseItemGroup::Defaul(_) => default = Some(list),
+                    }
+                }
+
+                let mut else_state = state.clone();
+                if let Some(default) = default {
+                    self.statements(&default, &mut else_state);
+                }
+                for (expression, list) in items.iter().rev() {
+                    let value = self.expression(expression, Some(sel.len()));
+                    let cond = self.binary_cell("$eq", sel.clone(), value, 1);
+                    let mut then_state = state.clone();
+                    self.statements(list, &mut then_state);
+                    else_state = self.merge(cond, then_state, else_state);
+                }
+                *state = else_state;
+            }
+            Statement::ForStatement(x) => {
+                let token = &x.for_statement.r#for.for_token;
+                self.warning(token, "for statement is not supported");
+            }
+            Statement::ReturnStatement(x) => {
+                let token = &x.return_statement.r#return.return_token;
+                self.warning(token, "return statement is not supported");
+            }
+            // assertions are not synthesized
+            Statement::AssertionStatement(_) => (),
+            // system tasks are for simulation only
+            Statement::SystemTaskStatement(_) => (),
+        }
+    }
+
+    fn compound(&mut self, op: &str, lhs: Vec<Bit>, rhs: Vec<Bit>, width: usize) -> Vec<Bit> {
+        let r#type = match op {
+            "+" => "$add",
+            "-" => "$sub",
+            "*" => "$mul",
+            "/" => "$div",
+            "%" => "$mod",
+            "&" => "$and",
+            "|" => "$or",
+            "^" => "$xor",
+            "<<" => "$shl",
+            ">>" => "$shr",
+            "<<<" => "$sshl",
+            _ => "$sshr",
+        };
+        self.binary_cell(r#type, lhs, rhs, width)
+    }
+
+    fn if_chain(
+        &mut self,
+        conds: &[(&Expression, Vec<&Statement>)],
+        else_: Option<&Vec<&Statement>>,
+        state: &mut State,
+    ) {
+        if let Some(((cond, list), rest)) = conds.split_first() {
+            let cond = self.expression(cond, None);
+            let cond = self.condition(cond);
+            let mut then_state = state.clone();
+            self.statements(list, &mut then_state);
+            let mut else_state = state.clone();
+            self.if_chain(rest, else_, &mut else_state);
+            *state = self.merge(cond, then_state, else_state);
+        } else if let Some(list) = else_ {
+            self.statements(list, state);
+        }
+    }
+
+    fn merge(&mut self, cond: Vec<Bit>, then_state: State, else_state: State) -> State {
+        let names: BTreeSet<_> = then_state
+            .keys()
+            .chain(else_state.keys())
+            .cloned()
+            .collect();
+        let mut ret = State::new();
+        for name in names {
+            let a = self.current(&else_state, &name);
+            let b = self.current(&then_state, &name);
+            let value = if a == b {
+                a
+            } else {
+                self.mux(a, b, cond.clone())
+            };
+            ret.insert(name, value);
+        }
+        ret
+    }
+
+    fn assign(&mut self, arg: &HierarchicalIdentifier, expression: &Expression) {
+        if let Some((name, lsb, width)) = self.target(arg) {
+            let rhs = self.expression(expression, Some(width));
+            let lhs = self.signals[&name][lsb..lsb + width].to_vec();
+            self.module.connections.push((lhs, resize(rhs, width)));
+        }
+    }
+
+    fn always_comb(&mut self, arg: &AlwaysCombDeclaration) {
+        let list: Vec<_> = arg
+            .always_comb_declaration_list
+            .iter()
+            .map(|x| &*x.statement)
+            .collect();
+        let mut state = State::new();
+        self.hold = false;
+        self.statements(&list, &mut state);
+        for (name, value) in state {
+            let lhs = self.signals[&name].clone();
+            self.module.connections.push((lhs, value));
+        }
+    }
+
+    fn always_ff(&mut self, arg: &AlwaysFfDeclaration) {
+        let clock = &arg.always_ff_clock;
+        let clock_polarity = match clock.always_ff_clock_opt {
+            Some(ref x) => matches!(
+                *x.always_ff_clock_opt_group,
+                AlwaysFfClockOptGroup::Posedge(_)
+            ),
+            None => self.clock_type == ClockType::PosEdge,
+        };
+        let clock = self.identifier(&clock.hierarchical_identifier.identifier.identifier_token);
+
+        let reset = arg.always_ff_declaration_opt.as_ref().map(|x| {
+            let reset = &x.always_ff_reset;
+            let reset_type = match reset.always_ff_reset_opt {
+                Some(ref x) => match *x.always_ff_reset_opt_group {
+                    AlwaysFfResetOptGroup::AsyncLow(_) => ResetType::AsyncLow,
+                    AlwaysFfResetOptGroup::AsyncHigh(_) => ResetType::AsyncHigh,
+                    AlwaysFfResetOptGroup::SyncLow(_) => ResetType::SyncLow,
+                    AlwaysFfResetOptGroup::SyncHigh(_) => ResetType::SyncHigh,
+                },
+                None => self.reset_type,
+            };
+            let token = reset
+                .hierarchical_identifier
+                .identifier
+                .identifier_token
+                .clone();
+            (reset_type, token)
+        });
+        let reset = reset.map(|(x, token)| (x, self.identifier(&token), token));
+
+        let list: Vec<_> = arg
+            .always_ff_declaration_list
+            .iter()
+            .map(|x| &*x.statement)
+            .collect();
+        let mut state = State::new();
+        self.hold = true;
+        self.reset_state = None;
+        self.statements(&list, &mut state);
+        let reset_state = self.reset_state.take().unwrap_or_default();
+
+        let names: BTreeSet<_> = state.keys().chain(reset_state.keys()).cloned().collect();
+        for name in names {
+            let q = self.signals[&name].clone();
+            let mut d = self.current(&state, &name);
+            let width = q.len() as i64;
+
+            let mut parameters = vec![
+                (
+                    "CLK_POLARITY",
+                    ParameterValue::Integer(clock_polarity as i64),
+                ),
+                ("WIDTH", ParameterValue::Integer(width)),
+            ];
+            let mut inputs = vec![("CLK", clock.clone())];
+            let mut r#type = "$dff";
+
+            if let (Some(value), Some((reset_type, rst, token))) = (reset_state.get(&name), &reset)
+            {
+                let (asynchronous, polarity) = match reset_type {
+                    ResetType::AsyncLow => (true, 0),
+                    ResetType::AsyncHigh => (true, 1),
+                    ResetType::SyncLow => (false, 0),
+                    ResetType::SyncHigh => (false, 1),
+                };
+                if value.iter().all(|x| x.is_const()) {
+                    let (t, rst_name, polarity_name, value_name) = if asynchronous {
+                        ("$adff", "ARST", "ARST_POLARITY", "ARST_VALUE")
+                    } else {
+                        ("$sdff", "SRST", "SRST_POLARITY", "SRST_VALUE")
+                    };
+                    r#type = t;
+                    parameters.push((polarity_name, ParameterValue::Integer(polarity)));
+                    parameters.push((value_name, ParameterValue::Bits(value.clone())));
+                    inputs.push((rst_name, rst.clone()));
+                } else {
+                    if asynchronous {
+                        let msg = format!(
+                            "reset value of \"{}\" is not constant, so it is lowered as synchronous reset",
+                            name
+                        );
+                        self.warning(token, &msg);
+                    }
+                    let active = if polarity == 1 {
+                        rst.clone()
+                    } else {
+                        self.unary_cell("$not", rst.clone(), 1)
+                    };
+                    d = self.mux(d, value.clone(), active);
+                }
+            }
+
+            inputs.push(("D", d));
+            self.index += 1;
+            let cell_name = format!("{}${}", r#type, self.index);
+            let mut ports: Vec<_> = inputs
+                .into_iter()
+                .map(|(k, v)| CellPort {
+                    name: k.to_string(),
+                    direction: Some(PortDirection::Input),
+                    bits: v,
+                })
+                .collect();
+            ports.push(CellPort {
+                name: "Q".to_string(),
+                direction: Some(PortDirection::Output),
+                bits: q,
+            });
+            self.module.cells.push(Cell {
+                name: cell_name,
+                r#type: r#type.to_string(),
+                parameters: parameters
+                    .into_iter()
+                    .map(|(k, v)| (k.to_string(), v))
+                    .collect(),
+                ports,
+            });
+        }
+        self.hold = false;
+    }
+
+    fn inst(&mut self, arg: &InstDeclaration) {
+        let name = arg.identifier.identifier_token.text();
+        let module = arg.identifier0.identifier_token.text();
+        if arg.inst_declaration_opt.is_some() {
+            self.warning(
+                &arg.identifier.identifier_token,
+                "instance array is not supported",
+            );
+            return;
+        }
+
+        let mut parameters = Vec::new();
+        if let Some(ref x) = arg.inst_declaration_opt0 {
+            if let Some(ref x) = x.inst_parameter.inst_parameter_opt {
+                let x = &x.inst_parameter_list;
+                let mut items = vec![&*x.inst_parameter_item];
+                for x in &x.inst_parameter_list_list {
+                    items.push(&*x.inst_parameter_item);
+                }
+                for item in items {
+                    let token = &item.identifier.identifier_token;
+                    let value = if let Some(ref x) = item.inst_parameter_item_opt {
+                        match &*x.parameter_value {
+                            veryl_grammar_trait::ParameterValue::Expression(x) => {
+                                self.eval(&x.expression)
+                            }
+                            veryl_grammar_trait::ParameterValue::TypeValue(_) => None,
+                        }
+                    } else {
+                        self.eval_parameter(&token.text())
+                    };
+                    if let Some(value) = value {
+                        parameters.push((token.text(), ParameterValue::Integer(value)));
+                    } else {
+                        self.warning(token, "parameter value must be constant");
+                    }
+                }
+            }
+        }
+
+        let directions = self.module_ports.get(&module).cloned();
+        let mut ports = Vec::new();
+        if let Some(ref x) = arg.inst_declaration_opt1 {
+            if let Some(ref x) = x.inst_declaration_opt2 {
+                let x = &x.inst_port_list;
+                let mut items = vec![&*x.inst_port_item];
+                for x in &x.inst_port_list_list {
+                    items.push(&*x.inst_port_item);
+                }
+                for item in items {
+                    let token = &item.identifier.identifier_token;
+                    let port = token.text();
+                    let direction = directions
+                        .as_ref()
+                        .and_then(|x| x.iter().find(|x| x.0 == port).map(|x| x.1));
+                    let bits = if let Some(ref x) = item.inst_port_item_opt {
+                        self.expression(&x.expression, None)
+                    } else {
+                        self.identifier(token)
+                    };
+                    ports.push(CellPort {
+                        name: port,
+                        direction,
+                        bits,
+                    });
+                }
+            }
+        }
+
+        self.module.cells.push(Cell {
+            name,
+            r#type: module,
+            parameters,
+            ports,
+        });
+    }
+
+    fn declare(&mut self, items: &[&ModuleItem]) {
+        for item in items {
+            if let ModuleItem::TypeDeclaration(x) = item {
+                let x = &x.type_declaration;
+                let name = x.identifier.identifier_token.text();
+                self.types.insert(name, *x.r#type.clone());
+            }
+        }
+        for item in items {
+            match item {
+                ModuleItem::VarDeclaration(x) => {
+                    let x = &x.var_declaration;
+                    let token = &x.identifier.identifier_token;
+                    if let Some(width) = self.type_width(&x.r#type) {
+                        let bits = self.new_wire(&token.text(), width, None);
+                        self.signals.insert(token.text(), bits);
+                    } else {
+                        self.warning(token, "type of variable is not supported");
+                    }
+                }
+                ModuleItem::LocalparamDeclaration(x) => {
+                    let x = &x.localparam_declaration;
+                    let width = self.type_width(&x.r#type);
+                    let name = x.identifier.identifier_token.text();
+                    self.parameters.insert(name, (*x.expression.clone(), width));
+                }
+                _ => (),
+            }
+        }
+    }
+
+    fn lower(&mut self, items: &[&ModuleItem]) {
+        for item in items {
+            match item {
+                ModuleItem::VarDeclaration(x) => {
+                    let x = &x.var_declaration;
+                    if let Some(ref y) = x.var_declaration_opt {
+                        let name = x.identifier.identifier_token.text();
+                        if let Some(lhs) = self.signals.get(&name).cloned() {
+                            let rhs = self.expression(&y.expression, Some(lhs.len()));
+                            let rhs = resize(rhs, lhs.len());
+                            self.module.connections.push((lhs, rhs));
+                        }
+                    }
+                }
+                ModuleItem::InstDeclaration(x) => self.inst(&x.inst_declaration),
+                ModuleItem::LocalparamDeclaration(_) | ModuleItem::TypeDeclaration(_) => (),
+                ModuleItem::AlwaysFfDeclaration(x) => self.always_ff(&x.always_ff_declaration),
+                ModuleItem::AlwaysCombDeclaration(x) => {
+                    self.always_comb(&x.always_comb_declaration)
+                }
+                ModuleItem::AssignDeclaration(x) => {
+                    let x = &x.assign_declaration;
+                    self.assign(&x.hierarchical_identifier, &x.expression);
+                }
+                ModuleItem::InitialDeclaration(x) => {
+                    let token = &x.initial_declaration.initial.initial_token;
+                    self.warning(token, "initial block is ignored in netlist");
+                }
+                ModuleItem::FinalDeclaration(x) => {
+                    let token = &x.final_declaration.r#final.final_token;
+                    self.warning(token, "final block is ignored in netlist");
+                }
+                ModuleItem::FunctionDeclaration(x) => {
+                    let token = &x.function_declaration.function.function_token;
+                    self.warning(token, "function declaration is not supported");
+                }
+                ModuleItem::ModuleIfDeclaration(x) => {
+                    let token = &x.module_if_declaration.r#if.if_token;
+                    self.warning(token, "generate if declaration is not supported");
+                }
+                ModuleItem::ModuleForDeclaration(x) => {
+                    let token = &x.module_for_declaration.r#for.for_token;
+                    self.warning(token, "generate for declaration is not supported");
+                }
+                ModuleItem::EnumDeclaration(x) => {
+                    let token = &x.enum_declaration.r#enum.enum_token;
+                    self.warning(token, "enum declaration is not supported");
+                }
+                ModuleItem::StructDeclaration(x) => {
+                    let (token, kind) = match &*x.struct_declaration.struct_union {
+                        StructUnion::Struct(x) => (&x.r#struct.struct_token, "struct"),
+                        StructUnion::Union(x) => (&x.union.union_token, "union"),
+                    };
+                    self.warning(token, &format!("{} declaration is not supported", kind));
+                }
+                ModuleItem::ModuleNamedBlock(x) => {
+                    let token = &x.module_named_block.identifier.identifier_token;
+                    self.warning(token, "named block is not supported");
+                }
+                ModuleItem::ImportDeclaration(_) | ModuleItem::AssertionDeclaration(_) => (),
+            }
+        }
+    }
+
+    fn sigspec(&self, bits: &[Bit], names: &HashMap<usize, (&str, usize, usize)>) -> String {
+        let mut chunks = Vec::new();
+        let mut i = bits.len();
+        while i > 0 {
+            let msb = bits[i - 1];
+            if msb.is_const() {
+                let mut lsb = i - 1;
+                while lsb > 0 && bits[lsb - 1].is_const() {
+                    lsb -= 1;
+                }
+                let text: String = bits[lsb..i].iter().rev().map(|x| x.to_char()).collect();
+                chunks.push(format!("{}'{}", i - lsb, text));
+                i = lsb;
+            } else if let Bit::Net(id) = msb {
+                let (name, hi, width) = names[&id];
+                let mut lo = hi;
+                let mut lsb = i - 1;
+                while lsb > 0 {
+                    match bits[lsb - 1] {
+                        Bit::Net(x) if lo > 0 && names[&x] == (name, lo - 1, width) => {
+                            lo -= 1;
+                            lsb -= 1;
+                        }
+                        _ => break,
+                    }
+                }
+                let name = escape(name);
+                if hi + 1 == width && lo == 0 {
+                    chunks.push(name);
+                } else if hi == lo {
+                    chunks.push(format!("{} [{}]", name, hi));
+                } else {
+                    chunks.push(format!("{} [{}:{}]", name, hi, lo));
+                }
+                i = lsb;
+            }
+        }
+        if chunks.len() == 1 {
+            chunks.pop().unwrap()
+        } else {
+            format!("{{ {} }}", chunks.join(" "))
+        }
+    }
+
+    /// Write as RTLIL text which can be read by `read_rtlil` of Yosys
+    pub fn to_rtlil(&self) -> String {
+        let mut ret = String::new();
+        let _ = writeln!(ret, "# Generated by Veryl {}", env!("CARGO_PKG_VERSION"));
+        let _ = writeln!(ret, "autoidx {}", self.index + 1);
+        for module in &self.modules {
+            let mut names = HashMap::new();
+            for wire in &module.wires {
+                for (i, bit) in wire.bits.iter().enumerate() {
+                    if let Bit::Net(id) = bit {
+                        names.insert(*id, (wire.name.as_str(), i, wire.bits.len()));
+                    }
+                }
+            }
+
+            let _ = writeln!(ret, "module {}", escape(&module.name));
+            let mut port = 0;
+            for wire in &module.wires {
+                let _ = write!(ret, "  wire width {}", wire.bits.len());
+                if let Some(x) = wire.direction {
+                    port += 1;
+                    let _ = write!(ret, " {} {}", x.as_str(), port);
+                }
+                let _ = writeln!(ret, " {}", escape(&wire.name));
+            }
+            for cell in &module.cells {
+                let _ = writeln!(
+                    ret,
+                    "  cell {} {}",
+                    escape(&cell.r#type),
+                    escape(&cell.name)
+                );
+                for (name, value) in &cell.parameters {
+                    let value = match value {
+                        ParameterValue::Integer(x) => format!("{}", x),
+                        ParameterValue::Bits(x) => {
+                            let text: String = x.iter().rev().map(|x| x.to_char()).collect();
+                            format!("{}'{}", x.len(), text)
+                        }
+                    };
+                    let _ = writeln!(ret, "    parameter {} {}", escape(name), value);
+                }
+                for port in &cell.ports {
+                    let _ = writeln!(
+                        ret,
+                        "    connect {} {}",
+                        escape(&port.name),
+                        self.sigspec(&port.bits, &names)
+                    );
+                }
+                let _ = writeln!(ret, "  end");
+            }
+            for (lhs, rhs) in &module.connections {
+                let _ = writeln!(
+                    ret,
+                    "  connect {} {}",
+                    self.sigspec(lhs, &names),
+                    self.sigspec(rhs, &names)
+                );
+            }
+            let _ = writeln!(ret, "end");
+        }
+        ret
+    }
+
+    /// Write as JSON netlist which can be read by `read_json` of Yosys or netlistsvg
+    pub fn to_json(&self) -> String {
+        let mut modules = Map::new();
+        for module in &self.modules {
+            // resolve connections as aliases of nets
+            let mut alias: HashMap<usize, Bit> = HashMap::new();
+            let find = |alias: &HashMap<usize, Bit>, mut bit: Bit| {
+                while let Bit::Net(id) = bit {
+                    if let Some(x) = alias.get(&id) {
+                        bit = *x;
+                    } else {
+                        break;
+                    }
+                }
+                bit
+            };
+            for (lhs, rhs) in &module.connections {
+                for (x, y) in lhs.iter().zip(rhs.iter()) {
+                    let x = find(&alias, *x);
+                    let y = find(&alias, *y);
+                    if x == y {
+                        continue;
+                    }
+                    match (x, y) {
+                        (Bit::Net(x), y) => {
+                            alias.insert(x, y);
+                        }
+                        (x, Bit::Net(y)) => {
+                            alias.insert(y, x);
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            let bits = |bits: &[Bit]| -> Value {
+                bits.iter()
+                    .map(|x| match find(&alias, *x) {
+                        Bit::Net(id) => json!(id + 2),
+                        x => json!(x.to_char().to_string()),
+                    })
+                    .collect()
+            };
+
+            let mut ports = Map::new();
+            let mut netnames = Map::new();
+            for wire in &module.wires {
+                if let Some(x) = wire.direction {
+                    ports.insert(
+                        wire.name.clone(),
+                        json!({"direction": x.as_str(), "bits": bits(&wire.bits)}),
+                    );
+                }
+                netnames.insert(
+                    wire.name.clone(),
+                    json!({
+                        "hide_name": wire.name.starts_with('$') as u8,
+                        "bits": bits(&wire.bits),
+                        "attributes": {},
+                    }),
+                );
+            }
+
+            let mut cells = Map::new();
+            for cell in &module.cells {
+                let mut parameters = Map::new();
+                for (name, value) in &cell.parameters {
+                    let value = match value {
+                        ParameterValue::Integer(x) => format!("{:032b}", *x as u32),
+                        ParameterValue::Bits(x) => x.iter().rev().map(|x| x.to_char()).collect(),
+                    };
+                    parameters.insert(name.clone(), json!(value));
+                }
+                let mut port_directions = Map::new();
+                let mut connections = Map::new();
+                for port in &cell.ports {
+                    if let Some(x) = port.direction {
+                        port_directions.insert(port.name.clone(), json!(x.as_str()));
+                    }
+                    connections.insert(port.name.clone(), bits(&port.bits));
+                }
+                cells.insert(
+                    cell.name.clone(),
+                    json!({
+                        "hide_name": cell.name.starts_with('$') as u8,
+                        "type": cell.r#type,
+                        "parameters": parameters,
+                        "attributes": {},
+                        "port_directions": port_directions,
+                        "connections": connections,
+                    }),
+                );
+            }
+
+            modules.insert(
+                module.name.clone(),
+                json!({
+                    "attributes": {},
+                    "ports": ports,
+                    "cells": cells,
+                    "netnames": netnames,
+                }),
+            );
+        }
+
+        let ret = json!({
+            "creator": format!("Veryl {}", env!("CARGO_PKG_VERSION")),
+            "modules": modules,
+        });
+        serde_json::to_string_pretty(&ret).unwrap()
+    }
+}
+
+fn escape(name: &str) -> String {
+    if name.starts_with('$') {
+        name.to_string()
+    } else {
+        format!("\\{}", name)
+    }
+}
+
+impl VerylWalker for Netlist {
+    /// Semantic action for non-terminal 'ModuleDeclaration'
+    fn module_declaration(&mut self, arg: &ModuleDeclaration) {
+        self.module = NetlistModule {
+            name: arg.identifier.identifier_token.text(),
+            ..Default::default()
+        };
+        self.signals.clear();
+        self.parameters.clear();
+        self.types.clear();
+        self.type_parameters.clear();
+
+        if let Some(ref x) = arg.module_declaration_opt {
+            if let Some(ref x) = x.with_parameter.with_parameter_opt {
+                let x = &x.with_parameter_list;
+                let mut items = vec![&*x.with_parameter_item];
+                for x in &x.with_parameter_list_list {
+                    items.push(&*x.with_parameter_item);
+                }
+                for item in items {
+                    let name = item.identifier.identifier_token.text();
+                    match &*item.with_parameter_item_group0 {
+                        WithParameterItemGroup0::Typ(_) => {
+                            self.type_parameters
+                                .insert(name, *item.parameter_value.clone());
+                        }
+                        WithParameterItemGroup0::Type(x) => match &*item.parameter_value {
+                            veryl_grammar_trait::ParameterValue::Expression(value) => {
+                                let width = self.type_width(&x.r#type);
+                                self.parameters
+                                    .insert(name, (*value.expression.clone(), width));
+                            }
+                            veryl_grammar_trait::ParameterValue::TypeValue(value) => {
+                                let token = builtin_token(&value.type_value.builtin_type);
+                                self.warning(token, "type can't be used as a value");
+                            }
+                        },
+                    }
+                }
+            }
+        }
+
+        for port in self.ports(arg) {
+            let token = &port.identifier.identifier_token;
+            match &*port.port_declaration_item_group {
+                PortDeclarationItemGroup::DirectionType(x) => {
+                    let direction = match &*x.direction {
+                        Direction::Input(_) => PortDirection::Input,
+                        Direction::Output(_) => PortDirection::Output,
+                        Direction::Inout(_) => PortDirection::Inout,
+                        _ => {
+                            self.warning(token, "direction of port is not supported");
+                            continue;
+                        }
+                    };
+                    if let Some(width) = self.type_width(&x.r#type) {
+                        let bits = self.new_wire(&token.text(), width, Some(direction));
+                        self.signals.insert(token.text(), bits);
+                    } else {
+                        self.warning(token, "type of port is not supported");
+                    }
+                }
+                PortDeclarationItemGroup::Interface(_) => {
+                    self.warning(token, "interface port is not supported");
+                }
+            }
+        }
+
+        let items: Vec<_> = arg
+            .module_declaration_list
+            .iter()
+            .map(|x| &*x.module_item)
+            .collect();
+        self.declare(&items);
+        self.lower(&items);
+
+        let module = std::mem::take(&mut self.module);
+        self.modules.push(module);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use veryl_parser::Parser;
+
+    const CODE: &str = r#"
+module Counter #(
+    parameter WIDTH: u32 = 4,
+) (
+    i_clk: input  logic       ,
+    i_rst: input  logic       ,
+    i_en : input  logic       ,
+    o_cnt: output logic[WIDTH],
+) {
+    var r_cnt: logic[WIDTH];
+
+    assign o_cnt = r_cnt;
+
+    always_ff (i_clk, i_rst) {
+        if_reset {
+            r_cnt = 0;
+        } else if i_en {
+            r_cnt += 1;
+        }
+    }
+}
+"#;
+
+    fn netlist() -> Netlist {
+        let parser = Parser::parse(CODE, &"").unwrap();
+        let mut netlist = Netlist::default();
+        netlist.build(&parser.veryl);
+        netlist
+    }
+
+    #[test]
+    fn lower() {
+        let netlist = netlist();
+        assert!(netlist.warnings.is_empty());
+
+        let module = &netlist.modules[0];
+        assert_eq!(module.name, "Counter");
+        let types: Vec<_> = module.cells.iter().map(|x| x.r#type.as_str()).collect();
+        assert_eq!(types, vec!["$add", "$mux", "$adff"]);
+        let adff = &module.cells[2];
+        let q = adff.ports.iter().find(|x| x.name == "Q").unwrap();
+        assert_eq!(q.bits.len(), 4);
+    }
+
+    #[test]
+    fn rtlil() {
+        let rtlil = netlist().to_rtlil();
+        assert!(rtlil.contains("module \\Counter\n"));
+        assert!(rtlil.contains("  wire width 4 output 4 \\o_cnt\n"));
+        assert!(rtlil.contains("    parameter \\ARST_VALUE 4'0000\n"));
+        assert!(rtlil.contains("  connect \\o_cnt \\r_cnt\n"));
+    }
+
+    #[test]
+    fn json() {
+        let json: Value = serde_json::from_str(&netlist().to_json()).unwrap();
+        let module = &json["modules"]["Counter"];
+        assert_eq!(module["ports"]["o_cnt"]["direction"], "output");
+        assert_eq!(
+            module["ports"]["o_cnt"]["bits"],
+            module["netnames"]["r_cnt"]["bits"]
+        );
+    }
+
+    #[test]
+    fn if_expression() {
+        let code = r#"
+module Select (
+    i_a: input  logic   ,
+    i_b: input  logic   ,
+    o_y: output logic[4],
+) {
+    assign o_y = if i_a { 4'd1 } else if i_b { 2'd2 } else { 1'b0 };
+}
+"#;
+        let parser = Parser::parse(code, &"").unwrap();
+        let mut netlist = Netlist::default();
+        netlist.build(&parser.veryl);
+        assert!(netlist.warnings.is_empty());
+
+        let module = &netlist.modules[0];
+        let types: Vec<_> = module.cells.iter().map(|x| x.r#type.as_str()).collect();
+        assert_eq!(types, vec!["$mux", "$mux"]);
+        for cell in &module.cells {
+            let y = cell.ports.iter().find(|x| x.name == "Y").unwrap();
+            assert_eq!(y.bits.len(), 4);
+        }
+    }
+
+    #[test]
+    fn unsupported_operator() {
+        let code = r#"
+module Compare (
+    i_a: input  logic[4],
+    i_b: input  logic[4],
+    o_x: output logic   ,
+    o_y: output logic[4],
+) {
+    assign o_x = i_a ==? i_b;
+    assign o_y = i_a ** i_b;
+}
+"#;
+        let parser = Parser::parse(code, &"").unwrap();
+        let mut netlist = Netlist::default();
+        netlist.build(&parser.veryl);
+        assert_eq!(
+            netlist.warnings,
+            vec!["8:22: wildcard equality is not supported"]
+        );
+
+        let module = &netlist.modules[0];
+        let types: Vec<_> = module.cells.iter().map(|x| x.r#type.as_str()).collect();
+        assert_eq!(types, vec!["$pow"]);
+    }
+}
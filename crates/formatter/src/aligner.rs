@@ -154,23 +154,12 @@ impl VerylWalker for Aligner {
         }
     }
 
-    /// Semantic action for non-terminal 'Expression'
-    fn expression(&mut self, arg: &Expression) {
-        self.expression01(&arg.expression01);
-        for x in &arg.expression_list {
-            self.space(1);
-            self.operator01(&x.operator01);
-            self.space(1);
-            self.expression01(&x.expression01);
-        }
-    }
-
     /// Semantic action for non-terminal 'Expression01'
     fn expression01(&mut self, arg: &Expression01) {
         self.expression02(&arg.expression02);
         for x in &arg.expression01_list {
             self.space(1);
-            self.operator02(&x.operator02);
+            self.operator01(&x.operator01);
             self.space(1);
             self.expression02(&x.expression02);
         }
@@ -181,7 +170,7 @@ impl VerylWalker for Aligner {
         self.expression03(&arg.expression03);
         for x in &arg.expression02_list {
             self.space(1);
-            self.operator03(&x.operator03);
+            self.operator02(&x.operator02);
             self.space(1);
             self.expression03(&x.expression03);
         }
@@ -192,7 +181,7 @@ impl VerylWalker for Aligner {
         self.expression04(&arg.expression04);
         for x in &arg.expression03_list {
             self.space(1);
-            self.operator04(&x.operator04);
+            self.operator03(&x.operator03);
             self.space(1);
             self.expression04(&x.expression04);
         }
@@ -203,7 +192,7 @@ impl VerylWalker for Aligner {
         self.expression05(&arg.expression05);
         for x in &arg.expression04_list {
             self.space(1);
-            self.operator05(&x.operator05);
+            self.operator04(&x.operator04);
             self.space(1);
             self.expression05(&x.expression05);
         }
@@ -214,7 +203,7 @@ impl VerylWalker for Aligner {
         self.expression06(&arg.expression06);
         for x in &arg.expression05_list {
             self.space(1);
-            self.operator06(&x.operator06);
+            self.operator05(&x.operator05);
             self.space(1);
             self.expression06(&x.expression06);
         }
@@ -225,7 +214,7 @@ impl VerylWalker for Aligner {
         self.expression07(&arg.expression07);
         for x in &arg.expression06_list {
             self.space(1);
-            self.operator07(&x.operator07);
+            self.operator06(&x.operator06);
             self.space(1);
             self.expression07(&x.expression07);
         }
@@ -236,7 +225,7 @@ impl VerylWalker for Aligner {
         self.expression08(&arg.expression08);
         for x in &arg.expression07_list {
             self.space(1);
-            self.operator08(&x.operator08);
+            self.operator07(&x.operator07);
             self.space(1);
             self.expression08(&x.expression08);
         }
@@ -247,7 +236,7 @@ impl VerylWalker for Aligner {
         self.expression09(&arg.expression09);
         for x in &arg.expression08_list {
             self.space(1);
-            self.operator09(&x.operator09);
+            self.operator08(&x.operator08);
             self.space(1);
             self.expression09(&x.expression09);
         }
@@ -258,10 +247,7 @@ impl VerylWalker for Aligner {
         self.expression10(&arg.expression10);
         for x in &arg.expression09_list {
             self.space(1);
-            match &*x.expression09_list_group {
-                Expression09ListGroup::Operator10(x) => self.operator10(&x.operator10),
-                Expression09ListGroup::Star(x) => self.star(&x.star),
-            }
+            self.operator09(&x.operator09);
             self.space(1);
             self.expression10(&x.expression10);
         }
@@ -272,12 +258,108 @@ impl VerylWalker for Aligner {
         self.expression11(&arg.expression11);
         for x in &arg.expression10_list {
             self.space(1);
-            self.operator11(&x.operator11);
+            match &*x.expression10_list_group {
+                Expression10ListGroup::Operator10(x) => self.operator10(&x.operator10),
+                Expression10ListGroup::Star(x) => self.star(&x.star),
+            }
             self.space(1);
             self.expression11(&x.expression11);
         }
     }
 
+    /// Semantic action for non-terminal 'Expression11'
+    fn expression11(&mut self, arg: &Expression11) {
+        self.expression12(&arg.expression12);
+        for x in &arg.expression11_list {
+            self.space(1);
+            self.operator11(&x.operator11);
+            self.space(1);
+            self.expression12(&x.expression12);
+        }
+    }
+
+    /// Semantic action for non-terminal 'Expression12'
+    fn expression12(&mut self, arg: &Expression12) {
+        for x in &arg.expression12_list {
+            match &*x.expression12_list_group {
+                Expression12ListGroup::UnaryOperator(x) => self.unary_operator(&x.unary_operator),
+                Expression12ListGroup::Operator03(x) => self.operator03(&x.operator03),
+                Expression12ListGroup::Operator04(x) => self.operator04(&x.operator04),
+                Expression12ListGroup::Operator05(x) => self.operator05(&x.operator05),
+                Expression12ListGroup::Operator09(x) => self.operator09(&x.operator09),
+            }
+        }
+        self.factor(&arg.factor);
+        if let Some(ref x) = arg.expression12_opt {
+            self.space(1);
+            self.r#as(&x.r#as);
+            self.space(1);
+            // cast type is not aligned and has no trailing space
+            let r#type = &x.r#type;
+            for x in &r#type.type_list {
+                self.type_modifier(&x.type_modifier);
+                self.space(1);
+            }
+            match &*r#type.type_group {
+                TypeGroup::BuiltinType(x) => self.builtin_type(&x.builtin_type),
+                TypeGroup::ScopedIdentifier(x) => self.scoped_identifier(&x.scoped_identifier),
+                TypeGroup::ModportIdentifier(x) => self.modport_identifier(&x.modport_identifier),
+            };
+            if !r#type.type_list0.is_empty() {
+                self.space(1);
+                for x in &r#type.type_list0 {
+                    self.width(&x.width);
+                }
+            }
+        }
+    }
+
+    /// Semantic action for non-terminal 'IfExpression'
+    fn if_expression(&mut self, arg: &IfExpression) {
+        self.r#if(&arg.r#if);
+        self.space(1);
+        self.expression(&arg.expression);
+        self.space(1);
+        self.l_brace(&arg.l_brace);
+        self.space(1);
+        self.expression(&arg.expression0);
+        self.space(1);
+        self.r_brace(&arg.r_brace);
+        for x in &arg.if_expression_list {
+            self.space(1);
+            self.r#else(&x.r#else);
+            self.space(1);
+            self.r#if(&x.r#if);
+            self.space(1);
+            self.expression(&x.expression);
+            self.space(1);
+            self.l_brace(&x.l_brace);
+            self.space(1);
+            self.expression(&x.expression0);
+            self.space(1);
+            self.r_brace(&x.r_brace);
+        }
+        self.space(1);
+        self.r#else(&arg.r#else);
+        self.space(1);
+        self.l_brace(&arg.l_brace0);
+        self.space(1);
+        self.expression(&arg.expression1);
+        self.space(1);
+        self.r_brace(&arg.r_brace0);
+    }
+
+    /// Semantic action for non-terminal 'TypeValue'
+    fn type_value(&mut self, arg: &TypeValue) {
+        self.builtin_type(&arg.builtin_type);
+        if !arg.type_value_list.is_empty() {
+            self.space(1);
+            for x in &arg.type_value_list {
+                self.width(&x.width);
+            }
+        }
+    }
+
     /// Semantic action for non-terminal 'FunctionCallArg'
     fn function_call_arg(&mut self, arg: &FunctionCallArg) {
         self.expression(&arg.expression);
@@ -308,7 +390,7 @@ impl VerylWalker for Aligner {
     /// Semantic action for non-terminal 'Type'
     fn r#type(&mut self, arg: &Type) {
         self.aligns[align_kind::TYPE].start_item();
-        if let Some(ref x) = arg.type_opt {
+        for x in &arg.type_list {
             self.type_modifier(&x.type_modifier);
             self.space(1);
         }
@@ -320,13 +402,24 @@ impl VerylWalker for Aligner {
         let loc = self.aligns[align_kind::TYPE].last_location;
         self.aligns[align_kind::TYPE].finish_item();
         self.aligns[align_kind::WIDTH].start_item();
-        if arg.type_list.is_empty() {
+        if arg.type_list0.is_empty() && arg.type_opt.is_none() {
             let loc = loc.unwrap();
             self.aligns[align_kind::WIDTH].dummy_location(loc);
         } else {
-            for x in &arg.type_list {
+            for x in &arg.type_list0 {
                 self.width(&x.width);
             }
+            if let Some(ref x) = arg.type_opt {
+                if !arg.type_list0.is_empty() {
+                    self.space(1);
+                }
+                self.unpacked(&x.array.unpacked);
+                self.space(1);
+                self.width(&x.array.width);
+                for x in &x.array.array_list {
+                    self.width(&x.width);
+                }
+            }
         }
         self.aligns[align_kind::WIDTH].finish_item();
     }
@@ -397,6 +490,17 @@ impl VerylWalker for Aligner {
         self.semicolon(&arg.semicolon);
     }
 
+    /// Semantic action for non-terminal 'TypeDeclaration'
+    fn type_declaration(&mut self, arg: &TypeDeclaration) {
+        self.typ(&arg.typ);
+        self.aligns[align_kind::IDENTIFIER].start_item();
+        self.identifier(&arg.identifier);
+        self.aligns[align_kind::IDENTIFIER].finish_item();
+        self.equ(&arg.equ);
+        self.r#type(&arg.r#type);
+        self.semicolon(&arg.semicolon);
+    }
+
     /// Semantic action for non-terminal 'AssignDeclaration'
     fn assign_declaration(&mut self, arg: &AssignDeclaration) {
         self.assign(&arg.assign);
@@ -463,7 +567,7 @@ impl VerylWalker for Aligner {
             self.colon(&x.colon);
             self.space(1);
             self.aligns[align_kind::EXPRESSION].start_item();
-            self.expression(&x.expression);
+            self.parameter_value(&x.parameter_value);
             self.aligns[align_kind::EXPRESSION].finish_item();
         } else {
             self.insert(&arg.identifier.identifier_token, ": ".len());
@@ -504,10 +608,21 @@ impl VerylWalker for Aligner {
         self.identifier(&arg.identifier);
         self.aligns[align_kind::IDENTIFIER].finish_item();
         self.colon(&arg.colon);
-        self.r#type(&arg.r#type);
+        match &*arg.with_parameter_item_group0 {
+            WithParameterItemGroup0::Typ(x) => {
+                self.aligns[align_kind::TYPE].start_item();
+                self.typ(&x.typ);
+                let loc = self.aligns[align_kind::TYPE].last_location;
+                self.aligns[align_kind::TYPE].finish_item();
+                self.aligns[align_kind::WIDTH].start_item();
+                self.aligns[align_kind::WIDTH].dummy_location(loc.unwrap());
+                self.aligns[align_kind::WIDTH].finish_item();
+            }
+            WithParameterItemGroup0::Type(x) => self.r#type(&x.r#type),
+        }
         self.equ(&arg.equ);
         self.aligns[align_kind::EXPRESSION].start_item();
-        self.expression(&arg.expression);
+        self.parameter_value(&arg.parameter_value);
         self.aligns[align_kind::EXPRESSION].finish_item();
     }
 
@@ -558,4 +673,18 @@ impl VerylWalker for Aligner {
         }
         self.r_brace(&arg.r_brace);
     }
+
+    /// Semantic action for non-terminal 'TestDeclaration'
+    fn test_declaration(&mut self, arg: &TestDeclaration) {
+        self.test(&arg.test);
+        self.identifier(&arg.identifier);
+        self.l_brace(&arg.l_brace);
+        self.inst_declaration(&arg.inst_declaration);
+        // instance name is not aligned with the following statements
+        self.finish_group();
+        for x in &arg.test_declaration_list {
+            self.test_item(&x.test_item);
+        }
+        self.r_brace(&arg.r_brace);
+    }
 }
@@ -1,4 +1,5 @@
 use crate::aligner::{Aligner, Location};
+use std::ops::Range;
 use veryl_metadata::Metadata;
 use veryl_parser::resource_table;
 use veryl_parser::veryl_grammar_trait::*;
@@ -16,6 +17,9 @@ pub struct Formatter {
     consumed_next_newline: bool,
     single_line: bool,
     adjust_line: bool,
+    in_description_head: bool,
+    /// Source offset and output offset of the beginning of each description
+    descriptions: Vec<(usize, usize)>,
 }
 
 impl Default for Formatter {
@@ -31,6 +35,8 @@ impl Default for Formatter {
             consumed_next_newline: false,
             single_line: false,
             adjust_line: false,
+            in_description_head: false,
+            descriptions: Vec::new(),
         }
     }
 }
@@ -48,6 +54,39 @@ impl Formatter {
         self.veryl(input);
     }
 
+    /// Format descriptions which intersect `range` of the source
+    ///
+    /// The replaced range of the source and the formatted text are returned.
+    /// Comments and blank lines after a description are included in it,
+    /// so the result is as same as the corresponding part of `format`.
+    pub fn format_range(
+        &mut self,
+        input: &Veryl,
+        source_len: usize,
+        range: Range<usize>,
+    ) -> Option<(Range<usize>, String)> {
+        self.format(input);
+
+        let len = self.descriptions.len();
+        let end = |i: usize| -> (usize, usize) {
+            if i + 1 < len {
+                self.descriptions[i + 1]
+            } else {
+                (source_len, self.string.len())
+            }
+        };
+        let intersected: Vec<_> = (0..len)
+            .filter(|i| self.descriptions[*i].0 <= range.end && range.start < end(*i).0)
+            .collect();
+        let first = *intersected.first()?;
+        let last = *intersected.last()?;
+
+        let (source_begin, output_begin) = self.descriptions[first];
+        let (source_end, output_end) = end(last);
+        let text = self.string[output_begin..output_end].to_string();
+        Some((source_begin..source_end, text))
+    }
+
     pub fn as_str(&self) -> &str {
         &self.string
     }
@@ -114,6 +153,10 @@ impl Formatter {
             self.newline();
         }
         self.adjust_line = false;
+        if self.in_description_head {
+            self.descriptions.push((x.pos, self.string.len()));
+            self.in_description_head = false;
+        }
         let text = resource_table::get_str_value(x.text).unwrap();
         let text = if text.ends_with('\n') {
             self.consumed_next_newline = true;
@@ -177,23 +220,12 @@ impl VerylWalker for Formatter {
         self.token(arg);
     }
 
-    /// Semantic action for non-terminal 'Expression'
-    fn expression(&mut self, arg: &Expression) {
-        self.expression01(&arg.expression01);
-        for x in &arg.expression_list {
-            self.space(1);
-            self.operator01(&x.operator01);
-            self.space(1);
-            self.expression01(&x.expression01);
-        }
-    }
-
     /// Semantic action for non-terminal 'Expression01'
     fn expression01(&mut self, arg: &Expression01) {
         self.expression02(&arg.expression02);
         for x in &arg.expression01_list {
             self.space(1);
-            self.operator02(&x.operator02);
+            self.operator01(&x.operator01);
             self.space(1);
             self.expression02(&x.expression02);
         }
@@ -204,7 +236,7 @@ impl VerylWalker for Formatter {
         self.expression03(&arg.expression03);
         for x in &arg.expression02_list {
             self.space(1);
-            self.operator03(&x.operator03);
+            self.operator02(&x.operator02);
             self.space(1);
             self.expression03(&x.expression03);
         }
@@ -215,7 +247,7 @@ impl VerylWalker for Formatter {
         self.expression04(&arg.expression04);
         for x in &arg.expression03_list {
             self.space(1);
-            self.operator04(&x.operator04);
+            self.operator03(&x.operator03);
             self.space(1);
             self.expression04(&x.expression04);
         }
@@ -226,7 +258,7 @@ impl VerylWalker for Formatter {
         self.expression05(&arg.expression05);
         for x in &arg.expression04_list {
             self.space(1);
-            self.operator05(&x.operator05);
+            self.operator04(&x.operator04);
             self.space(1);
             self.expression05(&x.expression05);
         }
@@ -237,7 +269,7 @@ impl VerylWalker for Formatter {
         self.expression06(&arg.expression06);
         for x in &arg.expression05_list {
             self.space(1);
-            self.operator06(&x.operator06);
+            self.operator05(&x.operator05);
             self.space(1);
             self.expression06(&x.expression06);
         }
@@ -248,7 +280,7 @@ impl VerylWalker for Formatter {
         self.expression07(&arg.expression07);
         for x in &arg.expression06_list {
             self.space(1);
-            self.operator07(&x.operator07);
+            self.operator06(&x.operator06);
             self.space(1);
             self.expression07(&x.expression07);
         }
@@ -259,7 +291,7 @@ impl VerylWalker for Formatter {
         self.expression08(&arg.expression08);
         for x in &arg.expression07_list {
             self.space(1);
-            self.operator08(&x.operator08);
+            self.operator07(&x.operator07);
             self.space(1);
             self.expression08(&x.expression08);
         }
@@ -270,7 +302,7 @@ impl VerylWalker for Formatter {
         self.expression09(&arg.expression09);
         for x in &arg.expression08_list {
             self.space(1);
-            self.operator09(&x.operator09);
+            self.operator08(&x.operator08);
             self.space(1);
             self.expression09(&x.expression09);
         }
@@ -281,10 +313,7 @@ impl VerylWalker for Formatter {
         self.expression10(&arg.expression10);
         for x in &arg.expression09_list {
             self.space(1);
-            match &*x.expression09_list_group {
-                Expression09ListGroup::Operator10(x) => self.operator10(&x.operator10),
-                Expression09ListGroup::Star(x) => self.star(&x.star),
-            }
+            self.operator09(&x.operator09);
             self.space(1);
             self.expression10(&x.expression10);
         }
@@ -295,12 +324,108 @@ impl VerylWalker for Formatter {
         self.expression11(&arg.expression11);
         for x in &arg.expression10_list {
             self.space(1);
-            self.operator11(&x.operator11);
+            match &*x.expression10_list_group {
+                Expression10ListGroup::Operator10(x) => self.operator10(&x.operator10),
+                Expression10ListGroup::Star(x) => self.star(&x.star),
+            }
             self.space(1);
             self.expression11(&x.expression11);
         }
     }
 
+    /// Semantic action for non-terminal 'Expression11'
+    fn expression11(&mut self, arg: &Expression11) {
+        self.expression12(&arg.expression12);
+        for x in &arg.expression11_list {
+            self.space(1);
+            self.operator11(&x.operator11);
+            self.space(1);
+            self.expression12(&x.expression12);
+        }
+    }
+
+    /// Semantic action for non-terminal 'Expression12'
+    fn expression12(&mut self, arg: &Expression12) {
+        for x in &arg.expression12_list {
+            match &*x.expression12_list_group {
+                Expression12ListGroup::UnaryOperator(x) => self.unary_operator(&x.unary_operator),
+                Expression12ListGroup::Operator03(x) => self.operator03(&x.operator03),
+                Expression12ListGroup::Operator04(x) => self.operator04(&x.operator04),
+                Expression12ListGroup::Operator05(x) => self.operator05(&x.operator05),
+                Expression12ListGroup::Operator09(x) => self.operator09(&x.operator09),
+            }
+        }
+        self.factor(&arg.factor);
+        if let Some(ref x) = arg.expression12_opt {
+            self.space(1);
+            self.r#as(&x.r#as);
+            self.space(1);
+            // cast type is not aligned and has no trailing space
+            let r#type = &x.r#type;
+            for x in &r#type.type_list {
+                self.type_modifier(&x.type_modifier);
+                self.space(1);
+            }
+            match &*r#type.type_group {
+                TypeGroup::BuiltinType(x) => self.builtin_type(&x.builtin_type),
+                TypeGroup::ScopedIdentifier(x) => self.scoped_identifier(&x.scoped_identifier),
+                TypeGroup::ModportIdentifier(x) => self.modport_identifier(&x.modport_identifier),
+            };
+            if !r#type.type_list0.is_empty() {
+                self.space(1);
+                for x in &r#type.type_list0 {
+                    self.width(&x.width);
+                }
+            }
+        }
+    }
+
+    /// Semantic action for non-terminal 'IfExpression'
+    fn if_expression(&mut self, arg: &IfExpression) {
+        self.r#if(&arg.r#if);
+        self.space(1);
+        self.expression(&arg.expression);
+        self.space(1);
+        self.l_brace(&arg.l_brace);
+        self.space(1);
+        self.expression(&arg.expression0);
+        self.space(1);
+        self.r_brace(&arg.r_brace);
+        for x in &arg.if_expression_list {
+            self.space(1);
+            self.r#else(&x.r#else);
+            self.space(1);
+            self.r#if(&x.r#if);
+            self.space(1);
+            self.expression(&x.expression);
+            self.space(1);
+            self.l_brace(&x.l_brace);
+            self.space(1);
+            self.expression(&x.expression0);
+            self.space(1);
+            self.r_brace(&x.r_brace);
+        }
+        self.space(1);
+        self.r#else(&arg.r#else);
+        self.space(1);
+        self.l_brace(&arg.l_brace0);
+        self.space(1);
+        self.expression(&arg.expression1);
+        self.space(1);
+        self.r_brace(&arg.r_brace0);
+    }
+
+    /// Semantic action for non-terminal 'TypeValue'
+    fn type_value(&mut self, arg: &TypeValue) {
+        self.builtin_type(&arg.builtin_type);
+        if !arg.type_value_list.is_empty() {
+            self.space(1);
+            for x in &arg.type_value_list {
+                self.width(&x.width);
+            }
+        }
+    }
+
     /// Semantic action for non-terminal 'FunctionCallArg'
     fn function_call_arg(&mut self, arg: &FunctionCallArg) {
         self.expression(&arg.expression);
@@ -354,7 +479,7 @@ impl VerylWalker for Formatter {
 
     /// Semantic action for non-terminal 'Type'
     fn r#type(&mut self, arg: &Type) {
-        if let Some(ref x) = arg.type_opt {
+        for x in &arg.type_list {
             self.type_modifier(&x.type_modifier);
             self.space(1);
         }
@@ -364,7 +489,23 @@ impl VerylWalker for Formatter {
             TypeGroup::ModportIdentifier(x) => self.modport_identifier(&x.modport_identifier),
         };
         self.space(1);
-        for x in &arg.type_list {
+        for x in &arg.type_list0 {
+            self.width(&x.width);
+        }
+        if let Some(ref x) = arg.type_opt {
+            if !arg.type_list0.is_empty() {
+                self.space(1);
+            }
+            self.array(&x.array);
+        }
+    }
+
+    /// Semantic action for non-terminal 'Array'
+    fn array(&mut self, arg: &Array) {
+        self.unpacked(&arg.unpacked);
+        self.space(1);
+        self.width(&arg.width);
+        for x in &arg.array_list {
             self.width(&x.width);
         }
     }
@@ -494,6 +635,14 @@ impl VerylWalker for Formatter {
         self.semicolon(&arg.semicolon);
     }
 
+    /// Semantic action for non-terminal 'AssertionStatement'
+    fn assertion_statement(&mut self, arg: &AssertionStatement) {
+        self.assertion_kind(&arg.assertion_kind);
+        self.space(1);
+        self.expression(&arg.expression);
+        self.semicolon(&arg.semicolon);
+    }
+
     /// Semantic action for non-terminal 'ForStatement'
     fn for_statement(&mut self, arg: &ForStatement) {
         self.r#for(&arg.r#for);
@@ -572,6 +721,19 @@ impl VerylWalker for Formatter {
         }
     }
 
+    /// Semantic action for non-terminal 'AttributeList'
+    fn attribute_list(&mut self, arg: &AttributeList) {
+        self.attribute_item(&arg.attribute_item);
+        for x in &arg.attribute_list_list {
+            self.comma(&x.comma);
+            self.space(1);
+            self.attribute_item(&x.attribute_item);
+        }
+        if let Some(ref x) = arg.attribute_list_opt {
+            self.token(&x.comma.comma_token.replace(""));
+        }
+    }
+
     /// Semantic action for non-terminal 'VarDeclaration'
     fn var_declaration(&mut self, arg: &VarDeclaration) {
         self.var(&arg.var);
@@ -604,6 +766,18 @@ impl VerylWalker for Formatter {
         self.semicolon(&arg.semicolon);
     }
 
+    /// Semantic action for non-terminal 'TypeDeclaration'
+    fn type_declaration(&mut self, arg: &TypeDeclaration) {
+        self.typ(&arg.typ);
+        self.space(1);
+        self.identifier(&arg.identifier);
+        self.space(1);
+        self.equ(&arg.equ);
+        self.space(1);
+        self.r#type(&arg.r#type);
+        self.semicolon(&arg.semicolon);
+    }
+
     /// Semantic action for non-terminal 'AlwaysFfDeclaration'
     fn always_ff_declaration(&mut self, arg: &AlwaysFfDeclaration) {
         self.always_ff(&arg.always_ff);
@@ -671,6 +845,55 @@ impl VerylWalker for Formatter {
         self.r_brace(&arg.r_brace);
     }
 
+    /// Semantic action for non-terminal 'AssertionDeclaration'
+    fn assertion_declaration(&mut self, arg: &AssertionDeclaration) {
+        self.assertion_kind(&arg.assertion_kind);
+        self.space(1);
+        self.l_paren(&arg.l_paren);
+        self.always_ff_clock(&arg.always_ff_clock);
+        if let Some(ref x) = arg.assertion_declaration_opt {
+            self.comma(&x.comma);
+            self.space(1);
+            self.always_ff_reset(&x.always_ff_reset);
+        }
+        self.r_paren(&arg.r_paren);
+        self.space(1);
+        self.expression(&arg.expression);
+        self.semicolon(&arg.semicolon);
+    }
+
+    /// Semantic action for non-terminal 'InitialDeclaration'
+    fn initial_declaration(&mut self, arg: &InitialDeclaration) {
+        self.initial(&arg.initial);
+        self.space(1);
+        self.token_will_push(&arg.l_brace.l_brace_token);
+        self.newline_push();
+        for (i, x) in arg.initial_declaration_list.iter().enumerate() {
+            if i != 0 {
+                self.newline();
+            }
+            self.statement(&x.statement);
+        }
+        self.newline_pop();
+        self.r_brace(&arg.r_brace);
+    }
+
+    /// Semantic action for non-terminal 'FinalDeclaration'
+    fn final_declaration(&mut self, arg: &FinalDeclaration) {
+        self.r#final(&arg.r#final);
+        self.space(1);
+        self.token_will_push(&arg.l_brace.l_brace_token);
+        self.newline_push();
+        for (i, x) in arg.final_declaration_list.iter().enumerate() {
+            if i != 0 {
+                self.newline();
+            }
+            self.statement(&x.statement);
+        }
+        self.newline_pop();
+        self.r_brace(&arg.r_brace);
+    }
+
     /// Semantic action for non-terminal 'AssignDeclaration'
     fn assign_declaration(&mut self, arg: &AssignDeclaration) {
         self.assign(&arg.assign);
@@ -763,8 +986,12 @@ impl VerylWalker for Formatter {
 
     /// Semantic action for non-terminal 'StructDeclaration'
     fn struct_declaration(&mut self, arg: &StructDeclaration) {
-        self.r#struct(&arg.r#struct);
+        self.struct_union(&arg.struct_union);
         self.space(1);
+        if let Some(ref x) = arg.struct_declaration_opt {
+            self.unpacked(&x.unpacked);
+            self.space(1);
+        }
         self.identifier(&arg.identifier);
         self.space(1);
         self.token_will_push(&arg.l_brace.l_brace_token);
@@ -873,7 +1100,7 @@ impl VerylWalker for Formatter {
         if let Some(ref x) = arg.inst_parameter_item_opt {
             self.colon(&x.colon);
             self.space(1);
-            self.expression(&x.expression);
+            self.parameter_value(&x.parameter_value);
         }
     }
 
@@ -943,11 +1170,17 @@ impl VerylWalker for Formatter {
         self.identifier(&arg.identifier);
         self.colon(&arg.colon);
         self.space(1);
-        self.r#type(&arg.r#type);
+        match &*arg.with_parameter_item_group0 {
+            WithParameterItemGroup0::Typ(x) => {
+                self.typ(&x.typ);
+                self.space(1);
+            }
+            WithParameterItemGroup0::Type(x) => self.r#type(&x.r#type),
+        }
         self.space(1);
         self.equ(&arg.equ);
         self.space(1);
-        self.expression(&arg.expression);
+        self.parameter_value(&arg.parameter_value);
     }
 
     /// Semantic action for non-terminal 'PortDeclaration'
@@ -981,6 +1214,10 @@ impl VerylWalker for Formatter {
 
     /// Semantic action for non-terminal 'PortDeclarationItem'
     fn port_declaration_item(&mut self, arg: &PortDeclarationItem) {
+        for x in &arg.port_declaration_item_list {
+            self.attribute(&x.attribute);
+            self.newline();
+        }
         self.identifier(&arg.identifier);
         self.colon(&arg.colon);
         self.space(1);
@@ -1073,6 +1310,10 @@ impl VerylWalker for Formatter {
             if i != 0 {
                 self.newline();
             }
+            for x in &x.module_declaration_list_list {
+                self.attribute(&x.attribute);
+                self.newline();
+            }
             self.module_item(&x.module_item);
         }
         self.newline_pop();
@@ -1138,6 +1379,10 @@ impl VerylWalker for Formatter {
             if i != 0 {
                 self.newline();
             }
+            for x in &x.module_named_block_list_list {
+                self.attribute(&x.attribute);
+                self.newline();
+            }
             self.module_item(&x.module_item);
         }
         self.newline_pop();
@@ -1157,6 +1402,10 @@ impl VerylWalker for Formatter {
             if i != 0 {
                 self.newline();
             }
+            for x in &x.module_optional_named_block_list_list {
+                self.attribute(&x.attribute);
+                self.newline();
+            }
             self.module_item(&x.module_item);
         }
         self.newline_pop();
@@ -1287,6 +1536,44 @@ impl VerylWalker for Formatter {
         self.r_brace(&arg.r_brace);
     }
 
+    /// Semantic action for non-terminal 'TestDeclaration'
+    fn test_declaration(&mut self, arg: &TestDeclaration) {
+        self.test(&arg.test);
+        self.space(1);
+        self.identifier(&arg.identifier);
+        self.space(1);
+        self.token_will_push(&arg.l_brace.l_brace_token);
+        self.newline_push();
+        self.inst_declaration(&arg.inst_declaration);
+        for x in &arg.test_declaration_list {
+            self.newline();
+            self.test_item(&x.test_item);
+        }
+        self.newline_pop();
+        self.r_brace(&arg.r_brace);
+    }
+
+    /// Semantic action for non-terminal 'StepStatement'
+    fn step_statement(&mut self, arg: &StepStatement) {
+        self.step(&arg.step);
+        self.space(1);
+        self.hierarchical_identifier(&arg.hierarchical_identifier);
+        if let Some(ref x) = arg.step_statement_opt {
+            self.comma(&x.comma);
+            self.space(1);
+            self.expression(&x.expression);
+        }
+        self.semicolon(&arg.semicolon);
+    }
+
+    /// Semantic action for non-terminal 'ExpectStatement'
+    fn expect_statement(&mut self, arg: &ExpectStatement) {
+        self.expect(&arg.expect);
+        self.space(1);
+        self.expression(&arg.expression);
+        self.semicolon(&arg.semicolon);
+    }
+
     /// Semantic action for non-terminal 'Veryl'
     fn veryl(&mut self, arg: &Veryl) {
         self.in_start_token = true;
@@ -1299,6 +1586,11 @@ impl VerylWalker for Formatter {
             if i != 0 {
                 self.newline();
             }
+            for x in &x.veryl_list_list {
+                self.attribute(&x.attribute);
+                self.newline();
+            }
+            self.in_description_head = true;
             self.description(&x.description);
         }
         self.newline();
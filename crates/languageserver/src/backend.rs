@@ -1,32 +1,41 @@
+use crate::call_hierarchy;
+use crate::code_action;
+use crate::completion;
+use crate::document_symbol;
+use crate::formatting;
+use crate::inlay_hint;
+use crate::semantic_token;
+use crate::signature_help;
 use dashmap::DashMap;
 use glob::glob;
 use ropey::Rope;
 use serde_json::Value;
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Mutex;
+use std::time::Duration;
 use tower_lsp::jsonrpc::Result;
 use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer};
+use veryl_analyzer::reference_table::{self, ReferenceKind};
 use veryl_analyzer::symbol_table::Name;
 use veryl_analyzer::{namespace_table, symbol_table, Analyzer};
 use veryl_formatter::Formatter;
-use veryl_metadata::Metadata;
+use veryl_metadata::{Build, Metadata};
 use veryl_parser::veryl_token::Token;
 use veryl_parser::veryl_walker::VerylWalker;
 use veryl_parser::{miette, resource_table, Finder, Parser, ParserError};
 
+/// Delay of analysis after the last change
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
 #[derive(Debug)]
 pub struct Backend {
     client: Client,
     root_uri: Mutex<Option<Url>>,
     document_map: DashMap<String, Rope>,
     parser_map: DashMap<String, Parser>,
-}
-
-struct TextDocumentItem {
-    uri: Url,
-    text: String,
-    version: i32,
+    version_map: DashMap<String, i32>,
 }
 
 impl Backend {
@@ -36,27 +45,99 @@ impl Backend {
             root_uri: Mutex::new(None),
             document_map: DashMap::new(),
             parser_map: DashMap::new(),
+            version_map: DashMap::new(),
+        }
+    }
+
+    /// Apply the change to the document
+    fn apply_change(rope: &mut Rope, change: TextDocumentContentChangeEvent) {
+        if let Some(range) = change.range {
+            let begin = Backend::to_char(rope, &range.start);
+            let end = Backend::to_char(rope, &range.end);
+            rope.remove(begin..end);
+            rope.insert(begin, &change.text);
+        } else {
+            *rope = Rope::from_str(&change.text);
+        }
+    }
+
+    /// Char index of the position whose character is counted by UTF-16 code units
+    pub fn to_char(rope: &Rope, position: &Position) -> usize {
+        let line = position.line as usize;
+        if line >= rope.len_lines() {
+            return rope.len_chars();
         }
+        let line_begin = rope.char_to_utf16_cu(rope.line_to_char(line));
+        let utf16_cu = line_begin + position.character as usize;
+        rope.utf16_cu_to_char(utf16_cu.min(rope.len_utf16_cu()))
     }
 
-    async fn on_change(&self, params: TextDocumentItem) {
-        let path = params.uri.to_string();
-        let rope = Rope::from_str(&params.text);
+    /// Position of the byte offset whose character is counted by UTF-16 code units
+    pub fn to_position(rope: &Rope, byte: usize) -> Position {
+        let char = rope.byte_to_char(byte);
+        let line = rope.char_to_line(char);
+        let line_begin = rope.char_to_utf16_cu(rope.line_to_char(line));
+        let character = rope.char_to_utf16_cu(char) - line_begin;
+        Position::new(line as u32, character as u32)
+    }
+
+    /// Format descriptions which intersect `range` with the current text
+    fn format_range(&self, uri: &Url, range: &Range) -> Option<Vec<TextEdit>> {
+        let metadata = Metadata::load(Metadata::search_from(uri.path()).ok()?).ok()?;
+        let path = uri.to_string();
+        let rope = self.document_map.get(&path)?.clone();
+        let parser = Parser::parse(&rope.to_string(), &path).ok()?;
+        let mut formatter = Formatter::new(&metadata);
+        let edit = formatting::range_formatting(&mut formatter, &parser.veryl, &rope, range);
+        Some(edit.into_iter().collect())
+    }
+
+    /// Whether `version` is still the latest version of the document
+    fn is_latest(&self, path: &str, version: i32) -> bool {
+        self.version_map
+            .get(path)
+            .map(|x| *x == version)
+            .unwrap_or(true)
+    }
+
+    /// Analyze the current document and publish diagnostics
+    ///
+    /// The analysis is cancelled at each stage if a newer version arrives,
+    /// because its result will be overwritten by the analysis of the newer version.
+    async fn on_change(&self, uri: Url, version: i32) {
+        let path = uri.to_string();
+        let rope = match self.document_map.get(&path) {
+            Some(x) => x.clone(),
+            None => return,
+        };
         let text = rope.to_string();
 
-        let diag = match Parser::parse(&text, &path) {
+        let parsed = Parser::parse(&text, &path);
+
+        // handle pending changes before analysis
+        tokio::task::yield_now().await;
+        if !self.is_latest(&path, version) {
+            return;
+        }
+
+        let diag = match parsed {
             Ok(x) => {
                 if let Some(path) = resource_table::get_path_id(Path::new(&path).to_path_buf()) {
                     symbol_table::drop(path);
                     namespace_table::drop(path);
+                    reference_table::drop(path);
                 }
                 let mut analyzer = Analyzer::new(&text);
                 let mut errors = analyzer.analyze(&x.veryl);
+                errors.append(&mut analyzer.take_warnings());
                 let ret: Vec<_> = errors
                     .drain(0..)
                     .map(|x| {
+                        let data = code_action::fix_data(x.fixes(), &rope);
                         let x: miette::ErrReport = x.into();
-                        Backend::to_diag(x, &rope)
+                        let mut diag = Backend::to_diag(x, &rope);
+                        diag.data = data;
+                        diag
                     })
                     .collect();
                 self.parser_map.insert(path.clone(), x);
@@ -67,11 +148,12 @@ impl Backend {
                 vec![Backend::to_diag(x, &rope)]
             }
         };
-        self.client
-            .publish_diagnostics(params.uri, diag, Some(params.version))
-            .await;
 
-        self.document_map.insert(path, rope);
+        if self.is_latest(&path, version) {
+            self.client
+                .publish_diagnostics(uri, diag, Some(version))
+                .await;
+        }
     }
 
     async fn background_analyze(&self, path: &Path) {
@@ -86,6 +168,7 @@ impl Backend {
                     {
                         symbol_table::drop(path);
                         namespace_table::drop(path);
+                        reference_table::drop(path);
                     }
                     let mut analyzer = Analyzer::new(&text);
                     let _ = analyzer.analyze(&x.veryl);
@@ -117,6 +200,12 @@ impl Backend {
             .code()
             .map(|d| NumberOrString::String(format!("{d}")));
 
+        let severity = match miette_diag.severity() {
+            Some(miette::Severity::Warning) => DiagnosticSeverity::WARNING,
+            Some(miette::Severity::Advice) => DiagnosticSeverity::HINT,
+            _ => DiagnosticSeverity::ERROR,
+        };
+
         let message = if let Some(x) = err.downcast_ref::<ParserError>() {
             match x {
                 ParserError::PredictionErrorWithExpectations {
@@ -129,13 +218,15 @@ impl Backend {
                 }
                 _ => format!("Syntax Error: {}", x),
             }
+        } else if severity == DiagnosticSeverity::WARNING {
+            format!("Semantic Warning: {}", err)
         } else {
             format!("Semantic Error: {}", err)
         };
 
         Diagnostic::new(
             range,
-            Some(DiagnosticSeverity::ERROR),
+            Some(severity),
             code,
             Some(String::from("veryl-ls")),
             message,
@@ -154,6 +245,15 @@ impl Backend {
         }
     }
 
+    fn find_token(&self, uri: &Url, position: &Position) -> Option<Token> {
+        let parser = self.parser_map.get(&uri.to_string())?;
+        let mut finder = Finder::new();
+        finder.line = position.line as usize + 1;
+        finder.column = position.character as usize + 1;
+        finder.veryl(&parser.veryl);
+        finder.token
+    }
+
     fn to_location(token: &Token) -> Location {
         let line = token.line as u32 - 1;
         let column = token.column as u32 - 1;
@@ -165,6 +265,23 @@ impl Backend {
         );
         Location { uri, range }
     }
+
+    /// `textDocument/inlayHint` which is not supported by `LanguageServer` yet
+    pub async fn inlay_hint(&self, params: InlayHintParams) -> Result<Option<Vec<InlayHint>>> {
+        let uri = params.text_document.uri;
+        let path = uri.to_string();
+        let build = match Metadata::search_from(uri.path()).and_then(Metadata::load) {
+            Ok(metadata) => metadata.build,
+            Err(_) => Build::default(),
+        };
+        if let (Some(parser), Some(rope)) =
+            (self.parser_map.get(&path), self.document_map.get(&path))
+        {
+            let hints = inlay_hint::inlay_hints(&parser.veryl, &rope, &build, &params.range);
+            return Ok(Some(hints));
+        }
+        Ok(None)
+    }
 }
 
 #[tower_lsp::async_trait]
@@ -179,7 +296,7 @@ impl LanguageServer for Backend {
         Ok(InitializeResult {
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(
-                    TextDocumentSyncKind::FULL,
+                    TextDocumentSyncKind::INCREMENTAL,
                 )),
                 workspace: Some(WorkspaceServerCapabilities {
                     workspace_folders: Some(WorkspaceFoldersServerCapabilities {
@@ -190,14 +307,56 @@ impl LanguageServer for Backend {
                 }),
                 definition_provider: Some(OneOf::Left(true)),
                 document_formatting_provider: Some(OneOf::Left(true)),
+                document_range_formatting_provider: Some(OneOf::Left(true)),
+                document_on_type_formatting_provider: Some(DocumentOnTypeFormattingOptions {
+                    first_trigger_character: "}".to_string(),
+                    more_trigger_character: Some(vec![";".to_string()]),
+                }),
                 workspace_symbol_provider: Some(OneOf::Left(true)),
+                document_symbol_provider: Some(OneOf::Left(true)),
+                call_hierarchy_provider: Some(CallHierarchyServerCapability::Simple(true)),
+                code_action_provider: Some(CodeActionProviderCapability::Options(
+                    CodeActionOptions {
+                        code_action_kinds: Some(vec![
+                            CodeActionKind::QUICKFIX,
+                            CodeActionKind::REFACTOR_REWRITE,
+                        ]),
+                        work_done_progress_options: WorkDoneProgressOptions::default(),
+                        resolve_provider: None,
+                    },
+                )),
+                inlay_hint_provider: Some(OneOf::Left(true)),
+                signature_help_provider: Some(SignatureHelpOptions {
+                    trigger_characters: Some(vec!["(".to_string(), ",".to_string()]),
+                    retrigger_characters: None,
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                }),
+                references_provider: Some(OneOf::Left(true)),
+                semantic_tokens_provider: Some(
+                    SemanticTokensOptions {
+                        legend: semantic_token::legend(),
+                        range: Some(true),
+                        full: Some(SemanticTokensFullOptions::Bool(true)),
+                        ..SemanticTokensOptions::default()
+                    }
+                    .into(),
+                ),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                })),
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
+                completion_provider: Some(CompletionOptions {
+                    trigger_characters: Some(vec![":".to_string()]),
+                    ..CompletionOptions::default()
+                }),
                 ..ServerCapabilities::default()
             },
             server_info: Some(ServerInfo {
                 name: String::from("veryl-ls"),
                 version: Some(String::from(env!("CARGO_PKG_VERSION"))),
             }),
+            offset_encoding: None,
         })
     }
 
@@ -242,27 +401,39 @@ impl LanguageServer for Backend {
     }
 
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        let uri = params.text_document.uri;
+        let path = uri.to_string();
+        let version = params.text_document.version;
+        self.document_map
+            .insert(path.clone(), Rope::from_str(&params.text_document.text));
+        self.version_map.insert(path, version);
+
         self.client.log_message(MessageType::INFO, "did_open").await;
 
-        self.on_change(TextDocumentItem {
-            uri: params.text_document.uri,
-            text: params.text_document.text,
-            version: params.text_document.version,
-        })
-        .await
+        self.on_change(uri, version).await
     }
 
-    async fn did_change(&self, mut params: DidChangeTextDocumentParams) {
+    async fn did_change(&self, params: DidChangeTextDocumentParams) {
+        // changes should be applied before any await to keep the order of notifications
+        let uri = params.text_document.uri;
+        let path = uri.to_string();
+        let version = params.text_document.version;
+        {
+            let mut rope = self.document_map.entry(path.clone()).or_default();
+            for change in params.content_changes {
+                Backend::apply_change(&mut rope, change);
+            }
+        }
+        self.version_map.insert(path.clone(), version);
+
         self.client
             .log_message(MessageType::INFO, "did_change")
             .await;
 
-        self.on_change(TextDocumentItem {
-            uri: params.text_document.uri,
-            text: std::mem::take(&mut params.content_changes[0].text),
-            version: params.text_document.version,
-        })
-        .await
+        tokio::time::sleep(DEBOUNCE).await;
+        if self.is_latest(&path, version) {
+            self.on_change(uri, version).await
+        }
     }
 
     async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
@@ -316,8 +487,13 @@ impl LanguageServer for Backend {
                     veryl_analyzer::symbol::SymbolKind::Function(_) => SymbolKind::FUNCTION,
                     veryl_analyzer::symbol::SymbolKind::Parameter(_) => SymbolKind::CONSTANT,
                     veryl_analyzer::symbol::SymbolKind::Instance(_) => SymbolKind::OBJECT,
+                    veryl_analyzer::symbol::SymbolKind::Type(_) => SymbolKind::TYPE_PARAMETER,
+                    veryl_analyzer::symbol::SymbolKind::Struct => SymbolKind::STRUCT,
+                    veryl_analyzer::symbol::SymbolKind::Union => SymbolKind::STRUCT,
+                    veryl_analyzer::symbol::SymbolKind::Enum => SymbolKind::ENUM,
                     veryl_analyzer::symbol::SymbolKind::Block => SymbolKind::NAMESPACE,
                     veryl_analyzer::symbol::SymbolKind::Package => SymbolKind::PACKAGE,
+                    veryl_analyzer::symbol::SymbolKind::Test => SymbolKind::FUNCTION,
                 };
                 let location = Backend::to_location(&symbol.token);
                 #[allow(deprecated)]
@@ -335,6 +511,18 @@ impl LanguageServer for Backend {
         Ok(Some(ret))
     }
 
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> Result<Option<DocumentSymbolResponse>> {
+        let path = params.text_document.uri.to_string();
+        if let Some(parser) = self.parser_map.get(&path) {
+            let symbols = document_symbol::document_symbols(&parser.veryl);
+            return Ok(Some(DocumentSymbolResponse::Nested(symbols)));
+        }
+        Ok(None)
+    }
+
     async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
         let uri = params.text_document_position_params.text_document.uri;
         let path = uri.to_string();
@@ -360,6 +548,187 @@ impl LanguageServer for Backend {
         Ok(None)
     }
 
+    async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
+        let uri = params.text_document_position.text_document.uri;
+        let path = uri.to_string();
+        if let Some(rope) = self.document_map.get(&path) {
+            let char = Backend::to_char(&rope, &params.text_document_position.position);
+            let text = rope.slice(..char).to_string();
+            let items = completion::completion(&text);
+            return Ok(Some(CompletionResponse::Array(items)));
+        }
+        Ok(None)
+    }
+
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        let uri = &params.text_document_position.text_document.uri;
+        let position = &params.text_document_position.position;
+        if let Some(token) = self.find_token(uri, position) {
+            if let Some(symbol) = reference_table::resolve(&token) {
+                let mut ret = Vec::new();
+                if params.context.include_declaration {
+                    ret.push(Backend::to_location(&symbol.token));
+                }
+                for reference in reference_table::get(&symbol) {
+                    let location = Backend::to_location(&reference.token);
+                    if !ret.contains(&location) {
+                        ret.push(location);
+                    }
+                }
+                return Ok(Some(ret));
+            }
+        }
+        Ok(None)
+    }
+
+    async fn prepare_call_hierarchy(
+        &self,
+        params: CallHierarchyPrepareParams,
+    ) -> Result<Option<Vec<CallHierarchyItem>>> {
+        let uri = &params.text_document_position_params.text_document.uri;
+        let position = &params.text_document_position_params.position;
+        if let Some(token) = self.find_token(uri, position) {
+            if let Some(symbol) = reference_table::resolve(&token) {
+                return Ok(call_hierarchy::prepare(&symbol).map(|x| vec![x]));
+            }
+        }
+        Ok(None)
+    }
+
+    async fn incoming_calls(
+        &self,
+        params: CallHierarchyIncomingCallsParams,
+    ) -> Result<Option<Vec<CallHierarchyIncomingCall>>> {
+        Ok(Some(call_hierarchy::incoming_calls(&params.item)))
+    }
+
+    async fn outgoing_calls(
+        &self,
+        params: CallHierarchyOutgoingCallsParams,
+    ) -> Result<Option<Vec<CallHierarchyOutgoingCall>>> {
+        Ok(Some(call_hierarchy::outgoing_calls(&params.item)))
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Result<Option<PrepareRenameResponse>> {
+        if let Some(token) = self.find_token(&params.text_document.uri, &params.position) {
+            if reference_table::resolve(&token).is_some() {
+                let range = Backend::to_location(&token).range;
+                return Ok(Some(PrepareRenameResponse::Range(range)));
+            }
+        }
+        Ok(None)
+    }
+
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let uri = &params.text_document_position.text_document.uri;
+        let position = &params.text_document_position.position;
+        let new_name = &params.new_name;
+
+        let is_identifier = new_name
+            .chars()
+            .enumerate()
+            .all(|(i, x)| x.is_ascii_alphabetic() || x == '_' || (i != 0 && x.is_ascii_digit()));
+        if new_name.is_empty() || !is_identifier {
+            return Err(tower_lsp::jsonrpc::Error::invalid_params(format!(
+                "\"{}\" is not a valid identifier",
+                new_name
+            )));
+        }
+
+        if let Some(token) = self.find_token(uri, position) {
+            if let Some(symbol) = reference_table::resolve(&token) {
+                let old_name = symbol.token.text.to_string();
+                let mut edits = vec![(symbol.token, new_name.clone())];
+                for reference in reference_table::get(&symbol) {
+                    // implicit connection is expanded to keep the other side
+                    let new_text = match reference.kind {
+                        ReferenceKind::Explicit => new_name.clone(),
+                        ReferenceKind::ImplicitPort => format!("{}: {}", new_name, old_name),
+                        ReferenceKind::ImplicitConnection => format!("{}: {}", old_name, new_name),
+                    };
+                    if !edits.iter().any(|(x, _)| x.id == reference.token.id) {
+                        edits.push((reference.token, new_text));
+                    }
+                }
+
+                let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+                for (token, new_text) in edits {
+                    let location = Backend::to_location(&token);
+                    changes.entry(location.uri).or_default().push(TextEdit {
+                        range: location.range,
+                        new_text,
+                    });
+                }
+                return Ok(Some(WorkspaceEdit {
+                    changes: Some(changes),
+                    ..WorkspaceEdit::default()
+                }));
+            }
+        }
+        Ok(None)
+    }
+
+    async fn semantic_tokens_full(
+        &self,
+        params: SemanticTokensParams,
+    ) -> Result<Option<SemanticTokensResult>> {
+        let path = params.text_document.uri.to_string();
+        if let Some(parser) = self.parser_map.get(&path) {
+            let data = semantic_token::semantic_tokens(&parser.veryl, None);
+            return Ok(Some(SemanticTokensResult::Tokens(SemanticTokens {
+                result_id: None,
+                data,
+            })));
+        }
+        Ok(None)
+    }
+
+    async fn semantic_tokens_range(
+        &self,
+        params: SemanticTokensRangeParams,
+    ) -> Result<Option<SemanticTokensRangeResult>> {
+        let path = params.text_document.uri.to_string();
+        if let Some(parser) = self.parser_map.get(&path) {
+            let data = semantic_token::semantic_tokens(&parser.veryl, Some(&params.range));
+            return Ok(Some(SemanticTokensRangeResult::Tokens(SemanticTokens {
+                result_id: None,
+                data,
+            })));
+        }
+        Ok(None)
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let uri = params.text_document.uri;
+        let mut ret = code_action::quick_fixes(&uri, &params.context.diagnostics);
+        let path = uri.to_string();
+        if let (Some(parser), Some(rope)) =
+            (self.parser_map.get(&path), self.document_map.get(&path))
+        {
+            if let Some(x) =
+                code_action::named_connections(&uri, &parser.veryl, &rope, &params.range)
+            {
+                ret.push(x);
+            }
+        }
+        Ok(Some(ret))
+    }
+
+    async fn signature_help(&self, params: SignatureHelpParams) -> Result<Option<SignatureHelp>> {
+        let uri = params.text_document_position_params.text_document.uri;
+        let path = uri.to_string();
+        if let Some(rope) = self.document_map.get(&path) {
+            let position = &params.text_document_position_params.position;
+            let char = Backend::to_char(&rope, position);
+            let text = rope.slice(..char).to_string();
+            return Ok(signature_help::signature_help(&text));
+        }
+        Ok(None)
+    }
+
     async fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
         let uri = params.text_document.uri;
         let path = uri.to_string();
@@ -384,7 +753,64 @@ impl LanguageServer for Backend {
         Ok(None)
     }
 
+    async fn range_formatting(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        Ok(self.format_range(&params.text_document.uri, &params.range))
+    }
+
+    async fn on_type_formatting(
+        &self,
+        params: DocumentOnTypeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        let uri = &params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+        Ok(self.format_range(uri, &Range::new(position, position)))
+    }
+
     async fn shutdown(&self) -> Result<()> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(begin: (u32, u32), end: (u32, u32), text: &str) -> TextDocumentContentChangeEvent {
+        TextDocumentContentChangeEvent {
+            range: Some(Range::new(
+                Position::new(begin.0, begin.1),
+                Position::new(end.0, end.1),
+            )),
+            range_length: None,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn incremental_change() {
+        let mut rope = Rope::from_str("module A {\n    // 😀y\n    var a: logic;\n}\n");
+
+        Backend::apply_change(&mut rope, change((2, 8), (2, 9), "b"));
+        assert_eq!(rope.line(2).to_string(), "    var b: logic;\n");
+
+        // characters are counted by UTF-16 code units
+        Backend::apply_change(&mut rope, change((1, 7), (1, 9), "x"));
+        assert_eq!(rope.line(1).to_string(), "    // xy\n");
+
+        Backend::apply_change(&mut rope, change((3, 1), (3, 1), "\nmodule B {}"));
+        assert_eq!(rope.line(4).to_string(), "module B {}\n");
+
+        Backend::apply_change(
+            &mut rope,
+            TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: "module C {}".to_string(),
+            },
+        );
+        assert_eq!(rope.to_string(), "module C {}");
+    }
+}
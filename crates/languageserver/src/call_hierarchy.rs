@@ -0,0 +1,190 @@
+//! Instance hierarchy mapped onto call hierarchy
+//!
+//! Outgoing calls of a module are the modules it instantiates,
+//! and incoming calls are the modules which instantiate it.
+
+use tower_lsp::lsp_types::{
+    CallHierarchyIncomingCall, CallHierarchyItem, CallHierarchyOutgoingCall, Position, Range,
+    SymbolKind, Url,
+};
+use veryl_analyzer::namespace::Namespace;
+use veryl_analyzer::symbol::{Symbol, SymbolKind as VerylSymbolKind};
+use veryl_analyzer::symbol_table::{self, Name};
+use veryl_parser::resource_table::{self, StrId};
+use veryl_parser::veryl_token::Token;
+
+fn range(token: &Token) -> Range {
+    let line = token.line as u32 - 1;
+    let column = token.column as u32 - 1;
+    Range::new(
+        Position::new(line, column),
+        Position::new(line, column + token.length as u32),
+    )
+}
+
+/// Top level symbol which can contain instances
+fn top_level(name: StrId) -> Option<Symbol> {
+    symbol_table::get(&Name::Hierarchical(vec![name]), &Namespace::default())
+}
+
+fn item(symbol: &Symbol) -> Option<CallHierarchyItem> {
+    let kind = match symbol.kind {
+        VerylSymbolKind::Module(_) => SymbolKind::MODULE,
+        VerylSymbolKind::Interface(_) => SymbolKind::INTERFACE,
+        VerylSymbolKind::Test => SymbolKind::METHOD,
+        _ => return None,
+    };
+    let uri = Url::parse(&symbol.token.file_path.to_string()).ok()?;
+    Some(CallHierarchyItem {
+        name: symbol.token.text.to_string(),
+        kind,
+        tags: None,
+        detail: Some(symbol.kind.to_kind_name()),
+        uri,
+        range: range(&symbol.token),
+        selection_range: range(&symbol.token),
+        data: None,
+    })
+}
+
+/// Instances sorted by the declared position
+fn instances() -> Vec<(Symbol, StrId)> {
+    let mut ret: Vec<_> = symbol_table::get_all()
+        .into_iter()
+        .filter_map(|x| match x.kind {
+            VerylSymbolKind::Instance(ref y) => {
+                let type_name = y.type_name;
+                Some((x, type_name))
+            }
+            _ => None,
+        })
+        .collect();
+    ret.sort_by_key(|(x, _)| (x.token.file_path, x.token.pos));
+    ret
+}
+
+/// Group ranges by the key with keeping the order of the first appearance
+fn group(list: Vec<(StrId, Range)>) -> Vec<(StrId, Vec<Range>)> {
+    let mut ret: Vec<(StrId, Vec<Range>)> = Vec::new();
+    for (key, range) in list {
+        if let Some(x) = ret.iter_mut().find(|x| x.0 == key) {
+            x.1.push(range);
+        } else {
+            ret.push((key, vec![range]));
+        }
+    }
+    ret
+}
+
+/// Item of the module which is declared or instantiated at the token
+pub fn prepare(symbol: &Symbol) -> Option<CallHierarchyItem> {
+    match symbol.kind {
+        VerylSymbolKind::Instance(ref x) => item(&top_level(x.type_name)?),
+        _ => item(symbol),
+    }
+}
+
+pub fn incoming_calls(item: &CallHierarchyItem) -> Vec<CallHierarchyIncomingCall> {
+    let name = resource_table::insert_str(&item.name);
+    let list: Vec<_> = instances()
+        .into_iter()
+        .filter(|(_, type_name)| *type_name == name)
+        .filter_map(|(x, _)| Some((*x.namespace.paths.first()?, range(&x.token))))
+        .collect();
+
+    let mut ret = Vec::new();
+    for (parent, from_ranges) in group(list) {
+        if let Some(from) = top_level(parent).as_ref().and_then(self::item) {
+            ret.push(CallHierarchyIncomingCall { from, from_ranges });
+        }
+    }
+    ret
+}
+
+pub fn outgoing_calls(item: &CallHierarchyItem) -> Vec<CallHierarchyOutgoingCall> {
+    let name = resource_table::insert_str(&item.name);
+    let list: Vec<_> = instances()
+        .into_iter()
+        .filter(|(x, _)| x.namespace.paths.first() == Some(&name))
+        .map(|(x, type_name)| (type_name, range(&x.token)))
+        .collect();
+
+    let mut ret = Vec::new();
+    for (child, from_ranges) in group(list) {
+        if let Some(to) = top_level(child).as_ref().and_then(self::item) {
+            ret.push(CallHierarchyOutgoingCall { to, from_ranges });
+        }
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use veryl_analyzer::Analyzer;
+    use veryl_parser::Parser;
+
+    #[test]
+    fn instance_hierarchy() {
+        let code = r#"module ModuleA {
+}
+
+module ModuleB {
+    inst u0: ModuleA;
+    inst u1: ModuleA;
+}
+
+module ModuleC {
+    inst u0: ModuleA;
+    inst u1: ModuleB;
+}
+"#;
+        let parser = Parser::parse(code, &"file:///hierarchy.vl").unwrap();
+        let mut analyzer = Analyzer::new(code);
+        analyzer.analyze(&parser.veryl);
+
+        let module = |name: &str| {
+            let name = resource_table::insert_str(name);
+            prepare(&top_level(name).unwrap()).unwrap()
+        };
+
+        let outgoing: Vec<_> = outgoing_calls(&module("ModuleC"))
+            .iter()
+            .map(|x| (x.to.name.clone(), x.from_ranges.len()))
+            .collect();
+        assert_eq!(
+            outgoing,
+            vec![("ModuleA".to_string(), 1), ("ModuleB".to_string(), 1)]
+        );
+
+        let incoming: Vec<_> = incoming_calls(&module("ModuleA"))
+            .iter()
+            .map(|x| (x.from.name.clone(), x.from_ranges.clone()))
+            .collect();
+        assert_eq!(
+            incoming,
+            vec![
+                (
+                    "ModuleB".to_string(),
+                    vec![
+                        Range::new(Position::new(4, 9), Position::new(4, 11)),
+                        Range::new(Position::new(5, 9), Position::new(5, 11)),
+                    ]
+                ),
+                (
+                    "ModuleC".to_string(),
+                    vec![Range::new(Position::new(9, 9), Position::new(9, 11))]
+                ),
+            ]
+        );
+
+        // instance is resolved to the instantiated module
+        let module_b = resource_table::insert_str("ModuleB");
+        let instance = instances()
+            .into_iter()
+            .find(|(_, type_name)| *type_name == module_b)
+            .unwrap();
+        assert_eq!(prepare(&instance.0).unwrap().name, "ModuleB");
+        assert!(incoming_calls(&module("ModuleC")).is_empty());
+    }
+}
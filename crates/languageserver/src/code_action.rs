@@ -0,0 +1,295 @@
+use crate::backend::Backend;
+use ropey::Rope;
+use serde_json::Value;
+use std::collections::HashMap;
+use tower_lsp::lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, Diagnostic, Range, TextEdit, Url,
+    WorkspaceEdit,
+};
+use veryl_analyzer::analyze_error::Fix;
+use veryl_parser::miette::Result;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::VerylToken;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
+
+/// Fixes of the error which are stored to `Diagnostic::data`
+///
+/// The byte offsets are converted to positions at the time of analysis,
+/// because the text may be changed until the code action is requested.
+pub fn fix_data(fixes: &[Fix], rope: &Rope) -> Option<Value> {
+    if fixes.is_empty() {
+        return None;
+    }
+    let fixes: Vec<_> = fixes
+        .iter()
+        .map(|x| {
+            let range = Range::new(
+                Backend::to_position(rope, x.offset),
+                Backend::to_position(rope, x.offset + x.length),
+            );
+            (x.title.clone(), TextEdit::new(range, x.text.clone()))
+        })
+        .collect();
+    serde_json::to_value(fixes).ok()
+}
+
+fn code_action(
+    title: String,
+    kind: CodeActionKind,
+    uri: &Url,
+    edits: Vec<TextEdit>,
+    diagnostic: Option<&Diagnostic>,
+) -> CodeActionOrCommand {
+    let changes = HashMap::from([(uri.clone(), edits)]);
+    CodeActionOrCommand::CodeAction(CodeAction {
+        title,
+        kind: Some(kind),
+        diagnostics: diagnostic.map(|x| vec![x.clone()]),
+        edit: Some(WorkspaceEdit::new(changes)),
+        ..Default::default()
+    })
+}
+
+/// Quick fixes of the diagnostics which have fixes in `data`
+pub fn quick_fixes(uri: &Url, diagnostics: &[Diagnostic]) -> Vec<CodeActionOrCommand> {
+    let mut ret = Vec::new();
+    for diagnostic in diagnostics {
+        if let Some(ref data) = diagnostic.data {
+            if let Ok(fixes) = serde_json::from_value::<Vec<(String, TextEdit)>>(data.clone()) {
+                for (title, edit) in fixes {
+                    ret.push(code_action(
+                        title,
+                        CodeActionKind::QUICKFIX,
+                        uri,
+                        vec![edit],
+                        Some(diagnostic),
+                    ));
+                }
+            }
+        }
+    }
+    ret
+}
+
+fn range(rope: &Rope, begin: &VerylToken, end: &VerylToken) -> Range {
+    let begin = &begin.token;
+    let end = &end.token;
+    Range::new(
+        Backend::to_position(rope, begin.pos),
+        Backend::to_position(rope, end.pos + end.length),
+    )
+}
+
+struct NamedConnection<'a> {
+    point: HandlerPoint,
+    rope: &'a Rope,
+    range: &'a Range,
+    edits: Vec<TextEdit>,
+}
+
+impl<'a> Handler for NamedConnection<'a> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl<'a> VerylGrammarTrait for NamedConnection<'a> {
+    fn inst_declaration(&mut self, arg: &InstDeclaration) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            let inst = range(
+                self.rope,
+                &arg.inst.inst_token,
+                &arg.semicolon.semicolon_token,
+            );
+            if inst.end < self.range.start || self.range.end < inst.start {
+                return Ok(());
+            }
+
+            if let Some(ref x) = arg.inst_declaration_opt1 {
+                if let Some(ref x) = x.inst_declaration_opt2 {
+                    let x = &x.inst_port_list;
+                    let mut items = vec![&*x.inst_port_item];
+                    items.extend(x.inst_port_list_list.iter().map(|x| &*x.inst_port_item));
+                    for item in items {
+                        if item.inst_port_item_opt.is_none() {
+                            let token = &item.identifier.identifier_token;
+                            let end = range(self.rope, token, token).end;
+                            let text = format!(": {}", token.text());
+                            self.edits.push(TextEdit::new(Range::new(end, end), text));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+struct NamedConnectionWalker<'a> {
+    handler: NamedConnection<'a>,
+}
+
+impl<'a> VerylWalker for NamedConnectionWalker<'a> {
+    fn get_handlers(&mut self) -> Option<Vec<&mut dyn Handler>> {
+        Some(vec![&mut self.handler as &mut dyn Handler])
+    }
+}
+
+/// Rewrite implicit connections like `inst u: M (a)` to named ones like `inst u: M (a: a)`
+pub fn named_connections(
+    uri: &Url,
+    veryl: &Veryl,
+    rope: &Rope,
+    range: &Range,
+) -> Option<CodeActionOrCommand> {
+    let mut walker = NamedConnectionWalker {
+        handler: NamedConnection {
+            point: HandlerPoint::Before,
+            rope,
+            range,
+            edits: Vec::new(),
+        },
+    };
+    walker.veryl(veryl);
+
+    let edits = walker.handler.edits;
+    if edits.is_empty() {
+        None
+    } else {
+        Some(code_action(
+            "Convert to named connections".to_string(),
+            CodeActionKind::REFACTOR_REWRITE,
+            uri,
+            edits,
+            None,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower_lsp::lsp_types::Position;
+    use veryl_analyzer::Analyzer;
+    use veryl_parser::Parser;
+
+    const CODE: &str = r#"module ModuleA (
+    i_clk: input  logic,
+    i_rst: input  logic,
+    o_a  : output logic,
+) {
+    always_ff (i_clk, i_rst) {
+        o_a = /* 🙂 */ 2'b12;
+    }
+}
+
+module ModuleB (
+    i_clk: input logic,
+    i_rst: input logic,
+) {
+    var x: logic;
+
+    inst u0: ModuleA (
+        i_clk,
+        i_rst,
+    );
+
+    inst u1: ModuleA (i_clk, o_a: x, o_b: x);
+}
+"#;
+
+    /// Apply the edit to `CODE`
+    fn apply(edit: &TextEdit) -> String {
+        let mut rope = Rope::from_str(CODE);
+        let begin = Backend::to_char(&rope, &edit.range.start);
+        let end = Backend::to_char(&rope, &edit.range.end);
+        rope.remove(begin..end);
+        rope.insert(begin, &edit.new_text);
+        rope.to_string()
+    }
+
+    fn action(x: &CodeActionOrCommand, uri: &Url) -> (String, Vec<TextEdit>) {
+        match x {
+            CodeActionOrCommand::CodeAction(x) => {
+                let edits = x.edit.as_ref().unwrap().changes.as_ref().unwrap()[uri].clone();
+                (x.title.clone(), edits)
+            }
+            CodeActionOrCommand::Command(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn quick_fix() {
+        let parser = Parser::parse(CODE, &"").unwrap();
+        let mut analyzer = Analyzer::new(CODE);
+        let errors = analyzer.analyze(&parser.veryl);
+        let rope = Rope::from_str(CODE);
+        let uri = Url::parse("file:///test.vl").unwrap();
+
+        let diagnostics: Vec<_> = errors
+            .iter()
+            .map(|x| Diagnostic {
+                data: fix_data(x.fixes(), &rope),
+                ..Default::default()
+            })
+            .collect();
+        let mut actions: Vec<_> = quick_fixes(&uri, &diagnostics)
+            .iter()
+            .map(|x| action(x, &uri))
+            .collect();
+        actions.sort_by(|x, y| x.0.cmp(&y.0));
+
+        let titles: Vec<_> = actions.iter().map(|x| x.0.as_str()).collect();
+        assert_eq!(
+            titles,
+            vec![
+                "Add \"i_rst\" port",
+                "Add \"o_a\" port",
+                "Add if_reset statement",
+                "Change to octal number",
+                "Remove \"o_b\" port",
+            ]
+        );
+
+        let fixed = apply(&actions[0].1[0]);
+        assert!(fixed.contains("    inst u1: ModuleA (i_clk, o_a: x, o_b: x, i_rst);\n"));
+        let fixed = apply(&actions[1].1[0]);
+        assert!(fixed.contains("        i_rst,\n        o_a,\n    );\n"));
+        let fixed = apply(&actions[2].1[0]);
+        assert!(fixed.contains(
+            r#"    always_ff (i_clk, i_rst) {
+        if_reset {
+        } else {
+            o_a = /* 🙂 */ 2'b12;
+        }
+    }
+"#
+        ));
+        let fixed = apply(&actions[3].1[0]);
+        assert!(fixed.contains("        o_a = /* 🙂 */ 2'o12;\n"));
+        let fixed = apply(&actions[4].1[0]);
+        assert!(fixed.contains("    inst u1: ModuleA (i_clk, o_a: x);\n"));
+    }
+
+    #[test]
+    fn named_connection() {
+        // the emoji before `i_clk` is two UTF-16 code units
+        let code = CODE.replace("        i_clk,\n", "        /* 🙂 */ i_clk,\n");
+        let parser = Parser::parse(&code, &"").unwrap();
+        let rope = Rope::from_str(&code);
+        let uri = Url::parse("file:///test.vl").unwrap();
+
+        let range = Range::new(Position::new(17, 0), Position::new(17, 0));
+        let (title, edits) = action(
+            &named_connections(&uri, &parser.veryl, &rope, &range).unwrap(),
+            &uri,
+        );
+        assert_eq!(title, "Convert to named connections");
+        assert_eq!(edits.len(), 2);
+        assert_eq!(edits[0].range.start, Position::new(17, 22));
+        assert_eq!(edits[0].new_text, ": i_clk");
+
+        let range = Range::new(Position::new(14, 0), Position::new(14, 0));
+        assert!(named_connections(&uri, &parser.veryl, &rope, &range).is_none());
+    }
+}
@@ -0,0 +1,473 @@
+use crate::lexer::{self, item, lex, Lex};
+use std::collections::HashMap;
+use tower_lsp::lsp_types::*;
+use veryl_analyzer::namespace::Namespace;
+use veryl_analyzer::symbol::{Port, Symbol, SymbolKind as VerylSymbolKind, TypeKind};
+use veryl_analyzer::symbol_table::{self, Name};
+use veryl_parser::resource_table;
+
+/// Context of the cursor position
+///
+/// It is detected from the text before the cursor instead of the syntax tree
+/// because the document being edited can't be parsed in most cases.
+#[derive(Debug, PartialEq)]
+pub enum Context {
+    /// After `Pkg::`
+    Package(String),
+    /// After `inst x:`
+    InstType,
+    /// At the connection name in the port list of `inst x: Module (`
+    InstPort {
+        module: String,
+        connected: Vec<String>,
+    },
+    /// After `name:` in the port declaration of module or function
+    Direction,
+    /// At the beginning of module items or statements
+    Statement,
+    /// Out of any declaration
+    Description,
+    /// Anywhere else
+    Expression,
+}
+
+/// Detect context and namespace at the end of `text`
+pub fn context(text: &str) -> (Context, Vec<String>) {
+    // strip the identifier being typed
+    let text = text.trim_end_matches(|x: char| x.is_ascii_alphanumeric() || x == '_');
+    let tokens = lex(text);
+
+    let open = lexer::open_brackets(&tokens);
+    let namespace = lexer::namespace(&tokens, &open);
+
+    let len = tokens.len();
+    if len >= 2 && tokens[len - 1].is_punct("::") {
+        if let Some(name) = tokens[len - 2].ident() {
+            return (Context::Package(name.to_string()), namespace);
+        }
+    }
+
+    let current = item(&tokens, &[";", "}"]);
+    if current.len() == 3
+        && current[0].ident() == Some("inst")
+        && current[1].ident().is_some()
+        && current[2].is_punct(":")
+    {
+        return (Context::InstType, namespace);
+    }
+
+    if let Some(last) = open.last() {
+        if tokens[*last].is_punct("(") {
+            let header = item(&tokens[..*last], &[";", "}"]);
+            let first = header.first().and_then(|x| x.ident());
+            let is_param = header.last().map(|x| x.is_punct("#")).unwrap_or(false);
+            let current = item(&tokens, &[","]);
+
+            if matches!(first, Some("module" | "function")) && !is_param {
+                let is_direction =
+                    current.len() == 2 && current[0].ident().is_some() && current[1].is_punct(":");
+                if is_direction {
+                    return (Context::Direction, namespace);
+                }
+            }
+
+            if first == Some("inst") && !is_param && current.is_empty() {
+                let module = header
+                    .iter()
+                    .skip(3)
+                    .take_while(|x| !x.is_punct("#"))
+                    .filter_map(|x| x.ident())
+                    .last();
+                if let Some(module) = module {
+                    let mut connected = Vec::new();
+                    let mut depth = 0;
+                    let mut head = true;
+                    for token in &tokens[last + 1..] {
+                        match token {
+                            Lex::Punct(x) if x == "(" || x == "[" || x == "{" => depth += 1,
+                            Lex::Punct(x) if x == ")" || x == "]" || x == "}" => depth -= 1,
+                            Lex::Punct(x) if x == "," && depth == 0 => head = true,
+                            Lex::Ident(x) if head && depth == 0 => {
+                                connected.push(x.clone());
+                                head = false;
+                            }
+                            _ => head = false,
+                        }
+                    }
+                    let module = module.to_string();
+                    return (Context::InstPort { module, connected }, namespace);
+                }
+            }
+        }
+    }
+
+    let is_statement = current.is_empty()
+        && open
+            .last()
+            .map(|x| tokens[*x].is_punct("{"))
+            .unwrap_or(true);
+    if is_statement {
+        if open.is_empty() {
+            (Context::Description, namespace)
+        } else {
+            (Context::Statement, namespace)
+        }
+    } else {
+        (Context::Expression, namespace)
+    }
+}
+
+fn to_namespace(paths: &[String]) -> Namespace {
+    let mut ret = Namespace::default();
+    for path in paths {
+        ret.push(resource_table::insert_str(path));
+    }
+    ret
+}
+
+fn get_symbol(name: &str) -> Option<Symbol> {
+    let name = Name::Hierarchical(vec![resource_table::insert_str(name)]);
+    symbol_table::get(&name, &Namespace::default())
+}
+
+fn item_kind(kind: &VerylSymbolKind) -> Option<CompletionItemKind> {
+    match kind {
+        VerylSymbolKind::Port(_) => Some(CompletionItemKind::VARIABLE),
+        VerylSymbolKind::Variable(_) => Some(CompletionItemKind::VARIABLE),
+        VerylSymbolKind::Module(_) => Some(CompletionItemKind::MODULE),
+        VerylSymbolKind::Interface(_) => Some(CompletionItemKind::INTERFACE),
+        VerylSymbolKind::Function(_) => Some(CompletionItemKind::FUNCTION),
+        VerylSymbolKind::Parameter(x) if matches!(x.r#type.kind, TypeKind::Type) => {
+            Some(CompletionItemKind::TYPE_PARAMETER)
+        }
+        VerylSymbolKind::Parameter(_) => Some(CompletionItemKind::CONSTANT),
+        VerylSymbolKind::Instance(_) => Some(CompletionItemKind::VARIABLE),
+        VerylSymbolKind::Type(_) => Some(CompletionItemKind::TYPE_PARAMETER),
+        VerylSymbolKind::Struct => Some(CompletionItemKind::STRUCT),
+        VerylSymbolKind::Union => Some(CompletionItemKind::STRUCT),
+        VerylSymbolKind::Enum => Some(CompletionItemKind::ENUM),
+        VerylSymbolKind::Package => Some(CompletionItemKind::MODULE),
+        VerylSymbolKind::Block => None,
+        VerylSymbolKind::Test => None,
+    }
+}
+
+fn symbol_item(symbol: &Symbol) -> Option<CompletionItem> {
+    let kind = item_kind(&symbol.kind)?;
+    Some(CompletionItem {
+        label: format!("{}", symbol.token.text),
+        kind: Some(kind),
+        detail: Some(symbol.kind.to_string()),
+        ..Default::default()
+    })
+}
+
+fn keyword_item(keyword: &str) -> CompletionItem {
+    CompletionItem {
+        label: keyword.to_string(),
+        kind: Some(CompletionItemKind::KEYWORD),
+        ..Default::default()
+    }
+}
+
+fn snippet_item(label: &str, detail: &str, text: String) -> CompletionItem {
+    CompletionItem {
+        label: label.to_string(),
+        kind: Some(CompletionItemKind::SNIPPET),
+        detail: Some(detail.to_string()),
+        insert_text: Some(text),
+        insert_text_format: Some(InsertTextFormat::SNIPPET),
+        ..Default::default()
+    }
+}
+
+/// Variables, ports, parameters and so on which are visible from `namespace`
+fn visible_symbols(namespace: &Namespace) -> Vec<Symbol> {
+    let mut ret: HashMap<String, Symbol> = HashMap::new();
+    for symbol in symbol_table::get_all() {
+        if !namespace.included(&symbol.namespace) {
+            continue;
+        }
+        if matches!(
+            symbol.kind,
+            VerylSymbolKind::Module(_) | VerylSymbolKind::Interface(_) | VerylSymbolKind::Test
+        ) {
+            continue;
+        }
+        let name = format!("{}", symbol.token.text);
+        let shadowed = ret
+            .get(&name)
+            .map(|x| x.namespace.depth() > symbol.namespace.depth())
+            .unwrap_or(false);
+        if !shadowed {
+            ret.insert(name, symbol);
+        }
+    }
+    let mut ret: Vec<_> = ret.into_values().collect();
+    ret.sort_by_key(|x| format!("{}", x.token.text));
+    ret
+}
+
+fn module_ports(name: &str) -> Vec<Port> {
+    match get_symbol(name).map(|x| x.kind) {
+        Some(VerylSymbolKind::Module(x)) => x.ports,
+        _ => Vec::new(),
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    let mut ret = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i != 0 && !ret.ends_with('_') {
+                ret.push('_');
+            }
+            ret.push(c.to_ascii_lowercase());
+        } else {
+            ret.push(c);
+        }
+    }
+    ret
+}
+
+/// `inst` declaration connecting all ports to the signals which have the same name
+fn inst_snippet(module: &str, ports: &[Port]) -> String {
+    let mut ret = format!("inst ${{1:u_{}}}: {}", snake_case(module), module);
+    if ports.is_empty() {
+        ret.push_str(";$0");
+        return ret;
+    }
+    let names: Vec<_> = ports.iter().map(|x| format!("{}", x.name)).collect();
+    let width = names.iter().map(|x| x.len()).max().unwrap_or(0);
+    ret.push_str(" (\n");
+    for (i, name) in names.iter().enumerate() {
+        ret.push_str(&format!(
+            "    {:width$}: ${{{}:{}}},\n",
+            name,
+            i + 2,
+            name,
+            width = width
+        ));
+    }
+    ret.push_str(");$0");
+    ret
+}
+
+/// `always_ff` with clock and reset guessed from ports of the module
+fn always_ff_snippet(module: Option<&str>) -> String {
+    let ports = module.map(module_ports).unwrap_or_default();
+    let names: Vec<_> = ports.iter().map(|x| format!("{}", x.name)).collect();
+    let clock = names
+        .iter()
+        .find(|x| x.contains("clk") || x.contains("clock"))
+        .map(|x| x.as_str())
+        .unwrap_or("i_clk");
+    let reset = names
+        .iter()
+        .find(|x| x.contains("rst") || x.contains("reset"))
+        .map(|x| x.as_str())
+        .unwrap_or("i_rst");
+    format!(
+        "always_ff (${{1:{}}}, ${{2:{}}}) {{\n    if_reset {{\n        $3\n    }} else {{\n        $0\n    }}\n}}",
+        clock, reset
+    )
+}
+
+const MODULE_SNIPPET: &str = "module ${1:Name} (\n    $2\n) {\n    $0\n}";
+
+const DIRECTIONS: [&str; 6] = ["input", "output", "inout", "ref", "modport", "interface"];
+
+/// Completion items for the end of `text`
+pub fn completion(text: &str) -> Vec<CompletionItem> {
+    let (context, namespace) = context(text);
+    let namespace = to_namespace(&namespace);
+
+    let mut ret = Vec::new();
+    match context {
+        Context::Package(name) => {
+            let package = to_namespace(&[name]);
+            for symbol in symbol_table::get_all() {
+                if symbol.namespace == package {
+                    ret.extend(symbol_item(&symbol));
+                }
+            }
+        }
+        Context::InstType => {
+            for symbol in symbol_table::get_all() {
+                if matches!(
+                    symbol.kind,
+                    VerylSymbolKind::Module(_) | VerylSymbolKind::Interface(_)
+                ) {
+                    ret.extend(symbol_item(&symbol));
+                }
+            }
+        }
+        Context::InstPort { module, connected } => {
+            for port in module_ports(&module) {
+                let name = format!("{}", port.name);
+                if !connected.contains(&name) {
+                    ret.push(CompletionItem {
+                        label: name,
+                        kind: Some(CompletionItemKind::FIELD),
+                        detail: Some(format!("port [{}]", port.property.direction)),
+                        ..Default::default()
+                    });
+                }
+            }
+        }
+        Context::Direction => {
+            ret.extend(DIRECTIONS.iter().map(|x| keyword_item(x)));
+        }
+        Context::Statement => {
+            let module = namespace.paths.first().map(|x| format!("{}", x));
+            ret.push(snippet_item(
+                "always_ff",
+                "always_ff with reset",
+                always_ff_snippet(module.as_deref()),
+            ));
+            for symbol in symbol_table::get_all() {
+                if let VerylSymbolKind::Module(ref x) = symbol.kind {
+                    let name = format!("{}", symbol.token.text);
+                    ret.push(snippet_item(
+                        &format!("inst {}", name),
+                        "inst with all ports",
+                        inst_snippet(&name, &x.ports),
+                    ));
+                }
+            }
+            ret.extend(visible_symbols(&namespace).iter().filter_map(symbol_item));
+        }
+        Context::Description => {
+            ret.push(snippet_item(
+                "module",
+                "module declaration",
+                MODULE_SNIPPET.to_string(),
+            ));
+        }
+        Context::Expression => {
+            ret.extend(visible_symbols(&namespace).iter().filter_map(symbol_item));
+        }
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(text: &str, context: Context, namespace: &[&str]) {
+        let namespace: Vec<_> = namespace.iter().map(|x| x.to_string()).collect();
+        assert_eq!(super::context(text), (context, namespace));
+    }
+
+    #[test]
+    fn detect_context() {
+        check("", Context::Description, &[]);
+        check("module A {\n    ", Context::Statement, &["A"]);
+        check("module A {\n    assign a = b", Context::Expression, &["A"]);
+        check(
+            "module A {\n    assign a = PkgA::b",
+            Context::Package("PkgA".to_string()),
+            &["A"],
+        );
+        check("module A {\n    inst u: Co", Context::InstType, &["A"]);
+        check(
+            "module A (\n    a: input logic,\n    b: ",
+            Context::Direction,
+            &["A"],
+        );
+        check(
+            "module A #(\n    parameter X: u32 = 1,\n) (\n    a: ",
+            Context::Direction,
+            &["A"],
+        );
+        check(
+            "module A #(\n    parameter X: ",
+            Context::Expression,
+            &["A"],
+        );
+        check(
+            "module A {\n    inst u: B #(X: 1) (\n        a,\n        b: c[1],\n        ",
+            Context::InstPort {
+                module: "B".to_string(),
+                connected: vec!["a".to_string(), "b".to_string()],
+            },
+            &["A"],
+        );
+        check(
+            "module A {\n    inst u: B (\n        a: x",
+            Context::Expression,
+            &["A"],
+        );
+        check(
+            "module A {\n    if a :label {\n        // comment {\n        ",
+            Context::Statement,
+            &["A", "label"],
+        );
+        check(
+            "package P {\n    function f (a: input logic) -> logic {\n        return ",
+            Context::Expression,
+            &["P", "f"],
+        );
+        check("module A {\n}\n", Context::Description, &[]);
+    }
+
+    #[test]
+    fn items() {
+        let code = r#"
+package PkgA {
+    localparam X: u32 = 1;
+}
+
+module ModuleA (
+    i_clk: input  logic,
+    i_rst: input  logic,
+    o_a  : output logic,
+) {
+    var a: logic;
+}
+"#;
+        let parser = veryl_parser::Parser::parse(code, &"").unwrap();
+        let mut analyzer = veryl_analyzer::Analyzer::new(code);
+        analyzer.analyze(&parser.veryl);
+
+        let labels = |text: &str| -> Vec<String> {
+            let mut ret: Vec<_> = completion(text).into_iter().map(|x| x.label).collect();
+            ret.sort();
+            ret
+        };
+
+        assert_eq!(labels("module B {\n    assign b = PkgA::"), vec!["X"]);
+        assert!(labels("module B {\n    inst u: ").contains(&"ModuleA".to_string()));
+        assert_eq!(
+            labels("module B {\n    inst u: ModuleA (\n        i_clk,\n        "),
+            vec!["i_rst", "o_a"]
+        );
+        assert_eq!(
+            labels("module ModuleA (\n    i_clk: input logic,\n) {\n    assign o_a = "),
+            vec!["PkgA", "a", "i_clk", "i_rst", "o_a"]
+        );
+
+        let items = completion("module B {\n    ");
+        let always_ff = items.iter().find(|x| x.label == "always_ff").unwrap();
+        assert!(always_ff
+            .insert_text
+            .as_ref()
+            .unwrap()
+            .starts_with("always_ff (${1:i_clk}, ${2:i_rst})"));
+        let inst = items.iter().find(|x| x.label == "inst ModuleA").unwrap();
+        assert_eq!(
+            inst.insert_text.as_deref(),
+            Some("inst ${1:u_module_a}: ModuleA (\n    i_clk: ${2:i_clk},\n    i_rst: ${3:i_rst},\n    o_a  : ${4:o_a},\n);$0")
+        );
+    }
+
+    #[test]
+    fn snippet() {
+        assert_eq!(snake_case("CounterTop"), "counter_top");
+        assert_eq!(
+            always_ff_snippet(None),
+            "always_ff (${1:i_clk}, ${2:i_rst}) {\n    if_reset {\n        $3\n    } else {\n        $0\n    }\n}"
+        );
+    }
+}
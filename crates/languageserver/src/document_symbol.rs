@@ -0,0 +1,490 @@
+use tower_lsp::lsp_types::{DocumentSymbol, Position, Range, SymbolKind};
+use veryl_parser::miette::Result;
+use veryl_parser::resource_table;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::{Token, VerylToken};
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
+use veryl_parser::Stringifier;
+
+fn range(begin: &VerylToken, end: &VerylToken) -> Range {
+    let begin = &begin.token;
+    let end = &end.token;
+    Range::new(
+        Position::new(begin.line as u32 - 1, begin.column as u32 - 1),
+        Position::new(end.line as u32 - 1, (end.column + end.length) as u32 - 1),
+    )
+}
+
+fn name(token: &Token) -> String {
+    resource_table::get_str_value(token.text).unwrap()
+}
+
+/// Builder of the outline of declarations
+///
+/// Symbols are pushed to `stack` at the beginning of the declaration,
+/// and moved into the children of the parent at the end.
+#[derive(Default)]
+struct DocumentSymbolBuilder {
+    point: HandlerPoint,
+    stack: Vec<DocumentSymbol>,
+    symbols: Vec<DocumentSymbol>,
+}
+
+impl DocumentSymbolBuilder {
+    fn begin(
+        &mut self,
+        name: String,
+        detail: Option<String>,
+        kind: SymbolKind,
+        range: Range,
+        selection_range: Range,
+    ) {
+        #[allow(deprecated)]
+        let symbol = DocumentSymbol {
+            name,
+            detail,
+            kind,
+            tags: None,
+            deprecated: None,
+            range,
+            selection_range,
+            children: None,
+        };
+        self.stack.push(symbol);
+    }
+
+    fn end(&mut self) {
+        if let Some(symbol) = self.stack.pop() {
+            if let Some(parent) = self.stack.last_mut() {
+                parent.children.get_or_insert_with(Vec::new).push(symbol);
+            } else {
+                self.symbols.push(symbol);
+            }
+        }
+    }
+
+    /// Declaration which has an identifier as its name
+    fn declaration(
+        &mut self,
+        identifier: &Identifier,
+        detail: Option<String>,
+        kind: SymbolKind,
+        range: Range,
+    ) {
+        match self.point {
+            HandlerPoint::Before => {
+                let token = &identifier.identifier_token;
+                let selection_range = self::range(token, token);
+                self.begin(name(&token.token), detail, kind, range, selection_range);
+            }
+            HandlerPoint::After => self.end(),
+        }
+    }
+
+    /// Block which has an optional label as its name
+    fn block(
+        &mut self,
+        label: Option<&Identifier>,
+        default: &str,
+        begin: &VerylToken,
+        end: &VerylToken,
+    ) {
+        match self.point {
+            HandlerPoint::Before => {
+                let (name, selection_range) = if let Some(x) = label {
+                    let token = &x.identifier_token;
+                    (self::name(&token.token), range(token, token))
+                } else {
+                    (default.to_string(), range(begin, begin))
+                };
+                let kind = SymbolKind::NAMESPACE;
+                self.begin(name, None, kind, range(begin, end), selection_range);
+            }
+            HandlerPoint::After => self.end(),
+        }
+    }
+}
+
+impl Handler for DocumentSymbolBuilder {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl VerylGrammarTrait for DocumentSymbolBuilder {
+    fn var_declaration(&mut self, arg: &VarDeclaration) -> Result<()> {
+        let mut stringifier = Stringifier::new();
+        stringifier.r#type(&arg.r#type);
+        let detail = Some(stringifier.as_str().to_string());
+        let range = range(&arg.var.var_token, &arg.semicolon.semicolon_token);
+        self.declaration(&arg.identifier, detail, SymbolKind::VARIABLE, range);
+        Ok(())
+    }
+
+    fn localparam_declaration(&mut self, arg: &LocalparamDeclaration) -> Result<()> {
+        let mut stringifier = Stringifier::new();
+        stringifier.r#type(&arg.r#type);
+        let detail = Some(format!("localparam {}", stringifier.as_str()));
+        let range = range(
+            &arg.localparam.localparam_token,
+            &arg.semicolon.semicolon_token,
+        );
+        self.declaration(&arg.identifier, detail, SymbolKind::CONSTANT, range);
+        Ok(())
+    }
+
+    fn always_ff_declaration(&mut self, arg: &AlwaysFfDeclaration) -> Result<()> {
+        let begin = &arg.always_ff.always_ff_token;
+        let end = &arg.r_brace.r_brace_token;
+        match self.point {
+            HandlerPoint::Before => {
+                let mut stringifier = Stringifier::new();
+                stringifier.always_ff_clock(&arg.always_ff_clock);
+                let detail = Some(stringifier.as_str().to_string());
+                let name = "always_ff".to_string();
+                let kind = SymbolKind::EVENT;
+                self.begin(name, detail, kind, range(begin, end), range(begin, begin));
+            }
+            HandlerPoint::After => self.end(),
+        }
+        Ok(())
+    }
+
+    fn always_comb_declaration(&mut self, arg: &AlwaysCombDeclaration) -> Result<()> {
+        let begin = &arg.always_comb.always_comb_token;
+        let end = &arg.r_brace.r_brace_token;
+        match self.point {
+            HandlerPoint::Before => {
+                let name = "always_comb".to_string();
+                let kind = SymbolKind::EVENT;
+                self.begin(name, None, kind, range(begin, end), range(begin, begin));
+            }
+            HandlerPoint::After => self.end(),
+        }
+        Ok(())
+    }
+
+    fn initial_declaration(&mut self, arg: &InitialDeclaration) -> Result<()> {
+        let begin = &arg.initial.initial_token;
+        let end = &arg.r_brace.r_brace_token;
+        match self.point {
+            HandlerPoint::Before => {
+                let name = "initial".to_string();
+                let kind = SymbolKind::EVENT;
+                self.begin(name, None, kind, range(begin, end), range(begin, begin));
+            }
+            HandlerPoint::After => self.end(),
+        }
+        Ok(())
+    }
+
+    fn final_declaration(&mut self, arg: &FinalDeclaration) -> Result<()> {
+        let begin = &arg.r#final.final_token;
+        let end = &arg.r_brace.r_brace_token;
+        match self.point {
+            HandlerPoint::Before => {
+                let name = "final".to_string();
+                let kind = SymbolKind::EVENT;
+                self.begin(name, None, kind, range(begin, end), range(begin, begin));
+            }
+            HandlerPoint::After => self.end(),
+        }
+        Ok(())
+    }
+
+    fn modport_declaration(&mut self, arg: &ModportDeclaration) -> Result<()> {
+        let range = range(&arg.modport.modport_token, &arg.r_brace.r_brace_token);
+        self.declaration(&arg.identifier, None, SymbolKind::INTERFACE, range);
+        Ok(())
+    }
+
+    fn modport_item(&mut self, arg: &ModportItem) -> Result<()> {
+        let mut stringifier = Stringifier::new();
+        stringifier.direction(&arg.direction);
+        let detail = Some(stringifier.as_str().to_string());
+        let token = &arg.identifier.identifier_token;
+        self.declaration(
+            &arg.identifier,
+            detail,
+            SymbolKind::FIELD,
+            range(token, token),
+        );
+        Ok(())
+    }
+
+    fn enum_declaration(&mut self, arg: &EnumDeclaration) -> Result<()> {
+        let mut stringifier = Stringifier::new();
+        stringifier.r#type(&arg.r#type);
+        let detail = Some(stringifier.as_str().to_string());
+        let range = range(&arg.r#enum.enum_token, &arg.r_brace.r_brace_token);
+        self.declaration(&arg.identifier, detail, SymbolKind::ENUM, range);
+        Ok(())
+    }
+
+    fn enum_item(&mut self, arg: &EnumItem) -> Result<()> {
+        let token = &arg.identifier.identifier_token;
+        let range = range(token, token);
+        self.declaration(&arg.identifier, None, SymbolKind::ENUM_MEMBER, range);
+        Ok(())
+    }
+
+    fn struct_declaration(&mut self, arg: &StructDeclaration) -> Result<()> {
+        let begin = match &*arg.struct_union {
+            StructUnion::Struct(x) => &x.r#struct.struct_token,
+            StructUnion::Union(x) => &x.union.union_token,
+        };
+        let range = range(begin, &arg.r_brace.r_brace_token);
+        self.declaration(&arg.identifier, None, SymbolKind::STRUCT, range);
+        Ok(())
+    }
+
+    fn type_declaration(&mut self, arg: &TypeDeclaration) -> Result<()> {
+        let mut stringifier = Stringifier::new();
+        stringifier.r#type(&arg.r#type);
+        let detail = Some(stringifier.as_str().to_string());
+        let range = range(&arg.typ.type_token, &arg.semicolon.semicolon_token);
+        self.declaration(&arg.identifier, detail, SymbolKind::TYPE_PARAMETER, range);
+        Ok(())
+    }
+
+    fn struct_item(&mut self, arg: &StructItem) -> Result<()> {
+        let mut stringifier = Stringifier::new();
+        stringifier.r#type(&arg.r#type);
+        let detail = Some(stringifier.as_str().to_string());
+        let token = &arg.identifier.identifier_token;
+        self.declaration(
+            &arg.identifier,
+            detail,
+            SymbolKind::FIELD,
+            range(token, token),
+        );
+        Ok(())
+    }
+
+    fn inst_declaration(&mut self, arg: &InstDeclaration) -> Result<()> {
+        let detail = Some(name(&arg.identifier0.identifier_token.token));
+        let range = range(&arg.inst.inst_token, &arg.semicolon.semicolon_token);
+        self.declaration(&arg.identifier, detail, SymbolKind::OBJECT, range);
+        Ok(())
+    }
+
+    fn with_parameter_item(&mut self, arg: &WithParameterItem) -> Result<()> {
+        let (scope, begin) = match &*arg.with_parameter_item_group {
+            WithParameterItemGroup::Parameter(x) => ("parameter", &x.parameter.parameter_token),
+            WithParameterItemGroup::Localparam(x) => ("localparam", &x.localparam.localparam_token),
+        };
+        let mut stringifier = Stringifier::new();
+        let kind = match &*arg.with_parameter_item_group0 {
+            WithParameterItemGroup0::Typ(x) => {
+                stringifier.typ(&x.typ);
+                SymbolKind::TYPE_PARAMETER
+            }
+            WithParameterItemGroup0::Type(x) => {
+                stringifier.r#type(&x.r#type);
+                SymbolKind::CONSTANT
+            }
+        };
+        let detail = Some(format!("{} {}", scope, stringifier.as_str()));
+        let range = range(begin, begin);
+        self.declaration(&arg.identifier, detail, kind, range);
+        Ok(())
+    }
+
+    fn port_declaration_item(&mut self, arg: &PortDeclarationItem) -> Result<()> {
+        let mut stringifier = Stringifier::new();
+        let detail = match &*arg.port_declaration_item_group {
+            PortDeclarationItemGroup::DirectionType(x) => {
+                stringifier.direction(&x.direction);
+                let direction = stringifier.as_str().to_string();
+                let mut stringifier = Stringifier::new();
+                stringifier.r#type(&x.r#type);
+                format!("{} {}", direction, stringifier.as_str())
+            }
+            PortDeclarationItemGroup::Interface(_) => "interface".to_string(),
+        };
+        let token = &arg.identifier.identifier_token;
+        let range = range(token, token);
+        self.declaration(&arg.identifier, Some(detail), SymbolKind::VARIABLE, range);
+        Ok(())
+    }
+
+    fn function_declaration(&mut self, arg: &FunctionDeclaration) -> Result<()> {
+        let range = range(&arg.function.function_token, &arg.r_brace.r_brace_token);
+        self.declaration(&arg.identifier, None, SymbolKind::FUNCTION, range);
+        Ok(())
+    }
+
+    fn module_declaration(&mut self, arg: &ModuleDeclaration) -> Result<()> {
+        let range = range(&arg.module.module_token, &arg.r_brace.r_brace_token);
+        self.declaration(&arg.identifier, None, SymbolKind::MODULE, range);
+        Ok(())
+    }
+
+    fn module_named_block(&mut self, arg: &ModuleNamedBlock) -> Result<()> {
+        let begin = &arg.colon.colon_token;
+        let end = &arg.r_brace.r_brace_token;
+        self.block(Some(&arg.identifier), "", begin, end);
+        Ok(())
+    }
+
+    fn module_optional_named_block(&mut self, arg: &ModuleOptionalNamedBlock) -> Result<()> {
+        let label = arg
+            .module_optional_named_block_opt
+            .as_ref()
+            .map(|x| &*x.identifier);
+        let begin = if let Some(ref x) = arg.module_optional_named_block_opt {
+            &x.colon.colon_token
+        } else {
+            &arg.l_brace.l_brace_token
+        };
+        self.block(label, "else", begin, &arg.r_brace.r_brace_token);
+        Ok(())
+    }
+
+    fn interface_declaration(&mut self, arg: &InterfaceDeclaration) -> Result<()> {
+        let range = range(&arg.interface.interface_token, &arg.r_brace.r_brace_token);
+        self.declaration(&arg.identifier, None, SymbolKind::INTERFACE, range);
+        Ok(())
+    }
+
+    fn interface_named_block(&mut self, arg: &InterfaceNamedBlock) -> Result<()> {
+        let begin = &arg.colon.colon_token;
+        let end = &arg.r_brace.r_brace_token;
+        self.block(Some(&arg.identifier), "", begin, end);
+        Ok(())
+    }
+
+    fn interface_optional_named_block(&mut self, arg: &InterfaceOptionalNamedBlock) -> Result<()> {
+        let label = arg
+            .interface_optional_named_block_opt
+            .as_ref()
+            .map(|x| &*x.identifier);
+        let begin = if let Some(ref x) = arg.interface_optional_named_block_opt {
+            &x.colon.colon_token
+        } else {
+            &arg.l_brace.l_brace_token
+        };
+        self.block(label, "else", begin, &arg.r_brace.r_brace_token);
+        Ok(())
+    }
+
+    fn package_declaration(&mut self, arg: &PackageDeclaration) -> Result<()> {
+        let range = range(&arg.package.package_token, &arg.r_brace.r_brace_token);
+        self.declaration(&arg.identifier, None, SymbolKind::PACKAGE, range);
+        Ok(())
+    }
+
+    fn test_declaration(&mut self, arg: &TestDeclaration) -> Result<()> {
+        let range = range(&arg.test.test_token, &arg.r_brace.r_brace_token);
+        self.declaration(&arg.identifier, None, SymbolKind::FUNCTION, range);
+        Ok(())
+    }
+}
+
+struct DocumentSymbolWalker {
+    builder: DocumentSymbolBuilder,
+}
+
+impl VerylWalker for DocumentSymbolWalker {
+    fn get_handlers(&mut self) -> Option<Vec<&mut dyn Handler>> {
+        Some(vec![&mut self.builder as &mut dyn Handler])
+    }
+}
+
+/// Hierarchical symbols of declarations in the document
+pub fn document_symbols(veryl: &Veryl) -> Vec<DocumentSymbol> {
+    let mut walker = DocumentSymbolWalker {
+        builder: DocumentSymbolBuilder::default(),
+    };
+    walker.veryl(veryl);
+    walker.builder.symbols
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use veryl_parser::Parser;
+
+    fn outline(symbols: &[DocumentSymbol], depth: usize, ret: &mut Vec<String>) {
+        for symbol in symbols {
+            let detail = symbol.detail.as_deref().unwrap_or("");
+            ret.push(format!("{}{} {}", "  ".repeat(depth), symbol.name, detail));
+            if let Some(ref children) = symbol.children {
+                outline(children, depth + 1, ret);
+            }
+        }
+    }
+
+    #[test]
+    fn document_symbol() {
+        let code = r#"
+module ModuleA #(
+    parameter X: u32 = 1,
+) (
+    i_clk: input  logic   ,
+    o_a  : output logic[2],
+) {
+    var a: logic;
+
+    always_ff (i_clk) {
+        a = 1;
+    }
+
+    if X == 1 :g {
+        inst u: ModuleB;
+    } else {
+        assign o_a = 0;
+    }
+}
+
+interface InterfaceA {
+    var a: logic;
+
+    modport mp {
+        a: input,
+    }
+}
+
+package PackageA {
+    enum EnumA: logic {
+        A,
+    }
+}
+"#;
+        let parser = Parser::parse(code, &"").unwrap();
+        let symbols = document_symbols(&parser.veryl);
+        let mut ret = Vec::new();
+        outline(&symbols, 0, &mut ret);
+        assert_eq!(
+            ret,
+            vec![
+                "ModuleA ",
+                "  X parameter u32",
+                "  i_clk input logic",
+                "  o_a output logic[2]",
+                "  a logic",
+                "  always_ff i_clk",
+                "  g ",
+                "    u ModuleB",
+                "  else ",
+                "InterfaceA ",
+                "  a logic",
+                "  mp ",
+                "    a input",
+                "PackageA ",
+                "  EnumA logic",
+                "    A ",
+            ]
+        );
+
+        let module = &symbols[0];
+        assert_eq!(
+            module.range,
+            Range::new(Position::new(1, 0), Position::new(18, 1))
+        );
+        assert_eq!(
+            module.selection_range,
+            Range::new(Position::new(1, 7), Position::new(1, 14))
+        );
+    }
+}
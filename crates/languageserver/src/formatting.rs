@@ -0,0 +1,73 @@
+use crate::backend::Backend;
+use ropey::Rope;
+use tower_lsp::lsp_types::{Range, TextEdit};
+use veryl_formatter::Formatter;
+use veryl_parser::veryl_grammar_trait::Veryl;
+
+/// Edit which formats descriptions intersecting `range`
+///
+/// `None` is returned if no description intersects or the text is already formatted.
+pub fn range_formatting(
+    formatter: &mut Formatter,
+    veryl: &Veryl,
+    rope: &Rope,
+    range: &Range,
+) -> Option<TextEdit> {
+    let begin = rope.char_to_byte(Backend::to_char(rope, &range.start));
+    let end = rope.char_to_byte(Backend::to_char(rope, &range.end));
+
+    let (source, text) = formatter.format_range(veryl, rope.len_bytes(), begin..end)?;
+    if rope.byte_slice(source.clone()) == text.as_str() {
+        return None;
+    }
+    let range = Range::new(
+        Backend::to_position(rope, source.start),
+        Backend::to_position(rope, source.end),
+    );
+    Some(TextEdit::new(range, text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower_lsp::lsp_types::Position;
+    use veryl_parser::Parser;
+
+    #[test]
+    fn range_format() {
+        let code = r#"module ModuleA {
+  var a: logic;
+}
+// comment
+
+module ModuleB {
+  var b: logic;
+} // comment
+module ModuleC {
+  var c: logic;
+}
+"#;
+        let parser = Parser::parse(code, &"").unwrap();
+        let rope = Rope::from_str(code);
+
+        let range = Range::new(Position::new(6, 3), Position::new(6, 3));
+        let mut formatter = Formatter::default();
+        let edit = range_formatting(&mut formatter, &parser.veryl, &rope, &range).unwrap();
+        assert_eq!(
+            edit.range,
+            Range::new(Position::new(5, 0), Position::new(8, 0))
+        );
+        assert_eq!(
+            edit.new_text,
+            "module ModuleB {\n    var b: logic ;\n} // comment\n"
+        );
+
+        let range = Range::new(Position::new(0, 0), Position::new(5, 0));
+        let mut formatter = Formatter::default();
+        let edit = range_formatting(&mut formatter, &parser.veryl, &rope, &range).unwrap();
+        assert_eq!(
+            edit.range,
+            Range::new(Position::new(0, 0), Position::new(8, 0))
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use crate::backend::Backend;
+use ropey::Rope;
+use tower_lsp::lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, Position, Range};
+use veryl_analyzer::evaluator::Evaluator;
+use veryl_analyzer::namespace::Namespace;
+use veryl_analyzer::namespace_table;
+use veryl_analyzer::symbol::{ParameterProperty, SymbolKind, Type, TypeKind};
+use veryl_analyzer::symbol_table::{self, Name};
+use veryl_metadata::{Build, ClockType, ResetType};
+use veryl_parser::miette::Result;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::VerylToken;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
+
+/// Maximum depth of parameter references to be evaluated
+const MAX_DEPTH: usize = 16;
+
+/// Value of the constant expression whose identifiers are resolved to parameters
+fn eval(expression: &Expression, namespace: &Namespace, depth: usize) -> Option<i64> {
+    if depth > MAX_DEPTH {
+        return None;
+    }
+    let mut evaluator = Evaluator::new(|x: &ScopedOrHierIdentifier| parameter(x, namespace, depth));
+    evaluator.expression(expression)
+}
+
+fn parameter(arg: &ScopedOrHierIdentifier, namespace: &Namespace, depth: usize) -> Option<i64> {
+    let name: Name = arg.into();
+    let symbol = symbol_table::get(&name, namespace)?;
+    match symbol.kind {
+        SymbolKind::Parameter(ParameterProperty {
+            value: ParameterValue::Expression(x),
+            ..
+        }) => eval(&x.expression, &symbol.namespace, depth + 1),
+        _ => None,
+    }
+}
+
+/// Bit width of the type
+fn bit_width(x: &Type, namespace: &Namespace) -> Option<i64> {
+    let mut ret: i64 = match x.kind {
+        TypeKind::Bit | TypeKind::Logic => 1,
+        TypeKind::U32 | TypeKind::I32 | TypeKind::F32 => 32,
+        TypeKind::U64 | TypeKind::I64 | TypeKind::F64 => 64,
+        _ => return None,
+    };
+    for width in &x.width {
+        ret = ret.checked_mul(eval(width, namespace, 0)?)?;
+    }
+    Some(ret)
+}
+
+fn bits(width: i64) -> String {
+    if width == 1 {
+        "1 bit".to_string()
+    } else {
+        format!("{} bits", width)
+    }
+}
+
+fn begin(rope: &Rope, token: &VerylToken) -> Position {
+    Backend::to_position(rope, token.token.pos)
+}
+
+fn end(rope: &Rope, token: &VerylToken) -> Position {
+    let token = &token.token;
+    Backend::to_position(rope, token.pos + token.length)
+}
+
+#[derive(Default)]
+struct FirstToken {
+    token: Option<VerylToken>,
+}
+
+impl VerylWalker for FirstToken {
+    fn veryl_token(&mut self, arg: &VerylToken) {
+        if self.token.is_none() {
+            self.token = Some(arg.clone());
+        }
+    }
+}
+
+struct InlayHintBuilder<'a> {
+    point: HandlerPoint,
+    rope: &'a Rope,
+    build: &'a Build,
+    range: &'a Range,
+    hints: Vec<InlayHint>,
+}
+
+impl<'a> InlayHintBuilder<'a> {
+    fn push(&mut self, position: Position, label: String, kind: Option<InlayHintKind>, left: bool) {
+        if position < self.range.start || self.range.end < position {
+            return;
+        }
+        self.hints.push(InlayHint {
+            position,
+            label: InlayHintLabel::String(label),
+            kind,
+            text_edits: None,
+            tooltip: None,
+            padding_left: Some(left),
+            padding_right: Some(!left),
+            data: None,
+        });
+    }
+
+    fn function_call(&mut self, identifier: &ScopedOrHierIdentifier, args: &[&Expression]) {
+        let name: Name = identifier.into();
+        let namespace = namespace_table::get(identifier.identifier.identifier_token.token.id);
+        let symbol = namespace.and_then(|x| symbol_table::get(&name, &x));
+        let symbol = match symbol {
+            Some(x) => x,
+            None => return,
+        };
+        let function = match symbol.kind {
+            SymbolKind::Function(ref x) => x.clone(),
+            _ => return,
+        };
+
+        // ports of the function are placed at the namespace of the function
+        let mut namespace = symbol.namespace.clone();
+        namespace.push(symbol.token.text);
+
+        for (arg, port) in args.iter().zip(function.ports.iter()) {
+            let mut first = FirstToken::default();
+            first.expression(arg);
+            if let Some(token) = first.token {
+                let width = port
+                    .property
+                    .r#type
+                    .as_ref()
+                    .and_then(|x| bit_width(x, &namespace));
+                let label = if let Some(width) = width {
+                    format!("{} ({}):", port.name, bits(width))
+                } else {
+                    format!("{}:", port.name)
+                };
+                self.push(
+                    begin(self.rope, &token),
+                    label,
+                    Some(InlayHintKind::PARAMETER),
+                    false,
+                );
+            }
+        }
+    }
+}
+
+impl<'a> Handler for InlayHintBuilder<'a> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl<'a> VerylGrammarTrait for InlayHintBuilder<'a> {
+    fn always_ff_clock(&mut self, arg: &AlwaysFfClock) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            if arg.always_ff_clock_opt.is_none() {
+                let label = match self.build.clock_type {
+                    ClockType::PosEdge => "posedge",
+                    ClockType::NegEdge => "negedge",
+                };
+                let token = &arg.hierarchical_identifier.identifier.identifier_token;
+                self.push(begin(self.rope, token), label.to_string(), None, false);
+            }
+        }
+        Ok(())
+    }
+
+    fn always_ff_reset(&mut self, arg: &AlwaysFfReset) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            if arg.always_ff_reset_opt.is_none() {
+                let label = match self.build.reset_type {
+                    ResetType::AsyncLow => "async_low",
+                    ResetType::AsyncHigh => "async_high",
+                    ResetType::SyncLow => "sync_low",
+                    ResetType::SyncHigh => "sync_high",
+                };
+                let token = &arg.hierarchical_identifier.identifier.identifier_token;
+                self.push(begin(self.rope, token), label.to_string(), None, false);
+            }
+        }
+        Ok(())
+    }
+
+    fn var_declaration(&mut self, arg: &VarDeclaration) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            // the width is obvious if the type has no width
+            if let Some(last) = arg.r#type.type_list0.last() {
+                let token = &arg.identifier.identifier_token;
+                let namespace = namespace_table::get(token.token.id).unwrap_or_default();
+                let r#type: Type = (&*arg.r#type).into();
+                if let Some(width) = bit_width(&r#type, &namespace) {
+                    let position = end(self.rope, &last.width.r_bracket.r_bracket_token);
+                    self.push(position, bits(width), Some(InlayHintKind::TYPE), true);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn factor(&mut self, arg: &Factor) -> Result<()> {
+        if let HandlerPoint::Before = self.point {
+            if let Factor::ScopedOrHierIdentifierFactorOpt(x) = arg {
+                let args = x.factor_opt.as_ref().and_then(|x| x.factor_opt0.as_ref());
+                if let Some(args) = args {
+                    let args = &args.function_call_arg;
+                    let mut expressions = vec![&*args.expression];
+                    expressions.extend(args.function_call_arg_list.iter().map(|x| &*x.expression));
+                    self.function_call(&x.scoped_or_hier_identifier, &expressions);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+struct InlayHintWalker<'a> {
+    builder: InlayHintBuilder<'a>,
+}
+
+impl<'a> VerylWalker for InlayHintWalker<'a> {
+    fn get_handlers(&mut self) -> Option<Vec<&mut dyn Handler>> {
+        Some(vec![&mut self.builder as &mut dyn Handler])
+    }
+}
+
+/// Inlay hints of implicit clock/reset polarity and inferred bit widths within `range`
+pub fn inlay_hints(veryl: &Veryl, rope: &Rope, build: &Build, range: &Range) -> Vec<InlayHint> {
+    let mut walker = InlayHintWalker {
+        builder: InlayHintBuilder {
+            point: HandlerPoint::Before,
+            rope,
+            build,
+            range,
+            hints: Vec::new(),
+        },
+    };
+    walker.veryl(veryl);
+    walker.builder.hints
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use veryl_analyzer::Analyzer;
+    use veryl_parser::Parser;
+
+    #[test]
+    fn inlay_hint() {
+        let code = r#"package PkgA {
+    localparam W: u32 = 4;
+    function FuncA (
+        a: input logic [W * 2],
+        b: input logic,
+    ) -> logic {
+        return a[0] & b;
+    }
+}
+
+module ModuleA #(
+    parameter X: u32 = PkgA::W + 1,
+) (
+    i_clk: input logic,
+    i_rst: input logic,
+) {
+    var a: logic [X][2];
+    var b: logic;
+    var c: logic [a];
+
+    always_ff (i_clk, sync_high i_rst) {
+        b = /* 🙂 */ PkgA::FuncA(a, 1);
+    }
+    always_ff (negedge i_clk) {
+        b = 0;
+    }
+}
+"#;
+        let parser = Parser::parse(code, &"").unwrap();
+        let rope = Rope::from_str(code);
+        let mut analyzer = Analyzer::new(code);
+        analyzer.analyze(&parser.veryl);
+
+        let build = Build {
+            clock_type: ClockType::NegEdge,
+            ..Build::default()
+        };
+        let range = Range::new(Position::new(0, 0), Position::new(u32::MAX, 0));
+        let hints: Vec<_> = inlay_hints(&parser.veryl, &rope, &build, &range)
+            .iter()
+            .map(|x| {
+                let label = match x.label {
+                    InlayHintLabel::String(ref x) => x.clone(),
+                    InlayHintLabel::LabelParts(_) => unreachable!(),
+                };
+                (x.position.line, x.position.character, label)
+            })
+            .collect();
+        assert_eq!(
+            hints,
+            vec![
+                (16, 23, "10 bits".to_string()),
+                (20, 15, "negedge".to_string()),
+                (21, 33, "a (8 bits):".to_string()),
+                (21, 36, "b (1 bit):".to_string()),
+            ]
+        );
+
+        let range = Range::new(Position::new(20, 0), Position::new(21, 0));
+        assert_eq!(inlay_hints(&parser.veryl, &rope, &build, &range).len(), 1);
+    }
+}
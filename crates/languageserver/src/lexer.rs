@@ -0,0 +1,147 @@
+//! Lightweight lexer for the text being edited
+//!
+//! The document being edited can't be parsed in most cases,
+//! so the context of the cursor is detected from the text before the cursor.
+
+/// Lexical token which is enough to detect the context of the cursor
+#[derive(Clone, Debug, PartialEq)]
+pub enum Lex {
+    Ident(String),
+    Punct(String),
+    /// Bracketed group which is closed before the cursor
+    Group,
+    Other,
+}
+
+impl Lex {
+    pub fn is_punct(&self, x: &str) -> bool {
+        matches!(self, Lex::Punct(p) if p == x)
+    }
+
+    pub fn ident(&self) -> Option<&str> {
+        match self {
+            Lex::Ident(x) => Some(x),
+            _ => None,
+        }
+    }
+}
+
+pub fn lex(text: &str) -> Vec<Lex> {
+    let mut ret = Vec::new();
+    let chars: Vec<char> = text.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '/' && next == Some('/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && next == Some('*') {
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                i += 1;
+            }
+            i += 2;
+        } else if c == '"' {
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                if chars[i] == '\\' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            i += 1;
+            ret.push(Lex::Other);
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            ret.push(Lex::Ident(chars[start..i].iter().collect()));
+        } else if c.is_ascii_digit() || c == '\'' {
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || "'_".contains(chars[i])) {
+                i += 1;
+            }
+            ret.push(Lex::Other);
+        } else if c == ':' && next == Some(':') {
+            i += 2;
+            ret.push(Lex::Punct("::".to_string()));
+        } else {
+            i += 1;
+            ret.push(Lex::Punct(c.to_string()));
+        }
+    }
+    ret
+}
+
+/// Tokens of the current item at the depth of the innermost open bracket.
+/// Closed groups are folded into [`Lex::Group`].
+pub fn item(tokens: &[Lex], separator: &[&str]) -> Vec<Lex> {
+    let mut ret = Vec::new();
+    let mut depth = 0;
+    for token in tokens.iter().rev() {
+        let text = match token {
+            Lex::Punct(x) => x.as_str(),
+            _ => "",
+        };
+        if depth == 0 {
+            if matches!(text, "(" | "[" | "{") || separator.contains(&text) {
+                break;
+            } else if matches!(text, ")" | "]" | "}") {
+                ret.push(Lex::Group);
+                depth += 1;
+            } else {
+                ret.push(token.clone());
+            }
+        } else if matches!(text, ")" | "]" | "}") {
+            depth += 1;
+        } else if matches!(text, "(" | "[" | "{") {
+            depth -= 1;
+        }
+    }
+    ret.reverse();
+    ret
+}
+
+/// Indices of brackets which are not closed
+pub fn open_brackets(tokens: &[Lex]) -> Vec<usize> {
+    let mut ret = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Lex::Punct(x) if x == "(" || x == "[" || x == "{" => ret.push(i),
+            Lex::Punct(x) if x == ")" || x == "]" || x == "}" => {
+                ret.pop();
+            }
+            _ => (),
+        }
+    }
+    ret
+}
+
+/// Namespace which contains the end of `tokens`
+pub fn namespace(tokens: &[Lex], open: &[usize]) -> Vec<String> {
+    let mut ret = Vec::new();
+    for i in open {
+        let header = item(&tokens[..*i], &[";", "}"]);
+        let first = header.first().and_then(|x| x.ident());
+        if matches!(
+            first,
+            Some("module" | "interface" | "package" | "function" | "test")
+        ) {
+            if let Some(name) = header.get(1).and_then(|x| x.ident()) {
+                ret.push(name.to_string());
+            }
+        } else if tokens[*i].is_punct("{") && header.len() >= 2 {
+            // named block like `:label {`
+            if header[header.len() - 2].is_punct(":") {
+                if let Some(name) = header[header.len() - 1].ident() {
+                    ret.push(name.to_string());
+                }
+            }
+        }
+    }
+    ret
+}
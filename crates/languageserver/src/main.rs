@@ -1,6 +1,15 @@
 use tower_lsp::{LspService, Server};
 
 mod backend;
+mod call_hierarchy;
+mod code_action;
+mod completion;
+mod document_symbol;
+mod formatting;
+mod inlay_hint;
+mod lexer;
+mod semantic_token;
+mod signature_help;
 use backend::Backend;
 
 #[tokio::main]
@@ -8,6 +17,8 @@ async fn main() {
     let stdin = tokio::io::stdin();
     let stdout = tokio::io::stdout();
 
-    let (service, socket) = LspService::new(Backend::new);
+    let (service, socket) = LspService::build(Backend::new)
+        .custom_method("textDocument/inlayHint", Backend::inlay_hint)
+        .finish();
     Server::new(stdin, stdout, socket).serve(service).await;
 }
@@ -0,0 +1,191 @@
+use tower_lsp::lsp_types::*;
+use veryl_analyzer::reference_table;
+use veryl_analyzer::symbol::{Direction, SymbolKind, TypeKind};
+use veryl_parser::veryl_grammar_trait::{Identifier, Veryl};
+use veryl_parser::veryl_token::Token;
+use veryl_parser::veryl_walker::VerylWalker;
+
+const TOKEN_TYPES: [SemanticTokenType; 9] = [
+    SemanticTokenType::CLASS,
+    SemanticTokenType::INTERFACE,
+    SemanticTokenType::NAMESPACE,
+    SemanticTokenType::FUNCTION,
+    SemanticTokenType::PARAMETER,
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::PROPERTY,
+    SemanticTokenType::new("label"),
+    SemanticTokenType::TYPE,
+];
+
+const TOKEN_MODIFIERS: [SemanticTokenModifier; 6] = [
+    SemanticTokenModifier::DECLARATION,
+    SemanticTokenModifier::READONLY,
+    SemanticTokenModifier::new("input"),
+    SemanticTokenModifier::new("output"),
+    SemanticTokenModifier::new("inout"),
+    SemanticTokenModifier::new("ref"),
+];
+
+pub fn legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: TOKEN_TYPES.to_vec(),
+        token_modifiers: TOKEN_MODIFIERS.to_vec(),
+    }
+}
+
+fn token_type(kind: &SymbolKind) -> SemanticTokenType {
+    match kind {
+        SymbolKind::Module(_) => SemanticTokenType::CLASS,
+        SymbolKind::Interface(_) => SemanticTokenType::INTERFACE,
+        SymbolKind::Package => SemanticTokenType::NAMESPACE,
+        SymbolKind::Function(_) => SemanticTokenType::FUNCTION,
+        SymbolKind::Test => SemanticTokenType::FUNCTION,
+        SymbolKind::Port(_) => SemanticTokenType::PARAMETER,
+        SymbolKind::Variable(_) => SemanticTokenType::VARIABLE,
+        SymbolKind::Parameter(x) if matches!(x.r#type.kind, TypeKind::Type) => {
+            SemanticTokenType::TYPE
+        }
+        SymbolKind::Parameter(_) => SemanticTokenType::VARIABLE,
+        SymbolKind::Instance(_) => SemanticTokenType::PROPERTY,
+        SymbolKind::Type(_) => SemanticTokenType::TYPE,
+        SymbolKind::Struct | SymbolKind::Union | SymbolKind::Enum => SemanticTokenType::TYPE,
+        SymbolKind::Block => SemanticTokenType::new("label"),
+    }
+}
+
+fn token_modifiers(kind: &SymbolKind, declaration: bool) -> Vec<SemanticTokenModifier> {
+    let mut ret = Vec::new();
+    if declaration {
+        ret.push(SemanticTokenModifier::DECLARATION);
+    }
+    match kind {
+        SymbolKind::Parameter(_) => ret.push(SemanticTokenModifier::READONLY),
+        SymbolKind::Port(x) => match x.direction {
+            Direction::Input => ret.push(SemanticTokenModifier::new("input")),
+            Direction::Output => ret.push(SemanticTokenModifier::new("output")),
+            Direction::Inout => ret.push(SemanticTokenModifier::new("inout")),
+            Direction::Ref => ret.push(SemanticTokenModifier::new("ref")),
+            Direction::Interface | Direction::Modport => (),
+        },
+        _ => (),
+    }
+    ret
+}
+
+#[derive(Default)]
+struct IdentifierCollector {
+    tokens: Vec<Token>,
+}
+
+impl VerylWalker for IdentifierCollector {
+    /// Semantic action for non-terminal 'Identifier'
+    fn identifier(&mut self, arg: &Identifier) {
+        self.tokens.push(arg.identifier_token.token);
+    }
+}
+
+/// Semantic tokens of identifiers which refer symbols.
+/// Only tokens which start within `range` are returned if it is specified.
+pub fn semantic_tokens(veryl: &Veryl, range: Option<&Range>) -> Vec<SemanticToken> {
+    let mut collector = IdentifierCollector::default();
+    collector.veryl(veryl);
+    let mut tokens = collector.tokens;
+    tokens.sort_by_key(|x| (x.line, x.column));
+
+    let mut ret = Vec::new();
+    let mut line = 0;
+    let mut column = 0;
+    for token in tokens {
+        let position = Position::new(token.line as u32 - 1, token.column as u32 - 1);
+        if let Some(range) = range {
+            if position < range.start || range.end <= position {
+                continue;
+            }
+        }
+
+        if let Some(symbol) = reference_table::resolve(&token) {
+            let declaration = symbol.token.id == token.id;
+            let token_type = token_type(&symbol.kind);
+            let token_type = TOKEN_TYPES.iter().position(|x| *x == token_type).unwrap();
+            let mut token_modifiers_bitset = 0;
+            for modifier in token_modifiers(&symbol.kind, declaration) {
+                let i = TOKEN_MODIFIERS.iter().position(|x| *x == modifier).unwrap();
+                token_modifiers_bitset |= 1 << i;
+            }
+
+            let delta_line = position.line - line;
+            let delta_start = if delta_line == 0 {
+                position.character - column
+            } else {
+                position.character
+            };
+            line = position.line;
+            column = position.character;
+
+            ret.push(SemanticToken {
+                delta_line,
+                delta_start,
+                length: token.length as u32,
+                token_type: token_type as u32,
+                token_modifiers_bitset,
+            });
+        }
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use veryl_analyzer::Analyzer;
+    use veryl_parser::Parser;
+
+    #[test]
+    fn semantic_token() {
+        let code = r#"module ModuleA #(
+    parameter X: u32 = 1,
+) (
+    i_a: input  logic,
+    o_b: output logic,
+) {
+    var a: logic;
+    assign a   = i_a;
+    assign o_b = a + X;
+}
+"#;
+        let parser = Parser::parse(code, &"").unwrap();
+        let mut analyzer = Analyzer::new(code);
+        analyzer.analyze(&parser.veryl);
+
+        let tokens: Vec<_> = semantic_tokens(&parser.veryl, None)
+            .iter()
+            .map(|x| {
+                (
+                    x.delta_line,
+                    x.delta_start,
+                    x.length,
+                    x.token_type,
+                    x.token_modifiers_bitset,
+                )
+            })
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                (0, 7, 7, 0, 0b000001),  // ModuleA
+                (1, 14, 1, 5, 0b000011), // X
+                (2, 4, 3, 4, 0b000101),  // i_a
+                (1, 4, 3, 4, 0b001001),  // o_b
+                (2, 8, 1, 5, 0b000001),  // a
+                (1, 11, 1, 5, 0b000000), // a
+                (0, 6, 3, 4, 0b000100),  // i_a
+                (1, 11, 3, 4, 0b001000), // o_b
+                (0, 6, 1, 5, 0b000000),  // a
+                (0, 4, 1, 5, 0b000010),  // X
+            ]
+        );
+
+        let range = Range::new(Position::new(7, 0), Position::new(8, 0));
+        assert_eq!(semantic_tokens(&parser.veryl, Some(&range)).len(), 2);
+    }
+}
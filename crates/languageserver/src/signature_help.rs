@@ -0,0 +1,269 @@
+use crate::lexer::{self, item, lex, Lex};
+use tower_lsp::lsp_types::*;
+use veryl_analyzer::namespace::Namespace;
+use veryl_analyzer::symbol::{Parameter, Port, SymbolKind as VerylSymbolKind, Type};
+use veryl_analyzer::symbol_table::{self, Name};
+use veryl_parser::resource_table;
+use veryl_parser::veryl_walker::VerylWalker;
+use veryl_parser::Stringifier;
+
+fn type_text(x: &Type) -> String {
+    let mut ret = x.to_string();
+    if !x.width.is_empty() {
+        ret.push(' ');
+        for width in &x.width {
+            let mut stringifier = Stringifier::new();
+            stringifier.expression(width);
+            ret.push_str(&format!("[{}]", stringifier.as_str()));
+        }
+    }
+    if !x.array.is_empty() {
+        ret.push_str(" unpacked ");
+        for width in &x.array {
+            let mut stringifier = Stringifier::new();
+            stringifier.expression(width);
+            ret.push_str(&format!("[{}]", stringifier.as_str()));
+        }
+    }
+    ret
+}
+
+fn port_text(x: &Port) -> String {
+    if let Some(ref r#type) = x.property.r#type {
+        format!("{}: {} {}", x.name, x.property.direction, type_text(r#type))
+    } else {
+        format!("{}: {}", x.name, x.property.direction)
+    }
+}
+
+fn parameter_text(x: &Parameter) -> String {
+    format!("{}: {}", x.name, type_text(&x.property.r#type))
+}
+
+/// Signature like `name(a: input logic, b: output logic)`
+fn signature(
+    prefix: &str,
+    open: &str,
+    close: &str,
+    items: &[String],
+    active: usize,
+) -> SignatureHelp {
+    let mut label = format!("{}{}", prefix, open);
+    let mut parameters = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        if i != 0 {
+            label.push_str(", ");
+        }
+        let begin = label.len() as u32;
+        label.push_str(item);
+        let end = label.len() as u32;
+        parameters.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([begin, end]),
+            documentation: None,
+        });
+    }
+    label.push_str(close);
+
+    SignatureHelp {
+        signatures: vec![SignatureInformation {
+            label,
+            documentation: None,
+            parameters: Some(parameters),
+            active_parameter: None,
+        }],
+        active_signature: Some(0),
+        active_parameter: Some(active as u32),
+    }
+}
+
+fn to_namespace(paths: &[String]) -> Namespace {
+    let mut ret = Namespace::default();
+    for path in paths {
+        ret.push(resource_table::insert_str(path));
+    }
+    ret
+}
+
+/// Signature of the function call or the instance which contains the end of `text`
+pub fn signature_help(text: &str) -> Option<SignatureHelp> {
+    let tokens = lex(text);
+    let open = lexer::open_brackets(&tokens);
+
+    // the innermost `(` may be a parenthesized expression in the argument list
+    for i in (0..open.len()).rev() {
+        let last = open[i];
+        if tokens[last].is_punct("{") {
+            break;
+        }
+        if !tokens[last].is_punct("(") {
+            continue;
+        }
+
+        let mut active = 0;
+        let mut depth = 0;
+        for token in &tokens[last + 1..] {
+            match token {
+                Lex::Punct(x) if x == "(" || x == "[" || x == "{" => depth += 1,
+                Lex::Punct(x) if x == ")" || x == "]" || x == "}" => depth -= 1,
+                Lex::Punct(x) if x == "," && depth == 0 => active += 1,
+                _ => (),
+            }
+        }
+
+        let header = item(&tokens[..last], &[";", "}"]);
+        let ret = if header.first().and_then(|x| x.ident()) == Some("inst") {
+            inst_signature(&header, active)
+        } else {
+            let namespace = to_namespace(&lexer::namespace(&tokens, &open[..i]));
+            function_signature(&header, &namespace, active)
+        };
+        if ret.is_some() {
+            return ret;
+        }
+    }
+    None
+}
+
+fn inst_signature(header: &[Lex], active: usize) -> Option<SignatureHelp> {
+    let is_param = header.last().map(|x| x.is_punct("#")).unwrap_or(false);
+    let name = header
+        .iter()
+        .skip(3)
+        .take_while(|x| !x.is_punct("#"))
+        .filter_map(|x| x.ident())
+        .last()?;
+    let id = resource_table::insert_str(name);
+    let symbol = symbol_table::get(&Name::Hierarchical(vec![id]), &Namespace::default())?;
+    let (parameters, ports) = match symbol.kind {
+        VerylSymbolKind::Module(x) => (x.parameters, x.ports),
+        VerylSymbolKind::Interface(x) => (x.parameters, Vec::new()),
+        _ => return None,
+    };
+    if is_param {
+        let items: Vec<_> = parameters.iter().map(parameter_text).collect();
+        Some(signature(name, " #(", ")", &items, active))
+    } else {
+        let items: Vec<_> = ports.iter().map(port_text).collect();
+        Some(signature(name, " (", ")", &items, active))
+    }
+}
+
+fn function_signature(
+    header: &[Lex],
+    namespace: &Namespace,
+    active: usize,
+) -> Option<SignatureHelp> {
+    // function name like `a` or `Pkg::a`
+    let mut path = Vec::new();
+    let mut iter = header.iter().rev();
+    path.push(iter.next()?.ident()?.to_string());
+    while let Some(x) = iter.next() {
+        if !x.is_punct("::") {
+            // system function like `$clog2` is not a symbol
+            if x.is_punct("$") {
+                return None;
+            }
+            break;
+        }
+        path.push(iter.next()?.ident()?.to_string());
+    }
+    path.reverse();
+
+    let paths = path.iter().map(|x| resource_table::insert_str(x)).collect();
+    let name = if path.len() == 1 {
+        Name::Hierarchical(paths)
+    } else {
+        Name::Scoped(paths)
+    };
+    let symbol = symbol_table::get(&name, namespace)?;
+    if let VerylSymbolKind::Function(x) = symbol.kind {
+        let items: Vec<_> = x.ports.iter().map(port_text).collect();
+        Some(signature(&path.join("::"), "(", ")", &items, active))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use veryl_analyzer::Analyzer;
+    use veryl_parser::Parser;
+
+    fn help(text: &str) -> Option<(String, Vec<String>, u32)> {
+        let x = signature_help(text)?;
+        let signature = &x.signatures[0];
+        let parameters = signature
+            .parameters
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|x| match x.label {
+                ParameterLabel::LabelOffsets([b, e]) => {
+                    signature.label[b as usize..e as usize].to_string()
+                }
+                ParameterLabel::Simple(ref x) => x.clone(),
+            })
+            .collect();
+        Some((signature.label.clone(), parameters, x.active_parameter?))
+    }
+
+    #[test]
+    fn signature() {
+        let code = r#"
+package PkgA {
+    function FuncA (
+        a: input logic,
+        b: input logic [2],
+    ) -> logic {
+        return a;
+    }
+}
+
+module ModuleA #(
+    parameter X: u32 = 1,
+) (
+    i_a: input  logic    ,
+    o_b: output logic [X],
+) {
+    assign o_b = i_a;
+}
+"#;
+        let parser = Parser::parse(code, &"").unwrap();
+        let mut analyzer = Analyzer::new(code);
+        analyzer.analyze(&parser.veryl);
+
+        assert_eq!(
+            help("module B {\n    assign a = PkgA::FuncA(x, (y"),
+            Some((
+                "PkgA::FuncA(a: input logic, b: input logic [2])".to_string(),
+                vec![
+                    "a: input logic".to_string(),
+                    "b: input logic [2]".to_string()
+                ],
+                1
+            ))
+        );
+        assert_eq!(
+            help("module B {\n    inst u: ModuleA (\n        i_a,\n        o_b: x"),
+            Some((
+                "ModuleA (i_a: input logic, o_b: output logic [X])".to_string(),
+                vec![
+                    "i_a: input logic".to_string(),
+                    "o_b: output logic [X]".to_string()
+                ],
+                1
+            ))
+        );
+        assert_eq!(
+            help("module B {\n    inst u: ModuleA #("),
+            Some((
+                "ModuleA #(X: u32)".to_string(),
+                vec!["X: u32".to_string()],
+                0
+            ))
+        );
+        assert_eq!(help("module B {\n    assign a = $clog2("), None);
+        assert_eq!(help("module B {\n    assign a = FuncA("), None);
+    }
+}
@@ -1,5 +1,8 @@
 mod metadata;
 mod metadata_error;
-pub use metadata::{Build, ClockType, Format, Metadata, Package, ResetType, Target};
+pub use metadata::{
+    Build, ClockType, Doc, Format, Metadata, NetlistFormat, Package, ResetType, Target, Test,
+    WaveformFormat,
+};
 pub use metadata_error::MetadataError;
 pub use semver;
@@ -11,6 +11,10 @@ pub struct Metadata {
     pub build: Build,
     #[serde(default)]
     pub format: Format,
+    #[serde(default)]
+    pub test: Test,
+    #[serde(default)]
+    pub doc: Doc,
     #[serde(skip)]
     pub metadata_path: PathBuf,
 }
@@ -59,6 +63,14 @@ pub struct Build {
     pub reset_type: ResetType,
     #[serde(default)]
     pub target: Target,
+    #[serde(default)]
+    pub header: bool,
+    pub header_comment: Option<String>,
+    #[serde(default)]
+    pub strip_initial_final: bool,
+    #[serde(default)]
+    pub strip_assertion: bool,
+    pub netlist: Option<NetlistFormat>,
 }
 
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
@@ -93,6 +105,14 @@ pub enum Target {
     Directory { path: PathBuf },
 }
 
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum NetlistFormat {
+    #[serde(rename = "json")]
+    Json,
+    #[serde(rename = "rtlil")]
+    Rtlil,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Format {
     #[serde(default = "default_indent_width")]
@@ -113,6 +133,77 @@ fn default_indent_width() -> usize {
     DEFAULT_INDENT_WIDTH
 }
 
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Test {
+    pub waveform: Option<WaveformFormat>,
+    /// Maximum depth of module instances to be dumped. `1` means the top module only.
+    pub waveform_depth: Option<usize>,
+    #[serde(default)]
+    pub waveform_start: u64,
+    pub waveform_end: Option<u64>,
+    #[serde(default)]
+    pub timescale: Timescale,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum WaveformFormat {
+    #[serde(rename = "vcd")]
+    Vcd,
+    #[serde(rename = "fst")]
+    Fst,
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Timescale {
+    #[serde(rename = "1s")]
+    S,
+    #[serde(rename = "1ms")]
+    Ms,
+    #[serde(rename = "1us")]
+    Us,
+    #[default]
+    #[serde(rename = "1ns")]
+    Ns,
+    #[serde(rename = "1ps")]
+    Ps,
+    #[serde(rename = "1fs")]
+    Fs,
+}
+
+impl Timescale {
+    /// Exponent of the time unit like `-9` for `1ns`
+    pub fn exponent(&self) -> i8 {
+        match self {
+            Timescale::S => 0,
+            Timescale::Ms => -3,
+            Timescale::Us => -6,
+            Timescale::Ns => -9,
+            Timescale::Ps => -12,
+            Timescale::Fs => -15,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Doc {
+    #[serde(default = "default_doc_path")]
+    pub path: PathBuf,
+}
+
+const DEFAULT_DOC_PATH: &str = "doc";
+
+impl Default for Doc {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::from(DEFAULT_DOC_PATH),
+        }
+    }
+}
+
+fn default_doc_path() -> PathBuf {
+    PathBuf::from(DEFAULT_DOC_PATH)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,9 +219,21 @@ clock_type = "posedge"
 reset_type = "async_low"
 target = {type = "source"}
 #target = {type = "directory", path = "aaa"}
+header = true
+header_comment = "Copyright (c) test"
+netlist = "json"
 
 [format]
 indent_width = 4
+
+[test]
+waveform = "vcd"
+waveform_depth = 2
+waveform_end = 100
+timescale = "1ps"
+
+[doc]
+path = "target/doc"
     "#;
 
     #[test]
@@ -149,7 +252,19 @@ indent_width = 4
         );
         assert_eq!(metadata.build.clock_type, ClockType::PosEdge);
         assert_eq!(metadata.build.reset_type, ResetType::AsyncLow);
+        assert!(metadata.build.header);
+        assert_eq!(
+            metadata.build.header_comment.as_deref(),
+            Some("Copyright (c) test")
+        );
+        assert_eq!(metadata.build.netlist, Some(NetlistFormat::Json));
         assert_eq!(metadata.format.indent_width, 4);
+        assert_eq!(metadata.test.waveform, Some(WaveformFormat::Vcd));
+        assert_eq!(metadata.test.waveform_depth, Some(2));
+        assert_eq!(metadata.test.waveform_start, 0);
+        assert_eq!(metadata.test.waveform_end, Some(100));
+        assert_eq!(metadata.test.timescale.exponent(), -12);
+        assert_eq!(metadata.doc.path, PathBuf::from("target/doc"));
     }
 
     #[test]
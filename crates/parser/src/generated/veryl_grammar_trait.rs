@@ -52,6 +52,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'StringLiteralTerm'
+    fn string_literal_term(&mut self, _arg: &StringLiteralTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'MinusColonTerm'
     fn minus_colon_term(&mut self, _arg: &MinusColonTerm) -> Result<()> {
         Ok(())
@@ -147,11 +152,6 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
-    /// Semantic action for non-terminal 'DollarTerm'
-    fn dollar_term(&mut self, _arg: &DollarTerm) -> Result<()> {
-        Ok(())
-    }
-
     /// Semantic action for non-terminal 'DotDotTerm'
     fn dot_dot_term(&mut self, _arg: &DotDotTerm) -> Result<()> {
         Ok(())
@@ -222,11 +222,26 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AsTerm'
+    fn as_term(&mut self, _arg: &AsTerm) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'AssertTerm'
+    fn assert_term(&mut self, _arg: &AssertTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'AssignTerm'
     fn assign_term(&mut self, _arg: &AssignTerm) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AssumeTerm'
+    fn assume_term(&mut self, _arg: &AssumeTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'AsyncHighTerm'
     fn async_high_term(&mut self, _arg: &AsyncHighTerm) -> Result<()> {
         Ok(())
@@ -247,6 +262,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'CoverTerm'
+    fn cover_term(&mut self, _arg: &CoverTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'DefaultTerm'
     fn default_term(&mut self, _arg: &DefaultTerm) -> Result<()> {
         Ok(())
@@ -262,6 +282,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ExpectTerm'
+    fn expect_term(&mut self, _arg: &ExpectTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ExportTerm'
     fn export_term(&mut self, _arg: &ExportTerm) -> Result<()> {
         Ok(())
@@ -277,6 +302,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'FinalTerm'
+    fn final_term(&mut self, _arg: &FinalTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ForTerm'
     fn for_term(&mut self, _arg: &ForTerm) -> Result<()> {
         Ok(())
@@ -312,6 +342,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'InitialTerm'
+    fn initial_term(&mut self, _arg: &InitialTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'InoutTerm'
     fn inout_term(&mut self, _arg: &InoutTerm) -> Result<()> {
         Ok(())
@@ -397,6 +432,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'SignedTerm'
+    fn signed_term(&mut self, _arg: &SignedTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'StepTerm'
     fn step_term(&mut self, _arg: &StepTerm) -> Result<()> {
         Ok(())
@@ -417,11 +457,21 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'TestTerm'
+    fn test_term(&mut self, _arg: &TestTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'TriTerm'
     fn tri_term(&mut self, _arg: &TriTerm) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'TypeTerm'
+    fn type_term(&mut self, _arg: &TypeTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'U32Term'
     fn u32_term(&mut self, _arg: &U32Term) -> Result<()> {
         Ok(())
@@ -432,11 +482,26 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'UnionTerm'
+    fn union_term(&mut self, _arg: &UnionTerm) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'UnpackedTerm'
+    fn unpacked_term(&mut self, _arg: &UnpackedTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'VarTerm'
     fn var_term(&mut self, _arg: &VarTerm) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'DollarIdentifierTerm'
+    fn dollar_identifier_term(&mut self, _arg: &DollarIdentifierTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'IdentifierTerm'
     fn identifier_term(&mut self, _arg: &IdentifierTerm) -> Result<()> {
         Ok(())
@@ -477,6 +542,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'StringLiteralToken'
+    fn string_literal_token(&mut self, _arg: &StringLiteralToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'AssignmentOperatorToken'
     fn assignment_operator_token(&mut self, _arg: &AssignmentOperatorToken) -> Result<()> {
         Ok(())
@@ -557,11 +627,6 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
-    /// Semantic action for non-terminal 'DollarToken'
-    fn dollar_token(&mut self, _arg: &DollarToken) -> Result<()> {
-        Ok(())
-    }
-
     /// Semantic action for non-terminal 'DotDotToken'
     fn dot_dot_token(&mut self, _arg: &DotDotToken) -> Result<()> {
         Ok(())
@@ -647,11 +712,26 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AsToken'
+    fn as_token(&mut self, _arg: &AsToken) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'AssertToken'
+    fn assert_token(&mut self, _arg: &AssertToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'AssignToken'
     fn assign_token(&mut self, _arg: &AssignToken) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AssumeToken'
+    fn assume_token(&mut self, _arg: &AssumeToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'AsyncHighToken'
     fn async_high_token(&mut self, _arg: &AsyncHighToken) -> Result<()> {
         Ok(())
@@ -672,6 +752,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'CoverToken'
+    fn cover_token(&mut self, _arg: &CoverToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'DefaultToken'
     fn default_token(&mut self, _arg: &DefaultToken) -> Result<()> {
         Ok(())
@@ -687,6 +772,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ExpectToken'
+    fn expect_token(&mut self, _arg: &ExpectToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ExportToken'
     fn export_token(&mut self, _arg: &ExportToken) -> Result<()> {
         Ok(())
@@ -702,6 +792,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'FinalToken'
+    fn final_token(&mut self, _arg: &FinalToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ForToken'
     fn for_token(&mut self, _arg: &ForToken) -> Result<()> {
         Ok(())
@@ -737,6 +832,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'InitialToken'
+    fn initial_token(&mut self, _arg: &InitialToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'InoutToken'
     fn inout_token(&mut self, _arg: &InoutToken) -> Result<()> {
         Ok(())
@@ -822,6 +922,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'SignedToken'
+    fn signed_token(&mut self, _arg: &SignedToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'StepToken'
     fn step_token(&mut self, _arg: &StepToken) -> Result<()> {
         Ok(())
@@ -842,11 +947,21 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'TestToken'
+    fn test_token(&mut self, _arg: &TestToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'TriToken'
     fn tri_token(&mut self, _arg: &TriToken) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'TypeToken'
+    fn type_token(&mut self, _arg: &TypeToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'U32Token'
     fn u32_token(&mut self, _arg: &U32Token) -> Result<()> {
         Ok(())
@@ -857,11 +972,26 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'UnionToken'
+    fn union_token(&mut self, _arg: &UnionToken) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'UnpackedToken'
+    fn unpacked_token(&mut self, _arg: &UnpackedToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'VarToken'
     fn var_token(&mut self, _arg: &VarToken) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'DollarIdentifierToken'
+    fn dollar_identifier_token(&mut self, _arg: &DollarIdentifierToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'IdentifierToken'
     fn identifier_token(&mut self, _arg: &IdentifierToken) -> Result<()> {
         Ok(())
@@ -897,6 +1027,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'StringLiteral'
+    fn string_literal(&mut self, _arg: &StringLiteral) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'AssignmentOperator'
     fn assignment_operator(&mut self, _arg: &AssignmentOperator) -> Result<()> {
         Ok(())
@@ -977,11 +1112,6 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
-    /// Semantic action for non-terminal 'Dollar'
-    fn dollar(&mut self, _arg: &Dollar) -> Result<()> {
-        Ok(())
-    }
-
     /// Semantic action for non-terminal 'DotDot'
     fn dot_dot(&mut self, _arg: &DotDot) -> Result<()> {
         Ok(())
@@ -1067,11 +1197,26 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'As'
+    fn r#as(&mut self, _arg: &As) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Assert'
+    fn assert(&mut self, _arg: &Assert) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Assign'
     fn assign(&mut self, _arg: &Assign) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Assume'
+    fn assume(&mut self, _arg: &Assume) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'AsyncHigh'
     fn async_high(&mut self, _arg: &AsyncHigh) -> Result<()> {
         Ok(())
@@ -1092,6 +1237,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Cover'
+    fn cover(&mut self, _arg: &Cover) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Defaul'
     fn defaul(&mut self, _arg: &Defaul) -> Result<()> {
         Ok(())
@@ -1107,6 +1257,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Expect'
+    fn expect(&mut self, _arg: &Expect) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Export'
     fn export(&mut self, _arg: &Export) -> Result<()> {
         Ok(())
@@ -1122,6 +1277,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Final'
+    fn r#final(&mut self, _arg: &Final) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'For'
     fn r#for(&mut self, _arg: &For) -> Result<()> {
         Ok(())
@@ -1162,6 +1322,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Initial'
+    fn initial(&mut self, _arg: &Initial) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Inout'
     fn inout(&mut self, _arg: &Inout) -> Result<()> {
         Ok(())
@@ -1242,6 +1407,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Signed'
+    fn signed(&mut self, _arg: &Signed) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Step'
     fn step(&mut self, _arg: &Step) -> Result<()> {
         Ok(())
@@ -1262,11 +1432,21 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Test'
+    fn test(&mut self, _arg: &Test) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Tri'
     fn tri(&mut self, _arg: &Tri) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Typ'
+    fn typ(&mut self, _arg: &Typ) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'U32'
     fn u32(&mut self, _arg: &U32) -> Result<()> {
         Ok(())
@@ -1277,11 +1457,26 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Union'
+    fn r#union(&mut self, _arg: &Union) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Unpacked'
+    fn unpacked(&mut self, _arg: &Unpacked) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Var'
     fn var(&mut self, _arg: &Var) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'DollarIdentifier'
+    fn dollar_identifier(&mut self, _arg: &DollarIdentifier) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Identifier'
     fn identifier(&mut self, _arg: &Identifier) -> Result<()> {
         Ok(())
@@ -1382,11 +1577,26 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Expression12'
+    fn expression12(&mut self, _arg: &Expression12) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Factor'
     fn factor(&mut self, _arg: &Factor) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'IfExpression'
+    fn if_expression(&mut self, _arg: &IfExpression) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'SystemFunctionCall'
+    fn system_function_call(&mut self, _arg: &SystemFunctionCall) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'FunctionCallArg'
     fn function_call_arg(&mut self, _arg: &FunctionCallArg) -> Result<()> {
         Ok(())
@@ -1427,6 +1637,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Array'
+    fn array(&mut self, _arg: &Array) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Type'
     fn r#type(&mut self, _arg: &Type) -> Result<()> {
         Ok(())
@@ -1467,11 +1682,41 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AssertionStatement'
+    fn assertion_statement(&mut self, _arg: &AssertionStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'AssertionKind'
+    fn assertion_kind(&mut self, _arg: &AssertionKind) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'SystemTaskStatement'
+    fn system_task_statement(&mut self, _arg: &SystemTaskStatement) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'CaseItem'
     fn case_item(&mut self, _arg: &CaseItem) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Attribute'
+    fn attribute(&mut self, _arg: &Attribute) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'AttributeList'
+    fn attribute_list(&mut self, _arg: &AttributeList) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'AttributeItem'
+    fn attribute_item(&mut self, _arg: &AttributeItem) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'VarDeclaration'
     fn var_declaration(&mut self, _arg: &VarDeclaration) -> Result<()> {
         Ok(())
@@ -1482,6 +1727,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'TypeDeclaration'
+    fn type_declaration(&mut self, _arg: &TypeDeclaration) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'AlwaysFfDeclaration'
     fn always_ff_declaration(&mut self, _arg: &AlwaysFfDeclaration) -> Result<()> {
         Ok(())
@@ -1502,6 +1752,21 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AssertionDeclaration'
+    fn assertion_declaration(&mut self, _arg: &AssertionDeclaration) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'InitialDeclaration'
+    fn initial_declaration(&mut self, _arg: &InitialDeclaration) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'FinalDeclaration'
+    fn final_declaration(&mut self, _arg: &FinalDeclaration) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'AssignDeclaration'
     fn assign_declaration(&mut self, _arg: &AssignDeclaration) -> Result<()> {
         Ok(())
@@ -1537,6 +1802,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'StructUnion'
+    fn struct_union(&mut self, _arg: &StructUnion) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'StructDeclaration'
     fn struct_declaration(&mut self, _arg: &StructDeclaration) -> Result<()> {
         Ok(())
@@ -1597,6 +1867,16 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ParameterValue'
+    fn parameter_value(&mut self, _arg: &ParameterValue) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'TypeValue'
+    fn type_value(&mut self, _arg: &TypeValue) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'PortDeclaration'
     fn port_declaration(&mut self, _arg: &PortDeclaration) -> Result<()> {
         Ok(())
@@ -1707,6 +1987,26 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'TestDeclaration'
+    fn test_declaration(&mut self, _arg: &TestDeclaration) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'TestItem'
+    fn test_item(&mut self, _arg: &TestItem) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'StepStatement'
+    fn step_statement(&mut self, _arg: &StepStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'ExpectStatement'
+    fn expect_statement(&mut self, _arg: &ExpectStatement) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Description'
     fn description(&mut self, _arg: &Description) -> Result<()> {
         Ok(())
@@ -1724,7 +2024,7 @@ pub trait VerylGrammarTrait {
 //
 
 ///
-/// Type derived for production 255
+/// Type derived for production 294
 ///
 /// Number: IntegralNumber;
 ///
@@ -1736,7 +2036,7 @@ pub struct NumberIntegralNumber {
 }
 
 ///
-/// Type derived for production 256
+/// Type derived for production 295
 ///
 /// Number: RealNumber;
 ///
@@ -1748,7 +2048,7 @@ pub struct NumberRealNumber {
 }
 
 ///
-/// Type derived for production 257
+/// Type derived for production 296
 ///
 /// IntegralNumber: Based;
 ///
@@ -1760,7 +2060,7 @@ pub struct IntegralNumberBased {
 }
 
 ///
-/// Type derived for production 258
+/// Type derived for production 297
 ///
 /// IntegralNumber: BaseLess;
 ///
@@ -1772,7 +2072,7 @@ pub struct IntegralNumberBaseLess {
 }
 
 ///
-/// Type derived for production 259
+/// Type derived for production 298
 ///
 /// IntegralNumber: AllBit;
 ///
@@ -1784,7 +2084,7 @@ pub struct IntegralNumberAllBit {
 }
 
 ///
-/// Type derived for production 260
+/// Type derived for production 299
 ///
 /// RealNumber: FixedPoint;
 ///
@@ -1796,7 +2096,7 @@ pub struct RealNumberFixedPoint {
 }
 
 ///
-/// Type derived for production 261
+/// Type derived for production 300
 ///
 /// RealNumber: Exponent;
 ///
@@ -1808,7 +2108,7 @@ pub struct RealNumberExponent {
 }
 
 ///
-/// Type derived for production 274
+/// Type derived for production 313
 ///
 /// ScopedOrHierIdentifierGroup: ColonColon Identifier ScopedOrHierIdentifierGroupList /* Vec */;
 ///
@@ -1822,7 +2122,7 @@ pub struct ScopedOrHierIdentifierGroupColonColonIdentifierScopedOrHierIdentifier
 }
 
 ///
-/// Type derived for production 277
+/// Type derived for production 316
 ///
 /// ScopedOrHierIdentifierGroup: ScopedOrHierIdentifierGroupList0 /* Vec */ ScopedOrHierIdentifierGroupList1 /* Vec */;
 ///
@@ -1836,91 +2136,115 @@ pub struct ScopedOrHierIdentifierGroupScopedOrHierIdentifierGroupList0ScopedOrHi
 }
 
 ///
-/// Type derived for production 313
+/// Type derived for production 323
+///
+/// Expression: IfExpression;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ExpressionIfExpression {
+    pub if_expression: Box<IfExpression>,
+}
+
 ///
-/// Expression09ListGroup: Operator10;
+/// Type derived for production 324
+///
+/// Expression: Expression01;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ExpressionExpression01 {
+    pub expression01: Box<Expression01>,
+}
+
+///
+/// Type derived for production 354
+///
+/// Expression10ListGroup: Operator10;
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct Expression09ListGroupOperator10 {
+pub struct Expression10ListGroupOperator10 {
     pub operator10: Box<Operator10>,
 }
 
 ///
-/// Type derived for production 314
+/// Type derived for production 355
 ///
-/// Expression09ListGroup: Star;
+/// Expression10ListGroup: Star;
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct Expression09ListGroupStar {
+pub struct Expression10ListGroupStar {
     pub star: Box<Star>,
 }
 
 ///
-/// Type derived for production 321
+/// Type derived for production 362
 ///
-/// Expression11ListGroup: UnaryOperator;
+/// Expression12ListGroup: UnaryOperator;
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct Expression11ListGroupUnaryOperator {
+pub struct Expression12ListGroupUnaryOperator {
     pub unary_operator: Box<UnaryOperator>,
 }
 
 ///
-/// Type derived for production 322
+/// Type derived for production 363
 ///
-/// Expression11ListGroup: Operator09;
+/// Expression12ListGroup: Operator09;
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct Expression11ListGroupOperator09 {
+pub struct Expression12ListGroupOperator09 {
     pub operator09: Box<Operator09>,
 }
 
 ///
-/// Type derived for production 323
+/// Type derived for production 364
 ///
-/// Expression11ListGroup: Operator05;
+/// Expression12ListGroup: Operator05;
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct Expression11ListGroupOperator05 {
+pub struct Expression12ListGroupOperator05 {
     pub operator05: Box<Operator05>,
 }
 
 ///
-/// Type derived for production 324
+/// Type derived for production 365
 ///
-/// Expression11ListGroup: Operator03;
+/// Expression12ListGroup: Operator03;
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct Expression11ListGroupOperator03 {
+pub struct Expression12ListGroupOperator03 {
     pub operator03: Box<Operator03>,
 }
 
 ///
-/// Type derived for production 325
+/// Type derived for production 366
 ///
-/// Expression11ListGroup: Operator04;
+/// Expression12ListGroup: Operator04;
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct Expression11ListGroupOperator04 {
+pub struct Expression12ListGroupOperator04 {
     pub operator04: Box<Operator04>,
 }
 
 ///
-/// Type derived for production 327
+/// Type derived for production 370
 ///
 /// Factor: Number;
 ///
@@ -1932,21 +2256,44 @@ pub struct FactorNumber {
 }
 
 ///
-/// Type derived for production 328
+/// Type derived for production 371
 ///
-/// Factor: FactorOpt /* Option */ ScopedOrHierIdentifier FactorOpt0 /* Option */;
+/// Factor: StringLiteral;
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct FactorFactorOptScopedOrHierIdentifierFactorOpt0 {
-    pub factor_opt: Option<Box<FactorOpt>>,
+pub struct FactorStringLiteral {
+    pub string_literal: Box<StringLiteral>,
+}
+
+///
+/// Type derived for production 372
+///
+/// Factor: ScopedOrHierIdentifier FactorOpt /* Option */;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct FactorScopedOrHierIdentifierFactorOpt {
     pub scoped_or_hier_identifier: Box<ScopedOrHierIdentifier>,
-    pub factor_opt0: Option<Box<FactorOpt0>>,
+    pub factor_opt: Option<Box<FactorOpt>>,
+}
+
+///
+/// Type derived for production 373
+///
+/// Factor: SystemFunctionCall;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct FactorSystemFunctionCall {
+    pub system_function_call: Box<SystemFunctionCall>,
 }
 
 ///
-/// Type derived for production 329
+/// Type derived for production 374
 ///
 /// Factor: LParen Expression RParen;
 ///
@@ -1960,7 +2307,7 @@ pub struct FactorLParenExpressionRParen {
 }
 
 ///
-/// Type derived for production 330
+/// Type derived for production 375
 ///
 /// Factor: LBrace ConcatenationList RBrace;
 ///
@@ -1974,7 +2321,7 @@ pub struct FactorLBraceConcatenationListRBrace {
 }
 
 ///
-/// Type derived for production 353
+/// Type derived for production 404
 ///
 /// RangeOperator: Colon;
 ///
@@ -1986,7 +2333,7 @@ pub struct RangeOperatorColon {
 }
 
 ///
-/// Type derived for production 354
+/// Type derived for production 405
 ///
 /// RangeOperator: PlusColon;
 ///
@@ -1998,7 +2345,7 @@ pub struct RangeOperatorPlusColon {
 }
 
 ///
-/// Type derived for production 355
+/// Type derived for production 406
 ///
 /// RangeOperator: MinusColon;
 ///
@@ -2010,7 +2357,7 @@ pub struct RangeOperatorMinusColon {
 }
 
 ///
-/// Type derived for production 356
+/// Type derived for production 407
 ///
 /// RangeOperator: Step;
 ///
@@ -2022,7 +2369,7 @@ pub struct RangeOperatorStep {
 }
 
 ///
-/// Type derived for production 358
+/// Type derived for production 409
 ///
 /// BuiltinType: Logic;
 ///
@@ -2034,7 +2381,7 @@ pub struct BuiltinTypeLogic {
 }
 
 ///
-/// Type derived for production 359
+/// Type derived for production 410
 ///
 /// BuiltinType: Bit;
 ///
@@ -2046,7 +2393,7 @@ pub struct BuiltinTypeBit {
 }
 
 ///
-/// Type derived for production 360
+/// Type derived for production 411
 ///
 /// BuiltinType: U32;
 ///
@@ -2058,7 +2405,7 @@ pub struct BuiltinTypeU32 {
 }
 
 ///
-/// Type derived for production 361
+/// Type derived for production 412
 ///
 /// BuiltinType: U64;
 ///
@@ -2070,7 +2417,7 @@ pub struct BuiltinTypeU64 {
 }
 
 ///
-/// Type derived for production 362
+/// Type derived for production 413
 ///
 /// BuiltinType: I32;
 ///
@@ -2082,7 +2429,7 @@ pub struct BuiltinTypeI32 {
 }
 
 ///
-/// Type derived for production 363
+/// Type derived for production 414
 ///
 /// BuiltinType: I64;
 ///
@@ -2094,7 +2441,7 @@ pub struct BuiltinTypeI64 {
 }
 
 ///
-/// Type derived for production 364
+/// Type derived for production 415
 ///
 /// BuiltinType: F32;
 ///
@@ -2106,7 +2453,7 @@ pub struct BuiltinTypeF32 {
 }
 
 ///
-/// Type derived for production 365
+/// Type derived for production 416
 ///
 /// BuiltinType: F64;
 ///
@@ -2118,7 +2465,31 @@ pub struct BuiltinTypeF64 {
 }
 
 ///
-/// Type derived for production 368
+/// Type derived for production 417
+///
+/// TypeModifier: Tri;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TypeModifierTri {
+    pub tri: Box<Tri>,
+}
+
+///
+/// Type derived for production 418
+///
+/// TypeModifier: Signed;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TypeModifierSigned {
+    pub signed: Box<Signed>,
+}
+
+///
+/// Type derived for production 423
 ///
 /// TypeGroup: BuiltinType;
 ///
@@ -2130,7 +2501,7 @@ pub struct TypeGroupBuiltinType {
 }
 
 ///
-/// Type derived for production 369
+/// Type derived for production 424
 ///
 /// TypeGroup: ScopedIdentifier;
 ///
@@ -2142,7 +2513,7 @@ pub struct TypeGroupScopedIdentifier {
 }
 
 ///
-/// Type derived for production 370
+/// Type derived for production 425
 ///
 /// TypeGroup: ModportIdentifier;
 ///
@@ -2154,7 +2525,7 @@ pub struct TypeGroupModportIdentifier {
 }
 
 ///
-/// Type derived for production 375
+/// Type derived for production 432
 ///
 /// Statement: AssignmentStatement;
 ///
@@ -2166,7 +2537,7 @@ pub struct StatementAssignmentStatement {
 }
 
 ///
-/// Type derived for production 376
+/// Type derived for production 433
 ///
 /// Statement: IfStatement;
 ///
@@ -2178,7 +2549,7 @@ pub struct StatementIfStatement {
 }
 
 ///
-/// Type derived for production 377
+/// Type derived for production 434
 ///
 /// Statement: IfResetStatement;
 ///
@@ -2190,7 +2561,7 @@ pub struct StatementIfResetStatement {
 }
 
 ///
-/// Type derived for production 378
+/// Type derived for production 435
 ///
 /// Statement: ReturnStatement;
 ///
@@ -2202,7 +2573,7 @@ pub struct StatementReturnStatement {
 }
 
 ///
-/// Type derived for production 379
+/// Type derived for production 436
 ///
 /// Statement: ForStatement;
 ///
@@ -2214,7 +2585,7 @@ pub struct StatementForStatement {
 }
 
 ///
-/// Type derived for production 380
+/// Type derived for production 437
 ///
 /// Statement: CaseStatement;
 ///
@@ -2226,7 +2597,31 @@ pub struct StatementCaseStatement {
 }
 
 ///
-/// Type derived for production 382
+/// Type derived for production 438
+///
+/// Statement: AssertionStatement;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct StatementAssertionStatement {
+    pub assertion_statement: Box<AssertionStatement>,
+}
+
+///
+/// Type derived for production 439
+///
+/// Statement: SystemTaskStatement;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct StatementSystemTaskStatement {
+    pub system_task_statement: Box<SystemTaskStatement>,
+}
+
+///
+/// Type derived for production 441
 ///
 /// AssignmentStatementGroup: Equ;
 ///
@@ -2238,7 +2633,7 @@ pub struct AssignmentStatementGroupEqu {
 }
 
 ///
-/// Type derived for production 383
+/// Type derived for production 442
 ///
 /// AssignmentStatementGroup: AssignmentOperator;
 ///
@@ -2250,7 +2645,43 @@ pub struct AssignmentStatementGroupAssignmentOperator {
 }
 
 ///
-/// Type derived for production 416
+/// Type derived for production 475
+///
+/// AssertionKind: Assert;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionKindAssert {
+    pub assert: Box<Assert>,
+}
+
+///
+/// Type derived for production 476
+///
+/// AssertionKind: Assume;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionKindAssume {
+    pub assume: Box<Assume>,
+}
+
+///
+/// Type derived for production 477
+///
+/// AssertionKind: Cover;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionKindCover {
+    pub cover: Box<Cover>,
+}
+
+///
+/// Type derived for production 480
 ///
 /// CaseItemGroup0: Statement;
 ///
@@ -2262,7 +2693,7 @@ pub struct CaseItemGroup0Statement {
 }
 
 ///
-/// Type derived for production 417
+/// Type derived for production 481
 ///
 /// CaseItemGroup0: LBrace CaseItemGroup0List /* Vec */ RBrace;
 ///
@@ -2276,7 +2707,7 @@ pub struct CaseItemGroup0LBraceCaseItemGroup0ListRBrace {
 }
 
 ///
-/// Type derived for production 420
+/// Type derived for production 484
 ///
 /// CaseItemGroup: Expression;
 ///
@@ -2288,7 +2719,7 @@ pub struct CaseItemGroupExpression {
 }
 
 ///
-/// Type derived for production 421
+/// Type derived for production 485
 ///
 /// CaseItemGroup: Defaul;
 ///
@@ -2300,7 +2731,31 @@ pub struct CaseItemGroupDefaul {
 }
 
 ///
-/// Type derived for production 433
+/// Type derived for production 494
+///
+/// AttributeItem: Identifier;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeItemIdentifier {
+    pub identifier: Box<Identifier>,
+}
+
+///
+/// Type derived for production 495
+///
+/// AttributeItem: Number;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeItemNumber {
+    pub number: Box<Number>,
+}
+
+///
+/// Type derived for production 508
 ///
 /// AlwaysFfClockOptGroup: Posedge;
 ///
@@ -2312,7 +2767,7 @@ pub struct AlwaysFfClockOptGroupPosedge {
 }
 
 ///
-/// Type derived for production 434
+/// Type derived for production 509
 ///
 /// AlwaysFfClockOptGroup: Negedge;
 ///
@@ -2324,7 +2779,7 @@ pub struct AlwaysFfClockOptGroupNegedge {
 }
 
 ///
-/// Type derived for production 438
+/// Type derived for production 513
 ///
 /// AlwaysFfResetOptGroup: AsyncLow;
 ///
@@ -2336,7 +2791,7 @@ pub struct AlwaysFfResetOptGroupAsyncLow {
 }
 
 ///
-/// Type derived for production 439
+/// Type derived for production 514
 ///
 /// AlwaysFfResetOptGroup: AsyncHigh;
 ///
@@ -2348,7 +2803,7 @@ pub struct AlwaysFfResetOptGroupAsyncHigh {
 }
 
 ///
-/// Type derived for production 440
+/// Type derived for production 515
 ///
 /// AlwaysFfResetOptGroup: SyncLow;
 ///
@@ -2360,7 +2815,7 @@ pub struct AlwaysFfResetOptGroupSyncLow {
 }
 
 ///
-/// Type derived for production 441
+/// Type derived for production 516
 ///
 /// AlwaysFfResetOptGroup: SyncHigh;
 ///
@@ -2372,7 +2827,55 @@ pub struct AlwaysFfResetOptGroupSyncHigh {
 }
 
 ///
-/// Type derived for production 507
+/// Type derived for production 547
+///
+/// StructUnion: Struct;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct StructUnionStruct {
+    pub r#struct: Box<Struct>,
+}
+
+///
+/// Type derived for production 548
+///
+/// StructUnion: Union;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct StructUnionUnion {
+    pub r#union: Box<Union>,
+}
+
+///
+/// Type derived for production 595
+///
+/// WithParameterItemGroup0: Typ;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct WithParameterItemGroup0Typ {
+    pub typ: Box<Typ>,
+}
+
+///
+/// Type derived for production 596
+///
+/// WithParameterItemGroup0: Type;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct WithParameterItemGroup0Type {
+    pub r#type: Box<Type>,
+}
+
+///
+/// Type derived for production 597
 ///
 /// WithParameterItemGroup: Parameter;
 ///
@@ -2384,7 +2887,7 @@ pub struct WithParameterItemGroupParameter {
 }
 
 ///
-/// Type derived for production 508
+/// Type derived for production 598
 ///
 /// WithParameterItemGroup: Localparam;
 ///
@@ -2396,7 +2899,31 @@ pub struct WithParameterItemGroupLocalparam {
 }
 
 ///
-/// Type derived for production 518
+/// Type derived for production 599
+///
+/// ParameterValue: Expression;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ParameterValueExpression {
+    pub expression: Box<Expression>,
+}
+
+///
+/// Type derived for production 600
+///
+/// ParameterValue: TypeValue;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ParameterValueTypeValue {
+    pub type_value: Box<TypeValue>,
+}
+
+///
+/// Type derived for production 613
 ///
 /// PortDeclarationItemGroup: Direction Type;
 ///
@@ -2409,7 +2936,7 @@ pub struct PortDeclarationItemGroupDirectionType {
 }
 
 ///
-/// Type derived for production 519
+/// Type derived for production 614
 ///
 /// PortDeclarationItemGroup: Interface;
 ///
@@ -2421,7 +2948,7 @@ pub struct PortDeclarationItemGroupInterface {
 }
 
 ///
-/// Type derived for production 520
+/// Type derived for production 617
 ///
 /// Direction: Input;
 ///
@@ -2433,7 +2960,7 @@ pub struct DirectionInput {
 }
 
 ///
-/// Type derived for production 521
+/// Type derived for production 618
 ///
 /// Direction: Output;
 ///
@@ -2445,7 +2972,7 @@ pub struct DirectionOutput {
 }
 
 ///
-/// Type derived for production 522
+/// Type derived for production 619
 ///
 /// Direction: Inout;
 ///
@@ -2457,7 +2984,7 @@ pub struct DirectionInout {
 }
 
 ///
-/// Type derived for production 523
+/// Type derived for production 620
 ///
 /// Direction: Ref;
 ///
@@ -2469,7 +2996,7 @@ pub struct DirectionRef {
 }
 
 ///
-/// Type derived for production 524
+/// Type derived for production 621
 ///
 /// Direction: Modport;
 ///
@@ -2481,7 +3008,7 @@ pub struct DirectionModport {
 }
 
 ///
-/// Type derived for production 532
+/// Type derived for production 629
 ///
 /// FunctionItem: VarDeclaration;
 ///
@@ -2493,7 +3020,7 @@ pub struct FunctionItemVarDeclaration {
 }
 
 ///
-/// Type derived for production 533
+/// Type derived for production 630
 ///
 /// FunctionItem: Statement;
 ///
@@ -2505,7 +3032,7 @@ pub struct FunctionItemStatement {
 }
 
 ///
-/// Type derived for production 535
+/// Type derived for production 632
 ///
 /// ImportDeclarationGroup: Identifier;
 ///
@@ -2517,7 +3044,7 @@ pub struct ImportDeclarationGroupIdentifier {
 }
 
 ///
-/// Type derived for production 536
+/// Type derived for production 633
 ///
 /// ImportDeclarationGroup: Star;
 ///
@@ -2529,7 +3056,7 @@ pub struct ImportDeclarationGroupStar {
 }
 
 ///
-/// Type derived for production 538
+/// Type derived for production 635
 ///
 /// ExportDeclarationGroup0: Identifier;
 ///
@@ -2541,7 +3068,7 @@ pub struct ExportDeclarationGroup0Identifier {
 }
 
 ///
-/// Type derived for production 539
+/// Type derived for production 636
 ///
 /// ExportDeclarationGroup0: Star;
 ///
@@ -2553,7 +3080,7 @@ pub struct ExportDeclarationGroup0Star {
 }
 
 ///
-/// Type derived for production 540
+/// Type derived for production 637
 ///
 /// ExportDeclarationGroup: Identifier;
 ///
@@ -2565,7 +3092,7 @@ pub struct ExportDeclarationGroupIdentifier {
 }
 
 ///
-/// Type derived for production 541
+/// Type derived for production 638
 ///
 /// ExportDeclarationGroup: Star;
 ///
@@ -2577,7 +3104,7 @@ pub struct ExportDeclarationGroupStar {
 }
 
 ///
-/// Type derived for production 565
+/// Type derived for production 668
 ///
 /// ModuleItem: VarDeclaration;
 ///
@@ -2589,7 +3116,7 @@ pub struct ModuleItemVarDeclaration {
 }
 
 ///
-/// Type derived for production 566
+/// Type derived for production 669
 ///
 /// ModuleItem: InstDeclaration;
 ///
@@ -2601,7 +3128,7 @@ pub struct ModuleItemInstDeclaration {
 }
 
 ///
-/// Type derived for production 567
+/// Type derived for production 670
 ///
 /// ModuleItem: LocalparamDeclaration;
 ///
@@ -2613,7 +3140,7 @@ pub struct ModuleItemLocalparamDeclaration {
 }
 
 ///
-/// Type derived for production 568
+/// Type derived for production 671
 ///
 /// ModuleItem: AlwaysFfDeclaration;
 ///
@@ -2625,7 +3152,7 @@ pub struct ModuleItemAlwaysFfDeclaration {
 }
 
 ///
-/// Type derived for production 569
+/// Type derived for production 672
 ///
 /// ModuleItem: AlwaysCombDeclaration;
 ///
@@ -2637,7 +3164,7 @@ pub struct ModuleItemAlwaysCombDeclaration {
 }
 
 ///
-/// Type derived for production 570
+/// Type derived for production 673
 ///
 /// ModuleItem: AssignDeclaration;
 ///
@@ -2649,7 +3176,43 @@ pub struct ModuleItemAssignDeclaration {
 }
 
 ///
-/// Type derived for production 571
+/// Type derived for production 674
+///
+/// ModuleItem: InitialDeclaration;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ModuleItemInitialDeclaration {
+    pub initial_declaration: Box<InitialDeclaration>,
+}
+
+///
+/// Type derived for production 675
+///
+/// ModuleItem: FinalDeclaration;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ModuleItemFinalDeclaration {
+    pub final_declaration: Box<FinalDeclaration>,
+}
+
+///
+/// Type derived for production 676
+///
+/// ModuleItem: AssertionDeclaration;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ModuleItemAssertionDeclaration {
+    pub assertion_declaration: Box<AssertionDeclaration>,
+}
+
+///
+/// Type derived for production 677
 ///
 /// ModuleItem: FunctionDeclaration;
 ///
@@ -2661,7 +3224,7 @@ pub struct ModuleItemFunctionDeclaration {
 }
 
 ///
-/// Type derived for production 572
+/// Type derived for production 678
 ///
 /// ModuleItem: ModuleIfDeclaration;
 ///
@@ -2673,7 +3236,7 @@ pub struct ModuleItemModuleIfDeclaration {
 }
 
 ///
-/// Type derived for production 573
+/// Type derived for production 679
 ///
 /// ModuleItem: ModuleForDeclaration;
 ///
@@ -2685,7 +3248,7 @@ pub struct ModuleItemModuleForDeclaration {
 }
 
 ///
-/// Type derived for production 574
+/// Type derived for production 680
 ///
 /// ModuleItem: EnumDeclaration;
 ///
@@ -2697,7 +3260,7 @@ pub struct ModuleItemEnumDeclaration {
 }
 
 ///
-/// Type derived for production 575
+/// Type derived for production 681
 ///
 /// ModuleItem: StructDeclaration;
 ///
@@ -2709,7 +3272,19 @@ pub struct ModuleItemStructDeclaration {
 }
 
 ///
-/// Type derived for production 576
+/// Type derived for production 682
+///
+/// ModuleItem: TypeDeclaration;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ModuleItemTypeDeclaration {
+    pub type_declaration: Box<TypeDeclaration>,
+}
+
+///
+/// Type derived for production 683
 ///
 /// ModuleItem: ModuleNamedBlock;
 ///
@@ -2721,7 +3296,7 @@ pub struct ModuleItemModuleNamedBlock {
 }
 
 ///
-/// Type derived for production 577
+/// Type derived for production 684
 ///
 /// ModuleItem: ImportDeclaration;
 ///
@@ -2733,7 +3308,7 @@ pub struct ModuleItemImportDeclaration {
 }
 
 ///
-/// Type derived for production 599
+/// Type derived for production 706
 ///
 /// InterfaceItem: VarDeclaration;
 ///
@@ -2745,7 +3320,7 @@ pub struct InterfaceItemVarDeclaration {
 }
 
 ///
-/// Type derived for production 600
+/// Type derived for production 707
 ///
 /// InterfaceItem: LocalparamDeclaration;
 ///
@@ -2757,7 +3332,7 @@ pub struct InterfaceItemLocalparamDeclaration {
 }
 
 ///
-/// Type derived for production 601
+/// Type derived for production 708
 ///
 /// InterfaceItem: ModportDeclaration;
 ///
@@ -2769,7 +3344,7 @@ pub struct InterfaceItemModportDeclaration {
 }
 
 ///
-/// Type derived for production 602
+/// Type derived for production 709
 ///
 /// InterfaceItem: InterfaceIfDeclaration;
 ///
@@ -2781,7 +3356,7 @@ pub struct InterfaceItemInterfaceIfDeclaration {
 }
 
 ///
-/// Type derived for production 603
+/// Type derived for production 710
 ///
 /// InterfaceItem: InterfaceForDeclaration;
 ///
@@ -2793,7 +3368,7 @@ pub struct InterfaceItemInterfaceForDeclaration {
 }
 
 ///
-/// Type derived for production 604
+/// Type derived for production 711
 ///
 /// InterfaceItem: EnumDeclaration;
 ///
@@ -2805,7 +3380,7 @@ pub struct InterfaceItemEnumDeclaration {
 }
 
 ///
-/// Type derived for production 605
+/// Type derived for production 712
 ///
 /// InterfaceItem: StructDeclaration;
 ///
@@ -2817,7 +3392,19 @@ pub struct InterfaceItemStructDeclaration {
 }
 
 ///
-/// Type derived for production 606
+/// Type derived for production 713
+///
+/// InterfaceItem: TypeDeclaration;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct InterfaceItemTypeDeclaration {
+    pub type_declaration: Box<TypeDeclaration>,
+}
+
+///
+/// Type derived for production 714
 ///
 /// InterfaceItem: InterfaceNamedBlock;
 ///
@@ -2829,7 +3416,7 @@ pub struct InterfaceItemInterfaceNamedBlock {
 }
 
 ///
-/// Type derived for production 607
+/// Type derived for production 715
 ///
 /// InterfaceItem: FunctionDeclaration;
 ///
@@ -2841,7 +3428,7 @@ pub struct InterfaceItemFunctionDeclaration {
 }
 
 ///
-/// Type derived for production 608
+/// Type derived for production 716
 ///
 /// InterfaceItem: ImportDeclaration;
 ///
@@ -2853,7 +3440,7 @@ pub struct InterfaceItemImportDeclaration {
 }
 
 ///
-/// Type derived for production 612
+/// Type derived for production 720
 ///
 /// PackageItem: VarDeclaration;
 ///
@@ -2865,7 +3452,7 @@ pub struct PackageItemVarDeclaration {
 }
 
 ///
-/// Type derived for production 613
+/// Type derived for production 721
 ///
 /// PackageItem: LocalparamDeclaration;
 ///
@@ -2877,7 +3464,7 @@ pub struct PackageItemLocalparamDeclaration {
 }
 
 ///
-/// Type derived for production 614
+/// Type derived for production 722
 ///
 /// PackageItem: EnumDeclaration;
 ///
@@ -2889,7 +3476,7 @@ pub struct PackageItemEnumDeclaration {
 }
 
 ///
-/// Type derived for production 615
+/// Type derived for production 723
 ///
 /// PackageItem: StructDeclaration;
 ///
@@ -2901,7 +3488,19 @@ pub struct PackageItemStructDeclaration {
 }
 
 ///
-/// Type derived for production 616
+/// Type derived for production 724
+///
+/// PackageItem: TypeDeclaration;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct PackageItemTypeDeclaration {
+    pub type_declaration: Box<TypeDeclaration>,
+}
+
+///
+/// Type derived for production 725
 ///
 /// PackageItem: FunctionDeclaration;
 ///
@@ -2913,7 +3512,7 @@ pub struct PackageItemFunctionDeclaration {
 }
 
 ///
-/// Type derived for production 617
+/// Type derived for production 726
 ///
 /// PackageItem: ImportDeclaration;
 ///
@@ -2925,7 +3524,7 @@ pub struct PackageItemImportDeclaration {
 }
 
 ///
-/// Type derived for production 618
+/// Type derived for production 727
 ///
 /// PackageItem: ExportDeclaration;
 ///
@@ -2937,7 +3536,43 @@ pub struct PackageItemExportDeclaration {
 }
 
 ///
-/// Type derived for production 619
+/// Type derived for production 731
+///
+/// TestItem: AssignmentStatement;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TestItemAssignmentStatement {
+    pub assignment_statement: Box<AssignmentStatement>,
+}
+
+///
+/// Type derived for production 732
+///
+/// TestItem: StepStatement;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TestItemStepStatement {
+    pub step_statement: Box<StepStatement>,
+}
+
+///
+/// Type derived for production 733
+///
+/// TestItem: ExpectStatement;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TestItemExpectStatement {
+    pub expect_statement: Box<ExpectStatement>,
+}
+
+///
+/// Type derived for production 738
 ///
 /// Description: ModuleDeclaration;
 ///
@@ -2949,7 +3584,7 @@ pub struct DescriptionModuleDeclaration {
 }
 
 ///
-/// Type derived for production 620
+/// Type derived for production 739
 ///
 /// Description: InterfaceDeclaration;
 ///
@@ -2961,7 +3596,7 @@ pub struct DescriptionInterfaceDeclaration {
 }
 
 ///
-/// Type derived for production 621
+/// Type derived for production 740
 ///
 /// Description: PackageDeclaration;
 ///
@@ -2973,7 +3608,7 @@ pub struct DescriptionPackageDeclaration {
 }
 
 ///
-/// Type derived for production 622
+/// Type derived for production 741
 ///
 /// Description: ImportDeclaration;
 ///
@@ -2984,6 +3619,18 @@ pub struct DescriptionImportDeclaration {
     pub import_declaration: Box<ImportDeclaration>,
 }
 
+///
+/// Type derived for production 742
+///
+/// Description: TestDeclaration;
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DescriptionTestDeclaration {
+    pub test_declaration: Box<TestDeclaration>,
+}
+
 // -------------------------------------------------------------------------------------------------
 //
 // Types of non-terminals deduced from the structure of the transformed grammar
@@ -3208,37 +3855,171 @@ pub struct AlwaysFfToken {
 }
 
 ///
-/// Type derived for non-terminal Assign
+/// Type derived for non-terminal Array
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct Assign {
-    pub assign_token: crate::veryl_token::VerylToken,
+pub struct Array {
+    pub unpacked: Box<Unpacked>,
+    pub width: Box<Width>,
+    pub array_list: Vec<ArrayList>,
 }
 
 ///
-/// Type derived for non-terminal AssignDeclaration
+/// Type derived for non-terminal ArrayList
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct AssignDeclaration {
-    pub assign: Box<Assign>,
-    pub hierarchical_identifier: Box<HierarchicalIdentifier>,
-    pub equ: Box<Equ>,
-    pub expression: Box<Expression>,
-    pub semicolon: Box<Semicolon>,
+pub struct ArrayList {
+    pub width: Box<Width>,
 }
 
 ///
-/// Type derived for non-terminal AssignTerm
+/// Type derived for non-terminal As
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct AssignTerm {
-    pub assign_term: crate::veryl_token::Token, /* \bassign\b */
+pub struct As {
+    pub as_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal AsTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AsTerm {
+    pub as_term: crate::veryl_token::Token, /* \bas\b */
+}
+
+///
+/// Type derived for non-terminal AsToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AsToken {
+    pub as_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
+///
+/// Type derived for non-terminal Assert
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Assert {
+    pub assert_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal AssertTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertTerm {
+    pub assert_term: crate::veryl_token::Token, /* \bassert\b */
+}
+
+///
+/// Type derived for non-terminal AssertToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertToken {
+    pub assert_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
+///
+/// Type derived for non-terminal AssertionDeclaration
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionDeclaration {
+    pub assertion_kind: Box<AssertionKind>,
+    pub l_paren: Box<LParen>,
+    pub always_ff_clock: Box<AlwaysFfClock>,
+    pub assertion_declaration_opt: Option<Box<AssertionDeclarationOpt>>,
+    pub r_paren: Box<RParen>,
+    pub expression: Box<Expression>,
+    pub semicolon: Box<Semicolon>,
+}
+
+///
+/// Type derived for non-terminal AssertionDeclarationOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionDeclarationOpt {
+    pub comma: Box<Comma>,
+    pub always_ff_reset: Box<AlwaysFfReset>,
+}
+
+///
+/// Type derived for non-terminal AssertionKind
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum AssertionKind {
+    Assert(AssertionKindAssert),
+    Assume(AssertionKindAssume),
+    Cover(AssertionKindCover),
+}
+
+///
+/// Type derived for non-terminal AssertionStatement
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionStatement {
+    pub assertion_kind: Box<AssertionKind>,
+    pub expression: Box<Expression>,
+    pub semicolon: Box<Semicolon>,
+}
+
+///
+/// Type derived for non-terminal Assign
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Assign {
+    pub assign_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal AssignDeclaration
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssignDeclaration {
+    pub assign: Box<Assign>,
+    pub hierarchical_identifier: Box<HierarchicalIdentifier>,
+    pub equ: Box<Equ>,
+    pub expression: Box<Expression>,
+    pub semicolon: Box<Semicolon>,
+}
+
+///
+/// Type derived for non-terminal AssignTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssignTerm {
+    pub assign_term: crate::veryl_token::Token, /* \bassign\b */
 }
 
 ///
@@ -3306,6 +4087,37 @@ pub enum AssignmentStatementGroup {
     AssignmentOperator(AssignmentStatementGroupAssignmentOperator),
 }
 
+///
+/// Type derived for non-terminal Assume
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Assume {
+    pub assume_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal AssumeTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssumeTerm {
+    pub assume_term: crate::veryl_token::Token, /* \bassume\b */
+}
+
+///
+/// Type derived for non-terminal AssumeToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssumeToken {
+    pub assume_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal AsyncHigh
 ///
@@ -3368,6 +4180,75 @@ pub struct AsyncLowToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal Attribute
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Attribute {
+    pub hash: Box<Hash>,
+    pub l_bracket: Box<LBracket>,
+    pub identifier: Box<Identifier>,
+    pub attribute_opt: Option<Box<AttributeOpt>>,
+    pub r_bracket: Box<RBracket>,
+}
+
+///
+/// Type derived for non-terminal AttributeItem
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum AttributeItem {
+    Identifier(AttributeItemIdentifier),
+    Number(AttributeItemNumber),
+}
+
+///
+/// Type derived for non-terminal AttributeList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeList {
+    pub attribute_item: Box<AttributeItem>,
+    pub attribute_list_list: Vec<AttributeListList>,
+    pub attribute_list_opt: Option<Box<AttributeListOpt>>,
+}
+
+///
+/// Type derived for non-terminal AttributeListList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeListList {
+    pub comma: Box<Comma>,
+    pub attribute_item: Box<AttributeItem>,
+}
+
+///
+/// Type derived for non-terminal AttributeListOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeListOpt {
+    pub comma: Box<Comma>,
+}
+
+///
+/// Type derived for non-terminal AttributeOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeOpt {
+    pub l_paren: Box<LParen>,
+    pub attribute_list: Box<AttributeList>,
+    pub r_paren: Box<RParen>,
+}
+
 ///
 /// Type derived for non-terminal BaseLess
 ///
@@ -3752,6 +4633,37 @@ pub struct ConcatenationListOpt {
     pub comma: Box<Comma>,
 }
 
+///
+/// Type derived for non-terminal Cover
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Cover {
+    pub cover_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal CoverTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct CoverTerm {
+    pub cover_term: crate::veryl_token::Token, /* \bcover\b */
+}
+
+///
+/// Type derived for non-terminal CoverToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct CoverToken {
+    pub cover_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal Defaul
 ///
@@ -3793,6 +4705,7 @@ pub enum Description {
     InterfaceDeclaration(DescriptionInterfaceDeclaration),
     PackageDeclaration(DescriptionPackageDeclaration),
     ImportDeclaration(DescriptionImportDeclaration),
+    TestDeclaration(DescriptionTestDeclaration),
 }
 
 ///
@@ -3809,33 +4722,33 @@ pub enum Direction {
 }
 
 ///
-/// Type derived for non-terminal Dollar
+/// Type derived for non-terminal DollarIdentifier
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct Dollar {
-    pub dollar_token: crate::veryl_token::VerylToken,
+pub struct DollarIdentifier {
+    pub dollar_identifier_token: crate::veryl_token::VerylToken,
 }
 
 ///
-/// Type derived for non-terminal DollarTerm
+/// Type derived for non-terminal DollarIdentifierTerm
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct DollarTerm {
-    pub dollar_term: crate::veryl_token::Token, /* $ */
+pub struct DollarIdentifierTerm {
+    pub dollar_identifier_term: crate::veryl_token::Token, /* \$[a-zA-Z_][0-9a-zA-Z_$]* */
 }
 
 ///
-/// Type derived for non-terminal DollarToken
+/// Type derived for non-terminal DollarIdentifierToken
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct DollarToken {
-    pub dollar_term: crate::veryl_token::Token,
+pub struct DollarIdentifierToken {
+    pub dollar_identifier_term: crate::veryl_token::Token,
     pub comments: Box<Comments>,
 }
 
@@ -4065,6 +4978,49 @@ pub struct EquToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal Expect
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Expect {
+    pub expect_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal ExpectStatement
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ExpectStatement {
+    pub expect: Box<Expect>,
+    pub expression: Box<Expression>,
+    pub semicolon: Box<Semicolon>,
+}
+
+///
+/// Type derived for non-terminal ExpectTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ExpectTerm {
+    pub expect_term: crate::veryl_token::Token, /* \bexpect\b */
+}
+
+///
+/// Type derived for non-terminal ExpectToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ExpectToken {
+    pub expect_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal Exponent
 ///
@@ -4165,11 +5121,10 @@ pub struct ExportToken {
 /// Type derived for non-terminal Expression
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
-#[builder(crate = "parol_runtime::derive_builder")]
-pub struct Expression {
-    pub expression01: Box<Expression01>,
-    pub expression_list: Vec<ExpressionList>,
+#[derive(Debug, Clone)]
+pub enum Expression {
+    IfExpression(ExpressionIfExpression),
+    Expression01(ExpressionExpression01),
 }
 
 ///
@@ -4190,7 +5145,7 @@ pub struct Expression01 {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression01List {
-    pub operator02: Box<Operator02>,
+    pub operator01: Box<Operator01>,
     pub expression02: Box<Expression02>,
 }
 
@@ -4212,7 +5167,7 @@ pub struct Expression02 {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression02List {
-    pub operator03: Box<Operator03>,
+    pub operator02: Box<Operator02>,
     pub expression03: Box<Expression03>,
 }
 
@@ -4234,7 +5189,7 @@ pub struct Expression03 {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression03List {
-    pub operator04: Box<Operator04>,
+    pub operator03: Box<Operator03>,
     pub expression04: Box<Expression04>,
 }
 
@@ -4256,7 +5211,7 @@ pub struct Expression04 {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression04List {
-    pub operator05: Box<Operator05>,
+    pub operator04: Box<Operator04>,
     pub expression05: Box<Expression05>,
 }
 
@@ -4278,7 +5233,7 @@ pub struct Expression05 {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression05List {
-    pub operator06: Box<Operator06>,
+    pub operator05: Box<Operator05>,
     pub expression06: Box<Expression06>,
 }
 
@@ -4300,7 +5255,7 @@ pub struct Expression06 {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression06List {
-    pub operator07: Box<Operator07>,
+    pub operator06: Box<Operator06>,
     pub expression07: Box<Expression07>,
 }
 
@@ -4322,7 +5277,7 @@ pub struct Expression07 {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression07List {
-    pub operator08: Box<Operator08>,
+    pub operator07: Box<Operator07>,
     pub expression08: Box<Expression08>,
 }
 
@@ -4344,7 +5299,7 @@ pub struct Expression08 {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression08List {
-    pub operator09: Box<Operator09>,
+    pub operator08: Box<Operator08>,
     pub expression09: Box<Expression09>,
 }
 
@@ -4366,22 +5321,12 @@ pub struct Expression09 {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression09List {
-    pub expression09_list_group: Box<Expression09ListGroup>,
+    pub operator09: Box<Operator09>,
     pub expression10: Box<Expression10>,
 }
 
 ///
-/// Type derived for non-terminal Expression09ListGroup
-///
-#[allow(dead_code)]
-#[derive(Debug, Clone)]
-pub enum Expression09ListGroup {
-    Operator10(Expression09ListGroupOperator10),
-    Star(Expression09ListGroupStar),
-}
-
-///
-/// Type derived for non-terminal Expression10
+/// Type derived for non-terminal Expression10
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
@@ -4398,10 +5343,20 @@ pub struct Expression10 {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression10List {
-    pub operator11: Box<Operator11>,
+    pub expression10_list_group: Box<Expression10ListGroup>,
     pub expression11: Box<Expression11>,
 }
 
+///
+/// Type derived for non-terminal Expression10ListGroup
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Expression10ListGroup {
+    Operator10(Expression10ListGroupOperator10),
+    Star(Expression10ListGroupStar),
+}
+
 ///
 /// Type derived for non-terminal Expression11
 ///
@@ -4409,8 +5364,8 @@ pub struct Expression10List {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression11 {
+    pub expression12: Box<Expression12>,
     pub expression11_list: Vec<Expression11List>,
-    pub factor: Box<Factor>,
 }
 
 ///
@@ -4420,31 +5375,54 @@ pub struct Expression11 {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression11List {
-    pub expression11_list_group: Box<Expression11ListGroup>,
+    pub operator11: Box<Operator11>,
+    pub expression12: Box<Expression12>,
+}
+
+///
+/// Type derived for non-terminal Expression12
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Expression12 {
+    pub expression12_list: Vec<Expression12List>,
+    pub factor: Box<Factor>,
+    pub expression12_opt: Option<Box<Expression12Opt>>,
+}
+
+///
+/// Type derived for non-terminal Expression12List
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Expression12List {
+    pub expression12_list_group: Box<Expression12ListGroup>,
 }
 
 ///
-/// Type derived for non-terminal Expression11ListGroup
+/// Type derived for non-terminal Expression12ListGroup
 ///
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
-pub enum Expression11ListGroup {
-    UnaryOperator(Expression11ListGroupUnaryOperator),
-    Operator09(Expression11ListGroupOperator09),
-    Operator05(Expression11ListGroupOperator05),
-    Operator03(Expression11ListGroupOperator03),
-    Operator04(Expression11ListGroupOperator04),
+pub enum Expression12ListGroup {
+    UnaryOperator(Expression12ListGroupUnaryOperator),
+    Operator09(Expression12ListGroupOperator09),
+    Operator05(Expression12ListGroupOperator05),
+    Operator03(Expression12ListGroupOperator03),
+    Operator04(Expression12ListGroupOperator04),
 }
 
 ///
-/// Type derived for non-terminal ExpressionList
+/// Type derived for non-terminal Expression12Opt
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct ExpressionList {
-    pub operator01: Box<Operator01>,
-    pub expression01: Box<Expression01>,
+pub struct Expression12Opt {
+    pub r#as: Box<As>,
+    pub r#type: Box<Type>,
 }
 
 ///
@@ -4516,7 +5494,9 @@ pub struct F64Token {
 #[derive(Debug, Clone)]
 pub enum Factor {
     Number(FactorNumber),
-    FactorOptScopedOrHierIdentifierFactorOpt0(FactorFactorOptScopedOrHierIdentifierFactorOpt0),
+    StringLiteral(FactorStringLiteral),
+    ScopedOrHierIdentifierFactorOpt(FactorScopedOrHierIdentifierFactorOpt),
+    SystemFunctionCall(FactorSystemFunctionCall),
     LParenExpressionRParen(FactorLParenExpressionRParen),
     LBraceConcatenationListRBrace(FactorLBraceConcatenationListRBrace),
 }
@@ -4528,7 +5508,9 @@ pub enum Factor {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorOpt {
-    pub dollar: Box<Dollar>,
+    pub l_paren: Box<LParen>,
+    pub factor_opt0: Option<Box<FactorOpt0>>,
+    pub r_paren: Box<RParen>,
 }
 
 ///
@@ -4538,19 +5520,61 @@ pub struct FactorOpt {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorOpt0 {
-    pub l_paren: Box<LParen>,
-    pub factor_opt1: Option<Box<FactorOpt1>>,
-    pub r_paren: Box<RParen>,
+    pub function_call_arg: Box<FunctionCallArg>,
 }
 
 ///
-/// Type derived for non-terminal FactorOpt1
+/// Type derived for non-terminal Final
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct FactorOpt1 {
-    pub function_call_arg: Box<FunctionCallArg>,
+pub struct Final {
+    pub final_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal FinalDeclaration
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct FinalDeclaration {
+    pub r#final: Box<Final>,
+    pub l_brace: Box<LBrace>,
+    pub final_declaration_list: Vec<FinalDeclarationList>,
+    pub r_brace: Box<RBrace>,
+}
+
+///
+/// Type derived for non-terminal FinalDeclarationList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct FinalDeclarationList {
+    pub statement: Box<Statement>,
+}
+
+///
+/// Type derived for non-terminal FinalTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct FinalTerm {
+    pub final_term: crate::veryl_token::Token, /* \bfinal\b */
+}
+
+///
+/// Type derived for non-terminal FinalToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct FinalToken {
+    pub final_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
 }
 
 ///
@@ -4958,6 +5982,40 @@ pub struct If {
     pub if_token: crate::veryl_token::VerylToken,
 }
 
+///
+/// Type derived for non-terminal IfExpression
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct IfExpression {
+    pub r#if: Box<If>,
+    pub expression: Box<Expression>,
+    pub l_brace: Box<LBrace>,
+    pub expression0: Box<Expression>,
+    pub r_brace: Box<RBrace>,
+    pub if_expression_list: Vec<IfExpressionList>,
+    pub r#else: Box<Else>,
+    pub l_brace0: Box<LBrace>,
+    pub expression1: Box<Expression>,
+    pub r_brace0: Box<RBrace>,
+}
+
+///
+/// Type derived for non-terminal IfExpressionList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct IfExpressionList {
+    pub r#else: Box<Else>,
+    pub r#if: Box<If>,
+    pub expression: Box<Expression>,
+    pub l_brace: Box<LBrace>,
+    pub expression0: Box<Expression>,
+    pub r_brace: Box<RBrace>,
+}
+
 ///
 /// Type derived for non-terminal IfReset
 ///
@@ -5243,6 +6301,60 @@ pub struct InToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal Initial
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Initial {
+    pub initial_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal InitialDeclaration
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct InitialDeclaration {
+    pub initial: Box<Initial>,
+    pub l_brace: Box<LBrace>,
+    pub initial_declaration_list: Vec<InitialDeclarationList>,
+    pub r_brace: Box<RBrace>,
+}
+
+///
+/// Type derived for non-terminal InitialDeclarationList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct InitialDeclarationList {
+    pub statement: Box<Statement>,
+}
+
+///
+/// Type derived for non-terminal InitialTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct InitialTerm {
+    pub initial_term: crate::veryl_token::Token, /* \binitial\b */
+}
+
+///
+/// Type derived for non-terminal InitialToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct InitialToken {
+    pub initial_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal Inout
 ///
@@ -5406,7 +6518,7 @@ pub struct InstParameterItem {
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstParameterItemOpt {
     pub colon: Box<Colon>,
-    pub expression: Box<Expression>,
+    pub parameter_value: Box<ParameterValue>,
 }
 
 ///
@@ -5664,6 +6776,7 @@ pub enum InterfaceItem {
     InterfaceForDeclaration(InterfaceItemInterfaceForDeclaration),
     EnumDeclaration(InterfaceItemEnumDeclaration),
     StructDeclaration(InterfaceItemStructDeclaration),
+    TypeDeclaration(InterfaceItemTypeDeclaration),
     InterfaceNamedBlock(InterfaceItemInterfaceNamedBlock),
     FunctionDeclaration(InterfaceItemFunctionDeclaration),
     ImportDeclaration(InterfaceItemImportDeclaration),
@@ -6116,9 +7229,20 @@ pub struct ModuleDeclaration {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModuleDeclarationList {
+    pub module_declaration_list_list: Vec<ModuleDeclarationListList>,
     pub module_item: Box<ModuleItem>,
 }
 
+///
+/// Type derived for non-terminal ModuleDeclarationListList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ModuleDeclarationListList {
+    pub attribute: Box<Attribute>,
+}
+
 ///
 /// Type derived for non-terminal ModuleDeclarationOpt
 ///
@@ -6218,11 +7342,15 @@ pub enum ModuleItem {
     AlwaysFfDeclaration(ModuleItemAlwaysFfDeclaration),
     AlwaysCombDeclaration(ModuleItemAlwaysCombDeclaration),
     AssignDeclaration(ModuleItemAssignDeclaration),
+    InitialDeclaration(ModuleItemInitialDeclaration),
+    FinalDeclaration(ModuleItemFinalDeclaration),
+    AssertionDeclaration(ModuleItemAssertionDeclaration),
     FunctionDeclaration(ModuleItemFunctionDeclaration),
     ModuleIfDeclaration(ModuleItemModuleIfDeclaration),
     ModuleForDeclaration(ModuleItemModuleForDeclaration),
     EnumDeclaration(ModuleItemEnumDeclaration),
     StructDeclaration(ModuleItemStructDeclaration),
+    TypeDeclaration(ModuleItemTypeDeclaration),
     ModuleNamedBlock(ModuleItemModuleNamedBlock),
     ImportDeclaration(ModuleItemImportDeclaration),
 }
@@ -6248,9 +7376,20 @@ pub struct ModuleNamedBlock {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModuleNamedBlockList {
+    pub module_named_block_list_list: Vec<ModuleNamedBlockListList>,
     pub module_item: Box<ModuleItem>,
 }
 
+///
+/// Type derived for non-terminal ModuleNamedBlockListList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ModuleNamedBlockListList {
+    pub attribute: Box<Attribute>,
+}
+
 ///
 /// Type derived for non-terminal ModuleOptionalNamedBlock
 ///
@@ -6271,9 +7410,20 @@ pub struct ModuleOptionalNamedBlock {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModuleOptionalNamedBlockList {
+    pub module_optional_named_block_list_list: Vec<ModuleOptionalNamedBlockListList>,
     pub module_item: Box<ModuleItem>,
 }
 
+///
+/// Type derived for non-terminal ModuleOptionalNamedBlockListList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ModuleOptionalNamedBlockListList {
+    pub attribute: Box<Attribute>,
+}
+
 ///
 /// Type derived for non-terminal ModuleOptionalNamedBlockOpt
 ///
@@ -6763,6 +7913,7 @@ pub enum PackageItem {
     LocalparamDeclaration(PackageItemLocalparamDeclaration),
     EnumDeclaration(PackageItemEnumDeclaration),
     StructDeclaration(PackageItemStructDeclaration),
+    TypeDeclaration(PackageItemTypeDeclaration),
     FunctionDeclaration(PackageItemFunctionDeclaration),
     ImportDeclaration(PackageItemImportDeclaration),
     ExportDeclaration(PackageItemExportDeclaration),
@@ -6820,6 +7971,16 @@ pub struct ParameterToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal ParameterValue
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum ParameterValue {
+    Expression(ParameterValueExpression),
+    TypeValue(ParameterValueTypeValue),
+}
+
 ///
 /// Type derived for non-terminal PlusColon
 ///
@@ -6870,6 +8031,7 @@ pub struct PortDeclaration {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortDeclarationItem {
+    pub port_declaration_item_list: Vec<PortDeclarationItemList>,
     pub identifier: Box<Identifier>,
     pub colon: Box<Colon>,
     pub port_declaration_item_group: Box<PortDeclarationItemGroup>,
@@ -6885,6 +8047,16 @@ pub enum PortDeclarationItemGroup {
     Interface(PortDeclarationItemGroupInterface),
 }
 
+///
+/// Type derived for non-terminal PortDeclarationItemList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct PortDeclarationItemList {
+    pub attribute: Box<Attribute>,
+}
+
 ///
 /// Type derived for non-terminal PortDeclarationList
 ///
@@ -7324,6 +8496,37 @@ pub struct SemicolonToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal Signed
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Signed {
+    pub signed_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal SignedTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct SignedTerm {
+    pub signed_term: crate::veryl_token::Token, /* \bsigned\b */
+}
+
+///
+/// Type derived for non-terminal SignedToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct SignedToken {
+    pub signed_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal Star
 ///
@@ -7387,6 +8590,8 @@ pub enum Statement {
     ReturnStatement(StatementReturnStatement),
     ForStatement(StatementForStatement),
     CaseStatement(StatementCaseStatement),
+    AssertionStatement(StatementAssertionStatement),
+    SystemTaskStatement(StatementSystemTaskStatement),
 }
 
 ///
@@ -7399,6 +8604,30 @@ pub struct Step {
     pub step_token: crate::veryl_token::VerylToken,
 }
 
+///
+/// Type derived for non-terminal StepStatement
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct StepStatement {
+    pub step: Box<Step>,
+    pub hierarchical_identifier: Box<HierarchicalIdentifier>,
+    pub step_statement_opt: Option<Box<StepStatementOpt>>,
+    pub semicolon: Box<Semicolon>,
+}
+
+///
+/// Type derived for non-terminal StepStatementOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct StepStatementOpt {
+    pub comma: Box<Comma>,
+    pub expression: Box<Expression>,
+}
+
 ///
 /// Type derived for non-terminal StepTerm
 ///
@@ -7420,6 +8649,37 @@ pub struct StepToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal StringLiteral
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct StringLiteral {
+    pub string_literal_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal StringLiteralTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct StringLiteralTerm {
+    pub string_literal_term: crate::veryl_token::Token, /* "(?:\\[^\r\n]|[^"\\\r\n])*" */
+}
+
+///
+/// Type derived for non-terminal StringLiteralToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct StringLiteralToken {
+    pub string_literal_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal Struct
 ///
@@ -7437,13 +8697,24 @@ pub struct Struct {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StructDeclaration {
-    pub r#struct: Box<Struct>,
+    pub struct_union: Box<StructUnion>,
+    pub struct_declaration_opt: Option<Box<StructDeclarationOpt>>,
     pub identifier: Box<Identifier>,
     pub l_brace: Box<LBrace>,
     pub struct_list: Box<StructList>,
     pub r_brace: Box<RBrace>,
 }
 
+///
+/// Type derived for non-terminal StructDeclarationOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct StructDeclarationOpt {
+    pub unpacked: Box<Unpacked>,
+}
+
 ///
 /// Type derived for non-terminal StructItem
 ///
@@ -7510,6 +8781,16 @@ pub struct StructToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal StructUnion
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum StructUnion {
+    Struct(StructUnionStruct),
+    Union(StructUnionUnion),
+}
+
 ///
 /// Type derived for non-terminal SyncHigh
 ///
@@ -7573,27 +8854,138 @@ pub struct SyncLowToken {
 }
 
 ///
-/// Type derived for non-terminal Tri
+/// Type derived for non-terminal SystemFunctionCall
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct Tri {
-    pub tri_token: crate::veryl_token::VerylToken,
+pub struct SystemFunctionCall {
+    pub dollar_identifier: Box<DollarIdentifier>,
+    pub system_function_call_opt: Option<Box<SystemFunctionCallOpt>>,
 }
 
 ///
-/// Type derived for non-terminal TriTerm
+/// Type derived for non-terminal SystemFunctionCallOpt
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct TriTerm {
-    pub tri_term: crate::veryl_token::Token, /* \btri\b */
+pub struct SystemFunctionCallOpt {
+    pub l_paren: Box<LParen>,
+    pub system_function_call_opt0: Option<Box<SystemFunctionCallOpt0>>,
+    pub r_paren: Box<RParen>,
 }
 
 ///
-/// Type derived for non-terminal TriToken
+/// Type derived for non-terminal SystemFunctionCallOpt0
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct SystemFunctionCallOpt0 {
+    pub function_call_arg: Box<FunctionCallArg>,
+}
+
+///
+/// Type derived for non-terminal SystemTaskStatement
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct SystemTaskStatement {
+    pub system_function_call: Box<SystemFunctionCall>,
+    pub semicolon: Box<Semicolon>,
+}
+
+///
+/// Type derived for non-terminal Test
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Test {
+    pub test_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal TestDeclaration
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TestDeclaration {
+    pub test: Box<Test>,
+    pub identifier: Box<Identifier>,
+    pub l_brace: Box<LBrace>,
+    pub inst_declaration: Box<InstDeclaration>,
+    pub test_declaration_list: Vec<TestDeclarationList>,
+    pub r_brace: Box<RBrace>,
+}
+
+///
+/// Type derived for non-terminal TestDeclarationList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TestDeclarationList {
+    pub test_item: Box<TestItem>,
+}
+
+///
+/// Type derived for non-terminal TestItem
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum TestItem {
+    AssignmentStatement(TestItemAssignmentStatement),
+    StepStatement(TestItemStepStatement),
+    ExpectStatement(TestItemExpectStatement),
+}
+
+///
+/// Type derived for non-terminal TestTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TestTerm {
+    pub test_term: crate::veryl_token::Token, /* \btest\b */
+}
+
+///
+/// Type derived for non-terminal TestToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TestToken {
+    pub test_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
+///
+/// Type derived for non-terminal Tri
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Tri {
+    pub tri_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal TriTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TriTerm {
+    pub tri_term: crate::veryl_token::Token, /* \btri\b */
+}
+
+///
+/// Type derived for non-terminal TriToken
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
@@ -7603,6 +8995,16 @@ pub struct TriToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal Typ
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Typ {
+    pub type_token: crate::veryl_token::VerylToken,
+}
+
 ///
 /// Type derived for non-terminal Type
 ///
@@ -7610,9 +9012,24 @@ pub struct TriToken {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Type {
-    pub type_opt: Option<Box<TypeOpt>>,
-    pub type_group: Box<TypeGroup>,
     pub type_list: Vec<TypeList>,
+    pub type_group: Box<TypeGroup>,
+    pub type_list0: Vec<TypeList0>,
+    pub type_opt: Option<Box<TypeOpt>>,
+}
+
+///
+/// Type derived for non-terminal TypeDeclaration
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TypeDeclaration {
+    pub typ: Box<Typ>,
+    pub identifier: Box<Identifier>,
+    pub equ: Box<Equ>,
+    pub r#type: Box<Type>,
+    pub semicolon: Box<Semicolon>,
 }
 
 ///
@@ -7633,17 +9050,27 @@ pub enum TypeGroup {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct TypeList {
-    pub width: Box<Width>,
+    pub type_modifier: Box<TypeModifier>,
 }
 
 ///
-/// Type derived for non-terminal TypeModifier
+/// Type derived for non-terminal TypeList0
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct TypeModifier {
-    pub tri: Box<Tri>,
+pub struct TypeList0 {
+    pub width: Box<Width>,
+}
+
+///
+/// Type derived for non-terminal TypeModifier
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum TypeModifier {
+    Tri(TypeModifierTri),
+    Signed(TypeModifierSigned),
 }
 
 ///
@@ -7653,7 +9080,49 @@ pub struct TypeModifier {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct TypeOpt {
-    pub type_modifier: Box<TypeModifier>,
+    pub array: Box<Array>,
+}
+
+///
+/// Type derived for non-terminal TypeTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TypeTerm {
+    pub type_term: crate::veryl_token::Token, /* \btype\b */
+}
+
+///
+/// Type derived for non-terminal TypeToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TypeToken {
+    pub type_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
+///
+/// Type derived for non-terminal TypeValue
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TypeValue {
+    pub builtin_type: Box<BuiltinType>,
+    pub type_value_list: Vec<TypeValueList>,
+}
+
+///
+/// Type derived for non-terminal TypeValueList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TypeValueList {
+    pub width: Box<Width>,
 }
 
 ///
@@ -7749,6 +9218,68 @@ pub struct UnaryOperatorToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal Union
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Union {
+    pub union_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal UnionTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct UnionTerm {
+    pub union_term: crate::veryl_token::Token, /* \bunion\b */
+}
+
+///
+/// Type derived for non-terminal UnionToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct UnionToken {
+    pub union_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
+///
+/// Type derived for non-terminal Unpacked
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Unpacked {
+    pub unpacked_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal UnpackedTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct UnpackedTerm {
+    pub unpacked_term: crate::veryl_token::Token, /* \bunpacked\b */
+}
+
+///
+/// Type derived for non-terminal UnpackedToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct UnpackedToken {
+    pub unpacked_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal Var
 ///
@@ -7824,9 +9355,20 @@ pub struct Veryl {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct VerylList {
+    pub veryl_list_list: Vec<VerylListList>,
     pub description: Box<Description>,
 }
 
+///
+/// Type derived for non-terminal VerylListList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct VerylListList {
+    pub attribute: Box<Attribute>,
+}
+
 ///
 /// Type derived for non-terminal Width
 ///
@@ -7862,9 +9404,9 @@ pub struct WithParameterItem {
     pub with_parameter_item_group: Box<WithParameterItemGroup>,
     pub identifier: Box<Identifier>,
     pub colon: Box<Colon>,
-    pub r#type: Box<Type>,
+    pub with_parameter_item_group0: Box<WithParameterItemGroup0>,
     pub equ: Box<Equ>,
-    pub expression: Box<Expression>,
+    pub parameter_value: Box<ParameterValue>,
 }
 
 ///
@@ -7877,6 +9419,16 @@ pub enum WithParameterItemGroup {
     Localparam(WithParameterItemGroupLocalparam),
 }
 
+///
+/// Type derived for non-terminal WithParameterItemGroup0
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum WithParameterItemGroup0 {
+    Typ(WithParameterItemGroup0Typ),
+    Type(WithParameterItemGroup0Type),
+}
+
 ///
 /// Type derived for non-terminal WithParameterList
 ///
@@ -7948,6 +9500,18 @@ pub enum ASTType {
     AlwaysFfResetOptGroup(AlwaysFfResetOptGroup),
     AlwaysFfTerm(AlwaysFfTerm),
     AlwaysFfToken(AlwaysFfToken),
+    Array(Array),
+    ArrayList(Vec<ArrayList>),
+    As(As),
+    AsTerm(AsTerm),
+    AsToken(AsToken),
+    Assert(Assert),
+    AssertTerm(AssertTerm),
+    AssertToken(AssertToken),
+    AssertionDeclaration(AssertionDeclaration),
+    AssertionDeclarationOpt(Option<Box<AssertionDeclarationOpt>>),
+    AssertionKind(AssertionKind),
+    AssertionStatement(AssertionStatement),
     Assign(Assign),
     AssignDeclaration(AssignDeclaration),
     AssignTerm(AssignTerm),
@@ -7957,12 +9521,21 @@ pub enum ASTType {
     AssignmentOperatorToken(AssignmentOperatorToken),
     AssignmentStatement(AssignmentStatement),
     AssignmentStatementGroup(AssignmentStatementGroup),
+    Assume(Assume),
+    AssumeTerm(AssumeTerm),
+    AssumeToken(AssumeToken),
     AsyncHigh(AsyncHigh),
     AsyncHighTerm(AsyncHighTerm),
     AsyncHighToken(AsyncHighToken),
     AsyncLow(AsyncLow),
     AsyncLowTerm(AsyncLowTerm),
     AsyncLowToken(AsyncLowToken),
+    Attribute(Attribute),
+    AttributeItem(AttributeItem),
+    AttributeList(AttributeList),
+    AttributeListList(Vec<AttributeListList>),
+    AttributeListOpt(Option<Box<AttributeListOpt>>),
+    AttributeOpt(Option<Box<AttributeOpt>>),
     BaseLess(BaseLess),
     BaseLessTerm(BaseLessTerm),
     BaseLessToken(BaseLessToken),
@@ -7999,14 +9572,17 @@ pub enum ASTType {
     ConcatenationList(ConcatenationList),
     ConcatenationListList(Vec<ConcatenationListList>),
     ConcatenationListOpt(Option<Box<ConcatenationListOpt>>),
+    Cover(Cover),
+    CoverTerm(CoverTerm),
+    CoverToken(CoverToken),
     Defaul(Defaul),
     DefaultTerm(DefaultTerm),
     DefaultToken(DefaultToken),
     Description(Description),
     Direction(Direction),
-    Dollar(Dollar),
-    DollarTerm(DollarTerm),
-    DollarToken(DollarToken),
+    DollarIdentifier(DollarIdentifier),
+    DollarIdentifierTerm(DollarIdentifierTerm),
+    DollarIdentifierToken(DollarIdentifierToken),
     Dot(Dot),
     DotDot(DotDot),
     DotDotTerm(DotDotTerm),
@@ -8028,6 +9604,10 @@ pub enum ASTType {
     Equ(Equ),
     EquTerm(EquTerm),
     EquToken(EquToken),
+    Expect(Expect),
+    ExpectStatement(ExpectStatement),
+    ExpectTerm(ExpectTerm),
+    ExpectToken(ExpectToken),
     Exponent(Exponent),
     ExponentTerm(ExponentTerm),
     ExponentToken(ExponentToken),
@@ -8056,13 +9636,15 @@ pub enum ASTType {
     Expression08List(Vec<Expression08List>),
     Expression09(Expression09),
     Expression09List(Vec<Expression09List>),
-    Expression09ListGroup(Expression09ListGroup),
     Expression10(Expression10),
     Expression10List(Vec<Expression10List>),
+    Expression10ListGroup(Expression10ListGroup),
     Expression11(Expression11),
     Expression11List(Vec<Expression11List>),
-    Expression11ListGroup(Expression11ListGroup),
-    ExpressionList(Vec<ExpressionList>),
+    Expression12(Expression12),
+    Expression12List(Vec<Expression12List>),
+    Expression12ListGroup(Expression12ListGroup),
+    Expression12Opt(Option<Box<Expression12Opt>>),
     F32(F32),
     F32Term(F32Term),
     F32Token(F32Token),
@@ -8072,7 +9654,11 @@ pub enum ASTType {
     Factor(Factor),
     FactorOpt(Option<Box<FactorOpt>>),
     FactorOpt0(Option<Box<FactorOpt0>>),
-    FactorOpt1(Option<Box<FactorOpt1>>),
+    Final(Final),
+    FinalDeclaration(FinalDeclaration),
+    FinalDeclarationList(Vec<FinalDeclarationList>),
+    FinalTerm(FinalTerm),
+    FinalToken(FinalToken),
     FixedPoint(FixedPoint),
     FixedPointTerm(FixedPointTerm),
     FixedPointToken(FixedPointToken),
@@ -8110,6 +9696,8 @@ pub enum ASTType {
     IdentifierTerm(IdentifierTerm),
     IdentifierToken(IdentifierToken),
     If(If),
+    IfExpression(IfExpression),
+    IfExpressionList(Vec<IfExpressionList>),
     IfReset(IfReset),
     IfResetStatement(IfResetStatement),
     IfResetStatementList(Vec<IfResetStatementList>),
@@ -8135,6 +9723,11 @@ pub enum ASTType {
     In(In),
     InTerm(InTerm),
     InToken(InToken),
+    Initial(Initial),
+    InitialDeclaration(InitialDeclaration),
+    InitialDeclarationList(Vec<InitialDeclarationList>),
+    InitialTerm(InitialTerm),
+    InitialToken(InitialToken),
     Inout(Inout),
     InoutTerm(InoutTerm),
     InoutToken(InoutToken),
@@ -8213,6 +9806,7 @@ pub enum ASTType {
     Module(Module),
     ModuleDeclaration(ModuleDeclaration),
     ModuleDeclarationList(Vec<ModuleDeclarationList>),
+    ModuleDeclarationListList(Vec<ModuleDeclarationListList>),
     ModuleDeclarationOpt(Option<Box<ModuleDeclarationOpt>>),
     ModuleDeclarationOpt0(Option<Box<ModuleDeclarationOpt0>>),
     ModuleForDeclaration(ModuleForDeclaration),
@@ -8223,8 +9817,10 @@ pub enum ASTType {
     ModuleItem(ModuleItem),
     ModuleNamedBlock(ModuleNamedBlock),
     ModuleNamedBlockList(Vec<ModuleNamedBlockList>),
+    ModuleNamedBlockListList(Vec<ModuleNamedBlockListList>),
     ModuleOptionalNamedBlock(ModuleOptionalNamedBlock),
     ModuleOptionalNamedBlockList(Vec<ModuleOptionalNamedBlockList>),
+    ModuleOptionalNamedBlockListList(Vec<ModuleOptionalNamedBlockListList>),
     ModuleOptionalNamedBlockOpt(Option<Box<ModuleOptionalNamedBlockOpt>>),
     ModuleTerm(ModuleTerm),
     ModuleToken(ModuleToken),
@@ -8277,12 +9873,14 @@ pub enum ASTType {
     Parameter(Parameter),
     ParameterTerm(ParameterTerm),
     ParameterToken(ParameterToken),
+    ParameterValue(ParameterValue),
     PlusColon(PlusColon),
     PlusColonTerm(PlusColonTerm),
     PlusColonToken(PlusColonToken),
     PortDeclaration(PortDeclaration),
     PortDeclarationItem(PortDeclarationItem),
     PortDeclarationItemGroup(PortDeclarationItemGroup),
+    PortDeclarationItemList(Vec<PortDeclarationItemList>),
     PortDeclarationList(PortDeclarationList),
     PortDeclarationListList(Vec<PortDeclarationListList>),
     PortDeclarationListOpt(Option<Box<PortDeclarationListOpt>>),
@@ -8324,6 +9922,9 @@ pub enum ASTType {
     Semicolon(Semicolon),
     SemicolonTerm(SemicolonTerm),
     SemicolonToken(SemicolonToken),
+    Signed(Signed),
+    SignedTerm(SignedTerm),
+    SignedToken(SignedToken),
     Star(Star),
     StarTerm(StarTerm),
     StarToken(StarToken),
@@ -8331,30 +9932,54 @@ pub enum ASTType {
     StartToken(StartToken),
     Statement(Statement),
     Step(Step),
+    StepStatement(StepStatement),
+    StepStatementOpt(Option<Box<StepStatementOpt>>),
     StepTerm(StepTerm),
     StepToken(StepToken),
+    StringLiteral(StringLiteral),
+    StringLiteralTerm(StringLiteralTerm),
+    StringLiteralToken(StringLiteralToken),
     Struct(Struct),
     StructDeclaration(StructDeclaration),
+    StructDeclarationOpt(Option<Box<StructDeclarationOpt>>),
     StructItem(StructItem),
     StructList(StructList),
     StructListList(Vec<StructListList>),
     StructListOpt(Option<Box<StructListOpt>>),
     StructTerm(StructTerm),
     StructToken(StructToken),
+    StructUnion(StructUnion),
     SyncHigh(SyncHigh),
     SyncHighTerm(SyncHighTerm),
     SyncHighToken(SyncHighToken),
     SyncLow(SyncLow),
     SyncLowTerm(SyncLowTerm),
     SyncLowToken(SyncLowToken),
+    SystemFunctionCall(SystemFunctionCall),
+    SystemFunctionCallOpt(Option<Box<SystemFunctionCallOpt>>),
+    SystemFunctionCallOpt0(Option<Box<SystemFunctionCallOpt0>>),
+    SystemTaskStatement(SystemTaskStatement),
+    Test(Test),
+    TestDeclaration(TestDeclaration),
+    TestDeclarationList(Vec<TestDeclarationList>),
+    TestItem(TestItem),
+    TestTerm(TestTerm),
+    TestToken(TestToken),
     Tri(Tri),
     TriTerm(TriTerm),
     TriToken(TriToken),
+    Typ(Typ),
     Type(Type),
+    TypeDeclaration(TypeDeclaration),
     TypeGroup(TypeGroup),
     TypeList(Vec<TypeList>),
+    TypeList0(Vec<TypeList0>),
     TypeModifier(TypeModifier),
     TypeOpt(Option<Box<TypeOpt>>),
+    TypeTerm(TypeTerm),
+    TypeToken(TypeToken),
+    TypeValue(TypeValue),
+    TypeValueList(Vec<TypeValueList>),
     U32(U32),
     U32Term(U32Term),
     U32Token(U32Token),
@@ -8364,6 +9989,12 @@ pub enum ASTType {
     UnaryOperator(UnaryOperator),
     UnaryOperatorTerm(UnaryOperatorTerm),
     UnaryOperatorToken(UnaryOperatorToken),
+    Union(Union),
+    UnionTerm(UnionTerm),
+    UnionToken(UnionToken),
+    Unpacked(Unpacked),
+    UnpackedTerm(UnpackedTerm),
+    UnpackedToken(UnpackedToken),
     Var(Var),
     VarDeclaration(VarDeclaration),
     VarDeclarationOpt(Option<Box<VarDeclarationOpt>>),
@@ -8371,10 +10002,12 @@ pub enum ASTType {
     VarToken(VarToken),
     Veryl(Veryl),
     VerylList(Vec<VerylList>),
+    VerylListList(Vec<VerylListList>),
     Width(Width),
     WithParameter(WithParameter),
     WithParameterItem(WithParameterItem),
     WithParameterItemGroup(WithParameterItemGroup),
+    WithParameterItemGroup0(WithParameterItemGroup0),
     WithParameterList(WithParameterList),
     WithParameterListList(Vec<WithParameterListList>),
     WithParameterListOpt(Option<Box<WithParameterListOpt>>),
@@ -8603,6 +10236,36 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 6:
     ///
+    /// StringLiteralTerm: /"(?:\\[^\r\n]|[^"\\\r\n])*"/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn string_literal_term(
+        &mut self,
+        string_literal_term: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let string_literal_term = string_literal_term
+            .token(parse_tree)?
+            .try_into()
+            .into_diagnostic()?;
+        let string_literal_term_built = StringLiteralTermBuilder::default()
+            .string_literal_term(string_literal_term)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .string_literal_term(&string_literal_term_built)?;
+        self.push(
+            ASTType::StringLiteralTerm(string_literal_term_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 7:
+    ///
     /// MinusColonTerm: '-:' : Token;
     ///
     #[parol_runtime::function_name::named]
@@ -8628,7 +10291,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 7:
+    /// Semantic action for production 8:
     ///
     /// MinusGTTerm: '->' : Token;
     ///
@@ -8654,7 +10317,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 8:
+    /// Semantic action for production 9:
     ///
     /// PlusColonTerm: '+:' : Token;
     ///
@@ -8680,7 +10343,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 9:
+    /// Semantic action for production 10:
     ///
     /// AssignmentOperatorTerm: "\+=|-=|\*=|/=|%=|&=|\|=|\^=|<<=|>>=|<<<=|>>>=" : Token;
     ///
@@ -8710,7 +10373,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 10:
+    /// Semantic action for production 11:
     ///
     /// Operator11Term: "\*\*" : Token;
     ///
@@ -8736,7 +10399,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 11:
+    /// Semantic action for production 12:
     ///
     /// Operator10Term: "/|%" : Token;
     ///
@@ -8762,7 +10425,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 12:
+    /// Semantic action for production 13:
     ///
     /// Operator09Term: "\+|-" : Token;
     ///
@@ -8788,7 +10451,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 13:
+    /// Semantic action for production 14:
     ///
     /// Operator08Term: "<<<|>>>|<<|>>" : Token;
     ///
@@ -8814,7 +10477,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 14:
+    /// Semantic action for production 15:
     ///
     /// Operator07Term: "<=|>=|<|>" : Token;
     ///
@@ -8840,7 +10503,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 15:
+    /// Semantic action for production 16:
     ///
     /// Operator06Term: "===|==\?|!==|!=\?|==|!=" : Token;
     ///
@@ -8866,7 +10529,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 16:
+    /// Semantic action for production 17:
     ///
     /// Operator02Term: "&&" : Token;
     ///
@@ -8892,7 +10555,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 17:
+    /// Semantic action for production 18:
     ///
     /// Operator01Term: "\|\|" : Token;
     ///
@@ -8918,7 +10581,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 18:
+    /// Semantic action for production 19:
     ///
     /// Operator05Term: "&" : Token;
     ///
@@ -8944,7 +10607,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 19:
+    /// Semantic action for production 20:
     ///
     /// Operator04Term: "\^~|\^|~\^" : Token;
     ///
@@ -8970,7 +10633,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 20:
+    /// Semantic action for production 21:
     ///
     /// Operator03Term: "\|" : Token;
     ///
@@ -8996,7 +10659,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 21:
+    /// Semantic action for production 22:
     ///
     /// UnaryOperatorTerm: "~&|~\||!|~" : Token;
     ///
@@ -9026,7 +10689,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 22:
+    /// Semantic action for production 23:
     ///
     /// ColonColonTerm: '::' : Token;
     ///
@@ -9053,7 +10716,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 23:
+    /// Semantic action for production 24:
     ///
     /// ColonTerm: ':' : Token;
     ///
@@ -9076,7 +10739,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 24:
+    /// Semantic action for production 25:
     ///
     /// CommaTerm: ',' : Token;
     ///
@@ -9099,32 +10762,6 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 25:
-    ///
-    /// DollarTerm: '$' : Token;
-    ///
-    #[parol_runtime::function_name::named]
-    fn dollar_term(
-        &mut self,
-        dollar_term: &ParseTreeStackEntry<'t>,
-        parse_tree: &Tree<ParseTreeType<'t>>,
-    ) -> Result<()> {
-        let context = function_name!();
-        trace!("{}", self.trace_item_stack(context));
-        let dollar_term = dollar_term
-            .token(parse_tree)?
-            .try_into()
-            .into_diagnostic()?;
-        let dollar_term_built = DollarTermBuilder::default()
-            .dollar_term(dollar_term)
-            .build()
-            .into_diagnostic()?;
-        // Calling user action here
-        self.user_grammar.dollar_term(&dollar_term_built)?;
-        self.push(ASTType::DollarTerm(dollar_term_built), context);
-        Ok(())
-    }
-
     /// Semantic action for production 26:
     ///
     /// DotDotTerm: '..' : Token;
@@ -9480,6 +11117,55 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 40:
     ///
+    /// AsTerm: /\bas\b/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn as_term(
+        &mut self,
+        as_term: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let as_term = as_term.token(parse_tree)?.try_into().into_diagnostic()?;
+        let as_term_built = AsTermBuilder::default()
+            .as_term(as_term)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.as_term(&as_term_built)?;
+        self.push(ASTType::AsTerm(as_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 41:
+    ///
+    /// AssertTerm: /\bassert\b/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn assert_term(
+        &mut self,
+        assert_term: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assert_term = assert_term
+            .token(parse_tree)?
+            .try_into()
+            .into_diagnostic()?;
+        let assert_term_built = AssertTermBuilder::default()
+            .assert_term(assert_term)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.assert_term(&assert_term_built)?;
+        self.push(ASTType::AssertTerm(assert_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 42:
+    ///
     /// AssignTerm: /\bassign\b/ : Token;
     ///
     #[parol_runtime::function_name::named]
@@ -9504,7 +11190,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 41:
+    /// Semantic action for production 43:
+    ///
+    /// AssumeTerm: /\bassume\b/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn assume_term(
+        &mut self,
+        assume_term: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assume_term = assume_term
+            .token(parse_tree)?
+            .try_into()
+            .into_diagnostic()?;
+        let assume_term_built = AssumeTermBuilder::default()
+            .assume_term(assume_term)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.assume_term(&assume_term_built)?;
+        self.push(ASTType::AssumeTerm(assume_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 44:
     ///
     /// AsyncHighTerm: /\basync_high\b/ : Token;
     ///
@@ -9530,7 +11242,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 42:
+    /// Semantic action for production 45:
     ///
     /// AsyncLowTerm: /\basync_low\b/ : Token;
     ///
@@ -9556,7 +11268,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 43:
+    /// Semantic action for production 46:
     ///
     /// BitTerm: /\bbit\b/ : Token;
     ///
@@ -9579,7 +11291,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 44:
+    /// Semantic action for production 47:
     ///
     /// CaseTerm: /\bcase\b/ : Token;
     ///
@@ -9602,7 +11314,30 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 45:
+    /// Semantic action for production 48:
+    ///
+    /// CoverTerm: /\bcover\b/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn cover_term(
+        &mut self,
+        cover_term: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let cover_term = cover_term.token(parse_tree)?.try_into().into_diagnostic()?;
+        let cover_term_built = CoverTermBuilder::default()
+            .cover_term(cover_term)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.cover_term(&cover_term_built)?;
+        self.push(ASTType::CoverTerm(cover_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 49:
     ///
     /// DefaultTerm: /\bdefault\b/ : Token;
     ///
@@ -9628,7 +11363,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 46:
+    /// Semantic action for production 50:
     ///
     /// ElseTerm: /\belse\b/ : Token;
     ///
@@ -9651,7 +11386,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 47:
+    /// Semantic action for production 51:
     ///
     /// EnumTerm: /\benum\b/ : Token;
     ///
@@ -9674,7 +11409,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 48:
+    /// Semantic action for production 52:
+    ///
+    /// ExpectTerm: /\bexpect\b/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn expect_term(
+        &mut self,
+        expect_term: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let expect_term = expect_term
+            .token(parse_tree)?
+            .try_into()
+            .into_diagnostic()?;
+        let expect_term_built = ExpectTermBuilder::default()
+            .expect_term(expect_term)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.expect_term(&expect_term_built)?;
+        self.push(ASTType::ExpectTerm(expect_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 53:
     ///
     /// ExportTerm: /\bexport\b/ : Token;
     ///
@@ -9700,7 +11461,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 49:
+    /// Semantic action for production 54:
     ///
     /// F32Term: /\bf32\b/ : Token;
     ///
@@ -9723,7 +11484,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 50:
+    /// Semantic action for production 55:
     ///
     /// F64Term: /\bf64\b/ : Token;
     ///
@@ -9746,7 +11507,30 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 51:
+    /// Semantic action for production 56:
+    ///
+    /// FinalTerm: /\bfinal\b/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn final_term(
+        &mut self,
+        final_term: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let final_term = final_term.token(parse_tree)?.try_into().into_diagnostic()?;
+        let final_term_built = FinalTermBuilder::default()
+            .final_term(final_term)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.final_term(&final_term_built)?;
+        self.push(ASTType::FinalTerm(final_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 57:
     ///
     /// ForTerm: /\bfor\b/ : Token;
     ///
@@ -9769,7 +11553,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 52:
+    /// Semantic action for production 58:
     ///
     /// FunctionTerm: /\bfunction\b/ : Token;
     ///
@@ -9795,7 +11579,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 53:
+    /// Semantic action for production 59:
     ///
     /// I32Term: /\bi32\b/ : Token;
     ///
@@ -9818,7 +11602,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 54:
+    /// Semantic action for production 60:
     ///
     /// I64Term: /\bi64\b/ : Token;
     ///
@@ -9841,7 +11625,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 55:
+    /// Semantic action for production 61:
     ///
     /// IfResetTerm: /\bif_reset\b/ : Token;
     ///
@@ -9867,7 +11651,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 56:
+    /// Semantic action for production 62:
     ///
     /// IfTerm: /\bif\b/ : Token;
     ///
@@ -9890,7 +11674,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 57:
+    /// Semantic action for production 63:
     ///
     /// ImportTerm: /\bimport\b/ : Token;
     ///
@@ -9916,7 +11700,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 58:
+    /// Semantic action for production 64:
+    ///
+    /// InitialTerm: /\binitial\b/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn initial_term(
+        &mut self,
+        initial_term: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let initial_term = initial_term
+            .token(parse_tree)?
+            .try_into()
+            .into_diagnostic()?;
+        let initial_term_built = InitialTermBuilder::default()
+            .initial_term(initial_term)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.initial_term(&initial_term_built)?;
+        self.push(ASTType::InitialTerm(initial_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 65:
     ///
     /// InoutTerm: /\binout\b/ : Token;
     ///
@@ -9939,7 +11749,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 59:
+    /// Semantic action for production 66:
     ///
     /// InputTerm: /\binput\b/ : Token;
     ///
@@ -9962,7 +11772,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 60:
+    /// Semantic action for production 67:
     ///
     /// InstTerm: /\binst\b/ : Token;
     ///
@@ -9985,7 +11795,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 61:
+    /// Semantic action for production 68:
     ///
     /// InterfaceTerm: /\binterface\b/ : Token;
     ///
@@ -10011,7 +11821,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 62:
+    /// Semantic action for production 69:
     ///
     /// InTerm: /\bin\b/ : Token;
     ///
@@ -10034,7 +11844,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 63:
+    /// Semantic action for production 70:
     ///
     /// LocalparamTerm: /\blocalparam\b/ : Token;
     ///
@@ -10060,7 +11870,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 64:
+    /// Semantic action for production 71:
     ///
     /// LogicTerm: /\blogic\b/ : Token;
     ///
@@ -10083,7 +11893,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 65:
+    /// Semantic action for production 72:
     ///
     /// ModportTerm: /\bmodport\b/ : Token;
     ///
@@ -10109,7 +11919,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 66:
+    /// Semantic action for production 73:
     ///
     /// ModuleTerm: /\bmodule\b/ : Token;
     ///
@@ -10135,7 +11945,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 67:
+    /// Semantic action for production 74:
     ///
     /// NegedgeTerm: /\bnegedge\b/ : Token;
     ///
@@ -10161,7 +11971,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 68:
+    /// Semantic action for production 75:
     ///
     /// OutputTerm: /\boutput\b/ : Token;
     ///
@@ -10187,7 +11997,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 69:
+    /// Semantic action for production 76:
     ///
     /// PackageTerm: /\bpackage\b/ : Token;
     ///
@@ -10213,7 +12023,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 70:
+    /// Semantic action for production 77:
     ///
     /// ParameterTerm: /\bparameter\b/ : Token;
     ///
@@ -10239,7 +12049,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 71:
+    /// Semantic action for production 78:
     ///
     /// PosedgeTerm: /\bposedge\b/ : Token;
     ///
@@ -10265,7 +12075,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 72:
+    /// Semantic action for production 79:
     ///
     /// RefTerm: /\bref\b/ : Token;
     ///
@@ -10288,7 +12098,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 73:
+    /// Semantic action for production 80:
     ///
     /// RepeatTerm: /\brepeat\b/ : Token;
     ///
@@ -10314,7 +12124,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 74:
+    /// Semantic action for production 81:
     ///
     /// ReturnTerm: /\breturn\b/ : Token;
     ///
@@ -10340,7 +12150,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 75:
+    /// Semantic action for production 82:
+    ///
+    /// SignedTerm: /\bsigned\b/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn signed_term(
+        &mut self,
+        signed_term: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let signed_term = signed_term
+            .token(parse_tree)?
+            .try_into()
+            .into_diagnostic()?;
+        let signed_term_built = SignedTermBuilder::default()
+            .signed_term(signed_term)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.signed_term(&signed_term_built)?;
+        self.push(ASTType::SignedTerm(signed_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 83:
     ///
     /// StepTerm: /\bstep\b/ : Token;
     ///
@@ -10363,7 +12199,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 76:
+    /// Semantic action for production 84:
     ///
     /// StructTerm: /\bstruct\b/ : Token;
     ///
@@ -10389,7 +12225,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 77:
+    /// Semantic action for production 85:
     ///
     /// SyncHighTerm: /\bsync_high\b/ : Token;
     ///
@@ -10415,7 +12251,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 78:
+    /// Semantic action for production 86:
     ///
     /// SyncLowTerm: /\bsync_low\b/ : Token;
     ///
@@ -10441,7 +12277,30 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 79:
+    /// Semantic action for production 87:
+    ///
+    /// TestTerm: /\btest\b/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn test_term(
+        &mut self,
+        test_term: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let test_term = test_term.token(parse_tree)?.try_into().into_diagnostic()?;
+        let test_term_built = TestTermBuilder::default()
+            .test_term(test_term)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.test_term(&test_term_built)?;
+        self.push(ASTType::TestTerm(test_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 88:
     ///
     /// TriTerm: /\btri\b/ : Token;
     ///
@@ -10464,7 +12323,30 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 80:
+    /// Semantic action for production 89:
+    ///
+    /// TypeTerm: /\btype\b/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn type_term(
+        &mut self,
+        type_term: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let type_term = type_term.token(parse_tree)?.try_into().into_diagnostic()?;
+        let type_term_built = TypeTermBuilder::default()
+            .type_term(type_term)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.type_term(&type_term_built)?;
+        self.push(ASTType::TypeTerm(type_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 90:
     ///
     /// U32Term: /\bu32\b/ : Token;
     ///
@@ -10487,7 +12369,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 81:
+    /// Semantic action for production 91:
     ///
     /// U64Term: /\bu64\b/ : Token;
     ///
@@ -10510,7 +12392,56 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 82:
+    /// Semantic action for production 92:
+    ///
+    /// UnionTerm: /\bunion\b/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn union_term(
+        &mut self,
+        union_term: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let union_term = union_term.token(parse_tree)?.try_into().into_diagnostic()?;
+        let union_term_built = UnionTermBuilder::default()
+            .union_term(union_term)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.union_term(&union_term_built)?;
+        self.push(ASTType::UnionTerm(union_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 93:
+    ///
+    /// UnpackedTerm: /\bunpacked\b/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn unpacked_term(
+        &mut self,
+        unpacked_term: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let unpacked_term = unpacked_term
+            .token(parse_tree)?
+            .try_into()
+            .into_diagnostic()?;
+        let unpacked_term_built = UnpackedTermBuilder::default()
+            .unpacked_term(unpacked_term)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.unpacked_term(&unpacked_term_built)?;
+        self.push(ASTType::UnpackedTerm(unpacked_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 94:
     ///
     /// VarTerm: /\bvar\b/ : Token;
     ///
@@ -10533,7 +12464,37 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 83:
+    /// Semantic action for production 95:
+    ///
+    /// DollarIdentifierTerm: /\$[a-zA-Z_][0-9a-zA-Z_$]*/ : Token;
+    ///
+    #[parol_runtime::function_name::named]
+    fn dollar_identifier_term(
+        &mut self,
+        dollar_identifier_term: &ParseTreeStackEntry<'t>,
+        parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let dollar_identifier_term = dollar_identifier_term
+            .token(parse_tree)?
+            .try_into()
+            .into_diagnostic()?;
+        let dollar_identifier_term_built = DollarIdentifierTermBuilder::default()
+            .dollar_identifier_term(dollar_identifier_term)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .dollar_identifier_term(&dollar_identifier_term_built)?;
+        self.push(
+            ASTType::DollarIdentifierTerm(dollar_identifier_term_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 96:
     ///
     /// IdentifierTerm: /[a-zA-Z_][0-9a-zA-Z_]*/ : Token;
     ///
@@ -10559,7 +12520,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 84:
+    /// Semantic action for production 97:
     ///
     /// Comments: CommentsOpt /* Option */;
     ///
@@ -10582,7 +12543,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 85:
+    /// Semantic action for production 98:
     ///
     /// CommentsOpt /* Option<T>::Some */: CommentsTerm;
     ///
@@ -10606,7 +12567,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 86:
+    /// Semantic action for production 99:
     ///
     /// CommentsOpt /* Option<T>::None */: ;
     ///
@@ -10618,7 +12579,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 87:
+    /// Semantic action for production 100:
     ///
     /// StartToken: Comments;
     ///
@@ -10641,7 +12602,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 88:
+    /// Semantic action for production 101:
     ///
     /// ExponentToken: ExponentTerm : Token Comments;
     ///
@@ -10667,7 +12628,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 89:
+    /// Semantic action for production 102:
     ///
     /// FixedPointToken: FixedPointTerm : Token Comments;
     ///
@@ -10694,7 +12655,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 90:
+    /// Semantic action for production 103:
     ///
     /// BasedToken: BasedTerm : Token Comments;
     ///
@@ -10720,7 +12681,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 91:
+    /// Semantic action for production 104:
     ///
     /// BaseLessToken: BaseLessTerm : Token Comments;
     ///
@@ -10746,7 +12707,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 92:
+    /// Semantic action for production 105:
     ///
     /// AllBitToken: AllBitTerm : Token Comments;
     ///
@@ -10772,7 +12733,37 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 93:
+    /// Semantic action for production 106:
+    ///
+    /// StringLiteralToken: StringLiteralTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn string_literal_token(
+        &mut self,
+        _string_literal_term: &ParseTreeStackEntry<'t>,
+        _comments: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let string_literal_term = pop_item!(self, string_literal_term, StringLiteralTerm, context);
+        let string_literal_token_built = StringLiteralTokenBuilder::default()
+            .string_literal_term((&string_literal_term).try_into().into_diagnostic()?)
+            .comments(Box::new(comments))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .string_literal_token(&string_literal_token_built)?;
+        self.push(
+            ASTType::StringLiteralToken(string_literal_token_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 107:
     ///
     /// AssignmentOperatorToken: AssignmentOperatorTerm : Token Comments;
     ///
@@ -10807,7 +12798,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 94:
+    /// Semantic action for production 108:
     ///
     /// Operator01Token: Operator01Term : Token Comments;
     ///
@@ -10834,7 +12825,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 95:
+    /// Semantic action for production 109:
     ///
     /// Operator02Token: Operator02Term : Token Comments;
     ///
@@ -10861,7 +12852,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 96:
+    /// Semantic action for production 110:
     ///
     /// Operator03Token: Operator03Term : Token Comments;
     ///
@@ -10888,7 +12879,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 97:
+    /// Semantic action for production 111:
     ///
     /// Operator04Token: Operator04Term : Token Comments;
     ///
@@ -10915,7 +12906,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 98:
+    /// Semantic action for production 112:
     ///
     /// Operator05Token: Operator05Term : Token Comments;
     ///
@@ -10942,7 +12933,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 99:
+    /// Semantic action for production 113:
     ///
     /// Operator06Token: Operator06Term : Token Comments;
     ///
@@ -10969,7 +12960,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 100:
+    /// Semantic action for production 114:
     ///
     /// Operator07Token: Operator07Term : Token Comments;
     ///
@@ -10996,7 +12987,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 101:
+    /// Semantic action for production 115:
     ///
     /// Operator08Token: Operator08Term : Token Comments;
     ///
@@ -11023,7 +13014,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 102:
+    /// Semantic action for production 116:
     ///
     /// Operator09Token: Operator09Term : Token Comments;
     ///
@@ -11050,7 +13041,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 103:
+    /// Semantic action for production 117:
     ///
     /// Operator10Token: Operator10Term : Token Comments;
     ///
@@ -11077,7 +13068,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 104:
+    /// Semantic action for production 118:
     ///
     /// Operator11Token: Operator11Term : Token Comments;
     ///
@@ -11104,7 +13095,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 105:
+    /// Semantic action for production 119:
     ///
     /// UnaryOperatorToken: UnaryOperatorTerm : Token Comments;
     ///
@@ -11134,7 +13125,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 106:
+    /// Semantic action for production 120:
     ///
     /// ColonToken: ColonTerm : Token Comments;
     ///
@@ -11160,7 +13151,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 107:
+    /// Semantic action for production 121:
     ///
     /// ColonColonToken: ColonColonTerm : Token Comments;
     ///
@@ -11187,7 +13178,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 108:
+    /// Semantic action for production 122:
     ///
     /// CommaToken: CommaTerm : Token Comments;
     ///
@@ -11213,33 +13204,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 109:
-    ///
-    /// DollarToken: DollarTerm : Token Comments;
-    ///
-    #[parol_runtime::function_name::named]
-    fn dollar_token(
-        &mut self,
-        _dollar_term: &ParseTreeStackEntry<'t>,
-        _comments: &ParseTreeStackEntry<'t>,
-        _parse_tree: &Tree<ParseTreeType<'t>>,
-    ) -> Result<()> {
-        let context = function_name!();
-        trace!("{}", self.trace_item_stack(context));
-        let comments = pop_item!(self, comments, Comments, context);
-        let dollar_term = pop_item!(self, dollar_term, DollarTerm, context);
-        let dollar_token_built = DollarTokenBuilder::default()
-            .dollar_term((&dollar_term).try_into().into_diagnostic()?)
-            .comments(Box::new(comments))
-            .build()
-            .into_diagnostic()?;
-        // Calling user action here
-        self.user_grammar.dollar_token(&dollar_token_built)?;
-        self.push(ASTType::DollarToken(dollar_token_built), context);
-        Ok(())
-    }
-
-    /// Semantic action for production 110:
+    /// Semantic action for production 123:
     ///
     /// DotDotToken: DotDotTerm : Token Comments;
     ///
@@ -11265,7 +13230,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 111:
+    /// Semantic action for production 124:
     ///
     /// DotToken: DotTerm : Token Comments;
     ///
@@ -11291,7 +13256,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 112:
+    /// Semantic action for production 125:
     ///
     /// EquToken: EquTerm : Token Comments;
     ///
@@ -11317,7 +13282,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 113:
+    /// Semantic action for production 126:
     ///
     /// HashToken: HashTerm : Token Comments;
     ///
@@ -11343,7 +13308,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 114:
+    /// Semantic action for production 127:
     ///
     /// LBraceToken: LBraceTerm : Token Comments;
     ///
@@ -11369,7 +13334,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 115:
+    /// Semantic action for production 128:
     ///
     /// LBracketToken: LBracketTerm : Token Comments;
     ///
@@ -11395,7 +13360,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 116:
+    /// Semantic action for production 129:
     ///
     /// LParenToken: LParenTerm : Token Comments;
     ///
@@ -11421,7 +13386,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 117:
+    /// Semantic action for production 130:
     ///
     /// MinusColonToken: MinusColonTerm : Token Comments;
     ///
@@ -11448,7 +13413,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 118:
+    /// Semantic action for production 131:
     ///
     /// MinusGTToken: MinusGTTerm : Token Comments;
     ///
@@ -11474,7 +13439,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 119:
+    /// Semantic action for production 132:
     ///
     /// PlusColonToken: PlusColonTerm : Token Comments;
     ///
@@ -11501,7 +13466,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 120:
+    /// Semantic action for production 133:
     ///
     /// RBraceToken: RBraceTerm : Token Comments;
     ///
@@ -11527,7 +13492,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 121:
+    /// Semantic action for production 134:
     ///
     /// RBracketToken: RBracketTerm : Token Comments;
     ///
@@ -11553,7 +13518,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 122:
+    /// Semantic action for production 135:
     ///
     /// RParenToken: RParenTerm : Token Comments;
     ///
@@ -11579,7 +13544,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 123:
+    /// Semantic action for production 136:
     ///
     /// SemicolonToken: SemicolonTerm : Token Comments;
     ///
@@ -11605,7 +13570,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 124:
+    /// Semantic action for production 137:
     ///
     /// StarToken: StarTerm : Token Comments;
     ///
@@ -11631,7 +13596,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 125:
+    /// Semantic action for production 138:
     ///
     /// AlwaysCombToken: AlwaysCombTerm : Token Comments;
     ///
@@ -11658,7 +13623,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 126:
+    /// Semantic action for production 139:
     ///
     /// AlwaysFfToken: AlwaysFfTerm : Token Comments;
     ///
@@ -11684,117 +13649,195 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 127:
+    /// Semantic action for production 140:
     ///
-    /// AssignToken: AssignTerm : Token Comments;
+    /// AsToken: AsTerm : Token Comments;
     ///
     #[parol_runtime::function_name::named]
-    fn assign_token(
+    fn as_token(
         &mut self,
-        _assign_term: &ParseTreeStackEntry<'t>,
+        _as_term: &ParseTreeStackEntry<'t>,
         _comments: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let comments = pop_item!(self, comments, Comments, context);
-        let assign_term = pop_item!(self, assign_term, AssignTerm, context);
-        let assign_token_built = AssignTokenBuilder::default()
-            .assign_term((&assign_term).try_into().into_diagnostic()?)
+        let as_term = pop_item!(self, as_term, AsTerm, context);
+        let as_token_built = AsTokenBuilder::default()
+            .as_term((&as_term).try_into().into_diagnostic()?)
             .comments(Box::new(comments))
             .build()
             .into_diagnostic()?;
         // Calling user action here
-        self.user_grammar.assign_token(&assign_token_built)?;
-        self.push(ASTType::AssignToken(assign_token_built), context);
+        self.user_grammar.as_token(&as_token_built)?;
+        self.push(ASTType::AsToken(as_token_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 128:
+    /// Semantic action for production 141:
     ///
-    /// AsyncHighToken: AsyncHighTerm : Token Comments;
+    /// AssertToken: AssertTerm : Token Comments;
     ///
     #[parol_runtime::function_name::named]
-    fn async_high_token(
+    fn assert_token(
         &mut self,
-        _async_high_term: &ParseTreeStackEntry<'t>,
+        _assert_term: &ParseTreeStackEntry<'t>,
         _comments: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let comments = pop_item!(self, comments, Comments, context);
-        let async_high_term = pop_item!(self, async_high_term, AsyncHighTerm, context);
-        let async_high_token_built = AsyncHighTokenBuilder::default()
-            .async_high_term((&async_high_term).try_into().into_diagnostic()?)
+        let assert_term = pop_item!(self, assert_term, AssertTerm, context);
+        let assert_token_built = AssertTokenBuilder::default()
+            .assert_term((&assert_term).try_into().into_diagnostic()?)
             .comments(Box::new(comments))
             .build()
             .into_diagnostic()?;
         // Calling user action here
-        self.user_grammar
-            .async_high_token(&async_high_token_built)?;
-        self.push(ASTType::AsyncHighToken(async_high_token_built), context);
+        self.user_grammar.assert_token(&assert_token_built)?;
+        self.push(ASTType::AssertToken(assert_token_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 129:
+    /// Semantic action for production 142:
     ///
-    /// AsyncLowToken: AsyncLowTerm : Token Comments;
+    /// AssignToken: AssignTerm : Token Comments;
     ///
     #[parol_runtime::function_name::named]
-    fn async_low_token(
+    fn assign_token(
         &mut self,
-        _async_low_term: &ParseTreeStackEntry<'t>,
+        _assign_term: &ParseTreeStackEntry<'t>,
         _comments: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let comments = pop_item!(self, comments, Comments, context);
-        let async_low_term = pop_item!(self, async_low_term, AsyncLowTerm, context);
-        let async_low_token_built = AsyncLowTokenBuilder::default()
-            .async_low_term((&async_low_term).try_into().into_diagnostic()?)
+        let assign_term = pop_item!(self, assign_term, AssignTerm, context);
+        let assign_token_built = AssignTokenBuilder::default()
+            .assign_term((&assign_term).try_into().into_diagnostic()?)
             .comments(Box::new(comments))
             .build()
             .into_diagnostic()?;
         // Calling user action here
-        self.user_grammar.async_low_token(&async_low_token_built)?;
-        self.push(ASTType::AsyncLowToken(async_low_token_built), context);
+        self.user_grammar.assign_token(&assign_token_built)?;
+        self.push(ASTType::AssignToken(assign_token_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 130:
+    /// Semantic action for production 143:
     ///
-    /// BitToken: BitTerm : Token Comments;
+    /// AssumeToken: AssumeTerm : Token Comments;
     ///
     #[parol_runtime::function_name::named]
-    fn bit_token(
+    fn assume_token(
         &mut self,
-        _bit_term: &ParseTreeStackEntry<'t>,
+        _assume_term: &ParseTreeStackEntry<'t>,
         _comments: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let comments = pop_item!(self, comments, Comments, context);
-        let bit_term = pop_item!(self, bit_term, BitTerm, context);
-        let bit_token_built = BitTokenBuilder::default()
-            .bit_term((&bit_term).try_into().into_diagnostic()?)
+        let assume_term = pop_item!(self, assume_term, AssumeTerm, context);
+        let assume_token_built = AssumeTokenBuilder::default()
+            .assume_term((&assume_term).try_into().into_diagnostic()?)
             .comments(Box::new(comments))
             .build()
             .into_diagnostic()?;
         // Calling user action here
-        self.user_grammar.bit_token(&bit_token_built)?;
-        self.push(ASTType::BitToken(bit_token_built), context);
+        self.user_grammar.assume_token(&assume_token_built)?;
+        self.push(ASTType::AssumeToken(assume_token_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 131:
+    /// Semantic action for production 144:
     ///
-    /// CaseToken: CaseTerm : Token Comments;
+    /// AsyncHighToken: AsyncHighTerm : Token Comments;
     ///
     #[parol_runtime::function_name::named]
-    fn case_token(
+    fn async_high_token(
+        &mut self,
+        _async_high_term: &ParseTreeStackEntry<'t>,
+        _comments: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let async_high_term = pop_item!(self, async_high_term, AsyncHighTerm, context);
+        let async_high_token_built = AsyncHighTokenBuilder::default()
+            .async_high_term((&async_high_term).try_into().into_diagnostic()?)
+            .comments(Box::new(comments))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .async_high_token(&async_high_token_built)?;
+        self.push(ASTType::AsyncHighToken(async_high_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 145:
+    ///
+    /// AsyncLowToken: AsyncLowTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn async_low_token(
+        &mut self,
+        _async_low_term: &ParseTreeStackEntry<'t>,
+        _comments: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let async_low_term = pop_item!(self, async_low_term, AsyncLowTerm, context);
+        let async_low_token_built = AsyncLowTokenBuilder::default()
+            .async_low_term((&async_low_term).try_into().into_diagnostic()?)
+            .comments(Box::new(comments))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.async_low_token(&async_low_token_built)?;
+        self.push(ASTType::AsyncLowToken(async_low_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 146:
+    ///
+    /// BitToken: BitTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn bit_token(
+        &mut self,
+        _bit_term: &ParseTreeStackEntry<'t>,
+        _comments: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let bit_term = pop_item!(self, bit_term, BitTerm, context);
+        let bit_token_built = BitTokenBuilder::default()
+            .bit_term((&bit_term).try_into().into_diagnostic()?)
+            .comments(Box::new(comments))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.bit_token(&bit_token_built)?;
+        self.push(ASTType::BitToken(bit_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 147:
+    ///
+    /// CaseToken: CaseTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn case_token(
         &mut self,
         _case_term: &ParseTreeStackEntry<'t>,
         _comments: &ParseTreeStackEntry<'t>,
@@ -11815,7 +13858,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 132:
+    /// Semantic action for production 148:
+    ///
+    /// CoverToken: CoverTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn cover_token(
+        &mut self,
+        _cover_term: &ParseTreeStackEntry<'t>,
+        _comments: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let cover_term = pop_item!(self, cover_term, CoverTerm, context);
+        let cover_token_built = CoverTokenBuilder::default()
+            .cover_term((&cover_term).try_into().into_diagnostic()?)
+            .comments(Box::new(comments))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.cover_token(&cover_token_built)?;
+        self.push(ASTType::CoverToken(cover_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 149:
     ///
     /// DefaultToken: DefaultTerm : Token Comments;
     ///
@@ -11841,7 +13910,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 133:
+    /// Semantic action for production 150:
     ///
     /// ElseToken: ElseTerm : Token Comments;
     ///
@@ -11867,7 +13936,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 134:
+    /// Semantic action for production 151:
     ///
     /// EnumToken: EnumTerm : Token Comments;
     ///
@@ -11893,7 +13962,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 135:
+    /// Semantic action for production 152:
+    ///
+    /// ExpectToken: ExpectTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn expect_token(
+        &mut self,
+        _expect_term: &ParseTreeStackEntry<'t>,
+        _comments: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let expect_term = pop_item!(self, expect_term, ExpectTerm, context);
+        let expect_token_built = ExpectTokenBuilder::default()
+            .expect_term((&expect_term).try_into().into_diagnostic()?)
+            .comments(Box::new(comments))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.expect_token(&expect_token_built)?;
+        self.push(ASTType::ExpectToken(expect_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 153:
     ///
     /// ExportToken: ExportTerm : Token Comments;
     ///
@@ -11919,7 +14014,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 136:
+    /// Semantic action for production 154:
     ///
     /// F32Token: F32Term : Token Comments;
     ///
@@ -11945,7 +14040,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 137:
+    /// Semantic action for production 155:
     ///
     /// F64Token: F64Term : Token Comments;
     ///
@@ -11971,7 +14066,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 138:
+    /// Semantic action for production 156:
+    ///
+    /// FinalToken: FinalTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn final_token(
+        &mut self,
+        _final_term: &ParseTreeStackEntry<'t>,
+        _comments: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let final_term = pop_item!(self, final_term, FinalTerm, context);
+        let final_token_built = FinalTokenBuilder::default()
+            .final_term((&final_term).try_into().into_diagnostic()?)
+            .comments(Box::new(comments))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.final_token(&final_token_built)?;
+        self.push(ASTType::FinalToken(final_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 157:
     ///
     /// ForToken: ForTerm : Token Comments;
     ///
@@ -11997,7 +14118,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 139:
+    /// Semantic action for production 158:
     ///
     /// FunctionToken: FunctionTerm : Token Comments;
     ///
@@ -12023,7 +14144,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 140:
+    /// Semantic action for production 159:
     ///
     /// I32Token: I32Term : Token Comments;
     ///
@@ -12049,7 +14170,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 141:
+    /// Semantic action for production 160:
     ///
     /// I64Token: I64Term : Token Comments;
     ///
@@ -12075,7 +14196,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 142:
+    /// Semantic action for production 161:
     ///
     /// IfResetToken: IfResetTerm : Token Comments;
     ///
@@ -12101,7 +14222,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 143:
+    /// Semantic action for production 162:
     ///
     /// IfToken: IfTerm : Token Comments;
     ///
@@ -12127,7 +14248,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 144:
+    /// Semantic action for production 163:
     ///
     /// ImportToken: ImportTerm : Token Comments;
     ///
@@ -12153,7 +14274,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 145:
+    /// Semantic action for production 164:
+    ///
+    /// InitialToken: InitialTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn initial_token(
+        &mut self,
+        _initial_term: &ParseTreeStackEntry<'t>,
+        _comments: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let initial_term = pop_item!(self, initial_term, InitialTerm, context);
+        let initial_token_built = InitialTokenBuilder::default()
+            .initial_term((&initial_term).try_into().into_diagnostic()?)
+            .comments(Box::new(comments))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.initial_token(&initial_token_built)?;
+        self.push(ASTType::InitialToken(initial_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 165:
     ///
     /// InoutToken: InoutTerm : Token Comments;
     ///
@@ -12179,7 +14326,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 146:
+    /// Semantic action for production 166:
     ///
     /// InputToken: InputTerm : Token Comments;
     ///
@@ -12205,7 +14352,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 147:
+    /// Semantic action for production 167:
     ///
     /// InstToken: InstTerm : Token Comments;
     ///
@@ -12231,7 +14378,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 148:
+    /// Semantic action for production 168:
     ///
     /// InterfaceToken: InterfaceTerm : Token Comments;
     ///
@@ -12257,7 +14404,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 149:
+    /// Semantic action for production 169:
     ///
     /// InToken: InTerm : Token Comments;
     ///
@@ -12283,7 +14430,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 150:
+    /// Semantic action for production 170:
     ///
     /// LocalparamToken: LocalparamTerm : Token Comments;
     ///
@@ -12310,7 +14457,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 151:
+    /// Semantic action for production 171:
     ///
     /// LogicToken: LogicTerm : Token Comments;
     ///
@@ -12336,7 +14483,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 152:
+    /// Semantic action for production 172:
     ///
     /// ModportToken: ModportTerm : Token Comments;
     ///
@@ -12362,7 +14509,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 153:
+    /// Semantic action for production 173:
     ///
     /// ModuleToken: ModuleTerm : Token Comments;
     ///
@@ -12388,7 +14535,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 154:
+    /// Semantic action for production 174:
     ///
     /// NegedgeToken: NegedgeTerm : Token Comments;
     ///
@@ -12414,7 +14561,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 155:
+    /// Semantic action for production 175:
     ///
     /// OutputToken: OutputTerm : Token Comments;
     ///
@@ -12440,7 +14587,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 156:
+    /// Semantic action for production 176:
     ///
     /// PackageToken: PackageTerm : Token Comments;
     ///
@@ -12466,7 +14613,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 157:
+    /// Semantic action for production 177:
     ///
     /// ParameterToken: ParameterTerm : Token Comments;
     ///
@@ -12492,7 +14639,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 158:
+    /// Semantic action for production 178:
     ///
     /// PosedgeToken: PosedgeTerm : Token Comments;
     ///
@@ -12518,7 +14665,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 159:
+    /// Semantic action for production 179:
     ///
     /// RefToken: RefTerm : Token Comments;
     ///
@@ -12544,7 +14691,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 160:
+    /// Semantic action for production 180:
     ///
     /// RepeatToken: RepeatTerm : Token Comments;
     ///
@@ -12570,7 +14717,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 161:
+    /// Semantic action for production 181:
     ///
     /// ReturnToken: ReturnTerm : Token Comments;
     ///
@@ -12596,7 +14743,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 162:
+    /// Semantic action for production 182:
+    ///
+    /// SignedToken: SignedTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn signed_token(
+        &mut self,
+        _signed_term: &ParseTreeStackEntry<'t>,
+        _comments: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let signed_term = pop_item!(self, signed_term, SignedTerm, context);
+        let signed_token_built = SignedTokenBuilder::default()
+            .signed_term((&signed_term).try_into().into_diagnostic()?)
+            .comments(Box::new(comments))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.signed_token(&signed_token_built)?;
+        self.push(ASTType::SignedToken(signed_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 183:
     ///
     /// StepToken: StepTerm : Token Comments;
     ///
@@ -12622,7 +14795,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 163:
+    /// Semantic action for production 184:
     ///
     /// StructToken: StructTerm : Token Comments;
     ///
@@ -12648,7 +14821,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 164:
+    /// Semantic action for production 185:
     ///
     /// SyncHighToken: SyncHighTerm : Token Comments;
     ///
@@ -12674,7 +14847,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 165:
+    /// Semantic action for production 186:
     ///
     /// SyncLowToken: SyncLowTerm : Token Comments;
     ///
@@ -12700,40 +14873,92 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 166:
+    /// Semantic action for production 187:
     ///
-    /// TriToken: TriTerm : Token Comments;
+    /// TestToken: TestTerm : Token Comments;
     ///
     #[parol_runtime::function_name::named]
-    fn tri_token(
+    fn test_token(
         &mut self,
-        _tri_term: &ParseTreeStackEntry<'t>,
+        _test_term: &ParseTreeStackEntry<'t>,
         _comments: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let comments = pop_item!(self, comments, Comments, context);
-        let tri_term = pop_item!(self, tri_term, TriTerm, context);
-        let tri_token_built = TriTokenBuilder::default()
-            .tri_term((&tri_term).try_into().into_diagnostic()?)
+        let test_term = pop_item!(self, test_term, TestTerm, context);
+        let test_token_built = TestTokenBuilder::default()
+            .test_term((&test_term).try_into().into_diagnostic()?)
             .comments(Box::new(comments))
             .build()
             .into_diagnostic()?;
         // Calling user action here
-        self.user_grammar.tri_token(&tri_token_built)?;
-        self.push(ASTType::TriToken(tri_token_built), context);
+        self.user_grammar.test_token(&test_token_built)?;
+        self.push(ASTType::TestToken(test_token_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 167:
+    /// Semantic action for production 188:
     ///
-    /// U32Token: U32Term : Token Comments;
+    /// TriToken: TriTerm : Token Comments;
     ///
     #[parol_runtime::function_name::named]
-    fn u32_token(
+    fn tri_token(
         &mut self,
-        _u32_term: &ParseTreeStackEntry<'t>,
+        _tri_term: &ParseTreeStackEntry<'t>,
+        _comments: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let tri_term = pop_item!(self, tri_term, TriTerm, context);
+        let tri_token_built = TriTokenBuilder::default()
+            .tri_term((&tri_term).try_into().into_diagnostic()?)
+            .comments(Box::new(comments))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.tri_token(&tri_token_built)?;
+        self.push(ASTType::TriToken(tri_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 189:
+    ///
+    /// TypeToken: TypeTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn type_token(
+        &mut self,
+        _type_term: &ParseTreeStackEntry<'t>,
+        _comments: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let type_term = pop_item!(self, type_term, TypeTerm, context);
+        let type_token_built = TypeTokenBuilder::default()
+            .type_term((&type_term).try_into().into_diagnostic()?)
+            .comments(Box::new(comments))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.type_token(&type_token_built)?;
+        self.push(ASTType::TypeToken(type_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 190:
+    ///
+    /// U32Token: U32Term : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn u32_token(
+        &mut self,
+        _u32_term: &ParseTreeStackEntry<'t>,
         _comments: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
@@ -12752,7 +14977,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 168:
+    /// Semantic action for production 191:
     ///
     /// U64Token: U64Term : Token Comments;
     ///
@@ -12778,7 +15003,59 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 169:
+    /// Semantic action for production 192:
+    ///
+    /// UnionToken: UnionTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn union_token(
+        &mut self,
+        _union_term: &ParseTreeStackEntry<'t>,
+        _comments: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let union_term = pop_item!(self, union_term, UnionTerm, context);
+        let union_token_built = UnionTokenBuilder::default()
+            .union_term((&union_term).try_into().into_diagnostic()?)
+            .comments(Box::new(comments))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.union_token(&union_token_built)?;
+        self.push(ASTType::UnionToken(union_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 193:
+    ///
+    /// UnpackedToken: UnpackedTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn unpacked_token(
+        &mut self,
+        _unpacked_term: &ParseTreeStackEntry<'t>,
+        _comments: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let unpacked_term = pop_item!(self, unpacked_term, UnpackedTerm, context);
+        let unpacked_token_built = UnpackedTokenBuilder::default()
+            .unpacked_term((&unpacked_term).try_into().into_diagnostic()?)
+            .comments(Box::new(comments))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.unpacked_token(&unpacked_token_built)?;
+        self.push(ASTType::UnpackedToken(unpacked_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 194:
     ///
     /// VarToken: VarTerm : Token Comments;
     ///
@@ -12804,7 +15081,38 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 170:
+    /// Semantic action for production 195:
+    ///
+    /// DollarIdentifierToken: DollarIdentifierTerm : Token Comments;
+    ///
+    #[parol_runtime::function_name::named]
+    fn dollar_identifier_token(
+        &mut self,
+        _dollar_identifier_term: &ParseTreeStackEntry<'t>,
+        _comments: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let dollar_identifier_term =
+            pop_item!(self, dollar_identifier_term, DollarIdentifierTerm, context);
+        let dollar_identifier_token_built = DollarIdentifierTokenBuilder::default()
+            .dollar_identifier_term((&dollar_identifier_term).try_into().into_diagnostic()?)
+            .comments(Box::new(comments))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .dollar_identifier_token(&dollar_identifier_token_built)?;
+        self.push(
+            ASTType::DollarIdentifierToken(dollar_identifier_token_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 196:
     ///
     /// IdentifierToken: IdentifierTerm : Token Comments;
     ///
@@ -12831,7 +15139,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 171:
+    /// Semantic action for production 197:
     ///
     /// Start: StartToken : VerylToken;
     ///
@@ -12854,7 +15162,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 172:
+    /// Semantic action for production 198:
     ///
     /// Exponent: ExponentToken : VerylToken;
     ///
@@ -12877,7 +15185,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 173:
+    /// Semantic action for production 199:
     ///
     /// FixedPoint: FixedPointToken : VerylToken;
     ///
@@ -12900,7 +15208,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 174:
+    /// Semantic action for production 200:
     ///
     /// Based: BasedToken : VerylToken;
     ///
@@ -12923,7 +15231,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 175:
+    /// Semantic action for production 201:
     ///
     /// BaseLess: BaseLessToken : VerylToken;
     ///
@@ -12946,7 +15254,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 176:
+    /// Semantic action for production 202:
     ///
     /// AllBit: AllBitToken : VerylToken;
     ///
@@ -12969,7 +15277,31 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 177:
+    /// Semantic action for production 203:
+    ///
+    /// StringLiteral: StringLiteralToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn string_literal(
+        &mut self,
+        _string_literal_token: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let string_literal_token =
+            pop_item!(self, string_literal_token, StringLiteralToken, context);
+        let string_literal_built = StringLiteralBuilder::default()
+            .string_literal_token((&string_literal_token).try_into().into_diagnostic()?)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.string_literal(&string_literal_built)?;
+        self.push(ASTType::StringLiteral(string_literal_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 204:
     ///
     /// AssignmentOperator: AssignmentOperatorToken : VerylToken;
     ///
@@ -13001,7 +15333,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 178:
+    /// Semantic action for production 205:
     ///
     /// Operator01: Operator01Token : VerylToken;
     ///
@@ -13024,7 +15356,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 179:
+    /// Semantic action for production 206:
     ///
     /// Operator02: Operator02Token : VerylToken;
     ///
@@ -13047,7 +15379,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 180:
+    /// Semantic action for production 207:
     ///
     /// Operator03: Operator03Token : VerylToken;
     ///
@@ -13070,7 +15402,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 181:
+    /// Semantic action for production 208:
     ///
     /// Operator04: Operator04Token : VerylToken;
     ///
@@ -13093,7 +15425,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 182:
+    /// Semantic action for production 209:
     ///
     /// Operator05: Operator05Token : VerylToken;
     ///
@@ -13116,7 +15448,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 183:
+    /// Semantic action for production 210:
     ///
     /// Operator06: Operator06Token : VerylToken;
     ///
@@ -13139,7 +15471,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 184:
+    /// Semantic action for production 211:
     ///
     /// Operator07: Operator07Token : VerylToken;
     ///
@@ -13162,7 +15494,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 185:
+    /// Semantic action for production 212:
     ///
     /// Operator08: Operator08Token : VerylToken;
     ///
@@ -13185,7 +15517,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 186:
+    /// Semantic action for production 213:
     ///
     /// Operator09: Operator09Token : VerylToken;
     ///
@@ -13208,7 +15540,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 187:
+    /// Semantic action for production 214:
     ///
     /// Operator10: Operator10Token : VerylToken;
     ///
@@ -13231,7 +15563,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 188:
+    /// Semantic action for production 215:
     ///
     /// Operator11: Operator11Token : VerylToken;
     ///
@@ -13254,7 +15586,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 189:
+    /// Semantic action for production 216:
     ///
     /// UnaryOperator: UnaryOperatorToken : VerylToken;
     ///
@@ -13278,7 +15610,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 190:
+    /// Semantic action for production 217:
     ///
     /// Colon: ColonToken : VerylToken;
     ///
@@ -13301,7 +15633,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 191:
+    /// Semantic action for production 218:
     ///
     /// ColonColon: ColonColonToken : VerylToken;
     ///
@@ -13324,7 +15656,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 192:
+    /// Semantic action for production 219:
     ///
     /// Comma: CommaToken : VerylToken;
     ///
@@ -13347,30 +15679,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 193:
-    ///
-    /// Dollar: DollarToken : VerylToken;
-    ///
-    #[parol_runtime::function_name::named]
-    fn dollar(
-        &mut self,
-        _dollar_token: &ParseTreeStackEntry<'t>,
-        _parse_tree: &Tree<ParseTreeType<'t>>,
-    ) -> Result<()> {
-        let context = function_name!();
-        trace!("{}", self.trace_item_stack(context));
-        let dollar_token = pop_item!(self, dollar_token, DollarToken, context);
-        let dollar_built = DollarBuilder::default()
-            .dollar_token((&dollar_token).try_into().into_diagnostic()?)
-            .build()
-            .into_diagnostic()?;
-        // Calling user action here
-        self.user_grammar.dollar(&dollar_built)?;
-        self.push(ASTType::Dollar(dollar_built), context);
-        Ok(())
-    }
-
-    /// Semantic action for production 194:
+    /// Semantic action for production 220:
     ///
     /// DotDot: DotDotToken : VerylToken;
     ///
@@ -13393,7 +15702,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 195:
+    /// Semantic action for production 221:
     ///
     /// Dot: DotToken : VerylToken;
     ///
@@ -13416,7 +15725,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 196:
+    /// Semantic action for production 222:
     ///
     /// Equ: EquToken : VerylToken;
     ///
@@ -13439,7 +15748,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 197:
+    /// Semantic action for production 223:
     ///
     /// Hash: HashToken : VerylToken;
     ///
@@ -13462,7 +15771,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 198:
+    /// Semantic action for production 224:
     ///
     /// LBrace: LBraceToken : VerylToken;
     ///
@@ -13485,7 +15794,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 199:
+    /// Semantic action for production 225:
     ///
     /// LBracket: LBracketToken : VerylToken;
     ///
@@ -13508,7 +15817,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 200:
+    /// Semantic action for production 226:
     ///
     /// LParen: LParenToken : VerylToken;
     ///
@@ -13531,7 +15840,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 201:
+    /// Semantic action for production 227:
     ///
     /// MinusColon: MinusColonToken : VerylToken;
     ///
@@ -13554,7 +15863,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 202:
+    /// Semantic action for production 228:
     ///
     /// MinusGT: MinusGTToken : VerylToken;
     ///
@@ -13577,7 +15886,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 203:
+    /// Semantic action for production 229:
     ///
     /// PlusColon: PlusColonToken : VerylToken;
     ///
@@ -13600,7 +15909,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 204:
+    /// Semantic action for production 230:
     ///
     /// RBrace: RBraceToken : VerylToken;
     ///
@@ -13623,7 +15932,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 205:
+    /// Semantic action for production 231:
     ///
     /// RBracket: RBracketToken : VerylToken;
     ///
@@ -13646,7 +15955,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 206:
+    /// Semantic action for production 232:
     ///
     /// RParen: RParenToken : VerylToken;
     ///
@@ -13669,7 +15978,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 207:
+    /// Semantic action for production 233:
     ///
     /// Semicolon: SemicolonToken : VerylToken;
     ///
@@ -13692,7 +16001,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 208:
+    /// Semantic action for production 234:
     ///
     /// Star: StarToken : VerylToken;
     ///
@@ -13715,7 +16024,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 209:
+    /// Semantic action for production 235:
     ///
     /// AlwaysComb: AlwaysCombToken : VerylToken;
     ///
@@ -13738,7 +16047,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 210:
+    /// Semantic action for production 236:
     ///
     /// AlwaysFf: AlwaysFfToken : VerylToken;
     ///
@@ -13761,76 +16070,145 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 211:
+    /// Semantic action for production 237:
     ///
-    /// Assign: AssignToken : VerylToken;
+    /// As: AsToken : VerylToken;
     ///
     #[parol_runtime::function_name::named]
-    fn assign(
+    fn r#as(
         &mut self,
-        _assign_token: &ParseTreeStackEntry<'t>,
+        _as_token: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let assign_token = pop_item!(self, assign_token, AssignToken, context);
-        let assign_built = AssignBuilder::default()
-            .assign_token((&assign_token).try_into().into_diagnostic()?)
+        let as_token = pop_item!(self, as_token, AsToken, context);
+        let r#as_built = AsBuilder::default()
+            .as_token((&as_token).try_into().into_diagnostic()?)
             .build()
             .into_diagnostic()?;
         // Calling user action here
-        self.user_grammar.assign(&assign_built)?;
-        self.push(ASTType::Assign(assign_built), context);
+        self.user_grammar.r#as(&r#as_built)?;
+        self.push(ASTType::As(r#as_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 212:
+    /// Semantic action for production 238:
     ///
-    /// AsyncHigh: AsyncHighToken : VerylToken;
+    /// Assert: AssertToken : VerylToken;
     ///
     #[parol_runtime::function_name::named]
-    fn async_high(
+    fn assert(
         &mut self,
-        _async_high_token: &ParseTreeStackEntry<'t>,
+        _assert_token: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let async_high_token = pop_item!(self, async_high_token, AsyncHighToken, context);
-        let async_high_built = AsyncHighBuilder::default()
-            .async_high_token((&async_high_token).try_into().into_diagnostic()?)
+        let assert_token = pop_item!(self, assert_token, AssertToken, context);
+        let assert_built = AssertBuilder::default()
+            .assert_token((&assert_token).try_into().into_diagnostic()?)
             .build()
             .into_diagnostic()?;
         // Calling user action here
-        self.user_grammar.async_high(&async_high_built)?;
-        self.push(ASTType::AsyncHigh(async_high_built), context);
+        self.user_grammar.assert(&assert_built)?;
+        self.push(ASTType::Assert(assert_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 213:
+    /// Semantic action for production 239:
     ///
-    /// AsyncLow: AsyncLowToken : VerylToken;
+    /// Assign: AssignToken : VerylToken;
     ///
     #[parol_runtime::function_name::named]
-    fn async_low(
+    fn assign(
         &mut self,
-        _async_low_token: &ParseTreeStackEntry<'t>,
+        _assign_token: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let async_low_token = pop_item!(self, async_low_token, AsyncLowToken, context);
-        let async_low_built = AsyncLowBuilder::default()
-            .async_low_token((&async_low_token).try_into().into_diagnostic()?)
+        let assign_token = pop_item!(self, assign_token, AssignToken, context);
+        let assign_built = AssignBuilder::default()
+            .assign_token((&assign_token).try_into().into_diagnostic()?)
             .build()
             .into_diagnostic()?;
         // Calling user action here
-        self.user_grammar.async_low(&async_low_built)?;
-        self.push(ASTType::AsyncLow(async_low_built), context);
+        self.user_grammar.assign(&assign_built)?;
+        self.push(ASTType::Assign(assign_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 214:
+    /// Semantic action for production 240:
+    ///
+    /// Assume: AssumeToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn assume(
+        &mut self,
+        _assume_token: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assume_token = pop_item!(self, assume_token, AssumeToken, context);
+        let assume_built = AssumeBuilder::default()
+            .assume_token((&assume_token).try_into().into_diagnostic()?)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.assume(&assume_built)?;
+        self.push(ASTType::Assume(assume_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 241:
+    ///
+    /// AsyncHigh: AsyncHighToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn async_high(
+        &mut self,
+        _async_high_token: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let async_high_token = pop_item!(self, async_high_token, AsyncHighToken, context);
+        let async_high_built = AsyncHighBuilder::default()
+            .async_high_token((&async_high_token).try_into().into_diagnostic()?)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.async_high(&async_high_built)?;
+        self.push(ASTType::AsyncHigh(async_high_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 242:
+    ///
+    /// AsyncLow: AsyncLowToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn async_low(
+        &mut self,
+        _async_low_token: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let async_low_token = pop_item!(self, async_low_token, AsyncLowToken, context);
+        let async_low_built = AsyncLowBuilder::default()
+            .async_low_token((&async_low_token).try_into().into_diagnostic()?)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.async_low(&async_low_built)?;
+        self.push(ASTType::AsyncLow(async_low_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 243:
     ///
     /// Bit: BitToken : VerylToken;
     ///
@@ -13853,7 +16231,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 215:
+    /// Semantic action for production 244:
     ///
     /// Case: CaseToken : VerylToken;
     ///
@@ -13876,7 +16254,30 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 216:
+    /// Semantic action for production 245:
+    ///
+    /// Cover: CoverToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn cover(
+        &mut self,
+        _cover_token: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let cover_token = pop_item!(self, cover_token, CoverToken, context);
+        let cover_built = CoverBuilder::default()
+            .cover_token((&cover_token).try_into().into_diagnostic()?)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.cover(&cover_built)?;
+        self.push(ASTType::Cover(cover_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 246:
     ///
     /// Defaul: DefaultToken : VerylToken;
     ///
@@ -13899,7 +16300,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 217:
+    /// Semantic action for production 247:
     ///
     /// Else: ElseToken : VerylToken;
     ///
@@ -13922,7 +16323,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 218:
+    /// Semantic action for production 248:
     ///
     /// Enum: EnumToken : VerylToken;
     ///
@@ -13945,7 +16346,30 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 219:
+    /// Semantic action for production 249:
+    ///
+    /// Expect: ExpectToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn expect(
+        &mut self,
+        _expect_token: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let expect_token = pop_item!(self, expect_token, ExpectToken, context);
+        let expect_built = ExpectBuilder::default()
+            .expect_token((&expect_token).try_into().into_diagnostic()?)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.expect(&expect_built)?;
+        self.push(ASTType::Expect(expect_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 250:
     ///
     /// Export: ExportToken : VerylToken;
     ///
@@ -13968,7 +16392,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 220:
+    /// Semantic action for production 251:
     ///
     /// F32: F32Token : VerylToken;
     ///
@@ -13991,7 +16415,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 221:
+    /// Semantic action for production 252:
     ///
     /// F64: F64Token : VerylToken;
     ///
@@ -14014,7 +16438,30 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 222:
+    /// Semantic action for production 253:
+    ///
+    /// Final: FinalToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn r#final(
+        &mut self,
+        _final_token: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let final_token = pop_item!(self, final_token, FinalToken, context);
+        let r#final_built = FinalBuilder::default()
+            .final_token((&final_token).try_into().into_diagnostic()?)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.r#final(&r#final_built)?;
+        self.push(ASTType::Final(r#final_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 254:
     ///
     /// For: ForToken : VerylToken;
     ///
@@ -14037,7 +16484,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 223:
+    /// Semantic action for production 255:
     ///
     /// Function: FunctionToken : VerylToken;
     ///
@@ -14060,7 +16507,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 224:
+    /// Semantic action for production 256:
     ///
     /// I32: I32Token : VerylToken;
     ///
@@ -14083,7 +16530,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 225:
+    /// Semantic action for production 257:
     ///
     /// I64: I64Token : VerylToken;
     ///
@@ -14106,7 +16553,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 226:
+    /// Semantic action for production 258:
     ///
     /// If: IfToken : VerylToken;
     ///
@@ -14129,7 +16576,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 227:
+    /// Semantic action for production 259:
     ///
     /// IfReset: IfResetToken : VerylToken;
     ///
@@ -14152,7 +16599,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 228:
+    /// Semantic action for production 260:
     ///
     /// Import: ImportToken : VerylToken;
     ///
@@ -14175,7 +16622,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 229:
+    /// Semantic action for production 261:
     ///
     /// In: InToken : VerylToken;
     ///
@@ -14198,7 +16645,30 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 230:
+    /// Semantic action for production 262:
+    ///
+    /// Initial: InitialToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn initial(
+        &mut self,
+        _initial_token: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let initial_token = pop_item!(self, initial_token, InitialToken, context);
+        let initial_built = InitialBuilder::default()
+            .initial_token((&initial_token).try_into().into_diagnostic()?)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.initial(&initial_built)?;
+        self.push(ASTType::Initial(initial_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 263:
     ///
     /// Inout: InoutToken : VerylToken;
     ///
@@ -14221,7 +16691,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 231:
+    /// Semantic action for production 264:
     ///
     /// Input: InputToken : VerylToken;
     ///
@@ -14244,7 +16714,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 232:
+    /// Semantic action for production 265:
     ///
     /// Inst: InstToken : VerylToken;
     ///
@@ -14267,7 +16737,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 233:
+    /// Semantic action for production 266:
     ///
     /// Interface: InterfaceToken : VerylToken;
     ///
@@ -14290,7 +16760,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 234:
+    /// Semantic action for production 267:
     ///
     /// Localparam: LocalparamToken : VerylToken;
     ///
@@ -14313,7 +16783,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 235:
+    /// Semantic action for production 268:
     ///
     /// Logic: LogicToken : VerylToken;
     ///
@@ -14336,7 +16806,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 236:
+    /// Semantic action for production 269:
     ///
     /// Modport: ModportToken : VerylToken;
     ///
@@ -14359,7 +16829,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 237:
+    /// Semantic action for production 270:
     ///
     /// Module: ModuleToken : VerylToken;
     ///
@@ -14382,7 +16852,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 238:
+    /// Semantic action for production 271:
     ///
     /// Negedge: NegedgeToken : VerylToken;
     ///
@@ -14405,7 +16875,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 239:
+    /// Semantic action for production 272:
     ///
     /// Output: OutputToken : VerylToken;
     ///
@@ -14428,7 +16898,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 240:
+    /// Semantic action for production 273:
     ///
     /// Package: PackageToken : VerylToken;
     ///
@@ -14451,7 +16921,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 241:
+    /// Semantic action for production 274:
     ///
     /// Parameter: ParameterToken : VerylToken;
     ///
@@ -14474,7 +16944,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 242:
+    /// Semantic action for production 275:
     ///
     /// Posedge: PosedgeToken : VerylToken;
     ///
@@ -14497,7 +16967,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 243:
+    /// Semantic action for production 276:
     ///
     /// Ref: RefToken : VerylToken;
     ///
@@ -14520,7 +16990,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 244:
+    /// Semantic action for production 277:
     ///
     /// Repeat: RepeatToken : VerylToken;
     ///
@@ -14543,7 +17013,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 245:
+    /// Semantic action for production 278:
     ///
     /// Return: ReturnToken : VerylToken;
     ///
@@ -14566,7 +17036,30 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 246:
+    /// Semantic action for production 279:
+    ///
+    /// Signed: SignedToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn signed(
+        &mut self,
+        _signed_token: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let signed_token = pop_item!(self, signed_token, SignedToken, context);
+        let signed_built = SignedBuilder::default()
+            .signed_token((&signed_token).try_into().into_diagnostic()?)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.signed(&signed_built)?;
+        self.push(ASTType::Signed(signed_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 280:
     ///
     /// Step: StepToken : VerylToken;
     ///
@@ -14589,7 +17082,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 247:
+    /// Semantic action for production 281:
     ///
     /// Struct: StructToken : VerylToken;
     ///
@@ -14612,7 +17105,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 248:
+    /// Semantic action for production 282:
     ///
     /// SyncHigh: SyncHighToken : VerylToken;
     ///
@@ -14635,7 +17128,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 249:
+    /// Semantic action for production 283:
     ///
     /// SyncLow: SyncLowToken : VerylToken;
     ///
@@ -14658,7 +17151,30 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 250:
+    /// Semantic action for production 284:
+    ///
+    /// Test: TestToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn test(
+        &mut self,
+        _test_token: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let test_token = pop_item!(self, test_token, TestToken, context);
+        let test_built = TestBuilder::default()
+            .test_token((&test_token).try_into().into_diagnostic()?)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.test(&test_built)?;
+        self.push(ASTType::Test(test_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 285:
     ///
     /// Tri: TriToken : VerylToken;
     ///
@@ -14681,7 +17197,30 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 251:
+    /// Semantic action for production 286:
+    ///
+    /// Typ: TypeToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn typ(
+        &mut self,
+        _type_token: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let type_token = pop_item!(self, type_token, TypeToken, context);
+        let typ_built = TypBuilder::default()
+            .type_token((&type_token).try_into().into_diagnostic()?)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.typ(&typ_built)?;
+        self.push(ASTType::Typ(typ_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 287:
     ///
     /// U32: U32Token : VerylToken;
     ///
@@ -14704,7 +17243,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 252:
+    /// Semantic action for production 288:
     ///
     /// U64: U64Token : VerylToken;
     ///
@@ -14727,35 +17266,110 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 253:
+    /// Semantic action for production 289:
     ///
-    /// Var: VarToken : VerylToken;
+    /// Union: UnionToken : VerylToken;
     ///
     #[parol_runtime::function_name::named]
-    fn var(
+    fn r#union(
         &mut self,
-        _var_token: &ParseTreeStackEntry<'t>,
+        _union_token: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let var_token = pop_item!(self, var_token, VarToken, context);
-        let var_built = VarBuilder::default()
-            .var_token((&var_token).try_into().into_diagnostic()?)
+        let union_token = pop_item!(self, union_token, UnionToken, context);
+        let r#union_built = UnionBuilder::default()
+            .union_token((&union_token).try_into().into_diagnostic()?)
             .build()
             .into_diagnostic()?;
         // Calling user action here
-        self.user_grammar.var(&var_built)?;
-        self.push(ASTType::Var(var_built), context);
+        self.user_grammar.r#union(&r#union_built)?;
+        self.push(ASTType::Union(r#union_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 254:
+    /// Semantic action for production 290:
     ///
-    /// Identifier: IdentifierToken : VerylToken;
+    /// Unpacked: UnpackedToken : VerylToken;
     ///
     #[parol_runtime::function_name::named]
-    fn identifier(
+    fn unpacked(
+        &mut self,
+        _unpacked_token: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let unpacked_token = pop_item!(self, unpacked_token, UnpackedToken, context);
+        let unpacked_built = UnpackedBuilder::default()
+            .unpacked_token((&unpacked_token).try_into().into_diagnostic()?)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.unpacked(&unpacked_built)?;
+        self.push(ASTType::Unpacked(unpacked_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 291:
+    ///
+    /// Var: VarToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn var(
+        &mut self,
+        _var_token: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let var_token = pop_item!(self, var_token, VarToken, context);
+        let var_built = VarBuilder::default()
+            .var_token((&var_token).try_into().into_diagnostic()?)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.var(&var_built)?;
+        self.push(ASTType::Var(var_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 292:
+    ///
+    /// DollarIdentifier: DollarIdentifierToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn dollar_identifier(
+        &mut self,
+        _dollar_identifier_token: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let dollar_identifier_token = pop_item!(
+            self,
+            dollar_identifier_token,
+            DollarIdentifierToken,
+            context
+        );
+        let dollar_identifier_built = DollarIdentifierBuilder::default()
+            .dollar_identifier_token((&dollar_identifier_token).try_into().into_diagnostic()?)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .dollar_identifier(&dollar_identifier_built)?;
+        self.push(ASTType::DollarIdentifier(dollar_identifier_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 293:
+    ///
+    /// Identifier: IdentifierToken : VerylToken;
+    ///
+    #[parol_runtime::function_name::named]
+    fn identifier(
         &mut self,
         _identifier_token: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -14773,7 +17387,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 255:
+    /// Semantic action for production 294:
     ///
     /// Number: IntegralNumber;
     ///
@@ -14797,7 +17411,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 256:
+    /// Semantic action for production 295:
     ///
     /// Number: RealNumber;
     ///
@@ -14821,7 +17435,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 257:
+    /// Semantic action for production 296:
     ///
     /// IntegralNumber: Based;
     ///
@@ -14846,7 +17460,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 258:
+    /// Semantic action for production 297:
     ///
     /// IntegralNumber: BaseLess;
     ///
@@ -14871,7 +17485,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 259:
+    /// Semantic action for production 298:
     ///
     /// IntegralNumber: AllBit;
     ///
@@ -14896,7 +17510,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 260:
+    /// Semantic action for production 299:
     ///
     /// RealNumber: FixedPoint;
     ///
@@ -14920,7 +17534,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 261:
+    /// Semantic action for production 300:
     ///
     /// RealNumber: Exponent;
     ///
@@ -14944,7 +17558,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 262:
+    /// Semantic action for production 301:
     ///
     /// HierarchicalIdentifier: Identifier HierarchicalIdentifierList /* Vec */ HierarchicalIdentifierList0 /* Vec */;
     ///
@@ -14987,7 +17601,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 263:
+    /// Semantic action for production 302:
     ///
     /// HierarchicalIdentifierList0 /* Vec<T>::Push */: Dot Identifier HierarchicalIdentifierList0List /* Vec */ HierarchicalIdentifierList0;
     ///
@@ -15031,7 +17645,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 264:
+    /// Semantic action for production 303:
     ///
     /// HierarchicalIdentifierList0List /* Vec<T>::Push */: Range HierarchicalIdentifierList0List;
     ///
@@ -15065,7 +17679,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 265:
+    /// Semantic action for production 304:
     ///
     /// HierarchicalIdentifierList0List /* Vec<T>::New */: ;
     ///
@@ -15084,7 +17698,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 266:
+    /// Semantic action for production 305:
     ///
     /// HierarchicalIdentifierList0 /* Vec<T>::New */: ;
     ///
@@ -15103,7 +17717,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 267:
+    /// Semantic action for production 306:
     ///
     /// HierarchicalIdentifierList /* Vec<T>::Push */: Range HierarchicalIdentifierList;
     ///
@@ -15136,7 +17750,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 268:
+    /// Semantic action for production 307:
     ///
     /// HierarchicalIdentifierList /* Vec<T>::New */: ;
     ///
@@ -15155,7 +17769,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 269:
+    /// Semantic action for production 308:
     ///
     /// ScopedIdentifier: Identifier ScopedIdentifierList /* Vec */;
     ///
@@ -15183,7 +17797,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 270:
+    /// Semantic action for production 309:
     ///
     /// ScopedIdentifierList /* Vec<T>::Push */: ColonColon Identifier ScopedIdentifierList;
     ///
@@ -15215,7 +17829,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 271:
+    /// Semantic action for production 310:
     ///
     /// ScopedIdentifierList /* Vec<T>::New */: ;
     ///
@@ -15231,7 +17845,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 272:
+    /// Semantic action for production 311:
     ///
     /// ModportIdentifier: Identifier Dot Identifier;
     ///
@@ -15264,7 +17878,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 273:
+    /// Semantic action for production 312:
     ///
     /// ScopedOrHierIdentifier: Identifier ScopedOrHierIdentifierGroup;
     ///
@@ -15299,7 +17913,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 274:
+    /// Semantic action for production 313:
     ///
     /// ScopedOrHierIdentifierGroup: ColonColon Identifier ScopedOrHierIdentifierGroupList /* Vec */;
     ///
@@ -15338,7 +17952,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 275:
+    /// Semantic action for production 314:
     ///
     /// ScopedOrHierIdentifierGroupList /* Vec<T>::Push */: ColonColon Identifier ScopedOrHierIdentifierGroupList;
     ///
@@ -15375,7 +17989,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 276:
+    /// Semantic action for production 315:
     ///
     /// ScopedOrHierIdentifierGroupList /* Vec<T>::New */: ;
     ///
@@ -15394,7 +18008,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 277:
+    /// Semantic action for production 316:
     ///
     /// ScopedOrHierIdentifierGroup: ScopedOrHierIdentifierGroupList0 /* Vec */ ScopedOrHierIdentifierGroupList1 /* Vec */;
     ///
@@ -15432,7 +18046,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 278:
+    /// Semantic action for production 317:
     ///
     /// ScopedOrHierIdentifierGroupList1 /* Vec<T>::Push */: Dot Identifier ScopedOrHierIdentifierGroupList1List /* Vec */ ScopedOrHierIdentifierGroupList1;
     ///
@@ -15479,7 +18093,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 279:
+    /// Semantic action for production 318:
     ///
     /// ScopedOrHierIdentifierGroupList1List /* Vec<T>::Push */: Range ScopedOrHierIdentifierGroupList1List;
     ///
@@ -15516,7 +18130,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 280:
+    /// Semantic action for production 319:
     ///
     /// ScopedOrHierIdentifierGroupList1List /* Vec<T>::New */: ;
     ///
@@ -15537,7 +18151,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 281:
+    /// Semantic action for production 320:
     ///
     /// ScopedOrHierIdentifierGroupList1 /* Vec<T>::New */: ;
     ///
@@ -15558,7 +18172,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 282:
+    /// Semantic action for production 321:
     ///
     /// ScopedOrHierIdentifierGroupList0 /* Vec<T>::Push */: Range ScopedOrHierIdentifierGroupList0;
     ///
@@ -15592,7 +18206,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 283:
+    /// Semantic action for production 322:
     ///
     /// ScopedOrHierIdentifierGroupList0 /* Vec<T>::New */: ;
     ///
@@ -15613,74 +18227,55 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 284:
+    /// Semantic action for production 323:
     ///
-    /// Expression: Expression01 ExpressionList /* Vec */;
+    /// Expression: IfExpression;
     ///
     #[parol_runtime::function_name::named]
-    fn expression(
+    fn expression_0(
         &mut self,
-        _expression01: &ParseTreeStackEntry<'t>,
-        _expression_list: &ParseTreeStackEntry<'t>,
+        _if_expression: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let expression_list = pop_and_reverse_item!(self, expression_list, ExpressionList, context);
-        let expression01 = pop_item!(self, expression01, Expression01, context);
-        let expression_built = ExpressionBuilder::default()
-            .expression01(Box::new(expression01))
-            .expression_list(expression_list)
+        let if_expression = pop_item!(self, if_expression, IfExpression, context);
+        let expression_0_built = ExpressionIfExpressionBuilder::default()
+            .if_expression(Box::new(if_expression))
             .build()
             .into_diagnostic()?;
+        let expression_0_built = Expression::IfExpression(expression_0_built);
         // Calling user action here
-        self.user_grammar.expression(&expression_built)?;
-        self.push(ASTType::Expression(expression_built), context);
+        self.user_grammar.expression(&expression_0_built)?;
+        self.push(ASTType::Expression(expression_0_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 285:
+    /// Semantic action for production 324:
     ///
-    /// ExpressionList /* Vec<T>::Push */: Operator01 Expression01 ExpressionList;
+    /// Expression: Expression01;
     ///
     #[parol_runtime::function_name::named]
-    fn expression_list_0(
+    fn expression_1(
         &mut self,
-        _operator01: &ParseTreeStackEntry<'t>,
         _expression01: &ParseTreeStackEntry<'t>,
-        _expression_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let mut expression_list = pop_item!(self, expression_list, ExpressionList, context);
         let expression01 = pop_item!(self, expression01, Expression01, context);
-        let operator01 = pop_item!(self, operator01, Operator01, context);
-        let expression_list_0_built = ExpressionListBuilder::default()
+        let expression_1_built = ExpressionExpression01Builder::default()
             .expression01(Box::new(expression01))
-            .operator01(Box::new(operator01))
             .build()
             .into_diagnostic()?;
-        // Add an element to the vector
-        expression_list.push(expression_list_0_built);
-        self.push(ASTType::ExpressionList(expression_list), context);
-        Ok(())
-    }
-
-    /// Semantic action for production 286:
-    ///
-    /// ExpressionList /* Vec<T>::New */: ;
-    ///
-    #[parol_runtime::function_name::named]
-    fn expression_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
-        let context = function_name!();
-        trace!("{}", self.trace_item_stack(context));
-        let expression_list_1_built = Vec::new();
-        self.push(ASTType::ExpressionList(expression_list_1_built), context);
+        let expression_1_built = Expression::Expression01(expression_1_built);
+        // Calling user action here
+        self.user_grammar.expression(&expression_1_built)?;
+        self.push(ASTType::Expression(expression_1_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 287:
+    /// Semantic action for production 325:
     ///
     /// Expression01: Expression02 Expression01List /* Vec */;
     ///
@@ -15707,14 +18302,14 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 288:
+    /// Semantic action for production 326:
     ///
-    /// Expression01List /* Vec<T>::Push */: Operator02 Expression02 Expression01List;
+    /// Expression01List /* Vec<T>::Push */: Operator01 Expression02 Expression01List;
     ///
     #[parol_runtime::function_name::named]
     fn expression01_list_0(
         &mut self,
-        _operator02: &ParseTreeStackEntry<'t>,
+        _operator01: &ParseTreeStackEntry<'t>,
         _expression02: &ParseTreeStackEntry<'t>,
         _expression01_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -15723,10 +18318,10 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         trace!("{}", self.trace_item_stack(context));
         let mut expression01_list = pop_item!(self, expression01_list, Expression01List, context);
         let expression02 = pop_item!(self, expression02, Expression02, context);
-        let operator02 = pop_item!(self, operator02, Operator02, context);
+        let operator01 = pop_item!(self, operator01, Operator01, context);
         let expression01_list_0_built = Expression01ListBuilder::default()
             .expression02(Box::new(expression02))
-            .operator02(Box::new(operator02))
+            .operator01(Box::new(operator01))
             .build()
             .into_diagnostic()?;
         // Add an element to the vector
@@ -15735,7 +18330,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 289:
+    /// Semantic action for production 327:
     ///
     /// Expression01List /* Vec<T>::New */: ;
     ///
@@ -15751,7 +18346,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 290:
+    /// Semantic action for production 328:
     ///
     /// Expression02: Expression03 Expression02List /* Vec */;
     ///
@@ -15778,14 +18373,14 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 291:
+    /// Semantic action for production 329:
     ///
-    /// Expression02List /* Vec<T>::Push */: Operator03 Expression03 Expression02List;
+    /// Expression02List /* Vec<T>::Push */: Operator02 Expression03 Expression02List;
     ///
     #[parol_runtime::function_name::named]
     fn expression02_list_0(
         &mut self,
-        _operator03: &ParseTreeStackEntry<'t>,
+        _operator02: &ParseTreeStackEntry<'t>,
         _expression03: &ParseTreeStackEntry<'t>,
         _expression02_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -15794,10 +18389,10 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         trace!("{}", self.trace_item_stack(context));
         let mut expression02_list = pop_item!(self, expression02_list, Expression02List, context);
         let expression03 = pop_item!(self, expression03, Expression03, context);
-        let operator03 = pop_item!(self, operator03, Operator03, context);
+        let operator02 = pop_item!(self, operator02, Operator02, context);
         let expression02_list_0_built = Expression02ListBuilder::default()
             .expression03(Box::new(expression03))
-            .operator03(Box::new(operator03))
+            .operator02(Box::new(operator02))
             .build()
             .into_diagnostic()?;
         // Add an element to the vector
@@ -15806,7 +18401,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 292:
+    /// Semantic action for production 330:
     ///
     /// Expression02List /* Vec<T>::New */: ;
     ///
@@ -15822,7 +18417,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 293:
+    /// Semantic action for production 331:
     ///
     /// Expression03: Expression04 Expression03List /* Vec */;
     ///
@@ -15849,14 +18444,14 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 294:
+    /// Semantic action for production 332:
     ///
-    /// Expression03List /* Vec<T>::Push */: Operator04 Expression04 Expression03List;
+    /// Expression03List /* Vec<T>::Push */: Operator03 Expression04 Expression03List;
     ///
     #[parol_runtime::function_name::named]
     fn expression03_list_0(
         &mut self,
-        _operator04: &ParseTreeStackEntry<'t>,
+        _operator03: &ParseTreeStackEntry<'t>,
         _expression04: &ParseTreeStackEntry<'t>,
         _expression03_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -15865,10 +18460,10 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         trace!("{}", self.trace_item_stack(context));
         let mut expression03_list = pop_item!(self, expression03_list, Expression03List, context);
         let expression04 = pop_item!(self, expression04, Expression04, context);
-        let operator04 = pop_item!(self, operator04, Operator04, context);
+        let operator03 = pop_item!(self, operator03, Operator03, context);
         let expression03_list_0_built = Expression03ListBuilder::default()
             .expression04(Box::new(expression04))
-            .operator04(Box::new(operator04))
+            .operator03(Box::new(operator03))
             .build()
             .into_diagnostic()?;
         // Add an element to the vector
@@ -15877,7 +18472,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 295:
+    /// Semantic action for production 333:
     ///
     /// Expression03List /* Vec<T>::New */: ;
     ///
@@ -15893,7 +18488,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 296:
+    /// Semantic action for production 334:
     ///
     /// Expression04: Expression05 Expression04List /* Vec */;
     ///
@@ -15920,14 +18515,14 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 297:
+    /// Semantic action for production 335:
     ///
-    /// Expression04List /* Vec<T>::Push */: Operator05 Expression05 Expression04List;
+    /// Expression04List /* Vec<T>::Push */: Operator04 Expression05 Expression04List;
     ///
     #[parol_runtime::function_name::named]
     fn expression04_list_0(
         &mut self,
-        _operator05: &ParseTreeStackEntry<'t>,
+        _operator04: &ParseTreeStackEntry<'t>,
         _expression05: &ParseTreeStackEntry<'t>,
         _expression04_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -15936,10 +18531,10 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         trace!("{}", self.trace_item_stack(context));
         let mut expression04_list = pop_item!(self, expression04_list, Expression04List, context);
         let expression05 = pop_item!(self, expression05, Expression05, context);
-        let operator05 = pop_item!(self, operator05, Operator05, context);
+        let operator04 = pop_item!(self, operator04, Operator04, context);
         let expression04_list_0_built = Expression04ListBuilder::default()
             .expression05(Box::new(expression05))
-            .operator05(Box::new(operator05))
+            .operator04(Box::new(operator04))
             .build()
             .into_diagnostic()?;
         // Add an element to the vector
@@ -15948,7 +18543,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 298:
+    /// Semantic action for production 336:
     ///
     /// Expression04List /* Vec<T>::New */: ;
     ///
@@ -15964,7 +18559,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 299:
+    /// Semantic action for production 337:
     ///
     /// Expression05: Expression06 Expression05List /* Vec */;
     ///
@@ -15991,14 +18586,14 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 300:
+    /// Semantic action for production 338:
     ///
-    /// Expression05List /* Vec<T>::Push */: Operator06 Expression06 Expression05List;
+    /// Expression05List /* Vec<T>::Push */: Operator05 Expression06 Expression05List;
     ///
     #[parol_runtime::function_name::named]
     fn expression05_list_0(
         &mut self,
-        _operator06: &ParseTreeStackEntry<'t>,
+        _operator05: &ParseTreeStackEntry<'t>,
         _expression06: &ParseTreeStackEntry<'t>,
         _expression05_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -16007,10 +18602,10 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         trace!("{}", self.trace_item_stack(context));
         let mut expression05_list = pop_item!(self, expression05_list, Expression05List, context);
         let expression06 = pop_item!(self, expression06, Expression06, context);
-        let operator06 = pop_item!(self, operator06, Operator06, context);
+        let operator05 = pop_item!(self, operator05, Operator05, context);
         let expression05_list_0_built = Expression05ListBuilder::default()
             .expression06(Box::new(expression06))
-            .operator06(Box::new(operator06))
+            .operator05(Box::new(operator05))
             .build()
             .into_diagnostic()?;
         // Add an element to the vector
@@ -16019,7 +18614,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 301:
+    /// Semantic action for production 339:
     ///
     /// Expression05List /* Vec<T>::New */: ;
     ///
@@ -16035,7 +18630,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 302:
+    /// Semantic action for production 340:
     ///
     /// Expression06: Expression07 Expression06List /* Vec */;
     ///
@@ -16062,14 +18657,14 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 303:
+    /// Semantic action for production 341:
     ///
-    /// Expression06List /* Vec<T>::Push */: Operator07 Expression07 Expression06List;
+    /// Expression06List /* Vec<T>::Push */: Operator06 Expression07 Expression06List;
     ///
     #[parol_runtime::function_name::named]
     fn expression06_list_0(
         &mut self,
-        _operator07: &ParseTreeStackEntry<'t>,
+        _operator06: &ParseTreeStackEntry<'t>,
         _expression07: &ParseTreeStackEntry<'t>,
         _expression06_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -16078,10 +18673,10 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         trace!("{}", self.trace_item_stack(context));
         let mut expression06_list = pop_item!(self, expression06_list, Expression06List, context);
         let expression07 = pop_item!(self, expression07, Expression07, context);
-        let operator07 = pop_item!(self, operator07, Operator07, context);
+        let operator06 = pop_item!(self, operator06, Operator06, context);
         let expression06_list_0_built = Expression06ListBuilder::default()
             .expression07(Box::new(expression07))
-            .operator07(Box::new(operator07))
+            .operator06(Box::new(operator06))
             .build()
             .into_diagnostic()?;
         // Add an element to the vector
@@ -16090,7 +18685,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 304:
+    /// Semantic action for production 342:
     ///
     /// Expression06List /* Vec<T>::New */: ;
     ///
@@ -16106,7 +18701,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 305:
+    /// Semantic action for production 343:
     ///
     /// Expression07: Expression08 Expression07List /* Vec */;
     ///
@@ -16133,14 +18728,14 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 306:
+    /// Semantic action for production 344:
     ///
-    /// Expression07List /* Vec<T>::Push */: Operator08 Expression08 Expression07List;
+    /// Expression07List /* Vec<T>::Push */: Operator07 Expression08 Expression07List;
     ///
     #[parol_runtime::function_name::named]
     fn expression07_list_0(
         &mut self,
-        _operator08: &ParseTreeStackEntry<'t>,
+        _operator07: &ParseTreeStackEntry<'t>,
         _expression08: &ParseTreeStackEntry<'t>,
         _expression07_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -16149,10 +18744,10 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         trace!("{}", self.trace_item_stack(context));
         let mut expression07_list = pop_item!(self, expression07_list, Expression07List, context);
         let expression08 = pop_item!(self, expression08, Expression08, context);
-        let operator08 = pop_item!(self, operator08, Operator08, context);
+        let operator07 = pop_item!(self, operator07, Operator07, context);
         let expression07_list_0_built = Expression07ListBuilder::default()
             .expression08(Box::new(expression08))
-            .operator08(Box::new(operator08))
+            .operator07(Box::new(operator07))
             .build()
             .into_diagnostic()?;
         // Add an element to the vector
@@ -16161,7 +18756,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 307:
+    /// Semantic action for production 345:
     ///
     /// Expression07List /* Vec<T>::New */: ;
     ///
@@ -16177,7 +18772,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 308:
+    /// Semantic action for production 346:
     ///
     /// Expression08: Expression09 Expression08List /* Vec */;
     ///
@@ -16204,14 +18799,14 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 309:
+    /// Semantic action for production 347:
     ///
-    /// Expression08List /* Vec<T>::Push */: Operator09 Expression09 Expression08List;
+    /// Expression08List /* Vec<T>::Push */: Operator08 Expression09 Expression08List;
     ///
     #[parol_runtime::function_name::named]
     fn expression08_list_0(
         &mut self,
-        _operator09: &ParseTreeStackEntry<'t>,
+        _operator08: &ParseTreeStackEntry<'t>,
         _expression09: &ParseTreeStackEntry<'t>,
         _expression08_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -16220,10 +18815,10 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         trace!("{}", self.trace_item_stack(context));
         let mut expression08_list = pop_item!(self, expression08_list, Expression08List, context);
         let expression09 = pop_item!(self, expression09, Expression09, context);
-        let operator09 = pop_item!(self, operator09, Operator09, context);
+        let operator08 = pop_item!(self, operator08, Operator08, context);
         let expression08_list_0_built = Expression08ListBuilder::default()
             .expression09(Box::new(expression09))
-            .operator09(Box::new(operator09))
+            .operator08(Box::new(operator08))
             .build()
             .into_diagnostic()?;
         // Add an element to the vector
@@ -16232,7 +18827,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 310:
+    /// Semantic action for production 348:
     ///
     /// Expression08List /* Vec<T>::New */: ;
     ///
@@ -16248,7 +18843,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 311:
+    /// Semantic action for production 349:
     ///
     /// Expression09: Expression10 Expression09List /* Vec */;
     ///
@@ -16275,14 +18870,14 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 312:
+    /// Semantic action for production 350:
     ///
-    /// Expression09List /* Vec<T>::Push */: Expression09ListGroup Expression10 Expression09List;
+    /// Expression09List /* Vec<T>::Push */: Operator09 Expression10 Expression09List;
     ///
     #[parol_runtime::function_name::named]
     fn expression09_list_0(
         &mut self,
-        _expression09_list_group: &ParseTreeStackEntry<'t>,
+        _operator09: &ParseTreeStackEntry<'t>,
         _expression10: &ParseTreeStackEntry<'t>,
         _expression09_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -16291,15 +18886,10 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         trace!("{}", self.trace_item_stack(context));
         let mut expression09_list = pop_item!(self, expression09_list, Expression09List, context);
         let expression10 = pop_item!(self, expression10, Expression10, context);
-        let expression09_list_group = pop_item!(
-            self,
-            expression09_list_group,
-            Expression09ListGroup,
-            context
-        );
+        let operator09 = pop_item!(self, operator09, Operator09, context);
         let expression09_list_0_built = Expression09ListBuilder::default()
             .expression10(Box::new(expression10))
-            .expression09_list_group(Box::new(expression09_list_group))
+            .operator09(Box::new(operator09))
             .build()
             .into_diagnostic()?;
         // Add an element to the vector
@@ -16308,64 +18898,12 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 313:
+    /// Semantic action for production 351:
     ///
-    /// Expression09ListGroup: Operator10;
+    /// Expression09List /* Vec<T>::New */: ;
     ///
     #[parol_runtime::function_name::named]
-    fn expression09_list_group_0(
-        &mut self,
-        _operator10: &ParseTreeStackEntry<'t>,
-        _parse_tree: &Tree<ParseTreeType<'t>>,
-    ) -> Result<()> {
-        let context = function_name!();
-        trace!("{}", self.trace_item_stack(context));
-        let operator10 = pop_item!(self, operator10, Operator10, context);
-        let expression09_list_group_0_built = Expression09ListGroupOperator10Builder::default()
-            .operator10(Box::new(operator10))
-            .build()
-            .into_diagnostic()?;
-        let expression09_list_group_0_built =
-            Expression09ListGroup::Operator10(expression09_list_group_0_built);
-        self.push(
-            ASTType::Expression09ListGroup(expression09_list_group_0_built),
-            context,
-        );
-        Ok(())
-    }
-
-    /// Semantic action for production 314:
-    ///
-    /// Expression09ListGroup: Star;
-    ///
-    #[parol_runtime::function_name::named]
-    fn expression09_list_group_1(
-        &mut self,
-        _star: &ParseTreeStackEntry<'t>,
-        _parse_tree: &Tree<ParseTreeType<'t>>,
-    ) -> Result<()> {
-        let context = function_name!();
-        trace!("{}", self.trace_item_stack(context));
-        let star = pop_item!(self, star, Star, context);
-        let expression09_list_group_1_built = Expression09ListGroupStarBuilder::default()
-            .star(Box::new(star))
-            .build()
-            .into_diagnostic()?;
-        let expression09_list_group_1_built =
-            Expression09ListGroup::Star(expression09_list_group_1_built);
-        self.push(
-            ASTType::Expression09ListGroup(expression09_list_group_1_built),
-            context,
-        );
-        Ok(())
-    }
-
-    /// Semantic action for production 315:
-    ///
-    /// Expression09List /* Vec<T>::New */: ;
-    ///
-    #[parol_runtime::function_name::named]
-    fn expression09_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+    fn expression09_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let expression09_list_1_built = Vec::new();
@@ -16376,7 +18914,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 316:
+    /// Semantic action for production 352:
     ///
     /// Expression10: Expression11 Expression10List /* Vec */;
     ///
@@ -16403,14 +18941,14 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 317:
+    /// Semantic action for production 353:
     ///
-    /// Expression10List /* Vec<T>::Push */: Operator11 Expression11 Expression10List;
+    /// Expression10List /* Vec<T>::Push */: Expression10ListGroup Expression11 Expression10List;
     ///
     #[parol_runtime::function_name::named]
     fn expression10_list_0(
         &mut self,
-        _operator11: &ParseTreeStackEntry<'t>,
+        _expression10_list_group: &ParseTreeStackEntry<'t>,
         _expression11: &ParseTreeStackEntry<'t>,
         _expression10_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -16419,10 +18957,15 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         trace!("{}", self.trace_item_stack(context));
         let mut expression10_list = pop_item!(self, expression10_list, Expression10List, context);
         let expression11 = pop_item!(self, expression11, Expression11, context);
-        let operator11 = pop_item!(self, operator11, Operator11, context);
+        let expression10_list_group = pop_item!(
+            self,
+            expression10_list_group,
+            Expression10ListGroup,
+            context
+        );
         let expression10_list_0_built = Expression10ListBuilder::default()
             .expression11(Box::new(expression11))
-            .operator11(Box::new(operator11))
+            .expression10_list_group(Box::new(expression10_list_group))
             .build()
             .into_diagnostic()?;
         // Add an element to the vector
@@ -16431,7 +18974,59 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 318:
+    /// Semantic action for production 354:
+    ///
+    /// Expression10ListGroup: Operator10;
+    ///
+    #[parol_runtime::function_name::named]
+    fn expression10_list_group_0(
+        &mut self,
+        _operator10: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let operator10 = pop_item!(self, operator10, Operator10, context);
+        let expression10_list_group_0_built = Expression10ListGroupOperator10Builder::default()
+            .operator10(Box::new(operator10))
+            .build()
+            .into_diagnostic()?;
+        let expression10_list_group_0_built =
+            Expression10ListGroup::Operator10(expression10_list_group_0_built);
+        self.push(
+            ASTType::Expression10ListGroup(expression10_list_group_0_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 355:
+    ///
+    /// Expression10ListGroup: Star;
+    ///
+    #[parol_runtime::function_name::named]
+    fn expression10_list_group_1(
+        &mut self,
+        _star: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let star = pop_item!(self, star, Star, context);
+        let expression10_list_group_1_built = Expression10ListGroupStarBuilder::default()
+            .star(Box::new(star))
+            .build()
+            .into_diagnostic()?;
+        let expression10_list_group_1_built =
+            Expression10ListGroup::Star(expression10_list_group_1_built);
+        self.push(
+            ASTType::Expression10ListGroup(expression10_list_group_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 356:
     ///
     /// Expression10List /* Vec<T>::New */: ;
     ///
@@ -16447,25 +19042,25 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 319:
+    /// Semantic action for production 357:
     ///
-    /// Expression11: Expression11List /* Vec */ Factor;
+    /// Expression11: Expression12 Expression11List /* Vec */;
     ///
     #[parol_runtime::function_name::named]
     fn expression11(
         &mut self,
+        _expression12: &ParseTreeStackEntry<'t>,
         _expression11_list: &ParseTreeStackEntry<'t>,
-        _factor: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let factor = pop_item!(self, factor, Factor, context);
         let expression11_list =
             pop_and_reverse_item!(self, expression11_list, Expression11List, context);
+        let expression12 = pop_item!(self, expression12, Expression12, context);
         let expression11_built = Expression11Builder::default()
+            .expression12(Box::new(expression12))
             .expression11_list(expression11_list)
-            .factor(Box::new(factor))
             .build()
             .into_diagnostic()?;
         // Calling user action here
@@ -16474,28 +19069,26 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 320:
+    /// Semantic action for production 358:
     ///
-    /// Expression11List /* Vec<T>::Push */: Expression11ListGroup Expression11List;
+    /// Expression11List /* Vec<T>::Push */: Operator11 Expression12 Expression11List;
     ///
     #[parol_runtime::function_name::named]
     fn expression11_list_0(
         &mut self,
-        _expression11_list_group: &ParseTreeStackEntry<'t>,
+        _operator11: &ParseTreeStackEntry<'t>,
+        _expression12: &ParseTreeStackEntry<'t>,
         _expression11_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let mut expression11_list = pop_item!(self, expression11_list, Expression11List, context);
-        let expression11_list_group = pop_item!(
-            self,
-            expression11_list_group,
-            Expression11ListGroup,
-            context
-        );
+        let expression12 = pop_item!(self, expression12, Expression12, context);
+        let operator11 = pop_item!(self, operator11, Operator11, context);
         let expression11_list_0_built = Expression11ListBuilder::default()
-            .expression11_list_group(Box::new(expression11_list_group))
+            .expression12(Box::new(expression12))
+            .operator11(Box::new(operator11))
             .build()
             .into_diagnostic()?;
         // Add an element to the vector
@@ -16504,12 +19097,88 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 321:
+    /// Semantic action for production 359:
+    ///
+    /// Expression11List /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn expression11_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let expression11_list_1_built = Vec::new();
+        self.push(
+            ASTType::Expression11List(expression11_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 360:
+    ///
+    /// Expression12: Expression12List /* Vec */ Factor Expression12Opt /* Option */;
+    ///
+    #[parol_runtime::function_name::named]
+    fn expression12(
+        &mut self,
+        _expression12_list: &ParseTreeStackEntry<'t>,
+        _factor: &ParseTreeStackEntry<'t>,
+        _expression12_opt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let expression12_opt = pop_item!(self, expression12_opt, Expression12Opt, context);
+        let factor = pop_item!(self, factor, Factor, context);
+        let expression12_list =
+            pop_and_reverse_item!(self, expression12_list, Expression12List, context);
+        let expression12_built = Expression12Builder::default()
+            .expression12_list(expression12_list)
+            .factor(Box::new(factor))
+            .expression12_opt(expression12_opt)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.expression12(&expression12_built)?;
+        self.push(ASTType::Expression12(expression12_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 361:
+    ///
+    /// Expression12List /* Vec<T>::Push */: Expression12ListGroup Expression12List;
+    ///
+    #[parol_runtime::function_name::named]
+    fn expression12_list_0(
+        &mut self,
+        _expression12_list_group: &ParseTreeStackEntry<'t>,
+        _expression12_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut expression12_list = pop_item!(self, expression12_list, Expression12List, context);
+        let expression12_list_group = pop_item!(
+            self,
+            expression12_list_group,
+            Expression12ListGroup,
+            context
+        );
+        let expression12_list_0_built = Expression12ListBuilder::default()
+            .expression12_list_group(Box::new(expression12_list_group))
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        expression12_list.push(expression12_list_0_built);
+        self.push(ASTType::Expression12List(expression12_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 362:
     ///
-    /// Expression11ListGroup: UnaryOperator;
+    /// Expression12ListGroup: UnaryOperator;
     ///
     #[parol_runtime::function_name::named]
-    fn expression11_list_group_0(
+    fn expression12_list_group_0(
         &mut self,
         _unary_operator: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -16517,25 +19186,25 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let unary_operator = pop_item!(self, unary_operator, UnaryOperator, context);
-        let expression11_list_group_0_built = Expression11ListGroupUnaryOperatorBuilder::default()
+        let expression12_list_group_0_built = Expression12ListGroupUnaryOperatorBuilder::default()
             .unary_operator(Box::new(unary_operator))
             .build()
             .into_diagnostic()?;
-        let expression11_list_group_0_built =
-            Expression11ListGroup::UnaryOperator(expression11_list_group_0_built);
+        let expression12_list_group_0_built =
+            Expression12ListGroup::UnaryOperator(expression12_list_group_0_built);
         self.push(
-            ASTType::Expression11ListGroup(expression11_list_group_0_built),
+            ASTType::Expression12ListGroup(expression12_list_group_0_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 322:
+    /// Semantic action for production 363:
     ///
-    /// Expression11ListGroup: Operator09;
+    /// Expression12ListGroup: Operator09;
     ///
     #[parol_runtime::function_name::named]
-    fn expression11_list_group_1(
+    fn expression12_list_group_1(
         &mut self,
         _operator09: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -16543,25 +19212,25 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let operator09 = pop_item!(self, operator09, Operator09, context);
-        let expression11_list_group_1_built = Expression11ListGroupOperator09Builder::default()
+        let expression12_list_group_1_built = Expression12ListGroupOperator09Builder::default()
             .operator09(Box::new(operator09))
             .build()
             .into_diagnostic()?;
-        let expression11_list_group_1_built =
-            Expression11ListGroup::Operator09(expression11_list_group_1_built);
+        let expression12_list_group_1_built =
+            Expression12ListGroup::Operator09(expression12_list_group_1_built);
         self.push(
-            ASTType::Expression11ListGroup(expression11_list_group_1_built),
+            ASTType::Expression12ListGroup(expression12_list_group_1_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 323:
+    /// Semantic action for production 364:
     ///
-    /// Expression11ListGroup: Operator05;
+    /// Expression12ListGroup: Operator05;
     ///
     #[parol_runtime::function_name::named]
-    fn expression11_list_group_2(
+    fn expression12_list_group_2(
         &mut self,
         _operator05: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -16569,25 +19238,25 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let operator05 = pop_item!(self, operator05, Operator05, context);
-        let expression11_list_group_2_built = Expression11ListGroupOperator05Builder::default()
+        let expression12_list_group_2_built = Expression12ListGroupOperator05Builder::default()
             .operator05(Box::new(operator05))
             .build()
             .into_diagnostic()?;
-        let expression11_list_group_2_built =
-            Expression11ListGroup::Operator05(expression11_list_group_2_built);
+        let expression12_list_group_2_built =
+            Expression12ListGroup::Operator05(expression12_list_group_2_built);
         self.push(
-            ASTType::Expression11ListGroup(expression11_list_group_2_built),
+            ASTType::Expression12ListGroup(expression12_list_group_2_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 324:
+    /// Semantic action for production 365:
     ///
-    /// Expression11ListGroup: Operator03;
+    /// Expression12ListGroup: Operator03;
     ///
     #[parol_runtime::function_name::named]
-    fn expression11_list_group_3(
+    fn expression12_list_group_3(
         &mut self,
         _operator03: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -16595,25 +19264,25 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let operator03 = pop_item!(self, operator03, Operator03, context);
-        let expression11_list_group_3_built = Expression11ListGroupOperator03Builder::default()
+        let expression12_list_group_3_built = Expression12ListGroupOperator03Builder::default()
             .operator03(Box::new(operator03))
             .build()
             .into_diagnostic()?;
-        let expression11_list_group_3_built =
-            Expression11ListGroup::Operator03(expression11_list_group_3_built);
+        let expression12_list_group_3_built =
+            Expression12ListGroup::Operator03(expression12_list_group_3_built);
         self.push(
-            ASTType::Expression11ListGroup(expression11_list_group_3_built),
+            ASTType::Expression12ListGroup(expression12_list_group_3_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 325:
+    /// Semantic action for production 366:
     ///
-    /// Expression11ListGroup: Operator04;
+    /// Expression12ListGroup: Operator04;
     ///
     #[parol_runtime::function_name::named]
-    fn expression11_list_group_4(
+    fn expression12_list_group_4(
         &mut self,
         _operator04: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -16621,36 +19290,75 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let operator04 = pop_item!(self, operator04, Operator04, context);
-        let expression11_list_group_4_built = Expression11ListGroupOperator04Builder::default()
+        let expression12_list_group_4_built = Expression12ListGroupOperator04Builder::default()
             .operator04(Box::new(operator04))
             .build()
             .into_diagnostic()?;
-        let expression11_list_group_4_built =
-            Expression11ListGroup::Operator04(expression11_list_group_4_built);
+        let expression12_list_group_4_built =
+            Expression12ListGroup::Operator04(expression12_list_group_4_built);
         self.push(
-            ASTType::Expression11ListGroup(expression11_list_group_4_built),
+            ASTType::Expression12ListGroup(expression12_list_group_4_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 326:
+    /// Semantic action for production 367:
     ///
-    /// Expression11List /* Vec<T>::New */: ;
+    /// Expression12List /* Vec<T>::New */: ;
     ///
     #[parol_runtime::function_name::named]
-    fn expression11_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+    fn expression12_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let expression11_list_1_built = Vec::new();
+        let expression12_list_1_built = Vec::new();
         self.push(
-            ASTType::Expression11List(expression11_list_1_built),
+            ASTType::Expression12List(expression12_list_1_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 327:
+    /// Semantic action for production 368:
+    ///
+    /// Expression12Opt /* Option<T>::Some */: As Type;
+    ///
+    #[parol_runtime::function_name::named]
+    fn expression12_opt_0(
+        &mut self,
+        _as: &ParseTreeStackEntry<'t>,
+        _type: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r#type = pop_item!(self, r#type, Type, context);
+        let r#as = pop_item!(self, r#as, As, context);
+        let expression12_opt_0_built = Expression12OptBuilder::default()
+            .r#as(Box::new(r#as))
+            .r#type(Box::new(r#type))
+            .build()
+            .into_diagnostic()?;
+        self.push(
+            ASTType::Expression12Opt(Some(Box::new(expression12_opt_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 369:
+    ///
+    /// Expression12Opt /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn expression12_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::Expression12Opt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 370:
     ///
     /// Factor: Number;
     ///
@@ -16674,137 +19382,183 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 328:
+    /// Semantic action for production 371:
     ///
-    /// Factor: FactorOpt /* Option */ ScopedOrHierIdentifier FactorOpt0 /* Option */;
+    /// Factor: StringLiteral;
     ///
     #[parol_runtime::function_name::named]
     fn factor_1(
         &mut self,
-        _factor_opt: &ParseTreeStackEntry<'t>,
-        _scoped_or_hier_identifier: &ParseTreeStackEntry<'t>,
-        _factor_opt0: &ParseTreeStackEntry<'t>,
+        _string_literal: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let factor_opt0 = pop_item!(self, factor_opt0, FactorOpt0, context);
-        let scoped_or_hier_identifier = pop_item!(
-            self,
-            scoped_or_hier_identifier,
-            ScopedOrHierIdentifier,
-            context
-        );
-        let factor_opt = pop_item!(self, factor_opt, FactorOpt, context);
-        let factor_1_built = FactorFactorOptScopedOrHierIdentifierFactorOpt0Builder::default()
-            .factor_opt(factor_opt)
-            .scoped_or_hier_identifier(Box::new(scoped_or_hier_identifier))
-            .factor_opt0(factor_opt0)
+        let string_literal = pop_item!(self, string_literal, StringLiteral, context);
+        let factor_1_built = FactorStringLiteralBuilder::default()
+            .string_literal(Box::new(string_literal))
             .build()
             .into_diagnostic()?;
-        let factor_1_built = Factor::FactorOptScopedOrHierIdentifierFactorOpt0(factor_1_built);
+        let factor_1_built = Factor::StringLiteral(factor_1_built);
         // Calling user action here
         self.user_grammar.factor(&factor_1_built)?;
         self.push(ASTType::Factor(factor_1_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 329:
+    /// Semantic action for production 372:
     ///
-    /// Factor: LParen Expression RParen;
+    /// Factor: ScopedOrHierIdentifier FactorOpt /* Option */;
     ///
     #[parol_runtime::function_name::named]
     fn factor_2(
         &mut self,
-        _l_paren: &ParseTreeStackEntry<'t>,
-        _expression: &ParseTreeStackEntry<'t>,
-        _r_paren: &ParseTreeStackEntry<'t>,
+        _scoped_or_hier_identifier: &ParseTreeStackEntry<'t>,
+        _factor_opt: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let r_paren = pop_item!(self, r_paren, RParen, context);
-        let expression = pop_item!(self, expression, Expression, context);
-        let l_paren = pop_item!(self, l_paren, LParen, context);
-        let factor_2_built = FactorLParenExpressionRParenBuilder::default()
-            .l_paren(Box::new(l_paren))
-            .expression(Box::new(expression))
-            .r_paren(Box::new(r_paren))
+        let factor_opt = pop_item!(self, factor_opt, FactorOpt, context);
+        let scoped_or_hier_identifier = pop_item!(
+            self,
+            scoped_or_hier_identifier,
+            ScopedOrHierIdentifier,
+            context
+        );
+        let factor_2_built = FactorScopedOrHierIdentifierFactorOptBuilder::default()
+            .scoped_or_hier_identifier(Box::new(scoped_or_hier_identifier))
+            .factor_opt(factor_opt)
             .build()
             .into_diagnostic()?;
-        let factor_2_built = Factor::LParenExpressionRParen(factor_2_built);
+        let factor_2_built = Factor::ScopedOrHierIdentifierFactorOpt(factor_2_built);
         // Calling user action here
         self.user_grammar.factor(&factor_2_built)?;
         self.push(ASTType::Factor(factor_2_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 330:
+    /// Semantic action for production 373:
     ///
-    /// Factor: LBrace ConcatenationList RBrace;
+    /// Factor: SystemFunctionCall;
     ///
     #[parol_runtime::function_name::named]
     fn factor_3(
         &mut self,
-        _l_brace: &ParseTreeStackEntry<'t>,
-        _concatenation_list: &ParseTreeStackEntry<'t>,
-        _r_brace: &ParseTreeStackEntry<'t>,
+        _system_function_call: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let r_brace = pop_item!(self, r_brace, RBrace, context);
-        let concatenation_list = pop_item!(self, concatenation_list, ConcatenationList, context);
-        let l_brace = pop_item!(self, l_brace, LBrace, context);
-        let factor_3_built = FactorLBraceConcatenationListRBraceBuilder::default()
-            .l_brace(Box::new(l_brace))
-            .concatenation_list(Box::new(concatenation_list))
-            .r_brace(Box::new(r_brace))
+        let system_function_call =
+            pop_item!(self, system_function_call, SystemFunctionCall, context);
+        let factor_3_built = FactorSystemFunctionCallBuilder::default()
+            .system_function_call(Box::new(system_function_call))
             .build()
             .into_diagnostic()?;
-        let factor_3_built = Factor::LBraceConcatenationListRBrace(factor_3_built);
+        let factor_3_built = Factor::SystemFunctionCall(factor_3_built);
         // Calling user action here
         self.user_grammar.factor(&factor_3_built)?;
         self.push(ASTType::Factor(factor_3_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 331:
+    /// Semantic action for production 374:
     ///
-    /// FactorOpt0 /* Option<T>::Some */: LParen FactorOpt1 /* Option */ RParen;
+    /// Factor: LParen Expression RParen;
     ///
     #[parol_runtime::function_name::named]
-    fn factor_opt0_0(
+    fn factor_4(
         &mut self,
         _l_paren: &ParseTreeStackEntry<'t>,
-        _factor_opt1: &ParseTreeStackEntry<'t>,
+        _expression: &ParseTreeStackEntry<'t>,
         _r_paren: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let r_paren = pop_item!(self, r_paren, RParen, context);
-        let factor_opt1 = pop_item!(self, factor_opt1, FactorOpt1, context);
+        let expression = pop_item!(self, expression, Expression, context);
         let l_paren = pop_item!(self, l_paren, LParen, context);
-        let factor_opt0_0_built = FactorOpt0Builder::default()
+        let factor_4_built = FactorLParenExpressionRParenBuilder::default()
             .l_paren(Box::new(l_paren))
-            .factor_opt1(factor_opt1)
+            .expression(Box::new(expression))
+            .r_paren(Box::new(r_paren))
+            .build()
+            .into_diagnostic()?;
+        let factor_4_built = Factor::LParenExpressionRParen(factor_4_built);
+        // Calling user action here
+        self.user_grammar.factor(&factor_4_built)?;
+        self.push(ASTType::Factor(factor_4_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 375:
+    ///
+    /// Factor: LBrace ConcatenationList RBrace;
+    ///
+    #[parol_runtime::function_name::named]
+    fn factor_5(
+        &mut self,
+        _l_brace: &ParseTreeStackEntry<'t>,
+        _concatenation_list: &ParseTreeStackEntry<'t>,
+        _r_brace: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r_brace = pop_item!(self, r_brace, RBrace, context);
+        let concatenation_list = pop_item!(self, concatenation_list, ConcatenationList, context);
+        let l_brace = pop_item!(self, l_brace, LBrace, context);
+        let factor_5_built = FactorLBraceConcatenationListRBraceBuilder::default()
+            .l_brace(Box::new(l_brace))
+            .concatenation_list(Box::new(concatenation_list))
+            .r_brace(Box::new(r_brace))
+            .build()
+            .into_diagnostic()?;
+        let factor_5_built = Factor::LBraceConcatenationListRBrace(factor_5_built);
+        // Calling user action here
+        self.user_grammar.factor(&factor_5_built)?;
+        self.push(ASTType::Factor(factor_5_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 376:
+    ///
+    /// FactorOpt /* Option<T>::Some */: LParen FactorOpt0 /* Option */ RParen;
+    ///
+    #[parol_runtime::function_name::named]
+    fn factor_opt_0(
+        &mut self,
+        _l_paren: &ParseTreeStackEntry<'t>,
+        _factor_opt0: &ParseTreeStackEntry<'t>,
+        _r_paren: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r_paren = pop_item!(self, r_paren, RParen, context);
+        let factor_opt0 = pop_item!(self, factor_opt0, FactorOpt0, context);
+        let l_paren = pop_item!(self, l_paren, LParen, context);
+        let factor_opt_0_built = FactorOptBuilder::default()
+            .l_paren(Box::new(l_paren))
+            .factor_opt0(factor_opt0)
             .r_paren(Box::new(r_paren))
             .build()
             .into_diagnostic()?;
         self.push(
-            ASTType::FactorOpt0(Some(Box::new(factor_opt0_0_built))),
+            ASTType::FactorOpt(Some(Box::new(factor_opt_0_built))),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 332:
+    /// Semantic action for production 377:
     ///
-    /// FactorOpt1 /* Option<T>::Some */: FunctionCallArg;
+    /// FactorOpt0 /* Option<T>::Some */: FunctionCallArg;
     ///
     #[parol_runtime::function_name::named]
-    fn factor_opt1_0(
+    fn factor_opt0_0(
         &mut self,
         _function_call_arg: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -16812,78 +19566,267 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let function_call_arg = pop_item!(self, function_call_arg, FunctionCallArg, context);
-        let factor_opt1_0_built = FactorOpt1Builder::default()
+        let factor_opt0_0_built = FactorOpt0Builder::default()
             .function_call_arg(Box::new(function_call_arg))
             .build()
             .into_diagnostic()?;
         self.push(
-            ASTType::FactorOpt1(Some(Box::new(factor_opt1_0_built))),
+            ASTType::FactorOpt0(Some(Box::new(factor_opt0_0_built))),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 333:
+    /// Semantic action for production 378:
+    ///
+    /// FactorOpt0 /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn factor_opt0_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::FactorOpt0(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 379:
     ///
-    /// FactorOpt1 /* Option<T>::None */: ;
+    /// FactorOpt /* Option<T>::None */: ;
     ///
     #[parol_runtime::function_name::named]
-    fn factor_opt1_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+    fn factor_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        self.push(ASTType::FactorOpt1(None), context);
+        self.push(ASTType::FactorOpt(None), context);
         Ok(())
     }
 
-    /// Semantic action for production 334:
+    /// Semantic action for production 380:
     ///
-    /// FactorOpt0 /* Option<T>::None */: ;
+    /// IfExpression: If Expression LBrace Expression RBrace IfExpressionList /* Vec */ Else LBrace Expression RBrace;
     ///
     #[parol_runtime::function_name::named]
-    fn factor_opt0_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+    fn if_expression(
+        &mut self,
+        _if: &ParseTreeStackEntry<'t>,
+        _expression: &ParseTreeStackEntry<'t>,
+        _l_brace: &ParseTreeStackEntry<'t>,
+        _expression0: &ParseTreeStackEntry<'t>,
+        _r_brace: &ParseTreeStackEntry<'t>,
+        _if_expression_list: &ParseTreeStackEntry<'t>,
+        _else: &ParseTreeStackEntry<'t>,
+        _l_brace0: &ParseTreeStackEntry<'t>,
+        _expression1: &ParseTreeStackEntry<'t>,
+        _r_brace0: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        self.push(ASTType::FactorOpt0(None), context);
+        let r_brace0 = pop_item!(self, r_brace0, RBrace, context);
+        let expression1 = pop_item!(self, expression1, Expression, context);
+        let l_brace0 = pop_item!(self, l_brace0, LBrace, context);
+        let r#else = pop_item!(self, r#else, Else, context);
+        let if_expression_list =
+            pop_and_reverse_item!(self, if_expression_list, IfExpressionList, context);
+        let r_brace = pop_item!(self, r_brace, RBrace, context);
+        let expression0 = pop_item!(self, expression0, Expression, context);
+        let l_brace = pop_item!(self, l_brace, LBrace, context);
+        let expression = pop_item!(self, expression, Expression, context);
+        let r#if = pop_item!(self, r#if, If, context);
+        let if_expression_built = IfExpressionBuilder::default()
+            .r#if(Box::new(r#if))
+            .expression(Box::new(expression))
+            .l_brace(Box::new(l_brace))
+            .expression0(Box::new(expression0))
+            .r_brace(Box::new(r_brace))
+            .if_expression_list(if_expression_list)
+            .r#else(Box::new(r#else))
+            .l_brace0(Box::new(l_brace0))
+            .expression1(Box::new(expression1))
+            .r_brace0(Box::new(r_brace0))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.if_expression(&if_expression_built)?;
+        self.push(ASTType::IfExpression(if_expression_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 335:
+    /// Semantic action for production 381:
     ///
-    /// FactorOpt /* Option<T>::Some */: Dollar;
+    /// IfExpressionList /* Vec<T>::Push */: Else If Expression LBrace Expression RBrace IfExpressionList;
     ///
     #[parol_runtime::function_name::named]
-    fn factor_opt_0(
+    fn if_expression_list_0(
         &mut self,
-        _dollar: &ParseTreeStackEntry<'t>,
+        _else: &ParseTreeStackEntry<'t>,
+        _if: &ParseTreeStackEntry<'t>,
+        _expression: &ParseTreeStackEntry<'t>,
+        _l_brace: &ParseTreeStackEntry<'t>,
+        _expression0: &ParseTreeStackEntry<'t>,
+        _r_brace: &ParseTreeStackEntry<'t>,
+        _if_expression_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let dollar = pop_item!(self, dollar, Dollar, context);
-        let factor_opt_0_built = FactorOptBuilder::default()
-            .dollar(Box::new(dollar))
+        let mut if_expression_list = pop_item!(self, if_expression_list, IfExpressionList, context);
+        let r_brace = pop_item!(self, r_brace, RBrace, context);
+        let expression0 = pop_item!(self, expression0, Expression, context);
+        let l_brace = pop_item!(self, l_brace, LBrace, context);
+        let expression = pop_item!(self, expression, Expression, context);
+        let r#if = pop_item!(self, r#if, If, context);
+        let r#else = pop_item!(self, r#else, Else, context);
+        let if_expression_list_0_built = IfExpressionListBuilder::default()
+            .r_brace(Box::new(r_brace))
+            .expression0(Box::new(expression0))
+            .l_brace(Box::new(l_brace))
+            .expression(Box::new(expression))
+            .r#if(Box::new(r#if))
+            .r#else(Box::new(r#else))
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        if_expression_list.push(if_expression_list_0_built);
+        self.push(ASTType::IfExpressionList(if_expression_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 382:
+    ///
+    /// IfExpressionList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn if_expression_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let if_expression_list_1_built = Vec::new();
+        self.push(
+            ASTType::IfExpressionList(if_expression_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 383:
+    ///
+    /// SystemFunctionCall: DollarIdentifier SystemFunctionCallOpt /* Option */;
+    ///
+    #[parol_runtime::function_name::named]
+    fn system_function_call(
+        &mut self,
+        _dollar_identifier: &ParseTreeStackEntry<'t>,
+        _system_function_call_opt: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let system_function_call_opt = pop_item!(
+            self,
+            system_function_call_opt,
+            SystemFunctionCallOpt,
+            context
+        );
+        let dollar_identifier = pop_item!(self, dollar_identifier, DollarIdentifier, context);
+        let system_function_call_built = SystemFunctionCallBuilder::default()
+            .dollar_identifier(Box::new(dollar_identifier))
+            .system_function_call_opt(system_function_call_opt)
             .build()
             .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .system_function_call(&system_function_call_built)?;
         self.push(
-            ASTType::FactorOpt(Some(Box::new(factor_opt_0_built))),
+            ASTType::SystemFunctionCall(system_function_call_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 336:
+    /// Semantic action for production 384:
     ///
-    /// FactorOpt /* Option<T>::None */: ;
+    /// SystemFunctionCallOpt /* Option<T>::Some */: LParen SystemFunctionCallOpt0 /* Option */ RParen;
     ///
     #[parol_runtime::function_name::named]
-    fn factor_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+    fn system_function_call_opt_0(
+        &mut self,
+        _l_paren: &ParseTreeStackEntry<'t>,
+        _system_function_call_opt0: &ParseTreeStackEntry<'t>,
+        _r_paren: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        self.push(ASTType::FactorOpt(None), context);
+        let r_paren = pop_item!(self, r_paren, RParen, context);
+        let system_function_call_opt0 = pop_item!(
+            self,
+            system_function_call_opt0,
+            SystemFunctionCallOpt0,
+            context
+        );
+        let l_paren = pop_item!(self, l_paren, LParen, context);
+        let system_function_call_opt_0_built = SystemFunctionCallOptBuilder::default()
+            .l_paren(Box::new(l_paren))
+            .system_function_call_opt0(system_function_call_opt0)
+            .r_paren(Box::new(r_paren))
+            .build()
+            .into_diagnostic()?;
+        self.push(
+            ASTType::SystemFunctionCallOpt(Some(Box::new(system_function_call_opt_0_built))),
+            context,
+        );
         Ok(())
     }
 
-    /// Semantic action for production 337:
+    /// Semantic action for production 385:
+    ///
+    /// SystemFunctionCallOpt0 /* Option<T>::Some */: FunctionCallArg;
+    ///
+    #[parol_runtime::function_name::named]
+    fn system_function_call_opt0_0(
+        &mut self,
+        _function_call_arg: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let function_call_arg = pop_item!(self, function_call_arg, FunctionCallArg, context);
+        let system_function_call_opt0_0_built = SystemFunctionCallOpt0Builder::default()
+            .function_call_arg(Box::new(function_call_arg))
+            .build()
+            .into_diagnostic()?;
+        self.push(
+            ASTType::SystemFunctionCallOpt0(Some(Box::new(system_function_call_opt0_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 386:
+    ///
+    /// SystemFunctionCallOpt0 /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn system_function_call_opt0_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::SystemFunctionCallOpt0(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 387:
+    ///
+    /// SystemFunctionCallOpt /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn system_function_call_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::SystemFunctionCallOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 388:
     ///
     /// FunctionCallArg: Expression FunctionCallArgList /* Vec */ FunctionCallArgOpt /* Option */;
     ///
@@ -16915,7 +19858,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 338:
+    /// Semantic action for production 389:
     ///
     /// FunctionCallArgList /* Vec<T>::Push */: Comma Expression FunctionCallArgList;
     ///
@@ -16947,7 +19890,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 339:
+    /// Semantic action for production 390:
     ///
     /// FunctionCallArgList /* Vec<T>::New */: ;
     ///
@@ -16963,7 +19906,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 340:
+    /// Semantic action for production 391:
     ///
     /// FunctionCallArgOpt /* Option<T>::Some */: Comma;
     ///
@@ -16987,7 +19930,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 341:
+    /// Semantic action for production 392:
     ///
     /// FunctionCallArgOpt /* Option<T>::None */: ;
     ///
@@ -16999,7 +19942,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 342:
+    /// Semantic action for production 393:
     ///
     /// ConcatenationList: ConcatenationItem ConcatenationListList /* Vec */ ConcatenationListOpt /* Option */;
     ///
@@ -17038,7 +19981,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 343:
+    /// Semantic action for production 394:
     ///
     /// ConcatenationListList /* Vec<T>::Push */: Comma ConcatenationItem ConcatenationListList;
     ///
@@ -17074,7 +20017,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 344:
+    /// Semantic action for production 395:
     ///
     /// ConcatenationListList /* Vec<T>::New */: ;
     ///
@@ -17090,7 +20033,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 345:
+    /// Semantic action for production 396:
     ///
     /// ConcatenationListOpt /* Option<T>::Some */: Comma;
     ///
@@ -17114,7 +20057,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 346:
+    /// Semantic action for production 397:
     ///
     /// ConcatenationListOpt /* Option<T>::None */: ;
     ///
@@ -17126,7 +20069,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 347:
+    /// Semantic action for production 398:
     ///
     /// ConcatenationItem: Expression ConcatenationItemOpt /* Option */;
     ///
@@ -17157,7 +20100,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 348:
+    /// Semantic action for production 399:
     ///
     /// ConcatenationItemOpt /* Option<T>::Some */: Repeat Expression;
     ///
@@ -17184,7 +20127,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 349:
+    /// Semantic action for production 400:
     ///
     /// ConcatenationItemOpt /* Option<T>::None */: ;
     ///
@@ -17196,7 +20139,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 350:
+    /// Semantic action for production 401:
     ///
     /// Range: LBracket Expression RangeOpt /* Option */ RBracket;
     ///
@@ -17228,7 +20171,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 351:
+    /// Semantic action for production 402:
     ///
     /// RangeOpt /* Option<T>::Some */: RangeOperator Expression;
     ///
@@ -17255,7 +20198,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 352:
+    /// Semantic action for production 403:
     ///
     /// RangeOpt /* Option<T>::None */: ;
     ///
@@ -17267,7 +20210,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 353:
+    /// Semantic action for production 404:
     ///
     /// RangeOperator: Colon;
     ///
@@ -17291,7 +20234,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 354:
+    /// Semantic action for production 405:
     ///
     /// RangeOperator: PlusColon;
     ///
@@ -17315,7 +20258,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 355:
+    /// Semantic action for production 406:
     ///
     /// RangeOperator: MinusColon;
     ///
@@ -17339,7 +20282,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 356:
+    /// Semantic action for production 407:
     ///
     /// RangeOperator: Step;
     ///
@@ -17363,7 +20306,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 357:
+    /// Semantic action for production 408:
     ///
     /// Width: LBracket Expression RBracket;
     ///
@@ -17392,7 +20335,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 358:
+    /// Semantic action for production 409:
     ///
     /// BuiltinType: Logic;
     ///
@@ -17416,7 +20359,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 359:
+    /// Semantic action for production 410:
     ///
     /// BuiltinType: Bit;
     ///
@@ -17440,7 +20383,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 360:
+    /// Semantic action for production 411:
     ///
     /// BuiltinType: U32;
     ///
@@ -17464,7 +20407,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 361:
+    /// Semantic action for production 412:
     ///
     /// BuiltinType: U64;
     ///
@@ -17488,7 +20431,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 362:
+    /// Semantic action for production 413:
     ///
     /// BuiltinType: I32;
     ///
@@ -17512,7 +20455,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 363:
+    /// Semantic action for production 414:
     ///
     /// BuiltinType: I64;
     ///
@@ -17536,7 +20479,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 364:
+    /// Semantic action for production 415:
     ///
     /// BuiltinType: F32;
     ///
@@ -17560,7 +20503,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 365:
+    /// Semantic action for production 416:
     ///
     /// BuiltinType: F64;
     ///
@@ -17584,12 +20527,12 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 366:
+    /// Semantic action for production 417:
     ///
     /// TypeModifier: Tri;
     ///
     #[parol_runtime::function_name::named]
-    fn type_modifier(
+    fn type_modifier_0(
         &mut self,
         _tri: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -17597,37 +20540,132 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let tri = pop_item!(self, tri, Tri, context);
-        let type_modifier_built = TypeModifierBuilder::default()
+        let type_modifier_0_built = TypeModifierTriBuilder::default()
             .tri(Box::new(tri))
             .build()
             .into_diagnostic()?;
+        let type_modifier_0_built = TypeModifier::Tri(type_modifier_0_built);
         // Calling user action here
-        self.user_grammar.type_modifier(&type_modifier_built)?;
-        self.push(ASTType::TypeModifier(type_modifier_built), context);
+        self.user_grammar.type_modifier(&type_modifier_0_built)?;
+        self.push(ASTType::TypeModifier(type_modifier_0_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 367:
+    /// Semantic action for production 418:
+    ///
+    /// TypeModifier: Signed;
+    ///
+    #[parol_runtime::function_name::named]
+    fn type_modifier_1(
+        &mut self,
+        _signed: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let signed = pop_item!(self, signed, Signed, context);
+        let type_modifier_1_built = TypeModifierSignedBuilder::default()
+            .signed(Box::new(signed))
+            .build()
+            .into_diagnostic()?;
+        let type_modifier_1_built = TypeModifier::Signed(type_modifier_1_built);
+        // Calling user action here
+        self.user_grammar.type_modifier(&type_modifier_1_built)?;
+        self.push(ASTType::TypeModifier(type_modifier_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 419:
+    ///
+    /// Array: Unpacked Width ArrayList /* Vec */;
+    ///
+    #[parol_runtime::function_name::named]
+    fn array(
+        &mut self,
+        _unpacked: &ParseTreeStackEntry<'t>,
+        _width: &ParseTreeStackEntry<'t>,
+        _array_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let array_list = pop_and_reverse_item!(self, array_list, ArrayList, context);
+        let width = pop_item!(self, width, Width, context);
+        let unpacked = pop_item!(self, unpacked, Unpacked, context);
+        let array_built = ArrayBuilder::default()
+            .unpacked(Box::new(unpacked))
+            .width(Box::new(width))
+            .array_list(array_list)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.array(&array_built)?;
+        self.push(ASTType::Array(array_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 420:
+    ///
+    /// ArrayList /* Vec<T>::Push */: Width ArrayList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn array_list_0(
+        &mut self,
+        _width: &ParseTreeStackEntry<'t>,
+        _array_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut array_list = pop_item!(self, array_list, ArrayList, context);
+        let width = pop_item!(self, width, Width, context);
+        let array_list_0_built = ArrayListBuilder::default()
+            .width(Box::new(width))
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        array_list.push(array_list_0_built);
+        self.push(ASTType::ArrayList(array_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 421:
+    ///
+    /// ArrayList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn array_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let array_list_1_built = Vec::new();
+        self.push(ASTType::ArrayList(array_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 422:
     ///
-    /// Type: TypeOpt /* Option */ TypeGroup TypeList /* Vec */;
+    /// Type: TypeList /* Vec */ TypeGroup TypeList0 /* Vec */ TypeOpt /* Option */;
     ///
     #[parol_runtime::function_name::named]
     fn r#type(
         &mut self,
-        _type_opt: &ParseTreeStackEntry<'t>,
-        _type_group: &ParseTreeStackEntry<'t>,
         _type_list: &ParseTreeStackEntry<'t>,
+        _type_group: &ParseTreeStackEntry<'t>,
+        _type_list0: &ParseTreeStackEntry<'t>,
+        _type_opt: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let type_list = pop_and_reverse_item!(self, type_list, TypeList, context);
-        let type_group = pop_item!(self, type_group, TypeGroup, context);
         let type_opt = pop_item!(self, type_opt, TypeOpt, context);
+        let type_list0 = pop_and_reverse_item!(self, type_list0, TypeList0, context);
+        let type_group = pop_item!(self, type_group, TypeGroup, context);
+        let type_list = pop_and_reverse_item!(self, type_list, TypeList, context);
         let r#type_built = TypeBuilder::default()
-            .type_opt(type_opt)
-            .type_group(Box::new(type_group))
             .type_list(type_list)
+            .type_group(Box::new(type_group))
+            .type_list0(type_list0)
+            .type_opt(type_opt)
             .build()
             .into_diagnostic()?;
         // Calling user action here
@@ -17636,7 +20674,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 368:
+    /// Semantic action for production 423:
     ///
     /// TypeGroup: BuiltinType;
     ///
@@ -17658,7 +20696,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 369:
+    /// Semantic action for production 424:
     ///
     /// TypeGroup: ScopedIdentifier;
     ///
@@ -17680,7 +20718,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 370:
+    /// Semantic action for production 425:
     ///
     /// TypeGroup: ModportIdentifier;
     ///
@@ -17702,23 +20740,61 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 371:
+    /// Semantic action for production 426:
     ///
-    /// TypeList /* Vec<T>::Push */: Width TypeList;
+    /// TypeList0 /* Vec<T>::Push */: Width TypeList0;
     ///
     #[parol_runtime::function_name::named]
-    fn type_list_0(
+    fn type_list0_0(
         &mut self,
         _width: &ParseTreeStackEntry<'t>,
+        _type_list0: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut type_list0 = pop_item!(self, type_list0, TypeList0, context);
+        let width = pop_item!(self, width, Width, context);
+        let type_list0_0_built = TypeList0Builder::default()
+            .width(Box::new(width))
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        type_list0.push(type_list0_0_built);
+        self.push(ASTType::TypeList0(type_list0), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 427:
+    ///
+    /// TypeList0 /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn type_list0_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let type_list0_1_built = Vec::new();
+        self.push(ASTType::TypeList0(type_list0_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 428:
+    ///
+    /// TypeList /* Vec<T>::Push */: TypeModifier TypeList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn type_list_0(
+        &mut self,
+        _type_modifier: &ParseTreeStackEntry<'t>,
         _type_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let mut type_list = pop_item!(self, type_list, TypeList, context);
-        let width = pop_item!(self, width, Width, context);
+        let type_modifier = pop_item!(self, type_modifier, TypeModifier, context);
         let type_list_0_built = TypeListBuilder::default()
-            .width(Box::new(width))
+            .type_modifier(Box::new(type_modifier))
             .build()
             .into_diagnostic()?;
         // Add an element to the vector
@@ -17727,7 +20803,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 372:
+    /// Semantic action for production 429:
     ///
     /// TypeList /* Vec<T>::New */: ;
     ///
@@ -17740,28 +20816,28 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 373:
+    /// Semantic action for production 430:
     ///
-    /// TypeOpt /* Option<T>::Some */: TypeModifier;
+    /// TypeOpt /* Option<T>::Some */: Array;
     ///
     #[parol_runtime::function_name::named]
     fn type_opt_0(
         &mut self,
-        _type_modifier: &ParseTreeStackEntry<'t>,
+        _array: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let type_modifier = pop_item!(self, type_modifier, TypeModifier, context);
+        let array = pop_item!(self, array, Array, context);
         let type_opt_0_built = TypeOptBuilder::default()
-            .type_modifier(Box::new(type_modifier))
+            .array(Box::new(array))
             .build()
             .into_diagnostic()?;
         self.push(ASTType::TypeOpt(Some(Box::new(type_opt_0_built))), context);
         Ok(())
     }
 
-    /// Semantic action for production 374:
+    /// Semantic action for production 431:
     ///
     /// TypeOpt /* Option<T>::None */: ;
     ///
@@ -17773,7 +20849,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 375:
+    /// Semantic action for production 432:
     ///
     /// Statement: AssignmentStatement;
     ///
@@ -17798,7 +20874,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 376:
+    /// Semantic action for production 433:
     ///
     /// Statement: IfStatement;
     ///
@@ -17822,7 +20898,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 377:
+    /// Semantic action for production 434:
     ///
     /// Statement: IfResetStatement;
     ///
@@ -17846,7 +20922,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 378:
+    /// Semantic action for production 435:
     ///
     /// Statement: ReturnStatement;
     ///
@@ -17870,7 +20946,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 379:
+    /// Semantic action for production 436:
     ///
     /// Statement: ForStatement;
     ///
@@ -17894,7 +20970,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 380:
+    /// Semantic action for production 437:
     ///
     /// Statement: CaseStatement;
     ///
@@ -17918,7 +20994,56 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 381:
+    /// Semantic action for production 438:
+    ///
+    /// Statement: AssertionStatement;
+    ///
+    #[parol_runtime::function_name::named]
+    fn statement_6(
+        &mut self,
+        _assertion_statement: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assertion_statement = pop_item!(self, assertion_statement, AssertionStatement, context);
+        let statement_6_built = StatementAssertionStatementBuilder::default()
+            .assertion_statement(Box::new(assertion_statement))
+            .build()
+            .into_diagnostic()?;
+        let statement_6_built = Statement::AssertionStatement(statement_6_built);
+        // Calling user action here
+        self.user_grammar.statement(&statement_6_built)?;
+        self.push(ASTType::Statement(statement_6_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 439:
+    ///
+    /// Statement: SystemTaskStatement;
+    ///
+    #[parol_runtime::function_name::named]
+    fn statement_7(
+        &mut self,
+        _system_task_statement: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let system_task_statement =
+            pop_item!(self, system_task_statement, SystemTaskStatement, context);
+        let statement_7_built = StatementSystemTaskStatementBuilder::default()
+            .system_task_statement(Box::new(system_task_statement))
+            .build()
+            .into_diagnostic()?;
+        let statement_7_built = Statement::SystemTaskStatement(statement_7_built);
+        // Calling user action here
+        self.user_grammar.statement(&statement_7_built)?;
+        self.push(ASTType::Statement(statement_7_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 440:
     ///
     /// AssignmentStatement: HierarchicalIdentifier AssignmentStatementGroup Expression Semicolon;
     ///
@@ -17964,7 +21089,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 382:
+    /// Semantic action for production 441:
     ///
     /// AssignmentStatementGroup: Equ;
     ///
@@ -17990,7 +21115,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 383:
+    /// Semantic action for production 442:
     ///
     /// AssignmentStatementGroup: AssignmentOperator;
     ///
@@ -18017,7 +21142,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 384:
+    /// Semantic action for production 443:
     ///
     /// IfStatement: If Expression LBrace IfStatementList /* Vec */ RBrace IfStatementList0 /* Vec */ IfStatementOpt /* Option */;
     ///
@@ -18060,7 +21185,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 385:
+    /// Semantic action for production 444:
     ///
     /// IfStatementList0 /* Vec<T>::Push */: Else If Expression LBrace IfStatementList0List /* Vec */ RBrace IfStatementList0;
     ///
@@ -18101,7 +21226,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 386:
+    /// Semantic action for production 445:
     ///
     /// IfStatementList0List /* Vec<T>::Push */: Statement IfStatementList0List;
     ///
@@ -18130,7 +21255,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 387:
+    /// Semantic action for production 446:
     ///
     /// IfStatementList0List /* Vec<T>::New */: ;
     ///
@@ -18146,7 +21271,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 388:
+    /// Semantic action for production 447:
     ///
     /// IfStatementList0 /* Vec<T>::New */: ;
     ///
@@ -18162,7 +21287,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 389:
+    /// Semantic action for production 448:
     ///
     /// IfStatementList /* Vec<T>::Push */: Statement IfStatementList;
     ///
@@ -18187,7 +21312,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 390:
+    /// Semantic action for production 449:
     ///
     /// IfStatementList /* Vec<T>::New */: ;
     ///
@@ -18200,7 +21325,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 391:
+    /// Semantic action for production 450:
     ///
     /// IfStatementOpt /* Option<T>::Some */: Else LBrace IfStatementOptList /* Vec */ RBrace;
     ///
@@ -18234,7 +21359,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 392:
+    /// Semantic action for production 451:
     ///
     /// IfStatementOptList /* Vec<T>::Push */: Statement IfStatementOptList;
     ///
@@ -18260,7 +21385,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 393:
+    /// Semantic action for production 452:
     ///
     /// IfStatementOptList /* Vec<T>::New */: ;
     ///
@@ -18276,7 +21401,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 394:
+    /// Semantic action for production 453:
     ///
     /// IfStatementOpt /* Option<T>::None */: ;
     ///
@@ -18288,7 +21413,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 395:
+    /// Semantic action for production 454:
     ///
     /// IfResetStatement: IfReset LBrace IfResetStatementList /* Vec */ RBrace IfResetStatementList0 /* Vec */ IfResetStatementOpt /* Option */;
     ///
@@ -18334,7 +21459,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 396:
+    /// Semantic action for production 455:
     ///
     /// IfResetStatementList0 /* Vec<T>::Push */: Else If Expression LBrace IfResetStatementList0List /* Vec */ RBrace IfResetStatementList0;
     ///
@@ -18387,7 +21512,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 397:
+    /// Semantic action for production 456:
     ///
     /// IfResetStatementList0List /* Vec<T>::Push */: Statement IfResetStatementList0List;
     ///
@@ -18420,7 +21545,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 398:
+    /// Semantic action for production 457:
     ///
     /// IfResetStatementList0List /* Vec<T>::New */: ;
     ///
@@ -18439,7 +21564,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 399:
+    /// Semantic action for production 458:
     ///
     /// IfResetStatementList0 /* Vec<T>::New */: ;
     ///
@@ -18455,7 +21580,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 400:
+    /// Semantic action for production 459:
     ///
     /// IfResetStatementList /* Vec<T>::Push */: Statement IfResetStatementList;
     ///
@@ -18484,7 +21609,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 401:
+    /// Semantic action for production 460:
     ///
     /// IfResetStatementList /* Vec<T>::New */: ;
     ///
@@ -18500,7 +21625,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 402:
+    /// Semantic action for production 461:
     ///
     /// IfResetStatementOpt /* Option<T>::Some */: Else LBrace IfResetStatementOptList /* Vec */ RBrace;
     ///
@@ -18538,7 +21663,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 403:
+    /// Semantic action for production 462:
     ///
     /// IfResetStatementOptList /* Vec<T>::Push */: Statement IfResetStatementOptList;
     ///
@@ -18571,7 +21696,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 404:
+    /// Semantic action for production 463:
     ///
     /// IfResetStatementOptList /* Vec<T>::New */: ;
     ///
@@ -18590,7 +21715,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 405:
+    /// Semantic action for production 464:
     ///
     /// IfResetStatementOpt /* Option<T>::None */: ;
     ///
@@ -18602,7 +21727,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 406:
+    /// Semantic action for production 465:
     ///
     /// ReturnStatement: Return Expression Semicolon;
     ///
@@ -18632,7 +21757,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 407:
+    /// Semantic action for production 466:
     ///
     /// ForStatement: For Identifier Colon Type In Expression DotDot Expression ForStatementOpt /* Option */ LBrace ForStatementList /* Vec */ RBrace;
     ///
@@ -18689,7 +21814,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 408:
+    /// Semantic action for production 467:
     ///
     /// ForStatementList /* Vec<T>::Push */: Statement ForStatementList;
     ///
@@ -18714,7 +21839,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 409:
+    /// Semantic action for production 468:
     ///
     /// ForStatementList /* Vec<T>::New */: ;
     ///
@@ -18730,7 +21855,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 410:
+    /// Semantic action for production 469:
     ///
     /// ForStatementOpt /* Option<T>::Some */: Step AssignmentOperator Expression;
     ///
@@ -18760,7 +21885,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 411:
+    /// Semantic action for production 470:
     ///
     /// ForStatementOpt /* Option<T>::None */: ;
     ///
@@ -18772,7 +21897,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 412:
+    /// Semantic action for production 471:
     ///
     /// CaseStatement: Case Expression LBrace CaseStatementList /* Vec */ RBrace;
     ///
@@ -18808,7 +21933,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 413:
+    /// Semantic action for production 472:
     ///
     /// CaseStatementList /* Vec<T>::Push */: CaseItem CaseStatementList;
     ///
@@ -18834,7 +21959,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 414:
+    /// Semantic action for production 473:
     ///
     /// CaseStatementList /* Vec<T>::New */: ;
     ///
@@ -18850,7 +21975,143 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 415:
+    /// Semantic action for production 474:
+    ///
+    /// AssertionStatement: AssertionKind Expression Semicolon;
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_statement(
+        &mut self,
+        _assertion_kind: &ParseTreeStackEntry<'t>,
+        _expression: &ParseTreeStackEntry<'t>,
+        _semicolon: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let semicolon = pop_item!(self, semicolon, Semicolon, context);
+        let expression = pop_item!(self, expression, Expression, context);
+        let assertion_kind = pop_item!(self, assertion_kind, AssertionKind, context);
+        let assertion_statement_built = AssertionStatementBuilder::default()
+            .assertion_kind(Box::new(assertion_kind))
+            .expression(Box::new(expression))
+            .semicolon(Box::new(semicolon))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .assertion_statement(&assertion_statement_built)?;
+        self.push(
+            ASTType::AssertionStatement(assertion_statement_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 475:
+    ///
+    /// AssertionKind: Assert;
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_kind_0(
+        &mut self,
+        _assert: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assert = pop_item!(self, assert, Assert, context);
+        let assertion_kind_0_built = AssertionKindAssertBuilder::default()
+            .assert(Box::new(assert))
+            .build()
+            .into_diagnostic()?;
+        let assertion_kind_0_built = AssertionKind::Assert(assertion_kind_0_built);
+        // Calling user action here
+        self.user_grammar.assertion_kind(&assertion_kind_0_built)?;
+        self.push(ASTType::AssertionKind(assertion_kind_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 476:
+    ///
+    /// AssertionKind: Assume;
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_kind_1(
+        &mut self,
+        _assume: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assume = pop_item!(self, assume, Assume, context);
+        let assertion_kind_1_built = AssertionKindAssumeBuilder::default()
+            .assume(Box::new(assume))
+            .build()
+            .into_diagnostic()?;
+        let assertion_kind_1_built = AssertionKind::Assume(assertion_kind_1_built);
+        // Calling user action here
+        self.user_grammar.assertion_kind(&assertion_kind_1_built)?;
+        self.push(ASTType::AssertionKind(assertion_kind_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 477:
+    ///
+    /// AssertionKind: Cover;
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_kind_2(
+        &mut self,
+        _cover: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let cover = pop_item!(self, cover, Cover, context);
+        let assertion_kind_2_built = AssertionKindCoverBuilder::default()
+            .cover(Box::new(cover))
+            .build()
+            .into_diagnostic()?;
+        let assertion_kind_2_built = AssertionKind::Cover(assertion_kind_2_built);
+        // Calling user action here
+        self.user_grammar.assertion_kind(&assertion_kind_2_built)?;
+        self.push(ASTType::AssertionKind(assertion_kind_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 478:
+    ///
+    /// SystemTaskStatement: SystemFunctionCall Semicolon;
+    ///
+    #[parol_runtime::function_name::named]
+    fn system_task_statement(
+        &mut self,
+        _system_function_call: &ParseTreeStackEntry<'t>,
+        _semicolon: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let semicolon = pop_item!(self, semicolon, Semicolon, context);
+        let system_function_call =
+            pop_item!(self, system_function_call, SystemFunctionCall, context);
+        let system_task_statement_built = SystemTaskStatementBuilder::default()
+            .system_function_call(Box::new(system_function_call))
+            .semicolon(Box::new(semicolon))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .system_task_statement(&system_task_statement_built)?;
+        self.push(
+            ASTType::SystemTaskStatement(system_task_statement_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 479:
     ///
     /// CaseItem: CaseItemGroup Colon CaseItemGroup0;
     ///
@@ -18879,7 +22140,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 416:
+    /// Semantic action for production 480:
     ///
     /// CaseItemGroup0: Statement;
     ///
@@ -18901,7 +22162,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 417:
+    /// Semantic action for production 481:
     ///
     /// CaseItemGroup0: LBrace CaseItemGroup0List /* Vec */ RBrace;
     ///
@@ -18932,7 +22193,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 418:
+    /// Semantic action for production 482:
     ///
     /// CaseItemGroup0List /* Vec<T>::Push */: Statement CaseItemGroup0List;
     ///
@@ -18958,7 +22219,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 419:
+    /// Semantic action for production 483:
     ///
     /// CaseItemGroup0List /* Vec<T>::New */: ;
     ///
@@ -18974,7 +22235,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 420:
+    /// Semantic action for production 484:
     ///
     /// CaseItemGroup: Expression;
     ///
@@ -18996,7 +22257,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 421:
+    /// Semantic action for production 485:
     ///
     /// CaseItemGroup: Defaul;
     ///
@@ -19018,195 +22279,467 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 422:
+    /// Semantic action for production 486:
     ///
-    /// VarDeclaration: Var Identifier Colon Type VarDeclarationOpt /* Option */ Semicolon;
+    /// Attribute: Hash LBracket Identifier AttributeOpt /* Option */ RBracket;
     ///
     #[parol_runtime::function_name::named]
-    fn var_declaration(
+    fn attribute(
         &mut self,
-        _var: &ParseTreeStackEntry<'t>,
+        _hash: &ParseTreeStackEntry<'t>,
+        _l_bracket: &ParseTreeStackEntry<'t>,
         _identifier: &ParseTreeStackEntry<'t>,
-        _colon: &ParseTreeStackEntry<'t>,
-        _type: &ParseTreeStackEntry<'t>,
-        _var_declaration_opt: &ParseTreeStackEntry<'t>,
-        _semicolon: &ParseTreeStackEntry<'t>,
+        _attribute_opt: &ParseTreeStackEntry<'t>,
+        _r_bracket: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let semicolon = pop_item!(self, semicolon, Semicolon, context);
-        let var_declaration_opt = pop_item!(self, var_declaration_opt, VarDeclarationOpt, context);
-        let r#type = pop_item!(self, r#type, Type, context);
-        let colon = pop_item!(self, colon, Colon, context);
+        let r_bracket = pop_item!(self, r_bracket, RBracket, context);
+        let attribute_opt = pop_item!(self, attribute_opt, AttributeOpt, context);
         let identifier = pop_item!(self, identifier, Identifier, context);
-        let var = pop_item!(self, var, Var, context);
-        let var_declaration_built = VarDeclarationBuilder::default()
-            .var(Box::new(var))
+        let l_bracket = pop_item!(self, l_bracket, LBracket, context);
+        let hash = pop_item!(self, hash, Hash, context);
+        let attribute_built = AttributeBuilder::default()
+            .hash(Box::new(hash))
+            .l_bracket(Box::new(l_bracket))
             .identifier(Box::new(identifier))
-            .colon(Box::new(colon))
-            .r#type(Box::new(r#type))
-            .var_declaration_opt(var_declaration_opt)
-            .semicolon(Box::new(semicolon))
+            .attribute_opt(attribute_opt)
+            .r_bracket(Box::new(r_bracket))
             .build()
             .into_diagnostic()?;
         // Calling user action here
-        self.user_grammar.var_declaration(&var_declaration_built)?;
-        self.push(ASTType::VarDeclaration(var_declaration_built), context);
+        self.user_grammar.attribute(&attribute_built)?;
+        self.push(ASTType::Attribute(attribute_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 423:
+    /// Semantic action for production 487:
     ///
-    /// VarDeclarationOpt /* Option<T>::Some */: Equ Expression;
+    /// AttributeOpt /* Option<T>::Some */: LParen AttributeList RParen;
     ///
     #[parol_runtime::function_name::named]
-    fn var_declaration_opt_0(
+    fn attribute_opt_0(
         &mut self,
-        _equ: &ParseTreeStackEntry<'t>,
-        _expression: &ParseTreeStackEntry<'t>,
+        _l_paren: &ParseTreeStackEntry<'t>,
+        _attribute_list: &ParseTreeStackEntry<'t>,
+        _r_paren: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let expression = pop_item!(self, expression, Expression, context);
-        let equ = pop_item!(self, equ, Equ, context);
-        let var_declaration_opt_0_built = VarDeclarationOptBuilder::default()
-            .equ(Box::new(equ))
-            .expression(Box::new(expression))
+        let r_paren = pop_item!(self, r_paren, RParen, context);
+        let attribute_list = pop_item!(self, attribute_list, AttributeList, context);
+        let l_paren = pop_item!(self, l_paren, LParen, context);
+        let attribute_opt_0_built = AttributeOptBuilder::default()
+            .l_paren(Box::new(l_paren))
+            .attribute_list(Box::new(attribute_list))
+            .r_paren(Box::new(r_paren))
             .build()
             .into_diagnostic()?;
         self.push(
-            ASTType::VarDeclarationOpt(Some(Box::new(var_declaration_opt_0_built))),
+            ASTType::AttributeOpt(Some(Box::new(attribute_opt_0_built))),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 424:
+    /// Semantic action for production 488:
     ///
-    /// VarDeclarationOpt /* Option<T>::None */: ;
+    /// AttributeOpt /* Option<T>::None */: ;
     ///
     #[parol_runtime::function_name::named]
-    fn var_declaration_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+    fn attribute_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        self.push(ASTType::VarDeclarationOpt(None), context);
+        self.push(ASTType::AttributeOpt(None), context);
         Ok(())
     }
 
-    /// Semantic action for production 425:
+    /// Semantic action for production 489:
     ///
-    /// LocalparamDeclaration: Localparam Identifier Colon Type Equ Expression Semicolon;
+    /// AttributeList: AttributeItem AttributeListList /* Vec */ AttributeListOpt /* Option */;
     ///
     #[parol_runtime::function_name::named]
-    fn localparam_declaration(
+    fn attribute_list(
         &mut self,
-        _localparam: &ParseTreeStackEntry<'t>,
-        _identifier: &ParseTreeStackEntry<'t>,
-        _colon: &ParseTreeStackEntry<'t>,
-        _type: &ParseTreeStackEntry<'t>,
-        _equ: &ParseTreeStackEntry<'t>,
-        _expression: &ParseTreeStackEntry<'t>,
-        _semicolon: &ParseTreeStackEntry<'t>,
+        _attribute_item: &ParseTreeStackEntry<'t>,
+        _attribute_list_list: &ParseTreeStackEntry<'t>,
+        _attribute_list_opt: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let semicolon = pop_item!(self, semicolon, Semicolon, context);
-        let expression = pop_item!(self, expression, Expression, context);
-        let equ = pop_item!(self, equ, Equ, context);
-        let r#type = pop_item!(self, r#type, Type, context);
-        let colon = pop_item!(self, colon, Colon, context);
-        let identifier = pop_item!(self, identifier, Identifier, context);
-        let localparam = pop_item!(self, localparam, Localparam, context);
-        let localparam_declaration_built = LocalparamDeclarationBuilder::default()
-            .localparam(Box::new(localparam))
-            .identifier(Box::new(identifier))
-            .colon(Box::new(colon))
-            .r#type(Box::new(r#type))
-            .equ(Box::new(equ))
-            .expression(Box::new(expression))
-            .semicolon(Box::new(semicolon))
+        let attribute_list_opt = pop_item!(self, attribute_list_opt, AttributeListOpt, context);
+        let attribute_list_list =
+            pop_and_reverse_item!(self, attribute_list_list, AttributeListList, context);
+        let attribute_item = pop_item!(self, attribute_item, AttributeItem, context);
+        let attribute_list_built = AttributeListBuilder::default()
+            .attribute_item(Box::new(attribute_item))
+            .attribute_list_list(attribute_list_list)
+            .attribute_list_opt(attribute_list_opt)
             .build()
             .into_diagnostic()?;
         // Calling user action here
-        self.user_grammar
-            .localparam_declaration(&localparam_declaration_built)?;
-        self.push(
-            ASTType::LocalparamDeclaration(localparam_declaration_built),
-            context,
-        );
+        self.user_grammar.attribute_list(&attribute_list_built)?;
+        self.push(ASTType::AttributeList(attribute_list_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 426:
+    /// Semantic action for production 490:
     ///
-    /// AlwaysFfDeclaration: AlwaysFf LParen AlwaysFfClock AlwaysFfDeclarationOpt /* Option */ RParen LBrace AlwaysFfDeclarationList /* Vec */ RBrace;
+    /// AttributeListList /* Vec<T>::Push */: Comma AttributeItem AttributeListList;
     ///
     #[parol_runtime::function_name::named]
-    fn always_ff_declaration(
+    fn attribute_list_list_0(
         &mut self,
-        _always_ff: &ParseTreeStackEntry<'t>,
-        _l_paren: &ParseTreeStackEntry<'t>,
-        _always_ff_clock: &ParseTreeStackEntry<'t>,
-        _always_ff_declaration_opt: &ParseTreeStackEntry<'t>,
-        _r_paren: &ParseTreeStackEntry<'t>,
-        _l_brace: &ParseTreeStackEntry<'t>,
-        _always_ff_declaration_list: &ParseTreeStackEntry<'t>,
-        _r_brace: &ParseTreeStackEntry<'t>,
+        _comma: &ParseTreeStackEntry<'t>,
+        _attribute_item: &ParseTreeStackEntry<'t>,
+        _attribute_list_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let r_brace = pop_item!(self, r_brace, RBrace, context);
-        let always_ff_declaration_list = pop_and_reverse_item!(
-            self,
-            always_ff_declaration_list,
-            AlwaysFfDeclarationList,
-            context
-        );
-        let l_brace = pop_item!(self, l_brace, LBrace, context);
-        let r_paren = pop_item!(self, r_paren, RParen, context);
-        let always_ff_declaration_opt = pop_item!(
-            self,
-            always_ff_declaration_opt,
-            AlwaysFfDeclarationOpt,
-            context
-        );
-        let always_ff_clock = pop_item!(self, always_ff_clock, AlwaysFfClock, context);
-        let l_paren = pop_item!(self, l_paren, LParen, context);
-        let always_ff = pop_item!(self, always_ff, AlwaysFf, context);
-        let always_ff_declaration_built = AlwaysFfDeclarationBuilder::default()
-            .always_ff(Box::new(always_ff))
-            .l_paren(Box::new(l_paren))
-            .always_ff_clock(Box::new(always_ff_clock))
-            .always_ff_declaration_opt(always_ff_declaration_opt)
-            .r_paren(Box::new(r_paren))
-            .l_brace(Box::new(l_brace))
-            .always_ff_declaration_list(always_ff_declaration_list)
-            .r_brace(Box::new(r_brace))
+        let mut attribute_list_list =
+            pop_item!(self, attribute_list_list, AttributeListList, context);
+        let attribute_item = pop_item!(self, attribute_item, AttributeItem, context);
+        let comma = pop_item!(self, comma, Comma, context);
+        let attribute_list_list_0_built = AttributeListListBuilder::default()
+            .attribute_item(Box::new(attribute_item))
+            .comma(Box::new(comma))
             .build()
             .into_diagnostic()?;
-        // Calling user action here
-        self.user_grammar
-            .always_ff_declaration(&always_ff_declaration_built)?;
+        // Add an element to the vector
+        attribute_list_list.push(attribute_list_list_0_built);
+        self.push(ASTType::AttributeListList(attribute_list_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 491:
+    ///
+    /// AttributeListList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_list_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let attribute_list_list_1_built = Vec::new();
         self.push(
-            ASTType::AlwaysFfDeclaration(always_ff_declaration_built),
+            ASTType::AttributeListList(attribute_list_list_1_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 427:
+    /// Semantic action for production 492:
     ///
-    /// AlwaysFfDeclarationList /* Vec<T>::Push */: Statement AlwaysFfDeclarationList;
+    /// AttributeListOpt /* Option<T>::Some */: Comma;
     ///
     #[parol_runtime::function_name::named]
-    fn always_ff_declaration_list_0(
+    fn attribute_list_opt_0(
         &mut self,
-        _statement: &ParseTreeStackEntry<'t>,
-        _always_ff_declaration_list: &ParseTreeStackEntry<'t>,
+        _comma: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comma = pop_item!(self, comma, Comma, context);
+        let attribute_list_opt_0_built = AttributeListOptBuilder::default()
+            .comma(Box::new(comma))
+            .build()
+            .into_diagnostic()?;
+        self.push(
+            ASTType::AttributeListOpt(Some(Box::new(attribute_list_opt_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 493:
+    ///
+    /// AttributeListOpt /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_list_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::AttributeListOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 494:
+    ///
+    /// AttributeItem: Identifier;
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_item_0(
+        &mut self,
+        _identifier: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let attribute_item_0_built = AttributeItemIdentifierBuilder::default()
+            .identifier(Box::new(identifier))
+            .build()
+            .into_diagnostic()?;
+        let attribute_item_0_built = AttributeItem::Identifier(attribute_item_0_built);
+        // Calling user action here
+        self.user_grammar.attribute_item(&attribute_item_0_built)?;
+        self.push(ASTType::AttributeItem(attribute_item_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 495:
+    ///
+    /// AttributeItem: Number;
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_item_1(
+        &mut self,
+        _number: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let number = pop_item!(self, number, Number, context);
+        let attribute_item_1_built = AttributeItemNumberBuilder::default()
+            .number(Box::new(number))
+            .build()
+            .into_diagnostic()?;
+        let attribute_item_1_built = AttributeItem::Number(attribute_item_1_built);
+        // Calling user action here
+        self.user_grammar.attribute_item(&attribute_item_1_built)?;
+        self.push(ASTType::AttributeItem(attribute_item_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 496:
+    ///
+    /// VarDeclaration: Var Identifier Colon Type VarDeclarationOpt /* Option */ Semicolon;
+    ///
+    #[parol_runtime::function_name::named]
+    fn var_declaration(
+        &mut self,
+        _var: &ParseTreeStackEntry<'t>,
+        _identifier: &ParseTreeStackEntry<'t>,
+        _colon: &ParseTreeStackEntry<'t>,
+        _type: &ParseTreeStackEntry<'t>,
+        _var_declaration_opt: &ParseTreeStackEntry<'t>,
+        _semicolon: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let semicolon = pop_item!(self, semicolon, Semicolon, context);
+        let var_declaration_opt = pop_item!(self, var_declaration_opt, VarDeclarationOpt, context);
+        let r#type = pop_item!(self, r#type, Type, context);
+        let colon = pop_item!(self, colon, Colon, context);
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let var = pop_item!(self, var, Var, context);
+        let var_declaration_built = VarDeclarationBuilder::default()
+            .var(Box::new(var))
+            .identifier(Box::new(identifier))
+            .colon(Box::new(colon))
+            .r#type(Box::new(r#type))
+            .var_declaration_opt(var_declaration_opt)
+            .semicolon(Box::new(semicolon))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.var_declaration(&var_declaration_built)?;
+        self.push(ASTType::VarDeclaration(var_declaration_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 497:
+    ///
+    /// VarDeclarationOpt /* Option<T>::Some */: Equ Expression;
+    ///
+    #[parol_runtime::function_name::named]
+    fn var_declaration_opt_0(
+        &mut self,
+        _equ: &ParseTreeStackEntry<'t>,
+        _expression: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let expression = pop_item!(self, expression, Expression, context);
+        let equ = pop_item!(self, equ, Equ, context);
+        let var_declaration_opt_0_built = VarDeclarationOptBuilder::default()
+            .equ(Box::new(equ))
+            .expression(Box::new(expression))
+            .build()
+            .into_diagnostic()?;
+        self.push(
+            ASTType::VarDeclarationOpt(Some(Box::new(var_declaration_opt_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 498:
+    ///
+    /// VarDeclarationOpt /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn var_declaration_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::VarDeclarationOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 499:
+    ///
+    /// LocalparamDeclaration: Localparam Identifier Colon Type Equ Expression Semicolon;
+    ///
+    #[parol_runtime::function_name::named]
+    fn localparam_declaration(
+        &mut self,
+        _localparam: &ParseTreeStackEntry<'t>,
+        _identifier: &ParseTreeStackEntry<'t>,
+        _colon: &ParseTreeStackEntry<'t>,
+        _type: &ParseTreeStackEntry<'t>,
+        _equ: &ParseTreeStackEntry<'t>,
+        _expression: &ParseTreeStackEntry<'t>,
+        _semicolon: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let semicolon = pop_item!(self, semicolon, Semicolon, context);
+        let expression = pop_item!(self, expression, Expression, context);
+        let equ = pop_item!(self, equ, Equ, context);
+        let r#type = pop_item!(self, r#type, Type, context);
+        let colon = pop_item!(self, colon, Colon, context);
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let localparam = pop_item!(self, localparam, Localparam, context);
+        let localparam_declaration_built = LocalparamDeclarationBuilder::default()
+            .localparam(Box::new(localparam))
+            .identifier(Box::new(identifier))
+            .colon(Box::new(colon))
+            .r#type(Box::new(r#type))
+            .equ(Box::new(equ))
+            .expression(Box::new(expression))
+            .semicolon(Box::new(semicolon))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .localparam_declaration(&localparam_declaration_built)?;
+        self.push(
+            ASTType::LocalparamDeclaration(localparam_declaration_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 500:
+    ///
+    /// TypeDeclaration: Typ Identifier Equ Type Semicolon;
+    ///
+    #[parol_runtime::function_name::named]
+    fn type_declaration(
+        &mut self,
+        _typ: &ParseTreeStackEntry<'t>,
+        _identifier: &ParseTreeStackEntry<'t>,
+        _equ: &ParseTreeStackEntry<'t>,
+        _type: &ParseTreeStackEntry<'t>,
+        _semicolon: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let semicolon = pop_item!(self, semicolon, Semicolon, context);
+        let r#type = pop_item!(self, r#type, Type, context);
+        let equ = pop_item!(self, equ, Equ, context);
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let typ = pop_item!(self, typ, Typ, context);
+        let type_declaration_built = TypeDeclarationBuilder::default()
+            .typ(Box::new(typ))
+            .identifier(Box::new(identifier))
+            .equ(Box::new(equ))
+            .r#type(Box::new(r#type))
+            .semicolon(Box::new(semicolon))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .type_declaration(&type_declaration_built)?;
+        self.push(ASTType::TypeDeclaration(type_declaration_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 501:
+    ///
+    /// AlwaysFfDeclaration: AlwaysFf LParen AlwaysFfClock AlwaysFfDeclarationOpt /* Option */ RParen LBrace AlwaysFfDeclarationList /* Vec */ RBrace;
+    ///
+    #[parol_runtime::function_name::named]
+    fn always_ff_declaration(
+        &mut self,
+        _always_ff: &ParseTreeStackEntry<'t>,
+        _l_paren: &ParseTreeStackEntry<'t>,
+        _always_ff_clock: &ParseTreeStackEntry<'t>,
+        _always_ff_declaration_opt: &ParseTreeStackEntry<'t>,
+        _r_paren: &ParseTreeStackEntry<'t>,
+        _l_brace: &ParseTreeStackEntry<'t>,
+        _always_ff_declaration_list: &ParseTreeStackEntry<'t>,
+        _r_brace: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r_brace = pop_item!(self, r_brace, RBrace, context);
+        let always_ff_declaration_list = pop_and_reverse_item!(
+            self,
+            always_ff_declaration_list,
+            AlwaysFfDeclarationList,
+            context
+        );
+        let l_brace = pop_item!(self, l_brace, LBrace, context);
+        let r_paren = pop_item!(self, r_paren, RParen, context);
+        let always_ff_declaration_opt = pop_item!(
+            self,
+            always_ff_declaration_opt,
+            AlwaysFfDeclarationOpt,
+            context
+        );
+        let always_ff_clock = pop_item!(self, always_ff_clock, AlwaysFfClock, context);
+        let l_paren = pop_item!(self, l_paren, LParen, context);
+        let always_ff = pop_item!(self, always_ff, AlwaysFf, context);
+        let always_ff_declaration_built = AlwaysFfDeclarationBuilder::default()
+            .always_ff(Box::new(always_ff))
+            .l_paren(Box::new(l_paren))
+            .always_ff_clock(Box::new(always_ff_clock))
+            .always_ff_declaration_opt(always_ff_declaration_opt)
+            .r_paren(Box::new(r_paren))
+            .l_brace(Box::new(l_brace))
+            .always_ff_declaration_list(always_ff_declaration_list)
+            .r_brace(Box::new(r_brace))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .always_ff_declaration(&always_ff_declaration_built)?;
+        self.push(
+            ASTType::AlwaysFfDeclaration(always_ff_declaration_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 502:
+    ///
+    /// AlwaysFfDeclarationList /* Vec<T>::Push */: Statement AlwaysFfDeclarationList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn always_ff_declaration_list_0(
+        &mut self,
+        _statement: &ParseTreeStackEntry<'t>,
+        _always_ff_declaration_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
@@ -19231,7 +22764,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 428:
+    /// Semantic action for production 503:
     ///
     /// AlwaysFfDeclarationList /* Vec<T>::New */: ;
     ///
@@ -19250,7 +22783,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 429:
+    /// Semantic action for production 504:
     ///
     /// AlwaysFfDeclarationOpt /* Option<T>::Some */: Comma AlwaysFfReset;
     ///
@@ -19277,7 +22810,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 430:
+    /// Semantic action for production 505:
     ///
     /// AlwaysFfDeclarationOpt /* Option<T>::None */: ;
     ///
@@ -19289,7 +22822,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 431:
+    /// Semantic action for production 506:
     ///
     /// AlwaysFfClock: AlwaysFfClockOpt /* Option */ HierarchicalIdentifier;
     ///
@@ -19320,7 +22853,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 432:
+    /// Semantic action for production 507:
     ///
     /// AlwaysFfClockOpt /* Option<T>::Some */: AlwaysFfClockOptGroup;
     ///
@@ -19349,7 +22882,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 433:
+    /// Semantic action for production 508:
     ///
     /// AlwaysFfClockOptGroup: Posedge;
     ///
@@ -19375,7 +22908,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 434:
+    /// Semantic action for production 509:
     ///
     /// AlwaysFfClockOptGroup: Negedge;
     ///
@@ -19401,7 +22934,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 435:
+    /// Semantic action for production 510:
     ///
     /// AlwaysFfClockOpt /* Option<T>::None */: ;
     ///
@@ -19413,7 +22946,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 436:
+    /// Semantic action for production 511:
     ///
     /// AlwaysFfReset: AlwaysFfResetOpt /* Option */ HierarchicalIdentifier;
     ///
@@ -19444,7 +22977,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 437:
+    /// Semantic action for production 512:
     ///
     /// AlwaysFfResetOpt /* Option<T>::Some */: AlwaysFfResetOptGroup;
     ///
@@ -19473,7 +23006,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 438:
+    /// Semantic action for production 513:
     ///
     /// AlwaysFfResetOptGroup: AsyncLow;
     ///
@@ -19493,196 +23026,454 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         let always_ff_reset_opt_group_0_built =
             AlwaysFfResetOptGroup::AsyncLow(always_ff_reset_opt_group_0_built);
         self.push(
-            ASTType::AlwaysFfResetOptGroup(always_ff_reset_opt_group_0_built),
+            ASTType::AlwaysFfResetOptGroup(always_ff_reset_opt_group_0_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 514:
+    ///
+    /// AlwaysFfResetOptGroup: AsyncHigh;
+    ///
+    #[parol_runtime::function_name::named]
+    fn always_ff_reset_opt_group_1(
+        &mut self,
+        _async_high: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let async_high = pop_item!(self, async_high, AsyncHigh, context);
+        let always_ff_reset_opt_group_1_built = AlwaysFfResetOptGroupAsyncHighBuilder::default()
+            .async_high(Box::new(async_high))
+            .build()
+            .into_diagnostic()?;
+        let always_ff_reset_opt_group_1_built =
+            AlwaysFfResetOptGroup::AsyncHigh(always_ff_reset_opt_group_1_built);
+        self.push(
+            ASTType::AlwaysFfResetOptGroup(always_ff_reset_opt_group_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 515:
+    ///
+    /// AlwaysFfResetOptGroup: SyncLow;
+    ///
+    #[parol_runtime::function_name::named]
+    fn always_ff_reset_opt_group_2(
+        &mut self,
+        _sync_low: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let sync_low = pop_item!(self, sync_low, SyncLow, context);
+        let always_ff_reset_opt_group_2_built = AlwaysFfResetOptGroupSyncLowBuilder::default()
+            .sync_low(Box::new(sync_low))
+            .build()
+            .into_diagnostic()?;
+        let always_ff_reset_opt_group_2_built =
+            AlwaysFfResetOptGroup::SyncLow(always_ff_reset_opt_group_2_built);
+        self.push(
+            ASTType::AlwaysFfResetOptGroup(always_ff_reset_opt_group_2_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 516:
+    ///
+    /// AlwaysFfResetOptGroup: SyncHigh;
+    ///
+    #[parol_runtime::function_name::named]
+    fn always_ff_reset_opt_group_3(
+        &mut self,
+        _sync_high: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let sync_high = pop_item!(self, sync_high, SyncHigh, context);
+        let always_ff_reset_opt_group_3_built = AlwaysFfResetOptGroupSyncHighBuilder::default()
+            .sync_high(Box::new(sync_high))
+            .build()
+            .into_diagnostic()?;
+        let always_ff_reset_opt_group_3_built =
+            AlwaysFfResetOptGroup::SyncHigh(always_ff_reset_opt_group_3_built);
+        self.push(
+            ASTType::AlwaysFfResetOptGroup(always_ff_reset_opt_group_3_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 517:
+    ///
+    /// AlwaysFfResetOpt /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn always_ff_reset_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::AlwaysFfResetOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 518:
+    ///
+    /// AlwaysCombDeclaration: AlwaysComb LBrace AlwaysCombDeclarationList /* Vec */ RBrace;
+    ///
+    #[parol_runtime::function_name::named]
+    fn always_comb_declaration(
+        &mut self,
+        _always_comb: &ParseTreeStackEntry<'t>,
+        _l_brace: &ParseTreeStackEntry<'t>,
+        _always_comb_declaration_list: &ParseTreeStackEntry<'t>,
+        _r_brace: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r_brace = pop_item!(self, r_brace, RBrace, context);
+        let always_comb_declaration_list = pop_and_reverse_item!(
+            self,
+            always_comb_declaration_list,
+            AlwaysCombDeclarationList,
+            context
+        );
+        let l_brace = pop_item!(self, l_brace, LBrace, context);
+        let always_comb = pop_item!(self, always_comb, AlwaysComb, context);
+        let always_comb_declaration_built = AlwaysCombDeclarationBuilder::default()
+            .always_comb(Box::new(always_comb))
+            .l_brace(Box::new(l_brace))
+            .always_comb_declaration_list(always_comb_declaration_list)
+            .r_brace(Box::new(r_brace))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .always_comb_declaration(&always_comb_declaration_built)?;
+        self.push(
+            ASTType::AlwaysCombDeclaration(always_comb_declaration_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 519:
+    ///
+    /// AlwaysCombDeclarationList /* Vec<T>::Push */: Statement AlwaysCombDeclarationList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn always_comb_declaration_list_0(
+        &mut self,
+        _statement: &ParseTreeStackEntry<'t>,
+        _always_comb_declaration_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut always_comb_declaration_list = pop_item!(
+            self,
+            always_comb_declaration_list,
+            AlwaysCombDeclarationList,
+            context
+        );
+        let statement = pop_item!(self, statement, Statement, context);
+        let always_comb_declaration_list_0_built = AlwaysCombDeclarationListBuilder::default()
+            .statement(Box::new(statement))
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        always_comb_declaration_list.push(always_comb_declaration_list_0_built);
+        self.push(
+            ASTType::AlwaysCombDeclarationList(always_comb_declaration_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 520:
+    ///
+    /// AlwaysCombDeclarationList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn always_comb_declaration_list_1(
+        &mut self,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let always_comb_declaration_list_1_built = Vec::new();
+        self.push(
+            ASTType::AlwaysCombDeclarationList(always_comb_declaration_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 521:
+    ///
+    /// AssertionDeclaration: AssertionKind LParen AlwaysFfClock AssertionDeclarationOpt /* Option */ RParen Expression Semicolon;
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_declaration(
+        &mut self,
+        _assertion_kind: &ParseTreeStackEntry<'t>,
+        _l_paren: &ParseTreeStackEntry<'t>,
+        _always_ff_clock: &ParseTreeStackEntry<'t>,
+        _assertion_declaration_opt: &ParseTreeStackEntry<'t>,
+        _r_paren: &ParseTreeStackEntry<'t>,
+        _expression: &ParseTreeStackEntry<'t>,
+        _semicolon: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let semicolon = pop_item!(self, semicolon, Semicolon, context);
+        let expression = pop_item!(self, expression, Expression, context);
+        let r_paren = pop_item!(self, r_paren, RParen, context);
+        let assertion_declaration_opt = pop_item!(
+            self,
+            assertion_declaration_opt,
+            AssertionDeclarationOpt,
+            context
+        );
+        let always_ff_clock = pop_item!(self, always_ff_clock, AlwaysFfClock, context);
+        let l_paren = pop_item!(self, l_paren, LParen, context);
+        let assertion_kind = pop_item!(self, assertion_kind, AssertionKind, context);
+        let assertion_declaration_built = AssertionDeclarationBuilder::default()
+            .assertion_kind(Box::new(assertion_kind))
+            .l_paren(Box::new(l_paren))
+            .always_ff_clock(Box::new(always_ff_clock))
+            .assertion_declaration_opt(assertion_declaration_opt)
+            .r_paren(Box::new(r_paren))
+            .expression(Box::new(expression))
+            .semicolon(Box::new(semicolon))
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar
+            .assertion_declaration(&assertion_declaration_built)?;
+        self.push(
+            ASTType::AssertionDeclaration(assertion_declaration_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 522:
+    ///
+    /// AssertionDeclarationOpt /* Option<T>::Some */: Comma AlwaysFfReset;
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_declaration_opt_0(
+        &mut self,
+        _comma: &ParseTreeStackEntry<'t>,
+        _always_ff_reset: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let always_ff_reset = pop_item!(self, always_ff_reset, AlwaysFfReset, context);
+        let comma = pop_item!(self, comma, Comma, context);
+        let assertion_declaration_opt_0_built = AssertionDeclarationOptBuilder::default()
+            .comma(Box::new(comma))
+            .always_ff_reset(Box::new(always_ff_reset))
+            .build()
+            .into_diagnostic()?;
+        self.push(
+            ASTType::AssertionDeclarationOpt(Some(Box::new(assertion_declaration_opt_0_built))),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 439:
+    /// Semantic action for production 523:
     ///
-    /// AlwaysFfResetOptGroup: AsyncHigh;
+    /// AssertionDeclarationOpt /* Option<T>::None */: ;
     ///
     #[parol_runtime::function_name::named]
-    fn always_ff_reset_opt_group_1(
-        &mut self,
-        _async_high: &ParseTreeStackEntry<'t>,
-        _parse_tree: &Tree<ParseTreeType<'t>>,
-    ) -> Result<()> {
+    fn assertion_declaration_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let async_high = pop_item!(self, async_high, AsyncHigh, context);
-        let always_ff_reset_opt_group_1_built = AlwaysFfResetOptGroupAsyncHighBuilder::default()
-            .async_high(Box::new(async_high))
-            .build()
-            .into_diagnostic()?;
-        let always_ff_reset_opt_group_1_built =
-            AlwaysFfResetOptGroup::AsyncHigh(always_ff_reset_opt_group_1_built);
-        self.push(
-            ASTType::AlwaysFfResetOptGroup(always_ff_reset_opt_group_1_built),
-            context,
-        );
+        self.push(ASTType::AssertionDeclarationOpt(None), context);
         Ok(())
     }
 
-    /// Semantic action for production 440:
+    /// Semantic action for production 524:
     ///
-    /// AlwaysFfResetOptGroup: SyncLow;
+    /// InitialDeclaration: Initial LBrace InitialDeclarationList /* Vec */ RBrace;
     ///
     #[parol_runtime::function_name::named]
-    fn always_ff_reset_opt_group_2(
+    fn initial_declaration(
         &mut self,
-        _sync_low: &ParseTreeStackEntry<'t>,
+        _initial: &ParseTreeStackEntry<'t>,
+        _l_brace: &ParseTreeStackEntry<'t>,
+        _initial_declaration_list: &ParseTreeStackEntry<'t>,
+        _r_brace: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let sync_low = pop_item!(self, sync_low, SyncLow, context);
-        let always_ff_reset_opt_group_2_built = AlwaysFfResetOptGroupSyncLowBuilder::default()
-            .sync_low(Box::new(sync_low))
+        let r_brace = pop_item!(self, r_brace, RBrace, context);
+        let initial_declaration_list = pop_and_reverse_item!(
+            self,
+            initial_declaration_list,
+            InitialDeclarationList,
+            context
+        );
+        let l_brace = pop_item!(self, l_brace, LBrace, context);
+        let initial = pop_item!(self, initial, Initial, context);
+        let initial_declaration_built = InitialDeclarationBuilder::default()
+            .initial(Box::new(initial))
+            .l_brace(Box::new(l_brace))
+            .initial_declaration_list(initial_declaration_list)
+            .r_brace(Box::new(r_brace))
             .build()
             .into_diagnostic()?;
-        let always_ff_reset_opt_group_2_built =
-            AlwaysFfResetOptGroup::SyncLow(always_ff_reset_opt_group_2_built);
+        // Calling user action here
+        self.user_grammar
+            .initial_declaration(&initial_declaration_built)?;
         self.push(
-            ASTType::AlwaysFfResetOptGroup(always_ff_reset_opt_group_2_built),
+            ASTType::InitialDeclaration(initial_declaration_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 441:
+    /// Semantic action for production 525:
     ///
-    /// AlwaysFfResetOptGroup: SyncHigh;
+    /// InitialDeclarationList /* Vec<T>::Push */: Statement InitialDeclarationList;
     ///
     #[parol_runtime::function_name::named]
-    fn always_ff_reset_opt_group_3(
+    fn initial_declaration_list_0(
         &mut self,
-        _sync_high: &ParseTreeStackEntry<'t>,
+        _statement: &ParseTreeStackEntry<'t>,
+        _initial_declaration_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let sync_high = pop_item!(self, sync_high, SyncHigh, context);
-        let always_ff_reset_opt_group_3_built = AlwaysFfResetOptGroupSyncHighBuilder::default()
-            .sync_high(Box::new(sync_high))
+        let mut initial_declaration_list = pop_item!(
+            self,
+            initial_declaration_list,
+            InitialDeclarationList,
+            context
+        );
+        let statement = pop_item!(self, statement, Statement, context);
+        let initial_declaration_list_0_built = InitialDeclarationListBuilder::default()
+            .statement(Box::new(statement))
             .build()
             .into_diagnostic()?;
-        let always_ff_reset_opt_group_3_built =
-            AlwaysFfResetOptGroup::SyncHigh(always_ff_reset_opt_group_3_built);
+        // Add an element to the vector
+        initial_declaration_list.push(initial_declaration_list_0_built);
         self.push(
-            ASTType::AlwaysFfResetOptGroup(always_ff_reset_opt_group_3_built),
+            ASTType::InitialDeclarationList(initial_declaration_list),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 442:
+    /// Semantic action for production 526:
     ///
-    /// AlwaysFfResetOpt /* Option<T>::None */: ;
+    /// InitialDeclarationList /* Vec<T>::New */: ;
     ///
     #[parol_runtime::function_name::named]
-    fn always_ff_reset_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+    fn initial_declaration_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        self.push(ASTType::AlwaysFfResetOpt(None), context);
+        let initial_declaration_list_1_built = Vec::new();
+        self.push(
+            ASTType::InitialDeclarationList(initial_declaration_list_1_built),
+            context,
+        );
         Ok(())
     }
 
-    /// Semantic action for production 443:
+    /// Semantic action for production 527:
     ///
-    /// AlwaysCombDeclaration: AlwaysComb LBrace AlwaysCombDeclarationList /* Vec */ RBrace;
+    /// FinalDeclaration: Final LBrace FinalDeclarationList /* Vec */ RBrace;
     ///
     #[parol_runtime::function_name::named]
-    fn always_comb_declaration(
+    fn final_declaration(
         &mut self,
-        _always_comb: &ParseTreeStackEntry<'t>,
+        _final: &ParseTreeStackEntry<'t>,
         _l_brace: &ParseTreeStackEntry<'t>,
-        _always_comb_declaration_list: &ParseTreeStackEntry<'t>,
+        _final_declaration_list: &ParseTreeStackEntry<'t>,
         _r_brace: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let r_brace = pop_item!(self, r_brace, RBrace, context);
-        let always_comb_declaration_list = pop_and_reverse_item!(
-            self,
-            always_comb_declaration_list,
-            AlwaysCombDeclarationList,
-            context
-        );
+        let final_declaration_list =
+            pop_and_reverse_item!(self, final_declaration_list, FinalDeclarationList, context);
         let l_brace = pop_item!(self, l_brace, LBrace, context);
-        let always_comb = pop_item!(self, always_comb, AlwaysComb, context);
-        let always_comb_declaration_built = AlwaysCombDeclarationBuilder::default()
-            .always_comb(Box::new(always_comb))
+        let r#final = pop_item!(self, r#final, Final, context);
+        let final_declaration_built = FinalDeclarationBuilder::default()
+            .r#final(Box::new(r#final))
             .l_brace(Box::new(l_brace))
-            .always_comb_declaration_list(always_comb_declaration_list)
+            .final_declaration_list(final_declaration_list)
             .r_brace(Box::new(r_brace))
             .build()
             .into_diagnostic()?;
         // Calling user action here
         self.user_grammar
-            .always_comb_declaration(&always_comb_declaration_built)?;
-        self.push(
-            ASTType::AlwaysCombDeclaration(always_comb_declaration_built),
-            context,
-        );
+            .final_declaration(&final_declaration_built)?;
+        self.push(ASTType::FinalDeclaration(final_declaration_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 444:
+    /// Semantic action for production 528:
     ///
-    /// AlwaysCombDeclarationList /* Vec<T>::Push */: Statement AlwaysCombDeclarationList;
+    /// FinalDeclarationList /* Vec<T>::Push */: Statement FinalDeclarationList;
     ///
     #[parol_runtime::function_name::named]
-    fn always_comb_declaration_list_0(
+    fn final_declaration_list_0(
         &mut self,
         _statement: &ParseTreeStackEntry<'t>,
-        _always_comb_declaration_list: &ParseTreeStackEntry<'t>,
+        _final_declaration_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let mut always_comb_declaration_list = pop_item!(
-            self,
-            always_comb_declaration_list,
-            AlwaysCombDeclarationList,
-            context
-        );
+        let mut final_declaration_list =
+            pop_item!(self, final_declaration_list, FinalDeclarationList, context);
         let statement = pop_item!(self, statement, Statement, context);
-        let always_comb_declaration_list_0_built = AlwaysCombDeclarationListBuilder::default()
+        let final_declaration_list_0_built = FinalDeclarationListBuilder::default()
             .statement(Box::new(statement))
             .build()
             .into_diagnostic()?;
         // Add an element to the vector
-        always_comb_declaration_list.push(always_comb_declaration_list_0_built);
+        final_declaration_list.push(final_declaration_list_0_built);
         self.push(
-            ASTType::AlwaysCombDeclarationList(always_comb_declaration_list),
+            ASTType::FinalDeclarationList(final_declaration_list),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 445:
+    /// Semantic action for production 529:
     ///
-    /// AlwaysCombDeclarationList /* Vec<T>::New */: ;
+    /// FinalDeclarationList /* Vec<T>::New */: ;
     ///
     #[parol_runtime::function_name::named]
-    fn always_comb_declaration_list_1(
-        &mut self,
-        _parse_tree: &Tree<ParseTreeType<'t>>,
-    ) -> Result<()> {
+    fn final_declaration_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let always_comb_declaration_list_1_built = Vec::new();
+        let final_declaration_list_1_built = Vec::new();
         self.push(
-            ASTType::AlwaysCombDeclarationList(always_comb_declaration_list_1_built),
+            ASTType::FinalDeclarationList(final_declaration_list_1_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 446:
+    /// Semantic action for production 530:
     ///
     /// AssignDeclaration: Assign HierarchicalIdentifier Equ Expression Semicolon;
     ///
@@ -19726,7 +23517,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 447:
+    /// Semantic action for production 531:
     ///
     /// ModportDeclaration: Modport Identifier LBrace ModportList RBrace;
     ///
@@ -19765,7 +23556,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 448:
+    /// Semantic action for production 532:
     ///
     /// ModportList: ModportItem ModportListList /* Vec */ ModportListOpt /* Option */;
     ///
@@ -19795,7 +23586,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 449:
+    /// Semantic action for production 533:
     ///
     /// ModportListList /* Vec<T>::Push */: Comma ModportItem ModportListList;
     ///
@@ -19823,7 +23614,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 450:
+    /// Semantic action for production 534:
     ///
     /// ModportListList /* Vec<T>::New */: ;
     ///
@@ -19836,7 +23627,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 451:
+    /// Semantic action for production 535:
     ///
     /// ModportListOpt /* Option<T>::Some */: Comma;
     ///
@@ -19860,7 +23651,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 452:
+    /// Semantic action for production 536:
     ///
     /// ModportListOpt /* Option<T>::None */: ;
     ///
@@ -19872,7 +23663,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 453:
+    /// Semantic action for production 537:
     ///
     /// ModportItem: Identifier Colon Direction;
     ///
@@ -19901,7 +23692,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 454:
+    /// Semantic action for production 538:
     ///
     /// EnumDeclaration: Enum Identifier Colon Type LBrace EnumList RBrace;
     ///
@@ -19943,7 +23734,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 455:
+    /// Semantic action for production 539:
     ///
     /// EnumList: EnumItem EnumListList /* Vec */ EnumListOpt /* Option */;
     ///
@@ -19972,7 +23763,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 456:
+    /// Semantic action for production 540:
     ///
     /// EnumListList /* Vec<T>::Push */: Comma EnumItem EnumListList;
     ///
@@ -20000,7 +23791,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 457:
+    /// Semantic action for production 541:
     ///
     /// EnumListList /* Vec<T>::New */: ;
     ///
@@ -20013,7 +23804,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 458:
+    /// Semantic action for production 542:
     ///
     /// EnumListOpt /* Option<T>::Some */: Comma;
     ///
@@ -20037,7 +23828,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 459:
+    /// Semantic action for production 543:
     ///
     /// EnumListOpt /* Option<T>::None */: ;
     ///
@@ -20049,7 +23840,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 460:
+    /// Semantic action for production 544:
     ///
     /// EnumItem: Identifier EnumItemOpt /* Option */;
     ///
@@ -20075,7 +23866,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 461:
+    /// Semantic action for production 545:
     ///
     /// EnumItemOpt /* Option<T>::Some */: Equ Expression;
     ///
@@ -20102,7 +23893,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 462:
+    /// Semantic action for production 546:
     ///
     /// EnumItemOpt /* Option<T>::None */: ;
     ///
@@ -20114,14 +23905,63 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 463:
+    /// Semantic action for production 547:
     ///
-    /// StructDeclaration: Struct Identifier LBrace StructList RBrace;
+    /// StructUnion: Struct;
     ///
     #[parol_runtime::function_name::named]
-    fn struct_declaration(
+    fn struct_union_0(
         &mut self,
         _struct: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r#struct = pop_item!(self, r#struct, Struct, context);
+        let struct_union_0_built = StructUnionStructBuilder::default()
+            .r#struct(Box::new(r#struct))
+            .build()
+            .into_diagnostic()?;
+        let struct_union_0_built = StructUnion::Struct(struct_union_0_built);
+        // Calling user action here
+        self.user_grammar.struct_union(&struct_union_0_built)?;
+        self.push(ASTType::StructUnion(struct_union_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 548:
+    ///
+    /// StructUnion: Union;
+    ///
+    #[parol_runtime::function_name::named]
+    fn struct_union_1(
+        &mut self,
+        _union: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r#union = pop_item!(self, r#union, Union, context);
+        let struct_union_1_built = StructUnionUnionBuilder::default()
+            .r#union(Box::new(r#union))
+            .build()
+            .into_diagnostic()?;
+        let struct_union_1_built = StructUnion::Union(struct_union_1_built);
+        // Calling user action here
+        self.user_grammar.struct_union(&struct_union_1_built)?;
+        self.push(ASTType::StructUnion(struct_union_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 549:
+    ///
+    /// StructDeclaration: StructUnion StructDeclarationOpt /* Option */ Identifier LBrace StructList RBrace;
+    ///
+    #[parol_runtime::function_name::named]
+    fn struct_declaration(
+        &mut self,
+        _struct_union: &ParseTreeStackEntry<'t>,
+        _struct_declaration_opt: &ParseTreeStackEntry<'t>,
         _identifier: &ParseTreeStackEntry<'t>,
         _l_brace: &ParseTreeStackEntry<'t>,
         _struct_list: &ParseTreeStackEntry<'t>,
@@ -20134,9 +23974,12 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         let struct_list = pop_item!(self, struct_list, StructList, context);
         let l_brace = pop_item!(self, l_brace, LBrace, context);
         let identifier = pop_item!(self, identifier, Identifier, context);
-        let r#struct = pop_item!(self, r#struct, Struct, context);
+        let struct_declaration_opt =
+            pop_item!(self, struct_declaration_opt, StructDeclarationOpt, context);
+        let struct_union = pop_item!(self, struct_union, StructUnion, context);
         let struct_declaration_built = StructDeclarationBuilder::default()
-            .r#struct(Box::new(r#struct))
+            .struct_union(Box::new(struct_union))
+            .struct_declaration_opt(struct_declaration_opt)
             .identifier(Box::new(identifier))
             .l_brace(Box::new(l_brace))
             .struct_list(Box::new(struct_list))
@@ -20153,7 +23996,43 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 464:
+    /// Semantic action for production 550:
+    ///
+    /// StructDeclarationOpt /* Option<T>::Some */: Unpacked;
+    ///
+    #[parol_runtime::function_name::named]
+    fn struct_declaration_opt_0(
+        &mut self,
+        _unpacked: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let unpacked = pop_item!(self, unpacked, Unpacked, context);
+        let struct_declaration_opt_0_built = StructDeclarationOptBuilder::default()
+            .unpacked(Box::new(unpacked))
+            .build()
+            .into_diagnostic()?;
+        self.push(
+            ASTType::StructDeclarationOpt(Some(Box::new(struct_declaration_opt_0_built))),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 551:
+    ///
+    /// StructDeclarationOpt /* Option<T>::None */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn struct_declaration_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::StructDeclarationOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 552:
     ///
     /// StructList: StructItem StructListList /* Vec */ StructListOpt /* Option */;
     ///
@@ -20183,7 +24062,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 465:
+    /// Semantic action for production 553:
     ///
     /// StructListList /* Vec<T>::Push */: Comma StructItem StructListList;
     ///
@@ -20211,7 +24090,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 466:
+    /// Semantic action for production 554:
     ///
     /// StructListList /* Vec<T>::New */: ;
     ///
@@ -20224,7 +24103,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 467:
+    /// Semantic action for production 555:
     ///
     /// StructListOpt /* Option<T>::Some */: Comma;
     ///
@@ -20248,7 +24127,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 468:
+    /// Semantic action for production 556:
     ///
     /// StructListOpt /* Option<T>::None */: ;
     ///
@@ -20260,7 +24139,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 469:
+    /// Semantic action for production 557:
     ///
     /// StructItem: Identifier Colon Type;
     ///
@@ -20289,7 +24168,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 470:
+    /// Semantic action for production 558:
     ///
     /// InstDeclaration: Inst Identifier Colon Identifier InstDeclarationOpt /* Option */ InstDeclarationOpt0 /* Option */ InstDeclarationOpt1 /* Option */ Semicolon;
     ///
@@ -20337,7 +24216,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 471:
+    /// Semantic action for production 559:
     ///
     /// InstDeclarationOpt1 /* Option<T>::Some */: LParen InstDeclarationOpt2 /* Option */ RParen;
     ///
@@ -20368,7 +24247,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 472:
+    /// Semantic action for production 560:
     ///
     /// InstDeclarationOpt2 /* Option<T>::Some */: InstPortList;
     ///
@@ -20392,7 +24271,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 473:
+    /// Semantic action for production 561:
     ///
     /// InstDeclarationOpt2 /* Option<T>::None */: ;
     ///
@@ -20404,7 +24283,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 474:
+    /// Semantic action for production 562:
     ///
     /// InstDeclarationOpt1 /* Option<T>::None */: ;
     ///
@@ -20416,7 +24295,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 475:
+    /// Semantic action for production 563:
     ///
     /// InstDeclarationOpt0 /* Option<T>::Some */: InstParameter;
     ///
@@ -20440,7 +24319,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 476:
+    /// Semantic action for production 564:
     ///
     /// InstDeclarationOpt0 /* Option<T>::None */: ;
     ///
@@ -20452,7 +24331,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 477:
+    /// Semantic action for production 565:
     ///
     /// InstDeclarationOpt /* Option<T>::Some */: Width;
     ///
@@ -20476,7 +24355,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 478:
+    /// Semantic action for production 566:
     ///
     /// InstDeclarationOpt /* Option<T>::None */: ;
     ///
@@ -20488,7 +24367,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 479:
+    /// Semantic action for production 567:
     ///
     /// InstParameter: Hash LParen InstParameterOpt /* Option */ RParen;
     ///
@@ -20520,7 +24399,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 480:
+    /// Semantic action for production 568:
     ///
     /// InstParameterOpt /* Option<T>::Some */: InstParameterList;
     ///
@@ -20544,7 +24423,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 481:
+    /// Semantic action for production 569:
     ///
     /// InstParameterOpt /* Option<T>::None */: ;
     ///
@@ -20556,7 +24435,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 482:
+    /// Semantic action for production 570:
     ///
     /// InstParameterList: InstParameterItem InstParameterListList /* Vec */ InstParameterListOpt /* Option */;
     ///
@@ -20595,7 +24474,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 483:
+    /// Semantic action for production 571:
     ///
     /// InstParameterListList /* Vec<T>::Push */: Comma InstParameterItem InstParameterListList;
     ///
@@ -20631,7 +24510,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 484:
+    /// Semantic action for production 572:
     ///
     /// InstParameterListList /* Vec<T>::New */: ;
     ///
@@ -20647,7 +24526,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 485:
+    /// Semantic action for production 573:
     ///
     /// InstParameterListOpt /* Option<T>::Some */: Comma;
     ///
@@ -20671,7 +24550,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 486:
+    /// Semantic action for production 574:
     ///
     /// InstParameterListOpt /* Option<T>::None */: ;
     ///
@@ -20683,7 +24562,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 487:
+    /// Semantic action for production 575:
     ///
     /// InstParameterItem: Identifier InstParameterItemOpt /* Option */;
     ///
@@ -20714,24 +24593,24 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 488:
+    /// Semantic action for production 576:
     ///
-    /// InstParameterItemOpt /* Option<T>::Some */: Colon Expression;
+    /// InstParameterItemOpt /* Option<T>::Some */: Colon ParameterValue;
     ///
     #[parol_runtime::function_name::named]
     fn inst_parameter_item_opt_0(
         &mut self,
         _colon: &ParseTreeStackEntry<'t>,
-        _expression: &ParseTreeStackEntry<'t>,
+        _parameter_value: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let expression = pop_item!(self, expression, Expression, context);
+        let parameter_value = pop_item!(self, parameter_value, ParameterValue, context);
         let colon = pop_item!(self, colon, Colon, context);
         let inst_parameter_item_opt_0_built = InstParameterItemOptBuilder::default()
             .colon(Box::new(colon))
-            .expression(Box::new(expression))
+            .parameter_value(Box::new(parameter_value))
             .build()
             .into_diagnostic()?;
         self.push(
@@ -20741,7 +24620,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 489:
+    /// Semantic action for production 577:
     ///
     /// InstParameterItemOpt /* Option<T>::None */: ;
     ///
@@ -20753,7 +24632,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 490:
+    /// Semantic action for production 578:
     ///
     /// InstPortList: InstPortItem InstPortListList /* Vec */ InstPortListOpt /* Option */;
     ///
@@ -20783,7 +24662,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 491:
+    /// Semantic action for production 579:
     ///
     /// InstPortListList /* Vec<T>::Push */: Comma InstPortItem InstPortListList;
     ///
@@ -20812,7 +24691,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 492:
+    /// Semantic action for production 580:
     ///
     /// InstPortListList /* Vec<T>::New */: ;
     ///
@@ -20828,7 +24707,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 493:
+    /// Semantic action for production 581:
     ///
     /// InstPortListOpt /* Option<T>::Some */: Comma;
     ///
@@ -20852,7 +24731,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 494:
+    /// Semantic action for production 582:
     ///
     /// InstPortListOpt /* Option<T>::None */: ;
     ///
@@ -20864,7 +24743,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 495:
+    /// Semantic action for production 583:
     ///
     /// InstPortItem: Identifier InstPortItemOpt /* Option */;
     ///
@@ -20890,7 +24769,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 496:
+    /// Semantic action for production 584:
     ///
     /// InstPortItemOpt /* Option<T>::Some */: Colon Expression;
     ///
@@ -20917,7 +24796,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 497:
+    /// Semantic action for production 585:
     ///
     /// InstPortItemOpt /* Option<T>::None */: ;
     ///
@@ -20929,7 +24808,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 498:
+    /// Semantic action for production 586:
     ///
     /// WithParameter: Hash LParen WithParameterOpt /* Option */ RParen;
     ///
@@ -20961,7 +24840,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 499:
+    /// Semantic action for production 587:
     ///
     /// WithParameterOpt /* Option<T>::Some */: WithParameterList;
     ///
@@ -20985,7 +24864,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 500:
+    /// Semantic action for production 588:
     ///
     /// WithParameterOpt /* Option<T>::None */: ;
     ///
@@ -20997,7 +24876,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 501:
+    /// Semantic action for production 589:
     ///
     /// WithParameterList: WithParameterItem WithParameterListList /* Vec */ WithParameterListOpt /* Option */;
     ///
@@ -21036,7 +24915,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 502:
+    /// Semantic action for production 590:
     ///
     /// WithParameterListList /* Vec<T>::Push */: Comma WithParameterItem WithParameterListList;
     ///
@@ -21072,7 +24951,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 503:
+    /// Semantic action for production 591:
     ///
     /// WithParameterListList /* Vec<T>::New */: ;
     ///
@@ -21088,7 +24967,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 504:
+    /// Semantic action for production 592:
     ///
     /// WithParameterListOpt /* Option<T>::Some */: Comma;
     ///
@@ -21112,7 +24991,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 505:
+    /// Semantic action for production 593:
     ///
     /// WithParameterListOpt /* Option<T>::None */: ;
     ///
@@ -21124,9 +25003,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 506:
+    /// Semantic action for production 594:
     ///
-    /// WithParameterItem: WithParameterItemGroup Identifier Colon Type Equ Expression;
+    /// WithParameterItem: WithParameterItemGroup Identifier Colon WithParameterItemGroup0 Equ ParameterValue;
     ///
     #[parol_runtime::function_name::named]
     fn with_parameter_item(
@@ -21134,16 +25013,21 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         _with_parameter_item_group: &ParseTreeStackEntry<'t>,
         _identifier: &ParseTreeStackEntry<'t>,
         _colon: &ParseTreeStackEntry<'t>,
-        _type: &ParseTreeStackEntry<'t>,
+        _with_parameter_item_group0: &ParseTreeStackEntry<'t>,
         _equ: &ParseTreeStackEntry<'t>,
-        _expression: &ParseTreeStackEntry<'t>,
+        _parameter_value: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let expression = pop_item!(self, expression, Expression, context);
+        let parameter_value = pop_item!(self, parameter_value, ParameterValue, context);
         let equ = pop_item!(self, equ, Equ, context);
-        let r#type = pop_item!(self, r#type, Type, context);
+        let with_parameter_item_group0 = pop_item!(
+            self,
+            with_parameter_item_group0,
+            WithParameterItemGroup0,
+            context
+        );
         let colon = pop_item!(self, colon, Colon, context);
         let identifier = pop_item!(self, identifier, Identifier, context);
         let with_parameter_item_group = pop_item!(
@@ -21156,9 +25040,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
             .with_parameter_item_group(Box::new(with_parameter_item_group))
             .identifier(Box::new(identifier))
             .colon(Box::new(colon))
-            .r#type(Box::new(r#type))
+            .with_parameter_item_group0(Box::new(with_parameter_item_group0))
             .equ(Box::new(equ))
-            .expression(Box::new(expression))
+            .parameter_value(Box::new(parameter_value))
             .build()
             .into_diagnostic()?;
         // Calling user action here
@@ -21171,7 +25055,59 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 507:
+    /// Semantic action for production 595:
+    ///
+    /// WithParameterItemGroup0: Typ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn with_parameter_item_group0_0(
+        &mut self,
+        _typ: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let typ = pop_item!(self, typ, Typ, context);
+        let with_parameter_item_group0_0_built = WithParameterItemGroup0TypBuilder::default()
+            .typ(Box::new(typ))
+            .build()
+            .into_diagnostic()?;
+        let with_parameter_item_group0_0_built =
+            WithParameterItemGroup0::Typ(with_parameter_item_group0_0_built);
+        self.push(
+            ASTType::WithParameterItemGroup0(with_parameter_item_group0_0_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 596:
+    ///
+    /// WithParameterItemGroup0: Type;
+    ///
+    #[parol_runtime::function_name::named]
+    fn with_parameter_item_group0_1(
+        &mut self,
+        _type: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r#type = pop_item!(self, r#type, Type, context);
+        let with_parameter_item_group0_1_built = WithParameterItemGroup0TypeBuilder::default()
+            .r#type(Box::new(r#type))
+            .build()
+            .into_diagnostic()?;
+        let with_parameter_item_group0_1_built =
+            WithParameterItemGroup0::Type(with_parameter_item_group0_1_built);
+        self.push(
+            ASTType::WithParameterItemGroup0(with_parameter_item_group0_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 597:
     ///
     /// WithParameterItemGroup: Parameter;
     ///
@@ -21197,7 +25133,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 508:
+    /// Semantic action for production 598:
     ///
     /// WithParameterItemGroup: Localparam;
     ///
@@ -21223,7 +25159,121 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 509:
+    /// Semantic action for production 599:
+    ///
+    /// ParameterValue: Expression;
+    ///
+    #[parol_runtime::function_name::named]
+    fn parameter_value_0(
+        &mut self,
+        _expression: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let expression = pop_item!(self, expression, Expression, context);
+        let parameter_value_0_built = ParameterValueExpressionBuilder::default()
+            .expression(Box::new(expression))
+            .build()
+            .into_diagnostic()?;
+        let parameter_value_0_built = ParameterValue::Expression(parameter_value_0_built);
+        // Calling user action here
+        self.user_grammar
+            .parameter_value(&parameter_value_0_built)?;
+        self.push(ASTType::ParameterValue(parameter_value_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 600:
+    ///
+    /// ParameterValue: TypeValue;
+    ///
+    #[parol_runtime::function_name::named]
+    fn parameter_value_1(
+        &mut self,
+        _type_value: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let type_value = pop_item!(self, type_value, TypeValue, context);
+        let parameter_value_1_built = ParameterValueTypeValueBuilder::default()
+            .type_value(Box::new(type_value))
+            .build()
+            .into_diagnostic()?;
+        let parameter_value_1_built = ParameterValue::TypeValue(parameter_value_1_built);
+        // Calling user action here
+        self.user_grammar
+            .parameter_value(&parameter_value_1_built)?;
+        self.push(ASTType::ParameterValue(parameter_value_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 601:
+    ///
+    /// TypeValue: BuiltinType TypeValueList /* Vec */;
+    ///
+    #[parol_runtime::function_name::named]
+    fn type_value(
+        &mut self,
+        _builtin_type: &ParseTreeStackEntry<'t>,
+        _type_value_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let type_value_list = pop_and_reverse_item!(self, type_value_list, TypeValueList, context);
+        let builtin_type = pop_item!(self, builtin_type, BuiltinType, context);
+        let type_value_built = TypeValueBuilder::default()
+            .builtin_type(Box::new(builtin_type))
+            .type_value_list(type_value_list)
+            .build()
+            .into_diagnostic()?;
+        // Calling user action here
+        self.user_grammar.type_value(&type_value_built)?;
+        self.push(ASTType::TypeValue(type_value_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 602:
+    ///
+    /// TypeValueList /* Vec<T>::Push */: Width TypeValueList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn type_value_list_0(
+        &mut self,
+        _width: &ParseTreeStackEntry<'t>,
+        _type_value_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut type_value_list = pop_item!(self, type_value_list, TypeValueList, context);
+        let width = pop_item!(self, width, Width, context);
+        let type_value_list_0_built = TypeValueListBuilder::default()
+            .width(Box::new(width))
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        type_value_list.push(type_value_list_0_built);
+        self.push(ASTType::TypeValueList(type_value_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 603:
+    ///
+    /// TypeValueList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn type_value_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let type_value_list_1_built = Vec::new();
+        self.push(ASTType::TypeValueList(type_value_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 604:
     ///
     /// PortDeclaration: LParen PortDeclarationOpt /* Option */ RParen;
     ///
@@ -21254,7 +25304,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 510:
+    /// Semantic action for production 605:
     ///
     /// PortDeclarationOpt /* Option<T>::Some */: PortDeclarationList;
     ///
@@ -21279,7 +25329,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 511:
+    /// Semantic action for production 606:
     ///
     /// PortDeclarationOpt /* Option<T>::None */: ;
     ///
@@ -21291,7 +25341,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 512:
+    /// Semantic action for production 607:
     ///
     /// PortDeclarationList: PortDeclarationItem PortDeclarationListList /* Vec */ PortDeclarationListOpt /* Option */;
     ///
@@ -21335,7 +25385,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 513:
+    /// Semantic action for production 608:
     ///
     /// PortDeclarationListList /* Vec<T>::Push */: Comma PortDeclarationItem PortDeclarationListList;
     ///
@@ -21372,7 +25422,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 514:
+    /// Semantic action for production 609:
     ///
     /// PortDeclarationListList /* Vec<T>::New */: ;
     ///
@@ -21391,7 +25441,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 515:
+    /// Semantic action for production 610:
     ///
     /// PortDeclarationListOpt /* Option<T>::Some */: Comma;
     ///
@@ -21415,7 +25465,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 516:
+    /// Semantic action for production 611:
     ///
     /// PortDeclarationListOpt /* Option<T>::None */: ;
     ///
@@ -21427,13 +25477,14 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 517:
+    /// Semantic action for production 612:
     ///
-    /// PortDeclarationItem: Identifier Colon PortDeclarationItemGroup;
+    /// PortDeclarationItem: PortDeclarationItemList /* Vec */ Identifier Colon PortDeclarationItemGroup;
     ///
     #[parol_runtime::function_name::named]
     fn port_declaration_item(
         &mut self,
+        _port_declaration_item_list: &ParseTreeStackEntry<'t>,
         _identifier: &ParseTreeStackEntry<'t>,
         _colon: &ParseTreeStackEntry<'t>,
         _port_declaration_item_group: &ParseTreeStackEntry<'t>,
@@ -21449,7 +25500,14 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         );
         let colon = pop_item!(self, colon, Colon, context);
         let identifier = pop_item!(self, identifier, Identifier, context);
+        let port_declaration_item_list = pop_and_reverse_item!(
+            self,
+            port_declaration_item_list,
+            PortDeclarationItemList,
+            context
+        );
         let port_declaration_item_built = PortDeclarationItemBuilder::default()
+            .port_declaration_item_list(port_declaration_item_list)
             .identifier(Box::new(identifier))
             .colon(Box::new(colon))
             .port_declaration_item_group(Box::new(port_declaration_item_group))
@@ -21465,7 +25523,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 518:
+    /// Semantic action for production 613:
     ///
     /// PortDeclarationItemGroup: Direction Type;
     ///
@@ -21495,7 +25553,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 519:
+    /// Semantic action for production 614:
     ///
     /// PortDeclarationItemGroup: Interface;
     ///
@@ -21522,7 +25580,59 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 520:
+    /// Semantic action for production 615:
+    ///
+    /// PortDeclarationItemList /* Vec<T>::Push */: Attribute PortDeclarationItemList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn port_declaration_item_list_0(
+        &mut self,
+        _attribute: &ParseTreeStackEntry<'t>,
+        _port_declaration_item_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut port_declaration_item_list = pop_item!(
+            self,
+            port_declaration_item_list,
+            PortDeclarationItemList,
+            context
+        );
+        let attribute = pop_item!(self, attribute, Attribute, context);
+        let port_declaration_item_list_0_built = PortDeclarationItemListBuilder::default()
+            .attribute(Box::new(attribute))
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        port_declaration_item_list.push(port_declaration_item_list_0_built);
+        self.push(
+            ASTType::PortDeclarationItemList(port_declaration_item_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 616:
+    ///
+    /// PortDeclarationItemList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn port_declaration_item_list_1(
+        &mut self,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let port_declaration_item_list_1_built = Vec::new();
+        self.push(
+            ASTType::PortDeclarationItemList(port_declaration_item_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 617:
     ///
     /// Direction: Input;
     ///
@@ -21546,7 +25656,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 521:
+    /// Semantic action for production 618:
     ///
     /// Direction: Output;
     ///
@@ -21570,7 +25680,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 522:
+    /// Semantic action for production 619:
     ///
     /// Direction: Inout;
     ///
@@ -21594,7 +25704,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 523:
+    /// Semantic action for production 620:
     ///
     /// Direction: Ref;
     ///
@@ -21618,7 +25728,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 524:
+    /// Semantic action for production 621:
     ///
     /// Direction: Modport;
     ///
@@ -21642,7 +25752,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 525:
+    /// Semantic action for production 622:
     ///
     /// FunctionDeclaration: Function Identifier FunctionDeclarationOpt /* Option */ FunctionDeclarationOpt0 /* Option */ MinusGT Type LBrace FunctionDeclarationList /* Vec */ RBrace;
     ///
@@ -21708,7 +25818,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 526:
+    /// Semantic action for production 623:
     ///
     /// FunctionDeclarationList /* Vec<T>::Push */: FunctionItem FunctionDeclarationList;
     ///
@@ -21741,7 +25851,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 527:
+    /// Semantic action for production 624:
     ///
     /// FunctionDeclarationList /* Vec<T>::New */: ;
     ///
@@ -21757,7 +25867,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 528:
+    /// Semantic action for production 625:
     ///
     /// FunctionDeclarationOpt0 /* Option<T>::Some */: PortDeclaration;
     ///
@@ -21781,7 +25891,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 529:
+    /// Semantic action for production 626:
     ///
     /// FunctionDeclarationOpt0 /* Option<T>::None */: ;
     ///
@@ -21793,7 +25903,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 530:
+    /// Semantic action for production 627:
     ///
     /// FunctionDeclarationOpt /* Option<T>::Some */: WithParameter;
     ///
@@ -21817,7 +25927,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 531:
+    /// Semantic action for production 628:
     ///
     /// FunctionDeclarationOpt /* Option<T>::None */: ;
     ///
@@ -21829,7 +25939,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 532:
+    /// Semantic action for production 629:
     ///
     /// FunctionItem: VarDeclaration;
     ///
@@ -21853,7 +25963,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 533:
+    /// Semantic action for production 630:
     ///
     /// FunctionItem: Statement;
     ///
@@ -21877,7 +25987,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 534:
+    /// Semantic action for production 631:
     ///
     /// ImportDeclaration: Import Identifier ColonColon ImportDeclarationGroup Semicolon;
     ///
@@ -21921,7 +26031,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 535:
+    /// Semantic action for production 632:
     ///
     /// ImportDeclarationGroup: Identifier;
     ///
@@ -21947,7 +26057,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 536:
+    /// Semantic action for production 633:
     ///
     /// ImportDeclarationGroup: Star;
     ///
@@ -21973,7 +26083,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 537:
+    /// Semantic action for production 634:
     ///
     /// ExportDeclaration: Export ExportDeclarationGroup ColonColon ExportDeclarationGroup0 Semicolon;
     ///
@@ -22022,7 +26132,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 538:
+    /// Semantic action for production 635:
     ///
     /// ExportDeclarationGroup0: Identifier;
     ///
@@ -22048,7 +26158,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 539:
+    /// Semantic action for production 636:
     ///
     /// ExportDeclarationGroup0: Star;
     ///
@@ -22074,7 +26184,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 540:
+    /// Semantic action for production 637:
     ///
     /// ExportDeclarationGroup: Identifier;
     ///
@@ -22100,7 +26210,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 541:
+    /// Semantic action for production 638:
     ///
     /// ExportDeclarationGroup: Star;
     ///
@@ -22126,7 +26236,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 542:
+    /// Semantic action for production 639:
     ///
     /// ModuleDeclaration: Module Identifier ModuleDeclarationOpt /* Option */ ModuleDeclarationOpt0 /* Option */ LBrace ModuleDeclarationList /* Vec */ RBrace;
     ///
@@ -22182,13 +26292,14 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 543:
+    /// Semantic action for production 640:
     ///
-    /// ModuleDeclarationList /* Vec<T>::Push */: ModuleItem ModuleDeclarationList;
+    /// ModuleDeclarationList /* Vec<T>::Push */: ModuleDeclarationListList /* Vec */ ModuleItem ModuleDeclarationList;
     ///
     #[parol_runtime::function_name::named]
     fn module_declaration_list_0(
         &mut self,
+        _module_declaration_list_list: &ParseTreeStackEntry<'t>,
         _module_item: &ParseTreeStackEntry<'t>,
         _module_declaration_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -22202,8 +26313,15 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
             context
         );
         let module_item = pop_item!(self, module_item, ModuleItem, context);
+        let module_declaration_list_list = pop_and_reverse_item!(
+            self,
+            module_declaration_list_list,
+            ModuleDeclarationListList,
+            context
+        );
         let module_declaration_list_0_built = ModuleDeclarationListBuilder::default()
             .module_item(Box::new(module_item))
+            .module_declaration_list_list(module_declaration_list_list)
             .build()
             .into_diagnostic()?;
         // Add an element to the vector
@@ -22215,7 +26333,59 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 544:
+    /// Semantic action for production 641:
+    ///
+    /// ModuleDeclarationListList /* Vec<T>::Push */: Attribute ModuleDeclarationListList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn module_declaration_list_list_0(
+        &mut self,
+        _attribute: &ParseTreeStackEntry<'t>,
+        _module_declaration_list_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut module_declaration_list_list = pop_item!(
+            self,
+            module_declaration_list_list,
+            ModuleDeclarationListList,
+            context
+        );
+        let attribute = pop_item!(self, attribute, Attribute, context);
+        let module_declaration_list_list_0_built = ModuleDeclarationListListBuilder::default()
+            .attribute(Box::new(attribute))
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        module_declaration_list_list.push(module_declaration_list_list_0_built);
+        self.push(
+            ASTType::ModuleDeclarationListList(module_declaration_list_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 642:
+    ///
+    /// ModuleDeclarationListList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn module_declaration_list_list_1(
+        &mut self,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let module_declaration_list_list_1_built = Vec::new();
+        self.push(
+            ASTType::ModuleDeclarationListList(module_declaration_list_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 643:
     ///
     /// ModuleDeclarationList /* Vec<T>::New */: ;
     ///
@@ -22231,7 +26401,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 545:
+    /// Semantic action for production 644:
     ///
     /// ModuleDeclarationOpt0 /* Option<T>::Some */: PortDeclaration;
     ///
@@ -22255,7 +26425,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 546:
+    /// Semantic action for production 645:
     ///
     /// ModuleDeclarationOpt0 /* Option<T>::None */: ;
     ///
@@ -22267,7 +26437,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 547:
+    /// Semantic action for production 646:
     ///
     /// ModuleDeclarationOpt /* Option<T>::Some */: WithParameter;
     ///
@@ -22291,7 +26461,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 548:
+    /// Semantic action for production 647:
     ///
     /// ModuleDeclarationOpt /* Option<T>::None */: ;
     ///
@@ -22303,7 +26473,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 549:
+    /// Semantic action for production 648:
     ///
     /// ModuleIfDeclaration: If Expression ModuleNamedBlock ModuleIfDeclarationList /* Vec */ ModuleIfDeclarationOpt /* Option */;
     ///
@@ -22352,7 +26522,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 550:
+    /// Semantic action for production 649:
     ///
     /// ModuleIfDeclarationList /* Vec<T>::Push */: Else If Expression ModuleOptionalNamedBlock ModuleIfDeclarationList;
     ///
@@ -22399,7 +26569,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 551:
+    /// Semantic action for production 650:
     ///
     /// ModuleIfDeclarationList /* Vec<T>::New */: ;
     ///
@@ -22418,7 +26588,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 552:
+    /// Semantic action for production 651:
     ///
     /// ModuleIfDeclarationOpt /* Option<T>::Some */: Else ModuleOptionalNamedBlock;
     ///
@@ -22450,7 +26620,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 553:
+    /// Semantic action for production 652:
     ///
     /// ModuleIfDeclarationOpt /* Option<T>::None */: ;
     ///
@@ -22462,7 +26632,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 554:
+    /// Semantic action for production 653:
     ///
     /// ModuleForDeclaration: For Identifier In Expression DotDot Expression ModuleForDeclarationOpt /* Option */ ModuleNamedBlock;
     ///
@@ -22515,7 +26685,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 555:
+    /// Semantic action for production 654:
     ///
     /// ModuleForDeclarationOpt /* Option<T>::Some */: Step AssignmentOperator Expression;
     ///
@@ -22545,7 +26715,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 556:
+    /// Semantic action for production 655:
     ///
     /// ModuleForDeclarationOpt /* Option<T>::None */: ;
     ///
@@ -22560,7 +26730,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 557:
+    /// Semantic action for production 656:
     ///
     /// ModuleNamedBlock: Colon Identifier LBrace ModuleNamedBlockList /* Vec */ RBrace;
     ///
@@ -22597,13 +26767,14 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 558:
+    /// Semantic action for production 657:
     ///
-    /// ModuleNamedBlockList /* Vec<T>::Push */: ModuleItem ModuleNamedBlockList;
+    /// ModuleNamedBlockList /* Vec<T>::Push */: ModuleNamedBlockListList /* Vec */ ModuleItem ModuleNamedBlockList;
     ///
     #[parol_runtime::function_name::named]
     fn module_named_block_list_0(
         &mut self,
+        _module_named_block_list_list: &ParseTreeStackEntry<'t>,
         _module_item: &ParseTreeStackEntry<'t>,
         _module_named_block_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -22613,8 +26784,15 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         let mut module_named_block_list =
             pop_item!(self, module_named_block_list, ModuleNamedBlockList, context);
         let module_item = pop_item!(self, module_item, ModuleItem, context);
+        let module_named_block_list_list = pop_and_reverse_item!(
+            self,
+            module_named_block_list_list,
+            ModuleNamedBlockListList,
+            context
+        );
         let module_named_block_list_0_built = ModuleNamedBlockListBuilder::default()
             .module_item(Box::new(module_item))
+            .module_named_block_list_list(module_named_block_list_list)
             .build()
             .into_diagnostic()?;
         // Add an element to the vector
@@ -22626,7 +26804,59 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 559:
+    /// Semantic action for production 658:
+    ///
+    /// ModuleNamedBlockListList /* Vec<T>::Push */: Attribute ModuleNamedBlockListList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn module_named_block_list_list_0(
+        &mut self,
+        _attribute: &ParseTreeStackEntry<'t>,
+        _module_named_block_list_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut module_named_block_list_list = pop_item!(
+            self,
+            module_named_block_list_list,
+            ModuleNamedBlockListList,
+            context
+        );
+        let attribute = pop_item!(self, attribute, Attribute, context);
+        let module_named_block_list_list_0_built = ModuleNamedBlockListListBuilder::default()
+            .attribute(Box::new(attribute))
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        module_named_block_list_list.push(module_named_block_list_list_0_built);
+        self.push(
+            ASTType::ModuleNamedBlockListList(module_named_block_list_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 659:
+    ///
+    /// ModuleNamedBlockListList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn module_named_block_list_list_1(
+        &mut self,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let module_named_block_list_list_1_built = Vec::new();
+        self.push(
+            ASTType::ModuleNamedBlockListList(module_named_block_list_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 660:
     ///
     /// ModuleNamedBlockList /* Vec<T>::New */: ;
     ///
@@ -22642,7 +26872,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 560:
+    /// Semantic action for production 661:
     ///
     /// ModuleOptionalNamedBlock: ModuleOptionalNamedBlockOpt /* Option */ LBrace ModuleOptionalNamedBlockList /* Vec */ RBrace;
     ///
@@ -22688,13 +26918,14 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 561:
+    /// Semantic action for production 662:
     ///
-    /// ModuleOptionalNamedBlockList /* Vec<T>::Push */: ModuleItem ModuleOptionalNamedBlockList;
+    /// ModuleOptionalNamedBlockList /* Vec<T>::Push */: ModuleOptionalNamedBlockListList /* Vec */ ModuleItem ModuleOptionalNamedBlockList;
     ///
     #[parol_runtime::function_name::named]
     fn module_optional_named_block_list_0(
         &mut self,
+        _module_optional_named_block_list_list: &ParseTreeStackEntry<'t>,
         _module_item: &ParseTreeStackEntry<'t>,
         _module_optional_named_block_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -22708,9 +26939,16 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
             context
         );
         let module_item = pop_item!(self, module_item, ModuleItem, context);
+        let module_optional_named_block_list_list = pop_and_reverse_item!(
+            self,
+            module_optional_named_block_list_list,
+            ModuleOptionalNamedBlockListList,
+            context
+        );
         let module_optional_named_block_list_0_built =
             ModuleOptionalNamedBlockListBuilder::default()
                 .module_item(Box::new(module_item))
+                .module_optional_named_block_list_list(module_optional_named_block_list_list)
                 .build()
                 .into_diagnostic()?;
         // Add an element to the vector
@@ -22722,7 +26960,62 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 562:
+    /// Semantic action for production 663:
+    ///
+    /// ModuleOptionalNamedBlockListList /* Vec<T>::Push */: Attribute ModuleOptionalNamedBlockListList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn module_optional_named_block_list_list_0(
+        &mut self,
+        _attribute: &ParseTreeStackEntry<'t>,
+        _module_optional_named_block_list_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut module_optional_named_block_list_list = pop_item!(
+            self,
+            module_optional_named_block_list_list,
+            ModuleOptionalNamedBlockListList,
+            context
+        );
+        let attribute = pop_item!(self, attribute, Attribute, context);
+        let module_optional_named_block_list_list_0_built =
+            ModuleOptionalNamedBlockListListBuilder::default()
+                .attribute(Box::new(attribute))
+                .build()
+                .into_diagnostic()?;
+        // Add an element to the vector
+        module_optional_named_block_list_list.push(module_optional_named_block_list_list_0_built);
+        self.push(
+            ASTType::ModuleOptionalNamedBlockListList(module_optional_named_block_list_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 664:
+    ///
+    /// ModuleOptionalNamedBlockListList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn module_optional_named_block_list_list_1(
+        &mut self,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let module_optional_named_block_list_list_1_built = Vec::new();
+        self.push(
+            ASTType::ModuleOptionalNamedBlockListList(
+                module_optional_named_block_list_list_1_built,
+            ),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 665:
     ///
     /// ModuleOptionalNamedBlockList /* Vec<T>::New */: ;
     ///
@@ -22741,7 +27034,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 563:
+    /// Semantic action for production 666:
     ///
     /// ModuleOptionalNamedBlockOpt /* Option<T>::Some */: Colon Identifier;
     ///
@@ -22770,7 +27063,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 564:
+    /// Semantic action for production 667:
     ///
     /// ModuleOptionalNamedBlockOpt /* Option<T>::None */: ;
     ///
@@ -22785,7 +27078,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 565:
+    /// Semantic action for production 668:
     ///
     /// ModuleItem: VarDeclaration;
     ///
@@ -22809,7 +27102,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 566:
+    /// Semantic action for production 669:
     ///
     /// ModuleItem: InstDeclaration;
     ///
@@ -22833,7 +27126,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 567:
+    /// Semantic action for production 670:
     ///
     /// ModuleItem: LocalparamDeclaration;
     ///
@@ -22858,7 +27151,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 568:
+    /// Semantic action for production 671:
     ///
     /// ModuleItem: AlwaysFfDeclaration;
     ///
@@ -22883,7 +27176,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 569:
+    /// Semantic action for production 672:
     ///
     /// ModuleItem: AlwaysCombDeclaration;
     ///
@@ -22912,7 +27205,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 570:
+    /// Semantic action for production 673:
     ///
     /// ModuleItem: AssignDeclaration;
     ///
@@ -22936,12 +27229,85 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 571:
+    /// Semantic action for production 674:
     ///
-    /// ModuleItem: FunctionDeclaration;
+    /// ModuleItem: InitialDeclaration;
     ///
     #[parol_runtime::function_name::named]
     fn module_item_6(
+        &mut self,
+        _initial_declaration: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let initial_declaration = pop_item!(self, initial_declaration, InitialDeclaration, context);
+        let module_item_6_built = ModuleItemInitialDeclarationBuilder::default()
+            .initial_declaration(Box::new(initial_declaration))
+            .build()
+            .into_diagnostic()?;
+        let module_item_6_built = ModuleItem::InitialDeclaration(module_item_6_built);
+        // Calling user action here
+        self.user_grammar.module_item(&module_item_6_built)?;
+        self.push(ASTType::ModuleItem(module_item_6_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 675:
+    ///
+    /// ModuleItem: FinalDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn module_item_7(
+        &mut self,
+        _final_declaration: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let final_declaration = pop_item!(self, final_declaration, FinalDeclaration, context);
+        let module_item_7_built = ModuleItemFinalDeclarationBuilder::default()
+            .final_declaration(Box::new(final_declaration))
+            .build()
+            .into_diagnostic()?;
+        let module_item_7_built = ModuleItem::FinalDeclaration(module_item_7_built);
+        // Calling user action here
+        self.user_grammar.module_item(&module_item_7_built)?;
+        self.push(ASTType::ModuleItem(module_item_7_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 676:
+    ///
+    /// ModuleItem: AssertionDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn module_item_8(
+        &mut self,
+        _assertion_declaration: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assertion_declaration =
+            pop_item!(self, assertion_declaration, AssertionDeclaration, context);
+        let module_item_8_built = ModuleItemAssertionDeclarationBuilder::default()
+            .assertion_declaration(Box::new(assertion_declaration))
+            .build()
+            .into_diagnostic()?;
+        let module_item_8_built = ModuleItem::AssertionDeclaration(module_item_8_built);
+        // Calling user action here
+        self.user_grammar.module_item(&module_item_8_built)?;
+        self.push(ASTType::ModuleItem(module_item_8_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 677:
+    ///
+    /// ModuleItem: FunctionDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn module_item_9(
         &mut self,
         _function_declaration: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -22950,23 +27316,23 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         trace!("{}", self.trace_item_stack(context));
         let function_declaration =
             pop_item!(self, function_declaration, FunctionDeclaration, context);
-        let module_item_6_built = ModuleItemFunctionDeclarationBuilder::default()
+        let module_item_9_built = ModuleItemFunctionDeclarationBuilder::default()
             .function_declaration(Box::new(function_declaration))
             .build()
             .into_diagnostic()?;
-        let module_item_6_built = ModuleItem::FunctionDeclaration(module_item_6_built);
+        let module_item_9_built = ModuleItem::FunctionDeclaration(module_item_9_built);
         // Calling user action here
-        self.user_grammar.module_item(&module_item_6_built)?;
-        self.push(ASTType::ModuleItem(module_item_6_built), context);
+        self.user_grammar.module_item(&module_item_9_built)?;
+        self.push(ASTType::ModuleItem(module_item_9_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 572:
+    /// Semantic action for production 678:
     ///
     /// ModuleItem: ModuleIfDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn module_item_7(
+    fn module_item_10(
         &mut self,
         _module_if_declaration: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -22975,23 +27341,23 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         trace!("{}", self.trace_item_stack(context));
         let module_if_declaration =
             pop_item!(self, module_if_declaration, ModuleIfDeclaration, context);
-        let module_item_7_built = ModuleItemModuleIfDeclarationBuilder::default()
+        let module_item_10_built = ModuleItemModuleIfDeclarationBuilder::default()
             .module_if_declaration(Box::new(module_if_declaration))
             .build()
             .into_diagnostic()?;
-        let module_item_7_built = ModuleItem::ModuleIfDeclaration(module_item_7_built);
+        let module_item_10_built = ModuleItem::ModuleIfDeclaration(module_item_10_built);
         // Calling user action here
-        self.user_grammar.module_item(&module_item_7_built)?;
-        self.push(ASTType::ModuleItem(module_item_7_built), context);
+        self.user_grammar.module_item(&module_item_10_built)?;
+        self.push(ASTType::ModuleItem(module_item_10_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 573:
+    /// Semantic action for production 679:
     ///
     /// ModuleItem: ModuleForDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn module_item_8(
+    fn module_item_11(
         &mut self,
         _module_for_declaration: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -23000,23 +27366,23 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         trace!("{}", self.trace_item_stack(context));
         let module_for_declaration =
             pop_item!(self, module_for_declaration, ModuleForDeclaration, context);
-        let module_item_8_built = ModuleItemModuleForDeclarationBuilder::default()
+        let module_item_11_built = ModuleItemModuleForDeclarationBuilder::default()
             .module_for_declaration(Box::new(module_for_declaration))
             .build()
             .into_diagnostic()?;
-        let module_item_8_built = ModuleItem::ModuleForDeclaration(module_item_8_built);
+        let module_item_11_built = ModuleItem::ModuleForDeclaration(module_item_11_built);
         // Calling user action here
-        self.user_grammar.module_item(&module_item_8_built)?;
-        self.push(ASTType::ModuleItem(module_item_8_built), context);
+        self.user_grammar.module_item(&module_item_11_built)?;
+        self.push(ASTType::ModuleItem(module_item_11_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 574:
+    /// Semantic action for production 680:
     ///
     /// ModuleItem: EnumDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn module_item_9(
+    fn module_item_12(
         &mut self,
         _enum_declaration: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -23024,23 +27390,23 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let enum_declaration = pop_item!(self, enum_declaration, EnumDeclaration, context);
-        let module_item_9_built = ModuleItemEnumDeclarationBuilder::default()
+        let module_item_12_built = ModuleItemEnumDeclarationBuilder::default()
             .enum_declaration(Box::new(enum_declaration))
             .build()
             .into_diagnostic()?;
-        let module_item_9_built = ModuleItem::EnumDeclaration(module_item_9_built);
+        let module_item_12_built = ModuleItem::EnumDeclaration(module_item_12_built);
         // Calling user action here
-        self.user_grammar.module_item(&module_item_9_built)?;
-        self.push(ASTType::ModuleItem(module_item_9_built), context);
+        self.user_grammar.module_item(&module_item_12_built)?;
+        self.push(ASTType::ModuleItem(module_item_12_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 575:
+    /// Semantic action for production 681:
     ///
     /// ModuleItem: StructDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn module_item_10(
+    fn module_item_13(
         &mut self,
         _struct_declaration: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -23048,23 +27414,47 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let struct_declaration = pop_item!(self, struct_declaration, StructDeclaration, context);
-        let module_item_10_built = ModuleItemStructDeclarationBuilder::default()
+        let module_item_13_built = ModuleItemStructDeclarationBuilder::default()
             .struct_declaration(Box::new(struct_declaration))
             .build()
             .into_diagnostic()?;
-        let module_item_10_built = ModuleItem::StructDeclaration(module_item_10_built);
+        let module_item_13_built = ModuleItem::StructDeclaration(module_item_13_built);
         // Calling user action here
-        self.user_grammar.module_item(&module_item_10_built)?;
-        self.push(ASTType::ModuleItem(module_item_10_built), context);
+        self.user_grammar.module_item(&module_item_13_built)?;
+        self.push(ASTType::ModuleItem(module_item_13_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 576:
+    /// Semantic action for production 682:
+    ///
+    /// ModuleItem: TypeDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn module_item_14(
+        &mut self,
+        _type_declaration: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let type_declaration = pop_item!(self, type_declaration, TypeDeclaration, context);
+        let module_item_14_built = ModuleItemTypeDeclarationBuilder::default()
+            .type_declaration(Box::new(type_declaration))
+            .build()
+            .into_diagnostic()?;
+        let module_item_14_built = ModuleItem::TypeDeclaration(module_item_14_built);
+        // Calling user action here
+        self.user_grammar.module_item(&module_item_14_built)?;
+        self.push(ASTType::ModuleItem(module_item_14_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 683:
     ///
     /// ModuleItem: ModuleNamedBlock;
     ///
     #[parol_runtime::function_name::named]
-    fn module_item_11(
+    fn module_item_15(
         &mut self,
         _module_named_block: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -23072,23 +27462,23 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let module_named_block = pop_item!(self, module_named_block, ModuleNamedBlock, context);
-        let module_item_11_built = ModuleItemModuleNamedBlockBuilder::default()
+        let module_item_15_built = ModuleItemModuleNamedBlockBuilder::default()
             .module_named_block(Box::new(module_named_block))
             .build()
             .into_diagnostic()?;
-        let module_item_11_built = ModuleItem::ModuleNamedBlock(module_item_11_built);
+        let module_item_15_built = ModuleItem::ModuleNamedBlock(module_item_15_built);
         // Calling user action here
-        self.user_grammar.module_item(&module_item_11_built)?;
-        self.push(ASTType::ModuleItem(module_item_11_built), context);
+        self.user_grammar.module_item(&module_item_15_built)?;
+        self.push(ASTType::ModuleItem(module_item_15_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 577:
+    /// Semantic action for production 684:
     ///
     /// ModuleItem: ImportDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn module_item_12(
+    fn module_item_16(
         &mut self,
         _import_declaration: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -23096,18 +27486,18 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let import_declaration = pop_item!(self, import_declaration, ImportDeclaration, context);
-        let module_item_12_built = ModuleItemImportDeclarationBuilder::default()
+        let module_item_16_built = ModuleItemImportDeclarationBuilder::default()
             .import_declaration(Box::new(import_declaration))
             .build()
             .into_diagnostic()?;
-        let module_item_12_built = ModuleItem::ImportDeclaration(module_item_12_built);
-        // Calling user action here
-        self.user_grammar.module_item(&module_item_12_built)?;
-        self.push(ASTType::ModuleItem(module_item_12_built), context);
+        let module_item_16_built = ModuleItem::ImportDeclaration(module_item_16_built);
+        // Calling user action here
+        self.user_grammar.module_item(&module_item_16_built)?;
+        self.push(ASTType::ModuleItem(module_item_16_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 578:
+    /// Semantic action for production 685:
     ///
     /// InterfaceDeclaration: Interface Identifier InterfaceDeclarationOpt /* Option */ LBrace InterfaceDeclarationList /* Vec */ RBrace;
     ///
@@ -23159,7 +27549,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 579:
+    /// Semantic action for production 686:
     ///
     /// InterfaceDeclarationList /* Vec<T>::Push */: InterfaceItem InterfaceDeclarationList;
     ///
@@ -23192,7 +27582,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 580:
+    /// Semantic action for production 687:
     ///
     /// InterfaceDeclarationList /* Vec<T>::New */: ;
     ///
@@ -23211,7 +27601,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 581:
+    /// Semantic action for production 688:
     ///
     /// InterfaceDeclarationOpt /* Option<T>::Some */: WithParameter;
     ///
@@ -23235,7 +27625,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 582:
+    /// Semantic action for production 689:
     ///
     /// InterfaceDeclarationOpt /* Option<T>::None */: ;
     ///
@@ -23247,7 +27637,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 583:
+    /// Semantic action for production 690:
     ///
     /// InterfaceIfDeclaration: If Expression InterfaceNamedBlock InterfaceIfDeclarationList /* Vec */ InterfaceIfDeclarationOpt /* Option */;
     ///
@@ -23297,7 +27687,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 584:
+    /// Semantic action for production 691:
     ///
     /// InterfaceIfDeclarationList /* Vec<T>::Push */: Else If Expression InterfaceOptionalNamedBlock InterfaceIfDeclarationList;
     ///
@@ -23344,7 +27734,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 585:
+    /// Semantic action for production 692:
     ///
     /// InterfaceIfDeclarationList /* Vec<T>::New */: ;
     ///
@@ -23363,7 +27753,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 586:
+    /// Semantic action for production 693:
     ///
     /// InterfaceIfDeclarationOpt /* Option<T>::Some */: Else InterfaceOptionalNamedBlock;
     ///
@@ -23397,7 +27787,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 587:
+    /// Semantic action for production 694:
     ///
     /// InterfaceIfDeclarationOpt /* Option<T>::None */: ;
     ///
@@ -23412,7 +27802,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 588:
+    /// Semantic action for production 695:
     ///
     /// InterfaceForDeclaration: For Identifier In Expression DotDot Expression InterfaceForDeclarationOpt /* Option */ InterfaceNamedBlock;
     ///
@@ -23466,7 +27856,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 589:
+    /// Semantic action for production 696:
     ///
     /// InterfaceForDeclarationOpt /* Option<T>::Some */: Step AssignmentOperator Expression;
     ///
@@ -23498,7 +27888,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 590:
+    /// Semantic action for production 697:
     ///
     /// InterfaceForDeclarationOpt /* Option<T>::None */: ;
     ///
@@ -23513,7 +27903,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 591:
+    /// Semantic action for production 698:
     ///
     /// InterfaceNamedBlock: Colon Identifier LBrace InterfaceNamedBlockList /* Vec */ RBrace;
     ///
@@ -23557,7 +27947,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 592:
+    /// Semantic action for production 699:
     ///
     /// InterfaceNamedBlockList /* Vec<T>::Push */: InterfaceItem InterfaceNamedBlockList;
     ///
@@ -23590,7 +27980,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 593:
+    /// Semantic action for production 700:
     ///
     /// InterfaceNamedBlockList /* Vec<T>::New */: ;
     ///
@@ -23609,7 +27999,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 594:
+    /// Semantic action for production 701:
     ///
     /// InterfaceOptionalNamedBlock: InterfaceOptionalNamedBlockOpt /* Option */ LBrace InterfaceOptionalNamedBlockList /* Vec */ RBrace;
     ///
@@ -23655,7 +28045,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 595:
+    /// Semantic action for production 702:
     ///
     /// InterfaceOptionalNamedBlockList /* Vec<T>::Push */: InterfaceItem InterfaceOptionalNamedBlockList;
     ///
@@ -23689,7 +28079,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 596:
+    /// Semantic action for production 703:
     ///
     /// InterfaceOptionalNamedBlockList /* Vec<T>::New */: ;
     ///
@@ -23708,7 +28098,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 597:
+    /// Semantic action for production 704:
     ///
     /// InterfaceOptionalNamedBlockOpt /* Option<T>::Some */: Colon Identifier;
     ///
@@ -23738,7 +28128,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 598:
+    /// Semantic action for production 705:
     ///
     /// InterfaceOptionalNamedBlockOpt /* Option<T>::None */: ;
     ///
@@ -23753,143 +28143,431 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 599:
+    /// Semantic action for production 706:
     ///
     /// InterfaceItem: VarDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn interface_item_0(
+    fn interface_item_0(
+        &mut self,
+        _var_declaration: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let var_declaration = pop_item!(self, var_declaration, VarDeclaration, context);
+        let interface_item_0_built = InterfaceItemVarDeclarationBuilder::default()
+            .var_declaration(Box::new(var_declaration))
+            .build()
+            .into_diagnostic()?;
+        let interface_item_0_built = InterfaceItem::VarDeclaration(interface_item_0_built);
+        // Calling user action here
+        self.user_grammar.interface_item(&interface_item_0_built)?;
+        self.push(ASTType::InterfaceItem(interface_item_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 707:
+    ///
+    /// InterfaceItem: LocalparamDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn interface_item_1(
+        &mut self,
+        _localparam_declaration: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let localparam_declaration =
+            pop_item!(self, localparam_declaration, LocalparamDeclaration, context);
+        let interface_item_1_built = InterfaceItemLocalparamDeclarationBuilder::default()
+            .localparam_declaration(Box::new(localparam_declaration))
+            .build()
+            .into_diagnostic()?;
+        let interface_item_1_built = InterfaceItem::LocalparamDeclaration(interface_item_1_built);
+        // Calling user action here
+        self.user_grammar.interface_item(&interface_item_1_built)?;
+        self.push(ASTType::InterfaceItem(interface_item_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 708:
+    ///
+    /// InterfaceItem: ModportDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn interface_item_2(
+        &mut self,
+        _modport_declaration: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let modport_declaration = pop_item!(self, modport_declaration, ModportDeclaration, context);
+        let interface_item_2_built = InterfaceItemModportDeclarationBuilder::default()
+            .modport_declaration(Box::new(modport_declaration))
+            .build()
+            .into_diagnostic()?;
+        let interface_item_2_built = InterfaceItem::ModportDeclaration(interface_item_2_built);
+        // Calling user action here
+        self.user_grammar.interface_item(&interface_item_2_built)?;
+        self.push(ASTType::InterfaceItem(interface_item_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 709:
+    ///
+    /// InterfaceItem: InterfaceIfDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn interface_item_3(
+        &mut self,
+        _interface_if_declaration: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let interface_if_declaration = pop_item!(
+            self,
+            interface_if_declaration,
+            InterfaceIfDeclaration,
+            context
+        );
+        let interface_item_3_built = InterfaceItemInterfaceIfDeclarationBuilder::default()
+            .interface_if_declaration(Box::new(interface_if_declaration))
+            .build()
+            .into_diagnostic()?;
+        let interface_item_3_built = InterfaceItem::InterfaceIfDeclaration(interface_item_3_built);
+        // Calling user action here
+        self.user_grammar.interface_item(&interface_item_3_built)?;
+        self.push(ASTType::InterfaceItem(interface_item_3_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 710:
+    ///
+    /// InterfaceItem: InterfaceForDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn interface_item_4(
+        &mut self,
+        _interface_for_declaration: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let interface_for_declaration = pop_item!(
+            self,
+            interface_for_declaration,
+            InterfaceForDeclaration,
+            context
+        );
+        let interface_item_4_built = InterfaceItemInterfaceForDeclarationBuilder::default()
+            .interface_for_declaration(Box::new(interface_for_declaration))
+            .build()
+            .into_diagnostic()?;
+        let interface_item_4_built = InterfaceItem::InterfaceForDeclaration(interface_item_4_built);
+        // Calling user action here
+        self.user_grammar.interface_item(&interface_item_4_built)?;
+        self.push(ASTType::InterfaceItem(interface_item_4_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 711:
+    ///
+    /// InterfaceItem: EnumDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn interface_item_5(
+        &mut self,
+        _enum_declaration: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let enum_declaration = pop_item!(self, enum_declaration, EnumDeclaration, context);
+        let interface_item_5_built = InterfaceItemEnumDeclarationBuilder::default()
+            .enum_declaration(Box::new(enum_declaration))
+            .build()
+            .into_diagnostic()?;
+        let interface_item_5_built = InterfaceItem::EnumDeclaration(interface_item_5_built);
+        // Calling user action here
+        self.user_grammar.interface_item(&interface_item_5_built)?;
+        self.push(ASTType::InterfaceItem(interface_item_5_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 712:
+    ///
+    /// InterfaceItem: StructDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn interface_item_6(
+        &mut self,
+        _struct_declaration: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let struct_declaration = pop_item!(self, struct_declaration, StructDeclaration, context);
+        let interface_item_6_built = InterfaceItemStructDeclarationBuilder::default()
+            .struct_declaration(Box::new(struct_declaration))
+            .build()
+            .into_diagnostic()?;
+        let interface_item_6_built = InterfaceItem::StructDeclaration(interface_item_6_built);
+        // Calling user action here
+        self.user_grammar.interface_item(&interface_item_6_built)?;
+        self.push(ASTType::InterfaceItem(interface_item_6_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 713:
+    ///
+    /// InterfaceItem: TypeDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn interface_item_7(
+        &mut self,
+        _type_declaration: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let type_declaration = pop_item!(self, type_declaration, TypeDeclaration, context);
+        let interface_item_7_built = InterfaceItemTypeDeclarationBuilder::default()
+            .type_declaration(Box::new(type_declaration))
+            .build()
+            .into_diagnostic()?;
+        let interface_item_7_built = InterfaceItem::TypeDeclaration(interface_item_7_built);
+        // Calling user action here
+        self.user_grammar.interface_item(&interface_item_7_built)?;
+        self.push(ASTType::InterfaceItem(interface_item_7_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 714:
+    ///
+    /// InterfaceItem: InterfaceNamedBlock;
+    ///
+    #[parol_runtime::function_name::named]
+    fn interface_item_8(
+        &mut self,
+        _interface_named_block: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let interface_named_block =
+            pop_item!(self, interface_named_block, InterfaceNamedBlock, context);
+        let interface_item_8_built = InterfaceItemInterfaceNamedBlockBuilder::default()
+            .interface_named_block(Box::new(interface_named_block))
+            .build()
+            .into_diagnostic()?;
+        let interface_item_8_built = InterfaceItem::InterfaceNamedBlock(interface_item_8_built);
+        // Calling user action here
+        self.user_grammar.interface_item(&interface_item_8_built)?;
+        self.push(ASTType::InterfaceItem(interface_item_8_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 715:
+    ///
+    /// InterfaceItem: FunctionDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn interface_item_9(
+        &mut self,
+        _function_declaration: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let function_declaration =
+            pop_item!(self, function_declaration, FunctionDeclaration, context);
+        let interface_item_9_built = InterfaceItemFunctionDeclarationBuilder::default()
+            .function_declaration(Box::new(function_declaration))
+            .build()
+            .into_diagnostic()?;
+        let interface_item_9_built = InterfaceItem::FunctionDeclaration(interface_item_9_built);
+        // Calling user action here
+        self.user_grammar.interface_item(&interface_item_9_built)?;
+        self.push(ASTType::InterfaceItem(interface_item_9_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 716:
+    ///
+    /// InterfaceItem: ImportDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn interface_item_10(
         &mut self,
-        _var_declaration: &ParseTreeStackEntry<'t>,
+        _import_declaration: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let var_declaration = pop_item!(self, var_declaration, VarDeclaration, context);
-        let interface_item_0_built = InterfaceItemVarDeclarationBuilder::default()
-            .var_declaration(Box::new(var_declaration))
+        let import_declaration = pop_item!(self, import_declaration, ImportDeclaration, context);
+        let interface_item_10_built = InterfaceItemImportDeclarationBuilder::default()
+            .import_declaration(Box::new(import_declaration))
             .build()
             .into_diagnostic()?;
-        let interface_item_0_built = InterfaceItem::VarDeclaration(interface_item_0_built);
+        let interface_item_10_built = InterfaceItem::ImportDeclaration(interface_item_10_built);
         // Calling user action here
-        self.user_grammar.interface_item(&interface_item_0_built)?;
-        self.push(ASTType::InterfaceItem(interface_item_0_built), context);
+        self.user_grammar.interface_item(&interface_item_10_built)?;
+        self.push(ASTType::InterfaceItem(interface_item_10_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 600:
+    /// Semantic action for production 717:
     ///
-    /// InterfaceItem: LocalparamDeclaration;
+    /// PackageDeclaration: Package Identifier LBrace PackageDeclarationList /* Vec */ RBrace;
     ///
     #[parol_runtime::function_name::named]
-    fn interface_item_1(
+    fn package_declaration(
         &mut self,
-        _localparam_declaration: &ParseTreeStackEntry<'t>,
+        _package: &ParseTreeStackEntry<'t>,
+        _identifier: &ParseTreeStackEntry<'t>,
+        _l_brace: &ParseTreeStackEntry<'t>,
+        _package_declaration_list: &ParseTreeStackEntry<'t>,
+        _r_brace: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let localparam_declaration =
-            pop_item!(self, localparam_declaration, LocalparamDeclaration, context);
-        let interface_item_1_built = InterfaceItemLocalparamDeclarationBuilder::default()
-            .localparam_declaration(Box::new(localparam_declaration))
+        let r_brace = pop_item!(self, r_brace, RBrace, context);
+        let package_declaration_list = pop_and_reverse_item!(
+            self,
+            package_declaration_list,
+            PackageDeclarationList,
+            context
+        );
+        let l_brace = pop_item!(self, l_brace, LBrace, context);
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let package = pop_item!(self, package, Package, context);
+        let package_declaration_built = PackageDeclarationBuilder::default()
+            .package(Box::new(package))
+            .identifier(Box::new(identifier))
+            .l_brace(Box::new(l_brace))
+            .package_declaration_list(package_declaration_list)
+            .r_brace(Box::new(r_brace))
             .build()
             .into_diagnostic()?;
-        let interface_item_1_built = InterfaceItem::LocalparamDeclaration(interface_item_1_built);
         // Calling user action here
-        self.user_grammar.interface_item(&interface_item_1_built)?;
-        self.push(ASTType::InterfaceItem(interface_item_1_built), context);
+        self.user_grammar
+            .package_declaration(&package_declaration_built)?;
+        self.push(
+            ASTType::PackageDeclaration(package_declaration_built),
+            context,
+        );
         Ok(())
     }
 
-    /// Semantic action for production 601:
+    /// Semantic action for production 718:
     ///
-    /// InterfaceItem: ModportDeclaration;
+    /// PackageDeclarationList /* Vec<T>::Push */: PackageItem PackageDeclarationList;
     ///
     #[parol_runtime::function_name::named]
-    fn interface_item_2(
+    fn package_declaration_list_0(
         &mut self,
-        _modport_declaration: &ParseTreeStackEntry<'t>,
+        _package_item: &ParseTreeStackEntry<'t>,
+        _package_declaration_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let modport_declaration = pop_item!(self, modport_declaration, ModportDeclaration, context);
-        let interface_item_2_built = InterfaceItemModportDeclarationBuilder::default()
-            .modport_declaration(Box::new(modport_declaration))
+        let mut package_declaration_list = pop_item!(
+            self,
+            package_declaration_list,
+            PackageDeclarationList,
+            context
+        );
+        let package_item = pop_item!(self, package_item, PackageItem, context);
+        let package_declaration_list_0_built = PackageDeclarationListBuilder::default()
+            .package_item(Box::new(package_item))
             .build()
             .into_diagnostic()?;
-        let interface_item_2_built = InterfaceItem::ModportDeclaration(interface_item_2_built);
-        // Calling user action here
-        self.user_grammar.interface_item(&interface_item_2_built)?;
-        self.push(ASTType::InterfaceItem(interface_item_2_built), context);
+        // Add an element to the vector
+        package_declaration_list.push(package_declaration_list_0_built);
+        self.push(
+            ASTType::PackageDeclarationList(package_declaration_list),
+            context,
+        );
         Ok(())
     }
 
-    /// Semantic action for production 602:
+    /// Semantic action for production 719:
     ///
-    /// InterfaceItem: InterfaceIfDeclaration;
+    /// PackageDeclarationList /* Vec<T>::New */: ;
     ///
     #[parol_runtime::function_name::named]
-    fn interface_item_3(
+    fn package_declaration_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let package_declaration_list_1_built = Vec::new();
+        self.push(
+            ASTType::PackageDeclarationList(package_declaration_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 720:
+    ///
+    /// PackageItem: VarDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn package_item_0(
         &mut self,
-        _interface_if_declaration: &ParseTreeStackEntry<'t>,
+        _var_declaration: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let interface_if_declaration = pop_item!(
-            self,
-            interface_if_declaration,
-            InterfaceIfDeclaration,
-            context
-        );
-        let interface_item_3_built = InterfaceItemInterfaceIfDeclarationBuilder::default()
-            .interface_if_declaration(Box::new(interface_if_declaration))
+        let var_declaration = pop_item!(self, var_declaration, VarDeclaration, context);
+        let package_item_0_built = PackageItemVarDeclarationBuilder::default()
+            .var_declaration(Box::new(var_declaration))
             .build()
             .into_diagnostic()?;
-        let interface_item_3_built = InterfaceItem::InterfaceIfDeclaration(interface_item_3_built);
+        let package_item_0_built = PackageItem::VarDeclaration(package_item_0_built);
         // Calling user action here
-        self.user_grammar.interface_item(&interface_item_3_built)?;
-        self.push(ASTType::InterfaceItem(interface_item_3_built), context);
+        self.user_grammar.package_item(&package_item_0_built)?;
+        self.push(ASTType::PackageItem(package_item_0_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 603:
+    /// Semantic action for production 721:
     ///
-    /// InterfaceItem: InterfaceForDeclaration;
+    /// PackageItem: LocalparamDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn interface_item_4(
+    fn package_item_1(
         &mut self,
-        _interface_for_declaration: &ParseTreeStackEntry<'t>,
+        _localparam_declaration: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let interface_for_declaration = pop_item!(
-            self,
-            interface_for_declaration,
-            InterfaceForDeclaration,
-            context
-        );
-        let interface_item_4_built = InterfaceItemInterfaceForDeclarationBuilder::default()
-            .interface_for_declaration(Box::new(interface_for_declaration))
+        let localparam_declaration =
+            pop_item!(self, localparam_declaration, LocalparamDeclaration, context);
+        let package_item_1_built = PackageItemLocalparamDeclarationBuilder::default()
+            .localparam_declaration(Box::new(localparam_declaration))
             .build()
             .into_diagnostic()?;
-        let interface_item_4_built = InterfaceItem::InterfaceForDeclaration(interface_item_4_built);
+        let package_item_1_built = PackageItem::LocalparamDeclaration(package_item_1_built);
         // Calling user action here
-        self.user_grammar.interface_item(&interface_item_4_built)?;
-        self.push(ASTType::InterfaceItem(interface_item_4_built), context);
+        self.user_grammar.package_item(&package_item_1_built)?;
+        self.push(ASTType::PackageItem(package_item_1_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 604:
+    /// Semantic action for production 722:
     ///
-    /// InterfaceItem: EnumDeclaration;
+    /// PackageItem: EnumDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn interface_item_5(
+    fn package_item_2(
         &mut self,
         _enum_declaration: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -23897,23 +28575,23 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let enum_declaration = pop_item!(self, enum_declaration, EnumDeclaration, context);
-        let interface_item_5_built = InterfaceItemEnumDeclarationBuilder::default()
+        let package_item_2_built = PackageItemEnumDeclarationBuilder::default()
             .enum_declaration(Box::new(enum_declaration))
             .build()
             .into_diagnostic()?;
-        let interface_item_5_built = InterfaceItem::EnumDeclaration(interface_item_5_built);
+        let package_item_2_built = PackageItem::EnumDeclaration(package_item_2_built);
         // Calling user action here
-        self.user_grammar.interface_item(&interface_item_5_built)?;
-        self.push(ASTType::InterfaceItem(interface_item_5_built), context);
+        self.user_grammar.package_item(&package_item_2_built)?;
+        self.push(ASTType::PackageItem(package_item_2_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 605:
+    /// Semantic action for production 723:
     ///
-    /// InterfaceItem: StructDeclaration;
+    /// PackageItem: StructDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn interface_item_6(
+    fn package_item_3(
         &mut self,
         _struct_declaration: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -23921,48 +28599,47 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let struct_declaration = pop_item!(self, struct_declaration, StructDeclaration, context);
-        let interface_item_6_built = InterfaceItemStructDeclarationBuilder::default()
+        let package_item_3_built = PackageItemStructDeclarationBuilder::default()
             .struct_declaration(Box::new(struct_declaration))
             .build()
             .into_diagnostic()?;
-        let interface_item_6_built = InterfaceItem::StructDeclaration(interface_item_6_built);
+        let package_item_3_built = PackageItem::StructDeclaration(package_item_3_built);
         // Calling user action here
-        self.user_grammar.interface_item(&interface_item_6_built)?;
-        self.push(ASTType::InterfaceItem(interface_item_6_built), context);
+        self.user_grammar.package_item(&package_item_3_built)?;
+        self.push(ASTType::PackageItem(package_item_3_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 606:
+    /// Semantic action for production 724:
     ///
-    /// InterfaceItem: InterfaceNamedBlock;
+    /// PackageItem: TypeDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn interface_item_7(
+    fn package_item_4(
         &mut self,
-        _interface_named_block: &ParseTreeStackEntry<'t>,
+        _type_declaration: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let interface_named_block =
-            pop_item!(self, interface_named_block, InterfaceNamedBlock, context);
-        let interface_item_7_built = InterfaceItemInterfaceNamedBlockBuilder::default()
-            .interface_named_block(Box::new(interface_named_block))
+        let type_declaration = pop_item!(self, type_declaration, TypeDeclaration, context);
+        let package_item_4_built = PackageItemTypeDeclarationBuilder::default()
+            .type_declaration(Box::new(type_declaration))
             .build()
             .into_diagnostic()?;
-        let interface_item_7_built = InterfaceItem::InterfaceNamedBlock(interface_item_7_built);
+        let package_item_4_built = PackageItem::TypeDeclaration(package_item_4_built);
         // Calling user action here
-        self.user_grammar.interface_item(&interface_item_7_built)?;
-        self.push(ASTType::InterfaceItem(interface_item_7_built), context);
+        self.user_grammar.package_item(&package_item_4_built)?;
+        self.push(ASTType::PackageItem(package_item_4_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 607:
+    /// Semantic action for production 725:
     ///
-    /// InterfaceItem: FunctionDeclaration;
+    /// PackageItem: FunctionDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn interface_item_8(
+    fn package_item_5(
         &mut self,
         _function_declaration: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -23971,23 +28648,23 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         trace!("{}", self.trace_item_stack(context));
         let function_declaration =
             pop_item!(self, function_declaration, FunctionDeclaration, context);
-        let interface_item_8_built = InterfaceItemFunctionDeclarationBuilder::default()
+        let package_item_5_built = PackageItemFunctionDeclarationBuilder::default()
             .function_declaration(Box::new(function_declaration))
             .build()
             .into_diagnostic()?;
-        let interface_item_8_built = InterfaceItem::FunctionDeclaration(interface_item_8_built);
+        let package_item_5_built = PackageItem::FunctionDeclaration(package_item_5_built);
         // Calling user action here
-        self.user_grammar.interface_item(&interface_item_8_built)?;
-        self.push(ASTType::InterfaceItem(interface_item_8_built), context);
+        self.user_grammar.package_item(&package_item_5_built)?;
+        self.push(ASTType::PackageItem(package_item_5_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 608:
+    /// Semantic action for production 726:
     ///
-    /// InterfaceItem: ImportDeclaration;
+    /// PackageItem: ImportDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn interface_item_9(
+    fn package_item_6(
         &mut self,
         _import_declaration: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -23995,281 +28672,303 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let import_declaration = pop_item!(self, import_declaration, ImportDeclaration, context);
-        let interface_item_9_built = InterfaceItemImportDeclarationBuilder::default()
+        let package_item_6_built = PackageItemImportDeclarationBuilder::default()
             .import_declaration(Box::new(import_declaration))
             .build()
             .into_diagnostic()?;
-        let interface_item_9_built = InterfaceItem::ImportDeclaration(interface_item_9_built);
+        let package_item_6_built = PackageItem::ImportDeclaration(package_item_6_built);
         // Calling user action here
-        self.user_grammar.interface_item(&interface_item_9_built)?;
-        self.push(ASTType::InterfaceItem(interface_item_9_built), context);
+        self.user_grammar.package_item(&package_item_6_built)?;
+        self.push(ASTType::PackageItem(package_item_6_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 609:
+    /// Semantic action for production 727:
     ///
-    /// PackageDeclaration: Package Identifier LBrace PackageDeclarationList /* Vec */ RBrace;
+    /// PackageItem: ExportDeclaration;
     ///
     #[parol_runtime::function_name::named]
-    fn package_declaration(
+    fn package_item_7(
         &mut self,
-        _package: &ParseTreeStackEntry<'t>,
+        _export_declaration: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let export_declaration = pop_item!(self, export_declaration, ExportDeclaration, context);
+        let package_item_7_built = PackageItemExportDeclarationBuilder::default()
+            .export_declaration(Box::new(export_declaration))
+            .build()
+            .into_diagnostic()?;
+        let package_item_7_built = PackageItem::ExportDeclaration(package_item_7_built);
+        // Calling user action here
+        self.user_grammar.package_item(&package_item_7_built)?;
+        self.push(ASTType::PackageItem(package_item_7_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 728:
+    ///
+    /// TestDeclaration: Test Identifier LBrace InstDeclaration TestDeclarationList /* Vec */ RBrace;
+    ///
+    #[parol_runtime::function_name::named]
+    fn test_declaration(
+        &mut self,
+        _test: &ParseTreeStackEntry<'t>,
         _identifier: &ParseTreeStackEntry<'t>,
         _l_brace: &ParseTreeStackEntry<'t>,
-        _package_declaration_list: &ParseTreeStackEntry<'t>,
+        _inst_declaration: &ParseTreeStackEntry<'t>,
+        _test_declaration_list: &ParseTreeStackEntry<'t>,
         _r_brace: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let r_brace = pop_item!(self, r_brace, RBrace, context);
-        let package_declaration_list = pop_and_reverse_item!(
-            self,
-            package_declaration_list,
-            PackageDeclarationList,
-            context
-        );
+        let test_declaration_list =
+            pop_and_reverse_item!(self, test_declaration_list, TestDeclarationList, context);
+        let inst_declaration = pop_item!(self, inst_declaration, InstDeclaration, context);
         let l_brace = pop_item!(self, l_brace, LBrace, context);
         let identifier = pop_item!(self, identifier, Identifier, context);
-        let package = pop_item!(self, package, Package, context);
-        let package_declaration_built = PackageDeclarationBuilder::default()
-            .package(Box::new(package))
+        let test = pop_item!(self, test, Test, context);
+        let test_declaration_built = TestDeclarationBuilder::default()
+            .test(Box::new(test))
             .identifier(Box::new(identifier))
             .l_brace(Box::new(l_brace))
-            .package_declaration_list(package_declaration_list)
+            .inst_declaration(Box::new(inst_declaration))
+            .test_declaration_list(test_declaration_list)
             .r_brace(Box::new(r_brace))
             .build()
             .into_diagnostic()?;
         // Calling user action here
         self.user_grammar
-            .package_declaration(&package_declaration_built)?;
-        self.push(
-            ASTType::PackageDeclaration(package_declaration_built),
-            context,
-        );
+            .test_declaration(&test_declaration_built)?;
+        self.push(ASTType::TestDeclaration(test_declaration_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 610:
+    /// Semantic action for production 729:
     ///
-    /// PackageDeclarationList /* Vec<T>::Push */: PackageItem PackageDeclarationList;
+    /// TestDeclarationList /* Vec<T>::Push */: TestItem TestDeclarationList;
     ///
     #[parol_runtime::function_name::named]
-    fn package_declaration_list_0(
+    fn test_declaration_list_0(
         &mut self,
-        _package_item: &ParseTreeStackEntry<'t>,
-        _package_declaration_list: &ParseTreeStackEntry<'t>,
+        _test_item: &ParseTreeStackEntry<'t>,
+        _test_declaration_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let mut package_declaration_list = pop_item!(
-            self,
-            package_declaration_list,
-            PackageDeclarationList,
-            context
-        );
-        let package_item = pop_item!(self, package_item, PackageItem, context);
-        let package_declaration_list_0_built = PackageDeclarationListBuilder::default()
-            .package_item(Box::new(package_item))
+        let mut test_declaration_list =
+            pop_item!(self, test_declaration_list, TestDeclarationList, context);
+        let test_item = pop_item!(self, test_item, TestItem, context);
+        let test_declaration_list_0_built = TestDeclarationListBuilder::default()
+            .test_item(Box::new(test_item))
             .build()
             .into_diagnostic()?;
         // Add an element to the vector
-        package_declaration_list.push(package_declaration_list_0_built);
-        self.push(
-            ASTType::PackageDeclarationList(package_declaration_list),
-            context,
-        );
+        test_declaration_list.push(test_declaration_list_0_built);
+        self.push(ASTType::TestDeclarationList(test_declaration_list), context);
         Ok(())
     }
 
-    /// Semantic action for production 611:
+    /// Semantic action for production 730:
     ///
-    /// PackageDeclarationList /* Vec<T>::New */: ;
+    /// TestDeclarationList /* Vec<T>::New */: ;
     ///
     #[parol_runtime::function_name::named]
-    fn package_declaration_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+    fn test_declaration_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let package_declaration_list_1_built = Vec::new();
+        let test_declaration_list_1_built = Vec::new();
         self.push(
-            ASTType::PackageDeclarationList(package_declaration_list_1_built),
+            ASTType::TestDeclarationList(test_declaration_list_1_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 612:
+    /// Semantic action for production 731:
     ///
-    /// PackageItem: VarDeclaration;
+    /// TestItem: AssignmentStatement;
     ///
     #[parol_runtime::function_name::named]
-    fn package_item_0(
+    fn test_item_0(
         &mut self,
-        _var_declaration: &ParseTreeStackEntry<'t>,
+        _assignment_statement: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let var_declaration = pop_item!(self, var_declaration, VarDeclaration, context);
-        let package_item_0_built = PackageItemVarDeclarationBuilder::default()
-            .var_declaration(Box::new(var_declaration))
+        let assignment_statement =
+            pop_item!(self, assignment_statement, AssignmentStatement, context);
+        let test_item_0_built = TestItemAssignmentStatementBuilder::default()
+            .assignment_statement(Box::new(assignment_statement))
             .build()
             .into_diagnostic()?;
-        let package_item_0_built = PackageItem::VarDeclaration(package_item_0_built);
+        let test_item_0_built = TestItem::AssignmentStatement(test_item_0_built);
         // Calling user action here
-        self.user_grammar.package_item(&package_item_0_built)?;
-        self.push(ASTType::PackageItem(package_item_0_built), context);
+        self.user_grammar.test_item(&test_item_0_built)?;
+        self.push(ASTType::TestItem(test_item_0_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 613:
+    /// Semantic action for production 732:
     ///
-    /// PackageItem: LocalparamDeclaration;
+    /// TestItem: StepStatement;
     ///
     #[parol_runtime::function_name::named]
-    fn package_item_1(
+    fn test_item_1(
         &mut self,
-        _localparam_declaration: &ParseTreeStackEntry<'t>,
+        _step_statement: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let localparam_declaration =
-            pop_item!(self, localparam_declaration, LocalparamDeclaration, context);
-        let package_item_1_built = PackageItemLocalparamDeclarationBuilder::default()
-            .localparam_declaration(Box::new(localparam_declaration))
+        let step_statement = pop_item!(self, step_statement, StepStatement, context);
+        let test_item_1_built = TestItemStepStatementBuilder::default()
+            .step_statement(Box::new(step_statement))
             .build()
             .into_diagnostic()?;
-        let package_item_1_built = PackageItem::LocalparamDeclaration(package_item_1_built);
+        let test_item_1_built = TestItem::StepStatement(test_item_1_built);
         // Calling user action here
-        self.user_grammar.package_item(&package_item_1_built)?;
-        self.push(ASTType::PackageItem(package_item_1_built), context);
+        self.user_grammar.test_item(&test_item_1_built)?;
+        self.push(ASTType::TestItem(test_item_1_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 614:
+    /// Semantic action for production 733:
     ///
-    /// PackageItem: EnumDeclaration;
+    /// TestItem: ExpectStatement;
     ///
     #[parol_runtime::function_name::named]
-    fn package_item_2(
+    fn test_item_2(
         &mut self,
-        _enum_declaration: &ParseTreeStackEntry<'t>,
+        _expect_statement: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let enum_declaration = pop_item!(self, enum_declaration, EnumDeclaration, context);
-        let package_item_2_built = PackageItemEnumDeclarationBuilder::default()
-            .enum_declaration(Box::new(enum_declaration))
+        let expect_statement = pop_item!(self, expect_statement, ExpectStatement, context);
+        let test_item_2_built = TestItemExpectStatementBuilder::default()
+            .expect_statement(Box::new(expect_statement))
             .build()
             .into_diagnostic()?;
-        let package_item_2_built = PackageItem::EnumDeclaration(package_item_2_built);
+        let test_item_2_built = TestItem::ExpectStatement(test_item_2_built);
         // Calling user action here
-        self.user_grammar.package_item(&package_item_2_built)?;
-        self.push(ASTType::PackageItem(package_item_2_built), context);
+        self.user_grammar.test_item(&test_item_2_built)?;
+        self.push(ASTType::TestItem(test_item_2_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 615:
+    /// Semantic action for production 734:
     ///
-    /// PackageItem: StructDeclaration;
+    /// StepStatement: Step HierarchicalIdentifier StepStatementOpt /* Option */ Semicolon;
     ///
     #[parol_runtime::function_name::named]
-    fn package_item_3(
+    fn step_statement(
         &mut self,
-        _struct_declaration: &ParseTreeStackEntry<'t>,
+        _step: &ParseTreeStackEntry<'t>,
+        _hierarchical_identifier: &ParseTreeStackEntry<'t>,
+        _step_statement_opt: &ParseTreeStackEntry<'t>,
+        _semicolon: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let struct_declaration = pop_item!(self, struct_declaration, StructDeclaration, context);
-        let package_item_3_built = PackageItemStructDeclarationBuilder::default()
-            .struct_declaration(Box::new(struct_declaration))
+        let semicolon = pop_item!(self, semicolon, Semicolon, context);
+        let step_statement_opt = pop_item!(self, step_statement_opt, StepStatementOpt, context);
+        let hierarchical_identifier = pop_item!(
+            self,
+            hierarchical_identifier,
+            HierarchicalIdentifier,
+            context
+        );
+        let step = pop_item!(self, step, Step, context);
+        let step_statement_built = StepStatementBuilder::default()
+            .step(Box::new(step))
+            .hierarchical_identifier(Box::new(hierarchical_identifier))
+            .step_statement_opt(step_statement_opt)
+            .semicolon(Box::new(semicolon))
             .build()
             .into_diagnostic()?;
-        let package_item_3_built = PackageItem::StructDeclaration(package_item_3_built);
         // Calling user action here
-        self.user_grammar.package_item(&package_item_3_built)?;
-        self.push(ASTType::PackageItem(package_item_3_built), context);
+        self.user_grammar.step_statement(&step_statement_built)?;
+        self.push(ASTType::StepStatement(step_statement_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 616:
+    /// Semantic action for production 735:
     ///
-    /// PackageItem: FunctionDeclaration;
+    /// StepStatementOpt /* Option<T>::Some */: Comma Expression;
     ///
     #[parol_runtime::function_name::named]
-    fn package_item_4(
+    fn step_statement_opt_0(
         &mut self,
-        _function_declaration: &ParseTreeStackEntry<'t>,
+        _comma: &ParseTreeStackEntry<'t>,
+        _expression: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let function_declaration =
-            pop_item!(self, function_declaration, FunctionDeclaration, context);
-        let package_item_4_built = PackageItemFunctionDeclarationBuilder::default()
-            .function_declaration(Box::new(function_declaration))
+        let expression = pop_item!(self, expression, Expression, context);
+        let comma = pop_item!(self, comma, Comma, context);
+        let step_statement_opt_0_built = StepStatementOptBuilder::default()
+            .comma(Box::new(comma))
+            .expression(Box::new(expression))
             .build()
             .into_diagnostic()?;
-        let package_item_4_built = PackageItem::FunctionDeclaration(package_item_4_built);
-        // Calling user action here
-        self.user_grammar.package_item(&package_item_4_built)?;
-        self.push(ASTType::PackageItem(package_item_4_built), context);
+        self.push(
+            ASTType::StepStatementOpt(Some(Box::new(step_statement_opt_0_built))),
+            context,
+        );
         Ok(())
     }
 
-    /// Semantic action for production 617:
+    /// Semantic action for production 736:
     ///
-    /// PackageItem: ImportDeclaration;
+    /// StepStatementOpt /* Option<T>::None */: ;
     ///
     #[parol_runtime::function_name::named]
-    fn package_item_5(
-        &mut self,
-        _import_declaration: &ParseTreeStackEntry<'t>,
-        _parse_tree: &Tree<ParseTreeType<'t>>,
-    ) -> Result<()> {
+    fn step_statement_opt_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let import_declaration = pop_item!(self, import_declaration, ImportDeclaration, context);
-        let package_item_5_built = PackageItemImportDeclarationBuilder::default()
-            .import_declaration(Box::new(import_declaration))
-            .build()
-            .into_diagnostic()?;
-        let package_item_5_built = PackageItem::ImportDeclaration(package_item_5_built);
-        // Calling user action here
-        self.user_grammar.package_item(&package_item_5_built)?;
-        self.push(ASTType::PackageItem(package_item_5_built), context);
+        self.push(ASTType::StepStatementOpt(None), context);
         Ok(())
     }
 
-    /// Semantic action for production 618:
+    /// Semantic action for production 737:
     ///
-    /// PackageItem: ExportDeclaration;
+    /// ExpectStatement: Expect Expression Semicolon;
     ///
     #[parol_runtime::function_name::named]
-    fn package_item_6(
+    fn expect_statement(
         &mut self,
-        _export_declaration: &ParseTreeStackEntry<'t>,
+        _expect: &ParseTreeStackEntry<'t>,
+        _expression: &ParseTreeStackEntry<'t>,
+        _semicolon: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let export_declaration = pop_item!(self, export_declaration, ExportDeclaration, context);
-        let package_item_6_built = PackageItemExportDeclarationBuilder::default()
-            .export_declaration(Box::new(export_declaration))
+        let semicolon = pop_item!(self, semicolon, Semicolon, context);
+        let expression = pop_item!(self, expression, Expression, context);
+        let expect = pop_item!(self, expect, Expect, context);
+        let expect_statement_built = ExpectStatementBuilder::default()
+            .expect(Box::new(expect))
+            .expression(Box::new(expression))
+            .semicolon(Box::new(semicolon))
             .build()
             .into_diagnostic()?;
-        let package_item_6_built = PackageItem::ExportDeclaration(package_item_6_built);
         // Calling user action here
-        self.user_grammar.package_item(&package_item_6_built)?;
-        self.push(ASTType::PackageItem(package_item_6_built), context);
+        self.user_grammar
+            .expect_statement(&expect_statement_built)?;
+        self.push(ASTType::ExpectStatement(expect_statement_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 619:
+    /// Semantic action for production 738:
     ///
     /// Description: ModuleDeclaration;
     ///
@@ -24293,7 +28992,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 620:
+    /// Semantic action for production 739:
     ///
     /// Description: InterfaceDeclaration;
     ///
@@ -24318,7 +29017,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 621:
+    /// Semantic action for production 740:
     ///
     /// Description: PackageDeclaration;
     ///
@@ -24342,7 +29041,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 622:
+    /// Semantic action for production 741:
     ///
     /// Description: ImportDeclaration;
     ///
@@ -24366,7 +29065,31 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 623:
+    /// Semantic action for production 742:
+    ///
+    /// Description: TestDeclaration;
+    ///
+    #[parol_runtime::function_name::named]
+    fn description_4(
+        &mut self,
+        _test_declaration: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let test_declaration = pop_item!(self, test_declaration, TestDeclaration, context);
+        let description_4_built = DescriptionTestDeclarationBuilder::default()
+            .test_declaration(Box::new(test_declaration))
+            .build()
+            .into_diagnostic()?;
+        let description_4_built = Description::TestDeclaration(description_4_built);
+        // Calling user action here
+        self.user_grammar.description(&description_4_built)?;
+        self.push(ASTType::Description(description_4_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 743:
     ///
     /// Veryl: Start VerylList /* Vec */;
     ///
@@ -24392,13 +29115,14 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 624:
+    /// Semantic action for production 744:
     ///
-    /// VerylList /* Vec<T>::Push */: Description VerylList;
+    /// VerylList /* Vec<T>::Push */: VerylListList /* Vec */ Description VerylList;
     ///
     #[parol_runtime::function_name::named]
     fn veryl_list_0(
         &mut self,
+        _veryl_list_list: &ParseTreeStackEntry<'t>,
         _description: &ParseTreeStackEntry<'t>,
         _veryl_list: &ParseTreeStackEntry<'t>,
         _parse_tree: &Tree<ParseTreeType<'t>>,
@@ -24407,8 +29131,10 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         trace!("{}", self.trace_item_stack(context));
         let mut veryl_list = pop_item!(self, veryl_list, VerylList, context);
         let description = pop_item!(self, description, Description, context);
+        let veryl_list_list = pop_and_reverse_item!(self, veryl_list_list, VerylListList, context);
         let veryl_list_0_built = VerylListBuilder::default()
             .description(Box::new(description))
+            .veryl_list_list(veryl_list_list)
             .build()
             .into_diagnostic()?;
         // Add an element to the vector
@@ -24417,7 +29143,45 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 625:
+    /// Semantic action for production 745:
+    ///
+    /// VerylListList /* Vec<T>::Push */: Attribute VerylListList;
+    ///
+    #[parol_runtime::function_name::named]
+    fn veryl_list_list_0(
+        &mut self,
+        _attribute: &ParseTreeStackEntry<'t>,
+        _veryl_list_list: &ParseTreeStackEntry<'t>,
+        _parse_tree: &Tree<ParseTreeType<'t>>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut veryl_list_list = pop_item!(self, veryl_list_list, VerylListList, context);
+        let attribute = pop_item!(self, attribute, Attribute, context);
+        let veryl_list_list_0_built = VerylListListBuilder::default()
+            .attribute(Box::new(attribute))
+            .build()
+            .into_diagnostic()?;
+        // Add an element to the vector
+        veryl_list_list.push(veryl_list_list_0_built);
+        self.push(ASTType::VerylListList(veryl_list_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 746:
+    ///
+    /// VerylListList /* Vec<T>::New */: ;
+    ///
+    #[parol_runtime::function_name::named]
+    fn veryl_list_list_1(&mut self, _parse_tree: &Tree<ParseTreeType<'t>>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let veryl_list_list_1_built = Vec::new();
+        self.push(ASTType::VerylListList(veryl_list_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 747:
     ///
     /// VerylList /* Vec<T>::New */: ;
     ///
@@ -24448,26 +29212,26 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
             3 => self.based_term(&children[0], parse_tree),
             4 => self.base_less_term(&children[0], parse_tree),
             5 => self.all_bit_term(&children[0], parse_tree),
-            6 => self.minus_colon_term(&children[0], parse_tree),
-            7 => self.minus_g_t_term(&children[0], parse_tree),
-            8 => self.plus_colon_term(&children[0], parse_tree),
-            9 => self.assignment_operator_term(&children[0], parse_tree),
-            10 => self.operator11_term(&children[0], parse_tree),
-            11 => self.operator10_term(&children[0], parse_tree),
-            12 => self.operator09_term(&children[0], parse_tree),
-            13 => self.operator08_term(&children[0], parse_tree),
-            14 => self.operator07_term(&children[0], parse_tree),
-            15 => self.operator06_term(&children[0], parse_tree),
-            16 => self.operator02_term(&children[0], parse_tree),
-            17 => self.operator01_term(&children[0], parse_tree),
-            18 => self.operator05_term(&children[0], parse_tree),
-            19 => self.operator04_term(&children[0], parse_tree),
-            20 => self.operator03_term(&children[0], parse_tree),
-            21 => self.unary_operator_term(&children[0], parse_tree),
-            22 => self.colon_colon_term(&children[0], parse_tree),
-            23 => self.colon_term(&children[0], parse_tree),
-            24 => self.comma_term(&children[0], parse_tree),
-            25 => self.dollar_term(&children[0], parse_tree),
+            6 => self.string_literal_term(&children[0], parse_tree),
+            7 => self.minus_colon_term(&children[0], parse_tree),
+            8 => self.minus_g_t_term(&children[0], parse_tree),
+            9 => self.plus_colon_term(&children[0], parse_tree),
+            10 => self.assignment_operator_term(&children[0], parse_tree),
+            11 => self.operator11_term(&children[0], parse_tree),
+            12 => self.operator10_term(&children[0], parse_tree),
+            13 => self.operator09_term(&children[0], parse_tree),
+            14 => self.operator08_term(&children[0], parse_tree),
+            15 => self.operator07_term(&children[0], parse_tree),
+            16 => self.operator06_term(&children[0], parse_tree),
+            17 => self.operator02_term(&children[0], parse_tree),
+            18 => self.operator01_term(&children[0], parse_tree),
+            19 => self.operator05_term(&children[0], parse_tree),
+            20 => self.operator04_term(&children[0], parse_tree),
+            21 => self.operator03_term(&children[0], parse_tree),
+            22 => self.unary_operator_term(&children[0], parse_tree),
+            23 => self.colon_colon_term(&children[0], parse_tree),
+            24 => self.colon_term(&children[0], parse_tree),
+            25 => self.comma_term(&children[0], parse_tree),
             26 => self.dot_dot_term(&children[0], parse_tree),
             27 => self.dot_term(&children[0], parse_tree),
             28 => self.equ_term(&children[0], parse_tree),
@@ -24482,401 +29246,492 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
             37 => self.star_term(&children[0], parse_tree),
             38 => self.always_comb_term(&children[0], parse_tree),
             39 => self.always_ff_term(&children[0], parse_tree),
-            40 => self.assign_term(&children[0], parse_tree),
-            41 => self.async_high_term(&children[0], parse_tree),
-            42 => self.async_low_term(&children[0], parse_tree),
-            43 => self.bit_term(&children[0], parse_tree),
-            44 => self.case_term(&children[0], parse_tree),
-            45 => self.default_term(&children[0], parse_tree),
-            46 => self.else_term(&children[0], parse_tree),
-            47 => self.enum_term(&children[0], parse_tree),
-            48 => self.export_term(&children[0], parse_tree),
-            49 => self.f32_term(&children[0], parse_tree),
-            50 => self.f64_term(&children[0], parse_tree),
-            51 => self.for_term(&children[0], parse_tree),
-            52 => self.function_term(&children[0], parse_tree),
-            53 => self.i32_term(&children[0], parse_tree),
-            54 => self.i64_term(&children[0], parse_tree),
-            55 => self.if_reset_term(&children[0], parse_tree),
-            56 => self.if_term(&children[0], parse_tree),
-            57 => self.import_term(&children[0], parse_tree),
-            58 => self.inout_term(&children[0], parse_tree),
-            59 => self.input_term(&children[0], parse_tree),
-            60 => self.inst_term(&children[0], parse_tree),
-            61 => self.interface_term(&children[0], parse_tree),
-            62 => self.in_term(&children[0], parse_tree),
-            63 => self.localparam_term(&children[0], parse_tree),
-            64 => self.logic_term(&children[0], parse_tree),
-            65 => self.modport_term(&children[0], parse_tree),
-            66 => self.module_term(&children[0], parse_tree),
-            67 => self.negedge_term(&children[0], parse_tree),
-            68 => self.output_term(&children[0], parse_tree),
-            69 => self.package_term(&children[0], parse_tree),
-            70 => self.parameter_term(&children[0], parse_tree),
-            71 => self.posedge_term(&children[0], parse_tree),
-            72 => self.ref_term(&children[0], parse_tree),
-            73 => self.repeat_term(&children[0], parse_tree),
-            74 => self.return_term(&children[0], parse_tree),
-            75 => self.step_term(&children[0], parse_tree),
-            76 => self.struct_term(&children[0], parse_tree),
-            77 => self.sync_high_term(&children[0], parse_tree),
-            78 => self.sync_low_term(&children[0], parse_tree),
-            79 => self.tri_term(&children[0], parse_tree),
-            80 => self.u32_term(&children[0], parse_tree),
-            81 => self.u64_term(&children[0], parse_tree),
-            82 => self.var_term(&children[0], parse_tree),
-            83 => self.identifier_term(&children[0], parse_tree),
-            84 => self.comments(&children[0], parse_tree),
-            85 => self.comments_opt_0(&children[0], parse_tree),
-            86 => self.comments_opt_1(parse_tree),
-            87 => self.start_token(&children[0], parse_tree),
-            88 => self.exponent_token(&children[0], &children[1], parse_tree),
-            89 => self.fixed_point_token(&children[0], &children[1], parse_tree),
-            90 => self.based_token(&children[0], &children[1], parse_tree),
-            91 => self.base_less_token(&children[0], &children[1], parse_tree),
-            92 => self.all_bit_token(&children[0], &children[1], parse_tree),
-            93 => self.assignment_operator_token(&children[0], &children[1], parse_tree),
-            94 => self.operator01_token(&children[0], &children[1], parse_tree),
-            95 => self.operator02_token(&children[0], &children[1], parse_tree),
-            96 => self.operator03_token(&children[0], &children[1], parse_tree),
-            97 => self.operator04_token(&children[0], &children[1], parse_tree),
-            98 => self.operator05_token(&children[0], &children[1], parse_tree),
-            99 => self.operator06_token(&children[0], &children[1], parse_tree),
-            100 => self.operator07_token(&children[0], &children[1], parse_tree),
-            101 => self.operator08_token(&children[0], &children[1], parse_tree),
-            102 => self.operator09_token(&children[0], &children[1], parse_tree),
-            103 => self.operator10_token(&children[0], &children[1], parse_tree),
-            104 => self.operator11_token(&children[0], &children[1], parse_tree),
-            105 => self.unary_operator_token(&children[0], &children[1], parse_tree),
-            106 => self.colon_token(&children[0], &children[1], parse_tree),
-            107 => self.colon_colon_token(&children[0], &children[1], parse_tree),
-            108 => self.comma_token(&children[0], &children[1], parse_tree),
-            109 => self.dollar_token(&children[0], &children[1], parse_tree),
-            110 => self.dot_dot_token(&children[0], &children[1], parse_tree),
-            111 => self.dot_token(&children[0], &children[1], parse_tree),
-            112 => self.equ_token(&children[0], &children[1], parse_tree),
-            113 => self.hash_token(&children[0], &children[1], parse_tree),
-            114 => self.l_brace_token(&children[0], &children[1], parse_tree),
-            115 => self.l_bracket_token(&children[0], &children[1], parse_tree),
-            116 => self.l_paren_token(&children[0], &children[1], parse_tree),
-            117 => self.minus_colon_token(&children[0], &children[1], parse_tree),
-            118 => self.minus_g_t_token(&children[0], &children[1], parse_tree),
-            119 => self.plus_colon_token(&children[0], &children[1], parse_tree),
-            120 => self.r_brace_token(&children[0], &children[1], parse_tree),
-            121 => self.r_bracket_token(&children[0], &children[1], parse_tree),
-            122 => self.r_paren_token(&children[0], &children[1], parse_tree),
-            123 => self.semicolon_token(&children[0], &children[1], parse_tree),
-            124 => self.star_token(&children[0], &children[1], parse_tree),
-            125 => self.always_comb_token(&children[0], &children[1], parse_tree),
-            126 => self.always_ff_token(&children[0], &children[1], parse_tree),
-            127 => self.assign_token(&children[0], &children[1], parse_tree),
-            128 => self.async_high_token(&children[0], &children[1], parse_tree),
-            129 => self.async_low_token(&children[0], &children[1], parse_tree),
-            130 => self.bit_token(&children[0], &children[1], parse_tree),
-            131 => self.case_token(&children[0], &children[1], parse_tree),
-            132 => self.default_token(&children[0], &children[1], parse_tree),
-            133 => self.else_token(&children[0], &children[1], parse_tree),
-            134 => self.enum_token(&children[0], &children[1], parse_tree),
-            135 => self.export_token(&children[0], &children[1], parse_tree),
-            136 => self.f32_token(&children[0], &children[1], parse_tree),
-            137 => self.f64_token(&children[0], &children[1], parse_tree),
-            138 => self.for_token(&children[0], &children[1], parse_tree),
-            139 => self.function_token(&children[0], &children[1], parse_tree),
-            140 => self.i32_token(&children[0], &children[1], parse_tree),
-            141 => self.i64_token(&children[0], &children[1], parse_tree),
-            142 => self.if_reset_token(&children[0], &children[1], parse_tree),
-            143 => self.if_token(&children[0], &children[1], parse_tree),
-            144 => self.import_token(&children[0], &children[1], parse_tree),
-            145 => self.inout_token(&children[0], &children[1], parse_tree),
-            146 => self.input_token(&children[0], &children[1], parse_tree),
-            147 => self.inst_token(&children[0], &children[1], parse_tree),
-            148 => self.interface_token(&children[0], &children[1], parse_tree),
-            149 => self.in_token(&children[0], &children[1], parse_tree),
-            150 => self.localparam_token(&children[0], &children[1], parse_tree),
-            151 => self.logic_token(&children[0], &children[1], parse_tree),
-            152 => self.modport_token(&children[0], &children[1], parse_tree),
-            153 => self.module_token(&children[0], &children[1], parse_tree),
-            154 => self.negedge_token(&children[0], &children[1], parse_tree),
-            155 => self.output_token(&children[0], &children[1], parse_tree),
-            156 => self.package_token(&children[0], &children[1], parse_tree),
-            157 => self.parameter_token(&children[0], &children[1], parse_tree),
-            158 => self.posedge_token(&children[0], &children[1], parse_tree),
-            159 => self.ref_token(&children[0], &children[1], parse_tree),
-            160 => self.repeat_token(&children[0], &children[1], parse_tree),
-            161 => self.return_token(&children[0], &children[1], parse_tree),
-            162 => self.step_token(&children[0], &children[1], parse_tree),
-            163 => self.struct_token(&children[0], &children[1], parse_tree),
-            164 => self.sync_high_token(&children[0], &children[1], parse_tree),
-            165 => self.sync_low_token(&children[0], &children[1], parse_tree),
-            166 => self.tri_token(&children[0], &children[1], parse_tree),
-            167 => self.u32_token(&children[0], &children[1], parse_tree),
-            168 => self.u64_token(&children[0], &children[1], parse_tree),
-            169 => self.var_token(&children[0], &children[1], parse_tree),
-            170 => self.identifier_token(&children[0], &children[1], parse_tree),
-            171 => self.start(&children[0], parse_tree),
-            172 => self.exponent(&children[0], parse_tree),
-            173 => self.fixed_point(&children[0], parse_tree),
-            174 => self.based(&children[0], parse_tree),
-            175 => self.base_less(&children[0], parse_tree),
-            176 => self.all_bit(&children[0], parse_tree),
-            177 => self.assignment_operator(&children[0], parse_tree),
-            178 => self.operator01(&children[0], parse_tree),
-            179 => self.operator02(&children[0], parse_tree),
-            180 => self.operator03(&children[0], parse_tree),
-            181 => self.operator04(&children[0], parse_tree),
-            182 => self.operator05(&children[0], parse_tree),
-            183 => self.operator06(&children[0], parse_tree),
-            184 => self.operator07(&children[0], parse_tree),
-            185 => self.operator08(&children[0], parse_tree),
-            186 => self.operator09(&children[0], parse_tree),
-            187 => self.operator10(&children[0], parse_tree),
-            188 => self.operator11(&children[0], parse_tree),
-            189 => self.unary_operator(&children[0], parse_tree),
-            190 => self.colon(&children[0], parse_tree),
-            191 => self.colon_colon(&children[0], parse_tree),
-            192 => self.comma(&children[0], parse_tree),
-            193 => self.dollar(&children[0], parse_tree),
-            194 => self.dot_dot(&children[0], parse_tree),
-            195 => self.dot(&children[0], parse_tree),
-            196 => self.equ(&children[0], parse_tree),
-            197 => self.hash(&children[0], parse_tree),
-            198 => self.l_brace(&children[0], parse_tree),
-            199 => self.l_bracket(&children[0], parse_tree),
-            200 => self.l_paren(&children[0], parse_tree),
-            201 => self.minus_colon(&children[0], parse_tree),
-            202 => self.minus_g_t(&children[0], parse_tree),
-            203 => self.plus_colon(&children[0], parse_tree),
-            204 => self.r_brace(&children[0], parse_tree),
-            205 => self.r_bracket(&children[0], parse_tree),
-            206 => self.r_paren(&children[0], parse_tree),
-            207 => self.semicolon(&children[0], parse_tree),
-            208 => self.star(&children[0], parse_tree),
-            209 => self.always_comb(&children[0], parse_tree),
-            210 => self.always_ff(&children[0], parse_tree),
-            211 => self.assign(&children[0], parse_tree),
-            212 => self.async_high(&children[0], parse_tree),
-            213 => self.async_low(&children[0], parse_tree),
-            214 => self.bit(&children[0], parse_tree),
-            215 => self.case(&children[0], parse_tree),
-            216 => self.defaul(&children[0], parse_tree),
-            217 => self.r#else(&children[0], parse_tree),
-            218 => self.r#enum(&children[0], parse_tree),
-            219 => self.export(&children[0], parse_tree),
-            220 => self.f32(&children[0], parse_tree),
-            221 => self.f64(&children[0], parse_tree),
-            222 => self.r#for(&children[0], parse_tree),
-            223 => self.function(&children[0], parse_tree),
-            224 => self.i32(&children[0], parse_tree),
-            225 => self.i64(&children[0], parse_tree),
-            226 => self.r#if(&children[0], parse_tree),
-            227 => self.if_reset(&children[0], parse_tree),
-            228 => self.import(&children[0], parse_tree),
-            229 => self.r#in(&children[0], parse_tree),
-            230 => self.inout(&children[0], parse_tree),
-            231 => self.input(&children[0], parse_tree),
-            232 => self.inst(&children[0], parse_tree),
-            233 => self.interface(&children[0], parse_tree),
-            234 => self.localparam(&children[0], parse_tree),
-            235 => self.logic(&children[0], parse_tree),
-            236 => self.modport(&children[0], parse_tree),
-            237 => self.module(&children[0], parse_tree),
-            238 => self.negedge(&children[0], parse_tree),
-            239 => self.output(&children[0], parse_tree),
-            240 => self.package(&children[0], parse_tree),
-            241 => self.parameter(&children[0], parse_tree),
-            242 => self.posedge(&children[0], parse_tree),
-            243 => self.r#ref(&children[0], parse_tree),
-            244 => self.repeat(&children[0], parse_tree),
-            245 => self.r#return(&children[0], parse_tree),
-            246 => self.step(&children[0], parse_tree),
-            247 => self.r#struct(&children[0], parse_tree),
-            248 => self.sync_high(&children[0], parse_tree),
-            249 => self.sync_low(&children[0], parse_tree),
-            250 => self.tri(&children[0], parse_tree),
-            251 => self.u32(&children[0], parse_tree),
-            252 => self.u64(&children[0], parse_tree),
-            253 => self.var(&children[0], parse_tree),
-            254 => self.identifier(&children[0], parse_tree),
-            255 => self.number_0(&children[0], parse_tree),
-            256 => self.number_1(&children[0], parse_tree),
-            257 => self.integral_number_0(&children[0], parse_tree),
-            258 => self.integral_number_1(&children[0], parse_tree),
-            259 => self.integral_number_2(&children[0], parse_tree),
-            260 => self.real_number_0(&children[0], parse_tree),
-            261 => self.real_number_1(&children[0], parse_tree),
-            262 => {
+            40 => self.as_term(&children[0], parse_tree),
+            41 => self.assert_term(&children[0], parse_tree),
+            42 => self.assign_term(&children[0], parse_tree),
+            43 => self.assume_term(&children[0], parse_tree),
+            44 => self.async_high_term(&children[0], parse_tree),
+            45 => self.async_low_term(&children[0], parse_tree),
+            46 => self.bit_term(&children[0], parse_tree),
+            47 => self.case_term(&children[0], parse_tree),
+            48 => self.cover_term(&children[0], parse_tree),
+            49 => self.default_term(&children[0], parse_tree),
+            50 => self.else_term(&children[0], parse_tree),
+            51 => self.enum_term(&children[0], parse_tree),
+            52 => self.expect_term(&children[0], parse_tree),
+            53 => self.export_term(&children[0], parse_tree),
+            54 => self.f32_term(&children[0], parse_tree),
+            55 => self.f64_term(&children[0], parse_tree),
+            56 => self.final_term(&children[0], parse_tree),
+            57 => self.for_term(&children[0], parse_tree),
+            58 => self.function_term(&children[0], parse_tree),
+            59 => self.i32_term(&children[0], parse_tree),
+            60 => self.i64_term(&children[0], parse_tree),
+            61 => self.if_reset_term(&children[0], parse_tree),
+            62 => self.if_term(&children[0], parse_tree),
+            63 => self.import_term(&children[0], parse_tree),
+            64 => self.initial_term(&children[0], parse_tree),
+            65 => self.inout_term(&children[0], parse_tree),
+            66 => self.input_term(&children[0], parse_tree),
+            67 => self.inst_term(&children[0], parse_tree),
+            68 => self.interface_term(&children[0], parse_tree),
+            69 => self.in_term(&children[0], parse_tree),
+            70 => self.localparam_term(&children[0], parse_tree),
+            71 => self.logic_term(&children[0], parse_tree),
+            72 => self.modport_term(&children[0], parse_tree),
+            73 => self.module_term(&children[0], parse_tree),
+            74 => self.negedge_term(&children[0], parse_tree),
+            75 => self.output_term(&children[0], parse_tree),
+            76 => self.package_term(&children[0], parse_tree),
+            77 => self.parameter_term(&children[0], parse_tree),
+            78 => self.posedge_term(&children[0], parse_tree),
+            79 => self.ref_term(&children[0], parse_tree),
+            80 => self.repeat_term(&children[0], parse_tree),
+            81 => self.return_term(&children[0], parse_tree),
+            82 => self.signed_term(&children[0], parse_tree),
+            83 => self.step_term(&children[0], parse_tree),
+            84 => self.struct_term(&children[0], parse_tree),
+            85 => self.sync_high_term(&children[0], parse_tree),
+            86 => self.sync_low_term(&children[0], parse_tree),
+            87 => self.test_term(&children[0], parse_tree),
+            88 => self.tri_term(&children[0], parse_tree),
+            89 => self.type_term(&children[0], parse_tree),
+            90 => self.u32_term(&children[0], parse_tree),
+            91 => self.u64_term(&children[0], parse_tree),
+            92 => self.union_term(&children[0], parse_tree),
+            93 => self.unpacked_term(&children[0], parse_tree),
+            94 => self.var_term(&children[0], parse_tree),
+            95 => self.dollar_identifier_term(&children[0], parse_tree),
+            96 => self.identifier_term(&children[0], parse_tree),
+            97 => self.comments(&children[0], parse_tree),
+            98 => self.comments_opt_0(&children[0], parse_tree),
+            99 => self.comments_opt_1(parse_tree),
+            100 => self.start_token(&children[0], parse_tree),
+            101 => self.exponent_token(&children[0], &children[1], parse_tree),
+            102 => self.fixed_point_token(&children[0], &children[1], parse_tree),
+            103 => self.based_token(&children[0], &children[1], parse_tree),
+            104 => self.base_less_token(&children[0], &children[1], parse_tree),
+            105 => self.all_bit_token(&children[0], &children[1], parse_tree),
+            106 => self.string_literal_token(&children[0], &children[1], parse_tree),
+            107 => self.assignment_operator_token(&children[0], &children[1], parse_tree),
+            108 => self.operator01_token(&children[0], &children[1], parse_tree),
+            109 => self.operator02_token(&children[0], &children[1], parse_tree),
+            110 => self.operator03_token(&children[0], &children[1], parse_tree),
+            111 => self.operator04_token(&children[0], &children[1], parse_tree),
+            112 => self.operator05_token(&children[0], &children[1], parse_tree),
+            113 => self.operator06_token(&children[0], &children[1], parse_tree),
+            114 => self.operator07_token(&children[0], &children[1], parse_tree),
+            115 => self.operator08_token(&children[0], &children[1], parse_tree),
+            116 => self.operator09_token(&children[0], &children[1], parse_tree),
+            117 => self.operator10_token(&children[0], &children[1], parse_tree),
+            118 => self.operator11_token(&children[0], &children[1], parse_tree),
+            119 => self.unary_operator_token(&children[0], &children[1], parse_tree),
+            120 => self.colon_token(&children[0], &children[1], parse_tree),
+            121 => self.colon_colon_token(&children[0], &children[1], parse_tree),
+            122 => self.comma_token(&children[0], &children[1], parse_tree),
+            123 => self.dot_dot_token(&children[0], &children[1], parse_tree),
+            124 => self.dot_token(&children[0], &children[1], parse_tree),
+            125 => self.equ_token(&children[0], &children[1], parse_tree),
+            126 => self.hash_token(&children[0], &children[1], parse_tree),
+            127 => self.l_brace_token(&children[0], &children[1], parse_tree),
+            128 => self.l_bracket_token(&children[0], &children[1], parse_tree),
+            129 => self.l_paren_token(&children[0], &children[1], parse_tree),
+            130 => self.minus_colon_token(&children[0], &children[1], parse_tree),
+            131 => self.minus_g_t_token(&children[0], &children[1], parse_tree),
+            132 => self.plus_colon_token(&children[0], &children[1], parse_tree),
+            133 => self.r_brace_token(&children[0], &children[1], parse_tree),
+            134 => self.r_bracket_token(&children[0], &children[1], parse_tree),
+            135 => self.r_paren_token(&children[0], &children[1], parse_tree),
+            136 => self.semicolon_token(&children[0], &children[1], parse_tree),
+            137 => self.star_token(&children[0], &children[1], parse_tree),
+            138 => self.always_comb_token(&children[0], &children[1], parse_tree),
+            139 => self.always_ff_token(&children[0], &children[1], parse_tree),
+            140 => self.as_token(&children[0], &children[1], parse_tree),
+            141 => self.assert_token(&children[0], &children[1], parse_tree),
+            142 => self.assign_token(&children[0], &children[1], parse_tree),
+            143 => self.assume_token(&children[0], &children[1], parse_tree),
+            144 => self.async_high_token(&children[0], &children[1], parse_tree),
+            145 => self.async_low_token(&children[0], &children[1], parse_tree),
+            146 => self.bit_token(&children[0], &children[1], parse_tree),
+            147 => self.case_token(&children[0], &children[1], parse_tree),
+            148 => self.cover_token(&children[0], &children[1], parse_tree),
+            149 => self.default_token(&children[0], &children[1], parse_tree),
+            150 => self.else_token(&children[0], &children[1], parse_tree),
+            151 => self.enum_token(&children[0], &children[1], parse_tree),
+            152 => self.expect_token(&children[0], &children[1], parse_tree),
+            153 => self.export_token(&children[0], &children[1], parse_tree),
+            154 => self.f32_token(&children[0], &children[1], parse_tree),
+            155 => self.f64_token(&children[0], &children[1], parse_tree),
+            156 => self.final_token(&children[0], &children[1], parse_tree),
+            157 => self.for_token(&children[0], &children[1], parse_tree),
+            158 => self.function_token(&children[0], &children[1], parse_tree),
+            159 => self.i32_token(&children[0], &children[1], parse_tree),
+            160 => self.i64_token(&children[0], &children[1], parse_tree),
+            161 => self.if_reset_token(&children[0], &children[1], parse_tree),
+            162 => self.if_token(&children[0], &children[1], parse_tree),
+            163 => self.import_token(&children[0], &children[1], parse_tree),
+            164 => self.initial_token(&children[0], &children[1], parse_tree),
+            165 => self.inout_token(&children[0], &children[1], parse_tree),
+            166 => self.input_token(&children[0], &children[1], parse_tree),
+            167 => self.inst_token(&children[0], &children[1], parse_tree),
+            168 => self.interface_token(&children[0], &children[1], parse_tree),
+            169 => self.in_token(&children[0], &children[1], parse_tree),
+            170 => self.localparam_token(&children[0], &children[1], parse_tree),
+            171 => self.logic_token(&children[0], &children[1], parse_tree),
+            172 => self.modport_token(&children[0], &children[1], parse_tree),
+            173 => self.module_token(&children[0], &children[1], parse_tree),
+            174 => self.negedge_token(&children[0], &children[1], parse_tree),
+            175 => self.output_token(&children[0], &children[1], parse_tree),
+            176 => self.package_token(&children[0], &children[1], parse_tree),
+            177 => self.parameter_token(&children[0], &children[1], parse_tree),
+            178 => self.posedge_token(&children[0], &children[1], parse_tree),
+            179 => self.ref_token(&children[0], &children[1], parse_tree),
+            180 => self.repeat_token(&children[0], &children[1], parse_tree),
+            181 => self.return_token(&children[0], &children[1], parse_tree),
+            182 => self.signed_token(&children[0], &children[1], parse_tree),
+            183 => self.step_token(&children[0], &children[1], parse_tree),
+            184 => self.struct_token(&children[0], &children[1], parse_tree),
+            185 => self.sync_high_token(&children[0], &children[1], parse_tree),
+            186 => self.sync_low_token(&children[0], &children[1], parse_tree),
+            187 => self.test_token(&children[0], &children[1], parse_tree),
+            188 => self.tri_token(&children[0], &children[1], parse_tree),
+            189 => self.type_token(&children[0], &children[1], parse_tree),
+            190 => self.u32_token(&children[0], &children[1], parse_tree),
+            191 => self.u64_token(&children[0], &children[1], parse_tree),
+            192 => self.union_token(&children[0], &children[1], parse_tree),
+            193 => self.unpacked_token(&children[0], &children[1], parse_tree),
+            194 => self.var_token(&children[0], &children[1], parse_tree),
+            195 => self.dollar_identifier_token(&children[0], &children[1], parse_tree),
+            196 => self.identifier_token(&children[0], &children[1], parse_tree),
+            197 => self.start(&children[0], parse_tree),
+            198 => self.exponent(&children[0], parse_tree),
+            199 => self.fixed_point(&children[0], parse_tree),
+            200 => self.based(&children[0], parse_tree),
+            201 => self.base_less(&children[0], parse_tree),
+            202 => self.all_bit(&children[0], parse_tree),
+            203 => self.string_literal(&children[0], parse_tree),
+            204 => self.assignment_operator(&children[0], parse_tree),
+            205 => self.operator01(&children[0], parse_tree),
+            206 => self.operator02(&children[0], parse_tree),
+            207 => self.operator03(&children[0], parse_tree),
+            208 => self.operator04(&children[0], parse_tree),
+            209 => self.operator05(&children[0], parse_tree),
+            210 => self.operator06(&children[0], parse_tree),
+            211 => self.operator07(&children[0], parse_tree),
+            212 => self.operator08(&children[0], parse_tree),
+            213 => self.operator09(&children[0], parse_tree),
+            214 => self.operator10(&children[0], parse_tree),
+            215 => self.operator11(&children[0], parse_tree),
+            216 => self.unary_operator(&children[0], parse_tree),
+            217 => self.colon(&children[0], parse_tree),
+            218 => self.colon_colon(&children[0], parse_tree),
+            219 => self.comma(&children[0], parse_tree),
+            220 => self.dot_dot(&children[0], parse_tree),
+            221 => self.dot(&children[0], parse_tree),
+            222 => self.equ(&children[0], parse_tree),
+            223 => self.hash(&children[0], parse_tree),
+            224 => self.l_brace(&children[0], parse_tree),
+            225 => self.l_bracket(&children[0], parse_tree),
+            226 => self.l_paren(&children[0], parse_tree),
+            227 => self.minus_colon(&children[0], parse_tree),
+            228 => self.minus_g_t(&children[0], parse_tree),
+            229 => self.plus_colon(&children[0], parse_tree),
+            230 => self.r_brace(&children[0], parse_tree),
+            231 => self.r_bracket(&children[0], parse_tree),
+            232 => self.r_paren(&children[0], parse_tree),
+            233 => self.semicolon(&children[0], parse_tree),
+            234 => self.star(&children[0], parse_tree),
+            235 => self.always_comb(&children[0], parse_tree),
+            236 => self.always_ff(&children[0], parse_tree),
+            237 => self.r#as(&children[0], parse_tree),
+            238 => self.assert(&children[0], parse_tree),
+            239 => self.assign(&children[0], parse_tree),
+            240 => self.assume(&children[0], parse_tree),
+            241 => self.async_high(&children[0], parse_tree),
+            242 => self.async_low(&children[0], parse_tree),
+            243 => self.bit(&children[0], parse_tree),
+            244 => self.case(&children[0], parse_tree),
+            245 => self.cover(&children[0], parse_tree),
+            246 => self.defaul(&children[0], parse_tree),
+            247 => self.r#else(&children[0], parse_tree),
+            248 => self.r#enum(&children[0], parse_tree),
+            249 => self.expect(&children[0], parse_tree),
+            250 => self.export(&children[0], parse_tree),
+            251 => self.f32(&children[0], parse_tree),
+            252 => self.f64(&children[0], parse_tree),
+            253 => self.r#final(&children[0], parse_tree),
+            254 => self.r#for(&children[0], parse_tree),
+            255 => self.function(&children[0], parse_tree),
+            256 => self.i32(&children[0], parse_tree),
+            257 => self.i64(&children[0], parse_tree),
+            258 => self.r#if(&children[0], parse_tree),
+            259 => self.if_reset(&children[0], parse_tree),
+            260 => self.import(&children[0], parse_tree),
+            261 => self.r#in(&children[0], parse_tree),
+            262 => self.initial(&children[0], parse_tree),
+            263 => self.inout(&children[0], parse_tree),
+            264 => self.input(&children[0], parse_tree),
+            265 => self.inst(&children[0], parse_tree),
+            266 => self.interface(&children[0], parse_tree),
+            267 => self.localparam(&children[0], parse_tree),
+            268 => self.logic(&children[0], parse_tree),
+            269 => self.modport(&children[0], parse_tree),
+            270 => self.module(&children[0], parse_tree),
+            271 => self.negedge(&children[0], parse_tree),
+            272 => self.output(&children[0], parse_tree),
+            273 => self.package(&children[0], parse_tree),
+            274 => self.parameter(&children[0], parse_tree),
+            275 => self.posedge(&children[0], parse_tree),
+            276 => self.r#ref(&children[0], parse_tree),
+            277 => self.repeat(&children[0], parse_tree),
+            278 => self.r#return(&children[0], parse_tree),
+            279 => self.signed(&children[0], parse_tree),
+            280 => self.step(&children[0], parse_tree),
+            281 => self.r#struct(&children[0], parse_tree),
+            282 => self.sync_high(&children[0], parse_tree),
+            283 => self.sync_low(&children[0], parse_tree),
+            284 => self.test(&children[0], parse_tree),
+            285 => self.tri(&children[0], parse_tree),
+            286 => self.typ(&children[0], parse_tree),
+            287 => self.u32(&children[0], parse_tree),
+            288 => self.u64(&children[0], parse_tree),
+            289 => self.r#union(&children[0], parse_tree),
+            290 => self.unpacked(&children[0], parse_tree),
+            291 => self.var(&children[0], parse_tree),
+            292 => self.dollar_identifier(&children[0], parse_tree),
+            293 => self.identifier(&children[0], parse_tree),
+            294 => self.number_0(&children[0], parse_tree),
+            295 => self.number_1(&children[0], parse_tree),
+            296 => self.integral_number_0(&children[0], parse_tree),
+            297 => self.integral_number_1(&children[0], parse_tree),
+            298 => self.integral_number_2(&children[0], parse_tree),
+            299 => self.real_number_0(&children[0], parse_tree),
+            300 => self.real_number_1(&children[0], parse_tree),
+            301 => {
                 self.hierarchical_identifier(&children[0], &children[1], &children[2], parse_tree)
             }
-            263 => self.hierarchical_identifier_list0_0(
+            302 => self.hierarchical_identifier_list0_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 parse_tree,
             ),
-            264 => {
+            303 => {
                 self.hierarchical_identifier_list0_list_0(&children[0], &children[1], parse_tree)
             }
-            265 => self.hierarchical_identifier_list0_list_1(parse_tree),
-            266 => self.hierarchical_identifier_list0_1(parse_tree),
-            267 => self.hierarchical_identifier_list_0(&children[0], &children[1], parse_tree),
-            268 => self.hierarchical_identifier_list_1(parse_tree),
-            269 => self.scoped_identifier(&children[0], &children[1], parse_tree),
-            270 => {
+            304 => self.hierarchical_identifier_list0_list_1(parse_tree),
+            305 => self.hierarchical_identifier_list0_1(parse_tree),
+            306 => self.hierarchical_identifier_list_0(&children[0], &children[1], parse_tree),
+            307 => self.hierarchical_identifier_list_1(parse_tree),
+            308 => self.scoped_identifier(&children[0], &children[1], parse_tree),
+            309 => {
                 self.scoped_identifier_list_0(&children[0], &children[1], &children[2], parse_tree)
             }
-            271 => self.scoped_identifier_list_1(parse_tree),
-            272 => self.modport_identifier(&children[0], &children[1], &children[2], parse_tree),
-            273 => self.scoped_or_hier_identifier(&children[0], &children[1], parse_tree),
-            274 => self.scoped_or_hier_identifier_group_0(
+            310 => self.scoped_identifier_list_1(parse_tree),
+            311 => self.modport_identifier(&children[0], &children[1], &children[2], parse_tree),
+            312 => self.scoped_or_hier_identifier(&children[0], &children[1], parse_tree),
+            313 => self.scoped_or_hier_identifier_group_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 parse_tree,
             ),
-            275 => self.scoped_or_hier_identifier_group_list_0(
+            314 => self.scoped_or_hier_identifier_group_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 parse_tree,
             ),
-            276 => self.scoped_or_hier_identifier_group_list_1(parse_tree),
-            277 => self.scoped_or_hier_identifier_group_1(&children[0], &children[1], parse_tree),
-            278 => self.scoped_or_hier_identifier_group_list1_0(
+            315 => self.scoped_or_hier_identifier_group_list_1(parse_tree),
+            316 => self.scoped_or_hier_identifier_group_1(&children[0], &children[1], parse_tree),
+            317 => self.scoped_or_hier_identifier_group_list1_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 parse_tree,
             ),
-            279 => self.scoped_or_hier_identifier_group_list1_list_0(
+            318 => self.scoped_or_hier_identifier_group_list1_list_0(
                 &children[0],
                 &children[1],
                 parse_tree,
             ),
-            280 => self.scoped_or_hier_identifier_group_list1_list_1(parse_tree),
-            281 => self.scoped_or_hier_identifier_group_list1_1(parse_tree),
-            282 => {
+            319 => self.scoped_or_hier_identifier_group_list1_list_1(parse_tree),
+            320 => self.scoped_or_hier_identifier_group_list1_1(parse_tree),
+            321 => {
                 self.scoped_or_hier_identifier_group_list0_0(&children[0], &children[1], parse_tree)
             }
-            283 => self.scoped_or_hier_identifier_group_list0_1(parse_tree),
-            284 => self.expression(&children[0], &children[1], parse_tree),
-            285 => self.expression_list_0(&children[0], &children[1], &children[2], parse_tree),
-            286 => self.expression_list_1(parse_tree),
-            287 => self.expression01(&children[0], &children[1], parse_tree),
-            288 => self.expression01_list_0(&children[0], &children[1], &children[2], parse_tree),
-            289 => self.expression01_list_1(parse_tree),
-            290 => self.expression02(&children[0], &children[1], parse_tree),
-            291 => self.expression02_list_0(&children[0], &children[1], &children[2], parse_tree),
-            292 => self.expression02_list_1(parse_tree),
-            293 => self.expression03(&children[0], &children[1], parse_tree),
-            294 => self.expression03_list_0(&children[0], &children[1], &children[2], parse_tree),
-            295 => self.expression03_list_1(parse_tree),
-            296 => self.expression04(&children[0], &children[1], parse_tree),
-            297 => self.expression04_list_0(&children[0], &children[1], &children[2], parse_tree),
-            298 => self.expression04_list_1(parse_tree),
-            299 => self.expression05(&children[0], &children[1], parse_tree),
-            300 => self.expression05_list_0(&children[0], &children[1], &children[2], parse_tree),
-            301 => self.expression05_list_1(parse_tree),
-            302 => self.expression06(&children[0], &children[1], parse_tree),
-            303 => self.expression06_list_0(&children[0], &children[1], &children[2], parse_tree),
-            304 => self.expression06_list_1(parse_tree),
-            305 => self.expression07(&children[0], &children[1], parse_tree),
-            306 => self.expression07_list_0(&children[0], &children[1], &children[2], parse_tree),
-            307 => self.expression07_list_1(parse_tree),
-            308 => self.expression08(&children[0], &children[1], parse_tree),
-            309 => self.expression08_list_0(&children[0], &children[1], &children[2], parse_tree),
-            310 => self.expression08_list_1(parse_tree),
-            311 => self.expression09(&children[0], &children[1], parse_tree),
-            312 => self.expression09_list_0(&children[0], &children[1], &children[2], parse_tree),
-            313 => self.expression09_list_group_0(&children[0], parse_tree),
-            314 => self.expression09_list_group_1(&children[0], parse_tree),
-            315 => self.expression09_list_1(parse_tree),
-            316 => self.expression10(&children[0], &children[1], parse_tree),
-            317 => self.expression10_list_0(&children[0], &children[1], &children[2], parse_tree),
-            318 => self.expression10_list_1(parse_tree),
-            319 => self.expression11(&children[0], &children[1], parse_tree),
-            320 => self.expression11_list_0(&children[0], &children[1], parse_tree),
-            321 => self.expression11_list_group_0(&children[0], parse_tree),
-            322 => self.expression11_list_group_1(&children[0], parse_tree),
-            323 => self.expression11_list_group_2(&children[0], parse_tree),
-            324 => self.expression11_list_group_3(&children[0], parse_tree),
-            325 => self.expression11_list_group_4(&children[0], parse_tree),
-            326 => self.expression11_list_1(parse_tree),
-            327 => self.factor_0(&children[0], parse_tree),
-            328 => self.factor_1(&children[0], &children[1], &children[2], parse_tree),
-            329 => self.factor_2(&children[0], &children[1], &children[2], parse_tree),
-            330 => self.factor_3(&children[0], &children[1], &children[2], parse_tree),
-            331 => self.factor_opt0_0(&children[0], &children[1], &children[2], parse_tree),
-            332 => self.factor_opt1_0(&children[0], parse_tree),
-            333 => self.factor_opt1_1(parse_tree),
-            334 => self.factor_opt0_1(parse_tree),
-            335 => self.factor_opt_0(&children[0], parse_tree),
-            336 => self.factor_opt_1(parse_tree),
-            337 => self.function_call_arg(&children[0], &children[1], &children[2], parse_tree),
-            338 => {
+            322 => self.scoped_or_hier_identifier_group_list0_1(parse_tree),
+            323 => self.expression_0(&children[0], parse_tree),
+            324 => self.expression_1(&children[0], parse_tree),
+            325 => self.expression01(&children[0], &children[1], parse_tree),
+            326 => self.expression01_list_0(&children[0], &children[1], &children[2], parse_tree),
+            327 => self.expression01_list_1(parse_tree),
+            328 => self.expression02(&children[0], &children[1], parse_tree),
+            329 => self.expression02_list_0(&children[0], &children[1], &children[2], parse_tree),
+            330 => self.expression02_list_1(parse_tree),
+            331 => self.expression03(&children[0], &children[1], parse_tree),
+            332 => self.expression03_list_0(&children[0], &children[1], &children[2], parse_tree),
+            333 => self.expression03_list_1(parse_tree),
+            334 => self.expression04(&children[0], &children[1], parse_tree),
+            335 => self.expression04_list_0(&children[0], &children[1], &children[2], parse_tree),
+            336 => self.expression04_list_1(parse_tree),
+            337 => self.expression05(&children[0], &children[1], parse_tree),
+            338 => self.expression05_list_0(&children[0], &children[1], &children[2], parse_tree),
+            339 => self.expression05_list_1(parse_tree),
+            340 => self.expression06(&children[0], &children[1], parse_tree),
+            341 => self.expression06_list_0(&children[0], &children[1], &children[2], parse_tree),
+            342 => self.expression06_list_1(parse_tree),
+            343 => self.expression07(&children[0], &children[1], parse_tree),
+            344 => self.expression07_list_0(&children[0], &children[1], &children[2], parse_tree),
+            345 => self.expression07_list_1(parse_tree),
+            346 => self.expression08(&children[0], &children[1], parse_tree),
+            347 => self.expression08_list_0(&children[0], &children[1], &children[2], parse_tree),
+            348 => self.expression08_list_1(parse_tree),
+            349 => self.expression09(&children[0], &children[1], parse_tree),
+            350 => self.expression09_list_0(&children[0], &children[1], &children[2], parse_tree),
+            351 => self.expression09_list_1(parse_tree),
+            352 => self.expression10(&children[0], &children[1], parse_tree),
+            353 => self.expression10_list_0(&children[0], &children[1], &children[2], parse_tree),
+            354 => self.expression10_list_group_0(&children[0], parse_tree),
+            355 => self.expression10_list_group_1(&children[0], parse_tree),
+            356 => self.expression10_list_1(parse_tree),
+            357 => self.expression11(&children[0], &children[1], parse_tree),
+            358 => self.expression11_list_0(&children[0], &children[1], &children[2], parse_tree),
+            359 => self.expression11_list_1(parse_tree),
+            360 => self.expression12(&children[0], &children[1], &children[2], parse_tree),
+            361 => self.expression12_list_0(&children[0], &children[1], parse_tree),
+            362 => self.expression12_list_group_0(&children[0], parse_tree),
+            363 => self.expression12_list_group_1(&children[0], parse_tree),
+            364 => self.expression12_list_group_2(&children[0], parse_tree),
+            365 => self.expression12_list_group_3(&children[0], parse_tree),
+            366 => self.expression12_list_group_4(&children[0], parse_tree),
+            367 => self.expression12_list_1(parse_tree),
+            368 => self.expression12_opt_0(&children[0], &children[1], parse_tree),
+            369 => self.expression12_opt_1(parse_tree),
+            370 => self.factor_0(&children[0], parse_tree),
+            371 => self.factor_1(&children[0], parse_tree),
+            372 => self.factor_2(&children[0], &children[1], parse_tree),
+            373 => self.factor_3(&children[0], parse_tree),
+            374 => self.factor_4(&children[0], &children[1], &children[2], parse_tree),
+            375 => self.factor_5(&children[0], &children[1], &children[2], parse_tree),
+            376 => self.factor_opt_0(&children[0], &children[1], &children[2], parse_tree),
+            377 => self.factor_opt0_0(&children[0], parse_tree),
+            378 => self.factor_opt0_1(parse_tree),
+            379 => self.factor_opt_1(parse_tree),
+            380 => self.if_expression(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                &children[5],
+                &children[6],
+                &children[7],
+                &children[8],
+                &children[9],
+                parse_tree,
+            ),
+            381 => self.if_expression_list_0(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                &children[5],
+                &children[6],
+                parse_tree,
+            ),
+            382 => self.if_expression_list_1(parse_tree),
+            383 => self.system_function_call(&children[0], &children[1], parse_tree),
+            384 => self.system_function_call_opt_0(
+                &children[0],
+                &children[1],
+                &children[2],
+                parse_tree,
+            ),
+            385 => self.system_function_call_opt0_0(&children[0], parse_tree),
+            386 => self.system_function_call_opt0_1(parse_tree),
+            387 => self.system_function_call_opt_1(parse_tree),
+            388 => self.function_call_arg(&children[0], &children[1], &children[2], parse_tree),
+            389 => {
                 self.function_call_arg_list_0(&children[0], &children[1], &children[2], parse_tree)
             }
-            339 => self.function_call_arg_list_1(parse_tree),
-            340 => self.function_call_arg_opt_0(&children[0], parse_tree),
-            341 => self.function_call_arg_opt_1(parse_tree),
-            342 => self.concatenation_list(&children[0], &children[1], &children[2], parse_tree),
-            343 => {
+            390 => self.function_call_arg_list_1(parse_tree),
+            391 => self.function_call_arg_opt_0(&children[0], parse_tree),
+            392 => self.function_call_arg_opt_1(parse_tree),
+            393 => self.concatenation_list(&children[0], &children[1], &children[2], parse_tree),
+            394 => {
                 self.concatenation_list_list_0(&children[0], &children[1], &children[2], parse_tree)
             }
-            344 => self.concatenation_list_list_1(parse_tree),
-            345 => self.concatenation_list_opt_0(&children[0], parse_tree),
-            346 => self.concatenation_list_opt_1(parse_tree),
-            347 => self.concatenation_item(&children[0], &children[1], parse_tree),
-            348 => self.concatenation_item_opt_0(&children[0], &children[1], parse_tree),
-            349 => self.concatenation_item_opt_1(parse_tree),
-            350 => self.range(
+            395 => self.concatenation_list_list_1(parse_tree),
+            396 => self.concatenation_list_opt_0(&children[0], parse_tree),
+            397 => self.concatenation_list_opt_1(parse_tree),
+            398 => self.concatenation_item(&children[0], &children[1], parse_tree),
+            399 => self.concatenation_item_opt_0(&children[0], &children[1], parse_tree),
+            400 => self.concatenation_item_opt_1(parse_tree),
+            401 => self.range(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            402 => self.range_opt_0(&children[0], &children[1], parse_tree),
+            403 => self.range_opt_1(parse_tree),
+            404 => self.range_operator_0(&children[0], parse_tree),
+            405 => self.range_operator_1(&children[0], parse_tree),
+            406 => self.range_operator_2(&children[0], parse_tree),
+            407 => self.range_operator_3(&children[0], parse_tree),
+            408 => self.width(&children[0], &children[1], &children[2], parse_tree),
+            409 => self.builtin_type_0(&children[0], parse_tree),
+            410 => self.builtin_type_1(&children[0], parse_tree),
+            411 => self.builtin_type_2(&children[0], parse_tree),
+            412 => self.builtin_type_3(&children[0], parse_tree),
+            413 => self.builtin_type_4(&children[0], parse_tree),
+            414 => self.builtin_type_5(&children[0], parse_tree),
+            415 => self.builtin_type_6(&children[0], parse_tree),
+            416 => self.builtin_type_7(&children[0], parse_tree),
+            417 => self.type_modifier_0(&children[0], parse_tree),
+            418 => self.type_modifier_1(&children[0], parse_tree),
+            419 => self.array(&children[0], &children[1], &children[2], parse_tree),
+            420 => self.array_list_0(&children[0], &children[1], parse_tree),
+            421 => self.array_list_1(parse_tree),
+            422 => self.r#type(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 parse_tree,
             ),
-            351 => self.range_opt_0(&children[0], &children[1], parse_tree),
-            352 => self.range_opt_1(parse_tree),
-            353 => self.range_operator_0(&children[0], parse_tree),
-            354 => self.range_operator_1(&children[0], parse_tree),
-            355 => self.range_operator_2(&children[0], parse_tree),
-            356 => self.range_operator_3(&children[0], parse_tree),
-            357 => self.width(&children[0], &children[1], &children[2], parse_tree),
-            358 => self.builtin_type_0(&children[0], parse_tree),
-            359 => self.builtin_type_1(&children[0], parse_tree),
-            360 => self.builtin_type_2(&children[0], parse_tree),
-            361 => self.builtin_type_3(&children[0], parse_tree),
-            362 => self.builtin_type_4(&children[0], parse_tree),
-            363 => self.builtin_type_5(&children[0], parse_tree),
-            364 => self.builtin_type_6(&children[0], parse_tree),
-            365 => self.builtin_type_7(&children[0], parse_tree),
-            366 => self.type_modifier(&children[0], parse_tree),
-            367 => self.r#type(&children[0], &children[1], &children[2], parse_tree),
-            368 => self.type_group_0(&children[0], parse_tree),
-            369 => self.type_group_1(&children[0], parse_tree),
-            370 => self.type_group_2(&children[0], parse_tree),
-            371 => self.type_list_0(&children[0], &children[1], parse_tree),
-            372 => self.type_list_1(parse_tree),
-            373 => self.type_opt_0(&children[0], parse_tree),
-            374 => self.type_opt_1(parse_tree),
-            375 => self.statement_0(&children[0], parse_tree),
-            376 => self.statement_1(&children[0], parse_tree),
-            377 => self.statement_2(&children[0], parse_tree),
-            378 => self.statement_3(&children[0], parse_tree),
-            379 => self.statement_4(&children[0], parse_tree),
-            380 => self.statement_5(&children[0], parse_tree),
-            381 => self.assignment_statement(
+            423 => self.type_group_0(&children[0], parse_tree),
+            424 => self.type_group_1(&children[0], parse_tree),
+            425 => self.type_group_2(&children[0], parse_tree),
+            426 => self.type_list0_0(&children[0], &children[1], parse_tree),
+            427 => self.type_list0_1(parse_tree),
+            428 => self.type_list_0(&children[0], &children[1], parse_tree),
+            429 => self.type_list_1(parse_tree),
+            430 => self.type_opt_0(&children[0], parse_tree),
+            431 => self.type_opt_1(parse_tree),
+            432 => self.statement_0(&children[0], parse_tree),
+            433 => self.statement_1(&children[0], parse_tree),
+            434 => self.statement_2(&children[0], parse_tree),
+            435 => self.statement_3(&children[0], parse_tree),
+            436 => self.statement_4(&children[0], parse_tree),
+            437 => self.statement_5(&children[0], parse_tree),
+            438 => self.statement_6(&children[0], parse_tree),
+            439 => self.statement_7(&children[0], parse_tree),
+            440 => self.assignment_statement(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 parse_tree,
             ),
-            382 => self.assignment_statement_group_0(&children[0], parse_tree),
-            383 => self.assignment_statement_group_1(&children[0], parse_tree),
-            384 => self.if_statement(
+            441 => self.assignment_statement_group_0(&children[0], parse_tree),
+            442 => self.assignment_statement_group_1(&children[0], parse_tree),
+            443 => self.if_statement(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -24886,7 +29741,7 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 parse_tree,
             ),
-            385 => self.if_statement_list0_0(
+            444 => self.if_statement_list0_0(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -24896,22 +29751,22 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 parse_tree,
             ),
-            386 => self.if_statement_list0_list_0(&children[0], &children[1], parse_tree),
-            387 => self.if_statement_list0_list_1(parse_tree),
-            388 => self.if_statement_list0_1(parse_tree),
-            389 => self.if_statement_list_0(&children[0], &children[1], parse_tree),
-            390 => self.if_statement_list_1(parse_tree),
-            391 => self.if_statement_opt_0(
+            445 => self.if_statement_list0_list_0(&children[0], &children[1], parse_tree),
+            446 => self.if_statement_list0_list_1(parse_tree),
+            447 => self.if_statement_list0_1(parse_tree),
+            448 => self.if_statement_list_0(&children[0], &children[1], parse_tree),
+            449 => self.if_statement_list_1(parse_tree),
+            450 => self.if_statement_opt_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 parse_tree,
             ),
-            392 => self.if_statement_opt_list_0(&children[0], &children[1], parse_tree),
-            393 => self.if_statement_opt_list_1(parse_tree),
-            394 => self.if_statement_opt_1(parse_tree),
-            395 => self.if_reset_statement(
+            451 => self.if_statement_opt_list_0(&children[0], &children[1], parse_tree),
+            452 => self.if_statement_opt_list_1(parse_tree),
+            453 => self.if_statement_opt_1(parse_tree),
+            454 => self.if_reset_statement(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -24920,7 +29775,7 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 parse_tree,
             ),
-            396 => self.if_reset_statement_list0_0(
+            455 => self.if_reset_statement_list0_0(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -24930,23 +29785,23 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 parse_tree,
             ),
-            397 => self.if_reset_statement_list0_list_0(&children[0], &children[1], parse_tree),
-            398 => self.if_reset_statement_list0_list_1(parse_tree),
-            399 => self.if_reset_statement_list0_1(parse_tree),
-            400 => self.if_reset_statement_list_0(&children[0], &children[1], parse_tree),
-            401 => self.if_reset_statement_list_1(parse_tree),
-            402 => self.if_reset_statement_opt_0(
+            456 => self.if_reset_statement_list0_list_0(&children[0], &children[1], parse_tree),
+            457 => self.if_reset_statement_list0_list_1(parse_tree),
+            458 => self.if_reset_statement_list0_1(parse_tree),
+            459 => self.if_reset_statement_list_0(&children[0], &children[1], parse_tree),
+            460 => self.if_reset_statement_list_1(parse_tree),
+            461 => self.if_reset_statement_opt_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 parse_tree,
             ),
-            403 => self.if_reset_statement_opt_list_0(&children[0], &children[1], parse_tree),
-            404 => self.if_reset_statement_opt_list_1(parse_tree),
-            405 => self.if_reset_statement_opt_1(parse_tree),
-            406 => self.return_statement(&children[0], &children[1], &children[2], parse_tree),
-            407 => self.for_statement(
+            462 => self.if_reset_statement_opt_list_0(&children[0], &children[1], parse_tree),
+            463 => self.if_reset_statement_opt_list_1(parse_tree),
+            464 => self.if_reset_statement_opt_1(parse_tree),
+            465 => self.return_statement(&children[0], &children[1], &children[2], parse_tree),
+            466 => self.for_statement(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -24961,11 +29816,11 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[11],
                 parse_tree,
             ),
-            408 => self.for_statement_list_0(&children[0], &children[1], parse_tree),
-            409 => self.for_statement_list_1(parse_tree),
-            410 => self.for_statement_opt_0(&children[0], &children[1], &children[2], parse_tree),
-            411 => self.for_statement_opt_1(parse_tree),
-            412 => self.case_statement(
+            467 => self.for_statement_list_0(&children[0], &children[1], parse_tree),
+            468 => self.for_statement_list_1(parse_tree),
+            469 => self.for_statement_opt_0(&children[0], &children[1], &children[2], parse_tree),
+            470 => self.for_statement_opt_1(parse_tree),
+            471 => self.case_statement(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -24973,16 +29828,38 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 parse_tree,
             ),
-            413 => self.case_statement_list_0(&children[0], &children[1], parse_tree),
-            414 => self.case_statement_list_1(parse_tree),
-            415 => self.case_item(&children[0], &children[1], &children[2], parse_tree),
-            416 => self.case_item_group0_0(&children[0], parse_tree),
-            417 => self.case_item_group0_1(&children[0], &children[1], &children[2], parse_tree),
-            418 => self.case_item_group0_list_0(&children[0], &children[1], parse_tree),
-            419 => self.case_item_group0_list_1(parse_tree),
-            420 => self.case_item_group_0(&children[0], parse_tree),
-            421 => self.case_item_group_1(&children[0], parse_tree),
-            422 => self.var_declaration(
+            472 => self.case_statement_list_0(&children[0], &children[1], parse_tree),
+            473 => self.case_statement_list_1(parse_tree),
+            474 => self.assertion_statement(&children[0], &children[1], &children[2], parse_tree),
+            475 => self.assertion_kind_0(&children[0], parse_tree),
+            476 => self.assertion_kind_1(&children[0], parse_tree),
+            477 => self.assertion_kind_2(&children[0], parse_tree),
+            478 => self.system_task_statement(&children[0], &children[1], parse_tree),
+            479 => self.case_item(&children[0], &children[1], &children[2], parse_tree),
+            480 => self.case_item_group0_0(&children[0], parse_tree),
+            481 => self.case_item_group0_1(&children[0], &children[1], &children[2], parse_tree),
+            482 => self.case_item_group0_list_0(&children[0], &children[1], parse_tree),
+            483 => self.case_item_group0_list_1(parse_tree),
+            484 => self.case_item_group_0(&children[0], parse_tree),
+            485 => self.case_item_group_1(&children[0], parse_tree),
+            486 => self.attribute(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                parse_tree,
+            ),
+            487 => self.attribute_opt_0(&children[0], &children[1], &children[2], parse_tree),
+            488 => self.attribute_opt_1(parse_tree),
+            489 => self.attribute_list(&children[0], &children[1], &children[2], parse_tree),
+            490 => self.attribute_list_list_0(&children[0], &children[1], &children[2], parse_tree),
+            491 => self.attribute_list_list_1(parse_tree),
+            492 => self.attribute_list_opt_0(&children[0], parse_tree),
+            493 => self.attribute_list_opt_1(parse_tree),
+            494 => self.attribute_item_0(&children[0], parse_tree),
+            495 => self.attribute_item_1(&children[0], parse_tree),
+            496 => self.var_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -24991,9 +29868,9 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 parse_tree,
             ),
-            423 => self.var_declaration_opt_0(&children[0], &children[1], parse_tree),
-            424 => self.var_declaration_opt_1(parse_tree),
-            425 => self.localparam_declaration(
+            497 => self.var_declaration_opt_0(&children[0], &children[1], parse_tree),
+            498 => self.var_declaration_opt_1(parse_tree),
+            499 => self.localparam_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -25003,7 +29880,15 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 parse_tree,
             ),
-            426 => self.always_ff_declaration(
+            500 => self.type_declaration(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                parse_tree,
+            ),
+            501 => self.always_ff_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -25014,32 +29899,62 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[7],
                 parse_tree,
             ),
-            427 => self.always_ff_declaration_list_0(&children[0], &children[1], parse_tree),
-            428 => self.always_ff_declaration_list_1(parse_tree),
-            429 => self.always_ff_declaration_opt_0(&children[0], &children[1], parse_tree),
-            430 => self.always_ff_declaration_opt_1(parse_tree),
-            431 => self.always_ff_clock(&children[0], &children[1], parse_tree),
-            432 => self.always_ff_clock_opt_0(&children[0], parse_tree),
-            433 => self.always_ff_clock_opt_group_0(&children[0], parse_tree),
-            434 => self.always_ff_clock_opt_group_1(&children[0], parse_tree),
-            435 => self.always_ff_clock_opt_1(parse_tree),
-            436 => self.always_ff_reset(&children[0], &children[1], parse_tree),
-            437 => self.always_ff_reset_opt_0(&children[0], parse_tree),
-            438 => self.always_ff_reset_opt_group_0(&children[0], parse_tree),
-            439 => self.always_ff_reset_opt_group_1(&children[0], parse_tree),
-            440 => self.always_ff_reset_opt_group_2(&children[0], parse_tree),
-            441 => self.always_ff_reset_opt_group_3(&children[0], parse_tree),
-            442 => self.always_ff_reset_opt_1(parse_tree),
-            443 => self.always_comb_declaration(
+            502 => self.always_ff_declaration_list_0(&children[0], &children[1], parse_tree),
+            503 => self.always_ff_declaration_list_1(parse_tree),
+            504 => self.always_ff_declaration_opt_0(&children[0], &children[1], parse_tree),
+            505 => self.always_ff_declaration_opt_1(parse_tree),
+            506 => self.always_ff_clock(&children[0], &children[1], parse_tree),
+            507 => self.always_ff_clock_opt_0(&children[0], parse_tree),
+            508 => self.always_ff_clock_opt_group_0(&children[0], parse_tree),
+            509 => self.always_ff_clock_opt_group_1(&children[0], parse_tree),
+            510 => self.always_ff_clock_opt_1(parse_tree),
+            511 => self.always_ff_reset(&children[0], &children[1], parse_tree),
+            512 => self.always_ff_reset_opt_0(&children[0], parse_tree),
+            513 => self.always_ff_reset_opt_group_0(&children[0], parse_tree),
+            514 => self.always_ff_reset_opt_group_1(&children[0], parse_tree),
+            515 => self.always_ff_reset_opt_group_2(&children[0], parse_tree),
+            516 => self.always_ff_reset_opt_group_3(&children[0], parse_tree),
+            517 => self.always_ff_reset_opt_1(parse_tree),
+            518 => self.always_comb_declaration(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            519 => self.always_comb_declaration_list_0(&children[0], &children[1], parse_tree),
+            520 => self.always_comb_declaration_list_1(parse_tree),
+            521 => self.assertion_declaration(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                &children[5],
+                &children[6],
+                parse_tree,
+            ),
+            522 => self.assertion_declaration_opt_0(&children[0], &children[1], parse_tree),
+            523 => self.assertion_declaration_opt_1(parse_tree),
+            524 => self.initial_declaration(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                parse_tree,
+            ),
+            525 => self.initial_declaration_list_0(&children[0], &children[1], parse_tree),
+            526 => self.initial_declaration_list_1(parse_tree),
+            527 => self.final_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 parse_tree,
             ),
-            444 => self.always_comb_declaration_list_0(&children[0], &children[1], parse_tree),
-            445 => self.always_comb_declaration_list_1(parse_tree),
-            446 => self.assign_declaration(
+            528 => self.final_declaration_list_0(&children[0], &children[1], parse_tree),
+            529 => self.final_declaration_list_1(parse_tree),
+            530 => self.assign_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -25047,7 +29962,7 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 parse_tree,
             ),
-            447 => self.modport_declaration(
+            531 => self.modport_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -25055,13 +29970,13 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 parse_tree,
             ),
-            448 => self.modport_list(&children[0], &children[1], &children[2], parse_tree),
-            449 => self.modport_list_list_0(&children[0], &children[1], &children[2], parse_tree),
-            450 => self.modport_list_list_1(parse_tree),
-            451 => self.modport_list_opt_0(&children[0], parse_tree),
-            452 => self.modport_list_opt_1(parse_tree),
-            453 => self.modport_item(&children[0], &children[1], &children[2], parse_tree),
-            454 => self.enum_declaration(
+            532 => self.modport_list(&children[0], &children[1], &children[2], parse_tree),
+            533 => self.modport_list_list_0(&children[0], &children[1], &children[2], parse_tree),
+            534 => self.modport_list_list_1(parse_tree),
+            535 => self.modport_list_opt_0(&children[0], parse_tree),
+            536 => self.modport_list_opt_1(parse_tree),
+            537 => self.modport_item(&children[0], &children[1], &children[2], parse_tree),
+            538 => self.enum_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -25071,29 +29986,34 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 parse_tree,
             ),
-            455 => self.enum_list(&children[0], &children[1], &children[2], parse_tree),
-            456 => self.enum_list_list_0(&children[0], &children[1], &children[2], parse_tree),
-            457 => self.enum_list_list_1(parse_tree),
-            458 => self.enum_list_opt_0(&children[0], parse_tree),
-            459 => self.enum_list_opt_1(parse_tree),
-            460 => self.enum_item(&children[0], &children[1], parse_tree),
-            461 => self.enum_item_opt_0(&children[0], &children[1], parse_tree),
-            462 => self.enum_item_opt_1(parse_tree),
-            463 => self.struct_declaration(
+            539 => self.enum_list(&children[0], &children[1], &children[2], parse_tree),
+            540 => self.enum_list_list_0(&children[0], &children[1], &children[2], parse_tree),
+            541 => self.enum_list_list_1(parse_tree),
+            542 => self.enum_list_opt_0(&children[0], parse_tree),
+            543 => self.enum_list_opt_1(parse_tree),
+            544 => self.enum_item(&children[0], &children[1], parse_tree),
+            545 => self.enum_item_opt_0(&children[0], &children[1], parse_tree),
+            546 => self.enum_item_opt_1(parse_tree),
+            547 => self.struct_union_0(&children[0], parse_tree),
+            548 => self.struct_union_1(&children[0], parse_tree),
+            549 => self.struct_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
+                &children[5],
                 parse_tree,
             ),
-            464 => self.struct_list(&children[0], &children[1], &children[2], parse_tree),
-            465 => self.struct_list_list_0(&children[0], &children[1], &children[2], parse_tree),
-            466 => self.struct_list_list_1(parse_tree),
-            467 => self.struct_list_opt_0(&children[0], parse_tree),
-            468 => self.struct_list_opt_1(parse_tree),
-            469 => self.struct_item(&children[0], &children[1], &children[2], parse_tree),
-            470 => self.inst_declaration(
+            550 => self.struct_declaration_opt_0(&children[0], parse_tree),
+            551 => self.struct_declaration_opt_1(parse_tree),
+            552 => self.struct_list(&children[0], &children[1], &children[2], parse_tree),
+            553 => self.struct_list_list_0(&children[0], &children[1], &children[2], parse_tree),
+            554 => self.struct_list_list_1(parse_tree),
+            555 => self.struct_list_opt_0(&children[0], parse_tree),
+            556 => self.struct_list_opt_1(parse_tree),
+            557 => self.struct_item(&children[0], &children[1], &children[2], parse_tree),
+            558 => self.inst_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -25104,66 +30024,66 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[7],
                 parse_tree,
             ),
-            471 => {
+            559 => {
                 self.inst_declaration_opt1_0(&children[0], &children[1], &children[2], parse_tree)
             }
-            472 => self.inst_declaration_opt2_0(&children[0], parse_tree),
-            473 => self.inst_declaration_opt2_1(parse_tree),
-            474 => self.inst_declaration_opt1_1(parse_tree),
-            475 => self.inst_declaration_opt0_0(&children[0], parse_tree),
-            476 => self.inst_declaration_opt0_1(parse_tree),
-            477 => self.inst_declaration_opt_0(&children[0], parse_tree),
-            478 => self.inst_declaration_opt_1(parse_tree),
-            479 => self.inst_parameter(
+            560 => self.inst_declaration_opt2_0(&children[0], parse_tree),
+            561 => self.inst_declaration_opt2_1(parse_tree),
+            562 => self.inst_declaration_opt1_1(parse_tree),
+            563 => self.inst_declaration_opt0_0(&children[0], parse_tree),
+            564 => self.inst_declaration_opt0_1(parse_tree),
+            565 => self.inst_declaration_opt_0(&children[0], parse_tree),
+            566 => self.inst_declaration_opt_1(parse_tree),
+            567 => self.inst_parameter(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 parse_tree,
             ),
-            480 => self.inst_parameter_opt_0(&children[0], parse_tree),
-            481 => self.inst_parameter_opt_1(parse_tree),
-            482 => self.inst_parameter_list(&children[0], &children[1], &children[2], parse_tree),
-            483 => self.inst_parameter_list_list_0(
+            568 => self.inst_parameter_opt_0(&children[0], parse_tree),
+            569 => self.inst_parameter_opt_1(parse_tree),
+            570 => self.inst_parameter_list(&children[0], &children[1], &children[2], parse_tree),
+            571 => self.inst_parameter_list_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 parse_tree,
             ),
-            484 => self.inst_parameter_list_list_1(parse_tree),
-            485 => self.inst_parameter_list_opt_0(&children[0], parse_tree),
-            486 => self.inst_parameter_list_opt_1(parse_tree),
-            487 => self.inst_parameter_item(&children[0], &children[1], parse_tree),
-            488 => self.inst_parameter_item_opt_0(&children[0], &children[1], parse_tree),
-            489 => self.inst_parameter_item_opt_1(parse_tree),
-            490 => self.inst_port_list(&children[0], &children[1], &children[2], parse_tree),
-            491 => self.inst_port_list_list_0(&children[0], &children[1], &children[2], parse_tree),
-            492 => self.inst_port_list_list_1(parse_tree),
-            493 => self.inst_port_list_opt_0(&children[0], parse_tree),
-            494 => self.inst_port_list_opt_1(parse_tree),
-            495 => self.inst_port_item(&children[0], &children[1], parse_tree),
-            496 => self.inst_port_item_opt_0(&children[0], &children[1], parse_tree),
-            497 => self.inst_port_item_opt_1(parse_tree),
-            498 => self.with_parameter(
+            572 => self.inst_parameter_list_list_1(parse_tree),
+            573 => self.inst_parameter_list_opt_0(&children[0], parse_tree),
+            574 => self.inst_parameter_list_opt_1(parse_tree),
+            575 => self.inst_parameter_item(&children[0], &children[1], parse_tree),
+            576 => self.inst_parameter_item_opt_0(&children[0], &children[1], parse_tree),
+            577 => self.inst_parameter_item_opt_1(parse_tree),
+            578 => self.inst_port_list(&children[0], &children[1], &children[2], parse_tree),
+            579 => self.inst_port_list_list_0(&children[0], &children[1], &children[2], parse_tree),
+            580 => self.inst_port_list_list_1(parse_tree),
+            581 => self.inst_port_list_opt_0(&children[0], parse_tree),
+            582 => self.inst_port_list_opt_1(parse_tree),
+            583 => self.inst_port_item(&children[0], &children[1], parse_tree),
+            584 => self.inst_port_item_opt_0(&children[0], &children[1], parse_tree),
+            585 => self.inst_port_item_opt_1(parse_tree),
+            586 => self.with_parameter(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 parse_tree,
             ),
-            499 => self.with_parameter_opt_0(&children[0], parse_tree),
-            500 => self.with_parameter_opt_1(parse_tree),
-            501 => self.with_parameter_list(&children[0], &children[1], &children[2], parse_tree),
-            502 => self.with_parameter_list_list_0(
+            587 => self.with_parameter_opt_0(&children[0], parse_tree),
+            588 => self.with_parameter_opt_1(parse_tree),
+            589 => self.with_parameter_list(&children[0], &children[1], &children[2], parse_tree),
+            590 => self.with_parameter_list_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 parse_tree,
             ),
-            503 => self.with_parameter_list_list_1(parse_tree),
-            504 => self.with_parameter_list_opt_0(&children[0], parse_tree),
-            505 => self.with_parameter_list_opt_1(parse_tree),
-            506 => self.with_parameter_item(
+            591 => self.with_parameter_list_list_1(parse_tree),
+            592 => self.with_parameter_list_opt_0(&children[0], parse_tree),
+            593 => self.with_parameter_list_opt_1(parse_tree),
+            594 => self.with_parameter_item(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -25172,30 +30092,45 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 parse_tree,
             ),
-            507 => self.with_parameter_item_group_0(&children[0], parse_tree),
-            508 => self.with_parameter_item_group_1(&children[0], parse_tree),
-            509 => self.port_declaration(&children[0], &children[1], &children[2], parse_tree),
-            510 => self.port_declaration_opt_0(&children[0], parse_tree),
-            511 => self.port_declaration_opt_1(parse_tree),
-            512 => self.port_declaration_list(&children[0], &children[1], &children[2], parse_tree),
-            513 => self.port_declaration_list_list_0(
+            595 => self.with_parameter_item_group0_0(&children[0], parse_tree),
+            596 => self.with_parameter_item_group0_1(&children[0], parse_tree),
+            597 => self.with_parameter_item_group_0(&children[0], parse_tree),
+            598 => self.with_parameter_item_group_1(&children[0], parse_tree),
+            599 => self.parameter_value_0(&children[0], parse_tree),
+            600 => self.parameter_value_1(&children[0], parse_tree),
+            601 => self.type_value(&children[0], &children[1], parse_tree),
+            602 => self.type_value_list_0(&children[0], &children[1], parse_tree),
+            603 => self.type_value_list_1(parse_tree),
+            604 => self.port_declaration(&children[0], &children[1], &children[2], parse_tree),
+            605 => self.port_declaration_opt_0(&children[0], parse_tree),
+            606 => self.port_declaration_opt_1(parse_tree),
+            607 => self.port_declaration_list(&children[0], &children[1], &children[2], parse_tree),
+            608 => self.port_declaration_list_list_0(
+                &children[0],
+                &children[1],
+                &children[2],
+                parse_tree,
+            ),
+            609 => self.port_declaration_list_list_1(parse_tree),
+            610 => self.port_declaration_list_opt_0(&children[0], parse_tree),
+            611 => self.port_declaration_list_opt_1(parse_tree),
+            612 => self.port_declaration_item(
                 &children[0],
                 &children[1],
                 &children[2],
+                &children[3],
                 parse_tree,
             ),
-            514 => self.port_declaration_list_list_1(parse_tree),
-            515 => self.port_declaration_list_opt_0(&children[0], parse_tree),
-            516 => self.port_declaration_list_opt_1(parse_tree),
-            517 => self.port_declaration_item(&children[0], &children[1], &children[2], parse_tree),
-            518 => self.port_declaration_item_group_0(&children[0], &children[1], parse_tree),
-            519 => self.port_declaration_item_group_1(&children[0], parse_tree),
-            520 => self.direction_0(&children[0], parse_tree),
-            521 => self.direction_1(&children[0], parse_tree),
-            522 => self.direction_2(&children[0], parse_tree),
-            523 => self.direction_3(&children[0], parse_tree),
-            524 => self.direction_4(&children[0], parse_tree),
-            525 => self.function_declaration(
+            613 => self.port_declaration_item_group_0(&children[0], &children[1], parse_tree),
+            614 => self.port_declaration_item_group_1(&children[0], parse_tree),
+            615 => self.port_declaration_item_list_0(&children[0], &children[1], parse_tree),
+            616 => self.port_declaration_item_list_1(parse_tree),
+            617 => self.direction_0(&children[0], parse_tree),
+            618 => self.direction_1(&children[0], parse_tree),
+            619 => self.direction_2(&children[0], parse_tree),
+            620 => self.direction_3(&children[0], parse_tree),
+            621 => self.direction_4(&children[0], parse_tree),
+            622 => self.function_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -25207,15 +30142,15 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[8],
                 parse_tree,
             ),
-            526 => self.function_declaration_list_0(&children[0], &children[1], parse_tree),
-            527 => self.function_declaration_list_1(parse_tree),
-            528 => self.function_declaration_opt0_0(&children[0], parse_tree),
-            529 => self.function_declaration_opt0_1(parse_tree),
-            530 => self.function_declaration_opt_0(&children[0], parse_tree),
-            531 => self.function_declaration_opt_1(parse_tree),
-            532 => self.function_item_0(&children[0], parse_tree),
-            533 => self.function_item_1(&children[0], parse_tree),
-            534 => self.import_declaration(
+            623 => self.function_declaration_list_0(&children[0], &children[1], parse_tree),
+            624 => self.function_declaration_list_1(parse_tree),
+            625 => self.function_declaration_opt0_0(&children[0], parse_tree),
+            626 => self.function_declaration_opt0_1(parse_tree),
+            627 => self.function_declaration_opt_0(&children[0], parse_tree),
+            628 => self.function_declaration_opt_1(parse_tree),
+            629 => self.function_item_0(&children[0], parse_tree),
+            630 => self.function_item_1(&children[0], parse_tree),
+            631 => self.import_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -25223,9 +30158,9 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 parse_tree,
             ),
-            535 => self.import_declaration_group_0(&children[0], parse_tree),
-            536 => self.import_declaration_group_1(&children[0], parse_tree),
-            537 => self.export_declaration(
+            632 => self.import_declaration_group_0(&children[0], parse_tree),
+            633 => self.import_declaration_group_1(&children[0], parse_tree),
+            634 => self.export_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -25233,11 +30168,11 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 parse_tree,
             ),
-            538 => self.export_declaration_group0_0(&children[0], parse_tree),
-            539 => self.export_declaration_group0_1(&children[0], parse_tree),
-            540 => self.export_declaration_group_0(&children[0], parse_tree),
-            541 => self.export_declaration_group_1(&children[0], parse_tree),
-            542 => self.module_declaration(
+            635 => self.export_declaration_group0_0(&children[0], parse_tree),
+            636 => self.export_declaration_group0_1(&children[0], parse_tree),
+            637 => self.export_declaration_group_0(&children[0], parse_tree),
+            638 => self.export_declaration_group_1(&children[0], parse_tree),
+            639 => self.module_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -25247,13 +30182,17 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 parse_tree,
             ),
-            543 => self.module_declaration_list_0(&children[0], &children[1], parse_tree),
-            544 => self.module_declaration_list_1(parse_tree),
-            545 => self.module_declaration_opt0_0(&children[0], parse_tree),
-            546 => self.module_declaration_opt0_1(parse_tree),
-            547 => self.module_declaration_opt_0(&children[0], parse_tree),
-            548 => self.module_declaration_opt_1(parse_tree),
-            549 => self.module_if_declaration(
+            640 => {
+                self.module_declaration_list_0(&children[0], &children[1], &children[2], parse_tree)
+            }
+            641 => self.module_declaration_list_list_0(&children[0], &children[1], parse_tree),
+            642 => self.module_declaration_list_list_1(parse_tree),
+            643 => self.module_declaration_list_1(parse_tree),
+            644 => self.module_declaration_opt0_0(&children[0], parse_tree),
+            645 => self.module_declaration_opt0_1(parse_tree),
+            646 => self.module_declaration_opt_0(&children[0], parse_tree),
+            647 => self.module_declaration_opt_1(parse_tree),
+            648 => self.module_if_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -25261,7 +30200,7 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 parse_tree,
             ),
-            550 => self.module_if_declaration_list_0(
+            649 => self.module_if_declaration_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -25269,10 +30208,10 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 parse_tree,
             ),
-            551 => self.module_if_declaration_list_1(parse_tree),
-            552 => self.module_if_declaration_opt_0(&children[0], &children[1], parse_tree),
-            553 => self.module_if_declaration_opt_1(parse_tree),
-            554 => self.module_for_declaration(
+            650 => self.module_if_declaration_list_1(parse_tree),
+            651 => self.module_if_declaration_opt_0(&children[0], &children[1], parse_tree),
+            652 => self.module_if_declaration_opt_1(parse_tree),
+            653 => self.module_for_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -25283,14 +30222,14 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[7],
                 parse_tree,
             ),
-            555 => self.module_for_declaration_opt_0(
+            654 => self.module_for_declaration_opt_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 parse_tree,
             ),
-            556 => self.module_for_declaration_opt_1(parse_tree),
-            557 => self.module_named_block(
+            655 => self.module_for_declaration_opt_1(parse_tree),
+            656 => self.module_named_block(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -25298,33 +30237,50 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 parse_tree,
             ),
-            558 => self.module_named_block_list_0(&children[0], &children[1], parse_tree),
-            559 => self.module_named_block_list_1(parse_tree),
-            560 => self.module_optional_named_block(
+            657 => {
+                self.module_named_block_list_0(&children[0], &children[1], &children[2], parse_tree)
+            }
+            658 => self.module_named_block_list_list_0(&children[0], &children[1], parse_tree),
+            659 => self.module_named_block_list_list_1(parse_tree),
+            660 => self.module_named_block_list_1(parse_tree),
+            661 => self.module_optional_named_block(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 parse_tree,
             ),
-            561 => self.module_optional_named_block_list_0(&children[0], &children[1], parse_tree),
-            562 => self.module_optional_named_block_list_1(parse_tree),
-            563 => self.module_optional_named_block_opt_0(&children[0], &children[1], parse_tree),
-            564 => self.module_optional_named_block_opt_1(parse_tree),
-            565 => self.module_item_0(&children[0], parse_tree),
-            566 => self.module_item_1(&children[0], parse_tree),
-            567 => self.module_item_2(&children[0], parse_tree),
-            568 => self.module_item_3(&children[0], parse_tree),
-            569 => self.module_item_4(&children[0], parse_tree),
-            570 => self.module_item_5(&children[0], parse_tree),
-            571 => self.module_item_6(&children[0], parse_tree),
-            572 => self.module_item_7(&children[0], parse_tree),
-            573 => self.module_item_8(&children[0], parse_tree),
-            574 => self.module_item_9(&children[0], parse_tree),
-            575 => self.module_item_10(&children[0], parse_tree),
-            576 => self.module_item_11(&children[0], parse_tree),
-            577 => self.module_item_12(&children[0], parse_tree),
-            578 => self.interface_declaration(
+            662 => self.module_optional_named_block_list_0(
+                &children[0],
+                &children[1],
+                &children[2],
+                parse_tree,
+            ),
+            663 => {
+                self.module_optional_named_block_list_list_0(&children[0], &children[1], parse_tree)
+            }
+            664 => self.module_optional_named_block_list_list_1(parse_tree),
+            665 => self.module_optional_named_block_list_1(parse_tree),
+            666 => self.module_optional_named_block_opt_0(&children[0], &children[1], parse_tree),
+            667 => self.module_optional_named_block_opt_1(parse_tree),
+            668 => self.module_item_0(&children[0], parse_tree),
+            669 => self.module_item_1(&children[0], parse_tree),
+            670 => self.module_item_2(&children[0], parse_tree),
+            671 => self.module_item_3(&children[0], parse_tree),
+            672 => self.module_item_4(&children[0], parse_tree),
+            673 => self.module_item_5(&children[0], parse_tree),
+            674 => self.module_item_6(&children[0], parse_tree),
+            675 => self.module_item_7(&children[0], parse_tree),
+            676 => self.module_item_8(&children[0], parse_tree),
+            677 => self.module_item_9(&children[0], parse_tree),
+            678 => self.module_item_10(&children[0], parse_tree),
+            679 => self.module_item_11(&children[0], parse_tree),
+            680 => self.module_item_12(&children[0], parse_tree),
+            681 => self.module_item_13(&children[0], parse_tree),
+            682 => self.module_item_14(&children[0], parse_tree),
+            683 => self.module_item_15(&children[0], parse_tree),
+            684 => self.module_item_16(&children[0], parse_tree),
+            685 => self.interface_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -25333,11 +30289,11 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 parse_tree,
             ),
-            579 => self.interface_declaration_list_0(&children[0], &children[1], parse_tree),
-            580 => self.interface_declaration_list_1(parse_tree),
-            581 => self.interface_declaration_opt_0(&children[0], parse_tree),
-            582 => self.interface_declaration_opt_1(parse_tree),
-            583 => self.interface_if_declaration(
+            686 => self.interface_declaration_list_0(&children[0], &children[1], parse_tree),
+            687 => self.interface_declaration_list_1(parse_tree),
+            688 => self.interface_declaration_opt_0(&children[0], parse_tree),
+            689 => self.interface_declaration_opt_1(parse_tree),
+            690 => self.interface_if_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -25345,7 +30301,7 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 parse_tree,
             ),
-            584 => self.interface_if_declaration_list_0(
+            691 => self.interface_if_declaration_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -25353,10 +30309,10 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 parse_tree,
             ),
-            585 => self.interface_if_declaration_list_1(parse_tree),
-            586 => self.interface_if_declaration_opt_0(&children[0], &children[1], parse_tree),
-            587 => self.interface_if_declaration_opt_1(parse_tree),
-            588 => self.interface_for_declaration(
+            692 => self.interface_if_declaration_list_1(parse_tree),
+            693 => self.interface_if_declaration_opt_0(&children[0], &children[1], parse_tree),
+            694 => self.interface_if_declaration_opt_1(parse_tree),
+            695 => self.interface_for_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -25367,14 +30323,14 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[7],
                 parse_tree,
             ),
-            589 => self.interface_for_declaration_opt_0(
+            696 => self.interface_for_declaration_opt_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 parse_tree,
             ),
-            590 => self.interface_for_declaration_opt_1(parse_tree),
-            591 => self.interface_named_block(
+            697 => self.interface_for_declaration_opt_1(parse_tree),
+            698 => self.interface_named_block(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -25382,57 +30338,86 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 parse_tree,
             ),
-            592 => self.interface_named_block_list_0(&children[0], &children[1], parse_tree),
-            593 => self.interface_named_block_list_1(parse_tree),
-            594 => self.interface_optional_named_block(
+            699 => self.interface_named_block_list_0(&children[0], &children[1], parse_tree),
+            700 => self.interface_named_block_list_1(parse_tree),
+            701 => self.interface_optional_named_block(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 parse_tree,
             ),
-            595 => {
+            702 => {
                 self.interface_optional_named_block_list_0(&children[0], &children[1], parse_tree)
             }
-            596 => self.interface_optional_named_block_list_1(parse_tree),
-            597 => {
+            703 => self.interface_optional_named_block_list_1(parse_tree),
+            704 => {
                 self.interface_optional_named_block_opt_0(&children[0], &children[1], parse_tree)
             }
-            598 => self.interface_optional_named_block_opt_1(parse_tree),
-            599 => self.interface_item_0(&children[0], parse_tree),
-            600 => self.interface_item_1(&children[0], parse_tree),
-            601 => self.interface_item_2(&children[0], parse_tree),
-            602 => self.interface_item_3(&children[0], parse_tree),
-            603 => self.interface_item_4(&children[0], parse_tree),
-            604 => self.interface_item_5(&children[0], parse_tree),
-            605 => self.interface_item_6(&children[0], parse_tree),
-            606 => self.interface_item_7(&children[0], parse_tree),
-            607 => self.interface_item_8(&children[0], parse_tree),
-            608 => self.interface_item_9(&children[0], parse_tree),
-            609 => self.package_declaration(
+            705 => self.interface_optional_named_block_opt_1(parse_tree),
+            706 => self.interface_item_0(&children[0], parse_tree),
+            707 => self.interface_item_1(&children[0], parse_tree),
+            708 => self.interface_item_2(&children[0], parse_tree),
+            709 => self.interface_item_3(&children[0], parse_tree),
+            710 => self.interface_item_4(&children[0], parse_tree),
+            711 => self.interface_item_5(&children[0], parse_tree),
+            712 => self.interface_item_6(&children[0], parse_tree),
+            713 => self.interface_item_7(&children[0], parse_tree),
+            714 => self.interface_item_8(&children[0], parse_tree),
+            715 => self.interface_item_9(&children[0], parse_tree),
+            716 => self.interface_item_10(&children[0], parse_tree),
+            717 => self.package_declaration(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                parse_tree,
+            ),
+            718 => self.package_declaration_list_0(&children[0], &children[1], parse_tree),
+            719 => self.package_declaration_list_1(parse_tree),
+            720 => self.package_item_0(&children[0], parse_tree),
+            721 => self.package_item_1(&children[0], parse_tree),
+            722 => self.package_item_2(&children[0], parse_tree),
+            723 => self.package_item_3(&children[0], parse_tree),
+            724 => self.package_item_4(&children[0], parse_tree),
+            725 => self.package_item_5(&children[0], parse_tree),
+            726 => self.package_item_6(&children[0], parse_tree),
+            727 => self.package_item_7(&children[0], parse_tree),
+            728 => self.test_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
+                &children[5],
+                parse_tree,
+            ),
+            729 => self.test_declaration_list_0(&children[0], &children[1], parse_tree),
+            730 => self.test_declaration_list_1(parse_tree),
+            731 => self.test_item_0(&children[0], parse_tree),
+            732 => self.test_item_1(&children[0], parse_tree),
+            733 => self.test_item_2(&children[0], parse_tree),
+            734 => self.step_statement(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
                 parse_tree,
             ),
-            610 => self.package_declaration_list_0(&children[0], &children[1], parse_tree),
-            611 => self.package_declaration_list_1(parse_tree),
-            612 => self.package_item_0(&children[0], parse_tree),
-            613 => self.package_item_1(&children[0], parse_tree),
-            614 => self.package_item_2(&children[0], parse_tree),
-            615 => self.package_item_3(&children[0], parse_tree),
-            616 => self.package_item_4(&children[0], parse_tree),
-            617 => self.package_item_5(&children[0], parse_tree),
-            618 => self.package_item_6(&children[0], parse_tree),
-            619 => self.description_0(&children[0], parse_tree),
-            620 => self.description_1(&children[0], parse_tree),
-            621 => self.description_2(&children[0], parse_tree),
-            622 => self.description_3(&children[0], parse_tree),
-            623 => self.veryl(&children[0], &children[1], parse_tree),
-            624 => self.veryl_list_0(&children[0], &children[1], parse_tree),
-            625 => self.veryl_list_1(parse_tree),
+            735 => self.step_statement_opt_0(&children[0], &children[1], parse_tree),
+            736 => self.step_statement_opt_1(parse_tree),
+            737 => self.expect_statement(&children[0], &children[1], &children[2], parse_tree),
+            738 => self.description_0(&children[0], parse_tree),
+            739 => self.description_1(&children[0], parse_tree),
+            740 => self.description_2(&children[0], parse_tree),
+            741 => self.description_3(&children[0], parse_tree),
+            742 => self.description_4(&children[0], parse_tree),
+            743 => self.veryl(&children[0], &children[1], parse_tree),
+            744 => self.veryl_list_0(&children[0], &children[1], &children[2], parse_tree),
+            745 => self.veryl_list_list_0(&children[0], &children[1], parse_tree),
+            746 => self.veryl_list_list_1(parse_tree),
+            747 => self.veryl_list_1(parse_tree),
             _ => Err(miette!("Unhandled production number: {}", prod_num)),
         }
     }
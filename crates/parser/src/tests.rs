@@ -1,3 +1,5 @@
+use crate::veryl_grammar_trait::*;
+use crate::veryl_walker::VerylWalker;
 use crate::Parser;
 
 fn success(code: &str) {
@@ -87,6 +89,42 @@ fn expression() {
     failure("var a: u32 = ( (1 && 1) || 1 & (1 ^ 1 ~^ 1) ^~ 1 | 1;");
 }
 
+#[derive(Default)]
+struct FirstExpression(Option<Expression>);
+
+impl VerylWalker for FirstExpression {
+    fn expression(&mut self, arg: &Expression) {
+        if self.0.is_none() {
+            self.0 = Some(arg.clone());
+        }
+    }
+}
+
+#[test]
+fn if_expression() {
+    success("var a: u32 = if a { 1 } else { 2 };");
+    success("var a: u32 = if a { 1 } else if b { 2 } else { 3 };");
+    success("var a: u32 = if a == 1 { if b { 1 } else { 2 } } else { c + 1 };");
+    success("var a: u32 = (if a { 1 } else { 2 }) + 1;");
+
+    // if expression has the lowest precedence, so it can't be an operand
+    failure("var a: u32 = if a { 1 } else { 2 } + 1;");
+    failure("var a: u32 = 1 + if a { 1 } else { 2 };");
+    failure("var a: u32 = if a { 1 } else { 2 } as u32;");
+
+    let parser = Parser::parse("module A { assign a = if c { a } else { b + 1 }; }", &"").unwrap();
+    let mut first = FirstExpression::default();
+    first.veryl(&parser.veryl);
+    match first.0.unwrap() {
+        Expression::IfExpression(x) => {
+            let x = &x.if_expression;
+            assert!(x.expression0.single_factor().is_some());
+            assert!(x.expression1.single_factor().is_none());
+        }
+        Expression::Expression01(_) => unreachable!(),
+    }
+}
+
 #[test]
 fn function_call() {
     success("var a: u32 = a();");
@@ -129,6 +167,23 @@ fn r#type() {
     success("var a: f32[10][10];");
     success("var a: f64[10][10];");
     success("var a: a::a[10][10];");
+
+    success("var a: signed logic[10];");
+    success("var a: signed bit[10][10];");
+    success("var a: tri logic[10];");
+    success("var a: tri signed logic[10];");
+    success("var a: signed tri logic[10];");
+}
+
+#[test]
+fn cast() {
+    success("var a: u32 = a as i32;");
+    success("var a: u32 = a as logic[8];");
+    success("var a: u32 = a as signed logic[8];");
+    success("var a: u32 = a::a as a::a;");
+    success("var a: u32 = -a[1:0] as u32 + (b as i64);");
+    failure("var a: u32 = a as;");
+    failure("var a: u32 = a as 1;");
 }
 
 #[test]
@@ -148,3 +203,53 @@ fn assignment_statement() {
     success("always_comb { a <<<= 1; }");
     success("always_comb { a >>>= 1; }");
 }
+
+#[test]
+fn assertion() {
+    success("always_comb { assert a == 1; }");
+    success("always_comb { assume a; }");
+    success("always_comb { cover a != b; }");
+    success("assert (i_clk) a == 1;");
+    success("assert (posedge i_clk, async_low i_rst) a;");
+    success("cover (negedge i_clk, sync_high i_rst) a && b;");
+    failure("assert a;");
+    failure("always_comb { assert (i_clk) a; }");
+}
+
+#[test]
+fn attribute() {
+    success("#[keep] var a: logic;");
+    success("#[ram_style(block)] var a: logic;");
+    success("#[a(b, 1,)] #[c] assign a = 1;");
+    failure("#[] var a: logic;");
+    failure("#[a()] var a: logic;");
+}
+
+#[test]
+fn string_literal() {
+    success(r#"var a: logic [24] = "abc";"#);
+    success(r#"var a: logic [40] = "a\"b\\c";"#);
+    success(r#"var a: logic [8] = "";"#);
+    failure(r#"var a: logic [24] = "abc;"#);
+    failure("var a: logic [24] = \"a\nb\";");
+}
+
+#[test]
+fn system_task() {
+    success(r#"initial { $display("a = %d", a); }"#);
+    success(r#"initial { $readmemh("mem.hex", mem); }"#);
+    success("initial { $finish; }");
+    success("always_comb { $finish(); }");
+    success(r#"always_comb { a = $sformatf("%0d", b); }"#);
+    failure("initial { $finish }");
+    failure("initial { $ finish; }");
+}
+
+#[test]
+fn parameter_value() {
+    success("inst a: b #(c: 1, d: logic[8], e: u32, f);");
+    success("inst a: b #(c: a::b);");
+    failure("var a: u32 = logic[8];");
+    failure("var a: u32 = b + u32;");
+    failure("assign a = $bits(logic);");
+}
@@ -1 +1,73 @@
 pub use crate::generated::veryl_grammar_trait::*;
+
+impl Expression {
+    /// Factor of the expression which doesn't have any operator
+    pub fn single_factor(&self) -> Option<&Factor> {
+        let x = match self {
+            Expression::Expression01(x) => &x.expression01,
+            Expression::IfExpression(_) => return None,
+        };
+        if !x.expression01_list.is_empty() {
+            return None;
+        }
+        let x = &x.expression02;
+        if !x.expression02_list.is_empty() {
+            return None;
+        }
+        let x = &x.expression03;
+        if !x.expression03_list.is_empty() {
+            return None;
+        }
+        let x = &x.expression04;
+        if !x.expression04_list.is_empty() {
+            return None;
+        }
+        let x = &x.expression05;
+        if !x.expression05_list.is_empty() {
+            return None;
+        }
+        let x = &x.expression06;
+        if !x.expression06_list.is_empty() {
+            return None;
+        }
+        let x = &x.expression07;
+        if !x.expression07_list.is_empty() {
+            return None;
+        }
+        let x = &x.expression08;
+        if !x.expression08_list.is_empty() {
+            return None;
+        }
+        let x = &x.expression09;
+        if !x.expression09_list.is_empty() {
+            return None;
+        }
+        let x = &x.expression10;
+        if !x.expression10_list.is_empty() {
+            return None;
+        }
+        let x = &x.expression11;
+        if !x.expression11_list.is_empty() {
+            return None;
+        }
+        let x = &x.expression12;
+        if !x.expression12_list.is_empty() || x.expression12_opt.is_some() {
+            return None;
+        }
+        Some(&x.factor)
+    }
+}
+
+impl Attribute {
+    /// Directives are consumed by Veryl and not emitted as SystemVerilog attributes
+    pub fn is_directive(&self) -> bool {
+        matches!(
+            self.identifier.identifier_token.text().as_str(),
+            "skip" | "sync"
+        )
+    }
+
+    pub fn is_skip(&self) -> bool {
+        self.identifier.identifier_token.text() == "skip"
+    }
+}
@@ -156,10 +156,11 @@ token_with_comments!(BasedToken, BasedTerm, based_term);
 token_with_comments!(BaseLessToken, BaseLessTerm, base_less_term);
 token_with_comments!(AllBitToken, AllBitTerm, all_bit_term);
 
+token_with_comments!(StringLiteralToken, StringLiteralTerm, string_literal_term);
+
 token_with_comments!(ColonToken, ColonTerm, colon_term);
 token_with_comments!(ColonColonToken, ColonColonTerm, colon_colon_term);
 token_with_comments!(CommaToken, CommaTerm, comma_term);
-token_with_comments!(DollarToken, DollarTerm, dollar_term);
 token_with_comments!(DotDotToken, DotDotTerm, dot_dot_term);
 token_with_comments!(DotToken, DotTerm, dot_term);
 token_with_comments!(EquToken, EquTerm, equ_term);
@@ -196,17 +197,23 @@ token_with_comments!(UnaryOperatorToken, UnaryOperatorTerm, unary_operator_term)
 
 token_with_comments!(AlwaysCombToken, AlwaysCombTerm, always_comb_term);
 token_with_comments!(AlwaysFfToken, AlwaysFfTerm, always_ff_term);
+token_with_comments!(AsToken, AsTerm, as_term);
+token_with_comments!(AssertToken, AssertTerm, assert_term);
 token_with_comments!(AssignToken, AssignTerm, assign_term);
+token_with_comments!(AssumeToken, AssumeTerm, assume_term);
 token_with_comments!(AsyncHighToken, AsyncHighTerm, async_high_term);
 token_with_comments!(AsyncLowToken, AsyncLowTerm, async_low_term);
 token_with_comments!(BitToken, BitTerm, bit_term);
 token_with_comments!(CaseToken, CaseTerm, case_term);
+token_with_comments!(CoverToken, CoverTerm, cover_term);
 token_with_comments!(DefaultToken, DefaultTerm, default_term);
 token_with_comments!(ElseToken, ElseTerm, else_term);
 token_with_comments!(EnumToken, EnumTerm, enum_term);
+token_with_comments!(ExpectToken, ExpectTerm, expect_term);
 token_with_comments!(ExportToken, ExportTerm, export_term);
 token_with_comments!(F32Token, F32Term, f32_term);
 token_with_comments!(F64Token, F64Term, f64_term);
+token_with_comments!(FinalToken, FinalTerm, final_term);
 token_with_comments!(FunctionToken, FunctionTerm, function_term);
 token_with_comments!(ForToken, ForTerm, for_term);
 token_with_comments!(I32Token, I32Term, i32_term);
@@ -214,6 +221,7 @@ token_with_comments!(I64Token, I64Term, i64_term);
 token_with_comments!(IfToken, IfTerm, if_term);
 token_with_comments!(IfResetToken, IfResetTerm, if_reset_term);
 token_with_comments!(ImportToken, ImportTerm, import_term);
+token_with_comments!(InitialToken, InitialTerm, initial_term);
 token_with_comments!(InoutToken, InoutTerm, inout_term);
 token_with_comments!(InputToken, InputTerm, input_term);
 token_with_comments!(InstToken, InstTerm, inst_term);
@@ -231,13 +239,23 @@ token_with_comments!(PosedgeToken, PosedgeTerm, posedge_term);
 token_with_comments!(RefToken, RefTerm, ref_term);
 token_with_comments!(RepeatToken, RepeatTerm, repeat_term);
 token_with_comments!(ReturnToken, ReturnTerm, return_term);
+token_with_comments!(SignedToken, SignedTerm, signed_term);
 token_with_comments!(StepToken, StepTerm, step_term);
 token_with_comments!(StructToken, StructTerm, struct_term);
 token_with_comments!(SyncHighToken, SyncHighTerm, sync_high_term);
 token_with_comments!(SyncLowToken, SyncLowTerm, sync_low_term);
+token_with_comments!(TestToken, TestTerm, test_term);
 token_with_comments!(TriToken, TriTerm, tri_term);
+token_with_comments!(TypeToken, TypeTerm, type_term);
 token_with_comments!(U32Token, U32Term, u32_term);
 token_with_comments!(U64Token, U64Term, u64_term);
+token_with_comments!(UnionToken, UnionTerm, union_term);
+token_with_comments!(UnpackedToken, UnpackedTerm, unpacked_term);
 token_with_comments!(VarToken, VarTerm, var_term);
 
+token_with_comments!(
+    DollarIdentifierToken,
+    DollarIdentifierTerm,
+    dollar_identifier_term
+);
 token_with_comments!(IdentifierToken, IdentifierTerm, identifier_term);
@@ -69,6 +69,13 @@ pub trait VerylWalker {
         after!(self, all_bit, arg);
     }
 
+    /// Semantic action for non-terminal 'StringLiteral'
+    fn string_literal(&mut self, arg: &StringLiteral) {
+        before!(self, string_literal, arg);
+        self.veryl_token(&arg.string_literal_token);
+        after!(self, string_literal, arg);
+    }
+
     /// Semantic action for non-terminal 'AssignmentOperator'
     fn assignment_operator(&mut self, arg: &AssignmentOperator) {
         before!(self, assignment_operator, arg);
@@ -181,13 +188,6 @@ pub trait VerylWalker {
         after!(self, comma, arg);
     }
 
-    /// Semantic action for non-terminal 'Dollar'
-    fn dollar(&mut self, arg: &Dollar) {
-        before!(self, dollar, arg);
-        self.veryl_token(&arg.dollar_token);
-        after!(self, dollar, arg);
-    }
-
     /// Semantic action for non-terminal 'DotDot'
     fn dot_dot(&mut self, arg: &DotDot) {
         before!(self, dot_dot, arg);
@@ -307,6 +307,20 @@ pub trait VerylWalker {
         after!(self, always_ff, arg);
     }
 
+    /// Semantic action for non-terminal 'As'
+    fn r#as(&mut self, arg: &As) {
+        before!(self, r#as, arg);
+        self.veryl_token(&arg.as_token);
+        after!(self, r#as, arg);
+    }
+
+    /// Semantic action for non-terminal 'Assert'
+    fn assert(&mut self, arg: &Assert) {
+        before!(self, assert, arg);
+        self.veryl_token(&arg.assert_token);
+        after!(self, assert, arg);
+    }
+
     /// Semantic action for non-terminal 'Assign'
     fn assign(&mut self, arg: &Assign) {
         before!(self, assign, arg);
@@ -314,6 +328,13 @@ pub trait VerylWalker {
         after!(self, assign, arg);
     }
 
+    /// Semantic action for non-terminal 'Assume'
+    fn assume(&mut self, arg: &Assume) {
+        before!(self, assume, arg);
+        self.veryl_token(&arg.assume_token);
+        after!(self, assume, arg);
+    }
+
     /// Semantic action for non-terminal 'AsyncHigh'
     fn async_high(&mut self, arg: &AsyncHigh) {
         before!(self, async_high, arg);
@@ -342,6 +363,13 @@ pub trait VerylWalker {
         after!(self, case, arg);
     }
 
+    /// Semantic action for non-terminal 'Cover'
+    fn cover(&mut self, arg: &Cover) {
+        before!(self, cover, arg);
+        self.veryl_token(&arg.cover_token);
+        after!(self, cover, arg);
+    }
+
     /// Semantic action for non-terminal 'Defaul'
     fn defaul(&mut self, arg: &Defaul) {
         before!(self, defaul, arg);
@@ -363,6 +391,13 @@ pub trait VerylWalker {
         after!(self, r#enum, arg);
     }
 
+    /// Semantic action for non-terminal 'Expect'
+    fn expect(&mut self, arg: &Expect) {
+        before!(self, expect, arg);
+        self.veryl_token(&arg.expect_token);
+        after!(self, expect, arg);
+    }
+
     /// Semantic action for non-terminal 'Export'
     fn export(&mut self, arg: &Export) {
         before!(self, export, arg);
@@ -384,6 +419,13 @@ pub trait VerylWalker {
         after!(self, f64, arg);
     }
 
+    /// Semantic action for non-terminal 'Final'
+    fn r#final(&mut self, arg: &Final) {
+        before!(self, r#final, arg);
+        self.veryl_token(&arg.final_token);
+        after!(self, r#final, arg);
+    }
+
     /// Semantic action for non-terminal 'For'
     fn r#for(&mut self, arg: &For) {
         before!(self, r#for, arg);
@@ -440,6 +482,13 @@ pub trait VerylWalker {
         after!(self, r#in, arg);
     }
 
+    /// Semantic action for non-terminal 'Initial'
+    fn initial(&mut self, arg: &Initial) {
+        before!(self, initial, arg);
+        self.veryl_token(&arg.initial_token);
+        after!(self, initial, arg);
+    }
+
     /// Semantic action for non-terminal 'Inout'
     fn inout(&mut self, arg: &Inout) {
         before!(self, inout, arg);
@@ -552,6 +601,13 @@ pub trait VerylWalker {
         after!(self, r#return, arg);
     }
 
+    /// Semantic action for non-terminal 'Signed'
+    fn signed(&mut self, arg: &Signed) {
+        before!(self, signed, arg);
+        self.veryl_token(&arg.signed_token);
+        after!(self, signed, arg);
+    }
+
     /// Semantic action for non-terminal 'Step'
     fn step(&mut self, arg: &Step) {
         before!(self, step, arg);
@@ -580,6 +636,13 @@ pub trait VerylWalker {
         after!(self, sync_low, arg);
     }
 
+    /// Semantic action for non-terminal 'Test'
+    fn test(&mut self, arg: &Test) {
+        before!(self, test, arg);
+        self.veryl_token(&arg.test_token);
+        after!(self, test, arg);
+    }
+
     /// Semantic action for non-terminal 'Tri'
     fn tri(&mut self, arg: &Tri) {
         before!(self, tri, arg);
@@ -587,6 +650,13 @@ pub trait VerylWalker {
         after!(self, tri, arg);
     }
 
+    /// Semantic action for non-terminal 'Typ'
+    fn typ(&mut self, arg: &Typ) {
+        before!(self, typ, arg);
+        self.veryl_token(&arg.type_token);
+        after!(self, typ, arg);
+    }
+
     /// Semantic action for non-terminal 'U32'
     fn u32(&mut self, arg: &U32) {
         before!(self, u32, arg);
@@ -601,6 +671,20 @@ pub trait VerylWalker {
         after!(self, u64, arg);
     }
 
+    /// Semantic action for non-terminal 'Union'
+    fn union(&mut self, arg: &Union) {
+        before!(self, union, arg);
+        self.veryl_token(&arg.union_token);
+        after!(self, union, arg);
+    }
+
+    /// Semantic action for non-terminal 'Unpacked'
+    fn unpacked(&mut self, arg: &Unpacked) {
+        before!(self, unpacked, arg);
+        self.veryl_token(&arg.unpacked_token);
+        after!(self, unpacked, arg);
+    }
+
     /// Semantic action for non-terminal 'Var'
     fn var(&mut self, arg: &Var) {
         before!(self, var, arg);
@@ -608,6 +692,13 @@ pub trait VerylWalker {
         after!(self, var, arg);
     }
 
+    /// Semantic action for non-terminal 'DollarIdentifier'
+    fn dollar_identifier(&mut self, arg: &DollarIdentifier) {
+        before!(self, dollar_identifier, arg);
+        self.veryl_token(&arg.dollar_identifier_token);
+        after!(self, dollar_identifier, arg);
+    }
+
     /// Semantic action for non-terminal 'Identifier'
     fn identifier(&mut self, arg: &Identifier) {
         before!(self, identifier, arg);
@@ -715,10 +806,9 @@ pub trait VerylWalker {
     /// Semantic action for non-terminal 'Expression'
     fn expression(&mut self, arg: &Expression) {
         before!(self, expression, arg);
-        self.expression01(&arg.expression01);
-        for x in &arg.expression_list {
-            self.operator01(&x.operator01);
-            self.expression01(&x.expression01);
+        match arg {
+            Expression::IfExpression(x) => self.if_expression(&x.if_expression),
+            Expression::Expression01(x) => self.expression01(&x.expression01),
         }
         after!(self, expression, arg);
     }
@@ -728,7 +818,7 @@ pub trait VerylWalker {
         before!(self, expression01, arg);
         self.expression02(&arg.expression02);
         for x in &arg.expression01_list {
-            self.operator02(&x.operator02);
+            self.operator01(&x.operator01);
             self.expression02(&x.expression02);
         }
         after!(self, expression01, arg);
@@ -739,7 +829,7 @@ pub trait VerylWalker {
         before!(self, expression02, arg);
         self.expression03(&arg.expression03);
         for x in &arg.expression02_list {
-            self.operator03(&x.operator03);
+            self.operator02(&x.operator02);
             self.expression03(&x.expression03);
         }
         after!(self, expression02, arg);
@@ -750,7 +840,7 @@ pub trait VerylWalker {
         before!(self, expression03, arg);
         self.expression04(&arg.expression04);
         for x in &arg.expression03_list {
-            self.operator04(&x.operator04);
+            self.operator03(&x.operator03);
             self.expression04(&x.expression04);
         }
         after!(self, expression03, arg);
@@ -761,7 +851,7 @@ pub trait VerylWalker {
         before!(self, expression04, arg);
         self.expression05(&arg.expression05);
         for x in &arg.expression04_list {
-            self.operator05(&x.operator05);
+            self.operator04(&x.operator04);
             self.expression05(&x.expression05);
         }
         after!(self, expression04, arg);
@@ -772,7 +862,7 @@ pub trait VerylWalker {
         before!(self, expression05, arg);
         self.expression06(&arg.expression06);
         for x in &arg.expression05_list {
-            self.operator06(&x.operator06);
+            self.operator05(&x.operator05);
             self.expression06(&x.expression06);
         }
         after!(self, expression05, arg);
@@ -783,7 +873,7 @@ pub trait VerylWalker {
         before!(self, expression06, arg);
         self.expression07(&arg.expression07);
         for x in &arg.expression06_list {
-            self.operator07(&x.operator07);
+            self.operator06(&x.operator06);
             self.expression07(&x.expression07);
         }
         after!(self, expression06, arg);
@@ -794,7 +884,7 @@ pub trait VerylWalker {
         before!(self, expression07, arg);
         self.expression08(&arg.expression08);
         for x in &arg.expression07_list {
-            self.operator08(&x.operator08);
+            self.operator07(&x.operator07);
             self.expression08(&x.expression08);
         }
         after!(self, expression07, arg);
@@ -805,7 +895,7 @@ pub trait VerylWalker {
         before!(self, expression08, arg);
         self.expression09(&arg.expression09);
         for x in &arg.expression08_list {
-            self.operator09(&x.operator09);
+            self.operator08(&x.operator08);
             self.expression09(&x.expression09);
         }
         after!(self, expression08, arg);
@@ -816,10 +906,7 @@ pub trait VerylWalker {
         before!(self, expression09, arg);
         self.expression10(&arg.expression10);
         for x in &arg.expression09_list {
-            match &*x.expression09_list_group {
-                Expression09ListGroup::Operator10(x) => self.operator10(&x.operator10),
-                Expression09ListGroup::Star(x) => self.star(&x.star),
-            }
+            self.operator09(&x.operator09);
             self.expression10(&x.expression10);
         }
         after!(self, expression09, arg);
@@ -830,7 +917,10 @@ pub trait VerylWalker {
         before!(self, expression10, arg);
         self.expression11(&arg.expression11);
         for x in &arg.expression10_list {
-            self.operator11(&x.operator11);
+            match &*x.expression10_list_group {
+                Expression10ListGroup::Operator10(x) => self.operator10(&x.operator10),
+                Expression10ListGroup::Star(x) => self.star(&x.star),
+            }
             self.expression11(&x.expression11);
         }
         after!(self, expression10, arg);
@@ -839,17 +929,32 @@ pub trait VerylWalker {
     /// Semantic action for non-terminal 'Expression11'
     fn expression11(&mut self, arg: &Expression11) {
         before!(self, expression11, arg);
+        self.expression12(&arg.expression12);
         for x in &arg.expression11_list {
-            match &*x.expression11_list_group {
-                Expression11ListGroup::UnaryOperator(x) => self.unary_operator(&x.unary_operator),
-                Expression11ListGroup::Operator03(x) => self.operator03(&x.operator03),
-                Expression11ListGroup::Operator04(x) => self.operator04(&x.operator04),
-                Expression11ListGroup::Operator05(x) => self.operator05(&x.operator05),
-                Expression11ListGroup::Operator09(x) => self.operator09(&x.operator09),
+            self.operator11(&x.operator11);
+            self.expression12(&x.expression12);
+        }
+        after!(self, expression11, arg);
+    }
+
+    /// Semantic action for non-terminal 'Expression12'
+    fn expression12(&mut self, arg: &Expression12) {
+        before!(self, expression12, arg);
+        for x in &arg.expression12_list {
+            match &*x.expression12_list_group {
+                Expression12ListGroup::UnaryOperator(x) => self.unary_operator(&x.unary_operator),
+                Expression12ListGroup::Operator03(x) => self.operator03(&x.operator03),
+                Expression12ListGroup::Operator04(x) => self.operator04(&x.operator04),
+                Expression12ListGroup::Operator05(x) => self.operator05(&x.operator05),
+                Expression12ListGroup::Operator09(x) => self.operator09(&x.operator09),
             }
         }
         self.factor(&arg.factor);
-        after!(self, expression11, arg);
+        if let Some(ref x) = arg.expression12_opt {
+            self.r#as(&x.r#as);
+            self.r#type(&x.r#type);
+        }
+        after!(self, expression12, arg);
     }
 
     /// Semantic action for non-terminal 'Factor'
@@ -857,19 +962,18 @@ pub trait VerylWalker {
         before!(self, factor, arg);
         match arg {
             Factor::Number(x) => self.number(&x.number),
-            Factor::FactorOptScopedOrHierIdentifierFactorOpt0(x) => {
-                if let Some(ref x) = x.factor_opt {
-                    self.dollar(&x.dollar);
-                }
+            Factor::StringLiteral(x) => self.string_literal(&x.string_literal),
+            Factor::ScopedOrHierIdentifierFactorOpt(x) => {
                 self.scoped_or_hier_identifier(&x.scoped_or_hier_identifier);
-                if let Some(ref x) = x.factor_opt0 {
+                if let Some(ref x) = x.factor_opt {
                     self.l_paren(&x.l_paren);
-                    if let Some(ref x) = x.factor_opt1 {
+                    if let Some(ref x) = x.factor_opt0 {
                         self.function_call_arg(&x.function_call_arg);
                     }
                     self.r_paren(&x.r_paren);
                 }
             }
+            Factor::SystemFunctionCall(x) => self.system_function_call(&x.system_function_call),
             Factor::LParenExpressionRParen(x) => {
                 self.l_paren(&x.l_paren);
                 self.expression(&x.expression);
@@ -884,6 +988,43 @@ pub trait VerylWalker {
         after!(self, factor, arg);
     }
 
+    /// Semantic action for non-terminal 'IfExpression'
+    fn if_expression(&mut self, arg: &IfExpression) {
+        before!(self, if_expression, arg);
+        self.r#if(&arg.r#if);
+        self.expression(&arg.expression);
+        self.l_brace(&arg.l_brace);
+        self.expression(&arg.expression0);
+        self.r_brace(&arg.r_brace);
+        for x in &arg.if_expression_list {
+            self.r#else(&x.r#else);
+            self.r#if(&x.r#if);
+            self.expression(&x.expression);
+            self.l_brace(&x.l_brace);
+            self.expression(&x.expression0);
+            self.r_brace(&x.r_brace);
+        }
+        self.r#else(&arg.r#else);
+        self.l_brace(&arg.l_brace0);
+        self.expression(&arg.expression1);
+        self.r_brace(&arg.r_brace0);
+        after!(self, if_expression, arg);
+    }
+
+    /// Semantic action for non-terminal 'SystemFunctionCall'
+    fn system_function_call(&mut self, arg: &SystemFunctionCall) {
+        before!(self, system_function_call, arg);
+        self.dollar_identifier(&arg.dollar_identifier);
+        if let Some(ref x) = arg.system_function_call_opt {
+            self.l_paren(&x.l_paren);
+            if let Some(ref x) = x.system_function_call_opt0 {
+                self.function_call_arg(&x.function_call_arg);
+            }
+            self.r_paren(&x.r_paren);
+        }
+        after!(self, system_function_call, arg);
+    }
+
     /// Semantic action for non-terminal 'FunctionCallArg'
     fn function_call_arg(&mut self, arg: &FunctionCallArg) {
         before!(self, function_call_arg, arg);
@@ -957,6 +1098,17 @@ pub trait VerylWalker {
         after!(self, width, arg);
     }
 
+    /// Semantic action for non-terminal 'Array'
+    fn array(&mut self, arg: &Array) {
+        before!(self, array, arg);
+        self.unpacked(&arg.unpacked);
+        self.width(&arg.width);
+        for x in &arg.array_list {
+            self.width(&x.width);
+        }
+        after!(self, array, arg);
+    }
+
     /// Semantic action for non-terminal 'BuiltinType'
     fn builtin_type(&mut self, arg: &BuiltinType) {
         before!(self, builtin_type, arg);
@@ -976,14 +1128,17 @@ pub trait VerylWalker {
     /// Semantic action for non-terminal 'TypeModifier'
     fn type_modifier(&mut self, arg: &TypeModifier) {
         before!(self, type_modifier, arg);
-        self.tri(&arg.tri);
+        match arg {
+            TypeModifier::Tri(x) => self.tri(&x.tri),
+            TypeModifier::Signed(x) => self.signed(&x.signed),
+        }
         after!(self, type_modifier, arg);
     }
 
     /// Semantic action for non-terminal 'Type'
     fn r#type(&mut self, arg: &Type) {
         before!(self, r#type, arg);
-        if let Some(ref x) = arg.type_opt {
+        for x in &arg.type_list {
             self.type_modifier(&x.type_modifier);
         }
         match &*arg.type_group {
@@ -991,9 +1146,12 @@ pub trait VerylWalker {
             TypeGroup::ScopedIdentifier(x) => self.scoped_identifier(&x.scoped_identifier),
             TypeGroup::ModportIdentifier(x) => self.modport_identifier(&x.modport_identifier),
         };
-        for x in &arg.type_list {
+        for x in &arg.type_list0 {
             self.width(&x.width);
         }
+        if let Some(ref x) = arg.type_opt {
+            self.array(&x.array);
+        }
         after!(self, r#type, arg);
     }
 
@@ -1007,6 +1165,10 @@ pub trait VerylWalker {
             Statement::ReturnStatement(x) => self.return_statement(&x.return_statement),
             Statement::ForStatement(x) => self.for_statement(&x.for_statement),
             Statement::CaseStatement(x) => self.case_statement(&x.case_statement),
+            Statement::AssertionStatement(x) => self.assertion_statement(&x.assertion_statement),
+            Statement::SystemTaskStatement(x) => {
+                self.system_task_statement(&x.system_task_statement)
+            }
         };
         after!(self, statement, arg);
     }
@@ -1133,6 +1295,34 @@ pub trait VerylWalker {
         after!(self, case_statement, arg);
     }
 
+    /// Semantic action for non-terminal 'AssertionStatement'
+    fn assertion_statement(&mut self, arg: &AssertionStatement) {
+        before!(self, assertion_statement, arg);
+        self.assertion_kind(&arg.assertion_kind);
+        self.expression(&arg.expression);
+        self.semicolon(&arg.semicolon);
+        after!(self, assertion_statement, arg);
+    }
+
+    /// Semantic action for non-terminal 'AssertionKind'
+    fn assertion_kind(&mut self, arg: &AssertionKind) {
+        before!(self, assertion_kind, arg);
+        match arg {
+            AssertionKind::Assert(x) => self.assert(&x.assert),
+            AssertionKind::Assume(x) => self.assume(&x.assume),
+            AssertionKind::Cover(x) => self.cover(&x.cover),
+        };
+        after!(self, assertion_kind, arg);
+    }
+
+    /// Semantic action for non-terminal 'SystemTaskStatement'
+    fn system_task_statement(&mut self, arg: &SystemTaskStatement) {
+        before!(self, system_task_statement, arg);
+        self.system_function_call(&arg.system_function_call);
+        self.semicolon(&arg.semicolon);
+        after!(self, system_task_statement, arg);
+    }
+
     /// Semantic action for non-terminal 'CaseItem'
     fn case_item(&mut self, arg: &CaseItem) {
         before!(self, case_item, arg);
@@ -1154,7 +1344,45 @@ pub trait VerylWalker {
         after!(self, case_item, arg);
     }
 
-    /// Semantic action for non-terminal 'VarDeclaration'
+    /// Semantic action for non-terminal 'Attribute'
+    fn attribute(&mut self, arg: &Attribute) {
+        before!(self, attribute, arg);
+        self.hash(&arg.hash);
+        self.l_bracket(&arg.l_bracket);
+        self.identifier(&arg.identifier);
+        if let Some(ref x) = arg.attribute_opt {
+            self.l_paren(&x.l_paren);
+            self.attribute_list(&x.attribute_list);
+            self.r_paren(&x.r_paren);
+        }
+        self.r_bracket(&arg.r_bracket);
+        after!(self, attribute, arg);
+    }
+
+    /// Semantic action for non-terminal 'AttributeList'
+    fn attribute_list(&mut self, arg: &AttributeList) {
+        before!(self, attribute_list, arg);
+        self.attribute_item(&arg.attribute_item);
+        for x in &arg.attribute_list_list {
+            self.comma(&x.comma);
+            self.attribute_item(&x.attribute_item);
+        }
+        if let Some(ref x) = arg.attribute_list_opt {
+            self.comma(&x.comma);
+        }
+        after!(self, attribute_list, arg);
+    }
+
+    /// Semantic action for non-terminal 'AttributeItem'
+    fn attribute_item(&mut self, arg: &AttributeItem) {
+        before!(self, attribute_item, arg);
+        match arg {
+            AttributeItem::Identifier(x) => self.identifier(&x.identifier),
+            AttributeItem::Number(x) => self.number(&x.number),
+        };
+        after!(self, attribute_item, arg);
+    }
+
     fn var_declaration(&mut self, arg: &VarDeclaration) {
         before!(self, var_declaration, arg);
         self.var(&arg.var);
@@ -1182,6 +1410,17 @@ pub trait VerylWalker {
         after!(self, localparam_declaration, arg);
     }
 
+    /// Semantic action for non-terminal 'TypeDeclaration'
+    fn type_declaration(&mut self, arg: &TypeDeclaration) {
+        before!(self, type_declaration, arg);
+        self.typ(&arg.typ);
+        self.identifier(&arg.identifier);
+        self.equ(&arg.equ);
+        self.r#type(&arg.r#type);
+        self.semicolon(&arg.semicolon);
+        after!(self, type_declaration, arg);
+    }
+
     /// Semantic action for non-terminal 'AlwaysFfDeclaration'
     fn always_ff_declaration(&mut self, arg: &AlwaysFfDeclaration) {
         before!(self, always_ff_declaration, arg);
@@ -1241,6 +1480,46 @@ pub trait VerylWalker {
         after!(self, always_comb_declaration, arg);
     }
 
+    /// Semantic action for non-terminal 'AssertionDeclaration'
+    fn assertion_declaration(&mut self, arg: &AssertionDeclaration) {
+        before!(self, assertion_declaration, arg);
+        self.assertion_kind(&arg.assertion_kind);
+        self.l_paren(&arg.l_paren);
+        self.always_ff_clock(&arg.always_ff_clock);
+        if let Some(ref x) = arg.assertion_declaration_opt {
+            self.comma(&x.comma);
+            self.always_ff_reset(&x.always_ff_reset);
+        }
+        self.r_paren(&arg.r_paren);
+        self.expression(&arg.expression);
+        self.semicolon(&arg.semicolon);
+        after!(self, assertion_declaration, arg);
+    }
+
+    /// Semantic action for non-terminal 'InitialDeclaration'
+    fn initial_declaration(&mut self, arg: &InitialDeclaration) {
+        before!(self, initial_declaration, arg);
+        self.initial(&arg.initial);
+        self.l_brace(&arg.l_brace);
+        for x in &arg.initial_declaration_list {
+            self.statement(&x.statement);
+        }
+        self.r_brace(&arg.r_brace);
+        after!(self, initial_declaration, arg);
+    }
+
+    /// Semantic action for non-terminal 'FinalDeclaration'
+    fn final_declaration(&mut self, arg: &FinalDeclaration) {
+        before!(self, final_declaration, arg);
+        self.r#final(&arg.r#final);
+        self.l_brace(&arg.l_brace);
+        for x in &arg.final_declaration_list {
+            self.statement(&x.statement);
+        }
+        self.r_brace(&arg.r_brace);
+        after!(self, final_declaration, arg);
+    }
+
     /// Semantic action for non-terminal 'AssignDeclaration'
     fn assign_declaration(&mut self, arg: &AssignDeclaration) {
         before!(self, assign_declaration, arg);
@@ -1324,10 +1603,23 @@ pub trait VerylWalker {
         after!(self, enum_item, arg);
     }
 
+    /// Semantic action for non-terminal 'StructUnion'
+    fn struct_union(&mut self, arg: &StructUnion) {
+        before!(self, struct_union, arg);
+        match arg {
+            StructUnion::Struct(x) => self.r#struct(&x.r#struct),
+            StructUnion::Union(x) => self.union(&x.union),
+        };
+        after!(self, struct_union, arg);
+    }
+
     /// Semantic action for non-terminal 'StructDeclaration'
     fn struct_declaration(&mut self, arg: &StructDeclaration) {
         before!(self, struct_declaration, arg);
-        self.r#struct(&arg.r#struct);
+        self.struct_union(&arg.struct_union);
+        if let Some(ref x) = arg.struct_declaration_opt {
+            self.unpacked(&x.unpacked);
+        }
         self.identifier(&arg.identifier);
         self.l_brace(&arg.l_brace);
         self.struct_list(&arg.struct_list);
@@ -1414,7 +1706,7 @@ pub trait VerylWalker {
         self.identifier(&arg.identifier);
         if let Some(ref x) = arg.inst_parameter_item_opt {
             self.colon(&x.colon);
-            self.expression(&x.expression);
+            self.parameter_value(&x.parameter_value);
         }
         after!(self, inst_parameter_item, arg);
     }
@@ -1479,12 +1771,35 @@ pub trait VerylWalker {
         };
         self.identifier(&arg.identifier);
         self.colon(&arg.colon);
-        self.r#type(&arg.r#type);
+        match &*arg.with_parameter_item_group0 {
+            WithParameterItemGroup0::Typ(x) => self.typ(&x.typ),
+            WithParameterItemGroup0::Type(x) => self.r#type(&x.r#type),
+        };
         self.equ(&arg.equ);
-        self.expression(&arg.expression);
+        self.parameter_value(&arg.parameter_value);
         after!(self, with_parameter_item, arg);
     }
 
+    /// Semantic action for non-terminal 'ParameterValue'
+    fn parameter_value(&mut self, arg: &ParameterValue) {
+        before!(self, parameter_value, arg);
+        match arg {
+            ParameterValue::Expression(x) => self.expression(&x.expression),
+            ParameterValue::TypeValue(x) => self.type_value(&x.type_value),
+        };
+        after!(self, parameter_value, arg);
+    }
+
+    /// Semantic action for non-terminal 'TypeValue'
+    fn type_value(&mut self, arg: &TypeValue) {
+        before!(self, type_value, arg);
+        self.builtin_type(&arg.builtin_type);
+        for x in &arg.type_value_list {
+            self.width(&x.width);
+        }
+        after!(self, type_value, arg);
+    }
+
     /// Semantic action for non-terminal 'PortDeclaration'
     fn port_declaration(&mut self, arg: &PortDeclaration) {
         before!(self, port_declaration, arg);
@@ -1513,6 +1828,9 @@ pub trait VerylWalker {
     /// Semantic action for non-terminal 'PortDeclarationItem'
     fn port_declaration_item(&mut self, arg: &PortDeclarationItem) {
         before!(self, port_declaration_item, arg);
+        for x in &arg.port_declaration_item_list {
+            self.attribute(&x.attribute);
+        }
         self.identifier(&arg.identifier);
         self.colon(&arg.colon);
         match &*arg.port_declaration_item_group {
@@ -1613,6 +1931,9 @@ pub trait VerylWalker {
         }
         self.l_brace(&arg.l_brace);
         for x in &arg.module_declaration_list {
+            for x in &x.module_declaration_list_list {
+                self.attribute(&x.attribute);
+            }
             self.module_item(&x.module_item);
         }
         self.r_brace(&arg.r_brace);
@@ -1663,6 +1984,9 @@ pub trait VerylWalker {
         self.identifier(&arg.identifier);
         self.l_brace(&arg.l_brace);
         for x in &arg.module_named_block_list {
+            for x in &x.module_named_block_list_list {
+                self.attribute(&x.attribute);
+            }
             self.module_item(&x.module_item);
         }
         self.r_brace(&arg.r_brace);
@@ -1678,6 +2002,9 @@ pub trait VerylWalker {
         }
         self.l_brace(&arg.l_brace);
         for x in &arg.module_optional_named_block_list {
+            for x in &x.module_optional_named_block_list_list {
+                self.attribute(&x.attribute);
+            }
             self.module_item(&x.module_item);
         }
         self.r_brace(&arg.r_brace);
@@ -1700,6 +2027,11 @@ pub trait VerylWalker {
                 self.always_comb_declaration(&x.always_comb_declaration)
             }
             ModuleItem::AssignDeclaration(x) => self.assign_declaration(&x.assign_declaration),
+            ModuleItem::InitialDeclaration(x) => self.initial_declaration(&x.initial_declaration),
+            ModuleItem::FinalDeclaration(x) => self.final_declaration(&x.final_declaration),
+            ModuleItem::AssertionDeclaration(x) => {
+                self.assertion_declaration(&x.assertion_declaration)
+            }
             ModuleItem::FunctionDeclaration(x) => {
                 self.function_declaration(&x.function_declaration)
             }
@@ -1711,6 +2043,7 @@ pub trait VerylWalker {
             }
             ModuleItem::EnumDeclaration(x) => self.enum_declaration(&x.enum_declaration),
             ModuleItem::StructDeclaration(x) => self.struct_declaration(&x.struct_declaration),
+            ModuleItem::TypeDeclaration(x) => self.type_declaration(&x.type_declaration),
             ModuleItem::ModuleNamedBlock(x) => self.module_named_block(&x.module_named_block),
             ModuleItem::ImportDeclaration(x) => self.import_declaration(&x.import_declaration),
         };
@@ -1817,6 +2150,7 @@ pub trait VerylWalker {
             }
             InterfaceItem::EnumDeclaration(x) => self.enum_declaration(&x.enum_declaration),
             InterfaceItem::StructDeclaration(x) => self.struct_declaration(&x.struct_declaration),
+            InterfaceItem::TypeDeclaration(x) => self.type_declaration(&x.type_declaration),
             InterfaceItem::InterfaceNamedBlock(x) => {
                 self.interface_named_block(&x.interface_named_block)
             }
@@ -1851,6 +2185,7 @@ pub trait VerylWalker {
             }
             PackageItem::EnumDeclaration(x) => self.enum_declaration(&x.enum_declaration),
             PackageItem::StructDeclaration(x) => self.struct_declaration(&x.struct_declaration),
+            PackageItem::TypeDeclaration(x) => self.type_declaration(&x.type_declaration),
             PackageItem::FunctionDeclaration(x) => {
                 self.function_declaration(&x.function_declaration)
             }
@@ -1860,6 +2195,53 @@ pub trait VerylWalker {
         after!(self, package_item, arg);
     }
 
+    /// Semantic action for non-terminal 'TestDeclaration'
+    fn test_declaration(&mut self, arg: &TestDeclaration) {
+        before!(self, test_declaration, arg);
+        self.test(&arg.test);
+        self.identifier(&arg.identifier);
+        self.l_brace(&arg.l_brace);
+        self.inst_declaration(&arg.inst_declaration);
+        for x in &arg.test_declaration_list {
+            self.test_item(&x.test_item);
+        }
+        self.r_brace(&arg.r_brace);
+        after!(self, test_declaration, arg);
+    }
+
+    /// Semantic action for non-terminal 'TestItem'
+    fn test_item(&mut self, arg: &TestItem) {
+        before!(self, test_item, arg);
+        match arg {
+            TestItem::AssignmentStatement(x) => self.assignment_statement(&x.assignment_statement),
+            TestItem::StepStatement(x) => self.step_statement(&x.step_statement),
+            TestItem::ExpectStatement(x) => self.expect_statement(&x.expect_statement),
+        }
+        after!(self, test_item, arg);
+    }
+
+    /// Semantic action for non-terminal 'StepStatement'
+    fn step_statement(&mut self, arg: &StepStatement) {
+        before!(self, step_statement, arg);
+        self.step(&arg.step);
+        self.hierarchical_identifier(&arg.hierarchical_identifier);
+        if let Some(ref x) = arg.step_statement_opt {
+            self.comma(&x.comma);
+            self.expression(&x.expression);
+        }
+        self.semicolon(&arg.semicolon);
+        after!(self, step_statement, arg);
+    }
+
+    /// Semantic action for non-terminal 'ExpectStatement'
+    fn expect_statement(&mut self, arg: &ExpectStatement) {
+        before!(self, expect_statement, arg);
+        self.expect(&arg.expect);
+        self.expression(&arg.expression);
+        self.semicolon(&arg.semicolon);
+        after!(self, expect_statement, arg);
+    }
+
     /// Semantic action for non-terminal 'Description'
     fn description(&mut self, arg: &Description) {
         before!(self, description, arg);
@@ -1870,6 +2252,7 @@ pub trait VerylWalker {
             }
             Description::PackageDeclaration(x) => self.package_declaration(&x.package_declaration),
             Description::ImportDeclaration(x) => self.import_declaration(&x.import_declaration),
+            Description::TestDeclaration(x) => self.test_declaration(&x.test_declaration),
         };
         after!(self, description, arg);
     }
@@ -1879,6 +2262,9 @@ pub trait VerylWalker {
         before!(self, veryl, arg);
         self.start(&arg.start);
         for x in &arg.veryl_list {
+            for x in &x.veryl_list_list {
+                self.attribute(&x.attribute);
+            }
             self.description(&x.description);
         }
         after!(self, veryl, arg);
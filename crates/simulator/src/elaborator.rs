@@ -0,0 +1,1408 @@
+use crate::ir::{self, BinaryOp, Comb, Expr, ExprKind, Ff, Reset, Target, TargetSelect, UnaryOp};
+use crate::simulator_error::SimulatorError;
+use crate::value::{Value, MAX_WIDTH};
+use std::collections::{HashMap, HashSet};
+use veryl_analyzer::evaluator::Evaluator;
+use veryl_analyzer::namespace::Namespace;
+use veryl_analyzer::symbol::Direction as SymDirection;
+use veryl_analyzer::symbol::Type as SymType;
+use veryl_analyzer::symbol::{ParameterScope, SymbolKind, TypeKind};
+use veryl_analyzer::symbol_table::{self, Name};
+use veryl_metadata::{ClockType, ResetType};
+use veryl_parser::resource_table;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::VerylToken;
+use veryl_parser::veryl_walker::VerylWalker;
+use veryl_parser::Stringifier;
+
+const MAX_DEPTH: usize = 256;
+const MAX_LOOP: u128 = 65536;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignalKind {
+    Input,
+    Output,
+    Variable,
+}
+
+#[derive(Clone, Debug)]
+pub struct Signal {
+    /// Hierarchical name separated by `.` from the top module
+    pub name: String,
+    pub width: usize,
+    pub kind: SignalKind,
+}
+
+#[derive(Clone, Debug)]
+enum Item {
+    Signal(usize),
+    Constant(Value),
+    /// Width of type alias or type parameter
+    Type(usize),
+}
+
+type Scope = HashMap<String, Item>;
+
+fn stringify(arg: &Expression) -> String {
+    let mut stringifier = Stringifier::new();
+    stringifier.expression(arg);
+    stringifier.as_str().to_string()
+}
+
+/// Flatten the module hierarchy into signals and processes
+pub struct Elaborator<'a> {
+    pub signals: Vec<Signal>,
+    pub combs: Vec<Comb>,
+    pub ffs: Vec<Ff>,
+    /// Hierarchical names of module instances. The other scopes are generate blocks.
+    pub instances: HashSet<String>,
+    modules: &'a HashMap<String, ModuleDeclaration>,
+    clock_type: ClockType,
+    reset_type: ResetType,
+    reset: Option<Reset>,
+    depth: usize,
+}
+
+impl<'a> Elaborator<'a> {
+    pub fn new(
+        modules: &'a HashMap<String, ModuleDeclaration>,
+        clock_type: ClockType,
+        reset_type: ResetType,
+    ) -> Self {
+        Self {
+            signals: Vec::new(),
+            combs: Vec::new(),
+            ffs: Vec::new(),
+            instances: HashSet::new(),
+            modules,
+            clock_type,
+            reset_type,
+            reset: None,
+            depth: 0,
+        }
+    }
+
+    pub fn elaborate(&mut self, top: &str) -> Result<(), SimulatorError> {
+        self.module(top, "", HashMap::new())?;
+        Ok(())
+    }
+
+    /// Elaborate the module instantiated in the test as top, and operations of the test.
+    /// Signals of the module are referred through the instance name like `u_dut.o_cnt`.
+    pub fn elaborate_test(
+        &mut self,
+        arg: &TestDeclaration,
+    ) -> Result<Vec<ir::TestItem>, SimulatorError> {
+        let inst = &arg.inst_declaration;
+        let name = inst.identifier.identifier_token.text();
+        let module = inst.identifier0.identifier_token.text();
+        if inst.inst_declaration_opt.is_some() {
+            return Err(SimulatorError::Unsupported("instance array".to_string()));
+        }
+        if let Some(ref x) = inst.inst_declaration_opt1 {
+            if x.inst_declaration_opt2.is_some() {
+                return Err(SimulatorError::Unsupported(
+                    "port connection in test".to_string(),
+                ));
+            }
+        }
+
+        let overrides = self.overrides(inst, &[])?;
+        self.module(&module, "", overrides)?;
+
+        let mut scope = Scope::new();
+        for (id, x) in self.signals.iter().enumerate() {
+            scope.insert(format!("{}.{}", name, x.name), Item::Signal(id));
+        }
+        let scopes = vec![scope];
+
+        let mut ret = Vec::new();
+        for x in &arg.test_declaration_list {
+            match &*x.test_item {
+                TestItem::AssignmentStatement(x) => {
+                    let x = &x.assignment_statement;
+                    let statement = self.assignment_statement(x, &scopes)?;
+                    if let ir::Statement::Assign(ref target, _) = statement {
+                        self.test_input(target.id, &x.hierarchical_identifier)?;
+                    }
+                    ret.push(ir::TestItem::Assign(statement));
+                }
+                TestItem::StepStatement(x) => {
+                    let x = &x.step_statement;
+                    let target = self.target(&x.hierarchical_identifier, &scopes)?;
+                    self.test_input(target.id, &x.hierarchical_identifier)?;
+                    let count = match x.step_statement_opt {
+                        Some(ref x) => self.constant_u128(&x.expression, &scopes)?,
+                        None => 1,
+                    };
+                    ret.push(ir::TestItem::Step(target.id, count));
+                }
+                TestItem::ExpectStatement(x) => {
+                    let x = &x.expect_statement;
+                    let expr = self.expression(&x.expression, &scopes)?;
+                    ret.push(ir::TestItem::Expect(expr, stringify(&x.expression)));
+                }
+            }
+        }
+        Ok(ret)
+    }
+
+    /// Test can drive input ports of the top module only
+    fn test_input(&self, id: usize, arg: &HierarchicalIdentifier) -> Result<(), SimulatorError> {
+        let signal = &self.signals[id];
+        if signal.kind != SignalKind::Input || signal.name.contains('.') {
+            let mut stringifier = Stringifier::new();
+            stringifier.hierarchical_identifier(arg);
+            Err(SimulatorError::NotInput(stringifier.as_str().to_string()))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn module(
+        &mut self,
+        name: &str,
+        prefix: &str,
+        mut overrides: HashMap<String, Item>,
+    ) -> Result<HashMap<String, (usize, SignalKind)>, SimulatorError> {
+        let unknown = || SimulatorError::UnknownModule(name.to_string());
+
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(SimulatorError::Unsupported(format!(
+                "recursive instantiation of \"{}\"",
+                name
+            )));
+        }
+
+        let id = resource_table::get_str_id(name.to_string()).ok_or_else(unknown)?;
+        let symbol = symbol_table::get(&Name::Hierarchical(vec![id]), &Namespace::default())
+            .ok_or_else(unknown)?;
+        let property = match symbol.kind {
+            SymbolKind::Module(x) => x,
+            _ => return Err(unknown()),
+        };
+        let declaration = self.modules.get(name).ok_or_else(unknown)?;
+
+        let mut scopes = vec![Scope::new()];
+
+        for parameter in &property.parameters {
+            let name = parameter.name.to_string();
+            let value = match parameter.property.scope {
+                ParameterScope::Global => overrides.remove(&name),
+                ParameterScope::Local => None,
+            };
+            let item = if let TypeKind::Type = parameter.property.r#type.kind {
+                let width = match value {
+                    Some(Item::Type(x)) => x,
+                    Some(_) => return Err(SimulatorError::NotType(name)),
+                    None => self
+                        .type_value(&name, &parameter.property.value, &scopes)?
+                        .ok_or_else(|| SimulatorError::NotType(name.clone()))?,
+                };
+                Item::Type(width)
+            } else {
+                let value = match value {
+                    Some(Item::Constant(x)) => x,
+                    Some(_) => return Err(SimulatorError::NotConstant(name)),
+                    None => self.parameter_constant(&name, &parameter.property.value, &scopes)?,
+                };
+                let width = self.type_width(&name, &parameter.property.r#type, &scopes)?;
+                Item::Constant(value.resize(width))
+            };
+            scopes[0].insert(name, item);
+        }
+
+        let mut ports = HashMap::new();
+        for port in &property.ports {
+            let name = port.name.to_string();
+            let kind = match port.property.direction {
+                SymDirection::Input => SignalKind::Input,
+                SymDirection::Output => SignalKind::Output,
+                SymDirection::Inout => {
+                    return Err(SimulatorError::Unsupported("inout port".to_string()))
+                }
+                _ => return Err(SimulatorError::Unsupported("interface port".to_string())),
+            };
+            let width = match port.property.r#type {
+                Some(ref x) => self.type_width(&name, x, &scopes)?,
+                None => return Err(SimulatorError::Unsupported("interface port".to_string())),
+            };
+            let id = self.signal(&format!("{}{}", prefix, name), width, kind);
+            scopes[0].insert(name.clone(), Item::Signal(id));
+            ports.insert(name, (id, kind));
+        }
+
+        let items: Vec<_> = declaration
+            .module_declaration_list
+            .iter()
+            .map(|x| &*x.module_item)
+            .collect();
+        self.items(&items, prefix, &mut scopes)?;
+
+        self.depth -= 1;
+        Ok(ports)
+    }
+
+    fn signal(&mut self, name: &str, width: usize, kind: SignalKind) -> usize {
+        self.signals.push(Signal {
+            name: name.to_string(),
+            width,
+            kind,
+        });
+        self.signals.len() - 1
+    }
+
+    fn lookup<'b>(&self, name: &str, scopes: &'b [Scope]) -> Option<&'b Item> {
+        scopes.iter().rev().find_map(|x| x.get(name))
+    }
+
+    /// Width of the type given as the value of type parameter
+    fn type_value(
+        &self,
+        name: &str,
+        arg: &ParameterValue,
+        scopes: &[Scope],
+    ) -> Result<Option<usize>, SimulatorError> {
+        let arg = match arg {
+            ParameterValue::Expression(x) => &x.expression,
+            ParameterValue::TypeValue(x) => {
+                let r#type: SymType = (&*x.type_value).into();
+                return Ok(Some(self.type_width(name, &r#type, scopes)?));
+            }
+        };
+        match arg.single_factor() {
+            Some(Factor::ScopedOrHierIdentifierFactorOpt(x)) if x.factor_opt.is_none() => {
+                let x = &x.scoped_or_hier_identifier;
+                match self.lookup(&x.identifier.identifier_token.text(), scopes) {
+                    Some(Item::Type(x)) => Ok(Some(*x)),
+                    _ => Ok(None),
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn type_width(
+        &self,
+        name: &str,
+        arg: &SymType,
+        scopes: &[Scope],
+    ) -> Result<usize, SimulatorError> {
+        if !arg.array.is_empty() {
+            return Err(SimulatorError::Unsupported("unpacked array".to_string()));
+        }
+        let mut width: usize = match &arg.kind {
+            TypeKind::Bit | TypeKind::Logic => 1,
+            TypeKind::U32 | TypeKind::I32 => 32,
+            TypeKind::U64 | TypeKind::I64 => 64,
+            TypeKind::F32 | TypeKind::F64 => {
+                return Err(SimulatorError::Unsupported("real type".to_string()))
+            }
+            TypeKind::UserDefined(paths) if paths.len() == 1 => {
+                match self.lookup(&paths[0].to_string(), scopes) {
+                    Some(Item::Type(x)) => *x,
+                    _ => return Err(SimulatorError::Unsupported(format!("type \"{}\"", arg))),
+                }
+            }
+            TypeKind::UserDefined(_) | TypeKind::Modport(_, _) | TypeKind::Type => {
+                return Err(SimulatorError::Unsupported(format!("type \"{}\"", arg)))
+            }
+        };
+        for x in &arg.width {
+            let value = self.constant_u128(x, scopes)? as usize;
+            width = width.saturating_mul(value);
+        }
+        if width > MAX_WIDTH {
+            Err(SimulatorError::TooWide(name.to_string()))
+        } else {
+            Ok(width)
+        }
+    }
+
+    /// Constant given as the value of value parameter
+    fn parameter_constant(
+        &self,
+        name: &str,
+        arg: &ParameterValue,
+        scopes: &[Scope],
+    ) -> Result<Value, SimulatorError> {
+        match arg {
+            ParameterValue::Expression(x) => self.constant(&x.expression, scopes),
+            ParameterValue::TypeValue(_) => Err(SimulatorError::NotConstant(name.to_string())),
+        }
+    }
+
+    fn constant(&self, arg: &Expression, scopes: &[Scope]) -> Result<Value, SimulatorError> {
+        let expr = self.expression(arg, scopes)?;
+        expr.to_constant()
+            .ok_or_else(|| SimulatorError::NotConstant(stringify(arg)))
+    }
+
+    /// Non-negative integer like widths, counts and loop ranges
+    fn constant_u128(&self, arg: &Expression, scopes: &[Scope]) -> Result<u128, SimulatorError> {
+        let mut evaluator = Evaluator::new(|x: &ScopedOrHierIdentifier| {
+            match &*x.scoped_or_hier_identifier_group {
+                ScopedOrHierIdentifierGroup::ColonColonIdentifierScopedOrHierIdentifierGroupList(_) => None,
+                ScopedOrHierIdentifierGroup::ScopedOrHierIdentifierGroupList0ScopedOrHierIdentifierGroupList1(_) => {
+                    match self.lookup(&x.identifier.identifier_token.text(), scopes) {
+                        Some(Item::Constant(x)) => i64::try_from(x.to_u128()?).ok(),
+                        _ => None,
+                    }
+                }
+            }
+        });
+        evaluator
+            .expression(arg)
+            .and_then(|x| u128::try_from(x).ok())
+            .ok_or_else(|| SimulatorError::NotConstant(stringify(arg)))
+    }
+
+    fn items(
+        &mut self,
+        items: &[&ModuleItem],
+        prefix: &str,
+        scopes: &mut Vec<Scope>,
+    ) -> Result<(), SimulatorError> {
+        for item in items {
+            match item {
+                ModuleItem::VarDeclaration(x) => {
+                    let x = &x.var_declaration;
+                    let name = x.identifier.identifier_token.text();
+                    let r#type: SymType = (&*x.r#type).into();
+                    let width = self.type_width(&name, &r#type, scopes)?;
+                    let id =
+                        self.signal(&format!("{}{}", prefix, name), width, SignalKind::Variable);
+                    scopes.last_mut().unwrap().insert(name, Item::Signal(id));
+                }
+                ModuleItem::LocalparamDeclaration(x) => {
+                    let x = &x.localparam_declaration;
+                    let name = x.identifier.identifier_token.text();
+                    let r#type: SymType = (&*x.r#type).into();
+                    let width = self.type_width(&name, &r#type, scopes)?;
+                    let value = self.constant(&x.expression, scopes)?.resize(width);
+                    scopes
+                        .last_mut()
+                        .unwrap()
+                        .insert(name, Item::Constant(value));
+                }
+                ModuleItem::TypeDeclaration(x) => {
+                    let x = &x.type_declaration;
+                    let name = x.identifier.identifier_token.text();
+                    let r#type: SymType = (&*x.r#type).into();
+                    let width = self.type_width(&name, &r#type, scopes)?;
+                    scopes.last_mut().unwrap().insert(name, Item::Type(width));
+                }
+                _ => (),
+            }
+        }
+
+        for item in items {
+            match item {
+                ModuleItem::VarDeclaration(x) => {
+                    let x = &x.var_declaration;
+                    if let Some(ref y) = x.var_declaration_opt {
+                        let name = x.identifier.identifier_token.text();
+                        let target = self.target_by_name(&name, scopes)?;
+                        let expr = self.expression(&y.expression, scopes)?;
+                        let statement = ir::Statement::Assign(target, expr);
+                        self.combs.push(Comb::new(vec![statement]));
+                    }
+                }
+                ModuleItem::InstDeclaration(x) => {
+                    self.inst(&x.inst_declaration, prefix, scopes)?;
+                }
+                ModuleItem::AlwaysFfDeclaration(x) => {
+                    self.always_ff(&x.always_ff_declaration, scopes)?;
+                }
+                ModuleItem::AlwaysCombDeclaration(x) => {
+                    let list: Vec<_> = x
+                        .always_comb_declaration
+                        .always_comb_declaration_list
+                        .iter()
+                        .map(|x| &*x.statement)
+                        .collect();
+                    let statements = self.statements(&list, scopes)?;
+                    self.combs.push(Comb::new(statements));
+                }
+                ModuleItem::AssignDeclaration(x) => {
+                    let x = &x.assign_declaration;
+                    let target = self.target(&x.hierarchical_identifier, scopes)?;
+                    let expr = self.expression(&x.expression, scopes)?;
+                    let statement = ir::Statement::Assign(target, expr);
+                    self.combs.push(Comb::new(vec![statement]));
+                }
+                ModuleItem::ModuleIfDeclaration(x) => {
+                    let x = &x.module_if_declaration;
+                    let label = x.module_named_block.identifier.identifier_token.text();
+                    if self.constant(&x.expression, scopes)?.to_bool() == Some(true) {
+                        let items = named_block_items(&x.module_named_block);
+                        self.block(
+                            &items,
+                            &format!("{}{}.", prefix, label),
+                            scopes,
+                            Scope::new(),
+                        )?;
+                        continue;
+                    }
+
+                    let mut selected = None;
+                    for y in &x.module_if_declaration_list {
+                        if self.constant(&y.expression, scopes)?.to_bool() == Some(true) {
+                            selected = Some(&*y.module_optional_named_block);
+                            break;
+                        }
+                    }
+                    if selected.is_none() {
+                        if let Some(ref y) = x.module_if_declaration_opt {
+                            selected = Some(&*y.module_optional_named_block);
+                        }
+                    }
+                    if let Some(block) = selected {
+                        let label = match block.module_optional_named_block_opt {
+                            Some(ref x) => x.identifier.identifier_token.text(),
+                            None => label,
+                        };
+                        let items: Vec<_> = block
+                            .module_optional_named_block_list
+                            .iter()
+                            .map(|x| &*x.module_item)
+                            .collect();
+                        self.block(
+                            &items,
+                            &format!("{}{}.", prefix, label),
+                            scopes,
+                            Scope::new(),
+                        )?;
+                    }
+                }
+                ModuleItem::ModuleForDeclaration(x) => {
+                    let x = &x.module_for_declaration;
+                    let index = x.identifier.identifier_token.text();
+                    let label = x.module_named_block.identifier.identifier_token.text();
+                    let items = named_block_items(&x.module_named_block);
+                    let step = match x.module_for_declaration_opt {
+                        Some(ref x) => Some((
+                            &x.assignment_operator.assignment_operator_token,
+                            self.constant_u128(&x.expression, scopes)?,
+                        )),
+                        None => None,
+                    };
+                    let range = (
+                        self.constant_u128(&x.expression, scopes)?,
+                        self.constant_u128(&x.expression0, scopes)?,
+                    );
+
+                    for i in self.unroll(range, step)? {
+                        let mut scope = Scope::new();
+                        scope.insert(index.clone(), Item::Constant(Value::new(i, 32)));
+                        let prefix = format!("{}{}[{}].", prefix, label, i);
+                        self.block(&items, &prefix, scopes, scope)?;
+                    }
+                }
+                ModuleItem::ModuleNamedBlock(x) => {
+                    let x = &x.module_named_block;
+                    let label = x.identifier.identifier_token.text();
+                    let items = named_block_items(x);
+                    self.block(
+                        &items,
+                        &format!("{}{}.", prefix, label),
+                        scopes,
+                        Scope::new(),
+                    )?;
+                }
+                ModuleItem::InitialDeclaration(_) => {
+                    return Err(SimulatorError::Unsupported("initial block".to_string()));
+                }
+                ModuleItem::FinalDeclaration(_) => {
+                    return Err(SimulatorError::Unsupported("final block".to_string()));
+                }
+                // assertions are not checked during simulation yet
+                ModuleItem::AssertionDeclaration(_) => (),
+                // functions are elaborated at call site, but call is not supported yet
+                ModuleItem::FunctionDeclaration(_) => (),
+                // user-defined types are rejected when they are used
+                ModuleItem::EnumDeclaration(_) | ModuleItem::StructDeclaration(_) => (),
+                ModuleItem::LocalparamDeclaration(_)
+                | ModuleItem::TypeDeclaration(_)
+                | ModuleItem::ImportDeclaration(_) => (),
+            }
+        }
+        Ok(())
+    }
+
+    fn block(
+        &mut self,
+        items: &[&ModuleItem],
+        prefix: &str,
+        scopes: &mut Vec<Scope>,
+        scope: Scope,
+    ) -> Result<(), SimulatorError> {
+        scopes.push(scope);
+        let ret = self.items(items, prefix, scopes);
+        scopes.pop();
+        ret
+    }
+
+    /// Return loop indices of `for` from the range and the step
+    fn unroll(
+        &self,
+        range: (u128, u128),
+        step: Option<(&VerylToken, u128)>,
+    ) -> Result<Vec<u128>, SimulatorError> {
+        let (mut i, end) = range;
+        let mut ret = Vec::new();
+        while i < end {
+            ret.push(i);
+            if ret.len() as u128 > MAX_LOOP {
+                return Err(SimulatorError::Unsupported(
+                    "too many loop iterations".to_string(),
+                ));
+            }
+            let next = match step {
+                Some((op, x)) => match op.text().as_str() {
+                    "+=" => i.checked_add(x),
+                    "*=" => i.checked_mul(x),
+                    "<<=" => u32::try_from(x)
+                        .ok()
+                        .and_then(|x| i.checked_shl(x))
+                        .filter(|y| y >> x == i),
+                    op => return Err(SimulatorError::Unsupported(format!("step \"{}\"", op))),
+                },
+                None => i.checked_add(1),
+            };
+            i = next.ok_or_else(|| {
+                let op = step.map(|(op, x)| format!("{} {}", op.text(), x));
+                SimulatorError::LoopOverflow(op.unwrap_or_else(|| "+= 1".to_string()))
+            })?;
+        }
+        Ok(ret)
+    }
+
+    fn inst(
+        &mut self,
+        arg: &InstDeclaration,
+        prefix: &str,
+        scopes: &[Scope],
+    ) -> Result<(), SimulatorError> {
+        let name = arg.identifier.identifier_token.text();
+        let module = arg.identifier0.identifier_token.text();
+        if arg.inst_declaration_opt.is_some() {
+            return Err(SimulatorError::Unsupported("instance array".to_string()));
+        }
+
+        let overrides = self.overrides(arg, scopes)?;
+        self.instances.insert(format!("{}{}", prefix, name));
+        let ports = self.module(&module, &format!("{}{}.", prefix, name), overrides)?;
+
+        if let Some(ref x) = arg.inst_declaration_opt1 {
+            if let Some(ref x) = x.inst_declaration_opt2 {
+                let x = &x.inst_port_list;
+                let mut items = vec![&*x.inst_port_item];
+                for x in &x.inst_port_list_list {
+                    items.push(&*x.inst_port_item);
+                }
+                for item in items {
+                    let port = item.identifier.identifier_token.text();
+                    let (id, kind) = *ports.get(&port).ok_or_else(|| {
+                        SimulatorError::UndefinedIdentifier(format!("{}.{}", module, port))
+                    })?;
+                    let expr = match item.inst_port_item_opt {
+                        Some(ref x) => self.expression(&x.expression, scopes)?,
+                        None => self.identifier(&port, scopes)?,
+                    };
+                    let port = Expr {
+                        kind: ExprKind::Signal(id),
+                        width: self.signals[id].width,
+                    };
+                    let statement = match kind {
+                        SignalKind::Input => {
+                            let target = Target {
+                                id,
+                                select: TargetSelect::Full,
+                            };
+                            ir::Statement::Assign(target, expr)
+                        }
+                        _ => ir::Statement::Assign(expr_to_target(expr)?, port),
+                    };
+                    self.combs.push(Comb::new(vec![statement]));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Parameter values overridden by the instance
+    fn overrides(
+        &self,
+        arg: &InstDeclaration,
+        scopes: &[Scope],
+    ) -> Result<HashMap<String, Item>, SimulatorError> {
+        let mut overrides = HashMap::new();
+        if let Some(ref x) = arg.inst_declaration_opt0 {
+            if let Some(ref x) = x.inst_parameter.inst_parameter_opt {
+                let x = &x.inst_parameter_list;
+                let mut items = vec![&*x.inst_parameter_item];
+                for x in &x.inst_parameter_list_list {
+                    items.push(&*x.inst_parameter_item);
+                }
+                for item in items {
+                    let parameter = item.identifier.identifier_token.text();
+                    let value = match item.inst_parameter_item_opt {
+                        Some(ref x) => {
+                            match self.type_value(&parameter, &x.parameter_value, scopes)? {
+                                Some(y) => Item::Type(y),
+                                None => Item::Constant(self.parameter_constant(
+                                    &parameter,
+                                    &x.parameter_value,
+                                    scopes,
+                                )?),
+                            }
+                        }
+                        None => match self.lookup(&parameter, scopes) {
+                            Some(Item::Signal(_)) => {
+                                return Err(SimulatorError::NotConstant(parameter))
+                            }
+                            Some(x) => x.clone(),
+                            None => return Err(SimulatorError::UndefinedIdentifier(parameter)),
+                        },
+                    };
+                    overrides.insert(parameter, value);
+                }
+            }
+        }
+        Ok(overrides)
+    }
+
+    fn always_ff(
+        &mut self,
+        arg: &AlwaysFfDeclaration,
+        scopes: &[Scope],
+    ) -> Result<(), SimulatorError> {
+        let clock = &arg.always_ff_clock;
+        let posedge = match clock.always_ff_clock_opt {
+            Some(ref x) => matches!(
+                *x.always_ff_clock_opt_group,
+                AlwaysFfClockOptGroup::Posedge(_)
+            ),
+            None => self.clock_type == ClockType::PosEdge,
+        };
+        let clock = self.signal_id(&clock.hierarchical_identifier, scopes)?;
+
+        self.reset = match arg.always_ff_declaration_opt {
+            Some(ref x) => {
+                let reset = &x.always_ff_reset;
+                let reset_type = match reset.always_ff_reset_opt {
+                    Some(ref x) => match *x.always_ff_reset_opt_group {
+                        AlwaysFfResetOptGroup::AsyncLow(_) => ResetType::AsyncLow,
+                        AlwaysFfResetOptGroup::AsyncHigh(_) => ResetType::AsyncHigh,
+                        AlwaysFfResetOptGroup::SyncLow(_) => ResetType::SyncLow,
+                        AlwaysFfResetOptGroup::SyncHigh(_) => ResetType::SyncHigh,
+                    },
+                    None => self.reset_type,
+                };
+                let (asynchronous, active_high) = match reset_type {
+                    ResetType::AsyncLow => (true, false),
+                    ResetType::AsyncHigh => (true, true),
+                    ResetType::SyncLow => (false, false),
+                    ResetType::SyncHigh => (false, true),
+                };
+                Some(Reset {
+                    id: self.signal_id(&reset.hierarchical_identifier, scopes)?,
+                    asynchronous,
+                    active_high,
+                })
+            }
+            None => None,
+        };
+
+        let list: Vec<_> = arg
+            .always_ff_declaration_list
+            .iter()
+            .map(|x| &*x.statement)
+            .collect();
+        let mut scopes = scopes.to_vec();
+        let statements = self.statements(&list, &mut scopes)?;
+
+        self.ffs.push(Ff {
+            clock,
+            posedge,
+            reset: self.reset.take(),
+            statements,
+        });
+        Ok(())
+    }
+
+    fn statements(
+        &self,
+        arg: &[&Statement],
+        scopes: &mut Vec<Scope>,
+    ) -> Result<Vec<ir::Statement>, SimulatorError> {
+        let mut ret = Vec::new();
+        for x in arg {
+            self.statement(x, scopes, &mut ret)?;
+        }
+        Ok(ret)
+    }
+
+    fn statement(
+        &self,
+        arg: &Statement,
+        scopes: &mut Vec<Scope>,
+        ret: &mut Vec<ir::Statement>,
+    ) -> Result<(), SimulatorError> {
+        match arg {
+            Statement::AssignmentStatement(x) => {
+                ret.push(self.assignment_statement(&x.assignment_statement, scopes)?);
+            }
+            Statement::IfStatement(x) => {
+                let x = &x.if_statement;
+                let mut conds = vec![(
+                    self.expression(&x.expression, scopes)?,
+                    x.if_statement_list.iter().map(|x| &*x.statement).collect(),
+                )];
+                for y in &x.if_statement_list0 {
+                    conds.push((
+                        self.expression(&y.expression, scopes)?,
+                        y.if_statement_list0_list
+                            .iter()
+                            .map(|x| &*x.statement)
+                            .collect(),
+                    ));
+                }
+                let else_: Vec<_> = match x.if_statement_opt {
+                    Some(ref x) => x
+                        .if_statement_opt_list
+                        .iter()
+                        .map(|x| &*x.statement)
+                        .collect(),
+                    None => Vec::new(),
+                };
+                ret.push(self.if_chain(conds, &else_, scopes)?);
+            }
+            Statement::IfResetStatement(x) => {
+                let x = &x.if_reset_statement;
+                let reset = self.reset.ok_or_else(|| {
+                    SimulatorError::Unsupported("if_reset without reset".to_string())
+                })?;
+                let signal = Expr {
+                    kind: ExprKind::Signal(reset.id),
+                    width: self.signals[reset.id].width,
+                };
+                let active = if reset.active_high {
+                    signal
+                } else {
+                    Expr::unary(UnaryOp::LogicalNot, signal)
+                };
+
+                let mut conds = vec![(
+                    active,
+                    x.if_reset_statement_list
+                        .iter()
+                        .map(|x| &*x.statement)
+                        .collect(),
+                )];
+                for y in &x.if_reset_statement_list0 {
+                    conds.push((
+                        self.expression(&y.expression, scopes)?,
+                        y.if_reset_statement_list0_list
+                            .iter()
+                            .map(|x| &*x.statement)
+                            .collect(),
+                    ));
+                }
+                let else_: Vec<_> = match x.if_reset_statement_opt {
+                    Some(ref x) => x
+                        .if_reset_statement_opt_list
+                        .iter()
+                        .map(|x| &*x.statement)
+                        .collect(),
+                    None => Vec::new(),
+                };
+                ret.push(self.if_chain(conds, &else_, scopes)?);
+            }
+            Statement::ReturnStatement(_) => {
+                return Err(SimulatorError::Unsupported("return statement".to_string()));
+            }
+            // assertions are not checked during simulation yet
+            Statement::AssertionStatement(_) => (),
+            // system tasks are not executed during simulation yet
+            Statement::SystemTaskStatement(_) => (),
+            Statement::ForStatement(x) => {
+                let x = &x.for_statement;
+                let index = x.identifier.identifier_token.text();
+                let r#type: SymType = (&*x.r#type).into();
+                let width = self.type_width(&index, &r#type, scopes)?;
+                let step = match x.for_statement_opt {
+                    Some(ref x) => Some((
+                        &x.assignment_operator.assignment_operator_token,
+                        self.constant_u128(&x.expression, scopes)?,
+                    )),
+                    None => None,
+                };
+                let range = (
+                    self.constant_u128(&x.expression, scopes)?,
+                    self.constant_u128(&x.expression0, scopes)?,
+                );
+                let list: Vec<_> = x.for_statement_list.iter().map(|x| &*x.statement).collect();
+
+                for i in self.unroll(range, step)? {
+                    let mut scope = Scope::new();
+                    scope.insert(index.clone(), Item::Constant(Value::new(i, width)));
+                    scopes.push(scope);
+                    let statements = self.statements(&list, scopes);
+                    scopes.pop();
+                    ret.append(&mut statements?);
+                }
+            }
+            Statement::CaseStatement(x) => {
+                let x = &x.case_statement;
+                let sel = self.expression(&x.expression, scopes)?;
+                let mut items = Vec::new();
+                let mut default = Vec::new();
+                for y in &x.case_statement_list {
+                    let y = &y.case_item;
+                    let list: Vec<&Statement> = match &*y.case_item_group0 {
+                        CaseItemGroup0::Statement(x) => vec![&*x.statement],
+                        CaseItemGroup0::LBraceCaseItemGroup0ListRBrace(x) => x
+                            .case_item_group0_list
+                            .iter()
+                            .map(|x| &*x.statement)
+                            .collect(),
+                    };
+                    let statements = self.statements(&list, scopes)?;
+                    match &*y.case_item_group {
+                        CaseItemGroup::Expression(x) => {
+                            items.push((self.expression(&x.expression, scopes)?, statements))
+                        }
+                        CaseItemGroup::Defaul(_) => default = statements,
+                    }
+                }
+                ret.push(ir::Statement::Case(sel, items, default));
+            }
+        }
+        Ok(())
+    }
+
+    fn if_chain(
+        &self,
+        mut conds: Vec<(Expr, Vec<&Statement>)>,
+        else_: &[&Statement],
+        scopes: &mut Vec<Scope>,
+    ) -> Result<ir::Statement, SimulatorError> {
+        let mut statements = self.statements(else_, scopes)?;
+        while let Some((cond, list)) = conds.pop() {
+            let then = self.statements(&list, scopes)?;
+            statements = vec![ir::Statement::If(cond, then, statements)];
+        }
+        Ok(statements.pop().unwrap())
+    }
+
+    fn signal_id(
+        &self,
+        arg: &HierarchicalIdentifier,
+        scopes: &[Scope],
+    ) -> Result<usize, SimulatorError> {
+        let target = self.target(arg, scopes)?;
+        match target.select {
+            TargetSelect::Full => Ok(target.id),
+            _ => Err(SimulatorError::Unsupported(
+                "part-select of clock or reset".to_string(),
+            )),
+        }
+    }
+
+    fn assignment_statement(
+        &self,
+        arg: &AssignmentStatement,
+        scopes: &[Scope],
+    ) -> Result<ir::Statement, SimulatorError> {
+        let target = self.target(&arg.hierarchical_identifier, scopes)?;
+        let mut expr = self.expression(&arg.expression, scopes)?;
+        if let AssignmentStatementGroup::AssignmentOperator(x) = &*arg.assignment_statement_group {
+            let token = &x.assignment_operator.assignment_operator_token;
+            let op = token.text();
+            let op = BinaryOp::from_operator(op.trim_end_matches('='))
+                .ok_or_else(|| SimulatorError::Unsupported(format!("operator \"{}\"", op)))?;
+            expr = Expr::binary(op, self.target_expr(&target), expr);
+        }
+        Ok(ir::Statement::Assign(target, expr))
+    }
+
+    fn target_by_name(&self, name: &str, scopes: &[Scope]) -> Result<Target, SimulatorError> {
+        match self.lookup(name, scopes) {
+            Some(Item::Signal(id)) => Ok(Target {
+                id: *id,
+                select: TargetSelect::Full,
+            }),
+            Some(Item::Constant(_)) => Err(SimulatorError::Unsupported(format!(
+                "assignment to parameter \"{}\"",
+                name
+            ))),
+            Some(Item::Type(_)) => Err(SimulatorError::Unsupported(format!(
+                "assignment to type \"{}\"",
+                name
+            ))),
+            None => Err(SimulatorError::UndefinedIdentifier(name.to_string())),
+        }
+    }
+
+    fn target(
+        &self,
+        arg: &HierarchicalIdentifier,
+        scopes: &[Scope],
+    ) -> Result<Target, SimulatorError> {
+        let (name, ranges) = self.hierarchical_name(
+            arg.identifier.identifier_token.text(),
+            arg.hierarchical_identifier_list
+                .iter()
+                .map(|x| &*x.range)
+                .collect(),
+            arg.hierarchical_identifier_list0
+                .iter()
+                .map(|x| {
+                    let ranges = x
+                        .hierarchical_identifier_list0_list
+                        .iter()
+                        .map(|x| &*x.range)
+                        .collect();
+                    (x.identifier.identifier_token.text(), ranges)
+                })
+                .collect(),
+            scopes,
+        )?;
+        let target = self.target_by_name(&name, scopes)?;
+        let base = self.target_expr(&target);
+        expr_to_target(self.select(base, &ranges, scopes)?)
+    }
+
+    fn target_expr(&self, arg: &Target) -> Expr {
+        let signal = Expr {
+            kind: ExprKind::Signal(arg.id),
+            width: self.signals[arg.id].width,
+        };
+        match &arg.select {
+            TargetSelect::Full => signal,
+            TargetSelect::Const(lsb, width) => Expr {
+                kind: ExprKind::Select(Box::new(signal), *lsb),
+                width: *width,
+            },
+            TargetSelect::Dynamic(lsb, width) => Expr {
+                kind: ExprKind::DynSelect(Box::new(signal), Box::new(lsb.clone())),
+                width: *width,
+            },
+        }
+    }
+
+    /// Join hierarchical identifier like `u_dut.o_cnt` by `.`, and return it with the ranges of the last part.
+    /// Signals in instances are visible only from test through the hierarchical name.
+    fn hierarchical_name<'b>(
+        &self,
+        name: String,
+        ranges: Vec<&'b Range>,
+        rest: Vec<(String, Vec<&'b Range>)>,
+        scopes: &[Scope],
+    ) -> Result<(String, Vec<&'b Range>), SimulatorError> {
+        if rest.is_empty() {
+            return Ok((name, ranges));
+        }
+
+        let unsupported = || SimulatorError::Unsupported("hierarchical reference".to_string());
+        if !ranges.is_empty() || rest[..rest.len() - 1].iter().any(|x| !x.1.is_empty()) {
+            return Err(unsupported());
+        }
+
+        let mut name = name;
+        let mut ranges = Vec::new();
+        for (x, y) in rest {
+            name.push('.');
+            name.push_str(&x);
+            ranges = y;
+        }
+        if self.lookup(&name, scopes).is_none() {
+            return Err(unsupported());
+        }
+        Ok((name, ranges))
+    }
+
+    fn identifier(&self, name: &str, scopes: &[Scope]) -> Result<Expr, SimulatorError> {
+        match self.lookup(name, scopes) {
+            Some(Item::Signal(id)) => Ok(Expr {
+                kind: ExprKind::Signal(*id),
+                width: self.signals[*id].width,
+            }),
+            Some(Item::Constant(x)) => Ok(Expr::constant(*x)),
+            Some(Item::Type(_)) => Err(SimulatorError::Unsupported(format!(
+                "type \"{}\" in expression",
+                name
+            ))),
+            None => Err(SimulatorError::UndefinedIdentifier(name.to_string())),
+        }
+    }
+
+    fn select(
+        &self,
+        base: Expr,
+        ranges: &[&Range],
+        scopes: &[Scope],
+    ) -> Result<Expr, SimulatorError> {
+        let range = match ranges {
+            [] => return Ok(base),
+            [x] => x,
+            _ => {
+                return Err(SimulatorError::Unsupported(
+                    "multi-dimensional select".to_string(),
+                ))
+            }
+        };
+
+        let x = self.expression(&range.expression, scopes)?;
+        let (lsb, width) = match range.range_opt {
+            Some(ref y) => {
+                let y = self.expression(&y.expression, scopes)?;
+                let op = &range.range_opt.as_ref().unwrap().range_operator;
+                let y_value = y
+                    .to_constant()
+                    .and_then(|x| x.to_u128())
+                    .ok_or_else(|| SimulatorError::NotConstant(stringify(&range.expression)))?;
+                match &**op {
+                    RangeOperator::Colon(_) => {
+                        let x_value =
+                            x.to_constant().and_then(|x| x.to_u128()).ok_or_else(|| {
+                                SimulatorError::NotConstant(stringify(&range.expression))
+                            })?;
+                        if x_value < y_value {
+                            return Err(SimulatorError::Unsupported("reversed range".to_string()));
+                        }
+                        (y, x_value - y_value + 1)
+                    }
+                    RangeOperator::PlusColon(_) => (x, y_value),
+                    RangeOperator::MinusColon(_) => {
+                        let offset = Expr::constant(Value::new(y_value.saturating_sub(1), 32));
+                        (Expr::binary(BinaryOp::Sub, x, offset), y_value)
+                    }
+                    RangeOperator::Step(_) => {
+                        let step = Expr::constant(Value::new(y_value, 32));
+                        (Expr::binary(BinaryOp::Mul, x, step), y_value)
+                    }
+                }
+            }
+            None => (x, 1),
+        };
+
+        let width = width as usize;
+        let kind = match lsb.to_constant().and_then(|x| x.to_u128()) {
+            Some(lsb) => ExprKind::Select(Box::new(base), lsb as usize),
+            None => ExprKind::DynSelect(Box::new(base), Box::new(lsb)),
+        };
+        Ok(Expr { kind, width })
+    }
+
+    fn binary(&self, op: &VerylToken, x: Expr, y: Expr) -> Result<Expr, SimulatorError> {
+        let text = op.text();
+        let op = BinaryOp::from_operator(&text)
+            .ok_or_else(|| SimulatorError::Unsupported(format!("operator \"{}\"", text)))?;
+        Ok(Expr::binary(op, x, y))
+    }
+
+    fn expression(&self, arg: &Expression, scopes: &[Scope]) -> Result<Expr, SimulatorError> {
+        match arg {
+            Expression::IfExpression(x) => self.if_expression(&x.if_expression, scopes),
+            Expression::Expression01(x) => self.expression01(&x.expression01, scopes),
+        }
+    }
+
+    fn if_expression(&self, arg: &IfExpression, scopes: &[Scope]) -> Result<Expr, SimulatorError> {
+        let mut branches = vec![(&arg.expression, &arg.expression0)];
+        for x in &arg.if_expression_list {
+            branches.push((&x.expression, &x.expression0));
+        }
+
+        let mut ret = self.expression(&arg.expression1, scopes)?;
+        for (cond, value) in branches.into_iter().rev() {
+            let cond = self.expression(cond, scopes)?;
+            let value = self.expression(value, scopes)?;
+            ret = Expr::cond(cond, value, ret);
+        }
+        Ok(ret)
+    }
+
+    fn expression01(&self, arg: &Expression01, scopes: &[Scope]) -> Result<Expr, SimulatorError> {
+        let mut ret = self.expression02(&arg.expression02, scopes)?;
+        for x in &arg.expression01_list {
+            let y = self.expression02(&x.expression02, scopes)?;
+            ret = self.binary(&x.operator01.operator01_token, ret, y)?;
+        }
+        Ok(ret)
+    }
+
+    fn expression02(&self, arg: &Expression02, scopes: &[Scope]) -> Result<Expr, SimulatorError> {
+        let mut ret = self.expression03(&arg.expression03, scopes)?;
+        for x in &arg.expression02_list {
+            let y = self.expression03(&x.expression03, scopes)?;
+            ret = self.binary(&x.operator02.operator02_token, ret, y)?;
+        }
+        Ok(ret)
+    }
+
+    fn expression03(&self, arg: &Expression03, scopes: &[Scope]) -> Result<Expr, SimulatorError> {
+        let mut ret = self.expression04(&arg.expression04, scopes)?;
+        for x in &arg.expression03_list {
+            let y = self.expression04(&x.expression04, scopes)?;
+            ret = self.binary(&x.operator03.operator03_token, ret, y)?;
+        }
+        Ok(ret)
+    }
+
+    fn expression04(&self, arg: &Expression04, scopes: &[Scope]) -> Result<Expr, SimulatorError> {
+        let mut ret = self.expression05(&arg.expression05, scopes)?;
+        for x in &arg.expression04_list {
+            let y = self.expression05(&x.expression05, scopes)?;
+            ret = self.binary(&x.operator04.operator04_token, ret, y)?;
+        }
+        Ok(ret)
+    }
+
+    fn expression05(&self, arg: &Expression05, scopes: &[Scope]) -> Result<Expr, SimulatorError> {
+        let mut ret = self.expression06(&arg.expression06, scopes)?;
+        for x in &arg.expression05_list {
+            let y = self.expression06(&x.expression06, scopes)?;
+            ret = self.binary(&x.operator05.operator05_token, ret, y)?;
+        }
+        Ok(ret)
+    }
+
+    fn expression06(&self, arg: &Expression06, scopes: &[Scope]) -> Result<Expr, SimulatorError> {
+        let mut ret = self.expression07(&arg.expression07, scopes)?;
+        for x in &arg.expression06_list {
+            let y = self.expression07(&x.expression07, scopes)?;
+            ret = self.binary(&x.operator06.operator06_token, ret, y)?;
+        }
+        Ok(ret)
+    }
+
+    fn expression07(&self, arg: &Expression07, scopes: &[Scope]) -> Result<Expr, SimulatorError> {
+        let mut ret = self.expression08(&arg.expression08, scopes)?;
+        for x in &arg.expression07_list {
+            let y = self.expression08(&x.expression08, scopes)?;
+            ret = self.binary(&x.operator07.operator07_token, ret, y)?;
+        }
+        Ok(ret)
+    }
+
+    fn expression08(&self, arg: &Expression08, scopes: &[Scope]) -> Result<Expr, SimulatorError> {
+        let mut ret = self.expression09(&arg.expression09, scopes)?;
+        for x in &arg.expression08_list {
+            let y = self.expression09(&x.expression09, scopes)?;
+            ret = self.binary(&x.operator08.operator08_token, ret, y)?;
+        }
+        Ok(ret)
+    }
+
+    fn expression09(&self, arg: &Expression09, scopes: &[Scope]) -> Result<Expr, SimulatorError> {
+        let mut ret = self.expression10(&arg.expression10, scopes)?;
+        for x in &arg.expression09_list {
+            let y = self.expression10(&x.expression10, scopes)?;
+            ret = self.binary(&x.operator09.operator09_token, ret, y)?;
+        }
+        Ok(ret)
+    }
+
+    fn expression10(&self, arg: &Expression10, scopes: &[Scope]) -> Result<Expr, SimulatorError> {
+        let mut ret = self.expression11(&arg.expression11, scopes)?;
+        for x in &arg.expression10_list {
+            let y = self.expression11(&x.expression11, scopes)?;
+            let op = match &*x.expression10_list_group {
+                Expression10ListGroup::Operator10(x) => &x.operator10.operator10_token,
+                Expression10ListGroup::Star(x) => &x.star.star_token,
+            };
+            ret = self.binary(op, ret, y)?;
+        }
+        Ok(ret)
+    }
+
+    fn expression11(&self, arg: &Expression11, scopes: &[Scope]) -> Result<Expr, SimulatorError> {
+        let mut ret = self.expression12(&arg.expression12, scopes)?;
+        for x in &arg.expression11_list {
+            let y = self.expression12(&x.expression12, scopes)?;
+            ret = self.binary(&x.operator11.operator11_token, ret, y)?;
+        }
+        Ok(ret)
+    }
+
+    fn expression12(&self, arg: &Expression12, scopes: &[Scope]) -> Result<Expr, SimulatorError> {
+        if arg.expression12_opt.is_some() {
+            return Err(SimulatorError::Unsupported("cast expression".to_string()));
+        }
+        let mut ret = self.factor(&arg.factor, scopes)?;
+        for x in arg.expression12_list.iter().rev() {
+            let op = match &*x.expression12_list_group {
+                Expression12ListGroup::UnaryOperator(x) => &x.unary_operator.unary_operator_token,
+                Expression12ListGroup::Operator09(x) => &x.operator09.operator09_token,
+                Expression12ListGroup::Operator05(x) => &x.operator05.operator05_token,
+                Expression12ListGroup::Operator03(x) => &x.operator03.operator03_token,
+                Expression12ListGroup::Operator04(x) => &x.operator04.operator04_token,
+            };
+            let text = op.text();
+            let op = UnaryOp::from_operator(&text)
+                .ok_or_else(|| SimulatorError::Unsupported(format!("operator \"{}\"", text)))?;
+            ret = Expr::unary(op, ret);
+        }
+        Ok(ret)
+    }
+
+    fn factor(&self, arg: &Factor, scopes: &[Scope]) -> Result<Expr, SimulatorError> {
+        match arg {
+            Factor::Number(x) => match &*x.number {
+                Number::IntegralNumber(x) => match &*x.integral_number {
+                    IntegralNumber::Based(x) => {
+                        Ok(Expr::constant(x.based.based_token.text().parse()?))
+                    }
+                    IntegralNumber::BaseLess(x) => {
+                        Ok(Expr::constant(x.base_less.base_less_token.text().parse()?))
+                    }
+                    IntegralNumber::AllBit(x) => {
+                        let value: Value = x.all_bit.all_bit_token.text().parse()?;
+                        Ok(Expr {
+                            kind: ExprKind::Fill(value),
+                            width: 1,
+                        })
+                    }
+                },
+                Number::RealNumber(_) => {
+                    Err(SimulatorError::Unsupported("real number".to_string()))
+                }
+            },
+            Factor::ScopedOrHierIdentifierFactorOpt(x) => {
+                let identifier = &x.scoped_or_hier_identifier;
+                let name = identifier.identifier.identifier_token.text();
+
+                if x.factor_opt.is_some() {
+                    return Err(SimulatorError::Unsupported(format!(
+                        "function call \"{}\"",
+                        name
+                    )));
+                }
+
+                match &*identifier.scoped_or_hier_identifier_group {
+                    ScopedOrHierIdentifierGroup::ScopedOrHierIdentifierGroupList0ScopedOrHierIdentifierGroupList1(y) => {
+                        let (name, ranges) = self.hierarchical_name(
+                            name,
+                            y.scoped_or_hier_identifier_group_list0
+                                .iter()
+                                .map(|x| &*x.range)
+                                .collect(),
+                            y.scoped_or_hier_identifier_group_list1
+                                .iter()
+                                .map(|x| {
+                                    let ranges = x
+                                        .scoped_or_hier_identifier_group_list1_list
+                                        .iter()
+                                        .map(|x| &*x.range)
+                                        .collect();
+                                    (x.identifier.identifier_token.text(), ranges)
+                                })
+                                .collect(),
+                            scopes,
+                        )?;
+                        let base = self.identifier(&name, scopes)?;
+                        self.select(base, &ranges, scopes)
+                    }
+                    ScopedOrHierIdentifierGroup::ColonColonIdentifierScopedOrHierIdentifierGroupList(_) => {
+                        Err(SimulatorError::Unsupported("package reference".to_string()))
+                    }
+                }
+            }
+            Factor::SystemFunctionCall(x) => {
+                let x = &x.system_function_call;
+                let name = x.dollar_identifier.dollar_identifier_token.text();
+                if name == "$clog2" && x.system_function_call_opt.is_some() {
+                    return self.clog2(x, scopes);
+                }
+                Err(SimulatorError::Unsupported(format!(
+                    "function call \"{}\"",
+                    name
+                )))
+            }
+            Factor::LParenExpressionRParen(x) => self.expression(&x.expression, scopes),
+            Factor::StringLiteral(_) => {
+                Err(SimulatorError::Unsupported("string literal".to_string()))
+            }
+            Factor::LBraceConcatenationListRBrace(x) => {
+                let x = &x.concatenation_list;
+                let mut items = vec![&*x.concatenation_item];
+                for x in &x.concatenation_list_list {
+                    items.push(&*x.concatenation_item);
+                }
+
+                let mut list = Vec::new();
+                for item in items {
+                    let expr = self.expression(&item.expression, scopes)?;
+                    let repeat = match item.concatenation_item_opt {
+                        Some(ref x) => self.constant_u128(&x.expression, scopes)?,
+                        None => 1,
+                    };
+                    for _ in 0..repeat {
+                        list.push(expr.clone());
+                    }
+                }
+                let width: usize = list.iter().map(|x| x.width).sum();
+                if width > MAX_WIDTH {
+                    return Err(SimulatorError::TooWide("concatenation".to_string()));
+                }
+                Ok(Expr {
+                    kind: ExprKind::Concat(list),
+                    width,
+                })
+            }
+        }
+    }
+
+    fn clog2(&self, arg: &SystemFunctionCall, scopes: &[Scope]) -> Result<Expr, SimulatorError> {
+        let args = arg
+            .system_function_call_opt
+            .as_ref()
+            .and_then(|x| x.system_function_call_opt0.as_ref())
+            .ok_or_else(|| SimulatorError::Unsupported("$clog2 without argument".to_string()))?;
+        let x = self.constant_u128(&args.function_call_arg.expression, scopes)?;
+        let ret = if x <= 1 {
+            0
+        } else {
+            128 - (x - 1).leading_zeros() as u128
+        };
+        Ok(Expr::constant(Value::new(ret, 32)))
+    }
+}
+
+fn named_block_items(arg: &ModuleNamedBlock) -> Vec<&ModuleItem> {
+    arg.module_named_block_list
+        .iter()
+        .map(|x| &*x.module_item)
+        .collect()
+}
+
+fn expr_to_target(arg: Expr) -> Result<Target, SimulatorError> {
+    let error = || SimulatorError::Unsupported("assignment to expression".to_string());
+    let width = arg.width;
+    match arg.kind {
+        ExprKind::Signal(id) => Ok(Target {
+            id,
+            select: TargetSelect::Full,
+        }),
+        ExprKind::Select(x, lsb) => match x.kind {
+            ExprKind::Signal(id) => Ok(Target {
+                id,
+                select: TargetSelect::Const(lsb, width),
+            }),
+            _ => Err(error()),
+        },
+        ExprKind::DynSelect(x, lsb) => match x.kind {
+            ExprKind::Signal(id) => Ok(Target {
+                id,
+                select: TargetSelect::Dynamic(*lsb, width),
+            }),
+            _ => Err(error()),
+        },
+        _ => Err(error()),
+    }
+}
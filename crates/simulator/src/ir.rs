@@ -0,0 +1,500 @@
+use crate::value::Value;
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnaryOp {
+    Not,
+    Neg,
+    Pos,
+    LogicalNot,
+    ReduceAnd,
+    ReduceOr,
+    ReduceXor,
+    ReduceNand,
+    ReduceNor,
+    ReduceXnor,
+}
+
+impl UnaryOp {
+    pub fn from_operator(text: &str) -> Option<Self> {
+        let ret = match text {
+            "~" => UnaryOp::Not,
+            "-" => UnaryOp::Neg,
+            "+" => UnaryOp::Pos,
+            "!" => UnaryOp::LogicalNot,
+            "&" => UnaryOp::ReduceAnd,
+            "|" => UnaryOp::ReduceOr,
+            "^" => UnaryOp::ReduceXor,
+            "~&" => UnaryOp::ReduceNand,
+            "~|" => UnaryOp::ReduceNor,
+            "~^" | "^~" => UnaryOp::ReduceXnor,
+            _ => return None,
+        };
+        Some(ret)
+    }
+
+    /// Whether the operand width is affected by the context
+    fn is_context(&self) -> bool {
+        matches!(self, UnaryOp::Not | UnaryOp::Neg | UnaryOp::Pos)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryOp {
+    LogicalOr,
+    LogicalAnd,
+    Or,
+    Xor,
+    Xnor,
+    And,
+    Eq,
+    Ne,
+    CaseEq,
+    CaseNe,
+    WildcardEq,
+    WildcardNe,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Shl,
+    Shr,
+    Ashl,
+    Ashr,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+}
+
+impl BinaryOp {
+    pub fn from_operator(text: &str) -> Option<Self> {
+        let ret = match text {
+            "||" => BinaryOp::LogicalOr,
+            "&&" => BinaryOp::LogicalAnd,
+            "|" => BinaryOp::Or,
+            "^" => BinaryOp::Xor,
+            "~^" | "^~" => BinaryOp::Xnor,
+            "&" => BinaryOp::And,
+            "==" => BinaryOp::Eq,
+            "!=" => BinaryOp::Ne,
+            "===" => BinaryOp::CaseEq,
+            "!==" => BinaryOp::CaseNe,
+            "==?" => BinaryOp::WildcardEq,
+            "!=?" => BinaryOp::WildcardNe,
+            "<" => BinaryOp::Lt,
+            "<=" => BinaryOp::Le,
+            ">" => BinaryOp::Gt,
+            ">=" => BinaryOp::Ge,
+            "<<" => BinaryOp::Shl,
+            ">>" => BinaryOp::Shr,
+            "<<<" => BinaryOp::Ashl,
+            ">>>" => BinaryOp::Ashr,
+            "+" => BinaryOp::Add,
+            "-" => BinaryOp::Sub,
+            "*" => BinaryOp::Mul,
+            "/" => BinaryOp::Div,
+            "%" => BinaryOp::Rem,
+            "**" => BinaryOp::Pow,
+            _ => return None,
+        };
+        Some(ret)
+    }
+
+    fn is_logical(&self) -> bool {
+        matches!(self, BinaryOp::LogicalOr | BinaryOp::LogicalAnd)
+    }
+
+    fn is_compare(&self) -> bool {
+        matches!(
+            self,
+            BinaryOp::Eq
+                | BinaryOp::Ne
+                | BinaryOp::CaseEq
+                | BinaryOp::CaseNe
+                | BinaryOp::WildcardEq
+                | BinaryOp::WildcardNe
+                | BinaryOp::Lt
+                | BinaryOp::Le
+                | BinaryOp::Gt
+                | BinaryOp::Ge
+        )
+    }
+
+    fn is_shift(&self) -> bool {
+        matches!(
+            self,
+            BinaryOp::Shl | BinaryOp::Shr | BinaryOp::Ashl | BinaryOp::Ashr | BinaryOp::Pow
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum ExprKind {
+    Const(Value),
+    /// Unsized literal like `'1` which is extended to the context width
+    Fill(Value),
+    Signal(usize),
+    /// Constant part-select with LSB
+    Select(Box<Expr>, usize),
+    /// Variable part-select with LSB expression
+    DynSelect(Box<Expr>, Box<Expr>),
+    Unary(UnaryOp, Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    /// Concatenation from MSB
+    Concat(Vec<Expr>),
+    /// Conditional operator with condition, true and false values
+    Cond(Box<Expr>, Box<Expr>, Box<Expr>),
+}
+
+/// Expression with self-determined width
+#[derive(Clone, Debug)]
+pub struct Expr {
+    pub kind: ExprKind,
+    pub width: usize,
+}
+
+impl Expr {
+    pub fn constant(value: Value) -> Self {
+        Self {
+            width: value.width(),
+            kind: ExprKind::Const(value),
+        }
+    }
+
+    pub fn unary(op: UnaryOp, x: Expr) -> Self {
+        let width = if op.is_context() { x.width } else { 1 };
+        Self {
+            kind: ExprKind::Unary(op, Box::new(x)),
+            width,
+        }
+    }
+
+    pub fn binary(op: BinaryOp, x: Expr, y: Expr) -> Self {
+        let width = if op.is_logical() || op.is_compare() {
+            1
+        } else if op.is_shift() {
+            x.width
+        } else {
+            x.width.max(y.width)
+        };
+        Self {
+            kind: ExprKind::Binary(op, Box::new(x), Box::new(y)),
+            width,
+        }
+    }
+
+    pub fn cond(x: Expr, y: Expr, z: Expr) -> Self {
+        let width = y.width.max(z.width);
+        Self {
+            kind: ExprKind::Cond(Box::new(x), Box::new(y), Box::new(z)),
+            width,
+        }
+    }
+
+    /// Return the value if it doesn't depend on any signal
+    pub fn to_constant(&self) -> Option<Value> {
+        if self.signals().is_empty() {
+            Some(self.eval(&[], 0))
+        } else {
+            None
+        }
+    }
+
+    pub fn signals(&self) -> Vec<usize> {
+        let mut ret = Vec::new();
+        self.gather_signals(&mut ret);
+        ret
+    }
+
+    fn gather_signals(&self, ret: &mut Vec<usize>) {
+        match &self.kind {
+            ExprKind::Const(_) | ExprKind::Fill(_) => (),
+            ExprKind::Signal(x) => ret.push(*x),
+            ExprKind::Select(x, _) | ExprKind::Unary(_, x) => x.gather_signals(ret),
+            ExprKind::DynSelect(x, y) | ExprKind::Binary(_, x, y) => {
+                x.gather_signals(ret);
+                y.gather_signals(ret);
+            }
+            ExprKind::Concat(x) => {
+                for x in x {
+                    x.gather_signals(ret);
+                }
+            }
+            ExprKind::Cond(x, y, z) => {
+                x.gather_signals(ret);
+                y.gather_signals(ret);
+                z.gather_signals(ret);
+            }
+        }
+    }
+
+    /// Evaluate with the context width
+    pub fn eval(&self, values: &[Value], context: usize) -> Value {
+        let width = self.width.max(context);
+        match &self.kind {
+            ExprKind::Const(x) => x.resize(width),
+            ExprKind::Fill(x) => x.fill(width),
+            ExprKind::Signal(x) => values[*x].resize(width),
+            ExprKind::Select(x, lsb) => x.eval(values, 0).slice(*lsb, self.width).resize(width),
+            ExprKind::DynSelect(x, lsb) => {
+                let value = match lsb.eval(values, 0).to_u128() {
+                    Some(lsb) => x.eval(values, 0).slice(lsb as usize, self.width),
+                    None => Value::x(self.width),
+                };
+                value.resize(width)
+            }
+            ExprKind::Unary(op, x) => match op {
+                UnaryOp::Not => x.eval(values, width).not(),
+                UnaryOp::Neg => x.eval(values, width).neg(),
+                UnaryOp::Pos => x.eval(values, width),
+                UnaryOp::LogicalNot => x.eval(values, 0).logical_not().resize(width),
+                UnaryOp::ReduceAnd => x.eval(values, 0).reduce_and().resize(width),
+                UnaryOp::ReduceOr => x.eval(values, 0).reduce_or().resize(width),
+                UnaryOp::ReduceXor => x.eval(values, 0).reduce_xor().resize(width),
+                UnaryOp::ReduceNand => x.eval(values, 0).reduce_and().not().resize(width),
+                UnaryOp::ReduceNor => x.eval(values, 0).reduce_or().not().resize(width),
+                UnaryOp::ReduceXnor => x.eval(values, 0).reduce_xor().not().resize(width),
+            },
+            ExprKind::Binary(op, x, y) => {
+                if op.is_logical() {
+                    let x = x.eval(values, 0);
+                    let y = y.eval(values, 0);
+                    let ret = match op {
+                        BinaryOp::LogicalOr => x.logical_or(&y),
+                        _ => x.logical_and(&y),
+                    };
+                    ret.resize(width)
+                } else if op.is_compare() {
+                    let operand = x.width.max(y.width);
+                    let x = x.eval(values, operand);
+                    let y = y.eval(values, operand);
+                    let ret = match op {
+                        BinaryOp::Eq => x.eq(&y),
+                        BinaryOp::Ne => x.eq(&y).not(),
+                        BinaryOp::CaseEq => x.case_eq(&y),
+                        BinaryOp::CaseNe => x.case_eq(&y).not(),
+                        BinaryOp::WildcardEq => x.wildcard_eq(&y),
+                        BinaryOp::WildcardNe => x.wildcard_eq(&y).not(),
+                        BinaryOp::Lt => x.lt(&y),
+                        BinaryOp::Le => x.le(&y),
+                        BinaryOp::Gt => y.lt(&x),
+                        _ => y.le(&x),
+                    };
+                    ret.resize(width)
+                } else if op.is_shift() {
+                    let x = x.eval(values, width);
+                    let y = y.eval(values, 0);
+                    match op {
+                        BinaryOp::Shl | BinaryOp::Ashl => x.shl(&y),
+                        BinaryOp::Shr | BinaryOp::Ashr => x.shr(&y),
+                        _ => x.pow(&y, width),
+                    }
+                } else {
+                    let x = x.eval(values, width);
+                    let y = y.eval(values, width);
+                    match op {
+                        BinaryOp::Or => x.or(&y),
+                        BinaryOp::Xor => x.xor(&y),
+                        BinaryOp::Xnor => x.xor(&y).not(),
+                        BinaryOp::And => x.and(&y),
+                        BinaryOp::Add => x.add(&y, width),
+                        BinaryOp::Sub => x.sub(&y, width),
+                        BinaryOp::Mul => x.mul(&y, width),
+                        BinaryOp::Div => x.div(&y, width),
+                        _ => x.rem(&y, width),
+                    }
+                }
+            }
+            ExprKind::Concat(x) => {
+                let x: Vec<_> = x.iter().map(|x| x.eval(values, 0)).collect();
+                Value::concat(&x).resize(width)
+            }
+            ExprKind::Cond(x, y, z) => match x.eval(values, 0).to_bool() {
+                Some(true) => y.eval(values, width),
+                Some(false) => z.eval(values, width),
+                None => Value::x(width),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum TargetSelect {
+    Full,
+    /// LSB and width
+    Const(usize, usize),
+    /// LSB expression and width
+    Dynamic(Expr, usize),
+}
+
+#[derive(Clone, Debug)]
+pub struct Target {
+    pub id: usize,
+    pub select: TargetSelect,
+}
+
+#[derive(Clone, Debug)]
+pub enum Statement {
+    Assign(Target, Expr),
+    If(Expr, Vec<Statement>, Vec<Statement>),
+    Case(Expr, Vec<(Expr, Vec<Statement>)>, Vec<Statement>),
+}
+
+impl Statement {
+    pub fn gather(&self, reads: &mut Vec<usize>, writes: &mut Vec<usize>) {
+        match self {
+            Statement::Assign(target, x) => {
+                if let TargetSelect::Dynamic(x, _) = &target.select {
+                    reads.append(&mut x.signals());
+                }
+                reads.append(&mut x.signals());
+                writes.push(target.id);
+            }
+            Statement::If(x, y, z) => {
+                reads.append(&mut x.signals());
+                for x in y.iter().chain(z.iter()) {
+                    x.gather(reads, writes);
+                }
+            }
+            Statement::Case(x, y, z) => {
+                reads.append(&mut x.signals());
+                for (x, y) in y {
+                    reads.append(&mut x.signals());
+                    for x in y {
+                        x.gather(reads, writes);
+                    }
+                }
+                for x in z {
+                    x.gather(reads, writes);
+                }
+            }
+        }
+    }
+}
+
+/// Execution context of statements
+///
+/// Assignments are applied to `values` immediately in combinational processes,
+/// and are deferred to `pending` in sequential processes.
+pub struct Context<'a> {
+    pub values: &'a mut [Value],
+    pub pending: Option<HashMap<usize, Value>>,
+}
+
+impl<'a> Context<'a> {
+    pub fn execute(&mut self, statements: &[Statement]) {
+        for x in statements {
+            self.statement(x);
+        }
+    }
+
+    fn statement(&mut self, arg: &Statement) {
+        match arg {
+            Statement::Assign(target, x) => {
+                let current = match self.pending {
+                    Some(ref pending) => pending.get(&target.id).unwrap_or(&self.values[target.id]),
+                    None => &self.values[target.id],
+                };
+                let mut current = *current;
+                match &target.select {
+                    TargetSelect::Full => {
+                        current = x.eval(self.values, current.width()).resize(current.width());
+                    }
+                    TargetSelect::Const(lsb, width) => {
+                        let value = x.eval(self.values, *width).resize(*width);
+                        current.set_slice(*lsb, &value);
+                    }
+                    TargetSelect::Dynamic(lsb, width) => {
+                        // assignment to unknown index is ignored
+                        if let Some(lsb) = lsb.eval(self.values, 0).to_u128() {
+                            let value = x.eval(self.values, *width).resize(*width);
+                            current.set_slice(lsb as usize, &value);
+                        }
+                    }
+                }
+                match self.pending {
+                    Some(ref mut pending) => {
+                        pending.insert(target.id, current);
+                    }
+                    None => self.values[target.id] = current,
+                }
+            }
+            Statement::If(x, y, z) => {
+                if x.eval(self.values, 0).to_bool() == Some(true) {
+                    self.execute(y);
+                } else {
+                    self.execute(z);
+                }
+            }
+            Statement::Case(x, y, z) => {
+                for (cond, statements) in y {
+                    let width = x.width.max(cond.width);
+                    let sel = x.eval(self.values, width);
+                    let cond = cond.eval(self.values, width);
+                    if sel.case_eq(&cond).to_bool() == Some(true) {
+                        self.execute(statements);
+                        return;
+                    }
+                }
+                self.execute(z);
+            }
+        }
+    }
+}
+
+/// Process for `always_comb`, `assign` and port connection
+#[derive(Clone, Debug)]
+pub struct Comb {
+    pub statements: Vec<Statement>,
+    pub reads: Vec<usize>,
+    pub writes: Vec<usize>,
+}
+
+impl Comb {
+    pub fn new(statements: Vec<Statement>) -> Self {
+        let mut reads = Vec::new();
+        let mut writes = Vec::new();
+        for x in &statements {
+            x.gather(&mut reads, &mut writes);
+        }
+        reads.sort_unstable();
+        reads.dedup();
+        writes.sort_unstable();
+        writes.dedup();
+        Self {
+            statements,
+            reads,
+            writes,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Reset {
+    pub id: usize,
+    pub asynchronous: bool,
+    pub active_high: bool,
+}
+
+/// Process for `always_ff`
+#[derive(Clone, Debug)]
+pub struct Ff {
+    pub clock: usize,
+    pub posedge: bool,
+    pub reset: Option<Reset>,
+    pub statements: Vec<Statement>,
+}
+
+/// Operation of test
+#[derive(Clone, Debug)]
+pub enum TestItem {
+    /// Assignment to an input port
+    Assign(Statement),
+    /// Clock and the count of cycles
+    Step(usize, u128),
+    /// Expression which should be true, and the source text of it
+    Expect(Expr, String),
+}
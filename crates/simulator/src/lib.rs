@@ -0,0 +1,10 @@
+pub mod elaborator;
+pub mod ir;
+pub mod simulator;
+pub mod simulator_error;
+pub mod value;
+pub mod wave;
+pub use simulator::Simulator;
+pub use simulator_error::SimulatorError;
+pub use value::Value;
+pub use wave::{Wave, WaveFormat, WaveOption};
@@ -0,0 +1,609 @@
+use crate::elaborator::{Elaborator, Signal, SignalKind};
+use crate::ir::{Comb, Context, Ff, TestItem};
+use crate::simulator_error::SimulatorError;
+use crate::value::Value;
+use crate::wave::{Wave, WaveOption};
+use std::collections::{HashMap, HashSet, VecDeque};
+use veryl_metadata::{ClockType, Metadata, ResetType};
+use veryl_parser::veryl_grammar_trait::*;
+
+/// The maximum count of re-evaluation caused by derived clocks
+const MAX_DELTA: usize = 1024;
+
+/// Cycle-based simulator
+///
+/// Modules are registered by [`Simulator::add`] and the top module is elaborated by
+/// [`Simulator::elaborate`] with the symbol table created by the analyzer.
+/// Inputs changed by [`Simulator::poke`] are propagated by [`Simulator::eval`].
+/// `time` is advanced by [`Simulator::step`], and used as timestamp of waveform.
+pub struct Simulator {
+    pub clock_type: ClockType,
+    pub reset_type: ResetType,
+    pub time: u64,
+    modules: HashMap<String, ModuleDeclaration>,
+    top: String,
+    signals: Vec<Signal>,
+    instances: HashSet<String>,
+    names: HashMap<String, usize>,
+    values: Vec<Value>,
+    last: Vec<Value>,
+    combs: Vec<Comb>,
+    ffs: Vec<Ff>,
+    wave: Option<Wave>,
+}
+
+impl Default for Simulator {
+    fn default() -> Self {
+        Self {
+            clock_type: ClockType::PosEdge,
+            reset_type: ResetType::AsyncLow,
+            time: 0,
+            modules: HashMap::new(),
+            top: String::new(),
+            signals: Vec::new(),
+            instances: HashSet::new(),
+            names: HashMap::new(),
+            values: Vec::new(),
+            last: Vec::new(),
+            combs: Vec::new(),
+            ffs: Vec::new(),
+            wave: None,
+        }
+    }
+}
+
+fn edge(last: &Value, current: &Value, posedge: bool) -> bool {
+    let (from, to) = if posedge { ('0', '1') } else { ('1', '0') };
+    // transition from x or z to the active level is also an edge like SystemVerilog
+    last.bit(0) != to && current.bit(0) == to && (last.bit(0) == from || !last.is_known())
+}
+
+impl Simulator {
+    pub fn new(metadata: &Metadata) -> Self {
+        Self {
+            clock_type: metadata.build.clock_type,
+            reset_type: metadata.build.reset_type,
+            ..Default::default()
+        }
+    }
+
+    /// Register modules which can be elaborated
+    pub fn add(&mut self, input: &Veryl) {
+        for x in &input.veryl_list {
+            if let Description::ModuleDeclaration(x) = &*x.description {
+                let x = &x.module_declaration;
+                let name = x.identifier.identifier_token.text();
+                self.modules.insert(name, *x.clone());
+            }
+        }
+    }
+
+    /// Elaborate the top module, and initialize all signals to `x`
+    pub fn elaborate(&mut self, top: &str) -> Result<(), SimulatorError> {
+        let mut elaborator = Elaborator::new(&self.modules, self.clock_type, self.reset_type);
+        elaborator.elaborate(top)?;
+
+        self.top = top.to_string();
+        self.signals = elaborator.signals;
+        self.instances = elaborator.instances;
+        self.combs = sort_combs(elaborator.combs);
+        self.ffs = elaborator.ffs;
+        self.initialize();
+        Ok(())
+    }
+
+    /// Elaborate the module instantiated in the test, and return operations to be given to [`Simulator::run`]
+    pub fn elaborate_test(
+        &mut self,
+        arg: &TestDeclaration,
+    ) -> Result<Vec<TestItem>, SimulatorError> {
+        let mut elaborator = Elaborator::new(&self.modules, self.clock_type, self.reset_type);
+        let items = elaborator.elaborate_test(arg)?;
+
+        self.top = arg.inst_declaration.identifier.identifier_token.text();
+        self.signals = elaborator.signals;
+        self.instances = elaborator.instances;
+        self.combs = sort_combs(elaborator.combs);
+        self.ffs = elaborator.ffs;
+        self.initialize();
+        Ok(items)
+    }
+
+    fn initialize(&mut self) {
+        self.names = self
+            .signals
+            .iter()
+            .enumerate()
+            .map(|(i, x)| (x.name.clone(), i))
+            .collect();
+        self.values = self.signals.iter().map(|x| Value::x(x.width)).collect();
+        self.last = self.values.clone();
+        self.time = 0;
+        self.wave = None;
+    }
+
+    /// Start recording waveform from the current values
+    pub fn dump(&mut self, option: WaveOption) {
+        let mut wave = Wave::new(&self.top, &self.signals, &self.instances, option);
+        wave.sample(self.time, &self.values);
+        self.wave = Some(wave);
+    }
+
+    /// Recorded waveform which can be written by [`Wave::write`]
+    pub fn wave(&self) -> Option<&Wave> {
+        self.wave.as_ref()
+    }
+
+    /// All signals of the design with hierarchical names
+    pub fn signals(&self) -> &[Signal] {
+        &self.signals
+    }
+
+    /// Values of the signals in the same order as [`Simulator::signals`]
+    pub fn values(&self) -> &[Value] {
+        &self.values
+    }
+
+    fn id(&self, name: &str) -> Result<usize, SimulatorError> {
+        self.names
+            .get(name)
+            .copied()
+            .ok_or_else(|| SimulatorError::UnknownSignal(name.to_string()))
+    }
+
+    /// Set the value of an input port of the top module
+    ///
+    /// The value is not propagated until [`Simulator::eval`] is called.
+    pub fn poke(&mut self, name: &str, value: Value) -> Result<(), SimulatorError> {
+        let id = self.input(name)?;
+        self.values[id] = value.resize(self.signals[id].width);
+        Ok(())
+    }
+
+    fn input(&self, name: &str) -> Result<usize, SimulatorError> {
+        let id = self.id(name)?;
+        let signal = &self.signals[id];
+        if signal.kind != SignalKind::Input || signal.name.contains('.') {
+            Err(SimulatorError::NotInput(name.to_string()))
+        } else {
+            Ok(id)
+        }
+    }
+
+    /// Get the value of a signal by the hierarchical name like `u_sub.r_data`
+    pub fn peek(&self, name: &str) -> Result<Value, SimulatorError> {
+        Ok(self.values[self.id(name)?])
+    }
+
+    /// Propagate changes until the design becomes stable
+    pub fn eval(&mut self) -> Result<(), SimulatorError> {
+        for _ in 0..MAX_DELTA {
+            self.settle()?;
+
+            let mut triggered = Vec::new();
+            for (i, ff) in self.ffs.iter().enumerate() {
+                let clock = edge(&self.last[ff.clock], &self.values[ff.clock], ff.posedge);
+                let reset = match ff.reset {
+                    Some(x) if x.asynchronous => {
+                        edge(&self.last[x.id], &self.values[x.id], x.active_high)
+                    }
+                    _ => false,
+                };
+                if clock || reset {
+                    triggered.push(i);
+                }
+            }
+            self.last = self.values.clone();
+
+            if triggered.is_empty() {
+                if let Some(ref mut wave) = self.wave {
+                    wave.sample(self.time, &self.values);
+                }
+                return Ok(());
+            }
+
+            // all triggered blocks read values before update like non-blocking assignment
+            let mut context = Context {
+                values: &mut self.values,
+                pending: Some(HashMap::new()),
+            };
+            for i in triggered {
+                context.execute(&self.ffs[i].statements);
+            }
+            for (id, value) in context.pending.take().unwrap() {
+                self.values[id] = value;
+            }
+        }
+        Err(SimulatorError::NotConverged)
+    }
+
+    /// Evaluate combinational processes until all values become stable
+    fn settle(&mut self) -> Result<(), SimulatorError> {
+        for _ in 0..self.combs.len() + 2 {
+            let before = self.values.clone();
+            let mut context = Context {
+                values: &mut self.values,
+                pending: None,
+            };
+            for comb in &self.combs {
+                context.execute(&comb.statements);
+            }
+            if before == self.values {
+                return Ok(());
+            }
+        }
+        Err(SimulatorError::NotConverged)
+    }
+
+    /// Give an active edge of the clock by `ClockType`, and return it to the inactive level.
+    /// Each edge advances `time` by 1.
+    pub fn step(&mut self, clock: &str) -> Result<(), SimulatorError> {
+        let id = self.input(clock)?;
+        self.step_by_id(id)
+    }
+
+    fn step_by_id(&mut self, clock: usize) -> Result<(), SimulatorError> {
+        let active = match self.clock_type {
+            ClockType::PosEdge => 1,
+            ClockType::NegEdge => 0,
+        };
+        self.time += 1;
+        self.values[clock] = Value::new(active, 1);
+        self.eval()?;
+        self.time += 1;
+        self.values[clock] = Value::new(1 - active, 1);
+        self.eval()
+    }
+
+    /// Run operations of the test, and return an error at the first failed expectation
+    pub fn run(&mut self, items: &[TestItem]) -> Result<(), SimulatorError> {
+        for item in items {
+            match item {
+                TestItem::Assign(x) => {
+                    let mut context = Context {
+                        values: &mut self.values,
+                        pending: None,
+                    };
+                    context.execute(std::slice::from_ref(x));
+                }
+                TestItem::Step(clock, count) => {
+                    for _ in 0..*count {
+                        self.step_by_id(*clock)?;
+                    }
+                }
+                TestItem::Expect(expr, text) => {
+                    self.eval()?;
+                    if expr.eval(&self.values, expr.width).to_bool() != Some(true) {
+                        return Err(SimulatorError::ExpectFailed(text.clone(), self.time));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Sort combinational processes in dependency order.
+/// Processes in combinational loop are placed after the others in the original order.
+fn sort_combs(combs: Vec<Comb>) -> Vec<Comb> {
+    let mut writers: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (i, x) in combs.iter().enumerate() {
+        for id in &x.writes {
+            writers.entry(*id).or_default().push(i);
+        }
+    }
+
+    let mut successors = vec![Vec::new(); combs.len()];
+    let mut degree = vec![0; combs.len()];
+    for (i, x) in combs.iter().enumerate() {
+        for id in &x.reads {
+            if let Some(writers) = writers.get(id) {
+                for writer in writers {
+                    if *writer != i {
+                        successors[*writer].push(i);
+                        degree[i] += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    let mut queue: VecDeque<_> = (0..combs.len()).filter(|x| degree[*x] == 0).collect();
+    let mut order = Vec::new();
+    while let Some(i) = queue.pop_front() {
+        order.push(i);
+        for j in &successors[i] {
+            degree[*j] -= 1;
+            if degree[*j] == 0 {
+                queue.push_back(*j);
+            }
+        }
+    }
+    order.extend((0..combs.len()).filter(|x| degree[*x] != 0));
+
+    let mut combs: Vec<_> = combs.into_iter().map(Some).collect();
+    order
+        .into_iter()
+        .map(|i| combs[i].take().unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use veryl_analyzer::Analyzer;
+    use veryl_parser::Parser;
+
+    const CODE: &str = r#"
+module Counter #(
+    parameter WIDTH: u32 = 4,
+) (
+    i_clk: input  logic       ,
+    i_rst: input  logic       ,
+    i_en : input  logic       ,
+    o_cnt: output logic[WIDTH],
+    o_max: output logic       ,
+) {
+    var r_cnt: logic[WIDTH];
+
+    assign o_max = r_cnt == '1;
+    assign o_cnt = r_cnt;
+
+    always_ff (i_clk, i_rst) {
+        if_reset {
+            r_cnt = 0;
+        } else if i_en {
+            r_cnt += 1;
+        }
+    }
+}
+
+module Top (
+    i_clk: input  logic   ,
+    i_rst: input  logic   ,
+    i_en : input  logic   ,
+    i_sel: input  logic[2],
+    o_cnt: output logic[8],
+    o_max: output logic   ,
+    o_mux: output logic[8],
+) {
+    var mux: logic[8];
+
+    assign o_mux = mux;
+
+    always_comb {
+        case i_sel {
+            0      : mux = 8'h0x;
+            1      : mux = 8'hzz;
+            default: mux = {o_cnt[3:0], 4'hf};
+        }
+    }
+
+    inst u_counter: Counter #(
+        WIDTH: 8,
+    ) (
+        i_clk     ,
+        i_rst     ,
+        i_en      ,
+        o_cnt     ,
+        o_max     ,
+    );
+}
+"#;
+
+    fn simulator() -> Simulator {
+        let parser = Parser::parse(CODE, &"").unwrap();
+        let mut analyzer = Analyzer::new(CODE);
+        let errors = analyzer.analyze(&parser.veryl);
+        assert!(errors.is_empty());
+
+        let mut simulator = Simulator::default();
+        simulator.add(&parser.veryl);
+        simulator.elaborate("Top").unwrap();
+        simulator
+    }
+
+    #[test]
+    fn counter() {
+        let mut sim = simulator();
+        assert_eq!(format!("{}", sim.peek("o_cnt").unwrap()), "8'bxxxxxxxx");
+
+        sim.poke("i_clk", Value::new(0, 1)).unwrap();
+        sim.poke("i_rst", Value::new(0, 1)).unwrap();
+        sim.poke("i_en", Value::new(0, 1)).unwrap();
+        sim.eval().unwrap();
+        assert_eq!(sim.peek("o_cnt").unwrap().to_u128(), Some(0));
+
+        sim.poke("i_rst", Value::new(1, 1)).unwrap();
+        sim.step("i_clk").unwrap();
+        assert_eq!(sim.peek("o_cnt").unwrap().to_u128(), Some(0));
+
+        sim.poke("i_en", Value::new(1, 1)).unwrap();
+        for _ in 0..255 {
+            sim.step("i_clk").unwrap();
+        }
+        assert_eq!(sim.peek("u_counter.r_cnt").unwrap().to_u128(), Some(255));
+        assert_eq!(sim.peek("o_max").unwrap().to_u128(), Some(1));
+
+        sim.step("i_clk").unwrap();
+        assert_eq!(sim.peek("o_cnt").unwrap().to_u128(), Some(0));
+    }
+
+    #[test]
+    fn four_state() {
+        let mut sim = simulator();
+        sim.poke("i_sel", Value::new(0, 2)).unwrap();
+        sim.eval().unwrap();
+        assert_eq!(format!("{}", sim.peek("o_mux").unwrap()), "8'b0000xxxx");
+
+        sim.poke("i_sel", Value::new(1, 2)).unwrap();
+        sim.eval().unwrap();
+        assert_eq!(format!("{}", sim.peek("o_mux").unwrap()), "8'bzzzzzzzz");
+
+        sim.poke("i_sel", Value::new(2, 2)).unwrap();
+        sim.eval().unwrap();
+        assert_eq!(format!("{}", sim.peek("o_mux").unwrap()), "8'bxxxx1111");
+    }
+
+    #[test]
+    fn errors() {
+        let mut sim = simulator();
+        assert_eq!(
+            sim.poke("o_cnt", Value::new(0, 8)),
+            Err(SimulatorError::NotInput("o_cnt".to_string()))
+        );
+        assert_eq!(
+            sim.peek("u_counter.unknown"),
+            Err(SimulatorError::UnknownSignal(
+                "u_counter.unknown".to_string()
+            ))
+        );
+        assert_eq!(
+            sim.elaborate("Unknown"),
+            Err(SimulatorError::UnknownModule("Unknown".to_string()))
+        );
+    }
+
+    #[test]
+    fn loop_overflow() {
+        let code = r#"
+module LoopTop {
+    for i in 1..100 step <<= 200 :g {
+        var a: logic;
+    }
+}
+"#;
+        let parser = Parser::parse(code, &"").unwrap();
+        let mut analyzer = Analyzer::new(code);
+        let errors = analyzer.analyze(&parser.veryl);
+        assert!(errors.is_empty());
+
+        let mut sim = Simulator::default();
+        sim.add(&parser.veryl);
+        assert_eq!(
+            sim.elaborate("LoopTop"),
+            Err(SimulatorError::LoopOverflow("<<= 200".to_string()))
+        );
+    }
+
+    const TYPE_PARAMETER: &str = r#"
+module Buffer #(
+    parameter T: type = logic,
+) (
+    i_d: input  T,
+    o_d: output T,
+) {
+    assign o_d = i_d;
+}
+
+module TypeTop (
+    i_a: input  logic    ,
+    o_a: output logic    ,
+    i_b: input  logic [8],
+    o_b: output logic [8],
+    i_c: input  logic [4],
+    o_c: output logic [4],
+) {
+    type nibble_t = logic [4];
+
+    inst u_a: Buffer (
+        i_d: i_a,
+        o_d: o_a,
+    );
+
+    inst u_b: Buffer #(
+        T: logic [8],
+    ) (
+        i_d: i_b,
+        o_d: o_b,
+    );
+
+    inst u_c: Buffer #(
+        T: nibble_t,
+    ) (
+        i_d: i_c,
+        o_d: o_c,
+    );
+}
+"#;
+
+    #[test]
+    fn type_parameter() {
+        let parser = Parser::parse(TYPE_PARAMETER, &"").unwrap();
+        let mut analyzer = Analyzer::new(TYPE_PARAMETER);
+        let errors = analyzer.analyze(&parser.veryl);
+        assert!(errors.is_empty());
+
+        let mut sim = Simulator::default();
+        sim.add(&parser.veryl);
+        sim.elaborate("TypeTop").unwrap();
+
+        let width = |name: &str| sim.signals().iter().find(|x| x.name == name).unwrap().width;
+        assert_eq!(width("u_a.i_d"), 1);
+        assert_eq!(width("u_b.i_d"), 8);
+        assert_eq!(width("u_c.o_d"), 4);
+    }
+
+    const TEST: &str = r#"
+test pass {
+    inst u: Counter;
+    u.i_rst = 0;
+    u.i_en = 1;
+    step u.i_clk;
+    expect u.o_cnt == 0;
+    u.i_rst = 1;
+    step u.i_clk, 3;
+    expect u.o_cnt == 3;
+}
+
+test fail {
+    inst u: Counter;
+    u.i_rst = 0;
+    u.i_en = 0;
+    step u.i_clk;
+    expect u.o_cnt == 1;
+}
+
+test drive_output {
+    inst u: Counter;
+    u.o_cnt = 0;
+}
+"#;
+
+    #[test]
+    fn test_declaration() {
+        let code = format!("{}{}", CODE, TEST);
+        let parser = Parser::parse(&code, &"").unwrap();
+        let mut analyzer = Analyzer::new(&code);
+        let errors = analyzer.analyze(&parser.veryl);
+        assert!(errors.is_empty());
+
+        let mut sim = Simulator::default();
+        sim.add(&parser.veryl);
+
+        let tests: Vec<_> = parser
+            .veryl
+            .veryl_list
+            .iter()
+            .filter_map(|x| match &*x.description {
+                Description::TestDeclaration(x) => Some(x.test_declaration.clone()),
+                _ => None,
+            })
+            .collect();
+
+        let items = sim.elaborate_test(&tests[0]).unwrap();
+        assert_eq!(sim.run(&items), Ok(()));
+        assert_eq!(sim.time, 8);
+
+        let items = sim.elaborate_test(&tests[1]).unwrap();
+        assert_eq!(
+            sim.run(&items),
+            Err(SimulatorError::ExpectFailed("u.o_cnt==1".to_string(), 2))
+        );
+
+        assert_eq!(
+            sim.elaborate_test(&tests[2]).err(),
+            Some(SimulatorError::NotInput("u.o_cnt".to_string()))
+        );
+    }
+}
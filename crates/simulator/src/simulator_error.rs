@@ -0,0 +1,53 @@
+use thiserror::Error;
+use veryl_parser::miette::{self, Diagnostic};
+
+#[derive(Error, Diagnostic, Debug, PartialEq, Eq)]
+pub enum SimulatorError {
+    #[diagnostic(code(SimulatorError::UnknownModule), help("analyze the source first"))]
+    #[error("module \"{0}\" is not found")]
+    UnknownModule(String),
+
+    #[diagnostic(code(SimulatorError::UndefinedIdentifier), help(""))]
+    #[error("\"{0}\" is not defined")]
+    UndefinedIdentifier(String),
+
+    #[diagnostic(code(SimulatorError::NotConstant), help(""))]
+    #[error("\"{0}\" must be constant")]
+    NotConstant(String),
+
+    #[diagnostic(code(SimulatorError::NotType), help(""))]
+    #[error("\"{0}\" must be type")]
+    NotType(String),
+
+    #[diagnostic(code(SimulatorError::InvalidNumber), help(""))]
+    #[error("\"{0}\" is not a valid number")]
+    InvalidNumber(String),
+
+    #[diagnostic(code(SimulatorError::TooWide), help("the maximum width is 128"))]
+    #[error("width of \"{0}\" is too wide")]
+    TooWide(String),
+
+    #[diagnostic(code(SimulatorError::LoopOverflow), help("check the step of for loop"))]
+    #[error("loop index overflows by step \"{0}\"")]
+    LoopOverflow(String),
+
+    #[diagnostic(code(SimulatorError::Unsupported), help(""))]
+    #[error("{0} is not supported by simulator")]
+    Unsupported(String),
+
+    #[diagnostic(code(SimulatorError::UnknownSignal), help(""))]
+    #[error("signal \"{0}\" is not found")]
+    UnknownSignal(String),
+
+    #[diagnostic(code(SimulatorError::NotInput), help("only input ports can be poked"))]
+    #[error("\"{0}\" is not an input port")]
+    NotInput(String),
+
+    #[diagnostic(code(SimulatorError::NotConverged), help("check combinational loop"))]
+    #[error("evaluation is not converged")]
+    NotConverged,
+
+    #[diagnostic(code(SimulatorError::ExpectFailed), help(""))]
+    #[error("expect \"{0}\" failed at time {1}")]
+    ExpectFailed(String, u64),
+}
@@ -0,0 +1,512 @@
+use crate::simulator_error::SimulatorError;
+use std::fmt;
+use std::str::FromStr;
+
+pub const MAX_WIDTH: usize = 128;
+
+/// Four-state value up to 128 bits
+///
+/// Each bit is encoded by a pair of `value` and `mask` bits like VPI:
+/// `(0, 0)` is `0`, `(1, 0)` is `1`, `(0, 1)` is `z` and `(1, 1)` is `x`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Value {
+    width: usize,
+    value: u128,
+    mask: u128,
+}
+
+fn all_ones(width: usize) -> u128 {
+    if width >= MAX_WIDTH {
+        u128::MAX
+    } else {
+        (1 << width) - 1
+    }
+}
+
+/// Shift left which returns 0 if all bits are shifted out
+fn shl(value: u128, width: usize) -> u128 {
+    if width >= MAX_WIDTH {
+        0
+    } else {
+        value << width
+    }
+}
+
+impl Value {
+    pub fn new(value: u128, width: usize) -> Self {
+        Self {
+            width,
+            value: value & all_ones(width),
+            mask: 0,
+        }
+    }
+
+    pub fn x(width: usize) -> Self {
+        Self {
+            width,
+            value: all_ones(width),
+            mask: all_ones(width),
+        }
+    }
+
+    pub fn z(width: usize) -> Self {
+        Self {
+            width,
+            value: 0,
+            mask: all_ones(width),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Return `false` if any bit is `x` or `z`
+    pub fn is_known(&self) -> bool {
+        self.mask == 0
+    }
+
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.is_known() {
+            Some(self.value)
+        } else {
+            None
+        }
+    }
+
+    /// Return `None` if the truth value can't be determined because of `x` or `z`
+    pub fn to_bool(&self) -> Option<bool> {
+        if self.value & !self.mask != 0 {
+            Some(true)
+        } else if self.is_known() {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Return a character of the specified bit among `0`, `1`, `x` and `z`
+    pub fn bit(&self, index: usize) -> char {
+        let value = (self.value >> index) & 1;
+        let mask = (self.mask >> index) & 1;
+        match (value, mask) {
+            (0, 0) => '0',
+            (1, 0) => '1',
+            (0, 1) => 'z',
+            _ => 'x',
+        }
+    }
+
+    /// Zero-extend or truncate to the specified width
+    pub fn resize(&self, width: usize) -> Self {
+        Self {
+            width,
+            value: self.value & all_ones(width),
+            mask: self.mask & all_ones(width),
+        }
+    }
+
+    /// Extend to the specified width by repeating the value
+    pub fn fill(&self, width: usize) -> Self {
+        // zero width value can't be extended by repeating
+        if self.width == 0 {
+            return Value::new(0, width);
+        }
+        let mut ret = Value::new(0, 0);
+        while ret.width < width {
+            ret = Value::concat(&[*self, ret]);
+        }
+        ret.resize(width)
+    }
+
+    pub fn slice(&self, lsb: usize, width: usize) -> Self {
+        if lsb >= MAX_WIDTH {
+            return Value::x(width);
+        }
+        let mut ret = Self {
+            width,
+            value: (self.value >> lsb) & all_ones(width),
+            mask: (self.mask >> lsb) & all_ones(width),
+        };
+        // bits over the original width are x
+        if lsb + width > self.width {
+            let valid = self.width.saturating_sub(lsb);
+            let outside = all_ones(width) & !all_ones(valid);
+            ret.value |= outside;
+            ret.mask |= outside;
+        }
+        ret
+    }
+
+    pub fn set_slice(&mut self, lsb: usize, value: &Value) {
+        if lsb >= self.width {
+            return;
+        }
+        let width = value.width.min(self.width - lsb);
+        let field = all_ones(width) << lsb;
+        self.value = (self.value & !field) | ((value.value << lsb) & field);
+        self.mask = (self.mask & !field) | ((value.mask << lsb) & field);
+    }
+
+    /// Concatenate values from MSB
+    pub fn concat(values: &[Value]) -> Self {
+        let mut ret = Value::new(0, 0);
+        for x in values {
+            let width = ret.width + x.width;
+            ret = Self {
+                width,
+                value: shl(ret.value, x.width) | x.value,
+                mask: shl(ret.mask, x.width) | x.mask,
+            }
+            .resize(width.min(MAX_WIDTH));
+        }
+        ret
+    }
+
+    fn unknown_bits(&self) -> u128 {
+        self.mask
+    }
+
+    pub fn not(&self) -> Self {
+        Self {
+            width: self.width,
+            value: (!self.value | self.mask) & all_ones(self.width),
+            mask: self.mask,
+        }
+    }
+
+    pub fn and(&self, y: &Value) -> Self {
+        let width = self.width.max(y.width);
+        let zero = (!self.value & !self.mask) | (!y.value & !y.mask);
+        let one = (self.value & !self.mask) & (y.value & !y.mask);
+        let x = !(zero | one) & all_ones(width);
+        Self {
+            width,
+            value: one | x,
+            mask: x,
+        }
+    }
+
+    pub fn or(&self, y: &Value) -> Self {
+        let width = self.width.max(y.width);
+        let one = (self.value & !self.mask) | (y.value & !y.mask);
+        let zero = (!self.value & !self.mask) & (!y.value & !y.mask);
+        let x = !(zero | one) & all_ones(width);
+        Self {
+            width,
+            value: (one | x) & all_ones(width),
+            mask: x,
+        }
+    }
+
+    pub fn xor(&self, y: &Value) -> Self {
+        let width = self.width.max(y.width);
+        let x = self.unknown_bits() | y.unknown_bits();
+        Self {
+            width,
+            value: ((self.value ^ y.value) | x) & all_ones(width),
+            mask: x,
+        }
+    }
+
+    fn arithmetic<F: Fn(u128, u128) -> Option<u128>>(&self, y: &Value, width: usize, f: F) -> Self {
+        match (self.to_u128(), y.to_u128()) {
+            (Some(a), Some(b)) => match f(a, b) {
+                Some(x) => Value::new(x, width),
+                None => Value::x(width),
+            },
+            _ => Value::x(width),
+        }
+    }
+
+    pub fn add(&self, y: &Value, width: usize) -> Self {
+        self.arithmetic(y, width, |a, b| Some(a.wrapping_add(b)))
+    }
+
+    pub fn sub(&self, y: &Value, width: usize) -> Self {
+        self.arithmetic(y, width, |a, b| Some(a.wrapping_sub(b)))
+    }
+
+    pub fn mul(&self, y: &Value, width: usize) -> Self {
+        self.arithmetic(y, width, |a, b| Some(a.wrapping_mul(b)))
+    }
+
+    pub fn div(&self, y: &Value, width: usize) -> Self {
+        self.arithmetic(y, width, |a, b| a.checked_div(b))
+    }
+
+    pub fn rem(&self, y: &Value, width: usize) -> Self {
+        self.arithmetic(y, width, |a, b| a.checked_rem(b))
+    }
+
+    pub fn pow(&self, y: &Value, width: usize) -> Self {
+        self.arithmetic(y, width, |a, b| {
+            Some(a.wrapping_pow(b.min(u32::MAX as u128) as u32))
+        })
+    }
+
+    pub fn neg(&self) -> Self {
+        Value::new(0, self.width).sub(self, self.width)
+    }
+
+    pub fn shl(&self, y: &Value) -> Self {
+        match y.to_u128() {
+            Some(x) if x >= self.width as u128 => Value::new(0, self.width),
+            Some(x) => Self {
+                width: self.width,
+                value: (self.value << x) & all_ones(self.width),
+                mask: (self.mask << x) & all_ones(self.width),
+            },
+            None => Value::x(self.width),
+        }
+    }
+
+    pub fn shr(&self, y: &Value) -> Self {
+        match y.to_u128() {
+            Some(x) if x >= self.width as u128 => Value::new(0, self.width),
+            Some(x) => Self {
+                width: self.width,
+                value: self.value >> x,
+                mask: self.mask >> x,
+            },
+            None => Value::x(self.width),
+        }
+    }
+
+    /// `==`: the result is `x` if it depends on `x` or `z` bits
+    pub fn eq(&self, y: &Value) -> Self {
+        let width = self.width.max(y.width);
+        let a = self.resize(width);
+        let b = y.resize(width);
+        let known = !(a.mask | b.mask) & all_ones(width);
+        if (a.value ^ b.value) & known != 0 {
+            Value::new(0, 1)
+        } else if a.is_known() && b.is_known() {
+            Value::new(1, 1)
+        } else {
+            Value::x(1)
+        }
+    }
+
+    /// `===`: `x` and `z` bits are compared as is
+    pub fn case_eq(&self, y: &Value) -> Self {
+        let width = self.width.max(y.width);
+        let a = self.resize(width);
+        let b = y.resize(width);
+        Value::new((a.value == b.value && a.mask == b.mask) as u128, 1)
+    }
+
+    /// `==?`: `x` and `z` bits of the right hand side are wildcard
+    pub fn wildcard_eq(&self, y: &Value) -> Self {
+        let width = self.width.max(y.width);
+        let a = self.resize(width);
+        let b = y.resize(width);
+        let care = !b.mask & all_ones(width);
+        if (a.value ^ b.value) & care & !a.mask != 0 {
+            Value::new(0, 1)
+        } else if a.mask & care == 0 {
+            Value::new(1, 1)
+        } else {
+            Value::x(1)
+        }
+    }
+
+    pub fn lt(&self, y: &Value) -> Self {
+        self.arithmetic(y, 1, |a, b| Some((a < b) as u128))
+    }
+
+    pub fn le(&self, y: &Value) -> Self {
+        self.arithmetic(y, 1, |a, b| Some((a <= b) as u128))
+    }
+
+    pub fn logical_not(&self) -> Self {
+        match self.to_bool() {
+            Some(x) => Value::new(!x as u128, 1),
+            None => Value::x(1),
+        }
+    }
+
+    pub fn logical_and(&self, y: &Value) -> Self {
+        match (self.to_bool(), y.to_bool()) {
+            (Some(false), _) | (_, Some(false)) => Value::new(0, 1),
+            (Some(true), Some(true)) => Value::new(1, 1),
+            _ => Value::x(1),
+        }
+    }
+
+    pub fn logical_or(&self, y: &Value) -> Self {
+        match (self.to_bool(), y.to_bool()) {
+            (Some(true), _) | (_, Some(true)) => Value::new(1, 1),
+            (Some(false), Some(false)) => Value::new(0, 1),
+            _ => Value::x(1),
+        }
+    }
+
+    pub fn reduce_and(&self) -> Self {
+        let zero = !self.value & !self.mask & all_ones(self.width);
+        if zero != 0 {
+            Value::new(0, 1)
+        } else if self.is_known() {
+            Value::new(1, 1)
+        } else {
+            Value::x(1)
+        }
+    }
+
+    pub fn reduce_or(&self) -> Self {
+        match self.to_bool() {
+            Some(x) => Value::new(x as u128, 1),
+            None => Value::x(1),
+        }
+    }
+
+    pub fn reduce_xor(&self) -> Self {
+        if self.is_known() {
+            Value::new((self.value.count_ones() & 1) as u128, 1)
+        } else {
+            Value::x(1)
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut text = format!("{}'b", self.width);
+        for i in (0..self.width).rev() {
+            text.push(self.bit(i));
+        }
+        text.fmt(f)
+    }
+}
+
+impl FromStr for Value {
+    type Err = SimulatorError;
+
+    /// Parse a number literal like `8'hxf`, `'1` and `123`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.replace('_', "");
+        let error = || SimulatorError::InvalidNumber(s.to_string());
+
+        if let Some(x) = text.strip_prefix('\'') {
+            return match x {
+                "0" => Ok(Value::new(0, 1)),
+                "1" => Ok(Value::new(1, 1)),
+                "x" | "X" => Ok(Value::x(1)),
+                "z" | "Z" => Ok(Value::z(1)),
+                _ => Err(error()),
+            };
+        }
+
+        let (width, tail) = match text.split_once('\'') {
+            Some(x) => x,
+            None => {
+                let value: u128 = text.parse().map_err(|_| error())?;
+                let width = (MAX_WIDTH - value.leading_zeros() as usize).max(32);
+                return Ok(Value::new(value, width));
+            }
+        };
+
+        let width: usize = width.parse().map_err(|_| error())?;
+        if width > MAX_WIDTH {
+            return Err(SimulatorError::TooWide(s.to_string()));
+        }
+        let mut chars = tail.chars();
+        let base = chars.next().ok_or_else(error)?;
+        let digits: Vec<char> = chars.collect();
+        let digit_width = match base {
+            'b' => 1,
+            'o' => 3,
+            'h' => 4,
+            'd' => {
+                let digits: String = digits.iter().collect();
+                return match digits.as_str() {
+                    "x" | "X" => Ok(Value::x(width)),
+                    "z" | "Z" => Ok(Value::z(width)),
+                    _ => Ok(Value::new(digits.parse().map_err(|_| error())?, width)),
+                };
+            }
+            _ => return Err(error()),
+        };
+
+        let mut ret = Value::new(0, 0);
+        for c in &digits {
+            let digit = match c {
+                'x' | 'X' => Value::x(digit_width),
+                'z' | 'Z' | '?' => Value::z(digit_width),
+                c => {
+                    let x = c.to_digit(16).ok_or_else(error)?;
+                    Value::new(x as u128, digit_width)
+                }
+            };
+            ret = Value::concat(&[ret, digit]);
+        }
+        // x and z in MSB are extended to the width
+        let fill = match digits.first() {
+            Some('x' | 'X') => Value::x(1),
+            Some('z' | 'Z' | '?') => Value::z(1),
+            _ => Value::new(0, 1),
+        };
+        if ret.width < width {
+            let ext = fill.fill(width - ret.width);
+            ret = Value::concat(&[ext, ret]);
+        }
+        Ok(ret.resize(width))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let x: Value = "8'hx5".parse().unwrap();
+        assert_eq!(format!("{}", x), "8'bxxxx0101");
+        let x: Value = "6'bz1".parse().unwrap();
+        assert_eq!(format!("{}", x), "6'bzzzzz1");
+        let x: Value = "12'd100".parse().unwrap();
+        assert_eq!(x.to_u128(), Some(100));
+        let x: Value = "1_000".parse().unwrap();
+        assert_eq!((x.width(), x.to_u128()), (32, Some(1000)));
+    }
+
+    #[test]
+    fn four_state() {
+        let a: Value = "4'b01xz".parse().unwrap();
+        let zero = Value::new(0, 4);
+        let one = Value::new(0xf, 4);
+        assert_eq!(format!("{}", a.and(&zero)), "4'b0000");
+        assert_eq!(format!("{}", a.and(&one)), "4'b01xx");
+        assert_eq!(format!("{}", a.or(&one)), "4'b1111");
+        assert_eq!(format!("{}", a.not()), "4'b10xx");
+        assert_eq!(format!("{}", a.add(&one, 4)), "4'bxxxx");
+        assert_eq!(a.eq(&zero), Value::new(0, 1));
+        assert_eq!(a.case_eq(&a), Value::new(1, 1));
+    }
+
+    #[test]
+    fn concat() {
+        let a = Value::new(u128::MAX, MAX_WIDTH);
+        let b = Value::new(0x5, 4);
+        let x = Value::concat(&[b, a]);
+        assert_eq!((x.width(), x.to_u128()), (MAX_WIDTH, Some(u128::MAX)));
+        let x = Value::concat(&[a, b]);
+        assert_eq!(
+            (x.width(), x.to_u128()),
+            (MAX_WIDTH, Some(u128::MAX << 4 | 0x5))
+        );
+
+        let empty = Value::new(0, 0);
+        let x = Value::concat(&[empty, b, empty]);
+        assert_eq!(x, b);
+    }
+
+    #[test]
+    fn fill() {
+        let one = Value::new(1, 1);
+        assert_eq!(one.fill(MAX_WIDTH), Value::new(u128::MAX, MAX_WIDTH));
+        let x = Value::x(1).fill(3);
+        assert_eq!(format!("{}", x), "3'bxxx");
+        assert_eq!(Value::new(0, 0).fill(8), Value::new(0, 8));
+    }
+}
@@ -0,0 +1,735 @@
+use crate::elaborator::{Signal, SignalKind};
+use crate::value::Value;
+use miniz_oxide::deflate::{compress_to_vec, compress_to_vec_zlib};
+use std::collections::HashSet;
+use std::io::{self, Write};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WaveFormat {
+    #[default]
+    Vcd,
+    /// Uncompressed FST whose block layout is checked, but which isn't verified by external
+    /// readers yet
+    Fst,
+}
+
+#[derive(Clone, Debug)]
+pub struct WaveOption {
+    pub format: WaveFormat,
+    /// Maximum depth of module instances to be dumped. `1` means the top module only.
+    pub depth: Option<usize>,
+    /// Start time of dump window
+    pub start: u64,
+    /// End time of dump window (inclusive)
+    pub end: Option<u64>,
+    /// Exponent of time unit like `-9` for `1ns`
+    pub timescale: i8,
+}
+
+impl Default for WaveOption {
+    fn default() -> Self {
+        Self {
+            format: WaveFormat::default(),
+            depth: None,
+            start: 0,
+            end: None,
+            timescale: -9,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct WaveSignal {
+    id: usize,
+    name: String,
+    width: usize,
+    kind: SignalKind,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Scope {
+    name: String,
+    instance: bool,
+    signals: Vec<usize>,
+    scopes: Vec<Scope>,
+}
+
+impl Scope {
+    fn insert(&mut self, path: &[(&str, bool)], signal: usize) {
+        match path.split_first() {
+            Some(((name, instance), rest)) => {
+                let index = match self.scopes.iter().position(|x| x.name == *name) {
+                    Some(x) => x,
+                    None => {
+                        self.scopes.push(Scope {
+                            name: name.to_string(),
+                            instance: *instance,
+                            ..Default::default()
+                        });
+                        self.scopes.len() - 1
+                    }
+                };
+                self.scopes[index].insert(rest, signal);
+            }
+            None => self.signals.push(signal),
+        }
+    }
+
+    /// Signals in the order of appearance in the hierarchy
+    fn order(&self, ret: &mut Vec<usize>) {
+        ret.extend(&self.signals);
+        for x in &self.scopes {
+            x.order(ret);
+        }
+    }
+
+    fn count(&self) -> usize {
+        1 + self.scopes.iter().map(|x| x.count()).sum::<usize>()
+    }
+}
+
+/// Waveform recorder of the simulated design
+///
+/// Value changes are recorded at each evaluation of [`crate::Simulator`], and written as VCD or
+/// FST by [`Wave::write`].
+#[derive(Clone, Debug)]
+pub struct Wave {
+    pub option: WaveOption,
+    root: Scope,
+    signals: Vec<WaveSignal>,
+    last: Vec<Option<Value>>,
+    records: Vec<(u64, Vec<(usize, Value)>)>,
+}
+
+impl Wave {
+    pub fn new(
+        top: &str,
+        signals: &[Signal],
+        instances: &HashSet<String>,
+        option: WaveOption,
+    ) -> Self {
+        let mut root = Scope {
+            name: top.to_string(),
+            instance: true,
+            ..Default::default()
+        };
+        let mut wave_signals = Vec::new();
+
+        for (id, signal) in signals.iter().enumerate() {
+            let mut path: Vec<_> = signal.name.split('.').collect();
+            let name = path.pop().unwrap();
+
+            let mut scopes = Vec::new();
+            let mut prefix = String::new();
+            for x in &path {
+                prefix.push_str(x);
+                scopes.push((*x, instances.contains(&prefix)));
+                prefix.push('.');
+            }
+
+            let depth = 1 + scopes.iter().filter(|x| x.1).count();
+            if option.depth.map(|x| depth > x).unwrap_or(false) {
+                continue;
+            }
+
+            root.insert(&scopes, wave_signals.len());
+            wave_signals.push(WaveSignal {
+                id,
+                name: name.to_string(),
+                width: signal.width,
+                kind: signal.kind,
+            });
+        }
+
+        Self {
+            option,
+            root,
+            last: vec![None; wave_signals.len()],
+            signals: wave_signals,
+            records: Vec::new(),
+        }
+    }
+
+    /// Record changed values at the specified time
+    pub fn sample(&mut self, time: u64, values: &[Value]) {
+        if time < self.option.start || self.option.end.map(|x| time > x).unwrap_or(false) {
+            return;
+        }
+
+        let mut changes = Vec::new();
+        for (i, signal) in self.signals.iter().enumerate() {
+            let value = values[signal.id];
+            if self.last[i] != Some(value) {
+                self.last[i] = Some(value);
+                changes.push((i, value));
+            }
+        }
+
+        match self.records.last_mut() {
+            Some((last, x)) if *last == time => {
+                for (i, value) in changes {
+                    match x.iter_mut().find(|x| x.0 == i) {
+                        Some(x) => x.1 = value,
+                        None => x.push((i, value)),
+                    }
+                }
+            }
+            _ if changes.is_empty() => (),
+            _ => self.records.push((time, changes)),
+        }
+    }
+
+    pub fn write<T: Write>(&self, w: &mut T) -> io::Result<()> {
+        match self.option.format {
+            WaveFormat::Vcd => self.write_vcd(w),
+            WaveFormat::Fst => self.write_fst(w),
+        }
+    }
+
+    fn timescale(&self) -> String {
+        let exponent = self.option.timescale.clamp(-15, 2) as i32 + 15;
+        let unit = (exponent / 3).min(5);
+        let base = 10u32.pow((exponent - unit * 3) as u32);
+        let unit = ["fs", "ps", "ns", "us", "ms", "s"][unit as usize];
+        format!("{}{}", base, unit)
+    }
+
+    fn var_name(signal: &WaveSignal) -> String {
+        if signal.width > 1 {
+            format!("{} [{}:0]", signal.name, signal.width - 1)
+        } else {
+            signal.name.clone()
+        }
+    }
+
+    fn write_vcd<T: Write>(&self, w: &mut T) -> io::Result<()> {
+        writeln!(w, "$version")?;
+        writeln!(w, "    Veryl {}", env!("CARGO_PKG_VERSION"))?;
+        writeln!(w, "$end")?;
+        writeln!(w, "$timescale {} $end", self.timescale())?;
+        self.write_vcd_scope(w, &self.root)?;
+        writeln!(w, "$enddefinitions $end")?;
+
+        for (i, (time, changes)) in self.records.iter().enumerate() {
+            writeln!(w, "#{}", time)?;
+            if i == 0 {
+                writeln!(w, "$dumpvars")?;
+            }
+            for (id, value) in changes {
+                let code = vcd_identifier(*id);
+                if value.width() == 1 {
+                    writeln!(w, "{}{}", value.bit(0), code)?;
+                } else {
+                    writeln!(w, "b{} {}", bits(value), code)?;
+                }
+            }
+            if i == 0 {
+                writeln!(w, "$end")?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_vcd_scope<T: Write>(&self, w: &mut T, scope: &Scope) -> io::Result<()> {
+        let kind = if scope.instance { "module" } else { "begin" };
+        writeln!(w, "$scope {} {} $end", kind, scope.name)?;
+        for id in &scope.signals {
+            let signal = &self.signals[*id];
+            let kind = match signal.kind {
+                SignalKind::Variable => "reg",
+                _ => "wire",
+            };
+            writeln!(
+                w,
+                "$var {} {} {} {} $end",
+                kind,
+                signal.width,
+                vcd_identifier(*id),
+                Self::var_name(signal)
+            )?;
+        }
+        for x in &scope.scopes {
+            self.write_vcd_scope(w, x)?;
+        }
+        writeln!(w, "$upscope $end")
+    }
+
+    fn write_fst<T: Write>(&self, w: &mut T) -> io::Result<()> {
+        // signals are identified by handles in the order of appearance in the hierarchy
+        let mut order = Vec::new();
+        self.root.order(&mut order);
+        let mut handles = vec![0; self.signals.len()];
+        for (i, x) in order.iter().enumerate() {
+            handles[*x] = i;
+        }
+
+        let start = self.records.first().map(|x| x.0).unwrap_or(0);
+        let end = self.records.last().map(|x| x.0).unwrap_or(0);
+
+        let mut out = Vec::new();
+
+        out.push(FST_BL_HDR);
+        put_u64(&mut out, FST_HDR_SIZE);
+        put_u64(&mut out, start);
+        put_u64(&mut out, end);
+        out.extend(FST_DOUBLE_ENDTEST.to_le_bytes());
+        put_u64(&mut out, 0);
+        put_u64(&mut out, self.root.count() as u64);
+        put_u64(&mut out, order.len() as u64);
+        put_u64(&mut out, order.len() as u64);
+        put_u64(&mut out, !self.records.is_empty() as u64);
+        out.push(self.option.timescale as u8);
+        let mut version = format!("Veryl {}", env!("CARGO_PKG_VERSION")).into_bytes();
+        version.resize(FST_HDR_SIM_VERSION_SIZE, 0);
+        out.extend(version);
+        out.extend([0; FST_HDR_DATE_SIZE]);
+        out.push(FST_FT_VERILOG);
+        put_u64(&mut out, 0);
+
+        if !self.records.is_empty() {
+            let block = self.fst_value_changes(&order, &handles, start, end);
+            out.push(FST_BL_VCDATA);
+            put_u64(&mut out, block.len() as u64 + 8);
+            out.extend(block);
+        }
+
+        let mut geometry = Vec::new();
+        for x in &order {
+            put_varint(&mut geometry, self.signals[*x].width as u64);
+        }
+        let data = compress_if_smaller(&geometry);
+        out.push(FST_BL_GEOM);
+        put_u64(&mut out, data.len() as u64 + 24);
+        put_u64(&mut out, geometry.len() as u64);
+        put_u64(&mut out, order.len() as u64);
+        out.extend(data);
+
+        let mut hierarchy = Vec::new();
+        self.fst_scope(&mut hierarchy, &self.root);
+        let data = gzip(&hierarchy);
+        out.push(FST_BL_HIER);
+        put_u64(&mut out, data.len() as u64 + 16);
+        put_u64(&mut out, hierarchy.len() as u64);
+        out.extend(data);
+
+        w.write_all(&out)
+    }
+
+    fn fst_scope(&self, out: &mut Vec<u8>, scope: &Scope) {
+        out.push(FST_ST_VCD_SCOPE);
+        out.push(if scope.instance {
+            FST_ST_VCD_MODULE
+        } else {
+            FST_ST_VCD_BEGIN
+        });
+        put_string(out, &scope.name);
+        put_string(out, "");
+        for id in &scope.signals {
+            let signal = &self.signals[*id];
+            let (kind, direction) = match signal.kind {
+                SignalKind::Input => (FST_VT_VCD_WIRE, FST_VD_INPUT),
+                SignalKind::Output => (FST_VT_VCD_WIRE, FST_VD_OUTPUT),
+                SignalKind::Variable => (FST_VT_VCD_REG, FST_VD_IMPLICIT),
+            };
+            out.push(kind);
+            out.push(direction);
+            put_string(out, &Self::var_name(signal));
+            put_varint(out, signal.width as u64);
+            // no alias
+            put_varint(out, 0);
+        }
+        for x in &scope.scopes {
+            self.fst_scope(out, x);
+        }
+        out.push(FST_ST_VCD_UPSCOPE);
+    }
+
+    /// Value change block which contains the initial values, changes of each signals and the time table
+    fn fst_value_changes(
+        &self,
+        order: &[usize],
+        handles: &[usize],
+        start: u64,
+        end: u64,
+    ) -> Vec<u8> {
+        // the first record has the values of all signals
+        let mut frame = Vec::new();
+        let mut initial = vec![Value::x(0); self.signals.len()];
+        for (id, value) in &self.records[0].1 {
+            initial[*id] = *value;
+        }
+        for x in order {
+            frame.extend(bits(&initial[*x]).into_bytes());
+        }
+
+        let mut chains = vec![Vec::new(); order.len()];
+        let mut prev = vec![0; order.len()];
+        for (index, (_, changes)) in self.records.iter().enumerate().skip(1) {
+            for (id, value) in changes {
+                let handle = handles[*id];
+                let chain = &mut chains[handle];
+                let delta = (index - prev[handle]) as u64;
+                prev[handle] = index;
+                if value.width() == 1 {
+                    match value.bit(0) {
+                        '0' => put_varint(chain, delta << 2),
+                        '1' => put_varint(chain, delta << 2 | 2),
+                        'x' => put_varint(chain, delta << 4 | 1),
+                        _ => put_varint(chain, delta << 4 | 3),
+                    }
+                } else if value.is_known() {
+                    put_varint(chain, delta << 1);
+                    let mut byte = 0;
+                    for (i, x) in bits(value).bytes().enumerate() {
+                        byte |= (x & 1) << (7 - i % 8);
+                        if i % 8 == 7 {
+                            chain.push(byte);
+                            byte = 0;
+                        }
+                    }
+                    if value.width() % 8 != 0 {
+                        chain.push(byte);
+                    }
+                } else {
+                    put_varint(chain, delta << 1 | 1);
+                    chain.extend(bits(value).into_bytes());
+                }
+            }
+        }
+
+        let mut out = Vec::new();
+        put_u64(&mut out, start);
+        put_u64(&mut out, end);
+        put_u64(&mut out, chains.iter().map(|x| x.len() as u64).sum());
+
+        let data = compress_if_smaller(&frame);
+        put_varint(&mut out, frame.len() as u64);
+        put_varint(&mut out, data.len() as u64);
+        put_varint(&mut out, order.len() as u64);
+        out.extend(data);
+
+        put_varint(&mut out, order.len() as u64);
+        let base = out.len();
+        out.push(b'Z');
+        let mut positions = vec![0; order.len()];
+        for (i, chain) in chains.iter().enumerate() {
+            if chain.is_empty() {
+                continue;
+            }
+            positions[i] = out.len() - base;
+            let data = compress_to_vec_zlib(chain, 6);
+            if data.len() < chain.len() {
+                put_varint(&mut out, chain.len() as u64);
+                out.extend(data);
+            } else {
+                put_varint(&mut out, 0);
+                out.extend(chain);
+            }
+        }
+
+        // positions are encoded as deltas, and runs of signals without change are compressed
+        let table = out.len();
+        let mut last = 0;
+        let mut zeros = 0;
+        for position in positions {
+            if position == 0 {
+                zeros += 1;
+                continue;
+            }
+            if zeros != 0 {
+                put_varint(&mut out, zeros << 1);
+                zeros = 0;
+            }
+            put_varint(&mut out, ((position - last) as u64) << 1 | 1);
+            last = position;
+        }
+        if zeros != 0 {
+            put_varint(&mut out, zeros << 1);
+        }
+        let length = out.len() - table;
+        put_u64(&mut out, length as u64);
+
+        let mut times = Vec::new();
+        let mut last = 0;
+        for (time, _) in &self.records {
+            put_varint(&mut times, time - last);
+            last = *time;
+        }
+        let data = compress_if_smaller(&times);
+        out.extend(&data);
+        put_u64(&mut out, times.len() as u64);
+        put_u64(&mut out, data.len() as u64);
+        put_u64(&mut out, self.records.len() as u64);
+
+        out
+    }
+}
+
+const FST_BL_HDR: u8 = 0;
+const FST_BL_VCDATA: u8 = 1;
+const FST_BL_GEOM: u8 = 3;
+const FST_BL_HIER: u8 = 4;
+const FST_HDR_SIZE: u64 = 329;
+const FST_HDR_SIM_VERSION_SIZE: usize = 128;
+const FST_HDR_DATE_SIZE: usize = 119;
+const FST_DOUBLE_ENDTEST: f64 = std::f64::consts::E;
+const FST_FT_VERILOG: u8 = 0;
+const FST_ST_VCD_MODULE: u8 = 0;
+const FST_ST_VCD_BEGIN: u8 = 3;
+const FST_ST_VCD_SCOPE: u8 = 254;
+const FST_ST_VCD_UPSCOPE: u8 = 255;
+const FST_VT_VCD_REG: u8 = 5;
+const FST_VT_VCD_WIRE: u8 = 16;
+const FST_VD_IMPLICIT: u8 = 0;
+const FST_VD_INPUT: u8 = 1;
+const FST_VD_OUTPUT: u8 = 2;
+
+/// Bits from MSB
+fn bits(value: &Value) -> String {
+    (0..value.width()).rev().map(|x| value.bit(x)).collect()
+}
+
+/// Identifier code of VCD which consists of printable characters from `!` to `~`
+fn vcd_identifier(mut id: usize) -> String {
+    let mut ret = String::new();
+    loop {
+        ret.push((b'!' + (id % 94) as u8) as char);
+        id /= 94;
+        if id == 0 {
+            break;
+        }
+    }
+    ret
+}
+
+fn put_u64(out: &mut Vec<u8>, value: u64) {
+    out.extend(value.to_be_bytes());
+}
+
+fn put_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value & 0x7f) as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn put_string(out: &mut Vec<u8>, value: &str) {
+    out.extend(value.as_bytes());
+    out.push(0);
+}
+
+/// Data is stored without compression if the compressed one is not smaller,
+/// and readers distinguish it by the compressed length.
+fn compress_if_smaller(data: &[u8]) -> Vec<u8> {
+    let compressed = compress_to_vec_zlib(data, 6);
+    if compressed.len() < data.len() {
+        compressed
+    } else {
+        data.to_vec()
+    }
+}
+
+fn gzip(data: &[u8]) -> Vec<u8> {
+    let mut ret = vec![0x1f, 0x8b, 8, 0, 0, 0, 0, 0, 0, 0xff];
+    ret.extend(compress_to_vec(data, 6));
+    ret.extend(crc32(data).to_le_bytes());
+    ret.extend((data.len() as u32).to_le_bytes());
+    ret
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for x in data {
+        crc ^= *x as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Simulator;
+    use veryl_analyzer::Analyzer;
+    use veryl_parser::Parser;
+
+    const CODE: &str = r#"
+module Sub (
+    i_clk: input  logic   ,
+    i_rst: input  logic   ,
+    o_cnt: output logic[2],
+) {
+    var r_cnt: logic[2];
+
+    assign o_cnt = r_cnt;
+
+    always_ff (i_clk, i_rst) {
+        if_reset {
+            r_cnt = 0;
+        } else {
+            r_cnt += 1;
+        }
+    }
+}
+
+module Top (
+    i_clk: input  logic   ,
+    i_rst: input  logic   ,
+    o_cnt: output logic[2],
+) {
+    inst u_sub: Sub (
+        i_clk    ,
+        i_rst    ,
+        o_cnt    ,
+    );
+}
+"#;
+
+    fn simulate(option: WaveOption) -> Vec<u8> {
+        let parser = Parser::parse(CODE, &"").unwrap();
+        let mut analyzer = Analyzer::new(CODE);
+        let errors = analyzer.analyze(&parser.veryl);
+        assert!(errors.is_empty());
+
+        let mut sim = Simulator::default();
+        sim.add(&parser.veryl);
+        sim.elaborate("Top").unwrap();
+        sim.dump(option);
+        sim.poke("i_clk", Value::new(0, 1)).unwrap();
+        sim.poke("i_rst", Value::new(0, 1)).unwrap();
+        sim.eval().unwrap();
+        sim.poke("i_rst", Value::new(1, 1)).unwrap();
+        for _ in 0..3 {
+            sim.step("i_clk").unwrap();
+        }
+
+        let mut ret = Vec::new();
+        sim.wave().unwrap().write(&mut ret).unwrap();
+        ret
+    }
+
+    #[test]
+    fn vcd() {
+        let vcd = String::from_utf8(simulate(WaveOption::default())).unwrap();
+        let expect = r##"$version
+    Veryl VERSION
+$end
+$timescale 1ns $end
+$scope module Top $end
+$var wire 1 ! i_clk $end
+$var wire 1 " i_rst $end
+$var wire 2 # o_cnt [1:0] $end
+$scope module u_sub $end
+$var wire 1 $ i_clk $end
+$var wire 1 % i_rst $end
+$var wire 2 & o_cnt [1:0] $end
+$var reg 2 ' r_cnt [1:0] $end
+$upscope $end
+$upscope $end
+$enddefinitions $end
+#0
+$dumpvars
+0!
+0"
+b00 #
+0$
+0%
+b00 &
+b00 '
+$end
+#1
+1!
+1"
+b01 #
+1$
+1%
+b01 &
+b01 '
+#2
+0!
+0$
+#3
+1!
+b10 #
+1$
+b10 &
+b10 '
+#4
+0!
+0$
+#5
+1!
+b11 #
+1$
+b11 &
+b11 '
+#6
+0!
+0$
+"##;
+        assert_eq!(vcd, expect.replace("VERSION", env!("CARGO_PKG_VERSION")));
+    }
+
+    #[test]
+    fn vcd_option() {
+        let option = WaveOption {
+            depth: Some(1),
+            start: 2,
+            end: Some(3),
+            timescale: -10,
+            ..Default::default()
+        };
+        let vcd = String::from_utf8(simulate(option)).unwrap();
+        assert!(vcd.contains("$timescale 100ps $end"));
+        assert!(!vcd.contains("u_sub"));
+        assert!(vcd.contains("#2\n$dumpvars\n0!\n1\"\nb01 #\n$end\n#3\n1!\nb10 #\n"));
+        assert!(!vcd.contains("#4"));
+    }
+
+    /// Check the block layout and header fields only
+    #[test]
+    fn fst_layout() {
+        let option = WaveOption {
+            format: WaveFormat::Fst,
+            ..Default::default()
+        };
+        let fst = simulate(option);
+
+        // walk blocks by the section length
+        let u64_at = |x: usize| u64::from_be_bytes(fst[x..x + 8].try_into().unwrap());
+        let mut blocks = Vec::new();
+        let mut pos = 0;
+        while pos < fst.len() {
+            blocks.push(fst[pos]);
+            pos += 1 + u64_at(pos + 1) as usize;
+        }
+        assert_eq!(pos, fst.len());
+        assert_eq!(
+            blocks,
+            vec![FST_BL_HDR, FST_BL_VCDATA, FST_BL_GEOM, FST_BL_HIER]
+        );
+
+        // start time, end time, scopes, vars and handles in header
+        assert_eq!(u64_at(9), 0);
+        assert_eq!(u64_at(17), 6);
+        assert_eq!(u64_at(41), 2);
+        assert_eq!(u64_at(49), 7);
+        assert_eq!(u64_at(57), 7);
+
+        // time table at the end of value change block
+        let end = 1 + FST_HDR_SIZE as usize + 1 + u64_at(FST_HDR_SIZE as usize + 2) as usize;
+        assert_eq!(u64_at(end - 8), 7);
+    }
+
+    #[test]
+    fn gzip_crc() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+}
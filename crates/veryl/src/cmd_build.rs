@@ -4,8 +4,8 @@ use std::fs;
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::time::Instant;
-use veryl_emitter::Emitter;
-use veryl_metadata::{Metadata, Target};
+use veryl_emitter::{Emitter, Netlist};
+use veryl_metadata::{Metadata, NetlistFormat};
 use veryl_parser::miette::{IntoDiagnostic, Result, WrapErr};
 use veryl_parser::Parser;
 
@@ -25,6 +25,7 @@ impl CmdBuild {
             self.opt.files.clone()
         };
 
+        let mut all_pass = true;
         let now = Instant::now();
 
         for file in &files {
@@ -36,26 +37,56 @@ impl CmdBuild {
             let input = fs::read_to_string(file).into_diagnostic().wrap_err("")?;
             let parser = Parser::parse(&input, file)?;
             let mut emitter = Emitter::new(metadata);
-            emitter.emit(&parser.veryl);
+            emitter.emit_with_header(&parser.veryl, file, &input);
 
-            let output = match metadata.build.target {
-                Target::Source => file.with_extension("sv"),
-                Target::Directory { ref path } => {
-                    let base = metadata.metadata_path.parent().unwrap().to_owned();
-                    base.join(path.join(file.with_extension("sv").file_name().unwrap()))
-                }
-            };
+            let source = file;
+            let output = utils::output_path(metadata, file);
 
             self.print(&format!("[Info] Output file: {}", output.to_string_lossy()));
             let mut file = OpenOptions::new()
                 .create(true)
                 .write(true)
                 .truncate(true)
-                .open(output)
+                .open(&output)
                 .into_diagnostic()?;
             file.write_all(emitter.as_str().as_bytes())
                 .into_diagnostic()?;
             file.flush().into_diagnostic()?;
+
+            if let Some(format) = metadata.build.netlist {
+                let mut netlist = Netlist::new(metadata);
+                netlist.build(&parser.veryl);
+
+                let output = match format {
+                    NetlistFormat::Json => output.with_extension("json"),
+                    NetlistFormat::Rtlil => output.with_extension("il"),
+                };
+
+                // incomplete netlist is not written to avoid passing it to synthesis
+                if !netlist.warnings.is_empty() {
+                    all_pass = false;
+                    if !self.opt.quiet {
+                        for warning in &netlist.warnings {
+                            eprintln!("[Warning] {}:{}", source.to_string_lossy(), warning);
+                        }
+                        eprintln!(
+                            "[Warning] Netlist is not written: {}",
+                            output.to_string_lossy()
+                        );
+                    }
+                    if output.exists() {
+                        fs::remove_file(&output).into_diagnostic()?;
+                    }
+                    continue;
+                }
+
+                let text = match format {
+                    NetlistFormat::Json => netlist.to_json(),
+                    NetlistFormat::Rtlil => netlist.to_rtlil(),
+                };
+                self.print(&format!("[Info] Output file: {}", output.to_string_lossy()));
+                fs::write(output, text).into_diagnostic()?;
+            }
         }
 
         let elapsed_time = now.elapsed();
@@ -64,7 +95,7 @@ impl CmdBuild {
             elapsed_time.as_millis()
         ));
 
-        Ok(true)
+        Ok(all_pass)
     }
 
     fn print(&self, msg: &str) {
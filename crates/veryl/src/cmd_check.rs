@@ -1,15 +1,18 @@
 use crate::utils;
 use crate::OptCheck;
 use std::fs;
+use std::path::Path;
 use std::time::Instant;
 use thiserror::Error;
 use veryl_analyzer::{AnalyzeError, Analyzer};
+use veryl_emitter::{Header, HeaderStatus};
 use veryl_metadata::Metadata;
 use veryl_parser::miette::{self, Diagnostic, IntoDiagnostic, Result, WrapErr};
 use veryl_parser::Parser;
 
 pub struct CmdCheck {
     opt: OptCheck,
+    generated: bool,
 }
 
 #[derive(Error, Diagnostic, Debug, Default)]
@@ -21,10 +24,19 @@ pub struct CheckError {
 
 impl CmdCheck {
     pub fn new(opt: OptCheck) -> Self {
-        Self { opt }
+        Self {
+            opt,
+            generated: true,
+        }
+    }
+
+    /// Skip checking generated files which are overwritten by the following build
+    pub fn skip_generated(mut self) -> Self {
+        self.generated = false;
+        self
     }
 
-    pub fn exec(&self, _metadata: &Metadata) -> Result<bool> {
+    pub fn exec(&self, metadata: &Metadata) -> Result<bool> {
         let files = if self.opt.files.is_empty() {
             utils::gather_files("./")?
         } else {
@@ -47,6 +59,11 @@ impl CmdCheck {
 
             let mut analyzer = Analyzer::new(&input);
             let errors = analyzer.analyze(&parser.veryl);
+            if !self.opt.quiet {
+                for warning in analyzer.take_warnings() {
+                    eprintln!("{:?}", miette::Report::new(warning));
+                }
+            }
             if !errors.is_empty() {
                 all_pass = false;
 
@@ -54,6 +71,13 @@ impl CmdCheck {
                     check_error.related.push(error);
                 }
             }
+
+            if self.generated
+                && metadata.build.header
+                && !self.check_generated(metadata, file, &input)
+            {
+                all_pass = false;
+            }
         }
 
         let elapsed_time = now.elapsed();
@@ -69,6 +93,24 @@ impl CmdCheck {
         }
     }
 
+    /// Return `false` if the generated file is out of sync with the source
+    fn check_generated(&self, metadata: &Metadata, file: &Path, input: &str) -> bool {
+        let output = utils::output_path(metadata, file);
+        if let Ok(generated) = fs::read_to_string(&output) {
+            let msg = match Header::check(&generated, input) {
+                HeaderStatus::Stale => "is older than the source",
+                HeaderStatus::Modified => "was edited after generation",
+                HeaderStatus::UpToDate | HeaderStatus::NotGenerated => return true,
+            };
+            if !self.opt.quiet {
+                eprintln!("[Error] {} {}", output.to_string_lossy(), msg);
+            }
+            false
+        } else {
+            true
+        }
+    }
+
     fn print(&self, msg: &str) {
         if self.opt.verbose {
             println!("{}", msg);
@@ -0,0 +1,655 @@
+use crate::OptDoc;
+use std::fs;
+use std::time::Instant;
+use veryl_analyzer::symbol::{
+    DocComment, Parameter, ParameterScope, Port, Symbol, SymbolKind, Type,
+};
+use veryl_analyzer::symbol_table;
+use veryl_metadata::Metadata;
+use veryl_parser::miette::{IntoDiagnostic, Result};
+use veryl_parser::resource_table::StrId;
+use veryl_parser::veryl_grammar_trait::{Expression, ParameterValue};
+use veryl_parser::veryl_walker::VerylWalker;
+use veryl_parser::Stringifier;
+
+pub struct CmdDoc {
+    opt: OptDoc,
+}
+
+impl CmdDoc {
+    pub fn new(opt: OptDoc) -> Self {
+        Self { opt }
+    }
+
+    /// Build the documentation from the symbol table which is filled by `veryl check`
+    pub fn exec(&self, metadata: &Metadata) -> Result<bool> {
+        let now = Instant::now();
+
+        let builder = DocBuilder::new(metadata, symbol_table::get_all());
+
+        let base = metadata.metadata_path.parent().unwrap();
+        let output = base.join(&metadata.doc.path);
+        fs::create_dir_all(&output).into_diagnostic()?;
+
+        for (name, text) in builder.build() {
+            let path = output.join(name);
+            self.print(&format!("[Info] Output file: {}", path.to_string_lossy()));
+            fs::write(path, text).into_diagnostic()?;
+        }
+
+        let elapsed_time = now.elapsed();
+        self.print(&format!(
+            "[Info] Elapsed time: {} milliseconds.",
+            elapsed_time.as_millis()
+        ));
+
+        Ok(true)
+    }
+
+    fn print(&self, msg: &str) {
+        if self.opt.verbose {
+            println!("{}", msg);
+        }
+    }
+}
+
+const STYLE: &str = r#"body {
+    font-family: sans-serif;
+    margin: 0;
+    color: #222;
+}
+nav {
+    padding: 0.5em 2em;
+    background: #2b3a55;
+}
+nav a {
+    color: #fff;
+    font-weight: bold;
+    text-decoration: none;
+}
+main {
+    padding: 1em 2em;
+    max-width: 60em;
+}
+table {
+    border-collapse: collapse;
+    margin-bottom: 1em;
+}
+th, td {
+    border: 1px solid #ccc;
+    padding: 0.25em 0.75em;
+    text-align: left;
+    vertical-align: top;
+}
+th {
+    background: #f0f0f0;
+}
+code {
+    font-family: monospace;
+}
+"#;
+
+struct DocBuilder<'a> {
+    metadata: &'a Metadata,
+    symbols: Vec<Symbol>,
+    /// Modules, interfaces and packages which have their own page
+    items: Vec<Symbol>,
+}
+
+impl<'a> DocBuilder<'a> {
+    fn new(metadata: &'a Metadata, mut symbols: Vec<Symbol>) -> Self {
+        symbols.sort_by_key(|x| (x.token.file_path, x.token.id));
+        let mut items: Vec<_> = symbols
+            .iter()
+            .filter(|x| {
+                x.namespace.depth() == 0
+                    && matches!(
+                        x.kind,
+                        SymbolKind::Module(_) | SymbolKind::Interface(_) | SymbolKind::Package
+                    )
+            })
+            .cloned()
+            .collect();
+        items.sort_by_key(|x| x.token.text.to_string());
+        Self {
+            metadata,
+            symbols,
+            items,
+        }
+    }
+
+    fn build(&self) -> Vec<(String, String)> {
+        let mut ret = vec![
+            ("style.css".to_string(), STYLE.to_string()),
+            ("index.html".to_string(), self.index()),
+        ];
+        for item in &self.items {
+            ret.push((page_name(item), self.item(item)));
+        }
+        ret
+    }
+
+    fn index(&self) -> String {
+        let package = &self.metadata.package;
+        let mut body = format!("<h1>{} {}</h1>\n", escape(&package.name), package.version);
+        if let Some(ref x) = package.description {
+            body.push_str(&format!("<p>{}</p>\n", escape(x)));
+        }
+
+        for (title, kind) in [
+            ("Modules", "module"),
+            ("Interfaces", "interface"),
+            ("Packages", "package"),
+        ] {
+            let items: Vec<_> = self
+                .items
+                .iter()
+                .filter(|x| x.kind.to_kind_name() == kind)
+                .collect();
+            if items.is_empty() {
+                continue;
+            }
+            body.push_str(&format!("<h2>{}</h2>\n<table>\n", title));
+            for item in items {
+                body.push_str(&format!(
+                    "<tr><td>{}</td><td>{}</td></tr>\n",
+                    self.link(item.token.text),
+                    escape(&item.doc_comment.summary())
+                ));
+            }
+            body.push_str("</table>\n");
+        }
+
+        // modules which are not instantiated from any other module
+        let tops: Vec<_> = self
+            .items
+            .iter()
+            .filter(|x| matches!(x.kind, SymbolKind::Module(_)))
+            .filter(|x| self.parents(x.token.text).is_empty())
+            .collect();
+        if !tops.is_empty() {
+            body.push_str("<h2>Hierarchy</h2>\n<ul>\n");
+            for top in tops {
+                let name = top.token.text;
+                body.push_str(&format!(
+                    "<li>{}{}</li>\n",
+                    self.link(name),
+                    self.hierarchy(name, &mut vec![name])
+                ));
+            }
+            body.push_str("</ul>\n");
+        }
+
+        self.page(&package.name, &body)
+    }
+
+    fn item(&self, item: &Symbol) -> String {
+        let name = item.token.text;
+        let kind = item.kind.to_kind_name();
+        let mut body = format!("<h1>{} <code>{}</code></h1>\n", kind, name);
+        body.push_str(&doc_comment(&item.doc_comment));
+
+        match item.kind {
+            SymbolKind::Module(ref x) => {
+                body.push_str(&self.parameters(&x.parameters, &[name], 2));
+                body.push_str(&self.ports(&x.ports, &[name], 2));
+
+                let instances = self.instances(name);
+                if !instances.is_empty() {
+                    body.push_str("<h2>Instances</h2>\n<ul>\n");
+                    for (inst, type_name) in instances {
+                        body.push_str(&format!(
+                            "<li><code>{}</code>: {}{}</li>\n",
+                            inst,
+                            self.link(type_name),
+                            self.hierarchy(type_name, &mut vec![name, type_name])
+                        ));
+                    }
+                    body.push_str("</ul>\n");
+                }
+
+                let parents = self.parents(name);
+                if !parents.is_empty() {
+                    body.push_str("<h2>Instantiated by</h2>\n<ul>\n");
+                    for parent in parents {
+                        body.push_str(&format!("<li>{}</li>\n", self.link(parent)));
+                    }
+                    body.push_str("</ul>\n");
+                }
+            }
+            SymbolKind::Interface(ref x) => {
+                body.push_str(&self.parameters(&x.parameters, &[name], 2));
+            }
+            SymbolKind::Package => {
+                let parameters: Vec<_> = self
+                    .members(name)
+                    .filter_map(|x| match x.kind {
+                        SymbolKind::Parameter(ref property) => Some(Parameter {
+                            name: x.token.text,
+                            property: property.clone(),
+                        }),
+                        _ => None,
+                    })
+                    .collect();
+                body.push_str(&self.parameters(&parameters, &[name], 2));
+                body.push_str(&self.types(name));
+                body.push_str(&self.variables(name));
+            }
+            _ => (),
+        }
+
+        body.push_str(&self.functions(name));
+
+        self.page(&format!("{} {}", kind, name), &body)
+    }
+
+    fn parameters(&self, parameters: &[Parameter], namespace: &[StrId], level: usize) -> String {
+        if parameters.is_empty() {
+            return String::new();
+        }
+        let mut ret = format!("<h{0}>Parameters</h{0}>\n<table>\n", level);
+        ret.push_str(
+            "<tr><th>Name</th><th>Scope</th><th>Type</th><th>Default</th><th>Description</th></tr>\n",
+        );
+        for x in parameters {
+            let scope = match x.property.scope {
+                ParameterScope::Global => "parameter",
+                ParameterScope::Local => "localparam",
+            };
+            ret.push_str(&format!(
+                "<tr><td><code>{}</code></td><td>{}</td><td><code>{}</code></td><td><code>{}</code></td><td>{}</td></tr>\n",
+                x.name,
+                scope,
+                escape(&type_text(&x.property.r#type)),
+                escape(&parameter_value_text(&x.property.value)),
+                self.description(x.name, namespace)
+            ));
+        }
+        ret.push_str("</table>\n");
+        ret
+    }
+
+    fn ports(&self, ports: &[Port], namespace: &[StrId], level: usize) -> String {
+        if ports.is_empty() {
+            return String::new();
+        }
+        let mut ret = format!("<h{0}>Ports</h{0}>\n<table>\n", level);
+        ret.push_str("<tr><th>Name</th><th>Direction</th><th>Type</th><th>Description</th></tr>\n");
+        for x in ports {
+            let r#type = if let Some(ref x) = x.property.r#type {
+                type_text(x)
+            } else {
+                String::new()
+            };
+            ret.push_str(&format!(
+                "<tr><td><code>{}</code></td><td>{}</td><td><code>{}</code></td><td>{}</td></tr>\n",
+                x.name,
+                x.property.direction,
+                escape(&r#type),
+                self.description(x.name, namespace)
+            ));
+        }
+        ret.push_str("</table>\n");
+        ret
+    }
+
+    fn types(&self, owner: StrId) -> String {
+        let mut ret = String::new();
+        for x in self.members(owner) {
+            if matches!(
+                x.kind,
+                SymbolKind::Type(_) | SymbolKind::Struct | SymbolKind::Union | SymbolKind::Enum
+            ) {
+                if ret.is_empty() {
+                    ret.push_str("<h2>Types</h2>\n<table>\n");
+                    ret.push_str("<tr><th>Name</th><th>Kind</th><th>Description</th></tr>\n");
+                }
+                ret.push_str(&format!(
+                    "<tr><td><code>{}</code></td><td>{}</td><td>{}</td></tr>\n",
+                    x.token.text,
+                    x.kind.to_kind_name(),
+                    self.description(x.token.text, &[owner])
+                ));
+            }
+        }
+        if !ret.is_empty() {
+            ret.push_str("</table>\n");
+        }
+        ret
+    }
+
+    fn variables(&self, owner: StrId) -> String {
+        let mut ret = String::new();
+        for x in self.members(owner) {
+            if let SymbolKind::Variable(ref property) = x.kind {
+                if ret.is_empty() {
+                    ret.push_str("<h2>Variables</h2>\n<table>\n");
+                    ret.push_str("<tr><th>Name</th><th>Type</th><th>Description</th></tr>\n");
+                }
+                ret.push_str(&format!(
+                    "<tr><td><code>{}</code></td><td><code>{}</code></td><td>{}</td></tr>\n",
+                    x.token.text,
+                    escape(&type_text(&property.r#type)),
+                    self.description(x.token.text, &[owner])
+                ));
+            }
+        }
+        if !ret.is_empty() {
+            ret.push_str("</table>\n");
+        }
+        ret
+    }
+
+    fn functions(&self, owner: StrId) -> String {
+        let mut ret = String::new();
+        for x in self.members(owner) {
+            if let SymbolKind::Function(ref property) = x.kind {
+                let name = x.token.text;
+                if ret.is_empty() {
+                    ret.push_str("<h2>Functions</h2>\n");
+                }
+                ret.push_str(&format!("<h3><code>{}</code></h3>\n", name));
+                ret.push_str(&doc_comment(&x.doc_comment));
+                ret.push_str(&self.parameters(&property.parameters, &[owner, name], 4));
+                ret.push_str(&self.ports(&property.ports, &[owner, name], 4));
+            }
+        }
+        ret
+    }
+
+    /// Symbols declared directly under the item
+    fn members(&self, owner: StrId) -> impl Iterator<Item = &Symbol> {
+        self.symbols
+            .iter()
+            .filter(move |x| x.namespace.paths == [owner])
+    }
+
+    /// Instance names and their types in the module including generate blocks
+    fn instances(&self, owner: StrId) -> Vec<(StrId, StrId)> {
+        self.symbols
+            .iter()
+            .filter(|x| x.namespace.paths.first() == Some(&owner))
+            .filter_map(|x| match x.kind {
+                SymbolKind::Instance(ref property) => Some((x.token.text, property.type_name)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn parents(&self, name: StrId) -> Vec<StrId> {
+        let mut ret = Vec::new();
+        for item in &self.items {
+            let parent = item.token.text;
+            if !ret.contains(&parent) && self.instances(parent).iter().any(|x| x.1 == name) {
+                ret.push(parent);
+            }
+        }
+        ret
+    }
+
+    /// Nested list of instances under the module
+    fn hierarchy(&self, name: StrId, stack: &mut Vec<StrId>) -> String {
+        let instances = self.instances(name);
+        if instances.is_empty() {
+            return String::new();
+        }
+        let mut ret = "\n<ul>\n".to_string();
+        for (inst, type_name) in instances {
+            // recursive instantiation is reported by the analyzer
+            let children = if stack.contains(&type_name) {
+                String::new()
+            } else {
+                stack.push(type_name);
+                let children = self.hierarchy(type_name, stack);
+                stack.pop();
+                children
+            };
+            ret.push_str(&format!(
+                "<li><code>{}</code>: {}{}</li>\n",
+                inst,
+                self.link(type_name),
+                children
+            ));
+        }
+        ret.push_str("</ul>\n");
+        ret
+    }
+
+    fn description(&self, name: StrId, namespace: &[StrId]) -> String {
+        self.symbols
+            .iter()
+            .find(|x| x.token.text == name && x.namespace.paths == namespace)
+            .map(|x| escape(&x.doc_comment.text()).replace('\n', "<br>"))
+            .unwrap_or_default()
+    }
+
+    fn link(&self, name: StrId) -> String {
+        if let Some(item) = self.items.iter().find(|x| x.token.text == name) {
+            format!("<a href=\"{}\"><code>{}</code></a>", page_name(item), name)
+        } else {
+            format!("<code>{}</code>", name)
+        }
+    }
+
+    fn page(&self, title: &str, body: &str) -> String {
+        format!(
+            r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{}</title>
+<link rel="stylesheet" href="style.css">
+</head>
+<body>
+<nav><a href="index.html">{}</a></nav>
+<main>
+{}</main>
+</body>
+</html>
+"#,
+            escape(title),
+            escape(&self.metadata.package.name),
+            body
+        )
+    }
+}
+
+/// Page name prefixed by the kind not to conflict with `index.html` and the other kinds
+fn page_name(item: &Symbol) -> String {
+    format!("{}.{}.html", item.kind.to_kind_name(), item.token.text)
+}
+
+fn doc_comment(doc: &DocComment) -> String {
+    let mut ret = String::new();
+    for paragraph in doc.text().split("\n\n") {
+        if !paragraph.trim().is_empty() {
+            ret.push_str(&format!("<p>{}</p>\n", escape(paragraph.trim())));
+        }
+    }
+    ret
+}
+
+fn type_text(x: &Type) -> String {
+    let mut ret = x.to_string();
+    if !x.width.is_empty() {
+        ret.push(' ');
+        for x in &x.width {
+            ret.push_str(&format!("[{}]", expression_text(x)));
+        }
+    }
+    if !x.array.is_empty() {
+        ret.push_str(" unpacked ");
+        for x in &x.array {
+            ret.push_str(&format!("[{}]", expression_text(x)));
+        }
+    }
+    ret
+}
+
+fn expression_text(x: &Expression) -> String {
+    let mut stringifier = Stringifier::new();
+    stringifier.expression(x);
+    stringifier.as_str().to_string()
+}
+
+fn parameter_value_text(x: &ParameterValue) -> String {
+    let mut stringifier = Stringifier::new();
+    stringifier.parameter_value(x);
+    stringifier.as_str().to_string()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use veryl_analyzer::Analyzer;
+    use veryl_metadata::semver::Version;
+    use veryl_metadata::{Build, Doc, Format, Package, Test};
+    use veryl_parser::Parser;
+
+    const CODE: &str = r#"/// Top & entry
+module index #(
+    /// Width of <data>
+    parameter WIDTH: u32 = 8,
+) (
+    /// Clock
+    i_clk: input logic,
+    o_d  : output logic [WIDTH],
+) {
+    inst u_leaf: Leaf;
+}
+
+module Leaf {}
+
+/// Shared definitions
+package PkgA {
+    /// Bus state
+    enum State: logic [2] {
+        Idle,
+        Busy,
+    }
+
+    /// Request payload
+    struct Request {
+        addr: logic [8],
+    }
+
+    /// Data word
+    type word = logic [16];
+
+    /// Debug counter
+    var count: logic [8];
+}
+"#;
+
+    fn metadata() -> Metadata {
+        Metadata {
+            package: Package {
+                name: "test".to_string(),
+                version: Version::new(0, 1, 0),
+                authors: Vec::new(),
+                description: Some("a < b".to_string()),
+                license: None,
+                repository: None,
+            },
+            build: Build::default(),
+            format: Format::default(),
+            test: Test::default(),
+            doc: Doc::default(),
+            metadata_path: Default::default(),
+        }
+    }
+
+    fn build() -> Vec<(String, String)> {
+        let parser = Parser::parse(CODE, &"").unwrap();
+        let mut analyzer = Analyzer::new(CODE);
+        let errors = analyzer.analyze(&parser.veryl);
+        assert!(errors.is_empty());
+
+        let metadata = metadata();
+        let builder = DocBuilder::new(&metadata, symbol_table::get_all());
+        builder.build()
+    }
+
+    fn page<'a>(pages: &'a [(String, String)], name: &str) -> &'a str {
+        &pages.iter().find(|x| x.0 == name).unwrap().1
+    }
+
+    #[test]
+    fn page_names() {
+        let pages = build();
+        let names: Vec<_> = pages.iter().map(|x| x.0.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "style.css",
+                "index.html",
+                "module.Leaf.html",
+                "package.PkgA.html",
+                "module.index.html"
+            ]
+        );
+    }
+
+    #[test]
+    fn tables() {
+        let pages = build();
+        let module = page(&pages, "module.index.html");
+        assert!(module.contains("<h1>module <code>index</code></h1>\n<p>Top &amp; entry</p>\n"));
+        assert!(module.contains(
+            "<tr><td><code>WIDTH</code></td><td>parameter</td><td><code>u32</code></td><td><code>8</code></td><td>Width of &lt;data&gt;</td></tr>\n"
+        ));
+        assert!(module.contains(
+            "<tr><td><code>i_clk</code></td><td>input</td><td><code>logic</code></td><td>Clock</td></tr>\n"
+        ));
+        assert!(module.contains(
+            "<tr><td><code>o_d</code></td><td>output</td><td><code>logic [WIDTH]</code></td><td></td></tr>\n"
+        ));
+    }
+
+    #[test]
+    fn package_items() {
+        let pages = build();
+        let package = page(&pages, "package.PkgA.html");
+        assert!(package.contains("<h1>package <code>PkgA</code></h1>\n<p>Shared definitions</p>\n"));
+        assert!(package
+            .contains("<tr><td><code>State</code></td><td>enum</td><td>Bus state</td></tr>\n"));
+        assert!(package.contains(
+            "<tr><td><code>Request</code></td><td>struct</td><td>Request payload</td></tr>\n"
+        ));
+        assert!(package
+            .contains("<tr><td><code>word</code></td><td>type</td><td>Data word</td></tr>\n"));
+        assert!(package.contains(
+            "<tr><td><code>count</code></td><td><code>logic [8]</code></td><td>Debug counter</td></tr>\n"
+        ));
+    }
+
+    #[test]
+    fn hierarchy() {
+        let pages = build();
+        let index = page(&pages, "index.html");
+        assert!(index.contains("<h1>test 0.1.0</h1>\n<p>a &lt; b</p>\n"));
+        assert!(index.contains(
+            "<h2>Hierarchy</h2>\n<ul>\n<li><a href=\"module.index.html\"><code>index</code></a>\n<ul>\n<li><code>u_leaf</code>: <a href=\"module.Leaf.html\"><code>Leaf</code></a></li>\n</ul>\n</li>\n</ul>\n"
+        ));
+
+        let leaf = page(&pages, "module.Leaf.html");
+        assert!(leaf.contains(
+            "<h2>Instantiated by</h2>\n<ul>\n<li><a href=\"module.index.html\"><code>index</code></a></li>\n</ul>\n"
+        ));
+    }
+
+    #[test]
+    fn escape_html() {
+        assert_eq!(
+            escape(r#"<a href="x">&</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;"
+        );
+    }
+}
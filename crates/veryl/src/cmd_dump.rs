@@ -45,6 +45,10 @@ impl CmdDump {
             println!("{}", veryl_analyzer::namespace_table::dump());
         }
 
+        if self.opt.reference_table {
+            println!("{}", veryl_analyzer::reference_table::dump());
+        }
+
         let elapsed_time = now.elapsed();
         self.print(&format!(
             "[Info] Elapsed time: {} milliseconds.",
@@ -0,0 +1,149 @@
+use crate::utils;
+use crate::OptTest;
+use std::fs;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use veryl_metadata::{Metadata, WaveformFormat};
+use veryl_parser::miette::{IntoDiagnostic, Result, WrapErr};
+use veryl_parser::veryl_grammar_trait::{Description, TestDeclaration};
+use veryl_parser::Parser;
+use veryl_simulator::{Simulator, SimulatorError, WaveFormat, WaveOption};
+
+pub struct CmdTest {
+    opt: OptTest,
+}
+
+impl CmdTest {
+    pub fn new(opt: OptTest) -> Self {
+        Self { opt }
+    }
+
+    pub fn exec(&self, metadata: &Metadata) -> Result<bool> {
+        let files = if self.opt.files.is_empty() {
+            utils::gather_files("./")?
+        } else {
+            self.opt.files.clone()
+        };
+
+        let now = Instant::now();
+
+        let mut simulator = Simulator::new(metadata);
+        let mut tests: Vec<(PathBuf, TestDeclaration)> = Vec::new();
+
+        for file in &files {
+            self.print(&format!(
+                "[Info] Processing file: {}",
+                file.to_string_lossy()
+            ));
+
+            let input = fs::read_to_string(file).into_diagnostic().wrap_err("")?;
+            let parser = Parser::parse(&input, file)?;
+            simulator.add(&parser.veryl);
+
+            for x in &parser.veryl.veryl_list {
+                if let Description::TestDeclaration(x) = &*x.description {
+                    tests.push((file.clone(), *x.test_declaration.clone()));
+                }
+            }
+        }
+
+        if !self.opt.quiet {
+            let plural = if tests.len() == 1 { "" } else { "s" };
+            println!("running {} test{}", tests.len(), plural);
+        }
+
+        let mut passed = 0;
+        let mut failed = 0;
+        for (file, test) in &tests {
+            let name = test.identifier.identifier_token.text();
+            match self.run(metadata, &mut simulator, file, test) {
+                Ok(()) => {
+                    passed += 1;
+                    if !self.opt.quiet {
+                        println!("test {} ... ok", name);
+                    }
+                }
+                Err(err) => {
+                    failed += 1;
+                    if !self.opt.quiet {
+                        println!("test {} ... FAILED: {}", name, err);
+                    }
+                }
+            }
+        }
+
+        if !self.opt.quiet {
+            let result = if failed == 0 { "ok" } else { "FAILED" };
+            println!(
+                "test result: {}. {} passed; {} failed",
+                result, passed, failed
+            );
+        }
+
+        let elapsed_time = now.elapsed();
+        self.print(&format!(
+            "[Info] Elapsed time: {} milliseconds.",
+            elapsed_time.as_millis()
+        ));
+
+        Ok(failed == 0)
+    }
+
+    fn run(
+        &self,
+        metadata: &Metadata,
+        simulator: &mut Simulator,
+        file: &Path,
+        test: &TestDeclaration,
+    ) -> Result<(), SimulatorError> {
+        let items = simulator.elaborate_test(test)?;
+
+        let config = &metadata.test;
+        if let Some(format) = config.waveform {
+            let format = match format {
+                WaveformFormat::Vcd => WaveFormat::Vcd,
+                WaveformFormat::Fst => WaveFormat::Fst,
+            };
+            simulator.dump(WaveOption {
+                format,
+                depth: config.waveform_depth,
+                start: config.waveform_start,
+                end: config.waveform_end,
+                timescale: config.timescale.exponent(),
+            });
+        }
+
+        // waveform is written even if the test failed because it is useful to debug
+        let ret = simulator.run(&items);
+
+        if let Some(wave) = simulator.wave() {
+            let ext = match wave.option.format {
+                WaveFormat::Vcd => "vcd",
+                WaveFormat::Fst => "fst",
+            };
+            let name = test.identifier.identifier_token.text();
+            let output =
+                utils::output_path(metadata, file).with_file_name(format!("{}.{}", name, ext));
+            self.print(&format!("[Info] Output file: {}", output.to_string_lossy()));
+            match File::create(&output) {
+                Ok(x) => {
+                    let mut w = BufWriter::new(x);
+                    if let Err(err) = wave.write(&mut w) {
+                        println!("[Warning] {}: {}", output.to_string_lossy(), err);
+                    }
+                }
+                Err(err) => println!("[Warning] {}: {}", output.to_string_lossy(), err),
+            }
+        }
+
+        ret
+    }
+
+    fn print(&self, msg: &str) {
+        if self.opt.verbose {
+            println!("{}", msg);
+        }
+    }
+}
@@ -6,11 +6,13 @@ use veryl_parser::miette::Result;
 
 mod cmd_build;
 mod cmd_check;
+mod cmd_doc;
 mod cmd_dump;
 mod cmd_fmt;
 mod cmd_init;
 mod cmd_metadata;
 mod cmd_new;
+mod cmd_test;
 mod utils;
 
 // ---------------------------------------------------------------------------------------------------------------------
@@ -32,6 +34,8 @@ enum Commands {
     Fmt(OptFmt),
     Check(OptCheck),
     Build(OptBuild),
+    Test(OptTest),
+    Doc(OptDoc),
     Metadata(OptMetadata),
     Dump(OptDump),
 }
@@ -114,6 +118,36 @@ pub struct OptBuild {
     pub verbose: bool,
 }
 
+/// Run tests of the current package
+#[derive(Args)]
+pub struct OptTest {
+    /// Target files
+    pub files: Vec<PathBuf>,
+
+    /// No output printed to stdout
+    #[arg(long)]
+    pub quiet: bool,
+
+    /// Use verbose output
+    #[arg(long)]
+    pub verbose: bool,
+}
+
+/// Generate the documentation of the current package
+#[derive(Args)]
+pub struct OptDoc {
+    /// Target files
+    pub files: Vec<PathBuf>,
+
+    /// No output printed to stdout
+    #[arg(long)]
+    pub quiet: bool,
+
+    /// Use verbose output
+    #[arg(long)]
+    pub verbose: bool,
+}
+
 /// Dump metadata of the current packege
 #[derive(Args)]
 pub struct OptMetadata {
@@ -155,6 +189,10 @@ pub struct OptDump {
     #[arg(long)]
     pub namespace_table: bool,
 
+    /// output reference table
+    #[arg(long)]
+    pub reference_table: bool,
+
     /// No output printed to stdout
     #[arg(long)]
     pub quiet: bool,
@@ -186,9 +224,33 @@ fn main() -> Result<ExitCode> {
                 quiet: x.quiet,
                 verbose: x.verbose,
             };
-            cmd_check::CmdCheck::new(opt_check).exec(&metadata)?;
+            cmd_check::CmdCheck::new(opt_check)
+                .skip_generated()
+                .exec(&metadata)?;
             cmd_build::CmdBuild::new(x).exec(&metadata)?
         }
+        Commands::Test(x) => {
+            let opt_check = OptCheck {
+                files: x.files.clone(),
+                quiet: x.quiet,
+                verbose: x.verbose,
+            };
+            cmd_check::CmdCheck::new(opt_check)
+                .skip_generated()
+                .exec(&metadata)?;
+            cmd_test::CmdTest::new(x).exec(&metadata)?
+        }
+        Commands::Doc(x) => {
+            let opt_check = OptCheck {
+                files: x.files.clone(),
+                quiet: x.quiet,
+                verbose: x.verbose,
+            };
+            cmd_check::CmdCheck::new(opt_check)
+                .skip_generated()
+                .exec(&metadata)?;
+            cmd_doc::CmdDoc::new(x).exec(&metadata)?
+        }
         Commands::Metadata(x) => cmd_metadata::CmdMetadata::new(x).exec(&metadata)?,
         Commands::Dump(x) => cmd_dump::CmdDump::new(x).exec(&metadata)?,
     };
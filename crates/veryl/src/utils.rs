@@ -1,4 +1,5 @@
 use std::path::{Path, PathBuf};
+use veryl_metadata::{Metadata, Target};
 use veryl_parser::veryl_parser::miette::{IntoDiagnostic, Result};
 use walkdir::WalkDir;
 
@@ -17,6 +18,17 @@ pub fn gather_files<T: AsRef<Path>>(base_dir: T) -> Result<Vec<PathBuf>> {
     Ok(ret)
 }
 
+pub fn output_path<T: AsRef<Path>>(metadata: &Metadata, file: T) -> PathBuf {
+    let file = file.as_ref();
+    match metadata.build.target {
+        Target::Source => file.with_extension("sv"),
+        Target::Directory { ref path } => {
+            let base = metadata.metadata_path.parent().unwrap().to_owned();
+            base.join(path.join(file.with_extension("sv").file_name().unwrap()))
+        }
+    }
+}
+
 pub fn create_default_toml(name: &str) -> String {
     format!(
         r###"[package]
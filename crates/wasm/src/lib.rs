@@ -1,7 +1,7 @@
 use semver::Version;
 use veryl_emitter::Emitter;
 use veryl_formatter::Formatter;
-use veryl_metadata::{Build, Format, Metadata, Package};
+use veryl_metadata::{Build, Doc, Format, Metadata, Package, Test};
 use veryl_parser::miette::{
     ErrReport, GraphicalReportHandler, GraphicalTheme, ThemeCharacters, ThemeStyles,
 };
@@ -56,6 +56,8 @@ fn metadata() -> Metadata {
         },
         build: Build::default(),
         format: Format::default(),
+        test: Test::default(),
+        doc: Doc::default(),
         metadata_path: "".into(),
     }
 }
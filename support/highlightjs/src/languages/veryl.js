@@ -13,7 +13,7 @@ module.exports = function (hljs)
     case_insensitive: false,
     keywords:
       {
-        keyword: 'module interface function modport package enum struct parameter localparam posedge negedge async_high async_low sync_high sync_low always_ff always_comb assign return var inst import export logic bit tri u32 u64 i32 i64 f32 f64 input output inout ref if if_reset else for in case for in step repeat',
+        keyword: 'module interface function modport package enum struct union unpacked type parameter localparam posedge negedge async_high async_low sync_high sync_low always_ff always_comb initial final assign return assert assume cover var inst import export test expect logic bit tri signed as u32 u64 i32 i64 f32 f64 input output inout ref if if_reset else for in case for in step repeat',
         literal: ''
       },
     contains:
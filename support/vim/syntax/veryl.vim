@@ -18,6 +18,10 @@ syn match verylNumber "\(\<\d\+\|\)'[sS]\?[hH]\s*[0-9a-fA-F_xXzZ?]\+\>"
 syn match verylNumber "\<\=[0-9_]\+\(\.[0-9_]*\|\)\([eE][+-][0-9_]*\|\)\>"
 hi def link verylNumber Number
 
+" String
+syn region verylString start=+"+ skip=+\\\\\|\\"+ end=+"+
+hi def link verylString String
+
 " Operator
 syn match verylOperator1           "\m[~!&|^*+-/%><]"
 syn match verylAssignmentOperator1 "\m[=]"
@@ -40,19 +44,20 @@ syn match verylSymbol "[)(#@:;}{,.\[\]]"
 hi def link verylSymbol Special
 
 " Keyword
-syn keyword verylStructure module interface function modport package
-syn keyword verylStructure enum struct
+syn keyword verylStructure module interface function modport package test
+syn keyword verylStructure enum struct union unpacked type
 hi def link verylStructure Structure
 
 syn keyword verylStatement parameter localparam
 syn keyword verylStatement posedge negedge
 syn keyword verylStatement async_high async_low sync_high sync_low
-syn keyword verylStatement always_ff always_comb assign return
+syn keyword verylStatement always_ff always_comb initial final assign return assert assume cover
 syn keyword verylStatement var inst
 syn keyword verylStatement import export
+syn keyword verylStatement expect as
 hi def link verylStatement Statement
 
-syn keyword verylType logic bit tri
+syn keyword verylType logic bit tri signed
 syn keyword verylType u32 u64 i32 i64 f32 f64
 hi def link verylType Type
 
@@ -1,6 +1,6 @@
 module Module09 ;
     // struct declaration
-    typedef struct {
+    typedef struct packed {
         logic        [10-1:0] a  ;
         logic        [10-1:0] aa ;
         int unsigned         aaa ;
@@ -6,7 +6,7 @@ package Package17;
     logic  b;
 
     // struct declaration
-    typedef struct {
+    typedef struct packed {
         logic        [10-1:0] a  ;
         logic        [10-1:0] aa ;
         int unsigned         aaa ;
@@ -0,0 +1,14 @@
+module Module20 (
+    input  logic         i_clk  ,
+    input  logic         i_rst_n,
+    input  logic [8-1:0] i_a    ,
+    output logic [8-1:0] o_b
+) ;
+    always_ff @ (posedge i_clk, negedge i_rst_n) begin
+        if (!i_rst_n) begin
+            o_b <= 0;
+        end else begin
+            o_b <= i_a;
+        end
+    end
+endmodule
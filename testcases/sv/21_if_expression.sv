@@ -0,0 +1,11 @@
+module Module21 ;
+    logic  a;
+    logic  b;
+    logic  x;
+    logic  y;
+    logic  c;
+
+    assign a = x ? 1 : 0;
+    assign b = (x ? 1 : y ? 2 : 3) + 1;
+    assign c = x ? a : b + 1;
+endmodule
@@ -0,0 +1,18 @@
+module Module22 ;
+    // union declaration
+    typedef union packed {
+        logic [8-1:0] a ;
+        bit   [8-1:0] aa;
+    } A;
+
+    // unpacked struct declaration
+    typedef struct {
+        logic        [8-1:0] b  [4];
+        int unsigned        bb ;
+    } B;
+
+    // packed and unpacked array
+    logic [4-1:0][8-1:0] c  ;
+    logic [8-1:0]        cc  [16];
+    logic                ccc [2][4];
+endmodule
@@ -0,0 +1,12 @@
+package Package23;
+    typedef logic [32-1:0] word_t;
+endpackage
+
+module Module23 ;
+    typedef logic  [8-1:0] byte_t;
+    typedef byte_t [4-1:0] bytes_t;
+
+    byte_t            a;
+    bytes_t           b;
+    Package23::word_t c;
+endmodule
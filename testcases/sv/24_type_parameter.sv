@@ -0,0 +1,36 @@
+package Package24;
+    typedef logic [32-1:0] word_t;
+endpackage
+
+module Module24A #(
+    parameter type         T = Package24::word_t,
+    parameter int unsigned N  = 4            
+) (
+    input  T i_d,
+    output T o_d
+) ;
+    assign o_d = i_d;
+endmodule
+
+module Module24B ;
+    typedef logic [8-1:0] byte_t;
+
+    byte_t         a;
+    byte_t         b;
+    logic  [16-1:0] c;
+
+    Module24A #(
+        .T  (byte_t),
+        .N  (2     )
+    ) u0 (
+        .i_d (a),
+        .o_d (b)
+    );
+
+    Module24A #(
+        .T  (logic [16-1:0])
+    ) u1 (
+        .i_d (c),
+        .o_d (c)
+    );
+endmodule
@@ -0,0 +1,20 @@
+module Module25 ;
+    logic [8-1:0] a;
+    logic [8-1:0] b;
+
+    // simulation-only setup
+    initial begin
+        a = 1;
+        if (a == 1) begin
+            b = 0;
+        end else begin
+            b = 1;
+        end
+    end
+
+    final begin
+        a = 0;
+    end
+
+    assign b = a;
+endmodule
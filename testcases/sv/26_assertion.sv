@@ -0,0 +1,21 @@
+module Module26 (
+    input logic  i_clk,
+    input logic  i_rst
+) ;
+    logic  a;
+    logic  b;
+
+    // concurrent assertions
+    assert property (@(posedge i_clk) disable iff (!i_rst) a == b);
+    assume property (@(negedge i_clk) disable iff (i_rst) a);
+    cover property (@(posedge i_clk) a && b);
+
+    // immediate assertions
+    always_comb begin
+        a = 1;
+        assert (a != 0);
+        if (b) begin
+            cover (a == b);
+        end
+    end
+endmodule
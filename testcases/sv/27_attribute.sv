@@ -0,0 +1,18 @@
+(* dont_touch *)
+module Module27 (
+    (* keep *)
+    input  logic  i_clk,
+    input  logic  i_d  ,
+    output logic  o_d  
+) ;
+    // synchronizer
+    (* ram_style = "block" *)
+    logic  a;
+
+    (* max_fanout = 16 *)
+    logic  b;
+
+    // model only
+    // output
+    assign o_d = b;
+endmodule
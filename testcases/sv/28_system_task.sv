@@ -0,0 +1,21 @@
+module Module28 ;
+    logic [8-1:0] a  ;
+    logic [8-1:0] mem;
+
+    initial begin
+        $readmemh("mem.hex", mem);
+        $display("a = %d, mem = %h", a, mem);
+        $display("quote \" and backslash \\");
+        $finish;
+    end
+
+    always_comb begin
+        if (a == 0) begin
+            $fatal(1, "a must not be %0d", a);
+        end
+    end
+
+    final begin
+        $write("done\n");
+    end
+endmodule
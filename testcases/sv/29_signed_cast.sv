@@ -0,0 +1,32 @@
+package Package29;
+    typedef enum logic [2-1:0] {
+        MemberA,
+        MemberB
+    } EnumA;
+endpackage
+
+module Module29 (
+    input  logic signed     [8-1:0]  i_a,
+    input  logic            [8-1:0]  i_b,
+    inout  tri logic signed [8-1:0]  i_c,
+    output int signed               o_a ,
+    output logic signed     [16-1:0] o_b
+) ;
+    typedef logic signed [16-1:0] sword;
+
+    sword                          a;
+    int unsigned                   b ;
+    Package29::EnumA               c;
+    logic            [4-1:0]        d;
+    logic            [2-1:0][8-1:0] e;
+    bit                             f;
+
+    assign a   = i_a + (signed'(8'(i_b)));
+    assign b   = unsigned'(int'(o_a));
+    assign c   = Package29::EnumA'(d[1:0]);
+    assign d   = unsigned'(4'(i_b));
+    assign e   = unsigned'(((2)*(8))'(a));
+    assign f   = bit'(i_b);
+    assign o_a = int'(-i_a);
+    assign o_b = (sword'(i_a)) * $signed(i_b);
+endmodule
@@ -0,0 +1,25 @@
+package Package30;
+    // unpacked struct declaration
+    typedef struct {
+        logic        [8-1:0] a  [4];
+        int unsigned        aa ;
+    } A;
+endpackage
+
+module Module30 ;
+    // packed struct declaration
+    typedef struct packed {
+        logic [8-1:0] b ;
+        bit   [8-1:0] bb;
+    } B;
+
+    // unpacked struct declaration
+    typedef struct {
+        B            c ;
+        logic [8-1:0] cc [2];
+    } C;
+
+    Package30::A a;
+    B            b;
+    C            c;
+endmodule